@@ -9,7 +9,7 @@ use crate::{
     gc,
     init::{InitToken, token},
     obj::{GcError, Obj, ObjFullType, ObjType, TypeFlags},
-    print::{Print, PrintKind},
+    print::{Print, PrintKind, mp_obj_print_exception, mp_plat_print},
     qstr::Qstr,
 };
 
@@ -29,6 +29,9 @@ unsafe extern "C" {
     ) -> Obj;
     fn mp_obj_exception_print(print: *const Print, o: Obj, kind: PrintKind);
     fn mp_obj_exception_attr(self_in: Obj, attr: Qstr, dest: *mut Obj);
+    fn mp_obj_new_exception(exc_type: *const ObjType) -> Obj;
+    fn mp_obj_new_exception_msg(exc_type: *const ObjType, msg: RomErrorText) -> Obj;
+    fn mp_obj_exception_match(exc: Obj, exc_type: *const ObjType) -> bool;
 
     fn mp_raise_msg(exc_type: *const ObjType, msg: RomErrorText) -> !;
     fn mp_raise_StopIteration(arg: Obj) -> !;
@@ -45,6 +48,7 @@ unsafe extern "C" {
     safe static mp_type_RuntimeError: ObjType;
     safe static mp_type_AttributeError: ObjType;
     safe static mp_type_ZeroDivisionError: ObjType;
+    safe static mp_type_MemoryError: ObjType;
 }
 
 impl<'a> RomErrorText<'a> {
@@ -85,6 +89,23 @@ pub const IMPORT_ERROR_TYPE: &ObjType = &mp_type_ImportError;
 pub const RUNTIME_ERROR_TYPE: &ObjType = &mp_type_RuntimeError;
 pub const ATTRIBUTE_ERROR_TYPE: &ObjType = &mp_type_AttributeError;
 pub const ZERO_DIVISION_ERROR_TYPE: &ObjType = &mp_type_ZeroDivisionError;
+pub const MEMORY_ERROR_TYPE: &ObjType = &mp_type_MemoryError;
+
+/// Allocates a new instance of `exc_type` without raising it.
+pub fn new_exception(_: InitToken, exc_type: &ObjType) -> Obj {
+    unsafe { mp_obj_new_exception(exc_type) }
+}
+
+/// Prints `exc` and its traceback to the platform output, like an unhandled exception.
+pub fn print_exception(_: InitToken, exc: Obj) {
+    unsafe { mp_obj_print_exception(&raw const mp_plat_print, exc) };
+}
+
+/// Returns whether the exception instance `exc` is an instance of `exc_type` or one of its
+/// subclasses.
+pub fn exception_matches(exc: Obj, exc_type: &ObjType) -> bool {
+    unsafe { mp_obj_exception_match(exc, exc_type) }
+}
 
 pub fn raise_msg(_: InitToken, exc_type: &ObjType, msg: impl AsRef<CStr>) -> ! {
     unsafe { mp_raise_msg(exc_type, RomErrorText::new(msg.as_ref())) };
@@ -157,6 +178,11 @@ impl Exception {
     pub fn raise(&self, token: InitToken) -> ! {
         raise_msg(token, self.ty, self.msg.as_ref())
     }
+
+    /// Allocates the exception instance without raising it.
+    pub fn to_obj(&self, _: InitToken) -> Obj {
+        unsafe { mp_obj_new_exception_msg(self.ty, RomErrorText::new(self.msg.as_ref())) }
+    }
 }
 
 impl<T, E> From<Result<T, E>> for Obj
@@ -220,3 +246,10 @@ pub fn import_error(msg: impl Into<Message>) -> Exception {
         msg: msg.into(),
     }
 }
+
+pub fn memory_error(msg: impl Into<Message>) -> Exception {
+    Exception {
+        ty: MEMORY_ERROR_TYPE,
+        msg: msg.into(),
+    }
+}
@@ -1140,6 +1140,15 @@ impl Obj {
         unsafe { mp_store_attr(self, attr, value) };
     }
 
+    /// Tests the object's truthiness using MicroPython's normal truth testing.
+    pub fn is_true(self) -> bool {
+        unsafe extern "C" {
+            fn mp_obj_is_true(arg: Obj) -> bool;
+        }
+
+        unsafe { mp_obj_is_true(self) }
+    }
+
     pub fn ty(self) -> Option<repr_c::Ty> {
         repr_c::type_of(self.0)
     }
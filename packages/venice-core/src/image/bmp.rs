@@ -0,0 +1,308 @@
+//! A decoder for uncompressed Windows bitmaps with 1, 4, 8, 16, 24 or 32 bits per pixel.
+
+use std::alloc::Allocator;
+
+use super::{Bitmap, DecodeError};
+
+pub const MAGIC: &[u8] = b"BM";
+
+// the size of the file header that comes before the info header
+const FILE_HEADER_SIZE: usize = 14;
+
+const BI_RGB: u32 = 0;
+const BI_BITFIELDS: u32 = 3;
+const BI_ALPHABITFIELDS: u32 = 6;
+
+fn u16_at(data: &[u8], offset: usize) -> Result<u16, DecodeError> {
+    let bytes = data.get(offset..offset + 2).ok_or(DecodeError::Truncated)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Result<u32, DecodeError> {
+    let bytes = data.get(offset..offset + 4).ok_or(DecodeError::Truncated)?;
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// One color channel packed into a 16- or 32-bit pixel.
+#[derive(Clone, Copy)]
+struct Mask {
+    mask: u32,
+    shift: u32,
+    max: u32,
+}
+
+impl Mask {
+    fn new(mask: u32) -> Self {
+        let shift = if mask == 0 { 0 } else { mask.trailing_zeros() };
+        Self {
+            mask,
+            shift,
+            max: mask >> shift,
+        }
+    }
+
+    /// Extracts the channel from `pixel`, scaled to 8 bits, or `default` if the mask is empty.
+    fn extract(self, pixel: u32, default: u32) -> u32 {
+        if self.max == 0 {
+            return default;
+        }
+        (((pixel & self.mask) >> self.shift) as u64 * 255 / self.max as u64) as u32
+    }
+}
+
+/// Decodes a BMP image, which must start with [`MAGIC`].
+pub fn decode<A: Allocator>(data: &[u8], alloc: A) -> Result<Bitmap<A>, DecodeError> {
+    if !data.starts_with(MAGIC) {
+        return Err(DecodeError::UnknownFormat);
+    }
+
+    let pixel_offset = u32_at(data, 10)? as usize;
+    let header_size = u32_at(data, FILE_HEADER_SIZE)? as usize;
+    if header_size < 40 {
+        return Err(DecodeError::Unsupported("BMP header version"));
+    }
+
+    let width = u32_at(data, 18)? as i32;
+    let height = u32_at(data, 22)? as i32;
+    let bits = u16_at(data, 28)? as usize;
+    let compression = u32_at(data, 30)?;
+    let palette_size = u32_at(data, 46)? as usize;
+    if width < 0 {
+        return Err(DecodeError::Corrupt("the width is negative"));
+    }
+    // Rows are stored from the bottom up unless the height is negative
+    let top_down = height < 0;
+    let (width, height) = (width as usize, height.unsigned_abs() as usize);
+
+    let masks = match (compression, bits) {
+        (BI_RGB, 1 | 4 | 8 | 24) => None,
+        (BI_RGB, 16) => Some([0x7c00, 0x03e0, 0x001f, 0].map(Mask::new)),
+        (BI_RGB, 32) => Some([0x00ff_0000, 0x0000_ff00, 0x0000_00ff, 0].map(Mask::new)),
+        (BI_BITFIELDS | BI_ALPHABITFIELDS, 16 | 32) => {
+            // The masks follow a 40-byte header, or are part of a longer one
+            let offset = FILE_HEADER_SIZE + 40;
+            let alpha = if compression == BI_ALPHABITFIELDS || header_size >= 56 {
+                u32_at(data, offset + 12)?
+            } else {
+                0
+            };
+            Some(
+                [
+                    u32_at(data, offset)?,
+                    u32_at(data, offset + 4)?,
+                    u32_at(data, offset + 8)?,
+                    alpha,
+                ]
+                .map(Mask::new),
+            )
+        }
+        (BI_RGB | BI_BITFIELDS | BI_ALPHABITFIELDS, _) => {
+            return Err(DecodeError::Corrupt("the bit depth is invalid"));
+        }
+        _ => return Err(DecodeError::Unsupported("BMP compression")),
+    };
+
+    let palette = if bits <= 8 {
+        let count = if palette_size == 0 {
+            1 << bits
+        } else {
+            palette_size.min(1 << bits)
+        };
+        let start = FILE_HEADER_SIZE + header_size;
+        data.get(start..start + count * 4)
+            .ok_or(DecodeError::Truncated)?
+    } else {
+        &[]
+    };
+
+    let mut bitmap = Bitmap::new(width, height, alloc)?;
+
+    // Rows are padded to a multiple of four bytes
+    let row_bytes = (width * bits).div_ceil(32) * 4;
+    for row_index in 0..height {
+        let start = pixel_offset + row_index * row_bytes;
+        let row = data
+            .get(start..start + row_bytes)
+            .ok_or(DecodeError::Truncated)?;
+        let y = if top_down {
+            row_index
+        } else {
+            height - 1 - row_index
+        };
+
+        for (x, out) in bitmap.pixels[y * width..(y + 1) * width]
+            .iter_mut()
+            .enumerate()
+        {
+            *out = match (bits, masks) {
+                (1 | 4 | 8, _) => {
+                    let bit = x * bits;
+                    let shift = 8 - bits - bit % 8;
+                    let index = ((row[bit / 8] >> shift) as usize) & ((1 << bits) - 1);
+                    let color = palette
+                        .get(index * 4..index * 4 + 3)
+                        .ok_or(DecodeError::Corrupt("a pixel is outside the palette"))?;
+                    0xff00_0000 | (color[2] as u32) << 16 | (color[1] as u32) << 8 | color[0] as u32
+                }
+                (24, _) => {
+                    let [b, g, r] = [row[x * 3], row[x * 3 + 1], row[x * 3 + 2]].map(u32::from);
+                    0xff00_0000 | r << 16 | g << 8 | b
+                }
+                (_, Some([r, g, b, a])) => {
+                    let pixel = if bits == 16 {
+                        u16::from_le_bytes([row[x * 2], row[x * 2 + 1]]) as u32
+                    } else {
+                        u32::from_le_bytes([
+                            row[x * 4],
+                            row[x * 4 + 1],
+                            row[x * 4 + 2],
+                            row[x * 4 + 3],
+                        ])
+                    };
+                    a.extract(pixel, 255) << 24
+                        | r.extract(pixel, 0) << 16
+                        | g.extract(pixel, 0) << 8
+                        | b.extract(pixel, 0)
+                }
+                _ => unreachable!("16- and 32-bit images always have masks"),
+            };
+        }
+    }
+
+    Ok(bitmap)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::alloc::Global;
+
+    use super::{
+        super::{Bitmap, DecodeError},
+        BI_ALPHABITFIELDS, BI_BITFIELDS, BI_RGB, MAGIC, decode,
+    };
+
+    /// Builds a BMP with a 40-byte info header, followed by `extra`, which holds the masks or
+    /// palette, and then `pixels`.
+    fn bmp(
+        [width, height]: [i32; 2],
+        bits: u16,
+        compression: u32,
+        extra: &[u8],
+        pixels: &[u8],
+    ) -> Vec<u8> {
+        let pixel_offset = 14 + 40 + extra.len() as u32;
+        let mut image = MAGIC.to_vec();
+        image.extend_from_slice(&(pixel_offset + pixels.len() as u32).to_le_bytes());
+        image.extend_from_slice(&[0; 4]);
+        image.extend_from_slice(&pixel_offset.to_le_bytes());
+
+        image.extend_from_slice(&40u32.to_le_bytes());
+        image.extend_from_slice(&width.to_le_bytes());
+        image.extend_from_slice(&height.to_le_bytes());
+        image.extend_from_slice(&1u16.to_le_bytes());
+        image.extend_from_slice(&bits.to_le_bytes());
+        image.extend_from_slice(&compression.to_le_bytes());
+        // the image size, resolution, palette size and important color count
+        image.extend_from_slice(&[0; 20]);
+
+        image.extend_from_slice(extra);
+        image.extend_from_slice(pixels);
+        image
+    }
+
+    fn pixels(bitmap: &Bitmap<Global>) -> Vec<u32> {
+        (0..bitmap.height())
+            .flat_map(|y| (0..bitmap.width()).map(move |x| bitmap.pixel(x, y)))
+            .collect()
+    }
+
+    // two rows of two blue, green, red pixels, each padded to eight bytes
+    const ROWS: [u8; 16] = [
+        0x00, 0x00, 0xff, 0x00, 0xff, 0x00, 0, 0, //
+        0xff, 0x00, 0x00, 0x10, 0x20, 0x30, 0, 0,
+    ];
+
+    #[test]
+    fn rows_are_bottom_up_unless_the_height_is_negative() {
+        let bottom_up = decode(&bmp([2, 2], 24, BI_RGB, &[], &ROWS), Global).unwrap();
+        assert_eq!(
+            pixels(&bottom_up),
+            [0xff00_00ff, 0xff30_2010, 0xffff_0000, 0xff00_ff00]
+        );
+
+        let top_down = decode(&bmp([2, -2], 24, BI_RGB, &[], &ROWS), Global).unwrap();
+        assert_eq!(
+            pixels(&top_down),
+            [0xffff_0000, 0xff00_ff00, 0xff00_00ff, 0xff30_2010]
+        );
+    }
+
+    #[test]
+    fn bitfields_select_the_channels() {
+        // 5-6-5 red, green and blue
+        let masks: Vec<u8> = [0xf800u32, 0x07e0, 0x001f]
+            .iter()
+            .flat_map(|mask| mask.to_le_bytes())
+            .collect();
+        let row = [0x00, 0xf8, 0x10, 0x00];
+        let image = decode(&bmp([2, 1], 16, BI_BITFIELDS, &masks, &row), Global).unwrap();
+        // 16 of 31 blue scales to 131 of 255
+        assert_eq!(pixels(&image), [0xffff_0000, 0xff00_0083]);
+
+        // alpha, blue, green and red bytes, in that order from the top
+        let masks: Vec<u8> = [0x0000_00ffu32, 0x0000_ff00, 0x00ff_0000, 0xff00_0000]
+            .iter()
+            .flat_map(|mask| mask.to_le_bytes())
+            .collect();
+        let row = [0x11, 0x22, 0x33, 0x44];
+        let image = decode(&bmp([1, 1], 32, BI_ALPHABITFIELDS, &masks, &row), Global).unwrap();
+        assert_eq!(pixels(&image), [0x4411_2233]);
+    }
+
+    #[test]
+    fn palette_indices_are_looked_up() {
+        // blue, green, red and unused bytes of black and a light blue
+        let palette = [0, 0, 0, 0, 0xff, 0x80, 0x40, 0];
+        let row = [0b0110_0000, 0, 0, 0];
+        let image = decode(&bmp([3, 1], 1, BI_RGB, &palette, &row), Global).unwrap();
+        assert_eq!(pixels(&image), [0xff00_0000, 0xff40_80ff, 0xff40_80ff]);
+    }
+
+    #[test]
+    fn invalid_images_are_rejected() {
+        let decode = |image: &[u8]| decode(image, Global).map(|_| ());
+
+        assert_eq!(
+            decode(&bmp([2, 2], 24, 1, &[], &ROWS)),
+            Err(DecodeError::Unsupported("BMP compression"))
+        );
+        assert_eq!(
+            decode(&bmp([2, 2], 12, BI_RGB, &[], &ROWS)),
+            Err(DecodeError::Corrupt("the bit depth is invalid"))
+        );
+        assert_eq!(
+            decode(&bmp([-2, 2], 24, BI_RGB, &[], &ROWS)),
+            Err(DecodeError::Corrupt("the width is negative"))
+        );
+        assert_eq!(
+            decode(&bmp([2, 3], 24, BI_RGB, &[], &ROWS)),
+            Err(DecodeError::Truncated)
+        );
+    }
+
+    #[test]
+    fn damaged_images_fail_without_panicking() {
+        let image = bmp([2, 2], 24, BI_RGB, &[], &ROWS);
+        for length in 0..image.len() {
+            assert!(decode(&image[..length], Global).is_err());
+        }
+
+        for index in 0..image.len() {
+            for bit in 0..8 {
+                let mut damaged = image.clone();
+                damaged[index] ^= 1 << bit;
+                let _ = decode(&damaged, Global);
+            }
+        }
+    }
+}
@@ -0,0 +1,445 @@
+//! A zlib and DEFLATE decompressor (RFC 1950 and RFC 1951) for PNG image data.
+
+use std::alloc::Allocator;
+
+use super::DecodeError;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+// the order in which code length code lengths are stored in a dynamic block
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+const MAX_CODE_LENGTH: usize = 15;
+
+/// Reads bits from a byte stream, least significant bit first.
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    buffer: u32,
+    count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            position: 0,
+            buffer: 0,
+            count: 0,
+        }
+    }
+
+    /// Reads `n` bits, where `n` is at most 16.
+    fn bits(&mut self, n: u32) -> Result<u32, DecodeError> {
+        while self.count < n {
+            let byte = *self.data.get(self.position).ok_or(DecodeError::Truncated)?;
+            self.buffer |= (byte as u32) << self.count;
+            self.position += 1;
+            self.count += 8;
+        }
+
+        let value = self.buffer & ((1 << n) - 1);
+        self.buffer >>= n;
+        self.count -= n;
+        Ok(value)
+    }
+
+    /// Discards the bits left in the current byte and returns the rest of the stream.
+    fn align(&mut self) -> &'a [u8] {
+        self.buffer = 0;
+        self.count = 0;
+        &self.data[self.position..]
+    }
+}
+
+/// A canonical Huffman code, decoded one bit at a time.
+struct Huffman {
+    // the number of codes of each length
+    counts: [u16; MAX_CODE_LENGTH + 1],
+    // symbols ordered by code
+    symbols: [u16; 288],
+}
+
+impl Huffman {
+    /// Builds a code from the length of each symbol's code, where a length of zero means the
+    /// symbol is unused.
+    fn new(lengths: &[u8]) -> Result<Self, DecodeError> {
+        let mut counts = [0u16; MAX_CODE_LENGTH + 1];
+        for &length in lengths {
+            counts[length as usize] += 1;
+        }
+
+        // Each length can hold twice as many codes as the one before, less those already used
+        let mut left = 1i32;
+        for &count in &counts[1..] {
+            left = left * 2 - count as i32;
+            if left < 0 {
+                return Err(DecodeError::Corrupt("a Huffman code is oversubscribed"));
+            }
+        }
+
+        let mut offsets = [0u16; MAX_CODE_LENGTH + 1];
+        for length in 1..MAX_CODE_LENGTH {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+
+        let mut symbols = [0u16; 288];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                symbols[offsets[length as usize] as usize] = symbol as u16;
+                offsets[length as usize] += 1;
+            }
+        }
+
+        Ok(Self { counts, symbols })
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<u16, DecodeError> {
+        // the first code of the current length, and the index of its symbol
+        let mut code = 0i32;
+        let mut first = 0i32;
+        let mut index = 0i32;
+        for &count in &self.counts[1..] {
+            code |= reader.bits(1)? as i32;
+            let count = count as i32;
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(DecodeError::Corrupt("a Huffman code is invalid"))
+    }
+}
+
+fn fixed_codes() -> Result<(Huffman, Huffman), DecodeError> {
+    let mut lengths = [0u8; 288];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+    Ok((Huffman::new(&lengths)?, Huffman::new(&[5; 30])?))
+}
+
+fn dynamic_codes(reader: &mut BitReader) -> Result<(Huffman, Huffman), DecodeError> {
+    let literal_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_length_count = reader.bits(4)? as usize + 4;
+    if literal_count > 286 || distance_count > 30 {
+        return Err(DecodeError::Corrupt("a block has too many codes"));
+    }
+
+    let mut code_lengths = [0u8; 19];
+    for &symbol in &CODE_LENGTH_ORDER[..code_length_count] {
+        code_lengths[symbol] = reader.bits(3)? as u8;
+    }
+    let code_length_code = Huffman::new(&code_lengths)?;
+
+    let mut lengths = [0u8; 286 + 30];
+    let total = literal_count + distance_count;
+    let mut i = 0;
+    while i < total {
+        let symbol = code_length_code.decode(reader)?;
+        let (length, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *i
+                    .checked_sub(1)
+                    .and_then(|previous| lengths.get(previous))
+                    .ok_or(DecodeError::Corrupt("a code length repeats nothing"))?;
+                (previous, 3 + reader.bits(2)? as usize)
+            }
+            17 => (0, 3 + reader.bits(3)? as usize),
+            _ => (0, 11 + reader.bits(7)? as usize),
+        };
+        if i + repeat > total {
+            return Err(DecodeError::Corrupt("code lengths overrun the block"));
+        }
+        lengths[i..i + repeat].fill(length);
+        i += repeat;
+    }
+
+    if lengths[256] == 0 {
+        return Err(DecodeError::Corrupt("a block has no end code"));
+    }
+
+    Ok((
+        Huffman::new(&lengths[..literal_count])?,
+        Huffman::new(&lengths[literal_count..total])?,
+    ))
+}
+
+/// Makes room for `additional` more bytes of `output`, which may hold at most `limit` bytes.
+///
+/// The output grows as it is decompressed rather than being allocated at its full size up front,
+/// doubling in size each time but never past `limit`.
+fn reserve<A: Allocator>(
+    output: &mut Vec<u8, A>,
+    additional: usize,
+    limit: usize,
+) -> Result<(), DecodeError> {
+    let needed = output.len() + additional;
+    if needed > limit {
+        return Err(DecodeError::Corrupt(
+            "there is more image data than expected",
+        ));
+    }
+    if needed > output.capacity() {
+        let capacity = needed.max(output.capacity() * 2).min(limit);
+        output
+            .try_reserve_exact(capacity - output.len())
+            .map_err(|_| DecodeError::OutOfMemory)?;
+    }
+    Ok(())
+}
+
+fn inflate_block<A: Allocator>(
+    reader: &mut BitReader,
+    literals: &Huffman,
+    distances: &Huffman,
+    output: &mut Vec<u8, A>,
+    limit: usize,
+) -> Result<(), DecodeError> {
+    loop {
+        let symbol = literals.decode(reader)? as usize;
+        match symbol {
+            0..=255 => {
+                reserve(output, 1, limit)?;
+                output.push(symbol as u8);
+            }
+            256 => return Ok(()),
+            _ => {
+                let index = symbol - 257;
+                if index >= LENGTH_BASE.len() {
+                    return Err(DecodeError::Corrupt("a length code is invalid"));
+                }
+                let length =
+                    LENGTH_BASE[index] as usize + reader.bits(LENGTH_EXTRA[index] as u32)? as usize;
+
+                let index = distances.decode(reader)? as usize;
+                if index >= DISTANCE_BASE.len() {
+                    return Err(DecodeError::Corrupt("a distance code is invalid"));
+                }
+                let distance = DISTANCE_BASE[index] as usize
+                    + reader.bits(DISTANCE_EXTRA[index] as u32)? as usize;
+
+                if distance > output.len() {
+                    return Err(DecodeError::Corrupt("a distance reaches before the start"));
+                }
+                reserve(output, length, limit)?;
+                // Copied a byte at a time, since the copy may overlap what it is copying
+                let start = output.len() - distance;
+                for i in 0..length {
+                    output.push(output[start + i]);
+                }
+            }
+        }
+    }
+}
+
+pub fn adler32(data: &[u8]) -> u32 {
+    const MODULUS: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    // 5552 bytes is the most that can be summed before `b` could overflow
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MODULUS;
+        b %= MODULUS;
+    }
+    (b << 16) | a
+}
+
+/// Decompresses a zlib stream of at most `limit` bytes into memory from `alloc`.
+pub fn decompress<A: Allocator>(
+    data: &[u8],
+    limit: usize,
+    alloc: A,
+) -> Result<Vec<u8, A>, DecodeError> {
+    let [method, flags, ..] = *data else {
+        return Err(DecodeError::Truncated);
+    };
+    if method & 0x0f != 8 || !((method as u16) << 8 | flags as u16).is_multiple_of(31) {
+        return Err(DecodeError::Corrupt(
+            "the compressed data has an invalid header",
+        ));
+    }
+    if flags & 0x20 != 0 {
+        return Err(DecodeError::Unsupported(
+            "compression with a preset dictionary",
+        ));
+    }
+
+    let mut output = Vec::new_in(alloc);
+    let mut reader = BitReader::new(&data[2..]);
+    loop {
+        let last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => {
+                let rest = reader.align();
+                let [len_low, len_high, nlen_low, nlen_high, ..] = *rest else {
+                    return Err(DecodeError::Truncated);
+                };
+                let length = u16::from_le_bytes([len_low, len_high]);
+                if length != !u16::from_le_bytes([nlen_low, nlen_high]) {
+                    return Err(DecodeError::Corrupt("a stored block has an invalid length"));
+                }
+
+                let stored = rest
+                    .get(4..4 + length as usize)
+                    .ok_or(DecodeError::Truncated)?;
+                reserve(&mut output, stored.len(), limit)?;
+                output.extend_from_slice(stored);
+                reader = BitReader::new(&rest[4 + length as usize..]);
+            }
+            1 => {
+                let (literals, distances) = fixed_codes()?;
+                inflate_block(&mut reader, &literals, &distances, &mut output, limit)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_codes(&mut reader)?;
+                inflate_block(&mut reader, &literals, &distances, &mut output, limit)?;
+            }
+            _ => return Err(DecodeError::Corrupt("a block has an invalid type")),
+        }
+
+        if last {
+            break;
+        }
+    }
+
+    let checksum = reader
+        .align()
+        .first_chunk::<4>()
+        .ok_or(DecodeError::Truncated)?;
+    if u32::from_be_bytes(*checksum) != adler32(&output) {
+        return Err(DecodeError::Corrupt(
+            "the compressed data's checksum doesn't match",
+        ));
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::alloc::Global;
+
+    use super::{DecodeError, adler32, decompress};
+
+    const TEXT: &[u8] = b"It was the best of times, it was the worst of times, it was the age of \
+        wisdom, it was the age of foolishness, it was the epoch ";
+
+    /// `TEXT` compressed by zlib into a single block with dynamic Huffman codes.
+    const DYNAMIC: &[u8] = &[
+        0x78, 0xda, 0x75, 0xcb, 0xc1, 0x09, 0x80, 0x40, 0x0c, 0x04, 0xc0, 0x56, 0xb6, 0x00, 0x1b,
+        0xb1, 0x8c, 0x53, 0xf7, 0xbc, 0x80, 0x67, 0xc4, 0x0d, 0xa4, 0x7d, 0xf1, 0x25, 0x82, 0xbe,
+        0x87, 0x19, 0x03, 0x59, 0x84, 0x68, 0xc4, 0x44, 0x05, 0xbc, 0x22, 0xac, 0x53, 0x03, 0xec,
+        0x91, 0xf4, 0xf3, 0x8f, 0xca, 0xca, 0x1b, 0xd2, 0xb4, 0x78, 0xff, 0x92, 0xea, 0xbe, 0x99,
+        0xda, 0x4e, 0xbd, 0x23, 0x0f, 0x9f, 0x1b, 0x2e, 0x0d, 0x62, 0x2c, 0x4c,
+    ];
+
+    /// "abcabcabcabc, hello hello hello" compressed by zlib into a single block with the fixed
+    /// Huffman codes, including copies that overlap what they copy.
+    const FIXED: &[u8] = &[
+        0x78, 0x01, 0x4b, 0x4c, 0x4a, 0x4e, 0x84, 0x21, 0x1d, 0x85, 0x8c, 0xd4, 0x9c, 0x9c, 0x7c,
+        0x64, 0x12, 0x00, 0xb4, 0xdc, 0x0b, 0x61,
+    ];
+
+    /// "hello" split across two stored blocks.
+    fn stored() -> Vec<u8> {
+        let mut stream = vec![0x78, 0x01];
+        stream.extend_from_slice(&[0x00, 3, 0, !3, !0, b'h', b'e', b'l']);
+        stream.extend_from_slice(&[0x01, 2, 0, !2, !0, b'l', b'o']);
+        stream.extend_from_slice(&adler32(b"hello").to_be_bytes());
+        stream
+    }
+
+    #[test]
+    fn stored_blocks() {
+        assert_eq!(decompress(&stored(), 5, Global).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn fixed_block() {
+        let output = decompress(FIXED, 100, Global).unwrap();
+        assert_eq!(output, b"abcabcabcabc, hello hello hello");
+    }
+
+    #[test]
+    fn dynamic_block() {
+        assert_eq!(decompress(DYNAMIC, TEXT.len(), Global).unwrap(), TEXT);
+    }
+
+    #[test]
+    fn output_is_limited() {
+        assert_eq!(
+            decompress(&stored(), 4, Global),
+            Err(DecodeError::Corrupt(
+                "there is more image data than expected"
+            ))
+        );
+        assert_eq!(
+            decompress(DYNAMIC, TEXT.len() - 1, Global),
+            Err(DecodeError::Corrupt(
+                "there is more image data than expected"
+            ))
+        );
+    }
+
+    #[test]
+    fn bad_header_and_checksum_are_rejected() {
+        let mut stream = FIXED.to_vec();
+        stream[1] ^= 1;
+        assert_eq!(
+            decompress(&stream, 100, Global),
+            Err(DecodeError::Corrupt(
+                "the compressed data has an invalid header"
+            ))
+        );
+
+        let mut stream = FIXED.to_vec();
+        *stream.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            decompress(&stream, 100, Global),
+            Err(DecodeError::Corrupt(
+                "the compressed data's checksum doesn't match"
+            ))
+        );
+    }
+
+    #[test]
+    fn damaged_streams_fail_without_panicking() {
+        for stream in [stored(), FIXED.to_vec(), DYNAMIC.to_vec()] {
+            for length in 0..stream.len() {
+                assert!(decompress(&stream[..length], 200, Global).is_err());
+            }
+
+            for index in 0..stream.len() {
+                for bit in 0..8 {
+                    let mut damaged = stream.clone();
+                    damaged[index] ^= 1 << bit;
+                    let _ = decompress(&damaged, 200, Global);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+mod bmp;
+mod inflate;
+mod png;
+mod qoi;
+
+use std::alloc::Allocator;
+
+use thiserror::Error;
+
+/// The most pixels a decoded image may have, enough for four screens' worth of the 480 by 240
+/// display, which keeps a corrupt header from exhausting memory.
+pub const MAX_PIXELS: usize = 4 * 480 * 240;
+
+/// An error that stops image data from being decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum DecodeError {
+    #[error("unrecognized image format, expected PNG, BMP or QOI")]
+    UnknownFormat,
+    #[error("image data ends unexpectedly")]
+    Truncated,
+    #[error("image is corrupt: {0}")]
+    Corrupt(&'static str),
+    #[error("unsupported {0}")]
+    Unsupported(&'static str),
+    #[error("image must be between 1 and {MAX_PIXELS} pixels")]
+    TooLarge,
+    #[error("not enough memory to decode the image")]
+    OutOfMemory,
+}
+
+/// A decoded image.
+///
+/// Pixels are stored in row-major order as `0xAARRGGBB`.
+pub struct Bitmap<A: Allocator> {
+    width: usize,
+    height: usize,
+    pixels: Vec<u32, A>,
+}
+
+impl<A: Allocator> Bitmap<A> {
+    /// Creates a transparent image, or returns an error if it would be empty, too large, or
+    /// doesn't fit in memory.
+    pub fn new(width: usize, height: usize, alloc: A) -> Result<Self, DecodeError> {
+        let count = width
+            .checked_mul(height)
+            .filter(|count| (1..=MAX_PIXELS).contains(count))
+            .ok_or(DecodeError::TooLarge)?;
+
+        // The GC allocator can't grow a buffer of `u32`s, so it's allocated at its full size
+        let mut pixels = Vec::new_in(alloc);
+        pixels
+            .try_reserve_exact(count)
+            .map_err(|_| DecodeError::OutOfMemory)?;
+        pixels.resize(count, 0);
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the pixel at (`x`, `y`) as `0xAARRGGBB`.
+    pub fn pixel(&self, x: usize, y: usize) -> u32 {
+        self.pixels[y * self.width + x]
+    }
+}
+
+/// Decodes PNG, BMP or QOI data, recognizing the format from its first bytes.
+pub fn decode<A: Allocator + Clone>(data: &[u8], alloc: A) -> Result<Bitmap<A>, DecodeError> {
+    if data.starts_with(png::SIGNATURE) {
+        png::decode(data, alloc)
+    } else if data.starts_with(qoi::MAGIC) {
+        qoi::decode(data, alloc)
+    } else if data.starts_with(bmp::MAGIC) {
+        bmp::decode(data, alloc)
+    } else {
+        Err(DecodeError::UnknownFormat)
+    }
+}
@@ -0,0 +1,558 @@
+//! A PNG decoder supporting every color type, bit depth and interlacing method.
+
+use std::alloc::Allocator;
+
+use super::{Bitmap, DecodeError, inflate};
+
+pub const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// The first pixel and the spacing between pixels of each Adam7 pass.
+const ADAM7: [(usize, usize, usize, usize); 7] = [
+    (0, 0, 8, 8),
+    (4, 0, 8, 8),
+    (0, 4, 4, 8),
+    (2, 0, 4, 4),
+    (0, 2, 2, 4),
+    (1, 0, 2, 2),
+    (0, 1, 1, 2),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorType {
+    Gray,
+    Rgb,
+    Indexed,
+    GrayAlpha,
+    Rgba,
+}
+
+impl ColorType {
+    fn channels(self) -> usize {
+        match self {
+            ColorType::Gray | ColorType::Indexed => 1,
+            ColorType::GrayAlpha => 2,
+            ColorType::Rgb => 3,
+            ColorType::Rgba => 4,
+        }
+    }
+}
+
+struct Header {
+    width: usize,
+    height: usize,
+    depth: u8,
+    color_type: ColorType,
+    interlaced: bool,
+}
+
+impl Header {
+    fn parse(data: &[u8]) -> Result<Self, DecodeError> {
+        let data: &[u8; 13] = data
+            .try_into()
+            .map_err(|_| DecodeError::Corrupt("the header has the wrong length"))?;
+
+        let width = u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize;
+        let height = u32::from_be_bytes([data[4], data[5], data[6], data[7]]) as usize;
+        let depth = data[8];
+        let color_type = match (data[9], depth) {
+            (0, 1 | 2 | 4 | 8 | 16) => ColorType::Gray,
+            (2, 8 | 16) => ColorType::Rgb,
+            (3, 1 | 2 | 4 | 8) => ColorType::Indexed,
+            (4, 8 | 16) => ColorType::GrayAlpha,
+            (6, 8 | 16) => ColorType::Rgba,
+            _ => {
+                return Err(DecodeError::Corrupt(
+                    "the color type or bit depth is invalid",
+                ));
+            }
+        };
+        if data[10] != 0 || data[11] != 0 {
+            return Err(DecodeError::Unsupported("PNG compression or filter method"));
+        }
+        let interlaced = match data[12] {
+            0 => false,
+            1 => true,
+            _ => return Err(DecodeError::Unsupported("PNG interlace method")),
+        };
+
+        Ok(Self {
+            width,
+            height,
+            depth,
+            color_type,
+            interlaced,
+        })
+    }
+
+    fn bits_per_pixel(&self) -> usize {
+        self.color_type.channels() * self.depth as usize
+    }
+
+    /// Returns the first pixel, spacing and size in pixels of each pass over the image.
+    fn passes(&self) -> impl Iterator<Item = ([usize; 2], [usize; 2], [usize; 2])> {
+        let passes: &[_] = if self.interlaced {
+            &ADAM7
+        } else {
+            &[(0, 0, 1, 1)]
+        };
+        let (width, height) = (self.width, self.height);
+        passes.iter().map(move |&(x, y, dx, dy)| {
+            let size = [(width + dx - 1 - x) / dx, (height + dy - 1 - y) / dy];
+            ([x, y], [dx, dy], size)
+        })
+    }
+
+    fn row_bytes(&self, width: usize) -> usize {
+        (width * self.bits_per_pixel()).div_ceil(8)
+    }
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let (pa, pb, pc) = (
+        (p - a as i16).abs(),
+        (p - b as i16).abs(),
+        (p - c as i16).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// Reverses the filter on `row`, given the unfiltered row above it.
+fn unfilter(filter: u8, row: &mut [u8], above: &[u8], stride: usize) -> Result<(), DecodeError> {
+    match filter {
+        0 => {}
+        1 => {
+            for i in stride..row.len() {
+                row[i] = row[i].wrapping_add(row[i - stride]);
+            }
+        }
+        2 => {
+            for (byte, above) in row.iter_mut().zip(above) {
+                *byte = byte.wrapping_add(*above);
+            }
+        }
+        3 => {
+            for i in 0..row.len() {
+                let left = if i >= stride { row[i - stride] } else { 0 };
+                row[i] = row[i].wrapping_add(((left as u16 + above[i] as u16) / 2) as u8);
+            }
+        }
+        4 => {
+            for i in 0..row.len() {
+                let (left, corner) = if i >= stride {
+                    (row[i - stride], above[i - stride])
+                } else {
+                    (0, 0)
+                };
+                row[i] = row[i].wrapping_add(paeth(left, above[i], corner));
+            }
+        }
+        _ => return Err(DecodeError::Corrupt("a row has an invalid filter")),
+    }
+    Ok(())
+}
+
+/// Reads the `index`th sample of `depth` bits from `row`, keeping all 16 bits of 16-bit samples.
+fn sample(row: &[u8], index: usize, depth: u8) -> u16 {
+    match depth {
+        16 => u16::from_be_bytes([row[index * 2], row[index * 2 + 1]]),
+        8 => row[index] as u16,
+        _ => {
+            let bit = index * depth as usize;
+            let shift = 8 - depth as usize - bit % 8;
+            ((row[bit / 8] >> shift) & ((1 << depth) - 1)) as u16
+        }
+    }
+}
+
+/// Scales a sample of `depth` bits to 8 bits.
+fn to_8_bits(sample: u16, depth: u8) -> u32 {
+    match depth {
+        16 => (sample >> 8) as u32,
+        _ => sample as u32 * 255 / ((1 << depth) - 1),
+    }
+}
+
+/// The palette and transparency of an image, from its PLTE and tRNS chunks.
+struct Colors<'a> {
+    palette: &'a [u8],
+    transparency: Option<&'a [u8]>,
+}
+
+impl Colors<'_> {
+    /// Converts the pixel at `index` in an unfiltered row to `0xAARRGGBB`.
+    fn pixel(&self, header: &Header, row: &[u8], index: usize) -> Result<u32, DecodeError> {
+        let depth = header.depth;
+        let channels = header.color_type.channels();
+        let samples: [u16; 4] = std::array::from_fn(|channel| {
+            if channel < channels {
+                sample(row, index * channels + channel, depth)
+            } else {
+                0
+            }
+        });
+
+        // tRNS gives a single transparent gray level or color, in raw samples
+        let keyed = |count: usize| {
+            self.transparency.is_some_and(|key| {
+                key.len() >= count * 2
+                    && (0..count)
+                        .all(|i| u16::from_be_bytes([key[i * 2], key[i * 2 + 1]]) == samples[i])
+            })
+        };
+
+        let [r, g, b, a] = match header.color_type {
+            ColorType::Gray => {
+                let gray = to_8_bits(samples[0], depth);
+                [gray, gray, gray, if keyed(1) { 0 } else { 255 }]
+            }
+            ColorType::GrayAlpha => {
+                let gray = to_8_bits(samples[0], depth);
+                [gray, gray, gray, to_8_bits(samples[1], depth)]
+            }
+            ColorType::Rgb => [
+                to_8_bits(samples[0], depth),
+                to_8_bits(samples[1], depth),
+                to_8_bits(samples[2], depth),
+                if keyed(3) { 0 } else { 255 },
+            ],
+            ColorType::Rgba => samples.map(|sample| to_8_bits(sample, depth)),
+            ColorType::Indexed => {
+                let index = samples[0] as usize;
+                let color = self
+                    .palette
+                    .get(index * 3..index * 3 + 3)
+                    .ok_or(DecodeError::Corrupt("a pixel is outside the palette"))?;
+                let alpha = self
+                    .transparency
+                    .and_then(|alphas| alphas.get(index).copied())
+                    .unwrap_or(255);
+                [
+                    color[0] as u32,
+                    color[1] as u32,
+                    color[2] as u32,
+                    alpha as u32,
+                ]
+            }
+        };
+        Ok(a << 24 | r << 16 | g << 8 | b)
+    }
+}
+
+/// Decodes a PNG image, which must start with [`SIGNATURE`].
+///
+/// The image and the buffers used while decoding it are allocated from `alloc`.
+pub fn decode<A: Allocator + Clone>(data: &[u8], alloc: A) -> Result<Bitmap<A>, DecodeError> {
+    let mut rest = data
+        .strip_prefix(SIGNATURE)
+        .ok_or(DecodeError::UnknownFormat)?;
+
+    let mut header = None;
+    let mut colors = Colors {
+        palette: &[],
+        transparency: None,
+    };
+    let mut compressed = Vec::new_in(alloc.clone());
+    loop {
+        let (length, after) = rest
+            .split_first_chunk::<4>()
+            .ok_or(DecodeError::Truncated)?;
+        let length = u32::from_be_bytes(*length) as usize;
+        let (kind, after) = after
+            .split_first_chunk::<4>()
+            .ok_or(DecodeError::Truncated)?;
+        // the chunk's data, followed by its CRC
+        let chunk = after.get(..length).ok_or(DecodeError::Truncated)?;
+        rest = after.get(length + 4..).ok_or(DecodeError::Truncated)?;
+
+        match kind {
+            b"IHDR" => header = Some(Header::parse(chunk)?),
+            b"PLTE" => colors.palette = chunk,
+            b"tRNS" => colors.transparency = Some(chunk),
+            b"IDAT" => {
+                compressed
+                    .try_reserve(chunk.len())
+                    .map_err(|_| DecodeError::OutOfMemory)?;
+                compressed.extend_from_slice(chunk);
+            }
+            b"IEND" => break,
+            // Chunks with an uppercase first letter can't be skipped
+            _ if kind[0].is_ascii_uppercase() => {
+                return Err(DecodeError::Unsupported("PNG critical chunk"));
+            }
+            _ => {}
+        }
+    }
+
+    let header = header.ok_or(DecodeError::Corrupt("the image has no header"))?;
+    if header.color_type == ColorType::Indexed && colors.palette.is_empty() {
+        return Err(DecodeError::Corrupt("the image has no palette"));
+    }
+
+    let mut bitmap = Bitmap::new(header.width, header.height, alloc.clone())?;
+
+    // Each row of each pass starts with a byte giving its filter
+    let expected = header
+        .passes()
+        .filter(|(_, _, [width, height])| *width > 0 && *height > 0)
+        .map(|(_, _, [width, height])| height * (1 + header.row_bytes(width)))
+        .sum();
+    let mut raw = inflate::decompress(&compressed, expected, alloc.clone())?;
+    drop(compressed);
+    if raw.len() != expected {
+        return Err(DecodeError::Truncated);
+    }
+
+    let stride = header.bits_per_pixel().div_ceil(8);
+    let mut offset = 0;
+    // No pass has wider rows than the image
+    let mut above = Vec::new_in(alloc);
+    above
+        .try_reserve_exact(header.row_bytes(header.width))
+        .map_err(|_| DecodeError::OutOfMemory)?;
+    for ([x0, y0], [dx, dy], [width, height]) in header.passes() {
+        if width == 0 || height == 0 {
+            continue;
+        }
+
+        let row_bytes = header.row_bytes(width);
+        above.clear();
+        above.resize(row_bytes, 0);
+        for row_index in 0..height {
+            let filter = raw[offset];
+            let row = &mut raw[offset + 1..offset + 1 + row_bytes];
+            unfilter(filter, row, &above, stride)?;
+            above.copy_from_slice(row);
+
+            let y = y0 + row_index * dy;
+            for column in 0..width {
+                let x = x0 + column * dx;
+                bitmap.pixels[y * header.width + x] = colors.pixel(&header, &above, column)?;
+            }
+            offset += 1 + row_bytes;
+        }
+    }
+
+    Ok(bitmap)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::alloc::Global;
+
+    use super::{
+        super::{Bitmap, DecodeError, inflate::adler32},
+        ADAM7, SIGNATURE, decode, paeth,
+    };
+
+    const RGB: u8 = 2;
+    const INDEXED: u8 = 3;
+    const GRAY: u8 = 0;
+
+    fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+        png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        png.extend_from_slice(kind);
+        png.extend_from_slice(data);
+        // the decoder doesn't check CRCs
+        png.extend_from_slice(&[0; 4]);
+    }
+
+    /// Builds a PNG from its unfiltered image data, which is stored uncompressed.
+    fn png(
+        [width, height]: [u32; 2],
+        depth: u8,
+        color_type: u8,
+        interlaced: bool,
+        chunks: &[(&[u8; 4], &[u8])],
+        raw: &[u8],
+    ) -> Vec<u8> {
+        let mut header = Vec::new();
+        header.extend_from_slice(&width.to_be_bytes());
+        header.extend_from_slice(&height.to_be_bytes());
+        header.extend_from_slice(&[depth, color_type, 0, 0, interlaced as u8]);
+
+        let length = raw.len() as u16;
+        let mut compressed = vec![0x78, 0x01, 0x01];
+        compressed.extend_from_slice(&length.to_le_bytes());
+        compressed.extend_from_slice(&(!length).to_le_bytes());
+        compressed.extend_from_slice(raw);
+        compressed.extend_from_slice(&adler32(raw).to_be_bytes());
+
+        let mut png = SIGNATURE.to_vec();
+        chunk(&mut png, b"IHDR", &header);
+        for (kind, data) in chunks {
+            chunk(&mut png, kind, data);
+        }
+        // split the data to check that IDAT chunks are joined
+        let (first, second) = compressed.split_at(compressed.len() / 2);
+        chunk(&mut png, b"IDAT", first);
+        chunk(&mut png, b"IDAT", second);
+        chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    fn pixels(bitmap: &Bitmap<Global>) -> Vec<u32> {
+        (0..bitmap.height())
+            .flat_map(|y| (0..bitmap.width()).map(move |x| bitmap.pixel(x, y)))
+            .collect()
+    }
+
+    /// Applies the PNG filter `kind` to `row`, the inverse of what the decoder undoes.
+    fn filter(kind: u8, row: &[u8], above: &[u8], stride: usize) -> Vec<u8> {
+        let mut filtered = vec![kind];
+        for i in 0..row.len() {
+            let left = if i >= stride { row[i - stride] } else { 0 };
+            let corner = if i >= stride { above[i - stride] } else { 0 };
+            let prediction = match kind {
+                0 => 0,
+                1 => left,
+                2 => above[i],
+                3 => ((left as u16 + above[i] as u16) / 2) as u8,
+                _ => paeth(left, above[i], corner),
+            };
+            filtered.push(row[i].wrapping_sub(prediction));
+        }
+        filtered
+    }
+
+    #[test]
+    fn every_filter_type_is_reversed() {
+        let (width, height) = (3, 5);
+        let color = |x: usize, y: usize| [(x * 90) as u8, (y * 60) as u8, (x * y * 37) as u8];
+
+        let mut raw = Vec::new();
+        let mut above = vec![0; width * 3];
+        for y in 0..height {
+            let row: Vec<u8> = (0..width).flat_map(|x| color(x, y)).collect();
+            // each row uses a different filter, from none to Paeth
+            raw.extend(filter(y as u8, &row, &above, 3));
+            above = row;
+        }
+
+        let bitmap = decode(&png([3, 5], 8, RGB, false, &[], &raw), Global).unwrap();
+        for y in 0..height {
+            for x in 0..width {
+                let [r, g, b] = color(x, y).map(u32::from);
+                assert_eq!(bitmap.pixel(x, y), 0xff00_0000 | r << 16 | g << 8 | b);
+            }
+        }
+    }
+
+    #[test]
+    fn adam7_passes_are_interlaced() {
+        // 9 pixels square, so that every pass has pixels and the last column and row are partial
+        let size = 9;
+        let gray = |x: usize, y: usize| (y * size + x) as u8;
+
+        let mut raw = Vec::new();
+        for (x0, y0, dx, dy) in ADAM7 {
+            for y in (y0..size).step_by(dy) {
+                raw.push(0);
+                raw.extend((x0..size).step_by(dx).map(|x| gray(x, y)));
+            }
+        }
+
+        let bitmap = decode(&png([9, 9], 8, GRAY, true, &[], &raw), Global).unwrap();
+        for y in 0..size {
+            for x in 0..size {
+                let gray = gray(x, y) as u32;
+                assert_eq!(bitmap.pixel(x, y), 0xff00_0000 | (gray * 0x01_0101));
+            }
+        }
+    }
+
+    #[test]
+    fn palette_with_transparency() {
+        let palette = [255, 0, 0, 0, 255, 0, 0, 0, 255, 255, 255, 255];
+        // only the first two colors have an alpha, so the others are opaque
+        let alphas = [0, 128];
+        // four 2-bit indices in one byte
+        let raw = [0, 0b00_01_10_11];
+
+        let image = png(
+            [4, 1],
+            2,
+            INDEXED,
+            false,
+            &[(b"PLTE", &palette), (b"tRNS", &alphas)],
+            &raw,
+        );
+        assert_eq!(
+            pixels(&decode(&image, Global).unwrap()),
+            [0x00ff_0000, 0x8000_ff00, 0xff00_00ff, 0xffff_ffff]
+        );
+    }
+
+    #[test]
+    fn samples_are_scaled_to_8_bits() {
+        // 16-bit samples keep their high byte, and tRNS gives a transparent color
+        let raw = [0, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0, 1, 0, 2, 0, 3];
+        let key = [0, 1, 0, 2, 0, 3];
+        let image = png([2, 1], 16, RGB, false, &[(b"tRNS", &key)], &raw);
+        assert_eq!(
+            pixels(&decode(&image, Global).unwrap()),
+            [0xff12_569a, 0x0000_0000]
+        );
+
+        // 1-bit gray is black or white
+        let image = png([3, 1], 1, GRAY, false, &[], &[0, 0b1010_0000]);
+        assert_eq!(
+            pixels(&decode(&image, Global).unwrap()),
+            [0xffff_ffff, 0xff00_0000, 0xffff_ffff]
+        );
+    }
+
+    #[test]
+    fn invalid_images_are_rejected() {
+        let decode = |image: &[u8]| decode(image, Global).map(|_| ());
+
+        let image = png([1, 1], 8, GRAY, false, &[], &[5, 0]);
+        assert_eq!(
+            decode(&image),
+            Err(DecodeError::Corrupt("a row has an invalid filter"))
+        );
+
+        let image = png([1, 1], 8, GRAY, false, &[], &[0]);
+        assert_eq!(decode(&image), Err(DecodeError::Truncated));
+
+        let image = png([100_000, 100_000], 8, GRAY, false, &[], &[0]);
+        assert_eq!(decode(&image), Err(DecodeError::TooLarge));
+
+        let image = png([1, 1], 3, RGB, false, &[], &[0, 0]);
+        assert_eq!(
+            decode(&image),
+            Err(DecodeError::Corrupt(
+                "the color type or bit depth is invalid"
+            ))
+        );
+
+        let image = png([2, 1], 8, INDEXED, false, &[(b"PLTE", &[0; 3])], &[0, 0, 1]);
+        assert_eq!(
+            decode(&image),
+            Err(DecodeError::Corrupt("a pixel is outside the palette"))
+        );
+    }
+
+    #[test]
+    fn damaged_images_fail_without_panicking() {
+        let raw = [0, 1, 2, 3, 4, 5, 6, 2, 1, 2, 3, 4, 5, 6];
+        let image = png([2, 2], 8, RGB, false, &[], &raw);
+        for length in 0..image.len() {
+            assert!(decode(&image[..length], Global).is_err());
+        }
+
+        for index in 0..image.len() {
+            for bit in 0..8 {
+                let mut damaged = image.clone();
+                damaged[index] ^= 1 << bit;
+                let _ = decode(&damaged, Global);
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! A decoder for the Quite OK Image format.
+
+use std::alloc::Allocator;
+
+use super::{Bitmap, DecodeError};
+
+pub const MAGIC: &[u8] = b"qoif";
+
+const OP_RGB: u8 = 0xfe;
+const OP_RGBA: u8 = 0xff;
+const OP_INDEX: u8 = 0x00;
+const OP_DIFF: u8 = 0x40;
+const OP_LUMA: u8 = 0x80;
+const TAG_MASK: u8 = 0xc0;
+
+fn hash([r, g, b, a]: [u8; 4]) -> usize {
+    (r as usize * 3 + g as usize * 5 + b as usize * 7 + a as usize * 11) % 64
+}
+
+/// Decodes a QOI image, which must start with [`MAGIC`].
+pub fn decode<A: Allocator>(data: &[u8], alloc: A) -> Result<Bitmap<A>, DecodeError> {
+    let header = data
+        .strip_prefix(MAGIC)
+        .ok_or(DecodeError::UnknownFormat)?
+        .first_chunk::<10>()
+        .ok_or(DecodeError::Truncated)?;
+    let width = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    let height = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+    if !matches!(header[8], 3 | 4) {
+        return Err(DecodeError::Corrupt("the channel count is invalid"));
+    }
+
+    let mut bitmap = Bitmap::new(width, height, alloc)?;
+
+    let mut bytes = data[MAGIC.len() + header.len()..].iter().copied();
+    let mut next = || bytes.next().ok_or(DecodeError::Truncated);
+
+    let mut seen = [[0u8; 4]; 64];
+    let mut pixel = [0, 0, 0, 255];
+    let mut run = 0;
+    for out in bitmap.pixels.iter_mut() {
+        if run > 0 {
+            run -= 1;
+        } else {
+            let op = next()?;
+            match op {
+                OP_RGB => pixel = [next()?, next()?, next()?, pixel[3]],
+                OP_RGBA => pixel = [next()?, next()?, next()?, next()?],
+                _ => match op & TAG_MASK {
+                    OP_INDEX => pixel = seen[op as usize],
+                    OP_DIFF => {
+                        pixel[0] = pixel[0].wrapping_add((op >> 4) & 0x03).wrapping_sub(2);
+                        pixel[1] = pixel[1].wrapping_add((op >> 2) & 0x03).wrapping_sub(2);
+                        pixel[2] = pixel[2].wrapping_add(op & 0x03).wrapping_sub(2);
+                    }
+                    OP_LUMA => {
+                        let green = (op & 0x3f).wrapping_sub(32);
+                        let byte = next()?;
+                        pixel[0] = pixel[0]
+                            .wrapping_add(green)
+                            .wrapping_add(byte >> 4)
+                            .wrapping_sub(8);
+                        pixel[1] = pixel[1].wrapping_add(green);
+                        pixel[2] = pixel[2]
+                            .wrapping_add(green)
+                            .wrapping_add(byte & 0x0f)
+                            .wrapping_sub(8);
+                    }
+                    // the only tag left is a run of the previous pixel
+                    _ => run = op & 0x3f,
+                },
+            }
+            seen[hash(pixel)] = pixel;
+        }
+
+        let [r, g, b, a] = pixel.map(u32::from);
+        *out = a << 24 | r << 16 | g << 8 | b;
+    }
+
+    Ok(bitmap)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::alloc::Global;
+
+    use super::{super::DecodeError, MAGIC, decode};
+
+    fn qoi([width, height]: [u32; 2], channels: u8, ops: &[u8]) -> Vec<u8> {
+        let mut image = MAGIC.to_vec();
+        image.extend_from_slice(&width.to_be_bytes());
+        image.extend_from_slice(&height.to_be_bytes());
+        image.extend_from_slice(&[channels, 0]);
+        image.extend_from_slice(ops);
+        image.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1]);
+        image
+    }
+
+    /// An RGB op for (10, 20, 30), a DIFF by (1, -1, 0), a LUMA by (3, 5, 7), an RGBA op for
+    /// (1, 2, 3, 4), an INDEX back to (10, 20, 30, 255) and a RUN of three more of it.
+    const OPS: &[u8] = &[
+        0xfe, 10, 20, 30, 0x76, 0xa5, 0x6a, 0xff, 1, 2, 3, 4, 0x09, 0xc2,
+    ];
+
+    #[test]
+    fn every_op_is_decoded() {
+        let bitmap = decode(&qoi([4, 2], 4, OPS), Global).unwrap();
+        let pixels: Vec<u32> = (0..2)
+            .flat_map(|y| (0..4).map(move |x| (x, y)))
+            .map(|(x, y)| bitmap.pixel(x, y))
+            .collect();
+        assert_eq!(
+            pixels,
+            [
+                0xff0a_141e,
+                0xff0b_131e,
+                0xff0e_1825,
+                0x0401_0203,
+                0xff0a_141e,
+                0xff0a_141e,
+                0xff0a_141e,
+                0xff0a_141e,
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_images_are_rejected() {
+        assert_eq!(
+            decode(&qoi([4, 2], 5, OPS), Global).map(|_| ()),
+            Err(DecodeError::Corrupt("the channel count is invalid"))
+        );
+        assert_eq!(
+            decode(&qoi([0, 2], 4, OPS), Global).map(|_| ()),
+            Err(DecodeError::TooLarge)
+        );
+
+        let image = qoi([4, 2], 4, OPS);
+        let ops_end = image.len() - 8;
+        for length in 0..ops_end {
+            assert!(decode(&image[..length], Global).is_err());
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! The parts of Venice that don't depend on MicroPython or the VEX SDK.
+//!
+//! The workspace builds for the V5 brain by default, so this crate's tests are run on the host with
+//! `cargo test -p venice-core --target x86_64-unknown-linux-gnu`, or the host's own target.
+
+#![feature(allocator_api)]
+
+pub mod image;
+pub mod pid;
+pub mod pose;
+pub mod profile;
+pub mod raster;
+pub mod trajectory;
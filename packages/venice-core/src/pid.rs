@@ -0,0 +1,178 @@
+/// A PID controller with feedforward.
+///
+/// Setpoints, measurements and outputs are unitless; `dt` is in seconds, so `ki` is per second and
+/// `kd` is in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pid {
+    pub kp: f32,
+    pub ki: f32,
+    pub kd: f32,
+    /// Static feedforward, applied in the direction of the setpoint velocity.
+    pub ks: f32,
+    /// Velocity feedforward.
+    pub kv: f32,
+    /// Acceleration feedforward.
+    pub ka: f32,
+    /// The largest magnitude the integral term may accumulate to.
+    pub integral_limit: Option<f32>,
+    /// The largest magnitude of the controller output.
+    pub output_limit: Option<f32>,
+    /// The time constant of the derivative low-pass filter in seconds. Zero disables filtering.
+    pub derivative_filter: f32,
+    /// The fraction of the setpoint that the proportional term acts on.
+    pub setpoint_weight: f32,
+    /// The fraction of the setpoint that the derivative term acts on.
+    pub derivative_setpoint_weight: f32,
+    /// The span after which the input wraps around, such as 360 for an angle in degrees.
+    pub period: Option<f32>,
+    integral: f32,
+    derivative: f32,
+    // The setpoint and measurement of the previous update
+    previous: Option<(f32, f32)>,
+}
+
+impl Pid {
+    pub const fn new(kp: f32, ki: f32, kd: f32) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            ks: 0.0,
+            kv: 0.0,
+            ka: 0.0,
+            integral_limit: None,
+            output_limit: None,
+            derivative_filter: 0.0,
+            setpoint_weight: 1.0,
+            derivative_setpoint_weight: 1.0,
+            period: None,
+            integral: 0.0,
+            derivative: 0.0,
+            previous: None,
+        }
+    }
+
+    /// Clears the accumulated integral and derivative history.
+    pub fn reset(&mut self) {
+        self.integral = 0.0;
+        self.derivative = 0.0;
+        self.previous = None;
+    }
+
+    /// Wraps a difference between two inputs into the half period either side of zero.
+    fn wrap(&self, difference: f32) -> f32 {
+        match self.period {
+            Some(period) => difference - period * (difference / period).round(),
+            None => difference,
+        }
+    }
+
+    /// Computes the next output, `dt` seconds after the previous update.
+    ///
+    /// `velocity` and `acceleration` are the rates of change of the setpoint, used for
+    /// feedforward. `dt` must be positive.
+    pub fn update(
+        &mut self,
+        setpoint: f32,
+        measurement: f32,
+        dt: f32,
+        velocity: f32,
+        acceleration: f32,
+    ) -> f32 {
+        let error = self.wrap(setpoint - measurement);
+
+        // `setpoint_weight * setpoint - measurement`, where the measurement is the equivalent input
+        // nearest to the setpoint
+        let proportional = self.kp * (error - (1.0 - self.setpoint_weight) * setpoint);
+
+        let previous_integral = self.integral;
+        self.integral += self.ki * error * dt;
+        if let Some(limit) = self.integral_limit {
+            self.integral = self.integral.clamp(-limit, limit);
+        }
+
+        // The first update has nothing to differentiate against, which also avoids a derivative
+        // kick when the controller starts
+        if let Some((previous_setpoint, previous_measurement)) = self.previous {
+            let change = self.derivative_setpoint_weight * self.wrap(setpoint - previous_setpoint)
+                - self.wrap(measurement - previous_measurement);
+            let alpha = dt / (self.derivative_filter + dt);
+            self.derivative += alpha * (change / dt - self.derivative);
+        }
+        self.previous = Some((setpoint, measurement));
+
+        let static_friction = if velocity == 0.0 {
+            0.0
+        } else {
+            self.ks * velocity.signum()
+        };
+        let feedforward = static_friction + self.kv * velocity + self.ka * acceleration;
+
+        let mut output = proportional + self.integral + self.kd * self.derivative + feedforward;
+
+        let Some(limit) = self.output_limit else {
+            return output;
+        };
+
+        // Integrating further into saturation only builds up windup that has to be unwound later
+        if (output > limit && self.integral > previous_integral)
+            || (output < -limit && self.integral < previous_integral)
+        {
+            output -= self.integral - previous_integral;
+            self.integral = previous_integral;
+        }
+
+        output.clamp(-limit, limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pid;
+
+    #[test]
+    fn integral_is_clamped_to_its_limit() {
+        let mut pid = Pid::new(0.0, 1.0, 0.0);
+        pid.integral_limit = Some(0.5);
+
+        for _ in 0..10 {
+            assert!(pid.update(10.0, 0.0, 1.0, 0.0, 0.0) <= 0.5);
+        }
+        assert_eq!(pid.update(10.0, 0.0, 1.0, 0.0, 0.0), 0.5);
+        assert_eq!(pid.update(-10.0, 0.0, 0.1, 0.0, 0.0), -0.5);
+    }
+
+    #[test]
+    fn first_update_has_no_derivative() {
+        let mut pid = Pid::new(0.0, 0.0, 1.0);
+
+        assert_eq!(pid.update(10.0, 0.0, 0.01, 0.0, 0.0), 0.0);
+        // The measurement rises by 1 over 10 ms with the setpoint unchanged
+        let output = pid.update(10.0, 1.0, 0.01, 0.0, 0.0);
+        assert!((output + 100.0).abs() < 1e-3, "{output}");
+
+        pid.reset();
+        assert_eq!(pid.update(20.0, 5.0, 0.01, 0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn output_is_limited() {
+        let mut pid = Pid::new(10.0, 0.0, 0.0);
+        pid.output_limit = Some(1.0);
+
+        assert_eq!(pid.update(10.0, 0.0, 0.01, 0.0, 0.0), 1.0);
+        assert_eq!(pid.update(-10.0, 0.0, 0.01, 0.0, 0.0), -1.0);
+        assert_eq!(pid.update(0.05, 0.0, 0.01, 0.0, 0.0), 0.5);
+    }
+
+    #[test]
+    fn integral_does_not_wind_up_while_saturated() {
+        let mut pid = Pid::new(1.0, 1.0, 0.0);
+        pid.output_limit = Some(1.0);
+
+        for _ in 0..10 {
+            assert_eq!(pid.update(10.0, 0.0, 1.0, 0.0, 0.0), 1.0);
+        }
+        assert_eq!(pid.update(0.0, 0.0, 1.0, 0.0, 0.0), 0.0);
+    }
+}
@@ -0,0 +1,66 @@
+use std::f32::consts::{PI, TAU};
+
+/// Wraps `angle`, in radians, into the range [-π, π).
+pub fn wrap_angle(angle: f32) -> f32 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+/// A position and heading in the plane.
+///
+/// `theta` is in radians, counterclockwise from the positive x axis, and is not wrapped. A pose
+/// doubles as a rigid transform that moves by (`x`, `y`) and then turns by `theta`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Pose {
+    pub x: f32,
+    pub y: f32,
+    pub theta: f32,
+}
+
+impl Pose {
+    pub const fn new(x: f32, y: f32, theta: f32) -> Self {
+        Self { x, y, theta }
+    }
+
+    /// Applies `transform` in the frame of this pose.
+    pub fn compose(self, transform: Pose) -> Pose {
+        let (sin, cos) = self.theta.sin_cos();
+        Pose {
+            x: self.x + transform.x * cos - transform.y * sin,
+            y: self.y + transform.x * sin + transform.y * cos,
+            theta: self.theta + transform.theta,
+        }
+    }
+
+    /// Returns the transform that undoes this one.
+    pub fn inverse(self) -> Pose {
+        let (sin, cos) = self.theta.sin_cos();
+        Pose {
+            x: -self.x * cos - self.y * sin,
+            y: self.x * sin - self.y * cos,
+            theta: -self.theta,
+        }
+    }
+
+    /// Returns this pose expressed in the frame of `origin`.
+    pub fn relative_to(self, origin: Pose) -> Pose {
+        origin.inverse().compose(self)
+    }
+
+    /// Blends from this pose towards `other` by `t`, turning the short way around.
+    pub fn interpolate(self, other: Pose, t: f32) -> Pose {
+        Pose {
+            x: self.x + (other.x - self.x) * t,
+            y: self.y + (other.y - self.y) * t,
+            theta: self.theta + wrap_angle(other.theta - self.theta) * t,
+        }
+    }
+
+    pub fn distance_to(self, x: f32, y: f32) -> f32 {
+        (x - self.x).hypot(y - self.y)
+    }
+
+    /// Returns how far this pose must turn to face (`x`, `y`), wrapped into [-π, π).
+    pub fn angle_to(self, x: f32, y: f32) -> f32 {
+        wrap_angle((y - self.y).atan2(x - self.x) - self.theta)
+    }
+}
@@ -0,0 +1,290 @@
+/// The position, velocity and acceleration of a [`MotionProfile`] at one point in time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProfileSample {
+    pub position: f32,
+    pub velocity: f32,
+    pub acceleration: f32,
+}
+
+/// A symmetric motion profile over a fixed distance.
+///
+/// The profile accelerates from rest to its peak velocity, cruises, then decelerates to rest at
+/// the end of the distance. With a finite jerk limit, the acceleration itself ramps up and down,
+/// giving an S-curve; with an infinite one, the acceleration changes instantly, giving a
+/// trapezoid. Distances are unitless, and times are in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionProfile {
+    distance: f32,
+    peak_velocity: f32,
+    peak_acceleration: f32,
+    // Time spent ramping the acceleration up or down; zero for a trapezoid
+    jerk_time: f32,
+    // Time spent accelerating, which is also the time spent decelerating
+    acceleration_time: f32,
+    cruise_time: f32,
+}
+
+/// Returns the peak acceleration, the time taken to ramp to it, and the total acceleration time
+/// needed to reach `velocity` from rest.
+fn acceleration_phase(velocity: f32, max_acceleration: f32, max_jerk: f32) -> (f32, f32, f32) {
+    // Without reaching the acceleration limit, the acceleration is a triangle of area `velocity`
+    let peak_acceleration = max_acceleration.min((velocity * max_jerk).sqrt());
+    let jerk_time = peak_acceleration / max_jerk;
+    let acceleration_time = if peak_acceleration > 0.0 {
+        velocity / peak_acceleration + jerk_time
+    } else {
+        0.0
+    };
+    (peak_acceleration, jerk_time, acceleration_time)
+}
+
+impl MotionProfile {
+    /// Plans a profile over `distance`, which may be negative.
+    ///
+    /// Returns `None` if any limit isn't positive, or if `distance` isn't finite. `max_jerk` may be
+    /// infinite.
+    pub fn new(
+        distance: f32,
+        max_velocity: f32,
+        max_acceleration: f32,
+        max_jerk: f32,
+    ) -> Option<Self> {
+        let positive = |limit: f32| limit > 0.0 && limit.is_finite();
+        if !distance.is_finite()
+            || !positive(max_velocity)
+            || !positive(max_acceleration)
+            || max_jerk.is_nan()
+            || max_jerk <= 0.0
+        {
+            return None;
+        }
+
+        // An acceleration phase is symmetric about its midpoint, so it covers the distance of its
+        // average velocity over its duration
+        let acceleration_distance = |velocity: f32| {
+            velocity * acceleration_phase(velocity, max_acceleration, max_jerk).2 / 2.0
+        };
+
+        let length = distance.abs();
+        let peak_velocity = if 2.0 * acceleration_distance(max_velocity) <= length {
+            max_velocity
+        } else {
+            // Too short to reach `max_velocity`, so find the velocity whose acceleration and
+            // deceleration cover exactly the distance
+            let (mut low, mut high) = (0.0, max_velocity);
+            for _ in 0..48 {
+                let mid = (low + high) / 2.0;
+                if 2.0 * acceleration_distance(mid) <= length {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            low
+        };
+
+        let (peak_acceleration, jerk_time, acceleration_time) =
+            acceleration_phase(peak_velocity, max_acceleration, max_jerk);
+        let cruise_time = if peak_velocity > 0.0 {
+            ((length - 2.0 * acceleration_distance(peak_velocity)) / peak_velocity).max(0.0)
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            distance,
+            peak_velocity,
+            peak_acceleration,
+            jerk_time,
+            acceleration_time,
+            cruise_time,
+        })
+    }
+
+    /// Returns the distance covered by the profile.
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    /// Returns the time taken to complete the profile, in seconds.
+    pub fn duration(&self) -> f32 {
+        2.0 * self.acceleration_time + self.cruise_time
+    }
+
+    /// Samples the acceleration phase, `t` seconds after starting from rest, along a positive
+    /// distance.
+    fn sample_acceleration(&self, t: f32) -> ProfileSample {
+        let acceleration = self.peak_acceleration;
+        let ramp = self.jerk_time;
+        let hold = self.acceleration_time - 2.0 * ramp;
+        let jerk = if ramp > 0.0 { acceleration / ramp } else { 0.0 };
+
+        // Ramping the acceleration up
+        if t < ramp {
+            return ProfileSample {
+                position: jerk * t * t * t / 6.0,
+                velocity: jerk * t * t / 2.0,
+                acceleration: jerk * t,
+            };
+        }
+
+        // Holding the peak acceleration
+        let ramp_velocity = acceleration * ramp / 2.0;
+        let ramp_position = acceleration * ramp * ramp / 6.0;
+        let t = t - ramp;
+        if t < hold || ramp == 0.0 {
+            let t = t.min(hold);
+            return ProfileSample {
+                position: ramp_position + ramp_velocity * t + acceleration * t * t / 2.0,
+                velocity: ramp_velocity + acceleration * t,
+                acceleration,
+            };
+        }
+
+        // Ramping the acceleration back down
+        let hold_velocity = ramp_velocity + acceleration * hold;
+        let hold_position = ramp_position + ramp_velocity * hold + acceleration * hold * hold / 2.0;
+        let t = (t - hold).min(ramp);
+        ProfileSample {
+            position: hold_position + hold_velocity * t + acceleration * t * t / 2.0
+                - jerk * t * t * t / 6.0,
+            velocity: hold_velocity + acceleration * t - jerk * t * t / 2.0,
+            acceleration: acceleration - jerk * t,
+        }
+    }
+
+    /// Returns the state of the profile `t` seconds after it starts.
+    ///
+    /// Times before the start and after the end are clamped, so the profile is at rest at its
+    /// start and end positions.
+    pub fn sample(&self, t: f32) -> ProfileSample {
+        let duration = self.duration();
+        if t <= 0.0 || t >= duration {
+            let position = if t <= 0.0 { 0.0 } else { self.distance };
+            return ProfileSample {
+                position,
+                velocity: 0.0,
+                acceleration: 0.0,
+            };
+        }
+
+        let length = self.distance.abs();
+        let direction = self.distance.signum();
+
+        let sample = if t < self.acceleration_time {
+            self.sample_acceleration(t)
+        } else if t <= self.acceleration_time + self.cruise_time {
+            let accelerated = self.sample_acceleration(self.acceleration_time);
+            ProfileSample {
+                position: accelerated.position + self.peak_velocity * (t - self.acceleration_time),
+                velocity: self.peak_velocity,
+                acceleration: 0.0,
+            }
+        } else {
+            // Deceleration mirrors acceleration, run backwards from the end
+            let mirrored = self.sample_acceleration(duration - t);
+            ProfileSample {
+                position: length - mirrored.position,
+                velocity: mirrored.velocity,
+                acceleration: -mirrored.acceleration,
+            }
+        };
+
+        ProfileSample {
+            position: direction * sample.position,
+            velocity: direction * sample.velocity,
+            acceleration: direction * sample.acceleration,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MotionProfile;
+
+    /// Returns the times at which a profile moves from one segment to the next.
+    fn boundaries(profile: &MotionProfile) -> [f32; 6] {
+        let ramp = profile.jerk_time;
+        let accelerated = profile.acceleration_time;
+        let cruised = accelerated + profile.cruise_time;
+        [
+            ramp,
+            accelerated - ramp,
+            accelerated,
+            cruised,
+            cruised + ramp,
+            cruised + accelerated - ramp,
+        ]
+    }
+
+    #[test]
+    fn trapezoid_duration() {
+        // 2 s to reach 2 units/s over 2 units, 3 s cruising over 6 units, then 2 s to stop
+        let profile = MotionProfile::new(10.0, 2.0, 1.0, f32::INFINITY).unwrap();
+        assert!((profile.duration() - 7.0).abs() < 1e-4);
+        assert_eq!(profile.sample(3.5).velocity, 2.0);
+    }
+
+    #[test]
+    fn s_curve_duration() {
+        // The acceleration ramps for 1 s each way, so it takes 2 s and 1 unit to reach 1 unit/s
+        let profile = MotionProfile::new(4.0, 1.0, 1.0, 1.0).unwrap();
+        assert!((profile.duration() - 6.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn short_trapezoid_is_triangular() {
+        let profile = MotionProfile::new(1.0, 10.0, 1.0, f32::INFINITY).unwrap();
+        assert_eq!(profile.cruise_time, 0.0);
+        assert!((profile.peak_velocity - 1.0).abs() < 1e-4);
+        assert!((profile.duration() - 2.0).abs() < 1e-4);
+
+        let middle = profile.sample(1.0);
+        assert!((middle.position - 0.5).abs() < 1e-4);
+        assert!(middle.velocity < 10.0);
+    }
+
+    #[test]
+    fn s_curve_respects_limits() {
+        let (max_velocity, max_acceleration, max_jerk) = (2.0, 1.5, 3.0);
+        let profile = MotionProfile::new(10.0, max_velocity, max_acceleration, max_jerk).unwrap();
+
+        let dt = 1e-3;
+        let steps = (profile.duration() / dt) as usize;
+        let mut previous = profile.sample(0.0);
+        for step in 1..=steps {
+            let sample = profile.sample(step as f32 * dt);
+            assert!(sample.velocity <= max_velocity + 1e-4);
+            assert!(sample.acceleration.abs() <= max_acceleration + 1e-4);
+            let jerk = (sample.acceleration - previous.acceleration) / dt;
+            assert!(jerk.abs() <= max_jerk * 1.01, "jerk {jerk} at step {step}");
+            previous = sample;
+        }
+    }
+
+    #[test]
+    fn position_is_continuous_at_segment_boundaries() {
+        for profile in [
+            MotionProfile::new(10.0, 2.0, 1.5, 3.0).unwrap(),
+            MotionProfile::new(-10.0, 2.0, 1.5, f32::INFINITY).unwrap(),
+            MotionProfile::new(0.5, 2.0, 1.5, 3.0).unwrap(),
+        ] {
+            for t in boundaries(&profile) {
+                let before = profile.sample(t - 1e-4);
+                let after = profile.sample(t + 1e-4);
+                assert!(
+                    (after.position - before.position).abs() < 1e-3,
+                    "{profile:?} jumps at {t}"
+                );
+                assert!((after.velocity - before.velocity).abs() < 1e-2);
+            }
+
+            let end = profile.sample(profile.duration() - 1e-4);
+            assert!((end.position - profile.distance()).abs() < 1e-3);
+            assert_eq!(
+                profile.sample(profile.duration()).position,
+                profile.distance()
+            );
+        }
+    }
+}
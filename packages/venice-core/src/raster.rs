@@ -0,0 +1,754 @@
+//! A software rasterizer for shapes that the display SDK can't draw itself.
+//!
+//! Shapes are described by their signed distance from each pixel's center, which is negative
+//! inside the shape. Without anti-aliasing, a pixel is drawn if its center is inside; with it, a
+//! pixel is covered in proportion to how far its center is from the edge. Drawing happens on a
+//! [`Framebuffer`] in memory, which doesn't depend on the display and can be tested on the host.
+
+use std::{alloc::Allocator, f32::consts::TAU};
+
+/// A point, in pixels. Pixel centers are at half-integer coordinates.
+pub type Vec2 = [f32; 2];
+
+fn sub(a: Vec2, b: Vec2) -> Vec2 {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn dot(a: Vec2, b: Vec2) -> f32 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+fn length(a: Vec2) -> f32 {
+    dot(a, a).sqrt()
+}
+
+/// Returns the distance from `p` to the segment between `a` and `b`.
+fn segment_distance(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let (pa, ba) = (sub(p, a), sub(b, a));
+    let len_sq = dot(ba, ba);
+    let t = if len_sq > 0.0 {
+        (dot(pa, ba) / len_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    length([pa[0] - ba[0] * t, pa[1] - ba[1] * t])
+}
+
+/// Returns whether the angle of `offset` is within the clockwise sweep of `sweep` radians from
+/// `start`, where angles are measured clockwise from the positive x axis.
+fn in_sweep(offset: Vec2, start: f32, sweep: f32) -> bool {
+    (offset[1].atan2(offset[0]) - start).rem_euclid(TAU) <= sweep
+}
+
+fn point_at(center: Vec2, radius: f32, angle: f32) -> Vec2 {
+    [
+        center[0] + radius * angle.cos(),
+        center[1] + radius * angle.sin(),
+    ]
+}
+
+/// Returns whether `p` is inside `points` by the even-odd rule.
+fn polygon_contains(points: &[Vec2], p: Vec2) -> bool {
+    let mut inside = false;
+    let mut previous = points[points.len() - 1];
+    for &point in points {
+        if (point[1] > p[1]) != (previous[1] > p[1]) {
+            let x =
+                point[0] + (p[1] - point[1]) / (previous[1] - point[1]) * (previous[0] - point[0]);
+            if p[0] < x {
+                inside = !inside;
+            }
+        }
+        previous = point;
+    }
+    inside
+}
+
+fn polygon_edge_distance(points: &[Vec2], p: Vec2) -> f32 {
+    let mut previous = points[points.len() - 1];
+    let mut distance = f32::INFINITY;
+    for &point in points {
+        distance = distance.min(segment_distance(p, previous, point));
+        previous = point;
+    }
+    distance
+}
+
+/// A shape that can be drawn on a [`Framebuffer`].
+///
+/// Angles are in radians, measured clockwise from the positive x axis since y points down, and
+/// sweeps run clockwise from the start angle.
+#[derive(Debug, Clone, Copy)]
+pub enum Shape<'a> {
+    /// The inside of a polygon, by the even-odd rule. There must be at least one point.
+    Polygon(&'a [Vec2]),
+    /// The outline of a closed polygon. There must be at least one point.
+    PolygonOutline { points: &'a [Vec2], width: f32 },
+    /// A line with round ends.
+    Line { start: Vec2, end: Vec2, width: f32 },
+    /// Part of a circle's outline, with round ends.
+    Arc {
+        center: Vec2,
+        radius: f32,
+        start: f32,
+        sweep: f32,
+        width: f32,
+    },
+    /// A slice of a filled circle.
+    Pie {
+        center: Vec2,
+        radius: f32,
+        start: f32,
+        sweep: f32,
+    },
+    /// A filled rectangle with rounded corners, whose top-left corner is at `origin`.
+    RoundRect {
+        origin: Vec2,
+        size: Vec2,
+        radius: f32,
+    },
+    /// The outline of a rounded rectangle, drawn inside its edges.
+    RoundRectOutline {
+        origin: Vec2,
+        size: Vec2,
+        radius: f32,
+        width: f32,
+    },
+}
+
+impl Shape<'_> {
+    /// Returns the smallest and largest corners of a box that holds the shape.
+    pub fn bounds(&self) -> [Vec2; 2] {
+        let around = |points: &[Vec2], margin: f32| {
+            let mut bounds = [[f32::INFINITY; 2], [f32::NEG_INFINITY; 2]];
+            for point in points {
+                for axis in 0..2 {
+                    bounds[0][axis] = bounds[0][axis].min(point[axis] - margin);
+                    bounds[1][axis] = bounds[1][axis].max(point[axis] + margin);
+                }
+            }
+            bounds
+        };
+        match *self {
+            Self::Polygon(points) => around(points, 0.0),
+            Self::PolygonOutline { points, width } => around(points, width / 2.0),
+            Self::Line { start, end, width } => around(&[start, end], width / 2.0),
+            Self::Arc {
+                center,
+                radius,
+                width,
+                ..
+            } => around(&[center], radius + width / 2.0),
+            Self::Pie { center, radius, .. } => around(&[center], radius),
+            Self::RoundRect { origin, size, .. } | Self::RoundRectOutline { origin, size, .. } => {
+                [origin, [origin[0] + size[0], origin[1] + size[1]]]
+            }
+        }
+    }
+
+    /// Returns the signed distance from `p` to the shape's edge, which is negative inside it.
+    fn distance(&self, p: Vec2) -> f32 {
+        match *self {
+            Self::Polygon(points) => {
+                let distance = polygon_edge_distance(points, p);
+                if polygon_contains(points, p) {
+                    -distance
+                } else {
+                    distance
+                }
+            }
+            Self::PolygonOutline { points, width } => {
+                polygon_edge_distance(points, p) - width / 2.0
+            }
+            Self::Line { start, end, width } => segment_distance(p, start, end) - width / 2.0,
+            Self::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+                width,
+            } => {
+                let offset = sub(p, center);
+                if in_sweep(offset, start, sweep) {
+                    (length(offset) - radius).abs() - width / 2.0
+                } else {
+                    // Past either end, the distance is to the round cap
+                    let first = point_at(center, radius, start);
+                    let last = point_at(center, radius, start + sweep);
+                    length(sub(p, first)).min(length(sub(p, last))) - width / 2.0
+                }
+            }
+            Self::Pie {
+                center,
+                radius,
+                start,
+                sweep,
+            } => {
+                let offset = sub(p, center);
+                let to_circle = length(offset) - radius;
+                if sweep >= TAU {
+                    return to_circle;
+                }
+                let to_sides = segment_distance(p, center, point_at(center, radius, start)).min(
+                    segment_distance(p, center, point_at(center, radius, start + sweep)),
+                );
+                if in_sweep(offset, start, sweep) {
+                    to_circle.max(-to_sides)
+                } else {
+                    to_sides
+                }
+            }
+            Self::RoundRect {
+                origin,
+                size,
+                radius,
+            } => round_rect_distance(p, origin, size, radius),
+            Self::RoundRectOutline {
+                origin,
+                size,
+                radius,
+                width,
+            } => {
+                let distance = round_rect_distance(p, origin, size, radius);
+                distance.max(-distance - width)
+            }
+        }
+    }
+}
+
+fn round_rect_distance(p: Vec2, origin: Vec2, size: Vec2, radius: f32) -> f32 {
+    let half = [size[0] / 2.0, size[1] / 2.0];
+    let radius = radius.min(half[0]).min(half[1]).max(0.0);
+    let q = [
+        (p[0] - origin[0] - half[0]).abs() - half[0] + radius,
+        (p[1] - origin[1] - half[1]).abs() - half[1] + radius,
+    ];
+    length([q[0].max(0.0), q[1].max(0.0)]) + q[0].max(q[1]).min(0.0) - radius
+}
+
+/// What a shape is filled with. Colors are `0xAARRGGBB`, with straight alpha.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Paint {
+    Solid(u32),
+    /// A color that changes from `from` at `start` to `to` at `end`, and stays the same beyond
+    /// them.
+    Linear {
+        start: Vec2,
+        end: Vec2,
+        from: u32,
+        to: u32,
+    },
+}
+
+impl Paint {
+    fn color_at(&self, p: Vec2) -> u32 {
+        match *self {
+            Self::Solid(color) => color,
+            Self::Linear {
+                start,
+                end,
+                from,
+                to,
+            } => {
+                let direction = sub(end, start);
+                let len_sq = dot(direction, direction);
+                let t = if len_sq > 0.0 {
+                    (dot(sub(p, start), direction) / len_sq).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                lerp(from, to, (t * 255.0).round() as u32)
+            }
+        }
+    }
+}
+
+/// Mixes each channel of two colors, taking `amount` out of 255 of `b`.
+fn lerp(a: u32, b: u32, amount: u32) -> u32 {
+    let mut out = 0;
+    for shift in [0, 8, 16, 24] {
+        let (a, b) = ((a >> shift) & 0xff, (b >> shift) & 0xff);
+        out |= ((a * (255 - amount) + b * amount + 127) / 255) << shift;
+    }
+    out
+}
+
+/// Blends `source` over `dest` with source-over compositing, scaling the source's alpha by
+/// `coverage` out of 255. Both colors are `0xAARRGGBB`, with straight alpha.
+pub fn blend(dest: u32, source: u32, coverage: u32) -> u32 {
+    let source_alpha = (source >> 24) * coverage / 255;
+    if source_alpha == 255 {
+        return source;
+    }
+    if source_alpha == 0 {
+        return dest;
+    }
+
+    let dest_alpha = (dest >> 24) * (255 - source_alpha) / 255;
+    let alpha = source_alpha + dest_alpha;
+    let mut out = alpha << 24;
+    for shift in [0, 8, 16] {
+        let (s, d) = ((source >> shift) & 0xff, (dest >> shift) & 0xff);
+        out |= ((s * source_alpha + d * dest_alpha + alpha / 2) / alpha) << shift;
+    }
+    out
+}
+
+/// An image in memory that shapes can be drawn on, with `0xAARRGGBB` pixels.
+///
+/// A framebuffer covers part of an unbounded drawing surface, starting at `origin`, so that a
+/// large shape can be drawn a band at a time.
+pub struct Framebuffer<A: Allocator> {
+    origin: [i32; 2],
+    width: usize,
+    height: usize,
+    pixels: Vec<u32, A>,
+}
+
+impl<A: Allocator> Framebuffer<A> {
+    /// Creates a transparent framebuffer.
+    pub fn new(width: usize, height: usize, alloc: A) -> Self {
+        let count = width * height;
+        // The GC allocator can't grow a buffer of `u32`s, so it's allocated at its full size
+        let mut pixels = Vec::with_capacity_in(count, alloc);
+        pixels.resize(count, 0);
+        Self {
+            origin: [0, 0],
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Moves the framebuffer to cover the part of the surface starting at `origin`.
+    pub fn set_origin(&mut self, origin: [i32; 2]) {
+        self.origin = origin;
+    }
+
+    /// Returns one row of pixels.
+    pub fn row(&self, y: usize) -> &[u32] {
+        &self.pixels[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Sets every pixel to `color`.
+    pub fn clear(&mut self, color: u32) {
+        self.pixels.fill(color);
+    }
+
+    /// Draws a `width` by `height` image with its top-left corner at `position`, taking the pixel at
+    /// each offset into the image from `source`.
+    ///
+    /// With `Some(opacity)`, pixels are blended over the framebuffer with their alpha scaled by
+    /// `opacity` out of 255. With `None`, they replace what's there, alpha included.
+    pub fn composite(
+        &mut self,
+        position: [i32; 2],
+        [width, height]: [usize; 2],
+        opacity: Option<u32>,
+        source: impl Fn(usize, usize) -> u32,
+    ) {
+        let x = position[0] - self.origin[0];
+        let y = position[1] - self.origin[1];
+        // Columns and rows of the image that land on the framebuffer
+        let columns = (-x).max(0)..(width as i32).min(self.width as i32 - x);
+        let rows = (-y).max(0)..(height as i32).min(self.height as i32 - y);
+
+        for row in rows {
+            let start = (y + row) as usize * self.width;
+            for column in columns.clone() {
+                let pixel = &mut self.pixels[start + (x + column) as usize];
+                let color = source(column as usize, row as usize);
+                *pixel = match opacity {
+                    Some(opacity) => blend(*pixel, color, opacity),
+                    None => color,
+                };
+            }
+        }
+    }
+
+    /// Draws the `width` by `height` pixels of this framebuffer starting at the pixel `source` with
+    /// their top-left corner at `position`, like [`composite`](Self::composite).
+    ///
+    /// The two areas may overlap. Like `memmove`, rows are copied from the bottom up when moving
+    /// down, and pixels from right to left when moving right, so that every pixel is read before
+    /// it's drawn over.
+    pub fn copy_within(
+        &mut self,
+        source: [usize; 2],
+        position: [i32; 2],
+        [width, height]: [usize; 2],
+        opacity: Option<u32>,
+    ) {
+        let x = position[0] - self.origin[0];
+        let y = position[1] - self.origin[1];
+        let columns = (-x).max(0)..(width as i32).min(self.width as i32 - x);
+        let rows = (-y).max(0)..(height as i32).min(self.height as i32 - y);
+        if columns.is_empty() {
+            return;
+        }
+        let count = columns.len();
+        let moving_down = y > source[1] as i32;
+
+        for index in 0..rows.len() as i32 {
+            let row = if moving_down {
+                rows.end - 1 - index
+            } else {
+                rows.start + index
+            };
+            let from = (source[1] + row as usize) * self.width + source[0] + columns.start as usize;
+            let to = (y + row) as usize * self.width + (x + columns.start) as usize;
+
+            match opacity {
+                Some(opacity) => {
+                    for index in 0..count {
+                        let index = if to > from { count - 1 - index } else { index };
+                        let color = self.pixels[from + index];
+                        let pixel = &mut self.pixels[to + index];
+                        *pixel = blend(*pixel, color, opacity);
+                    }
+                }
+                None => self.pixels.copy_within(from..from + count, to),
+            }
+        }
+    }
+
+    /// Draws `shape` filled with `paint`, smoothing its edges if `antialias` is set.
+    pub fn draw(&mut self, shape: Shape, paint: Paint, antialias: bool) {
+        let [min, max] = shape.bounds();
+        if !(min[0].is_finite() && min[1].is_finite() && max[0].is_finite() && max[1].is_finite()) {
+            return;
+        }
+
+        // Anti-aliasing can cover pixels half a pixel outside of the shape
+        let margin = if antialias { 1.0 } else { 0.0 };
+        let clip = |low: f32, high: f32, origin: i32, size: usize| {
+            let start = ((low - margin).floor() as i64 - i64::from(origin)).clamp(0, size as i64);
+            let end = ((high + margin).ceil() as i64 - i64::from(origin)).clamp(0, size as i64);
+            start as usize..end as usize
+        };
+        let columns = clip(min[0], max[0], self.origin[0], self.width);
+        let rows = clip(min[1], max[1], self.origin[1], self.height);
+
+        for y in rows {
+            for x in columns.clone() {
+                let center = [
+                    (self.origin[0] + x as i32) as f32 + 0.5,
+                    (self.origin[1] + y as i32) as f32 + 0.5,
+                ];
+                let distance = shape.distance(center);
+                let coverage = if antialias {
+                    ((0.5 - distance).clamp(0.0, 1.0) * 255.0).round() as u32
+                } else if distance <= 0.0 {
+                    255
+                } else {
+                    0
+                };
+                if coverage > 0 {
+                    let pixel = &mut self.pixels[y * self.width + x];
+                    *pixel = blend(*pixel, paint.color_at(center), coverage);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{alloc::Global, f32::consts::TAU};
+
+    use super::{Framebuffer, Paint, Shape, Vec2};
+
+    const WHITE: Paint = Paint::Solid(0xffff_ffff);
+
+    /// Returns each row of `framebuffer` as a string, with `#` for opaque pixels, `.` for
+    /// transparent ones and `+` for anything in between.
+    fn mask(framebuffer: &Framebuffer<Global>) -> Vec<String> {
+        (0..framebuffer.height())
+            .map(|y| {
+                framebuffer
+                    .row(y)
+                    .iter()
+                    .map(|pixel| match pixel >> 24 {
+                        0 => '.',
+                        255 => '#',
+                        _ => '+',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn draw(size: [usize; 2], shape: Shape, paint: Paint, antialias: bool) -> Framebuffer<Global> {
+        let mut framebuffer = Framebuffer::new(size[0], size[1], Global);
+        framebuffer.draw(shape, paint, antialias);
+        framebuffer
+    }
+
+    #[test]
+    fn concave_polygons_leave_their_notch_empty() {
+        let points = [
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 3.0],
+            [5.0, 3.0],
+            [5.0, 5.0],
+            [0.0, 5.0],
+        ];
+        // The edges are on pixel boundaries, so anti-aliasing doesn't change anything
+        for antialias in [false, true] {
+            let framebuffer = draw([6, 6], Shape::Polygon(&points), WHITE, antialias);
+            assert_eq!(
+                mask(&framebuffer),
+                ["##....", "##....", "##....", "#####.", "#####.", "......"]
+            );
+        }
+    }
+
+    #[test]
+    fn self_intersecting_polygons_use_the_even_odd_rule() {
+        // A bow tie, whose left and right triangles are inside and top and bottom ones aren't
+        let bow_tie = [[0.0, 0.0], [8.0, 6.0], [8.0, 0.0], [0.0, 6.0]];
+        let framebuffer = draw([8, 6], Shape::Polygon(&bow_tie), WHITE, false);
+        assert_eq!(
+            mask(&framebuffer),
+            [
+                "#......#", "##....##", "###..###", "###..###", "##....##", "#......#"
+            ]
+        );
+
+        // A pentagram, whose arms are inside once and whose middle is inside twice
+        let star: Vec<Vec2> = (0..5)
+            .map(|i| {
+                let angle = -TAU / 4.0 + i as f32 * TAU * 2.0 / 5.0;
+                [10.0 + 10.0 * angle.cos(), 10.0 + 10.0 * angle.sin()]
+            })
+            .collect();
+        let framebuffer = draw([20, 20], Shape::Polygon(&star), WHITE, false);
+        let rows = mask(&framebuffer);
+        assert_eq!(&rows[2][9..11], "##");
+        assert_eq!(&rows[10][9..11], "..");
+        assert_eq!(&rows[9][9..11], "..");
+    }
+
+    #[test]
+    fn shapes_are_clipped_at_the_edges() {
+        let line = Shape::Line {
+            start: [-10.0, 4.0],
+            end: [20.0, 4.0],
+            width: 2.0,
+        };
+        let framebuffer = draw([6, 6], line, WHITE, true);
+        assert_eq!(
+            mask(&framebuffer),
+            ["......", "......", "......", "######", "######", "......"]
+        );
+
+        // Shapes entirely outside of the framebuffer, however far, draw nothing
+        for center in [[-5.0, 3.0], [3.0, 12.0], [1e30, -1e30]] {
+            let pie = Shape::Pie {
+                center,
+                radius: 2.0,
+                start: 0.0,
+                sweep: TAU,
+            };
+            let framebuffer = draw([6, 6], pie, WHITE, true);
+            assert!(mask(&framebuffer).iter().all(|row| row == "......"));
+        }
+    }
+
+    #[test]
+    fn bands_match_a_whole_framebuffer() {
+        let pie = Shape::Pie {
+            center: [5.0, 4.0],
+            radius: 5.5,
+            start: 0.5,
+            sweep: 4.0,
+        };
+        let whole = draw([8, 8], pie, WHITE, true);
+
+        // Three-row bands, the last of which hangs off the bottom
+        let mut band = Framebuffer::new(8, 3, Global);
+        for top in [0, 3, 6] {
+            band.clear(0);
+            band.set_origin([0, top]);
+            band.draw(pie, WHITE, true);
+            for y in 0..3.min(8 - top as usize) {
+                assert_eq!(
+                    band.row(y),
+                    whole.row(top as usize + y),
+                    "row {}",
+                    top as usize + y
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn images_are_clipped_at_the_edges() {
+        let mut framebuffer = Framebuffer::new(4, 4, Global);
+        framebuffer.set_origin([10, 10]);
+        framebuffer.composite([8, 9], [3, 3], None, |x, y| {
+            0xff00_0000 | (y * 3 + x) as u32
+        });
+        framebuffer.composite([13, 13], [3, 3], None, |_, _| 0xffff_ffff);
+        assert_eq!(framebuffer.row(0), [0xff00_0005, 0, 0, 0]);
+        assert_eq!(framebuffer.row(1), [0xff00_0008, 0, 0, 0]);
+        assert_eq!(framebuffer.row(2), [0; 4]);
+        assert_eq!(framebuffer.row(3), [0, 0, 0, 0xffff_ffff]);
+    }
+
+    #[test]
+    fn overlapping_copies_match_copies_of_a_snapshot() {
+        let pattern =
+            |x: usize, y: usize| ((x * 37 + y * 91) as u32 % 255) << 24 | (x * 8 + y) as u32;
+        for opacity in [None, Some(200)] {
+            for dy in -3..=3 {
+                for dx in -3..=3 {
+                    let mut framebuffer = Framebuffer::new(8, 8, Global);
+                    framebuffer.composite([0, 0], [8, 8], None, pattern);
+                    let mut expected = Framebuffer::new(8, 8, Global);
+                    expected.composite([0, 0], [8, 8], None, pattern);
+
+                    let position = [2 + dx, 2 + dy];
+                    framebuffer.copy_within([2, 2], position, [5, 4], opacity);
+                    expected.composite(position, [5, 4], opacity, |x, y| pattern(2 + x, 2 + y));
+                    for y in 0..8 {
+                        assert_eq!(framebuffer.row(y), expected.row(y), "moved by ({dx}, {dy})");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty_shapes_draw_nothing() {
+        let shapes = [
+            // Along a pixel boundary, so no pixel center is on it
+            Shape::Line {
+                start: [0.0, 2.0],
+                end: [6.0, 2.0],
+                width: 0.0,
+            },
+            Shape::Arc {
+                center: [3.0, 3.0],
+                radius: 0.0,
+                start: 0.0,
+                sweep: TAU,
+                width: 0.0,
+            },
+            Shape::Pie {
+                center: [3.0, 3.0],
+                radius: 0.0,
+                start: 0.0,
+                sweep: TAU,
+            },
+            Shape::Pie {
+                center: [3.0, 3.0],
+                radius: 0.0,
+                start: 1.0,
+                sweep: 1.0,
+            },
+            Shape::Polygon(&[[3.0, 3.0]]),
+            Shape::PolygonOutline {
+                points: &[[1.0, 1.0], [4.0, 1.0], [4.0, 4.0], [1.0, 4.0]],
+                width: 0.0,
+            },
+            Shape::RoundRect {
+                origin: [2.0, 2.0],
+                size: [0.0, 0.0],
+                radius: 1.0,
+            },
+            Shape::RoundRectOutline {
+                origin: [1.0, 1.0],
+                size: [4.0, 4.0],
+                radius: 0.0,
+                width: 0.0,
+            },
+        ];
+        for shape in shapes {
+            for antialias in [false, true] {
+                let framebuffer = draw([6, 6], shape, WHITE, antialias);
+                assert!(
+                    mask(&framebuffer).iter().all(|row| row == "......"),
+                    "{shape:?} drew {:?}",
+                    mask(&framebuffer)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn zero_radius_round_rects_are_square() {
+        for radius in [0.0, -1.0] {
+            let rect = Shape::RoundRect {
+                origin: [1.0, 1.0],
+                size: [3.0, 2.0],
+                radius,
+            };
+            let framebuffer = draw([5, 4], rect, WHITE, true);
+            assert_eq!(mask(&framebuffer), [".....", ".###.", ".###.", "....."]);
+
+            let outline = Shape::RoundRectOutline {
+                origin: [0.0, 0.0],
+                size: [5.0, 4.0],
+                radius,
+                width: 1.0,
+            };
+            let framebuffer = draw([5, 4], outline, WHITE, true);
+            assert_eq!(mask(&framebuffer), ["#####", "#...#", "#...#", "#####"]);
+        }
+    }
+
+    #[test]
+    fn gradients_reach_their_end_colors() {
+        let fill = Shape::RoundRect {
+            origin: [0.0, 0.0],
+            size: [10.0, 1.0],
+            radius: 0.0,
+        };
+        let paint = Paint::Linear {
+            start: [1.5, 0.5],
+            end: [8.5, 0.5],
+            from: 0xffff_0000,
+            to: 0xff00_00ff,
+        };
+        let framebuffer = draw([10, 1], fill, paint, false);
+        let row = framebuffer.row(0);
+        // The colors stay the same before the start and after the end
+        assert_eq!(row[..2], [0xffff_0000; 2]);
+        assert_eq!(row[8..], [0xff00_00ff; 2]);
+        for pair in row.windows(2) {
+            assert!((pair[1] >> 16) & 0xff <= (pair[0] >> 16) & 0xff);
+            assert!(pair[1] & 0xff >= pair[0] & 0xff);
+        }
+
+        // Alpha is interpolated too, so the transparent end leaves the framebuffer untouched
+        let paint = Paint::Linear {
+            start: [0.5, 0.5],
+            end: [9.5, 0.5],
+            from: 0x00ff_ffff,
+            to: 0xff00_ff00,
+        };
+        let framebuffer = draw([10, 1], fill, paint, false);
+        assert_eq!(framebuffer.row(0)[0], 0);
+        assert_eq!(framebuffer.row(0)[9], 0xff00_ff00);
+
+        // A gradient without a direction is its first color everywhere
+        let paint = Paint::Linear {
+            start: [4.0, 0.5],
+            end: [4.0, 0.5],
+            from: 0xff12_3456,
+            to: 0xff65_4321,
+        };
+        let framebuffer = draw([10, 1], fill, paint, true);
+        assert_eq!(framebuffer.row(0), [0xff12_3456; 10]);
+    }
+}
@@ -0,0 +1,500 @@
+use std::alloc::Allocator;
+
+use crate::pose::Pose;
+
+/// The number of steps each curve is split into when timing a trajectory.
+const STEPS_PER_CURVE: usize = 64;
+
+/// A cubic Bézier curve in the plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bezier {
+    points: [[f32; 2]; 4],
+}
+
+impl Bezier {
+    pub const fn new(points: [[f32; 2]; 4]) -> Self {
+        Self { points }
+    }
+
+    /// Creates the cubic Hermite curve from `start` to `end` that leaves and arrives along their
+    /// headings.
+    ///
+    /// The tangents at each end are as long as the straight line between them, which bends the
+    /// curve smoothly without looping.
+    pub fn hermite(start: Pose, end: Pose) -> Self {
+        let length = (end.x - start.x).hypot(end.y - start.y);
+        // A Hermite curve's Bézier control points lie a third of each tangent from its ends
+        let (start_sin, start_cos) = start.theta.sin_cos();
+        let (end_sin, end_cos) = end.theta.sin_cos();
+        Self::new([
+            [start.x, start.y],
+            [
+                start.x + start_cos * length / 3.0,
+                start.y + start_sin * length / 3.0,
+            ],
+            [
+                end.x - end_cos * length / 3.0,
+                end.y - end_sin * length / 3.0,
+            ],
+            [end.x, end.y],
+        ])
+    }
+
+    /// Returns the point at `u`, between 0 at the start and 1 at the end.
+    pub fn position(&self, u: f32) -> [f32; 2] {
+        let [p0, p1, p2, p3] = self.points;
+        let v = 1.0 - u;
+        let weights = [v * v * v, 3.0 * v * v * u, 3.0 * v * u * u, u * u * u];
+        [0, 1].map(|i| {
+            weights[0] * p0[i] + weights[1] * p1[i] + weights[2] * p2[i] + weights[3] * p3[i]
+        })
+    }
+
+    /// Returns the first derivative of the curve with respect to `u`.
+    pub fn derivative(&self, u: f32) -> [f32; 2] {
+        let [p0, p1, p2, p3] = self.points;
+        let v = 1.0 - u;
+        [0, 1].map(|i| {
+            3.0 * v * v * (p1[i] - p0[i])
+                + 6.0 * v * u * (p2[i] - p1[i])
+                + 3.0 * u * u * (p3[i] - p2[i])
+        })
+    }
+
+    /// Returns the second derivative of the curve with respect to `u`.
+    pub fn second_derivative(&self, u: f32) -> [f32; 2] {
+        let [p0, p1, p2, p3] = self.points;
+        [0, 1].map(|i| {
+            6.0 * (1.0 - u) * (p2[i] - 2.0 * p1[i] + p0[i])
+                + 6.0 * u * (p3[i] - 2.0 * p2[i] + p1[i])
+        })
+    }
+
+    /// Returns the direction of travel at `u`, counterclockwise from the x axis.
+    pub fn heading(&self, u: f32) -> f32 {
+        let [dx, dy] = self.derivative(u);
+        dy.atan2(dx)
+    }
+
+    /// Returns the curvature at `u`, positive when the curve turns counterclockwise, or zero where
+    /// the curve comes to a point.
+    pub fn curvature(&self, u: f32) -> f32 {
+        let [dx, dy] = self.derivative(u);
+        let [ddx, ddy] = self.second_derivative(u);
+        let speed = dx.hypot(dy);
+        if speed == 0.0 {
+            return 0.0;
+        }
+        (dx * ddy - dy * ddx) / (speed * speed * speed)
+    }
+}
+
+/// Returns the fastest a differential drive can follow a curve without either wheel exceeding
+/// `max_velocity`.
+pub fn cornering_velocity(max_velocity: f32, curvature: f32, track_width: f32) -> f32 {
+    max_velocity / (1.0 + curvature.abs() * track_width / 2.0)
+}
+
+/// The limits that a differential drive following a [`Trajectory`] must stay within.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Constraints {
+    /// The distance between the left and right wheels.
+    pub track_width: f32,
+    /// The fastest either wheel may move.
+    pub max_velocity: f32,
+    /// The fastest the robot may speed up or slow down.
+    pub max_acceleration: f32,
+}
+
+/// A step of a [`Trajectory`]: a point on its curves, and the distance, velocity and time at which
+/// the robot passes it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Knot {
+    // the index of the curve plus the position along it
+    u: f32,
+    distance: f32,
+    velocity: f32,
+    time: f32,
+}
+
+/// The state of a [`Trajectory`] at one point in time.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TrajectorySample {
+    pub time: f32,
+    pub distance: f32,
+    pub pose: Pose,
+    pub velocity: f32,
+    pub acceleration: f32,
+    pub curvature: f32,
+}
+
+impl TrajectorySample {
+    /// Returns how fast the robot is turning, in radians per second counterclockwise.
+    pub fn angular_velocity(&self) -> f32 {
+        self.velocity * self.curvature
+    }
+}
+
+/// Splits `u`, the index of a curve plus the position along it, into that curve and the position
+/// along it.
+fn locate(curves: &[Bezier], u: f32) -> (Bezier, f32) {
+    let index = (u.max(0.0) as usize).min(curves.len() - 1);
+    (curves[index], u - index as f32)
+}
+
+/// A smooth path through a list of poses, timed for a differential drive, independent of
+/// MicroPython.
+///
+/// Each pair of poses is joined by a cubic Hermite curve. The robot starts and ends at rest, and
+/// its velocity along the curves is the fastest that keeps within the [`Constraints`]: neither
+/// wheel exceeds the velocity limit on curves, and the robot never speeds up or slows down faster
+/// than the acceleration limit. Times are in seconds.
+pub struct Trajectory<A: Allocator> {
+    curves: Vec<Bezier, A>,
+    knots: Vec<Knot, A>,
+}
+
+impl<A: Allocator + Clone> Trajectory<A> {
+    /// Plans a trajectory through `waypoints`.
+    ///
+    /// Returns `None` if there are fewer than two waypoints, if two waypoints in a row share a
+    /// position, or if any limit isn't positive and finite.
+    pub fn new(waypoints: &[Pose], constraints: Constraints, alloc: A) -> Option<Self> {
+        let Constraints {
+            track_width,
+            max_velocity,
+            max_acceleration,
+        } = constraints;
+        let valid = |limit: f32| limit > 0.0 && limit.is_finite();
+        if waypoints.len() < 2
+            || !valid(track_width)
+            || !valid(max_velocity)
+            || !valid(max_acceleration)
+            || waypoints
+                .windows(2)
+                .any(|pair| pair[0].distance_to(pair[1].x, pair[1].y) == 0.0)
+        {
+            return None;
+        }
+
+        let mut curves = Vec::with_capacity_in(waypoints.len() - 1, alloc.clone());
+        curves.extend(
+            waypoints
+                .windows(2)
+                .map(|pair| Bezier::hermite(pair[0], pair[1])),
+        );
+
+        let count = curves.len() * STEPS_PER_CURVE + 1;
+        let mut knots = Vec::with_capacity_in(count, alloc);
+
+        // Measure the curves, and limit the velocity at each step by its curvature
+        let mut distance = 0.0;
+        let mut previous = curves[0].position(0.0);
+        for step in 0..count {
+            let u = step as f32 / STEPS_PER_CURVE as f32;
+            let (curve, t) = locate(&curves, u);
+            let position = curve.position(t);
+            distance += (position[0] - previous[0]).hypot(position[1] - previous[1]);
+            previous = position;
+
+            // Curves meet at the same heading but not the same curvature, so a step where two
+            // curves meet is limited by the sharper of them
+            let mut curvature = curve.curvature(t).abs();
+            if t == 0.0 && step > 0 {
+                curvature = curvature.max(curves[step / STEPS_PER_CURVE - 1].curvature(1.0).abs());
+            }
+
+            knots.push(Knot {
+                u,
+                distance,
+                velocity: cornering_velocity(max_velocity, curvature, track_width),
+                time: 0.0,
+            });
+        }
+
+        // Limit the velocity by how fast the robot can speed up from the start, then by how fast
+        // it can slow down for what comes next, including stopping at the end
+        let reachable = |velocity: f32, from: &Knot, to: &Knot| {
+            let distance = to.distance - from.distance;
+            (velocity * velocity + 2.0 * max_acceleration * distance).sqrt()
+        };
+        knots[0].velocity = 0.0;
+        for i in 1..count {
+            let limit = reachable(knots[i - 1].velocity, &knots[i - 1], &knots[i]);
+            knots[i].velocity = knots[i].velocity.min(limit);
+        }
+        knots[count - 1].velocity = 0.0;
+        for i in (0..count - 1).rev() {
+            let limit = reachable(knots[i + 1].velocity, &knots[i], &knots[i + 1]);
+            knots[i].velocity = knots[i].velocity.min(limit);
+        }
+
+        // Between steps, the acceleration is constant, so the average velocity is the mean of
+        // the velocities at either end
+        let mut time = 0.0;
+        for i in 1..count {
+            let distance = knots[i].distance - knots[i - 1].distance;
+            let velocity = knots[i].velocity + knots[i - 1].velocity;
+            if velocity > 0.0 {
+                time += 2.0 * distance / velocity;
+            }
+            knots[i].time = time;
+        }
+
+        Some(Self { curves, knots })
+    }
+}
+
+impl<A: Allocator> Trajectory<A> {
+    /// Returns the time taken to follow the trajectory.
+    pub fn duration(&self) -> f32 {
+        self.knots[self.knots.len() - 1].time
+    }
+
+    /// Returns the length of the trajectory.
+    pub fn length(&self) -> f32 {
+        self.knots[self.knots.len() - 1].distance
+    }
+
+    /// Returns the positions of the steps along the trajectory, in order.
+    pub fn positions(&self) -> impl Iterator<Item = [f32; 2]> {
+        self.knots.iter().map(|knot| {
+            let (curve, t) = locate(&self.curves, knot.u);
+            curve.position(t)
+        })
+    }
+
+    /// Returns the state of the trajectory `t` seconds after it starts.
+    ///
+    /// Times before the start and after the end are clamped, so the robot is at rest at the first
+    /// and last waypoints.
+    pub fn sample(&self, t: f32) -> TrajectorySample {
+        let t = t.clamp(0.0, self.duration());
+        let after = self.knots.partition_point(|knot| knot.time <= t);
+        let index = after.clamp(1, self.knots.len() - 1) - 1;
+        let (from, to) = (self.knots[index], self.knots[index + 1]);
+
+        let length = to.distance - from.distance;
+        let (v0, v1) = (from.velocity, to.velocity);
+        let acceleration = if length > 0.0 {
+            (v1 * v1 - v0 * v0) / (2.0 * length)
+        } else {
+            0.0
+        };
+        let elapsed = (t - from.time).max(0.0);
+        let velocity = (v0 + acceleration * elapsed).clamp(v0.min(v1), v0.max(v1));
+        let travelled = ((v0 + velocity) / 2.0 * elapsed).min(length);
+
+        let fraction = if length > 0.0 {
+            travelled / length
+        } else {
+            0.0
+        };
+        let u = from.u + (to.u - from.u) * fraction;
+        let (curve, u) = locate(&self.curves, u);
+        let [x, y] = curve.position(u);
+
+        TrajectorySample {
+            time: t,
+            distance: from.distance + travelled,
+            pose: Pose::new(x, y, curve.heading(u)),
+            velocity,
+            acceleration,
+            curvature: curve.curvature(u),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        alloc::Global,
+        f32::consts::{FRAC_PI_2, PI},
+    };
+
+    use super::{Bezier, Constraints, Trajectory, cornering_velocity, locate};
+    use crate::pose::Pose;
+
+    const CONSTRAINTS: Constraints = Constraints {
+        track_width: 12.0,
+        max_velocity: 20.0,
+        max_acceleration: 10.0,
+    };
+
+    fn plan(waypoints: &[Pose]) -> Trajectory<Global> {
+        Trajectory::new(waypoints, CONSTRAINTS, Global).unwrap()
+    }
+
+    fn assert_close(actual: f32, expected: f32, tolerance: f32) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn hermite_leaves_and_arrives_along_headings() {
+        let (start, end) = (Pose::new(1.0, 2.0, 0.5), Pose::new(8.0, -3.0, -1.0));
+        let curve = Bezier::hermite(start, end);
+
+        assert_eq!(curve.position(0.0), [start.x, start.y]);
+        assert_eq!(curve.position(1.0), [end.x, end.y]);
+        assert_close(curve.heading(0.0), start.theta, 1e-5);
+        assert_close(curve.heading(1.0), end.theta, 1e-5);
+
+        // the tangents are as long as the chord between the ends
+        let chord = (end.x - start.x).hypot(end.y - start.y);
+        let [dx, dy] = curve.derivative(0.0);
+        assert_close(dx.hypot(dy), chord, 1e-4);
+    }
+
+    #[test]
+    fn straight_line_has_no_curvature() {
+        let curve = Bezier::hermite(Pose::new(0.0, 0.0, 0.0), Pose::new(10.0, 0.0, 0.0));
+        for step in 0..=10 {
+            let u = step as f32 / 10.0;
+            assert_eq!(curve.curvature(u), 0.0);
+            assert_eq!(curve.position(u)[1], 0.0);
+        }
+
+        let trajectory = plan(&[Pose::new(0.0, 0.0, 0.0), Pose::new(10.0, 0.0, 0.0)]);
+        let sample = trajectory.sample(trajectory.duration() / 2.0);
+        assert_eq!(sample.curvature, 0.0);
+        assert_eq!(sample.angular_velocity(), 0.0);
+    }
+
+    #[test]
+    fn arc_has_constant_curvature() {
+        // the usual cubic approximation of a quarter circle of radius 5, counterclockwise, whose
+        // curvature strays from the circle's by under 3%
+        let (radius, k) = (5.0, 0.552_284_8);
+        let arc = Bezier::new([
+            [radius, 0.0],
+            [radius, k * radius],
+            [k * radius, radius],
+            [0.0, radius],
+        ]);
+        for step in 0..=10 {
+            let u = step as f32 / 10.0;
+            assert_close(arc.curvature(u), 1.0 / radius, 0.03 / radius);
+            let [x, y] = arc.position(u);
+            assert_close(x.hypot(y), radius, 2e-3);
+        }
+
+        // the same arc driven clockwise curves the other way
+        let [p0, p1, p2, p3] = arc.points;
+        let reversed = Bezier::new([p3, p2, p1, p0]);
+        assert_close(reversed.curvature(0.5), -1.0 / radius, 0.03 / radius);
+    }
+
+    #[test]
+    fn samples_start_and_end_at_rest_on_the_waypoints() {
+        let waypoints = [
+            Pose::new(0.0, 0.0, 0.0),
+            Pose::new(48.0, 24.0, FRAC_PI_2),
+            Pose::new(24.0, 60.0, PI),
+        ];
+        let trajectory = plan(&waypoints);
+
+        for (t, waypoint) in [(0.0, waypoints[0]), (trajectory.duration(), waypoints[2])] {
+            let sample = trajectory.sample(t);
+            assert_close(sample.pose.x, waypoint.x, 1e-3);
+            assert_close(sample.pose.y, waypoint.y, 1e-3);
+            assert_close(sample.pose.theta, waypoint.theta, 1e-3);
+            assert_close(sample.velocity, 0.0, 1e-4);
+        }
+
+        // times outside the trajectory are clamped
+        assert_eq!(trajectory.sample(-1.0), trajectory.sample(0.0));
+        let duration = trajectory.duration();
+        assert_eq!(
+            trajectory.sample(duration + 1.0),
+            trajectory.sample(duration)
+        );
+        assert_close(
+            trajectory.sample(duration).distance,
+            trajectory.length(),
+            1e-3,
+        );
+    }
+
+    #[test]
+    fn straight_line_follows_a_trapezoidal_profile() {
+        let trajectory = plan(&[Pose::new(0.0, 0.0, 0.0), Pose::new(100.0, 0.0, 0.0)]);
+        // 2 s to reach 20 units per second, 3 s at that speed, and 2 s to stop
+        assert_close(trajectory.duration(), 7.0, 0.01);
+        assert_close(trajectory.sample(3.5).velocity, 20.0, 1e-3);
+        assert_close(trajectory.sample(1.0).acceleration, 10.0, 1e-3);
+        assert_close(trajectory.sample(6.0).acceleration, -10.0, 1e-3);
+
+        // too short to reach full speed
+        let trajectory = plan(&[Pose::new(0.0, 0.0, 0.0), Pose::new(4.0, 0.0, 0.0)]);
+        let peak = (4.0 * CONSTRAINTS.max_acceleration).sqrt();
+        assert_close(
+            trajectory.duration(),
+            2.0 * peak / CONSTRAINTS.max_acceleration,
+            0.01,
+        );
+        assert_close(
+            trajectory.sample(trajectory.duration() / 2.0).velocity,
+            peak,
+            0.05,
+        );
+    }
+
+    #[test]
+    fn knots_keep_within_the_limits() {
+        let Constraints {
+            track_width,
+            max_velocity,
+            max_acceleration,
+        } = CONSTRAINTS;
+        let trajectory = plan(&[
+            Pose::new(0.0, 0.0, 0.0),
+            Pose::new(48.0, 24.0, FRAC_PI_2),
+            Pose::new(24.0, 60.0, PI),
+        ]);
+
+        for pair in trajectory.knots.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            assert!(to.distance > from.distance);
+            assert!(to.time > from.time);
+
+            // neither wheel is too fast where the curve is sharpest
+            let (curve, u) = locate(&trajectory.curves, to.u);
+            let limit = cornering_velocity(max_velocity, curve.curvature(u), track_width);
+            assert!(to.velocity <= limit + 1e-3);
+
+            // the robot speeds up and slows down no faster than it can
+            let acceleration = (to.velocity.powi(2) - from.velocity.powi(2))
+                / (2.0 * (to.distance - from.distance));
+            assert!(acceleration.abs() <= max_acceleration * 1.001);
+        }
+
+        let duration = trajectory.duration();
+        for step in 0..=100 {
+            let sample = trajectory.sample(duration * step as f32 / 100.0);
+            assert!(sample.velocity >= 0.0 && sample.velocity <= max_velocity);
+            assert!(sample.acceleration.abs() <= max_acceleration * 1.001);
+        }
+    }
+
+    #[test]
+    fn invalid_trajectories_are_rejected() {
+        let start = Pose::new(0.0, 0.0, 0.0);
+        let end = Pose::new(10.0, 0.0, 0.0);
+        assert!(Trajectory::new(&[start], CONSTRAINTS, Global).is_none());
+        assert!(Trajectory::new(&[start, start], CONSTRAINTS, Global).is_none());
+
+        let stalled = Constraints {
+            max_acceleration: 0.0,
+            ..CONSTRAINTS
+        };
+        assert!(Trajectory::new(&[start, end], stalled, Global).is_none());
+        let unbounded = Constraints {
+            max_velocity: f32::INFINITY,
+            ..CONSTRAINTS
+        };
+        assert!(Trajectory::new(&[start, end], unbounded, Global).is_none());
+    }
+}
@@ -17,6 +17,10 @@ pub struct Gc {
     pub token: InitToken,
 }
 
+/// The largest alignment the GC heap can give an allocation.
+///
+/// Objects and buffers allocated in the GC heap, including every `#[class]` struct, must not need
+/// a larger alignment than this, so they store `f32` rather than `f64`.
 pub const GC_MAX_ALIGN: usize = 4;
 
 impl Gc {
@@ -183,6 +183,21 @@ where
     REGISTRIES.registry_by_port(port).lock(init)
 }
 
+pub fn port_is_available(port: PortNumber) -> bool {
+    REGISTRIES.registry_by_port(port).is_available()
+}
+
+pub fn try_lock_port<D, I>(
+    port: PortNumber,
+    init: I,
+) -> Result<RegistryGuard<'static, SmartPort, D>, DeviceOccupiedError>
+where
+    D: PortDevice<SmartPort>,
+    I: FnOnce(SmartPort) -> D,
+{
+    REGISTRIES.registry_by_port(port).try_lock(init)
+}
+
 pub fn lock_controller(id: ControllerId) -> ControllerGuard {
     match id {
         ControllerId::Primary => REGISTRIES.primary_controller.lock(|c| c),
@@ -1,4 +1,5 @@
 use std::{
+    alloc::Allocator,
     cell::RefCell,
     ffi::c_int,
     fs::{File, OpenOptions},
@@ -8,7 +9,7 @@ use std::{
 use argparse::{ArgParser, Args, DefaultParser, KeywordError, ParseError, StrParser, type_name};
 use micropython_macros::{class, class_methods};
 use micropython_rs::{
-    errno::{MP_EBADF, MP_EINVAL, MP_EIO},
+    errno::{MP_EBADF, MP_EINVAL, MP_EIO, MP_ENOMEM},
     except::{Message, raise_os_error, type_error},
     fun::{Fun1, FunVarBetween, FunVarKw},
     init::token,
@@ -119,6 +120,28 @@ impl FileObj {
     const FLUSH: &Fun1 = &mp_stream_flush_obj;
 }
 
+impl FileObj {
+    /// Reads the rest of the file from its current position.
+    ///
+    /// The data is read into memory from `alloc` a chunk at a time, and `MP_ENOMEM` is returned if
+    /// it doesn't fit.
+    pub fn read_to_end<A: Allocator>(&self, alloc: A) -> Result<Vec<u8, A>, c_int> {
+        let mut file = self.file.borrow_mut();
+        let file = file.as_mut().ok_or(MP_EBADF)?;
+
+        let mut data = Vec::new_in(alloc);
+        let mut chunk = [0; 512];
+        loop {
+            let count = file.read(&mut chunk).map_err(io_to_errno)?;
+            if count == 0 {
+                return Ok(data);
+            }
+            data.try_reserve(count).map_err(|_| MP_ENOMEM)?;
+            data.extend_from_slice(&chunk[..count]);
+        }
+    }
+}
+
 fn checked_seek_from(seek: &StreamSeek) -> Result<SeekFrom, c_int> {
     match seek.whence {
         SEEK_SET if seek.offset >= 0 => Ok(SeekFrom::Start(seek.offset as u64)),
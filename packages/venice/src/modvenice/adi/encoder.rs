@@ -6,7 +6,7 @@ use micropython_rs::{
     except::value_error,
     obj::{Obj, ObjBase, ObjType},
 };
-use vexide_devices::adi::encoder::AdiEncoder;
+use vexide_devices::{adi::encoder::AdiEncoder, math::Angle};
 
 use crate::modvenice::{
     Exception,
@@ -109,6 +109,14 @@ fn check_ports(top_port: AdiPortSpec<'_>, bottom_port: AdiPortSpec<'_>) -> Resul
     Ok(())
 }
 
+impl AdiEncoderObj {
+    /// Returns the position of the encoder, corrected for its ticks per revolution.
+    pub fn position(&self) -> Result<Angle, Exception> {
+        let tick_turns = self.encoder.borrow().position()?; // ticks * TAU
+        Ok(tick_turns / self.tpr as f64) // (ticks * TAU) / tpr
+    }
+}
+
 #[class_methods]
 impl AdiEncoderObj {
     /// Creates a new encoder with a given `tpr` from `top_port` and `bottom_port`.
@@ -191,9 +199,7 @@ impl AdiEncoderObj {
     /// - `DeviceError`: If the associated ADI expander is disconnected or is the wrong device type.
     #[method]
     fn get_position(&self, unit: &RotationUnitObj) -> Result<f32, Exception> {
-        let tick_turns = self.encoder.borrow().position()?; // ticks * TAU
-        let position = tick_turns / self.tpr as f64; // (ticks * TAU) / tpr
-        Ok(unit.unit().angle_to_float(position))
+        Ok(unit.unit().angle_to_float(self.position()?))
     }
 
     /// Sets the current encoder position to the given position without any actual movement.
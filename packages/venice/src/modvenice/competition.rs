@@ -119,7 +119,8 @@ pub struct Competition {
 ///
 /// Users receive this type from `Competition.run`; it cannot be constructed directly. Its awaitable
 /// protocol is designed for the `vasyncio` event loop and understands `vasyncio.Sleep` and task objects
-/// returned by `vasyncio.spawn` when phase routines await them.
+/// returned by `vasyncio.spawn` when phase routines await them. Other `vasyncio` awaitables, such as
+/// the one returned by `vasyncio.wait_for`, are polled again each time the runtime is resumed.
 ///
 /// # Raises
 ///
@@ -331,8 +332,10 @@ impl CompetitionRuntime {
                         return Ok(Obj::NONE);
                     }
 
-                    // Unknown wake signals already cause the event loop to requeue the outer task.
-                    return Ok(result.obj);
+                    // Other awaitables re-check their own state whenever they are resumed. Yield a
+                    // generic wake signal instead of forwarding them so the event loop doesn't park
+                    // the outer task and competition status stays responsive.
+                    return Ok(Obj::NONE);
                 }
                 VmReturnKind::Normal => {
                     self.clear_phase_routine();
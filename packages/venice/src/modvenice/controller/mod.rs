@@ -22,8 +22,13 @@ use crate::{
     alloc::Gc,
     devices,
     modvenice::{
-        Exception, controller::id::ControllerIdObj, device_error, read_only_attr::read_only_attr,
+        Exception,
+        controller::id::ControllerIdObj,
+        device_error,
+        read_only_attr::read_only_attr,
+        vasyncio::{stream::SampleStream, time32},
     },
+    obj::alloc_obj,
     registry::ControllerGuard,
 };
 
@@ -364,6 +369,48 @@ impl ControllerObj {
         Ok(ControllerStateObj::new(state))
     }
 
+    /// Returns an asynchronous iterator over the controller's state.
+    ///
+    /// Each iteration of an `async for` loop waits for the next controller update, which VEXos
+    /// delivers every `Controller.UPDATE_INTERVAL_MS` milliseconds, then produces the same
+    /// `ControllerState` that `Controller.read_state` would return.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// controller = Controller()
+    /// left = Motor(1)
+    /// right = Motor(2, Direction.REVERSE)
+    ///
+    /// async def main():
+    ///     async for state in controller.states():
+    ///         left.set_voltage(state.left_stick.y * 12)
+    ///         right.set_voltage(state.right_stick.y * 12)
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    ///
+    /// # Raises
+    ///
+    /// While iterating:
+    ///
+    /// - `ValueError`: If the controller binding has been freed.
+    /// - `DeviceError`: If access to controller data is restricted by competition control, or the
+    ///   controller is not connected.
+    #[method]
+    fn states(self_in: Obj) -> Result<SampleStream, Exception> {
+        SampleStream::device(
+            |controller| {
+                let state = controller.as_obj::<Self>().guard.borrow().state()?;
+                Ok(alloc_obj(ControllerStateObj::new(state)))
+            },
+            self_in,
+            time32::Duration::from_duration(Controller::UPDATE_INTERVAL),
+        )
+    }
+
     /// Returns the controller's connection type as a root-importable `ControllerConnection`.
     ///
     /// # Examples
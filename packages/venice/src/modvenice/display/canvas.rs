@@ -0,0 +1,585 @@
+use std::{cell::RefCell, f32::consts::TAU, fmt::Write, ptr};
+
+use argparse::{Args, error_msg};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    buffer::Buffer,
+    except::{type_error, value_error},
+    init::token,
+    map::Map,
+    obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
+    print::{Print, PrintKind},
+    qstr::Qstr,
+};
+use venice_core::{
+    image::Bitmap,
+    raster::{Framebuffer, Paint, Shape},
+};
+use vexide_devices::color::Color;
+
+use super::{
+    buffer_pixel_count,
+    image::{ImageObj, Region, checked_region},
+    shapes::{DISPLAY_SIZE, ShapeKind, argb, draw_row, draw_shape_args},
+};
+use crate::{
+    alloc::Gc,
+    devices::lock_display,
+    modvenice::{Exception, color::ColorObj, read_only_attr::read_only_attr},
+};
+
+/// An image in memory that can be drawn on and then drawn on the display, available as
+/// `display.Canvas`.
+///
+/// Canvases have the same drawing functions as the `display` module, as methods, except for
+/// `draw_text`, since text is rendered by the display itself. Unlike on the display, shapes drawn
+/// with `antialias=True` are blended with whatever is already on the canvas, so they don't take a
+/// `bg_color`.
+///
+/// Every pixel has an alpha channel, and a new canvas is fully transparent. `blit` draws one
+/// canvas or `Image` on another, blending the pixels that are partly transparent, which lets a
+/// sprite be drawn over a background drawn once ahead of time. `draw` puts the canvas on the
+/// display, leaving its fully transparent pixels undrawn. With
+/// `display.RenderMode.DOUBLE_BUFFERED`, composing each frame on a canvas and drawing it in one go
+/// keeps moving sprites from flickering.
+///
+/// Canvases are held in memory at four bytes per pixel, so a full-screen 480 by 240 canvas takes
+/// 460800 bytes. The read-only `width` and `height` attributes are the canvas's size in pixels.
+/// Canvases print as `Canvas(width=..., height=...)`.
+///
+/// # Examples
+///
+/// Draw a robot moving over a field map:
+///
+/// ```python
+/// from venice import *
+///
+/// field = display.Canvas(240, 240, color=Color(40, 40, 40))
+/// for i in range(1, 6):
+///     field.draw_line(i * 40, 0, i * 40, 239, Color.GRAY)
+///     field.draw_line(0, i * 40, 239, i * 40, Color.GRAY)
+///
+/// robot = display.Canvas(21, 21)
+/// robot.fill_circle(10, 10, 10, Color.BLUE)
+/// robot.draw_line(10, 10, 20, 10, Color.WHITE, thickness=3)
+///
+/// frame = display.Canvas(240, 240)
+/// display.set_render_mode(display.RenderMode.DOUBLE_BUFFERED)
+///
+/// async def main():
+///     ticker = vasyncio.Ticker(20, MILLIS)
+///     x = 0
+///     while True:
+///         await ticker
+///         frame.blit(field, 0, 0)
+///         frame.blit(robot, x - 10, 110)
+///         frame.draw(120, 0)
+///         display.render()
+///         x = (x + 1) % 240
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(Canvas))]
+#[repr(C)]
+pub struct CanvasObj {
+    base: ObjBase,
+    framebuffer: RefCell<Framebuffer<Gc>>,
+}
+
+impl CanvasObj {
+    pub fn draw_shape(&self, shape: Shape, paint: Paint, antialias: bool) {
+        self.framebuffer.borrow_mut().draw(shape, paint, antialias);
+    }
+
+    fn size(&self) -> [usize; 2] {
+        let framebuffer = self.framebuffer.borrow();
+        [framebuffer.width(), framebuffer.height()]
+    }
+
+    /// Fills a rectangle with an opaque color, clipped to the canvas.
+    fn fill(&self, x: i16, y: i16, width: u16, height: u16, color: Color) {
+        let color = argb(color);
+        self.framebuffer.borrow_mut().composite(
+            [i32::from(x), i32::from(y)],
+            [usize::from(width), usize::from(height)],
+            None,
+            |_, _| color,
+        );
+    }
+}
+
+/// Something that can be drawn on a canvas with `Canvas.blit`.
+enum Source<'a> {
+    Image(&'a Bitmap<Gc>),
+    Canvas(&'a CanvasObj),
+}
+
+fn parse_circle_args(args: &[Obj]) -> Result<(&CanvasObj, i16, i16, u16, &ColorObj), Exception> {
+    let mut reader = Args::new(5, 0, args).reader();
+    let canvas = reader.next_positional()?;
+    let x = reader.next_positional()?;
+    let y = reader.next_positional()?;
+    let radius = reader.next_positional()?;
+    let color = reader.next_positional::<&ColorObj>()?;
+    Ok((canvas, x, y, radius, color))
+}
+
+fn parse_rect_args(args: &[Obj]) -> Result<(&CanvasObj, i16, i16, u16, u16, &ColorObj), Exception> {
+    let mut reader = Args::new(6, 0, args).reader();
+    let canvas = reader.next_positional()?;
+    let x = reader.next_positional()?;
+    let y = reader.next_positional()?;
+    let width = reader.next_positional()?;
+    let height = reader.next_positional()?;
+    let color = reader.next_positional::<&ColorObj>()?;
+    Ok((canvas, x, y, width, height, color))
+}
+
+#[class_methods]
+impl CanvasObj {
+    /// Creates a canvas that's `width` by `height` pixels, filled with `color`, or fully
+    /// transparent if `color` is `None`.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `width` or `height` is zero.
+    #[make_new]
+    #[stub(sig = "(self, width: int, height: int, /, *, color: Color | None = None) -> None")]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(2, 2).assert_nkw(0, 1);
+
+        let width = reader.next_positional::<u16>()?;
+        let height = reader.next_positional::<u16>()?;
+        if width == 0 || height == 0 {
+            Err(value_error(c"width and height must be at least 1"))?
+        }
+
+        let mut color = None;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "color" => {
+                    color = if arg.obj.is_none() {
+                        None
+                    } else {
+                        Some(arg.parse::<&ColorObj>()?.color())
+                    }
+                }
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        let mut framebuffer = Framebuffer::new(
+            usize::from(width),
+            usize::from(height),
+            Gc { token: token() },
+        );
+        if let Some(color) = color {
+            framebuffer.clear(argb(color));
+        }
+        Ok(Self {
+            base: ty.into(),
+            framebuffer: RefCell::new(framebuffer),
+        })
+    }
+
+    #[attr]
+    #[stub(attrs = ["width: int", "height: int"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+        let [width, height] = self.size();
+        result.return_value(match attr.as_str() {
+            "width" => width as i32,
+            "height" => height as i32,
+            _ => return,
+        });
+    }
+
+    /// Fills the whole canvas with `color`.
+    #[method]
+    fn erase(&self, color: &ColorObj) {
+        self.framebuffer.borrow_mut().clear(argb(color.color()));
+    }
+
+    /// Makes the whole canvas fully transparent.
+    #[method]
+    fn clear(&self) {
+        self.framebuffer.borrow_mut().clear(0);
+    }
+
+    /// Like `display.draw_pixel`, but draws on the canvas.
+    #[method(ty = var_between(min = 4, max = 4))]
+    #[stub(sig = "(self, x: int, y: int, color: Color, /) -> None")]
+    fn draw_pixel(args: &[Obj]) -> Result<(), Exception> {
+        let mut reader = Args::new(4, 0, args).reader();
+        let canvas = reader.next_positional::<&Self>()?;
+        let x = reader.next_positional()?;
+        let y = reader.next_positional()?;
+        let color = reader.next_positional::<&ColorObj>()?;
+        canvas.fill(x, y, 1, 1, color.color());
+        Ok(())
+    }
+
+    /// Like `display.draw_line`, but draws on the canvas.
+    ///
+    /// Lines on a canvas are always rasterized, even when they're one pixel wide and not
+    /// anti-aliased.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    /// - `ValueError`: If `thickness` is zero.
+    #[method(ty = kw(min = 6))]
+    #[stub(
+        sig = "(self, start_x: int, start_y: int, end_x: int, end_y: int, color: Color | LinearGradient, /, *, thickness: int = 1, antialias: bool = False) -> None"
+    )]
+    fn draw_line(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        draw_shape_args(args, kw_map, ShapeKind::Line, true, true)
+    }
+
+    /// Like `display.draw_circle`, but draws on the canvas.
+    #[method(ty = var_between(min = 5, max = 5))]
+    #[stub(sig = "(self, x: int, y: int, radius: int, color: Color, /) -> None")]
+    fn draw_circle(args: &[Obj]) -> Result<(), Exception> {
+        let (canvas, x, y, radius, color) = parse_circle_args(args)?;
+        canvas.draw_shape(
+            Shape::Arc {
+                center: [f32::from(x) + 0.5, f32::from(y) + 0.5],
+                radius: f32::from(radius),
+                start: 0.0,
+                sweep: TAU,
+                width: 1.0,
+            },
+            Paint::Solid(argb(color.color())),
+            false,
+        );
+        Ok(())
+    }
+
+    /// Like `display.fill_circle`, but draws on the canvas.
+    #[method(ty = var_between(min = 5, max = 5))]
+    #[stub(sig = "(self, x: int, y: int, radius: int, color: Color, /) -> None")]
+    fn fill_circle(args: &[Obj]) -> Result<(), Exception> {
+        let (canvas, x, y, radius, color) = parse_circle_args(args)?;
+        canvas.draw_shape(
+            Shape::Pie {
+                center: [f32::from(x) + 0.5, f32::from(y) + 0.5],
+                radius: f32::from(radius),
+                start: 0.0,
+                sweep: TAU,
+            },
+            Paint::Solid(argb(color.color())),
+            false,
+        );
+        Ok(())
+    }
+
+    /// Like `display.draw_rect`, but draws on the canvas.
+    #[method(ty = var_between(min = 6, max = 6))]
+    #[stub(sig = "(self, x: int, y: int, width: int, height: int, color: Color, /) -> None")]
+    fn draw_rect(args: &[Obj]) -> Result<(), Exception> {
+        let (canvas, x, y, width, height, color) = parse_rect_args(args)?;
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+
+        let color = color.color();
+        let right = x.saturating_add_unsigned(width - 1);
+        let bottom = y.saturating_add_unsigned(height - 1);
+        canvas.fill(x, y, width, 1, color);
+        canvas.fill(x, bottom, width, 1, color);
+        canvas.fill(x, y, 1, height, color);
+        canvas.fill(right, y, 1, height, color);
+        Ok(())
+    }
+
+    /// Like `display.fill_rect`, but draws on the canvas.
+    #[method(ty = var_between(min = 6, max = 6))]
+    #[stub(sig = "(self, x: int, y: int, width: int, height: int, color: Color, /) -> None")]
+    fn fill_rect(args: &[Obj]) -> Result<(), Exception> {
+        let (canvas, x, y, width, height, color) = parse_rect_args(args)?;
+        canvas.fill(x, y, width, height, color.color());
+        Ok(())
+    }
+
+    /// Like `display.draw_buffer`, but draws on the canvas.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `buffer` doesn't support the readable buffer protocol.
+    /// - `ValueError`: If the buffer is unaligned, its readable byte length isn't a multiple of
+    ///   four, or its pixel count doesn't equal `width * height`.
+    #[method(ty = var_between(min = 6, max = 6))]
+    #[stub(sig = "(self, x: int, y: int, width: int, height: int, buffer: Any, /) -> None")]
+    fn draw_buffer(args: &[Obj]) -> Result<(), Exception> {
+        let mut reader = Args::new(6, 0, args).reader();
+        let canvas = reader.next_positional::<&Self>()?;
+        let x = reader.next_positional::<i16>()?;
+        let y = reader.next_positional::<i16>()?;
+        let width = reader.next_positional::<u16>()?;
+        let height = reader.next_positional::<u16>()?;
+        let buffer = reader.next_positional::<Buffer<'_, Color>>()?;
+
+        let (width, height) = (usize::from(width), usize::from(height));
+        let expected_len = buffer_pixel_count(width, height)
+            .ok_or_else(|| value_error(c"canvas buffer dimensions overflow"))?;
+        let actual_len = buffer.buffer().len();
+        if actual_len != expected_len {
+            return Err(value_error(error_msg!(
+                "buffer contains {actual_len} pixels, expected {expected_len} for a {width} by {height} region"
+            ))
+            .into());
+        }
+
+        let pixels = buffer.buffer();
+        canvas.framebuffer.borrow_mut().composite(
+            [i32::from(x), i32::from(y)],
+            [width, height],
+            None,
+            |x, y| argb(pixels[y * width + x]),
+        );
+        Ok(())
+    }
+
+    /// Like `display.draw_polygon`, but draws on the canvas.
+    #[method(ty = kw(min = 3))]
+    #[stub(
+        sig = "(self, points: list[tuple[int, int]] | tuple[tuple[int, int], ...], color: Color | LinearGradient, /, *, thickness: int = 1, antialias: bool = False) -> None"
+    )]
+    fn draw_polygon(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        draw_shape_args(args, kw_map, ShapeKind::Polygon, true, true)
+    }
+
+    /// Like `display.fill_polygon`, but draws on the canvas.
+    #[method(ty = kw(min = 3))]
+    #[stub(
+        sig = "(self, points: list[tuple[int, int]] | tuple[tuple[int, int], ...], color: Color | LinearGradient, /, *, antialias: bool = False) -> None"
+    )]
+    fn fill_polygon(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        draw_shape_args(args, kw_map, ShapeKind::Polygon, false, true)
+    }
+
+    /// Like `display.draw_triangle`, but draws on the canvas.
+    #[method(ty = kw(min = 8))]
+    #[stub(
+        sig = "(self, x0: int, y0: int, x1: int, y1: int, x2: int, y2: int, color: Color | LinearGradient, /, *, thickness: int = 1, antialias: bool = False) -> None"
+    )]
+    fn draw_triangle(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        draw_shape_args(args, kw_map, ShapeKind::Triangle, true, true)
+    }
+
+    /// Like `display.fill_triangle`, but draws on the canvas.
+    #[method(ty = kw(min = 8))]
+    #[stub(
+        sig = "(self, x0: int, y0: int, x1: int, y1: int, x2: int, y2: int, color: Color | LinearGradient, /, *, antialias: bool = False) -> None"
+    )]
+    fn fill_triangle(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        draw_shape_args(args, kw_map, ShapeKind::Triangle, false, true)
+    }
+
+    /// Like `display.draw_arc`, but draws on the canvas.
+    #[method(ty = kw(min = 7))]
+    #[stub(
+        sig = "(self, x: int, y: int, radius: int, start: float, end: float, color: Color | LinearGradient, /, *, thickness: int = 1, antialias: bool = False) -> None"
+    )]
+    fn draw_arc(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        draw_shape_args(args, kw_map, ShapeKind::Arc, true, true)
+    }
+
+    /// Like `display.fill_pie`, but draws on the canvas.
+    #[method(ty = kw(min = 7))]
+    #[stub(
+        sig = "(self, x: int, y: int, radius: int, start: float, end: float, color: Color | LinearGradient, /, *, antialias: bool = False) -> None"
+    )]
+    fn fill_pie(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        draw_shape_args(args, kw_map, ShapeKind::Arc, false, true)
+    }
+
+    /// Like `display.draw_round_rect`, but draws on the canvas.
+    #[method(ty = kw(min = 7))]
+    #[stub(
+        sig = "(self, x: int, y: int, width: int, height: int, radius: int, color: Color | LinearGradient, /, *, thickness: int = 1, antialias: bool = False) -> None"
+    )]
+    fn draw_round_rect(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        draw_shape_args(args, kw_map, ShapeKind::RoundRect, true, true)
+    }
+
+    /// Like `display.fill_round_rect`, but draws on the canvas.
+    #[method(ty = kw(min = 7))]
+    #[stub(
+        sig = "(self, x: int, y: int, width: int, height: int, radius: int, color: Color | LinearGradient, /, *, antialias: bool = False) -> None"
+    )]
+    fn fill_round_rect(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        draw_shape_args(args, kw_map, ShapeKind::RoundRect, false, true)
+    }
+
+    /// Draws `source`, a `Canvas` or `Image`, on this canvas with its top-left corner at (`x`,
+    /// `y`).
+    ///
+    /// `region`, as `(x, y, width, height)` in the source's pixels, draws only that part of it,
+    /// which copies part of a canvas to another place on itself if `source` is this canvas.
+    /// Pixels are blended over the canvas by their alpha, scaled by `alpha` out of 255 so that the
+    /// whole source can be faded. With `blend=False`, pixels are instead copied as they are,
+    /// transparency included, and `alpha` is ignored. Anything that falls outside the canvas is
+    /// clipped.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `source` isn't a `Canvas` or `Image`, or `region` isn't a tuple of four
+    ///   integers.
+    /// - `ValueError`: If `region` is empty or extends outside the source, or `alpha` is outside
+    ///   0 to 255.
+    #[method(ty = kw(min = 4))]
+    #[stub(
+        sig = "(self, source: Canvas | Image, x: int, y: int, /, *, region: tuple[int, int, int, int] | None = None, alpha: int = 255, blend: bool = True) -> None"
+    )]
+    fn blit(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        let kwarg_count = kw_map.len();
+        let positional_count = args.len() - kwarg_count * 2;
+        let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+        reader.assert_npos(4, 4).assert_nkw(0, 3);
+
+        let canvas = reader.next_positional::<&Self>()?;
+        let source = reader.next_positional::<Obj>()?;
+        let position = [
+            i32::from(reader.next_positional::<i16>()?),
+            i32::from(reader.next_positional::<i16>()?),
+        ];
+
+        let source = if let Some(image) = source.try_as_obj::<ImageObj>() {
+            Source::Image(image.bitmap())
+        } else if let Some(source_canvas) = source.try_as_obj::<Self>() {
+            Source::Canvas(source_canvas)
+        } else {
+            Err(type_error(c"expected a Canvas or Image"))?
+        };
+        let size = match source {
+            Source::Image(bitmap) => [bitmap.width(), bitmap.height()],
+            Source::Canvas(source_canvas) => source_canvas.size(),
+        };
+
+        let mut region = Region {
+            x: 0,
+            y: 0,
+            width: size[0],
+            height: size[1],
+        };
+        let mut alpha = 255;
+        let mut blend = true;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "region" => {
+                    if !arg.obj.is_none() {
+                        region = checked_region(*arg.obj, size)?;
+                    }
+                }
+                "alpha" => alpha = u32::from(arg.parse::<u8>()?),
+                "blend" => blend = arg.parse()?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        let opacity = blend.then_some(alpha);
+        let region_size = [region.width, region.height];
+        let mut framebuffer = canvas.framebuffer.borrow_mut();
+        match source {
+            Source::Image(bitmap) => {
+                framebuffer.composite(position, region_size, opacity, |x, y| {
+                    bitmap.pixel(region.x + x, region.y + y)
+                });
+            }
+            Source::Canvas(source_canvas) if ptr::eq(source_canvas, canvas) => {
+                framebuffer.copy_within([region.x, region.y], position, region_size, opacity);
+            }
+            Source::Canvas(source_canvas) => {
+                let source = source_canvas.framebuffer.borrow();
+                framebuffer.composite(position, region_size, opacity, |x, y| {
+                    source.row(region.y + y)[region.x + x]
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Draws the canvas with its top-left corner at (`x`, `y`) on the display.
+    ///
+    /// `region`, as `(x, y, width, height)` in the canvas's pixels, draws only that part of the
+    /// canvas. Fully transparent pixels are left undrawn. Since the display can't be read back,
+    /// partly transparent pixels are blended with `bg_color`, which should be the color behind
+    /// them. Anything that falls outside the display is clipped.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `region` isn't a tuple of four integers.
+    /// - `ValueError`: If `region` is empty or extends outside the canvas.
+    #[method(ty = kw(min = 3))]
+    #[stub(
+        sig = "(self, x: int, y: int, /, *, region: tuple[int, int, int, int] | None = None, bg_color: Color = Color.BLACK) -> None"
+    )]
+    fn draw(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        let kwarg_count = kw_map.len();
+        let positional_count = args.len() - kwarg_count * 2;
+        let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+        reader.assert_npos(3, 3).assert_nkw(0, 2);
+
+        let canvas = reader.next_positional::<&Self>()?;
+        let x = i32::from(reader.next_positional::<i16>()?);
+        let y = i32::from(reader.next_positional::<i16>()?);
+
+        let size = canvas.size();
+        let mut region = Region {
+            x: 0,
+            y: 0,
+            width: size[0],
+            height: size[1],
+        };
+        let mut bg_color = Color::BLACK;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "region" => {
+                    if !arg.obj.is_none() {
+                        region = checked_region(*arg.obj, size)?;
+                    }
+                }
+                "bg_color" => bg_color = arg.parse::<&ColorObj>()?.color(),
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        // Visible columns and rows, relative to the region
+        let columns = (-x).max(0)..(region.width as i32).min(DISPLAY_SIZE[0] - x);
+        let rows = (-y).max(0)..(region.height as i32).min(DISPLAY_SIZE[1] - y);
+        if columns.is_empty() {
+            return Ok(());
+        }
+
+        let background = argb(bg_color);
+        let framebuffer = canvas.framebuffer.borrow();
+        let mut display = lock_display();
+        for row in rows {
+            let pixels = framebuffer.row(region.y + row as usize);
+            let visible =
+                &pixels[region.x + columns.start as usize..region.x + columns.end as usize];
+            draw_row(
+                &mut display,
+                [x + columns.start, y + row],
+                visible,
+                background,
+            );
+        }
+        Ok(())
+    }
+
+    #[printer]
+    fn printer(&self, print: &mut Print, _kind: PrintKind) {
+        let [width, height] = self.size();
+        let _ = write!(print, "Canvas(width={width}, height={height})");
+    }
+}
@@ -0,0 +1,329 @@
+use std::{alloc::Allocator, fmt::Write};
+
+use argparse::{Args, error_msg};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    errno::MP_ENOMEM,
+    except::{memory_error, raise_os_error, type_error, value_error},
+    init::token,
+    map::Map,
+    obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
+    print::{Print, PrintKind},
+    qstr::Qstr,
+};
+use venice_core::image::{Bitmap, DecodeError, decode};
+use vexide_devices::color::Color;
+
+use super::checked_rect;
+use crate::{
+    alloc::Gc,
+    devices::lock_display,
+    exports::fs::FileObj,
+    modvenice::{
+        Exception, color::ColorObj, read_only_attr::read_only_attr, validate::check_positive,
+    },
+};
+
+/// The size of the writable part of the display, which images are clipped to.
+const DISPLAY_SIZE: [i32; 2] = [480, 240];
+
+impl From<DecodeError> for Exception {
+    fn from(value: DecodeError) -> Self {
+        match value {
+            DecodeError::OutOfMemory => Self(memory_error(error_msg!("{value}"))),
+            _ => Self(value_error(error_msg!("{value}"))),
+        }
+    }
+}
+
+/// A rectangle of an image's pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// How to draw an image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placement {
+    /// The part of the image to draw.
+    pub region: Region,
+    /// Where the region's top-left corner goes.
+    pub x: i32,
+    pub y: i32,
+    pub scale: f64,
+    /// A color to leave undrawn, as `0xRRGGBB`.
+    pub key: Option<u32>,
+}
+
+/// Draws `placement` of `bitmap` within a `width` by `height` surface, by passing each horizontal
+/// run of visible, opaque pixels to `draw_run` with the surface position of its first pixel.
+///
+/// The image is scaled by nearest-neighbor sampling. Pixels that are less than half opaque or that
+/// match the key are left undrawn.
+pub fn draw_bitmap<A: Allocator>(
+    bitmap: &Bitmap<A>,
+    placement: Placement,
+    [width, height]: [i32; 2],
+    mut draw_run: impl FnMut(i32, i32, &[Color]),
+) {
+    let Placement {
+        region,
+        x,
+        y,
+        scale,
+        key,
+    } = placement;
+    let scaled_width = (region.width as f64 * scale).round() as i32;
+    let scaled_height = (region.height as f64 * scale).round() as i32;
+
+    // Visible columns and rows, relative to the placement
+    let columns = (-x).max(0)..scaled_width.min(width - x);
+    let rows = (-y).max(0)..scaled_height.min(height - y);
+    let source = |offset: i32, start: usize, size: usize| {
+        start + (((offset as f64 + 0.5) / scale) as usize).min(size - 1)
+    };
+
+    let mut run = Vec::with_capacity(columns.len());
+    for row in rows {
+        let source_y = source(row, region.y, region.height);
+        let mut run_start = columns.start;
+        for column in columns.clone() {
+            let pixel = bitmap.pixel(source(column, region.x, region.width), source_y);
+            let rgb = pixel & 0x00ff_ffff;
+            if pixel >> 24 < 0x80 || key == Some(rgb) {
+                if !run.is_empty() {
+                    draw_run(x + run_start, y + row, &run);
+                    run.clear();
+                }
+                run_start = column + 1;
+            } else {
+                run.push(Color::new((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8));
+            }
+        }
+        if !run.is_empty() {
+            draw_run(x + run_start, y + row, &run);
+            run.clear();
+        }
+    }
+}
+
+/// A decoded picture that can be drawn on the display, available as `display.Image`.
+///
+/// Images are decoded from PNG, BMP or QOI data, recognized from their first bytes. Every PNG
+/// color type, bit depth and interlacing method is supported, as are uncompressed BMPs with 1, 4,
+/// 8, 16, 24 or 32 bits per pixel. Images can have at most 460800 pixels, four times as many as
+/// the 480 by 240 display, though a full-screen image is usually as large as is useful.
+///
+/// Decoded images are held in memory at four bytes per pixel, whatever their format. QOI decodes
+/// the fastest and PNG files are the smallest to bundle.
+///
+/// The read-only `width` and `height` attributes are the image's size in pixels. Images print as
+/// `Image(width=..., height=...)`.
+///
+/// # Examples
+///
+/// Draw a logo bundled with the program in the middle of the display:
+///
+/// ```python
+/// from venice import *
+///
+/// logo = display.Image(open("logo.png", "rb"))
+/// logo.draw(240 - logo.width // 2, 120 - logo.height // 2)
+/// ```
+///
+/// Draw the second of a row of 32 by 32 icons at double size, leaving magenta pixels undrawn:
+///
+/// ```python
+/// from venice import *
+///
+/// icons = display.Image(open("icons.bmp", "rb"))
+/// icons.draw(10, 10, region=(32, 0, 32, 32), scale=2.0, key=Color(255, 0, 255))
+/// ```
+#[class(qstr!(Image))]
+#[repr(C)]
+pub struct ImageObj {
+    base: ObjBase,
+    bitmap: Bitmap<Gc>,
+}
+
+impl ImageObj {
+    pub fn bitmap(&self) -> &Bitmap<Gc> {
+        &self.bitmap
+    }
+}
+
+#[class_methods]
+impl ImageObj {
+    /// Decodes an image from `source`, which is a bytes-like object holding the image's data or a
+    /// file opened with `open`.
+    ///
+    /// A file is read from its current position to its end.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `source` isn't a bytes-like object or a file.
+    /// - `ValueError`: If the data isn't a PNG, BMP or QOI image, is corrupt or truncated, uses a
+    ///   feature that isn't supported, or has too many pixels.
+    /// - `MemoryError`: If there isn't enough memory to read or decode the image.
+    /// - `OSError`: If the file can't be read.
+    #[make_new]
+    #[stub(sig = "(self, source: bytes | bytearray | memoryview | IO[bytes], /) -> None")]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(1, 1).assert_nkw(0, 0);
+
+        let source = reader.next_positional::<Obj>()?;
+        let bitmap = match source.try_as_obj::<FileObj>() {
+            Some(file) => {
+                let data = match file.read_to_end(Gc { token: token() }) {
+                    Ok(data) => data,
+                    Err(MP_ENOMEM) => Err(memory_error(c"not enough memory to read the image"))?,
+                    Err(errno) => raise_os_error(token(), errno),
+                };
+                decode(&data, Gc { token: token() })?
+            }
+            None => {
+                let data = source
+                    .buffer()
+                    .map_err(|_| type_error(c"expected a bytes-like object or a file"))?;
+                decode(data.buffer(), Gc { token: token() })?
+            }
+        };
+
+        Ok(Self {
+            base: ty.into(),
+            bitmap,
+        })
+    }
+
+    #[attr]
+    #[stub(attrs = ["width: int", "height: int"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+        result.return_value(match attr.as_str() {
+            "width" => self.bitmap.width() as i32,
+            "height" => self.bitmap.height() as i32,
+            _ => return,
+        });
+    }
+
+    /// Draws the image with its top-left corner at (`x`, `y`) on the display.
+    ///
+    /// `region`, as `(x, y, width, height)` in the image's pixels, draws only that part of the
+    /// image, such as one icon from a sheet of them. `scale` stretches or shrinks the image,
+    /// picking the nearest pixel rather than blending them. Pixels that are less than half
+    /// opaque, or that match `key`, are left undrawn so that whatever is behind them shows
+    /// through. Anything that falls outside the display is clipped.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `region` isn't a tuple of four integers or `key` isn't a `Color`.
+    /// - `ValueError`: If `region` is empty or extends outside the image, or `scale` isn't
+    ///   positive and finite.
+    #[method(ty = kw(min = 3))]
+    #[stub(
+        sig = "(self, x: int, y: int, /, *, region: tuple[int, int, int, int] | None = None, scale: float = 1.0, key: Color | None = None) -> None"
+    )]
+    fn draw(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+        let kwarg_count = kw_map.len();
+        let positional_count = args.len() - kwarg_count * 2;
+        let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+        reader.assert_npos(3, 3).assert_nkw(0, 3);
+
+        let image = reader.next_positional::<&Self>()?;
+        let x = reader.next_positional::<i16>()?;
+        let y = reader.next_positional::<i16>()?;
+
+        let bitmap = &image.bitmap;
+        let mut placement = Placement {
+            region: Region {
+                x: 0,
+                y: 0,
+                width: bitmap.width(),
+                height: bitmap.height(),
+            },
+            x: x as i32,
+            y: y as i32,
+            scale: 1.0,
+            key: None,
+        };
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "region" => {
+                    if !arg.obj.is_none() {
+                        placement.region =
+                            checked_region(*arg.obj, [bitmap.width(), bitmap.height()])?;
+                    }
+                }
+                "scale" => placement.scale = check_positive(arg.parse::<f32>()?, arg.kw)?.into(),
+                "key" => {
+                    placement.key = if arg.obj.is_none() {
+                        None
+                    } else {
+                        Some(arg.parse::<&ColorObj>()?.color().into_raw())
+                    }
+                }
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        let mut display = lock_display();
+        draw_bitmap(bitmap, placement, DISPLAY_SIZE, |x, y, run| {
+            // Runs lie within the display, so their corners fit the display's coordinates
+            if let Ok(region) = checked_rect(x as i16, y as i16, run.len() as u16, 1) {
+                display.draw_buffer(region, run);
+            }
+        });
+        Ok(())
+    }
+
+    #[printer]
+    fn printer(&self, print: &mut Print, _kind: PrintKind) {
+        let _ = write!(
+            print,
+            "Image(width={}, height={})",
+            self.bitmap.width(),
+            self.bitmap.height()
+        );
+    }
+}
+
+/// Parses an `(x, y, width, height)` tuple as a region of an image that's `size` pixels.
+pub fn checked_region(obj: Obj, size: [usize; 2]) -> Result<Region, Exception> {
+    let items = obj
+        .try_array()
+        .ok_or_else(|| type_error(c"expected a tuple of (x, y, width, height)"))?;
+    let [x, y, width, height] = items[..] else {
+        Err(type_error(c"expected a tuple of (x, y, width, height)"))?
+    };
+
+    let mut values = [0usize; 4];
+    for (value, obj) in values.iter_mut().zip([x, y, width, height]) {
+        let int = obj
+            .try_to_int()
+            .ok_or_else(|| type_error(c"region values must be integers"))?;
+        *value =
+            usize::try_from(int).map_err(|_| value_error(c"region values cannot be negative"))?;
+    }
+
+    let [x, y, width, height] = values;
+    if width == 0 || height == 0 || x + width > size[0] || y + height > size[1] {
+        Err(value_error(c"region must be a non-empty part of the image"))?
+    }
+    Ok(Region {
+        x,
+        y,
+        width,
+        height,
+    })
+}
@@ -1,3 +1,8 @@
+mod canvas;
+mod image;
+mod plot;
+mod shapes;
+
 use std::fmt::Write;
 
 use argparse::{Args, PositionalError, error_msg};
@@ -12,6 +17,7 @@ use micropython_rs::{
     print::{Print, PrintKind, StringPrint},
     qstr::Qstr,
 };
+use venice_core::raster::Shape;
 use vex_sdk_jumptable::{vexDisplayForegroundColor, vexDisplayLineDraw};
 use vexide_devices::{
     color::Color,
@@ -19,6 +25,16 @@ use vexide_devices::{
     math::Point2,
 };
 
+use self::{
+    canvas::CanvasObj,
+    image::ImageObj,
+    plot::PlotObj,
+    shapes::{
+        LinearGradientObj, Style, draw_arc_obj, draw_on_display, draw_polygon_obj,
+        draw_round_rect_obj, draw_triangle_obj, fill_pie_obj, fill_polygon_obj,
+        fill_round_rect_obj, fill_triangle_obj, parse_paint,
+    },
+};
 use crate::{
     devices::lock_display,
     modvenice::{Exception, color::ColorObj, read_only_attr::read_only_attr},
@@ -32,6 +48,10 @@ pub const DISPLAY_DICT: &Dict = const_dict![
     qstr!(FontFamily) => Obj::from_static(FontFamilyObj::OBJ_TYPE),
     qstr!(FontSize) => Obj::from_static(FontSizeObj::OBJ_TYPE),
     qstr!(TouchEvent) => Obj::from_static(TouchEventObj::OBJ_TYPE),
+    qstr!(Image) => Obj::from_static(ImageObj::OBJ_TYPE),
+    qstr!(Plot) => Obj::from_static(PlotObj::OBJ_TYPE),
+    qstr!(LinearGradient) => Obj::from_static(LinearGradientObj::OBJ_TYPE),
+    qstr!(Canvas) => Obj::from_static(CanvasObj::OBJ_TYPE),
 
     // drawing
     qstr!(draw_pixel) => draw_pixel_obj,
@@ -42,6 +62,14 @@ pub const DISPLAY_DICT: &Dict = const_dict![
     qstr!(fill_rect) => fill_rect_obj,
     qstr!(draw_buffer) => draw_buffer_obj,
     qstr!(draw_text) => draw_text_obj,
+    qstr!(draw_polygon) => draw_polygon_obj,
+    qstr!(fill_polygon) => fill_polygon_obj,
+    qstr!(draw_triangle) => draw_triangle_obj,
+    qstr!(fill_triangle) => fill_triangle_obj,
+    qstr!(draw_arc) => draw_arc_obj,
+    qstr!(fill_pie) => fill_pie_obj,
+    qstr!(draw_round_rect) => draw_round_rect_obj,
+    qstr!(fill_round_rect) => fill_round_rect_obj,
     // scroll
     qstr!(scroll) => scroll_obj,
     qstr!(scroll_region) => scroll_region_obj,
@@ -245,16 +273,47 @@ fn draw_pixel(x: i16, y: i16, color: &ColorObj) {
 /// `start_x` and `start_y` are the start point of the line; `end_x` and `end_y` are the end point. The
 /// line width is one pixel. Coordinates are measured from the display's top-left, and both supplied
 /// endpoints are passed directly to the display SDK.
-#[fun(ty = var_between(min = 5, max = 5))]
-#[stub(sig = "(start_x: int, start_y: int, end_x: int, end_y: int, color: Color, /) -> None")]
-fn draw_line(args: &[Obj]) -> Result<(), Exception> {
-    let mut reader = Args::new(5, 0, args).reader();
+///
+/// With `thickness` above one or `antialias=True`, the line is instead rasterized like the other
+/// shapes, such as `display.draw_arc`: it's `thickness` pixels wide with rounded ends, and its
+/// anti-aliased edges are blended with `bg_color`. `color` can then also be a `LinearGradient`.
+///
+/// # Raises
+///
+/// - `TypeError`: If `color` isn't a `Color`, or a `LinearGradient` when the line is rasterized.
+/// - `ValueError`: If `thickness` is zero.
+#[fun(ty = kw(min = 5))]
+#[stub(
+    sig = "(start_x: int, start_y: int, end_x: int, end_y: int, color: Color | LinearGradient, /, *, thickness: int = 1, antialias: bool = False, bg_color: Color = Color.BLACK) -> None"
+)]
+fn draw_line(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+    let kwarg_count = kw_map.len();
+    let positional_count = args.len() - kwarg_count * 2;
+    let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+    reader.assert_npos(5, 5).assert_nkw(0, 3);
+
     let start_x = reader.next_positional::<i16>()?;
     let start_y = reader.next_positional::<i16>()?;
     let end_x = reader.next_positional::<i16>()?;
     let end_y = reader.next_positional::<i16>()?;
-    let color = reader.next_positional::<&ColorObj>()?;
+    let color = reader.next_positional::<Obj>()?;
+    let style = Style::parse(&mut reader, true)?;
+
+    if style.thickness > 1.0 || style.antialias {
+        let center = |x: i16, y: i16| [f32::from(x) + 0.5, f32::from(y) + 0.5];
+        let paint = parse_paint(color)?;
+        draw_thick_line(
+            center(start_x, start_y),
+            center(end_x, end_y),
+            paint,
+            &style,
+        );
+        return Ok(());
+    }
 
+    let Some(color) = color.try_as_obj::<ColorObj>() else {
+        Err(type_error(c"expected a Color for a one pixel wide line"))?
+    };
     let _display_guard = lock_display();
     unsafe {
         vexDisplayForegroundColor(color.color().into_raw());
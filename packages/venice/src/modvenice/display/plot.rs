@@ -0,0 +1,603 @@
+use std::{
+    cell::{Cell, RefCell},
+    ffi::CString,
+};
+
+use argparse::{Args, error_msg};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{type_error, value_error},
+    init::token,
+    obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
+    qstr::Qstr,
+};
+use vex_sdk_jumptable::{vexDisplayForegroundColor, vexDisplayLineDraw};
+use vexide_devices::{
+    color::Color,
+    display::{Display, Font, FontFamily, FontSize, Rect, Text},
+    math::Point2,
+};
+
+use super::checked_rect;
+use crate::{
+    alloc::Gc,
+    devices::lock_display,
+    modvenice::{Exception, color::ColorObj, read_only_attr::read_only_attr},
+};
+
+/// The height of the legend and axis labels above the plot area.
+const HEADER_HEIGHT: i16 = 36;
+/// The most channels a plot can have, which is as many as the legend has room for.
+const MAX_CHANNELS: usize = 8;
+/// The most gridlines a plot can have across its value axis.
+const MAX_GRID: u16 = 16;
+/// How far past a new extreme an automatic axis grows, as a fraction of its span.
+const AUTO_MARGIN: f32 = 0.1;
+
+const BORDER_COLOR: Color = Color::new(0x60, 0x60, 0x60);
+const GRID_COLOR: Color = Color::new(0x30, 0x30, 0x30);
+
+/// A scrolling strip chart of one or more channels of samples, available as `display.Plot`.
+///
+/// Every call to `push` records one sample per channel, and `draw` shows the samples pushed since
+/// it was last called. Time runs down the plot: the newest sample is on the bottom row, one row
+/// per sample, and values are plotted across its width. Since the display can only scroll
+/// vertically, this lets `draw` move the older samples up with `display.scroll_region` and draw
+/// just the new rows, so feeding a plot from a task at 50 Hz takes little time.
+///
+/// The plot keeps as many samples as it has rows, and older samples are discarded. Each channel is
+/// drawn in its own color and named in a legend at the top, above the ends of the value axis.
+///
+/// The value axis is fixed if `min` and `max` are given. Otherwise it scales automatically,
+/// growing whenever a sample falls outside of it, which redraws the whole plot. `NaN` samples leave
+/// a gap in their channel's line.
+///
+/// The read-only `min` and `max` attributes are the ends of the value axis, or `None` if it scales
+/// automatically and nothing has been pushed yet.
+///
+/// # Examples
+///
+/// Plot a flywheel's velocity against its target:
+///
+/// ```python
+/// from venice import *
+///
+/// flywheel = Motor(1)
+/// target = 3000.0
+/// plot = display.Plot(0, 0, 480, 240, channels=[("target", Color.GRAY), ("rpm", Color.LIME)])
+///
+/// async def main():
+///     ticker = vasyncio.Ticker(20, MILLIS)
+///     while True:
+///         await ticker
+///         plot.push(target, flywheel.get_velocity())
+///         plot.draw()
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(Plot))]
+#[repr(C)]
+pub struct PlotObj {
+    base: ObjBase,
+    bounds: Rect,
+    names: Vec<Obj, Gc>,
+    colors: Vec<Color, Gc>,
+    background: Color,
+    grid: u16,
+    fixed: Cell<bool>,
+    range: Cell<Option<(f32, f32)>>,
+    // one row of samples per channel for each row of the plot, filled as a ring
+    samples: RefCell<Vec<f32, Gc>>,
+    next_row: Cell<usize>,
+    len: Cell<usize>,
+    // the number of samples pushed since the last draw
+    pending: Cell<usize>,
+    full_redraw: Cell<bool>,
+}
+
+/// Returns the area of a plot at `bounds` that samples are plotted in, inside the border.
+fn plot_area(bounds: Rect) -> Rect {
+    Rect {
+        top_left: Point2 {
+            x: bounds.top_left.x + 1,
+            y: bounds.top_left.y + HEADER_HEIGHT + 1,
+        },
+        bottom_right: Point2 {
+            x: bounds.bottom_right.x - 1,
+            y: bounds.bottom_right.y - 1,
+        },
+    }
+}
+
+impl PlotObj {
+    fn area(&self) -> Rect {
+        plot_area(self.bounds)
+    }
+
+    /// Returns the number of rows in the plot area, which is the number of samples it keeps.
+    fn rows(&self) -> usize {
+        let area = self.area();
+        (area.bottom_right.y - area.top_left.y) as usize
+    }
+
+    fn channels(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns the samples pushed `age` samples before the newest one.
+    fn sample(&self, samples: &[f32], age: usize) -> Option<&[f32]> {
+        let rows = self.rows();
+        if age >= self.len.get() {
+            return None;
+        }
+        let row = (self.next_row.get() + rows - 1 - age) % rows;
+        Some(&samples[row * self.channels()..(row + 1) * self.channels()])
+    }
+
+    /// Widens an automatic value axis to include `value`, returning whether it changed.
+    fn include(&self, value: f32) -> bool {
+        if self.fixed.get() || !value.is_finite() {
+            return false;
+        }
+        let (min, max) = match self.range.get() {
+            Some((min, max)) if (min..=max).contains(&value) => return false,
+            Some((min, max)) => {
+                let span = max.max(value) - min.min(value);
+                if value < min {
+                    (value - span * AUTO_MARGIN, max)
+                } else {
+                    (min, value + span * AUTO_MARGIN)
+                }
+            }
+            // A single sample gets an axis of its own size around it
+            None => {
+                let half = (value.abs() * 0.5).max(0.5);
+                (value - half, value + half)
+            }
+        };
+        self.range.set(Some((min, max)));
+        true
+    }
+
+    /// Returns the x coordinate a value is plotted at, or `None` if it leaves a gap.
+    fn x_of(&self, value: f32) -> Option<i32> {
+        let (min, max) = self.range.get()?;
+        if value.is_nan() {
+            return None;
+        }
+        let area = self.area();
+        let width = i32::from(area.bottom_right.x - area.top_left.x);
+        let fraction = ((value - min) / (max - min)).clamp(0.0, 1.0);
+        Some(i32::from(area.top_left.x) + (fraction * (width - 1) as f32).round() as i32)
+    }
+
+    /// Draws the newest `count` samples on the bottom rows of the plot area.
+    ///
+    /// Each sample is joined to the one before it, which must already be drawn.
+    fn draw_samples(&self, display: &mut Display, count: usize) {
+        let area = self.area();
+        let samples = self.samples.borrow();
+        let bottom = i32::from(area.bottom_right.y) - 1;
+
+        for age in (0..count).rev() {
+            let Some(current) = self.sample(&samples, age) else {
+                continue;
+            };
+            let previous = self.sample(&samples, age + 1);
+            let y = bottom - age as i32;
+
+            for (channel, color) in self.colors.iter().enumerate() {
+                let Some(x) = self.x_of(current[channel]) else {
+                    continue;
+                };
+                let start = previous
+                    .and_then(|previous| self.x_of(previous[channel]))
+                    .map_or((x, y), |previous_x| (previous_x, y - 1));
+                draw_line(display, start, (x, y), *color);
+            }
+        }
+    }
+
+    /// Draws the gridlines across the given rows of the plot area.
+    fn draw_grid(&self, display: &mut Display, top: i16, bottom: i16) {
+        let area = self.area();
+        let width = i32::from(area.bottom_right.x - area.top_left.x);
+        for line in 1..self.grid {
+            let x = i32::from(area.top_left.x) + width * i32::from(line) / i32::from(self.grid);
+            display.fill(
+                &Rect {
+                    top_left: Point2 {
+                        x: x as i16,
+                        y: top,
+                    },
+                    bottom_right: Point2 {
+                        x: x as i16 + 1,
+                        y: bottom,
+                    },
+                },
+                GRID_COLOR,
+            );
+        }
+    }
+
+    /// Draws the legend and the labels at the ends of the value axis.
+    fn draw_header(&self, display: &mut Display) {
+        let font = Font::new(FontSize::SMALL, FontFamily::Proportional);
+        let Point2 { x: left, y: top } = self.bounds.top_left;
+        let right = self.bounds.bottom_right.x;
+
+        let mut x = left + 2;
+        for (name, color) in self.names.iter().zip(self.colors.iter()) {
+            let Some(Ok(name)) = name.get_cstr() else {
+                continue;
+            };
+            display.fill(
+                &Rect {
+                    top_left: Point2 { x, y: top + 5 },
+                    bottom_right: Point2 {
+                        x: x + 8,
+                        y: top + 13,
+                    },
+                },
+                *color,
+            );
+            let text = Text::new(
+                name,
+                font,
+                Point2 {
+                    x: x + 12,
+                    y: top + 1,
+                },
+            );
+            let width = text.width() as i16;
+            display.draw_text(&text, *color, None);
+            x += width + 24;
+        }
+
+        let Some((min, max)) = self.range.get() else {
+            return;
+        };
+        let y = top + HEADER_HEIGHT / 2;
+        let min = axis_label(min);
+        display.draw_text(
+            &Text::new(&min, font, Point2 { x: left + 2, y }),
+            Color::WHITE,
+            None,
+        );
+        let max = axis_label(max);
+        let width = Text::new(&max, font, Point2 { x: 0, y }).width() as i16;
+        display.draw_text(
+            &Text::new(
+                &max,
+                font,
+                Point2 {
+                    x: right - 2 - width,
+                    y,
+                },
+            ),
+            Color::WHITE,
+            None,
+        );
+    }
+
+    /// Clears the plot and draws everything in it again.
+    fn draw_all(&self, display: &mut Display) {
+        display.fill(&self.bounds, self.background);
+        self.draw_header(display);
+
+        let area = self.area();
+        let border = Rect {
+            top_left: Point2 {
+                x: area.top_left.x - 1,
+                y: area.top_left.y - 1,
+            },
+            bottom_right: Point2 {
+                x: area.bottom_right.x + 1,
+                y: area.bottom_right.y + 1,
+            },
+        };
+        display.stroke(&border, BORDER_COLOR);
+        self.draw_grid(display, area.top_left.y, area.bottom_right.y);
+        self.draw_samples(display, self.len.get());
+    }
+
+    /// Scrolls the plot up by the samples pushed since the last draw, drawing just the new rows.
+    fn scroll(&self, display: &mut Display, count: usize) {
+        let area = self.area();
+        display.scroll_region(area, count as i16);
+
+        // The rows scrolled into view are filled with the display's own background color
+        let top = area.bottom_right.y - count as i16;
+        display.fill(
+            &Rect {
+                top_left: Point2 {
+                    x: area.top_left.x,
+                    y: top,
+                },
+                ..area
+            },
+            self.background,
+        );
+        self.draw_grid(display, top, area.bottom_right.y);
+        self.draw_samples(display, count);
+    }
+}
+
+/// Draws a one-pixel-wide line between two points.
+///
+/// The SDK is called directly, like `display.draw_line` does, but only while the display is locked
+/// so that nothing else can change the foreground color in between.
+fn draw_line(_display: &mut Display, start: (i32, i32), end: (i32, i32), color: Color) {
+    unsafe {
+        vexDisplayForegroundColor(color.into_raw());
+        vexDisplayLineDraw(start.0, start.1, end.0, end.1);
+    }
+}
+
+/// Formats a value for the ends of the value axis.
+fn axis_label(value: f32) -> CString {
+    let precision = if value.abs() >= 100.0 { 0 } else { 2 };
+    // Formatted numbers never contain a NUL character
+    CString::new(format!("{value:.precision$}")).unwrap()
+}
+
+fn parse_channels(obj: Obj) -> Result<(Vec<Obj, Gc>, Vec<Color, Gc>), Exception> {
+    let items = obj
+        .try_array()
+        .ok_or_else(|| type_error(c"expected a list or tuple of (name, Color) tuples"))?;
+    if items.is_empty() || items.len() > MAX_CHANNELS {
+        Err(value_error(error_msg!(
+            "a plot must have between 1 and {MAX_CHANNELS} channels"
+        )))?
+    }
+
+    // The GC allocator can't grow a buffer, so they're allocated at their full size
+    let mut names = Vec::with_capacity_in(items.len(), Gc { token: token() });
+    let mut colors = Vec::with_capacity_in(items.len(), Gc { token: token() });
+    for item in items {
+        let Some([name, color]) = item.try_array().and_then(|pair| pair.try_into().ok()) else {
+            Err(type_error(c"expected a (name, Color) tuple"))?
+        };
+        match name.get_cstr() {
+            Some(Ok(_)) => names.push(name),
+            Some(Err(_)) => Err(value_error(c"channel names cannot contain a NUL character"))?,
+            None => Err(type_error(c"expected a str for the channel name"))?,
+        }
+        let color = color
+            .try_as_obj::<ColorObj>()
+            .ok_or_else(|| type_error(c"expected a Color for the channel color"))?;
+        colors.push(color.color());
+    }
+    Ok((names, colors))
+}
+
+/// Parses a pair of axis limits, which must either both be `None` or both be numbers.
+fn parse_range(min: Obj, max: Obj) -> Result<Option<(f32, f32)>, Exception> {
+    let number = |obj: Obj| {
+        obj.try_to_int()
+            .map(|value| value as f32)
+            .or(obj.try_to_float())
+            .ok_or_else(|| type_error(c"expected a number or None for the axis limits"))
+    };
+    match (min.is_none(), max.is_none()) {
+        (true, true) => Ok(None),
+        (false, false) => {
+            let (min, max) = (number(min)?, number(max)?);
+            if !min.is_finite() || !max.is_finite() || min >= max {
+                Err(value_error(
+                    c"min must be less than max, and both must be finite",
+                ))?
+            }
+            Ok(Some((min, max)))
+        }
+        _ => Err(value_error(c"min and max must be given together"))?,
+    }
+}
+
+#[class_methods]
+impl PlotObj {
+    /// Creates a plot filling the region whose top-left corner is at `x` and `y`, which is
+    /// `width` by `height` pixels.
+    ///
+    /// `channels` lists each channel's name and color, in the order their samples are pushed.
+    /// The value axis runs from `min` to `max`, or scales automatically if they are `None`, and
+    /// is divided by `grid` gridlines into equal parts. Nothing is drawn until `draw` is called.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `channels` isn't a list or tuple of `(str, Color)` tuples, or an axis
+    ///   limit isn't a number or `None`.
+    /// - `ValueError`: If there are no channels or more than eight, a channel name contains a
+    ///   NUL character, only one of `min` and `max` is given, `min` isn't less than `max`, `grid`
+    ///   is more than 16, the region is too small to plot in, or the region exceeds the
+    ///   supported coordinate range.
+    #[make_new]
+    #[stub(
+        sig = "(self, x: int, y: int, width: int, height: int, /, *, channels: list[tuple[str, Color]] | tuple[tuple[str, Color], ...], min: float | None = None, max: float | None = None, grid: int = 4, background: Color = Color.BLACK) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(4, 4).assert_nkw(1, 5);
+
+        let x = reader.next_positional()?;
+        let y = reader.next_positional()?;
+        let width = reader.next_positional::<u16>()?;
+        let height = reader.next_positional::<u16>()?;
+        let bounds = checked_rect(x, y, width, height)?;
+        if width < 16 || height < HEADER_HEIGHT as u16 + 16 {
+            Err(value_error(c"the plot is too small"))?
+        }
+
+        let mut channels = None;
+        let mut min = Obj::NONE;
+        let mut max = Obj::NONE;
+        let mut grid = 4;
+        let mut background = Color::BLACK;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "channels" => channels = Some(parse_channels(*arg.obj)?),
+                "min" => min = *arg.obj,
+                "max" => max = *arg.obj,
+                "grid" => grid = arg.parse::<u16>()?,
+                "background" => background = arg.parse::<&ColorObj>()?.color(),
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+        let Some((names, colors)) = channels else {
+            Err(type_error(c"missing required argument 'channels'"))?
+        };
+        if grid > MAX_GRID {
+            Err(value_error(error_msg!("grid must be at most {MAX_GRID}")))?
+        }
+        let range = parse_range(min, max)?;
+
+        let area = plot_area(bounds);
+        let capacity = (area.bottom_right.y - area.top_left.y) as usize * colors.len();
+        let mut samples = Vec::with_capacity_in(capacity, Gc { token: token() });
+        samples.resize(capacity, f32::NAN);
+
+        Ok(Self {
+            base: ty.into(),
+            bounds,
+            names,
+            colors,
+            background,
+            grid,
+            fixed: Cell::new(range.is_some()),
+            range: Cell::new(range),
+            samples: RefCell::new(samples),
+            next_row: Cell::new(0),
+            len: Cell::new(0),
+            pending: Cell::new(0),
+            full_redraw: Cell::new(true),
+        })
+    }
+
+    #[attr]
+    #[stub(attrs = ["min: float | None", "max: float | None"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+        let range = self.range.get();
+        result.return_value(match attr.as_str() {
+            "min" => range.map_or(Obj::NONE, |(min, _)| Obj::from(min)),
+            "max" => range.map_or(Obj::NONE, |(_, max)| Obj::from(max)),
+            _ => return,
+        });
+    }
+
+    /// Records one sample for each channel, in the order the channels were given.
+    ///
+    /// The samples are shown the next time `draw` is called.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If a sample isn't a number.
+    /// - `ValueError`: If there isn't one sample per channel.
+    #[method(ty = var(min = 1))]
+    #[stub(sig = "(self, *values: float) -> None")]
+    fn push(args: &[Obj]) -> Result<(), Exception> {
+        let plot = args[0].as_obj::<Self>();
+        let values = &args[1..];
+        if values.len() != plot.channels() {
+            Err(value_error(error_msg!(
+                "expected {} samples, one per channel, but got {}",
+                plot.channels(),
+                values.len()
+            )))?
+        }
+
+        let rows = plot.rows();
+        let row = plot.next_row.get();
+        let mut samples = plot.samples.borrow_mut();
+        let channels = plot.channels();
+        for (slot, value) in samples[row * channels..(row + 1) * channels]
+            .iter_mut()
+            .zip(values)
+        {
+            *slot = value
+                .try_to_int()
+                .map(|value| value as f32)
+                .or(value.try_to_float())
+                .ok_or_else(|| type_error(c"expected a number"))?;
+            if plot.include(*slot) {
+                plot.full_redraw.set(true);
+            }
+        }
+
+        plot.next_row.set((row + 1) % rows);
+        plot.len.set((plot.len.get() + 1).min(rows));
+        plot.pending.set(plot.pending.get() + 1);
+        Ok(())
+    }
+
+    /// Shows the samples pushed since the plot was last drawn.
+    ///
+    /// The first draw, and any after the value axis changes or `redraw` is called, draws the
+    /// whole plot. Otherwise only the new rows are drawn, after scrolling the rest up. With
+    /// `RenderMode.DOUBLE_BUFFERED`, `display.render` must still be called for them to appear.
+    #[method]
+    fn draw(&self) {
+        let mut display = lock_display();
+        let pending = self.pending.replace(0);
+        if self.full_redraw.replace(false) || pending >= self.rows() {
+            self.draw_all(&mut display);
+        } else if pending > 0 {
+            self.scroll(&mut display, pending);
+        }
+    }
+
+    /// Draws the whole plot again at the next call to `draw`.
+    ///
+    /// Call this after drawing over the plot with anything else.
+    #[method]
+    fn redraw(&self) {
+        self.full_redraw.set(true);
+    }
+
+    /// Discards every sample.
+    ///
+    /// An automatic value axis starts over from the next sample pushed.
+    #[method]
+    fn clear(&self) {
+        self.len.set(0);
+        self.pending.set(0);
+        if !self.fixed.get() {
+            self.range.set(None);
+        }
+        self.full_redraw.set(true);
+    }
+
+    /// Fixes the value axis to run from `min` to `max`, or makes it scale automatically if both
+    /// are `None`.
+    ///
+    /// An automatic axis is fitted to the samples already in the plot.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If an axis limit isn't a number or `None`.
+    /// - `ValueError`: If only one of `min` and `max` is given, or `min` isn't less than `max`.
+    #[method]
+    #[stub(sig = "(self, min: float | None, max: float | None, /) -> None")]
+    fn set_range(&self, min: Obj, max: Obj) -> Result<(), Exception> {
+        let range = parse_range(min, max)?;
+        self.fixed.set(range.is_some());
+        self.range.set(range);
+        if range.is_none() {
+            let samples = self.samples.borrow();
+            for age in 0..self.len.get() {
+                for value in self.sample(&samples, age).unwrap_or_default() {
+                    self.include(*value);
+                }
+            }
+        }
+        self.full_redraw.set(true);
+        Ok(())
+    }
+}
@@ -0,0 +1,575 @@
+//! Drawing functions for shapes that are rasterized in memory and then drawn on the display.
+
+use std::{alloc::Global, f32::consts::TAU};
+
+use argparse::{Args, ArgsReader, error_msg};
+use micropython_macros::{class, class_methods, fun};
+use micropython_rs::{
+    except::{type_error, value_error},
+    map::Map,
+    obj::{Obj, ObjBase, ObjTrait, ObjType},
+};
+use venice_core::raster::{Framebuffer, Paint, Shape, Vec2, blend};
+use vexide_devices::{
+    color::Color,
+    display::{Display, Rect},
+    math::Point2,
+};
+
+use super::canvas::CanvasObj;
+use crate::{
+    devices::lock_display,
+    modvenice::{Exception, color::ColorObj},
+};
+
+/// The size of the writable part of the display, which shapes are clipped to.
+pub const DISPLAY_SIZE: [i32; 2] = [480, 240];
+
+/// The number of rows rasterized at a time, which bounds the memory a large shape needs.
+const BAND_HEIGHT: usize = 16;
+
+/// A color that changes gradually along a line, available as `display.LinearGradient`.
+///
+/// A gradient can be passed instead of a `Color` to any of the shape drawing functions, such as
+/// `display.fill_polygon` or `display.fill_round_rect`. Pixels are colored by how far along the
+/// line from (`start_x`, `start_y`) to (`end_x`, `end_y`) they are, and the colors at the ends
+/// continue past them.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// # Fade the top of the display from blue to black.
+/// sky = display.LinearGradient(0, 0, 0, 119, Color(0, 80, 200), Color.BLACK)
+/// display.fill_round_rect(0, 0, 480, 120, 0, sky)
+/// ```
+#[class(qstr!(LinearGradient))]
+#[repr(C)]
+pub struct LinearGradientObj {
+    base: ObjBase,
+    paint: Paint,
+}
+
+#[class_methods]
+impl LinearGradientObj {
+    /// Creates a gradient from `start_color` at (`start_x`, `start_y`) to `end_color` at
+    /// (`end_x`, `end_y`).
+    #[make_new]
+    #[stub(
+        sig = "(self, start_x: int, start_y: int, end_x: int, end_y: int, start_color: Color, end_color: Color, /) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(6, 6).assert_nkw(0, 0);
+
+        let start = next_point(&mut reader)?;
+        let end = next_point(&mut reader)?;
+        let from = argb(reader.next_positional::<&ColorObj>()?.color());
+        let to = argb(reader.next_positional::<&ColorObj>()?.color());
+        Ok(Self {
+            base: ty.into(),
+            paint: Paint::Linear {
+                start,
+                end,
+                from,
+                to,
+            },
+        })
+    }
+}
+
+/// Converts an opaque color to `0xAARRGGBB`.
+pub fn argb(color: Color) -> u32 {
+    0xff00_0000 | color.into_raw()
+}
+
+/// Reads the next two positional arguments as the coordinates of a pixel, returning its center.
+fn next_point(reader: &mut ArgsReader) -> Result<Vec2, Exception> {
+    let x = reader.next_positional::<i16>()?;
+    let y = reader.next_positional::<i16>()?;
+    Ok([f32::from(x) + 0.5, f32::from(y) + 0.5])
+}
+
+pub fn parse_paint(obj: Obj) -> Result<Paint, Exception> {
+    if let Some(color) = obj.try_as_obj::<ColorObj>() {
+        Ok(Paint::Solid(argb(color.color())))
+    } else if let Some(gradient) = obj.try_as_obj::<LinearGradientObj>() {
+        Ok(gradient.paint)
+    } else {
+        Err(type_error(c"expected a Color or LinearGradient"))?
+    }
+}
+
+/// Parses a list or tuple of `(x, y)` tuples, returning the centers of those pixels.
+pub fn parse_points(obj: Obj) -> Result<Vec<Vec2>, Exception> {
+    let items = obj
+        .try_array()
+        .ok_or_else(|| type_error(c"expected a list or tuple of (x, y) tuples"))?;
+    if items.is_empty() {
+        Err(value_error(c"expected at least one point"))?
+    }
+
+    let mut points = Vec::with_capacity(items.len());
+    for item in items {
+        let Some([x, y]) = item.try_array().and_then(|pair| pair.try_into().ok()) else {
+            Err(type_error(c"expected an (x, y) tuple"))?
+        };
+        let coordinate = |obj: Obj| {
+            obj.try_to_int()
+                .and_then(|value| i16::try_from(value).ok())
+                .ok_or_else(|| value_error(c"point coordinates must be ints in the 16-bit range"))
+        };
+        points.push([
+            f32::from(coordinate(x)?) + 0.5,
+            f32::from(coordinate(y)?) + 0.5,
+        ]);
+    }
+    Ok(points)
+}
+
+/// Returns the clockwise sweep from `start` to `end`, both in degrees, in radians.
+pub fn sweep(start: f32, end: f32) -> Result<f32, Exception> {
+    if !start.is_finite() || !end.is_finite() {
+        Err(value_error(c"angles must be finite"))?
+    }
+    Ok(if end - start >= 360.0 {
+        TAU
+    } else {
+        (end - start).rem_euclid(360.0).to_radians()
+    })
+}
+
+/// The keyword arguments shared by the shape drawing functions.
+pub struct Style {
+    pub thickness: f32,
+    pub antialias: bool,
+    pub bg_color: Color,
+}
+
+impl Style {
+    /// Parses the keyword arguments, including `thickness` if the shape is an outline and
+    /// `bg_color` unless it's drawn on a canvas.
+    pub fn parse(
+        reader: &mut ArgsReader,
+        outline: bool,
+        on_canvas: bool,
+    ) -> Result<Self, Exception> {
+        let mut style = Self {
+            thickness: 1.0,
+            antialias: false,
+            bg_color: Color::BLACK,
+        };
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "thickness" if outline => {
+                    let thickness = arg.parse::<u16>()?;
+                    if thickness == 0 {
+                        Err(value_error(c"thickness must be at least 1"))?
+                    }
+                    style.thickness = f32::from(thickness);
+                }
+                "antialias" => style.antialias = arg.parse()?,
+                "bg_color" if !on_canvas => style.bg_color = arg.parse::<&ColorObj>()?.color(),
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+        Ok(style)
+    }
+}
+
+/// A kind of shape drawn by one of the shape drawing functions or `Canvas` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeKind {
+    Polygon,
+    Triangle,
+    Line,
+    Arc,
+    RoundRect,
+}
+
+impl ShapeKind {
+    /// The number of positional arguments that describe the shape, including its color.
+    fn positional_count(self) -> usize {
+        match self {
+            Self::Polygon => 2,
+            Self::Triangle => 7,
+            Self::Line => 5,
+            Self::Arc | Self::RoundRect => 6,
+        }
+    }
+}
+
+/// The position and size of a shape, as read from its arguments.
+enum Geometry {
+    Points(Vec<Vec2>),
+    Line(Vec2, Vec2),
+    Arc {
+        center: Vec2,
+        radius: f32,
+        start: f32,
+        sweep: f32,
+    },
+    RoundRect {
+        origin: Vec2,
+        size: Vec2,
+        radius: f32,
+    },
+}
+
+impl Geometry {
+    fn parse(reader: &mut ArgsReader, kind: ShapeKind) -> Result<Self, Exception> {
+        Ok(match kind {
+            ShapeKind::Polygon => Self::Points(parse_points(reader.next_positional()?)?),
+            ShapeKind::Triangle => Self::Points(vec![
+                next_point(reader)?,
+                next_point(reader)?,
+                next_point(reader)?,
+            ]),
+            ShapeKind::Line => Self::Line(next_point(reader)?, next_point(reader)?),
+            ShapeKind::Arc => {
+                let center = next_point(reader)?;
+                let radius = f32::from(reader.next_positional::<u16>()?);
+                let start = reader.next_positional::<f32>()?;
+                let end = reader.next_positional::<f32>()?;
+                Self::Arc {
+                    center,
+                    radius,
+                    start: start.to_radians(),
+                    sweep: sweep(start, end)?,
+                }
+            }
+            ShapeKind::RoundRect => {
+                let x = reader.next_positional::<i16>()?;
+                let y = reader.next_positional::<i16>()?;
+                let width = reader.next_positional::<u16>()?;
+                let height = reader.next_positional::<u16>()?;
+                let radius = reader.next_positional::<u16>()?;
+                Self::RoundRect {
+                    origin: [f32::from(x), f32::from(y)],
+                    size: [f32::from(width), f32::from(height)],
+                    radius: f32::from(radius),
+                }
+            }
+        })
+    }
+
+    /// Returns the shape's outline, `width` pixels wide, or the filled shape if `width` is `None`.
+    fn shape(&self, width: Option<f32>) -> Shape<'_> {
+        match (self, width) {
+            (Self::Points(points), Some(width)) => Shape::PolygonOutline { points, width },
+            (Self::Points(points), None) => Shape::Polygon(points),
+            (&Self::Line(start, end), width) => Shape::Line {
+                start,
+                end,
+                width: width.unwrap_or(1.0),
+            },
+            (
+                &Self::Arc {
+                    center,
+                    radius,
+                    start,
+                    sweep,
+                },
+                Some(width),
+            ) => Shape::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+                width,
+            },
+            (
+                &Self::Arc {
+                    center,
+                    radius,
+                    start,
+                    sweep,
+                },
+                None,
+            ) => Shape::Pie {
+                center,
+                radius,
+                start,
+                sweep,
+            },
+            (
+                &Self::RoundRect {
+                    origin,
+                    size,
+                    radius,
+                },
+                Some(width),
+            ) => Shape::RoundRectOutline {
+                origin,
+                size,
+                radius,
+                width,
+            },
+            (
+                &Self::RoundRect {
+                    origin,
+                    size,
+                    radius,
+                },
+                None,
+            ) => Shape::RoundRect {
+                origin,
+                size,
+                radius,
+            },
+        }
+    }
+}
+
+/// Draws a shape described by the arguments of a shape drawing function, outlining it if
+/// `outline` is set.
+///
+/// With `on_canvas`, the arguments are instead those of the `Canvas` method of the same name, and
+/// the shape is drawn on the canvas they start with.
+pub fn draw_shape_args(
+    args: &[Obj],
+    kw_map: &Map,
+    kind: ShapeKind,
+    outline: bool,
+    on_canvas: bool,
+) -> Result<(), Exception> {
+    let kwarg_count = kw_map.len();
+    let positional_count = args.len() - kwarg_count * 2;
+    let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+    let npos = kind.positional_count() + usize::from(on_canvas);
+    let nkw = usize::from(outline) + if on_canvas { 1 } else { 2 };
+    reader.assert_npos(npos, npos).assert_nkw(0, nkw);
+
+    let canvas = if on_canvas {
+        Some(reader.next_positional::<&CanvasObj>()?)
+    } else {
+        None
+    };
+    let geometry = Geometry::parse(&mut reader, kind)?;
+    let paint = parse_paint(reader.next_positional()?)?;
+    let style = Style::parse(&mut reader, outline, on_canvas)?;
+
+    let shape = geometry.shape(outline.then_some(style.thickness));
+    match canvas {
+        Some(canvas) => canvas.draw_shape(shape, paint, style.antialias),
+        None => draw_on_display(shape, paint, &style),
+    }
+    Ok(())
+}
+
+/// Rasterizes `shape` a band at a time and draws the pixels it covers on the display.
+///
+/// The display can't be read back, so anti-aliased edges are blended with `bg_color`.
+pub fn draw_on_display(shape: Shape, paint: Paint, style: &Style) {
+    let [min, max] = shape.bounds();
+    let clip = |low: f32, high: f32, size: i32| {
+        // Anti-aliasing can cover pixels half a pixel outside of the shape
+        let start = (low.floor() as i32 - 1).clamp(0, size);
+        let end = (high.ceil() as i32 + 1).clamp(0, size);
+        start..end
+    };
+    let columns = clip(min[0], max[0], DISPLAY_SIZE[0]);
+    let rows = clip(min[1], max[1], DISPLAY_SIZE[1]);
+    if columns.is_empty() || rows.is_empty() {
+        return;
+    }
+
+    let background = argb(style.bg_color);
+    let mut band = Framebuffer::new(columns.len(), BAND_HEIGHT, Global);
+    let mut display = lock_display();
+    for top in rows.clone().step_by(BAND_HEIGHT) {
+        band.set_origin([columns.start, top]);
+        band.clear(0);
+        band.draw(shape, paint, style.antialias);
+
+        for y in 0..band.height().min((rows.end - top) as usize) {
+            draw_row(
+                &mut display,
+                [columns.start, top + y as i32],
+                band.row(y),
+                background,
+            );
+        }
+    }
+}
+
+/// Draws the pixels of `row` that aren't fully transparent on the display, starting at `position`
+/// and blended over `background`.
+///
+/// The row must lie within the display.
+pub fn draw_row(display: &mut Display, [left, top]: [i32; 2], row: &[u32], background: u32) {
+    let mut run = Vec::with_capacity(row.len());
+    let mut flush = |run: &mut Vec<Color>, end: usize| {
+        if !run.is_empty() {
+            let region = Rect {
+                top_left: Point2 {
+                    x: (left + (end - run.len()) as i32) as i16,
+                    y: top as i16,
+                },
+                bottom_right: Point2 {
+                    x: (left + end as i32) as i16,
+                    y: (top + 1) as i16,
+                },
+            };
+            display.draw_buffer(region, run);
+            run.clear();
+        }
+    };
+
+    for (x, &pixel) in row.iter().enumerate() {
+        if pixel >> 24 == 0 {
+            flush(&mut run, x);
+        } else {
+            let rgb = blend(background, pixel, 255);
+            run.push(Color::new((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8));
+        }
+    }
+    flush(&mut run, row.len());
+}
+
+/// Draws the outline of a closed polygon through `points`, a list of `(x, y)` tuples.
+///
+/// The outline is `thickness` pixels wide, centered on the edges, with rounded corners. With
+/// `antialias=True`, its edges are smoothed by blending them with `bg_color`, which should be the
+/// color behind the outline, since the display can't be read back.
+///
+/// # Raises
+///
+/// - `TypeError`: If `points` isn't a list or tuple of `(x, y)` tuples, or `color` isn't a `Color`
+///   or `LinearGradient`.
+/// - `ValueError`: If `points` is empty, a coordinate is outside the 16-bit range, or `thickness`
+///   is zero.
+#[fun(ty = kw(min = 2))]
+#[stub(
+    sig = "(points: list[tuple[int, int]] | tuple[tuple[int, int], ...], color: Color | LinearGradient, /, *, thickness: int = 1, antialias: bool = False, bg_color: Color = Color.BLACK) -> None"
+)]
+pub fn draw_polygon(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+    draw_shape_args(args, kw_map, ShapeKind::Polygon, true, false)
+}
+
+/// Fills the polygon through `points`, a list of `(x, y)` tuples.
+///
+/// Where edges cross, areas are filled by the even-odd rule, so the middle of a five-pointed star
+/// is left empty. With `antialias=True`, the edges are smoothed by blending them with `bg_color`.
+///
+/// # Raises
+///
+/// - `TypeError`: If `points` isn't a list or tuple of `(x, y)` tuples, or `color` isn't a `Color`
+///   or `LinearGradient`.
+/// - `ValueError`: If `points` is empty or a coordinate is outside the 16-bit range.
+#[fun(ty = kw(min = 2))]
+#[stub(
+    sig = "(points: list[tuple[int, int]] | tuple[tuple[int, int], ...], color: Color | LinearGradient, /, *, antialias: bool = False, bg_color: Color = Color.BLACK) -> None"
+)]
+pub fn fill_polygon(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+    draw_shape_args(args, kw_map, ShapeKind::Polygon, false, false)
+}
+
+/// Draws the outline of the triangle with corners at (`x0`, `y0`), (`x1`, `y1`) and (`x2`, `y2`).
+///
+/// `thickness`, `antialias` and `bg_color` work like they do for `display.draw_polygon`.
+///
+/// # Raises
+///
+/// - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+/// - `ValueError`: If `thickness` is zero.
+#[fun(ty = kw(min = 7))]
+#[stub(
+    sig = "(x0: int, y0: int, x1: int, y1: int, x2: int, y2: int, color: Color | LinearGradient, /, *, thickness: int = 1, antialias: bool = False, bg_color: Color = Color.BLACK) -> None"
+)]
+pub fn draw_triangle(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+    draw_shape_args(args, kw_map, ShapeKind::Triangle, true, false)
+}
+
+/// Fills the triangle with corners at (`x0`, `y0`), (`x1`, `y1`) and (`x2`, `y2`).
+///
+/// `antialias` and `bg_color` work like they do for `display.fill_polygon`.
+///
+/// # Raises
+///
+/// - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+#[fun(ty = kw(min = 7))]
+#[stub(
+    sig = "(x0: int, y0: int, x1: int, y1: int, x2: int, y2: int, color: Color | LinearGradient, /, *, antialias: bool = False, bg_color: Color = Color.BLACK) -> None"
+)]
+pub fn fill_triangle(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+    draw_shape_args(args, kw_map, ShapeKind::Triangle, false, false)
+}
+
+/// Draws part of the outline of the circle centered at `x` and `y`, from the angle `start` to
+/// `end`.
+///
+/// Angles are in degrees, measured clockwise from the right, so 90 is straight down. The arc runs
+/// clockwise from `start` to `end`, and is a whole circle if `end` is at least 360 degrees past
+/// `start`. The ends of the arc are rounded. `thickness`, `antialias` and `bg_color` work like
+/// they do for `display.draw_polygon`.
+///
+/// # Raises
+///
+/// - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+/// - `ValueError`: If an angle isn't finite or `thickness` is zero.
+#[fun(ty = kw(min = 6))]
+#[stub(
+    sig = "(x: int, y: int, radius: int, start: float, end: float, color: Color | LinearGradient, /, *, thickness: int = 1, antialias: bool = False, bg_color: Color = Color.BLACK) -> None"
+)]
+pub fn draw_arc(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+    draw_shape_args(args, kw_map, ShapeKind::Arc, true, false)
+}
+
+/// Fills a slice of the circle centered at `x` and `y`, from the angle `start` to `end`.
+///
+/// Angles work like they do for `display.draw_arc`. `antialias` and `bg_color` work like they do
+/// for `display.fill_polygon`.
+///
+/// # Raises
+///
+/// - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+/// - `ValueError`: If an angle isn't finite.
+#[fun(ty = kw(min = 6))]
+#[stub(
+    sig = "(x: int, y: int, radius: int, start: float, end: float, color: Color | LinearGradient, /, *, antialias: bool = False, bg_color: Color = Color.BLACK) -> None"
+)]
+pub fn fill_pie(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+    draw_shape_args(args, kw_map, ShapeKind::Arc, false, false)
+}
+
+/// Draws the outline of a rectangle with rounded corners.
+///
+/// `x` and `y` are the top-left corner, and `width` and `height` are measured in pixels. Corners
+/// are rounded with `radius`, which is reduced if it doesn't fit. The outline is drawn inside the
+/// rectangle, `thickness` pixels wide. `antialias` and `bg_color` work like they do for
+/// `display.draw_polygon`.
+///
+/// # Raises
+///
+/// - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+/// - `ValueError`: If `thickness` is zero.
+#[fun(ty = kw(min = 6))]
+#[stub(
+    sig = "(x: int, y: int, width: int, height: int, radius: int, color: Color | LinearGradient, /, *, thickness: int = 1, antialias: bool = False, bg_color: Color = Color.BLACK) -> None"
+)]
+pub fn draw_round_rect(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+    draw_shape_args(args, kw_map, ShapeKind::RoundRect, true, false)
+}
+
+/// Fills a rectangle with rounded corners.
+///
+/// The rectangle is placed like it is for `display.draw_round_rect`. `antialias` and `bg_color`
+/// work like they do for `display.fill_polygon`.
+///
+/// # Raises
+///
+/// - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+#[fun(ty = kw(min = 6))]
+#[stub(
+    sig = "(x: int, y: int, width: int, height: int, radius: int, color: Color | LinearGradient, /, *, antialias: bool = False, bg_color: Color = Color.BLACK) -> None"
+)]
+pub fn fill_round_rect(args: &[Obj], kw_map: &Map) -> Result<(), Exception> {
+    draw_shape_args(args, kw_map, ShapeKind::RoundRect, false, false)
+}
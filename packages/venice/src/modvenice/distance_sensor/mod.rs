@@ -2,12 +2,16 @@ pub mod distance_object;
 
 use argparse::{Args, error_msg};
 use micropython_macros::{class, class_methods};
-use micropython_rs::obj::{Obj, ObjBase, ObjType};
+use micropython_rs::obj::{Obj, ObjBase, ObjTrait, ObjType};
 use vexide_devices::smart::distance::{DistanceObjectError, DistanceSensor};
 
 use crate::{
     devices::{self},
-    modvenice::{Exception, device_error, distance_sensor::distance_object::DistanceObjectObj},
+    modvenice::{
+        Exception, device_error,
+        distance_sensor::distance_object::DistanceObjectObj,
+        vasyncio::until::{Threshold, UntilFuture},
+    },
     registry::SmartGuard,
 };
 
@@ -159,6 +163,48 @@ impl DistanceSensorObj {
         Ok(self.guard.borrow().object()?.map(DistanceObjectObj::new))
     }
 
+    /// Waits until an object is detected no more than `distance` millimeters from the sensor.
+    ///
+    /// Awaiting the returned `UntilFuture` returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// Drive forward until a wall is 100mm away:
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// sensor = DistanceSensor(1)
+    /// left = Motor(2)
+    /// right = Motor(3, Direction.REVERSE)
+    ///
+    /// async def main():
+    ///     left.set_voltage(6)
+    ///     right.set_voltage(6)
+    ///     await sensor.wait_until_within(100)
+    ///     left.brake(BrakeMode.BRAKE)
+    ///     right.brake(BrakeMode.BRAKE)
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If the sensor binding is freed while awaiting.
+    /// - `DeviceError`: If, while awaiting, no sensor is connected to the port, or it has an
+    ///   unknown status code.
+    #[method]
+    fn wait_until_within(self_in: Obj, distance: f32) -> UntilFuture {
+        UntilFuture::reading(
+            |sensor| {
+                let object = sensor.as_obj::<Self>().guard.borrow().object()?;
+                Ok(object.map(|object| object.distance as f32))
+            },
+            self_in,
+            Threshold::AtMost(distance),
+        )
+    }
+
     /// Releases this sensor and frees its Smart Port lock.
     ///
     /// The object is unusable afterward, but its Smart Port can be assigned to another device.
@@ -0,0 +1,442 @@
+use argparse::{Args, Callable, KeywordArg, error_msg};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{runtime_error, type_error, value_error},
+    obj::{Obj, ObjBase, ObjTrait, ObjType},
+};
+
+use crate::modvenice::{
+    Exception,
+    imu::InertialSensorObj,
+    motor::{MotorObj, group::MotorGroupObj},
+    validate::check_positive,
+};
+
+/// The shape applied to each input once it is outside the dead band.
+#[derive(Clone, Copy)]
+enum Curve {
+    /// Raises the input's magnitude to a power, keeping its sign.
+    Power(f32),
+    /// Calls a Python function with the input.
+    Custom(Callable),
+}
+
+/// How joystick inputs are turned into drive commands.
+#[derive(Clone, Copy)]
+struct Shaping {
+    deadband: f32,
+    curve: Curve,
+}
+
+impl Shaping {
+    /// Reads a `deadband` or `curve` keyword argument, returning `false` for any other keyword.
+    fn parse_kw(&mut self, arg: KeywordArg) -> Result<bool, Exception> {
+        match arg.kw {
+            "deadband" => {
+                let deadband = arg.parse::<f32>()?;
+                if !(0.0..1.0).contains(&deadband) {
+                    Err(value_error(c"deadband must be at least 0 and less than 1"))?
+                }
+                self.deadband = deadband;
+            }
+            "curve" => {
+                self.curve = if arg.obj.is_callable() {
+                    Curve::Custom(arg.parse::<Callable>()?)
+                } else {
+                    Curve::Power(check_positive(arg.parse::<f32>()?, arg.kw)?)
+                };
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Clamps `input` to between -1 and 1, zeroes it inside the dead band, and applies the curve.
+    ///
+    /// Inputs just outside the dead band start from zero rather than jumping to the dead band's
+    /// edge.
+    fn apply(&self, input: f32) -> Result<f32, Exception> {
+        let input = input.clamp(-1.0, 1.0);
+        if input.abs() <= self.deadband {
+            return Ok(0.0);
+        }
+
+        let scaled = (input.abs() - self.deadband) / (1.0 - self.deadband) * input.signum();
+        match self.curve {
+            Curve::Power(exponent) => Ok(scaled.abs().powf(exponent) * scaled.signum()),
+            Curve::Custom(curve) => {
+                let output = curve.call(0, &[Obj::from(scaled)]);
+                let output = output
+                    .try_to_float()
+                    .or_else(|| output.try_to_int().map(|int| int as f32))
+                    .ok_or_else(|| type_error(c"the input curve must return a float"))?;
+                Ok(output.clamp(-1.0, 1.0))
+            }
+        }
+    }
+}
+
+impl Default for Shaping {
+    fn default() -> Self {
+        Self {
+            deadband: 0.0,
+            curve: Curve::Power(1.0),
+        }
+    }
+}
+
+/// Scales `outputs` down together so that none is larger than 1, keeping their proportions.
+fn desaturate<const N: usize>(outputs: [f32; N]) -> [f32; N] {
+    let largest = outputs
+        .iter()
+        .fold(1.0, |largest: f32, output| largest.max(output.abs()));
+    outputs.map(|output| output / largest)
+}
+
+/// Returns the left and right outputs that drive at `forward` while turning clockwise at `turn`.
+fn arcade_outputs(forward: f32, turn: f32) -> [f32; 2] {
+    desaturate([forward + turn, forward - turn])
+}
+
+/// Returns the left and right outputs that drive at `throttle` along a curve whose sharpness is
+/// set by `curvature`, turning in place if `throttle` is zero.
+fn curvature_outputs(throttle: f32, curvature: f32) -> [f32; 2] {
+    if throttle == 0.0 {
+        return arcade_outputs(0.0, curvature);
+    }
+    arcade_outputs(throttle, throttle.abs() * curvature)
+}
+
+/// Returns the front left, front right, back left and back right outputs that drive at `forward`
+/// while moving right at `strafe` and turning clockwise at `turn`.
+fn holonomic_outputs(forward: f32, strafe: f32, turn: f32) -> [f32; 4] {
+    desaturate([
+        forward + strafe + turn,
+        forward - strafe - turn,
+        forward - strafe + turn,
+        forward + strafe - turn,
+    ])
+}
+
+fn motor_output(obj: Obj) -> Result<Obj, Exception> {
+    if obj.try_as_obj::<MotorObj>().is_none() && obj.try_as_obj::<MotorGroupObj>().is_none() {
+        Err(type_error(c"expected a Motor or MotorGroup"))?
+    }
+    Ok(obj)
+}
+
+/// Sets each motor or motor group in `motors` to the matching fraction of its maximum voltage.
+///
+/// Like `MotorGroup`, every output is set even if an earlier one failed, and the first error is
+/// returned afterwards.
+fn set_outputs(motors: &[Obj], outputs: &[f32]) -> Result<(), Exception> {
+    let mut result = Ok(());
+    for (motor, &output) in motors.iter().zip(outputs) {
+        let outcome = match motor.try_as_obj::<MotorObj>() {
+            Some(motor) => motor.set_output(output.into()),
+            None => motor.as_obj::<MotorGroupObj>().set_output(output.into()),
+        };
+        if result.is_ok() {
+            result = outcome;
+        }
+    }
+    result
+}
+
+/// A drivetrain with a left and a right side, such as a tank drive, driven from joystick inputs.
+///
+/// Each side is a `Motor` or a `MotorGroup`, commanded by voltage as a fraction of its motors'
+/// maximum voltage. Inputs are between -1 and 1, as read from a `Controller`'s sticks, with
+/// positive values driving forwards or turning clockwise, to the right.
+///
+/// Before they are used, inputs are clamped to between -1 and 1 and then shaped. Inputs within
+/// `deadband` of zero are ignored, so a stick that doesn't quite centre doesn't creep, and the
+/// remaining range is stretched back out to 1 so that control starts smoothly at the dead band's
+/// edge. `curve` then shapes the input: a number raises its magnitude to that power, so that 2 or 3
+/// gives finer control at low speeds, and a function is called with the input and returns the
+/// shaped one. When the inputs would ask for more than full power on either side, both sides are
+/// scaled down together, so the robot keeps its heading rather than turning unexpectedly.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// left = MotorGroup([(1, Direction.REVERSE), (2, Direction.REVERSE)])
+/// right = MotorGroup([3, 4])
+/// drive = DifferentialDrive(left, right, deadband=0.05, curve=2.0)
+/// controller = Controller()
+///
+/// async def main():
+///     while True:
+///         state = controller.read_state()
+///         drive.arcade(state.left_stick.y, state.right_stick.x)
+///         await vasyncio.Sleep(Controller.UPDATE_INTERVAL_MS, MILLIS)
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(DifferentialDrive))]
+#[repr(C)]
+pub struct DifferentialDrive {
+    base: ObjBase,
+    motors: [Obj; 2],
+    shaping: Shaping,
+}
+
+/// A drivetrain with four omni or mecanum wheels that can drive in any direction, such as an
+/// X-drive or a mecanum drive, driven from joystick inputs.
+///
+/// Each wheel is a `Motor` or a `MotorGroup`, commanded by voltage as a fraction of its motors'
+/// maximum voltage. Inputs are between -1 and 1, as read from a `Controller`'s sticks, with
+/// positive values driving forwards, moving right, or turning clockwise. The wheels are expected to
+/// be set up so that a positive voltage on each drives the robot forwards.
+///
+/// Inputs are clamped, given a dead band and shaped by a curve as in `DifferentialDrive`. When the
+/// inputs would ask for more than full power on any wheel, every wheel is scaled down together, so
+/// the robot keeps moving in the requested direction.
+///
+/// With an `imu`, `HolonomicDrive.field_oriented` drives relative to the field instead of the
+/// robot: forwards is the direction the robot faced when the sensor's rotation was last reset,
+/// however the robot has turned since.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// imu = InertialSensor(5)
+/// drive = HolonomicDrive(
+///     Motor(1), Motor(2, Direction.REVERSE), Motor(3), Motor(4, Direction.REVERSE), imu=imu,
+/// )
+/// controller = Controller()
+///
+/// async def main():
+///     await imu.calibrate()
+///     while True:
+///         state = controller.read_state()
+///         drive.field_oriented(state.left_stick.y, state.left_stick.x, state.right_stick.x)
+///         await vasyncio.Sleep(Controller.UPDATE_INTERVAL_MS, MILLIS)
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(HolonomicDrive))]
+#[repr(C)]
+pub struct HolonomicDrive {
+    base: ObjBase,
+    motors: [Obj; 4],
+    imu: Option<Obj>,
+    shaping: Shaping,
+}
+
+#[class_methods]
+impl DifferentialDrive {
+    /// Creates a drivetrain from its `left` and `right` sides.
+    ///
+    /// `deadband` is between 0 and 1, and `curve` is a positive exponent or a function from a
+    /// float to a float.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `left` or `right` isn't a `Motor` or `MotorGroup`, or `curve` isn't a
+    ///   number or callable.
+    /// - `ValueError`: If `deadband` isn't at least 0 and less than 1, or `curve` is a number that
+    ///   isn't positive and finite.
+    #[make_new]
+    #[stub(
+        sig = "(self, left: Motor | MotorGroup, right: Motor | MotorGroup, /, *, deadband: float = 0.0, curve: float | Callable[[float], float] = 1.0) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(2, 2).assert_nkw(0, 2);
+
+        let left = motor_output(reader.next_positional::<Obj>()?)?;
+        let right = motor_output(reader.next_positional::<Obj>()?)?;
+
+        let mut shaping = Shaping::default();
+        while let Some(arg) = reader.next_kw() {
+            if !shaping.parse_kw(arg)? {
+                Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?
+            }
+        }
+
+        Ok(Self {
+            base: ty.into(),
+            motors: [left, right],
+            shaping,
+        })
+    }
+
+    /// Drives each side directly from its own input, such as the left and right sticks' `y`.
+    ///
+    /// # Raises
+    ///
+    /// - `DeviceError`: If a motor can't be used.
+    /// - Any exception raised by the input curve.
+    #[method]
+    fn tank(&self, left: f32, right: f32) -> Result<(), Exception> {
+        let outputs = [self.shaping.apply(left)?, self.shaping.apply(right)?];
+        set_outputs(&self.motors, &outputs)
+    }
+
+    /// Drives forwards at `forward` while turning clockwise at `turn`.
+    ///
+    /// Turning is added to one side and taken from the other, so the robot turns in place when
+    /// `forward` is zero.
+    ///
+    /// # Raises
+    ///
+    /// - `DeviceError`: If a motor can't be used.
+    /// - Any exception raised by the input curve.
+    #[method]
+    fn arcade(&self, forward: f32, turn: f32) -> Result<(), Exception> {
+        let outputs = arcade_outputs(self.shaping.apply(forward)?, self.shaping.apply(turn)?);
+        set_outputs(&self.motors, &outputs)
+    }
+
+    /// Drives forwards at `throttle` along a curve set by `curvature`, which is clockwise when
+    /// positive.
+    ///
+    /// Unlike `DifferentialDrive.arcade`, the turning rate grows with speed, so the same
+    /// `curvature` traces the same arc whether the robot is moving quickly or slowly. This makes
+    /// the robot easier to steer at speed. When `throttle` is zero after the dead band, the robot
+    /// turns in place at `curvature` instead.
+    ///
+    /// # Raises
+    ///
+    /// - `DeviceError`: If a motor can't be used.
+    /// - Any exception raised by the input curve.
+    #[method]
+    fn curvature(&self, throttle: f32, curvature: f32) -> Result<(), Exception> {
+        let outputs = curvature_outputs(
+            self.shaping.apply(throttle)?,
+            self.shaping.apply(curvature)?,
+        );
+        set_outputs(&self.motors, &outputs)
+    }
+}
+
+impl HolonomicDrive {
+    fn drive(&self, forward: f32, strafe: f32, turn: f32) -> Result<(), Exception> {
+        set_outputs(&self.motors, &holonomic_outputs(forward, strafe, turn))
+    }
+}
+
+#[class_methods]
+impl HolonomicDrive {
+    /// Creates a drivetrain from its four wheels.
+    ///
+    /// `deadband` and `curve` are as in `DifferentialDrive`. `imu` is needed for
+    /// `HolonomicDrive.field_oriented`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If a wheel isn't a `Motor` or `MotorGroup`, `imu` isn't an
+    ///   `InertialSensor`, or `curve` isn't a number or callable.
+    /// - `ValueError`: If `deadband` isn't at least 0 and less than 1, or `curve` is a number that
+    ///   isn't positive and finite.
+    #[make_new]
+    #[stub(
+        sig = "(self, front_left: Motor | MotorGroup, front_right: Motor | MotorGroup, back_left: Motor | MotorGroup, back_right: Motor | MotorGroup, /, *, imu: InertialSensor | None = None, deadband: float = 0.0, curve: float | Callable[[float], float] = 1.0) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(4, 4).assert_nkw(0, 3);
+
+        let mut motors = [Obj::NONE; 4];
+        for motor in &mut motors {
+            *motor = motor_output(reader.next_positional::<Obj>()?)?;
+        }
+
+        let mut imu = None;
+        let mut shaping = Shaping::default();
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "imu" => {
+                    let obj = arg.parse::<Obj>()?;
+                    if !obj.is_none() {
+                        if obj.try_as_obj::<InertialSensorObj>().is_none() {
+                            Err(type_error(c"expected an InertialSensor"))?
+                        }
+                        imu = Some(obj);
+                    }
+                }
+                _ => {
+                    if !shaping.parse_kw(arg)? {
+                        Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            base: ty.into(),
+            motors,
+            imu,
+            shaping,
+        })
+    }
+
+    /// Drives relative to the robot: forwards at `forward`, right at `strafe`, and clockwise at
+    /// `turn`.
+    ///
+    /// # Raises
+    ///
+    /// - `DeviceError`: If a motor can't be used.
+    /// - Any exception raised by the input curve.
+    #[method(ty = var_between(min = 4, max = 4))]
+    #[stub(sig = "(self, forward: float, strafe: float, turn: float, /) -> None")]
+    fn arcade(args: &[Obj]) -> Result<(), Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+
+        let drive = reader.next_positional::<&Self>()?;
+        let forward = drive.shaping.apply(reader.next_positional()?)?;
+        let strafe = drive.shaping.apply(reader.next_positional()?)?;
+        let turn = drive.shaping.apply(reader.next_positional()?)?;
+
+        drive.drive(forward, strafe, turn)
+    }
+
+    /// Drives relative to the field: towards the field's forwards at `forward` and its right at
+    /// `strafe`, whichever way the robot faces, while turning clockwise at `turn`.
+    ///
+    /// The robot's heading is read from `imu`.
+    ///
+    /// # Raises
+    ///
+    /// - `RuntimeError`: If the drivetrain has no `imu`.
+    /// - `DeviceError`: If a motor or the inertial sensor can't be used.
+    /// - Any exception raised by the input curve.
+    #[method(ty = var_between(min = 4, max = 4))]
+    #[stub(sig = "(self, forward: float, strafe: float, turn: float, /) -> None")]
+    fn field_oriented(args: &[Obj]) -> Result<(), Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+
+        let drive = reader.next_positional::<&Self>()?;
+        let forward = drive.shaping.apply(reader.next_positional()?)?;
+        let strafe = drive.shaping.apply(reader.next_positional()?)?;
+        let turn = drive.shaping.apply(reader.next_positional()?)?;
+
+        let imu = drive
+            .imu
+            .ok_or_else(|| runtime_error(c"field-oriented driving needs an imu"))?;
+        // clockwise from the field's forwards
+        let heading = imu.as_obj::<InertialSensorObj>().rotation()?.as_radians() as f32;
+        let (sin, cos) = heading.sin_cos();
+
+        drive.drive(
+            forward * cos + strafe * sin,
+            strafe * cos - forward * sin,
+            turn,
+        )
+    }
+}
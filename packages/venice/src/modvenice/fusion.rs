@@ -0,0 +1,533 @@
+use std::{array::from_fn, cell::Cell};
+
+use argparse::{Args, error_msg};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{type_error, value_error},
+    init::token,
+    obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
+    qstr::Qstr,
+    tuple::new_tuple,
+};
+
+use crate::modvenice::{
+    Exception,
+    gps::GpsSensorObj,
+    math::{Pose, Pose2, wrap_angle},
+    odometry::OdometryObj,
+    read_only_attr::read_only_attr,
+    units::time::TimeUnitObj,
+    validate::{check_non_negative, check_positive},
+    vasyncio::{polled::PolledFuture, ticker::Schedule, time32},
+};
+
+pub type Matrix3 = [[f32; 3]; 3];
+
+const ZERO: Matrix3 = [[0.0; 3]; 3];
+
+/// The number of fixes in a row that may be rejected before the filter assumes its own estimate is
+/// wrong, such as after the robot was pushed, and restarts from the next fix.
+const REJECTIONS_BEFORE_RESET: u32 = 25;
+
+/// How long a GPS reading has to stay the same before it is fused again.
+///
+/// The sensor's reading is read back unchanged on every update until its next sample, and fusing
+/// the same sample repeatedly would make the filter overconfident. A reading that stays the same
+/// for this long is treated as a new sample of a robot standing still.
+const REPEATED_FIX_INTERVAL: time32::Duration = time32::Duration::from_micros(100_000);
+
+fn diagonal(a: f32, b: f32, c: f32) -> Matrix3 {
+    [[a, 0.0, 0.0], [0.0, b, 0.0], [0.0, 0.0, c]]
+}
+
+fn add(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    from_fn(|i| from_fn(|j| a[i][j] + b[i][j]))
+}
+
+fn mul(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    from_fn(|i| from_fn(|j| (0..3).map(|k| a[i][k] * b[k][j]).sum()))
+}
+
+fn transpose(a: &Matrix3) -> Matrix3 {
+    from_fn(|i| from_fn(|j| a[j][i]))
+}
+
+fn mul_vec(a: &Matrix3, v: [f32; 3]) -> [f32; 3] {
+    from_fn(|i| (0..3).map(|k| a[i][k] * v[k]).sum())
+}
+
+fn inverse(a: &Matrix3) -> Option<Matrix3> {
+    let cofactor = |i: usize, j: usize| {
+        let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+        let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+        a[r0][c0] * a[r1][c1] - a[r0][c1] * a[r1][c0]
+    };
+
+    let det = (0..3).map(|j| a[0][j] * cofactor(0, j)).sum::<f32>();
+    if det.abs() < f32::MIN_POSITIVE || !det.is_finite() {
+        return None;
+    }
+
+    Some(from_fn(|i| from_fn(|j| cofactor(j, i) / det)))
+}
+
+/// An extended Kalman filter over a planar pose, independent of MicroPython.
+///
+/// Odometry moves the estimate and grows its uncertainty with the distance travelled and the angle
+/// turned. Absolute fixes, such as those from a GPS sensor, pull the estimate towards them in
+/// proportion to how much they are trusted. A fix whose Mahalanobis distance from the estimate
+/// exceeds `gate` is rejected as an outlier.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoseFilter {
+    /// The standard deviation of the odometry's position error per unit travelled.
+    pub odometry_error: f32,
+    /// The standard deviation of the odometry's heading error per radian turned.
+    pub turn_error: f32,
+    /// The standard deviation of a fix's heading in radians.
+    pub heading_error: f32,
+    /// The largest Mahalanobis distance from the estimate at which a fix is accepted.
+    pub gate: f32,
+    pose: Option<Pose>,
+    covariance: Matrix3,
+    rejections: u32,
+}
+
+impl PoseFilter {
+    /// Creates a filter that waits for its first fix to set the pose.
+    pub const fn new(odometry_error: f32, turn_error: f32, heading_error: f32, gate: f32) -> Self {
+        Self {
+            odometry_error,
+            turn_error,
+            heading_error,
+            gate,
+            pose: None,
+            covariance: ZERO,
+            rejections: 0,
+        }
+    }
+
+    /// Returns the estimated pose, or `None` before the first fix.
+    pub fn pose(&self) -> Option<Pose> {
+        self.pose
+    }
+
+    /// Returns the covariance of the estimated pose, in the order x, y, heading.
+    pub fn covariance(&self) -> Matrix3 {
+        self.covariance
+    }
+
+    /// Sets the estimate to `pose` with no uncertainty, or forgets it if `pose` is `None`.
+    pub fn reset(&mut self, pose: Option<Pose>) {
+        self.pose = pose;
+        self.covariance = ZERO;
+        self.rejections = 0;
+    }
+
+    /// Moves the estimate by `delta`, measured in the robot's frame.
+    pub fn predict(&mut self, delta: Pose) {
+        let Some(pose) = self.pose else {
+            return;
+        };
+
+        let (sin, cos) = pose.theta.sin_cos();
+        let jacobian = [
+            [1.0, 0.0, -delta.x * sin - delta.y * cos],
+            [0.0, 1.0, delta.x * cos - delta.y * sin],
+            [0.0, 0.0, 1.0],
+        ];
+
+        let translation = self.odometry_error * delta.x.hypot(delta.y);
+        let rotation = self.turn_error * delta.theta.abs();
+        let noise = diagonal(
+            translation * translation,
+            translation * translation,
+            rotation * rotation,
+        );
+
+        self.pose = Some(pose.compose(delta));
+        self.covariance = add(
+            &mul(&mul(&jacobian, &self.covariance), &transpose(&jacobian)),
+            &noise,
+        );
+    }
+
+    /// Fuses a fix of `pose` whose position has a standard deviation of `error`.
+    ///
+    /// Returns whether the fix was accepted.
+    pub fn correct(&mut self, fix: Pose, error: f32) -> bool {
+        let noise = diagonal(
+            error * error,
+            error * error,
+            self.heading_error * self.heading_error,
+        );
+
+        let Some(pose) = self.pose else {
+            self.pose = Some(fix);
+            self.covariance = noise;
+            return true;
+        };
+
+        let innovation = [
+            fix.x - pose.x,
+            fix.y - pose.y,
+            wrap_angle(fix.theta - pose.theta),
+        ];
+        let Some(inverse) = inverse(&add(&self.covariance, &noise)) else {
+            return false;
+        };
+
+        let weighted = mul_vec(&inverse, innovation);
+        let distance_squared = (0..3).map(|i| innovation[i] * weighted[i]).sum::<f32>();
+        if distance_squared > self.gate * self.gate {
+            self.rejections += 1;
+            if self.rejections >= REJECTIONS_BEFORE_RESET {
+                self.pose = Some(fix);
+                self.covariance = noise;
+                self.rejections = 0;
+            }
+            return false;
+        }
+
+        let gain = mul(&self.covariance, &inverse);
+        let correction = mul_vec(&gain, innovation);
+        self.pose = Some(Pose::new(
+            pose.x + correction[0],
+            pose.y + correction[1],
+            pose.theta + correction[2],
+        ));
+
+        let remaining: Matrix3 =
+            from_fn(|i| from_fn(|j| if i == j { 1.0 } else { 0.0 } - gain[i][j]));
+        let covariance = mul(&remaining, &self.covariance);
+        // keep the covariance symmetric as rounding errors build up
+        self.covariance = from_fn(|i| from_fn(|j| (covariance[i][j] + covariance[j][i]) / 2.0));
+        self.rejections = 0;
+        true
+    }
+}
+
+/// Fuses wheel odometry with GPS fixes into a smoothed estimate of the robot's pose on the field.
+///
+/// Between fixes, the estimate follows the motion measured by an `Odometry`, including the heading
+/// from its inertial sensor if it has one, and its uncertainty grows as the robot drives. Each GPS
+/// fix is weighted by the sensor's reported error (`GpsSensor.get_error`), so precise fixes correct
+/// the estimate quickly while noisy ones barely move it. Fixes that disagree with the estimate by
+/// much more than the uncertainty of both allows are rejected as outliers. If 25 fixes in a row are
+/// rejected, the estimate is assumed to be wrong, such as after the robot was pushed, and restarts
+/// from the next fix. A GPS reading is only fused once, when it changes, unless it stays the same
+/// for a tenth of a second while the robot stands still.
+///
+/// The estimate is in the GPS sensor's field frame, with x and y measured in the odometry's unit.
+/// Set `scale` to the number of odometry units in a metre, such as 39.37 for inches. Headings are
+/// counterclockwise from the positive x axis, as with `Pose2.from_gps`.
+///
+/// There is no estimate until the first GPS fix is accepted, unless one is set with
+/// `PoseEstimator.set_pose`.
+///
+/// The `outliers` attribute counts the fixes that have been rejected.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// odom = Odometry(
+///     [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+///     imu=InertialSensor(3),
+/// )
+/// gps = GpsSensor(4, Point2(0, 0), Point2(0, 0), 0, DEGREES)
+/// estimator = PoseEstimator(odom, gps, scale=39.37)
+///
+/// async def track():
+///     await estimator.run(10, MILLIS)
+///
+/// async def main():
+///     vasyncio.spawn(track())
+///     while True:
+///         pose = estimator.get_pose()
+///         if pose is not None:
+///             print(pose)
+///         await vasyncio.Sleep(100, MILLIS)
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(PoseEstimator))]
+#[repr(C)]
+pub struct PoseEstimator {
+    base: ObjBase,
+    odometry: Obj,
+    gps: Obj,
+    scale: f32,
+    filter: Cell<PoseFilter>,
+    // the odometry pose at the previous update
+    previous: Cell<Option<Pose>>,
+    // the GPS reading most recently fused, with its error and when it was fused
+    last_fix: Cell<Option<(Pose, f32, time32::Instant)>>,
+    outliers: Cell<u32>,
+}
+
+/// An awaitable that updates a `PoseEstimator` on a fixed schedule until it is cancelled, returned
+/// by `PoseEstimator.run`.
+///
+/// Scheduling works like `OdometryFuture`.
+///
+/// # Raises
+///
+/// - `DeviceError`: If a sensor can't be read.
+#[class(qstr!(PoseEstimatorFuture))]
+#[repr(C)]
+pub struct PoseEstimatorFuture {
+    base: ObjBase,
+    estimator: Obj,
+    schedule: Schedule,
+}
+
+impl PoseEstimator {
+    /// Updates the odometry, follows its motion, then fuses the GPS fix if it is a new reading.
+    fn step(&self) -> Result<(), Exception> {
+        let odometry = self.odometry.as_obj::<OdometryObj>();
+        odometry.integrate()?;
+        let current = odometry.pose();
+
+        let gps = self.gps.as_obj::<GpsSensorObj>();
+        let fix = gps.pose()?;
+        let error = gps.error()? as f32;
+
+        let mut filter = self.filter.get();
+        if let Some(previous) = self.previous.replace(Some(current)) {
+            filter.predict(current.relative_to(previous));
+        }
+
+        let now = time32::Instant::now();
+        let fresh = self
+            .last_fix
+            .get()
+            .is_none_or(|(last, last_error, fused_at)| {
+                last != fix || last_error != error || now - fused_at >= REPEATED_FIX_INTERVAL
+            });
+
+        // a fix without a finite error can't be weighted
+        if fresh && error.is_finite() && error > 0.0 {
+            self.last_fix.set(Some((fix, error, now)));
+            let fix = Pose::new(fix.x * self.scale, fix.y * self.scale, fix.theta);
+            if !filter.correct(fix, error * self.scale) {
+                self.outliers.set(self.outliers.get().saturating_add(1));
+            }
+        }
+
+        self.filter.set(filter);
+        Ok(())
+    }
+
+    /// Returns the estimated pose, if there is one.
+    pub fn pose(&self) -> Option<Pose> {
+        self.filter.get().pose()
+    }
+
+    fn reset_filter(&self, pose: Option<Pose>) {
+        self.last_fix.set(None);
+        let mut filter = self.filter.get();
+        filter.reset(pose);
+        self.filter.set(filter);
+    }
+}
+
+#[class_methods]
+impl PoseEstimator {
+    /// Creates a pose estimator that fuses `odometry` with fixes from `gps`.
+    ///
+    /// `scale` is the number of odometry units in a metre. `odometry_error` is the standard
+    /// deviation of the odometry's position error as a fraction of the distance travelled, and
+    /// `turn_error` is the standard deviation of its heading error as a fraction of the angle
+    /// turned. `heading_error` is the standard deviation of the GPS heading in radians; make it
+    /// large to rely on the odometry's heading instead. `gate` is how many standard deviations a
+    /// fix may be from the estimate before it is rejected.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `odometry` isn't an `Odometry`, `gps` isn't a `GpsSensor`, or a number
+    ///   isn't numeric.
+    /// - `ValueError`: If `scale`, `heading_error` or `gate` isn't positive and finite, or
+    ///   `odometry_error` or `turn_error` is negative or non-finite.
+    #[make_new]
+    #[stub(
+        sig = "(self, odometry: Odometry, gps: GpsSensor, /, *, scale: float = 1.0, odometry_error: float = 0.02, turn_error: float = 0.02, heading_error: float = 0.05, gate: float = 4.0) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(2, 2).assert_nkw(0, 5);
+
+        let odometry = reader.next_positional::<Obj>()?;
+        let gps = reader.next_positional::<Obj>()?;
+        if odometry.try_as_obj::<OdometryObj>().is_none() {
+            Err(type_error(c"expected an Odometry"))?
+        }
+        if gps.try_as_obj::<GpsSensorObj>().is_none() {
+            Err(type_error(c"expected a GpsSensor"))?
+        }
+
+        let mut scale = 1.0;
+        let mut odometry_error = 0.02;
+        let mut turn_error = 0.02;
+        let mut heading_error = 0.05;
+        let mut gate = 4.0;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "scale" => scale = arg.parse::<f32>()?,
+                "odometry_error" => odometry_error = arg.parse::<f32>()?,
+                "turn_error" => turn_error = arg.parse::<f32>()?,
+                "heading_error" => heading_error = arg.parse::<f32>()?,
+                "gate" => gate = arg.parse::<f32>()?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        let scale = check_positive(scale, "scale")?;
+        let filter = PoseFilter::new(
+            check_non_negative(odometry_error, "odometry_error")?,
+            check_non_negative(turn_error, "turn_error")?,
+            check_positive(heading_error, "heading_error")?,
+            check_positive(gate, "gate")?,
+        );
+
+        Ok(Self {
+            base: ty.into(),
+            odometry,
+            gps,
+            scale,
+            filter: Cell::new(filter),
+            previous: Cell::new(None),
+            last_fix: Cell::new(None),
+            outliers: Cell::new(0),
+        })
+    }
+
+    /// Updates the odometry, then fuses the latest GPS fix into the estimate.
+    ///
+    /// Use this instead of `PoseEstimator.run` to update the estimate from your own loop.
+    ///
+    /// # Raises
+    ///
+    /// - `DeviceError`: If a sensor can't be read.
+    #[method]
+    fn update(&self) -> Result<(), Exception> {
+        self.step()
+    }
+
+    /// Returns a `PoseEstimatorFuture` that updates the estimate every `period`, measured in
+    /// `unit`, until it is cancelled.
+    ///
+    /// Each update also updates the odometry, so there is no need to run it separately.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+    #[method(ty = var_between(min = 1, max = 3))]
+    #[stub(
+        sig = "(self, period: float = 10.0, unit: TimeUnit = TimeUnit.MILLIS, /) -> PoseEstimatorFuture"
+    )]
+    fn run(args: &[Obj]) -> Result<PoseEstimatorFuture, Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+
+        let estimator = reader.next_positional::<Obj>()?;
+        let period = reader.next_positional_or(10.0)?;
+        let unit = reader.next_positional_or(TimeUnitObj::MILLIS)?.unit();
+
+        let period = time32::Duration::from_duration(unit.float_to_dur(period)?);
+        Ok(PoseEstimatorFuture {
+            base: PoseEstimatorFuture::OBJ_TYPE.into(),
+            estimator,
+            schedule: Schedule::new(period)?,
+        })
+    }
+
+    /// Returns the estimated pose, or `None` if there is no estimate yet.
+    #[method]
+    #[stub(sig = "(self, /) -> Pose2 | None")]
+    fn get_pose(&self) -> Obj {
+        match self.filter.get().pose() {
+            Some(pose) => Obj::from(Pose2::new(pose)),
+            None => Obj::NONE,
+        }
+    }
+
+    /// Returns the covariance of the estimate as three rows of three, in the order x, y, heading.
+    ///
+    /// Positions are in odometry units and headings are in radians, so the square root of the
+    /// first entry is the standard deviation of x.
+    #[method]
+    #[stub(sig = "(self, /) -> tuple[tuple[float, float, float], ...]")]
+    fn get_covariance(&self) -> Obj {
+        let rows = self
+            .filter
+            .get()
+            .covariance()
+            .map(|row| new_tuple(&row.map(Obj::from)));
+        new_tuple(&rows)
+    }
+
+    /// Sets the estimate to `pose`, treating it as exact until the robot moves.
+    #[method]
+    fn set_pose(&self, pose: &Pose2) {
+        self.reset_filter(Some(pose.pose()));
+    }
+
+    /// Discards the estimate so that the next accepted GPS fix starts a new one.
+    #[method]
+    fn reset(&self) {
+        self.reset_filter(None);
+    }
+
+    #[attr]
+    #[stub(attrs = ["outliers: int"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        if attr.as_str() != "outliers" {
+            return;
+        }
+
+        match op {
+            AttrOp::Load { result } => result.return_value(Obj::from_int(
+                self.outliers.get().min(i32::MAX as u32) as i32,
+            )),
+            _ => read_only_attr::<Self>(),
+        }
+    }
+}
+
+impl PolledFuture for PoseEstimatorFuture {
+    /// Returns the deadline of the next update, if the future has been awaited.
+    fn next_poll(&self) -> Option<time32::Instant> {
+        self.schedule.deadline()
+    }
+}
+
+#[class_methods]
+impl PoseEstimatorFuture {
+    #[iter]
+    extern "C" fn estimator_iternext(self_in: Obj) -> Obj {
+        let future = self_in.as_obj::<Self>();
+        let estimator = future.estimator.as_obj::<PoseEstimator>();
+
+        let due = match future.schedule.deadline() {
+            // take the starting readings as soon as the future is awaited
+            None => {
+                future.schedule.poll();
+                true
+            }
+            Some(_) => future.schedule.poll().is_some(),
+        };
+
+        if due && let Err(error) = estimator.step() {
+            // awaiting the future again restarts the updates
+            future.schedule.reset();
+            error.raise(token());
+        }
+
+        future.pend(self_in)
+    }
+}
@@ -1,3 +1,5 @@
+use std::f64::consts::FRAC_PI_2;
+
 use argparse::Args;
 use micropython_macros::{class, class_methods};
 use micropython_rs::obj::{Obj, ObjBase, ObjType};
@@ -7,7 +9,7 @@ use crate::{
     devices,
     modvenice::{
         Exception,
-        math::{EulerZYX, Point2, Quaternion, Vec3},
+        math::{EulerZYX, Point2, Pose, Quaternion, Vec3},
         units::{rotation::RotationUnitObj, time::TimeUnitObj},
     },
     registry::SmartGuard,
@@ -41,6 +43,26 @@ pub struct GpsSensorObj {
     guard: SmartGuard<GpsSensor>,
 }
 
+impl GpsSensorObj {
+    /// Returns the sensor's position in metres and its heading converted to radians
+    /// counterclockwise from the positive x axis.
+    pub fn pose(&self) -> Result<Pose, Exception> {
+        let gps = self.guard.borrow();
+        let position = gps.position()?;
+        let heading = gps.heading()?;
+        Ok(Pose::new(
+            position.x as f32,
+            position.y as f32,
+            (FRAC_PI_2 - heading.as_radians()) as f32,
+        ))
+    }
+
+    /// Returns the RMS error of the sensor's position in metres.
+    pub fn error(&self) -> Result<f64, Exception> {
+        Ok(self.guard.borrow().error()?)
+    }
+}
+
 #[class_methods]
 impl GpsSensorObj {
     /// Creates a new GPS sensor.
@@ -663,7 +685,7 @@ impl GpsSensorObj {
     /// ```
     #[method]
     fn get_error(&self) -> Result<f32, Exception> {
-        Ok(self.guard.borrow().error()? as f32)
+        Ok(self.error()? as f32)
     }
 
     /// Returns the internal status code of the sensor.
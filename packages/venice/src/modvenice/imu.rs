@@ -9,9 +9,12 @@ use micropython_rs::{
     print::{Print, PrintKind},
 };
 use vex_sdk::{vexDeviceImuReset, vexDeviceImuStatusGet};
-use vexide_devices::smart::{
-    SmartDevice,
-    imu::{InertialError, InertialOrientation, InertialSensor, InertialStatus},
+use vexide_devices::{
+    math::Angle,
+    smart::{
+        SmartDevice,
+        imu::{InertialError, InertialOrientation, InertialSensor, InertialStatus},
+    },
 };
 
 use crate::{
@@ -156,6 +159,13 @@ impl InertialOrientationObj {
     }
 }
 
+impl InertialSensorObj {
+    /// Returns the total clockwise rotation of the sensor since calibration.
+    pub fn rotation(&self) -> Result<Angle, Exception> {
+        Ok(self.guard.borrow().rotation()?)
+    }
+}
+
 #[class_methods]
 impl InertialSensorObj {
     /// The maximum time that the Inertial Sensor should take to *begin* its calibration process
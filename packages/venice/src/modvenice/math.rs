@@ -3,18 +3,28 @@ use std::{cell::Cell, fmt::Write};
 use argparse::{ArgType, Args, error_msg};
 use micropython_macros::{class, class_methods};
 use micropython_rs::{
-    except::{ZERO_DIVISION_ERROR_TYPE, raise_msg, type_error},
+    except::{ZERO_DIVISION_ERROR_TYPE, raise_msg, type_error, value_error},
     init::token,
+    map::Map,
     obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
     ops::{BinaryOpCode, UnaryOpCode},
     print::{Print, PrintKind},
     qstr::Qstr,
 };
 use mint::{EulerAngles, IntraZYX};
+use venice_core::pid::Pid;
+pub use venice_core::pose::{Pose, wrap_angle};
 use vexide_devices::math::Angle;
 
 use crate::{
-    modvenice::{Exception, units::rotation::RotationUnit},
+    modvenice::{
+        Exception,
+        gps::GpsSensorObj,
+        units::{
+            rotation::{RotationUnit, RotationUnitObj},
+            time::TimeUnitObj,
+        },
+    },
     obj::alloc_obj,
 };
 
@@ -602,6 +612,608 @@ impl From<vexide_devices::math::Point2<f64>> for Point2 {
     }
 }
 
+/// A robot's position and heading on the field.
+///
+/// `x` and `y` are mutable `float` attributes whose unit depends on the API that produced the pose;
+/// `Odometry` uses the unit of its tracking wheel diameters and `Pose2.from_gps` uses metres.
+/// `heading` is a mutable `float` attribute in radians, measured counterclockwise from the positive
+/// x axis. Use `Pose2.get_heading` to read it in another unit. Deleting an attribute resets it to
+/// `0.0`.
+///
+/// Adding a `Transform2` to a pose moves it by that transform in the pose's own frame, and
+/// subtracting two poses returns the `Transform2` between them, so `a + (b - a) == b` up to
+/// rounding. Poses support exact equality, return `False` when compared with another type, and
+/// have a readable `Pose2(x=..., y=..., heading=...)` representation.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// start = Pose2(0, 0, 90, DEGREES)
+/// # Drive 24 units forward, then turn 90 degrees left
+/// end = start + Transform2(24, 0, 90, DEGREES)
+/// print(end.x, end.y, end.get_heading(DEGREES))
+/// ```
+#[class(qstr!(Pose2))]
+#[repr(C)]
+pub struct Pose2 {
+    base: ObjBase,
+    x: Cell<f32>,
+    y: Cell<f32>,
+    heading: Cell<f32>,
+}
+
+/// A rigid motion in the plane: a translation by (`x`, `y`) followed by a turn of `rotation`.
+///
+/// The translation is measured in the frame of whatever the transform is applied to, so
+/// `Transform2(1, 0, 0)` always means one unit forward. `x` and `y` are mutable `float` attributes,
+/// and `rotation` is a mutable `float` attribute in radians, counterclockwise positive. Use
+/// `Transform2.get_rotation` to read it in another unit. Deleting an attribute resets it to `0.0`.
+///
+/// Adding two transforms composes them, applying the left one first. Transforms support exact
+/// equality, return `False` when compared with another type, and have a readable
+/// `Transform2(x=..., y=..., rotation=...)` representation.
+#[class(qstr!(Transform2))]
+#[repr(C)]
+pub struct Transform2 {
+    base: ObjBase,
+    x: Cell<f32>,
+    y: Cell<f32>,
+    rotation: Cell<f32>,
+}
+
+/// Returns the coordinates of a `Pose2` or `Point2`.
+pub fn target_coords(obj: Obj) -> Result<(f32, f32), Exception> {
+    if let Some(pose) = obj.try_as_obj::<Pose2>() {
+        Ok((pose.x.get(), pose.y.get()))
+    } else if let Some(point) = obj.try_as_obj::<Point2>() {
+        Ok((point.x.get(), point.y.get()))
+    } else {
+        Err(type_error(c"expected a Pose2 or Point2").into())
+    }
+}
+
+#[class_methods]
+impl Pose2 {
+    pub fn new(pose: Pose) -> Self {
+        Self {
+            base: Self::OBJ_TYPE.into(),
+            x: Cell::new(pose.x),
+            y: Cell::new(pose.y),
+            heading: Cell::new(pose.theta),
+        }
+    }
+
+    pub fn pose(&self) -> Pose {
+        Pose::new(self.x.get(), self.y.get(), self.heading.get())
+    }
+
+    /// Creates a pose at (`x`, `y`) with a heading of `heading`, measured in `unit`.
+    ///
+    /// Every argument is positional-only, and the coordinates and heading default to `0.0`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If a number is not numeric, `unit` isn't a `RotationUnit`, a keyword argument
+    ///   is supplied, or more than four positional arguments are given.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// pose = Pose2(12.0, -6.0, 45, DEGREES)
+    /// ```
+    #[make_new]
+    #[stub(
+        sig = "(self, x: float = 0.0, y: float = 0.0, heading: float = 0.0, unit: RotationUnit = RotationUnit.RADIANS, /) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(0, 4).assert_nkw(0, 0);
+
+        let x: f32 = reader.next_positional_or(0.0)?;
+        let y: f32 = reader.next_positional_or(0.0)?;
+        let heading = reader.next_positional_or(0.0)?;
+        let unit = reader.next_positional_or(RotationUnitObj::RADIANS)?.unit();
+
+        Ok(Self {
+            base: ty.into(),
+            x: x.into(),
+            y: y.into(),
+            heading: Cell::new(unit.float_to_angle(heading).as_radians() as f32),
+        })
+    }
+
+    /// Returns the pose reported by `gps`, in metres.
+    ///
+    /// The GPS heading, which is measured clockwise with 0 pointing along the positive y axis, is
+    /// converted to a counterclockwise heading from the positive x axis.
+    ///
+    /// # Raises
+    ///
+    /// - `DeviceError`: If the sensor can't be read.
+    #[method(ty = var_between(min = 1, max = 1), binding = "static")]
+    #[stub(sig = "(gps: GpsSensor, /) -> Pose2")]
+    fn from_gps(args: &[Obj]) -> Result<Self, Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+        let gps = reader.next_positional::<&GpsSensorObj>()?;
+
+        Ok(Self::new(gps.pose()?))
+    }
+
+    #[attr]
+    #[stub(attrs = ["x: float", "y: float", "heading: float"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let value = match attr.as_str() {
+            "x" => &self.x,
+            "y" => &self.y,
+            "heading" => &self.heading,
+            _ => return,
+        };
+
+        handle_op(op, value);
+    }
+
+    /// Returns the heading measured in `unit`.
+    #[method]
+    fn get_heading(&self, unit: &RotationUnitObj) -> f32 {
+        unit.unit()
+            .angle_to_float(Angle::from_radians(self.heading.get() as f64))
+    }
+
+    /// Returns this pose moved by `transform` in its own frame. Equivalent to `self + transform`.
+    #[method]
+    fn transform_by(&self, transform: &Transform2) -> Self {
+        Self::new(self.pose().compose(transform.pose()))
+    }
+
+    /// Returns this pose as seen from `origin`, as if `origin` were at (0, 0) facing along the
+    /// positive x axis.
+    #[method]
+    fn relative_to(&self, origin: &Pose2) -> Self {
+        Self::new(self.pose().relative_to(origin.pose()))
+    }
+
+    /// Returns the pose a fraction `t` of the way from this pose to `other`.
+    ///
+    /// The position is interpolated in a straight line and the heading turns the short way around.
+    /// `t` is not clamped, so values outside [0, 1] extrapolate.
+    #[method]
+    fn interpolate(&self, other: &Pose2, t: f32) -> Self {
+        Self::new(self.pose().interpolate(other.pose(), t))
+    }
+
+    /// Returns the straight-line distance from this pose to `target`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `target` isn't a `Pose2` or `Point2`.
+    #[method]
+    #[stub(sig = "(self, target: Pose2 | Point2, /) -> float")]
+    fn distance_to(&self, target: Obj) -> Result<f32, Exception> {
+        let (x, y) = target_coords(target)?;
+        Ok(self.pose().distance_to(x, y))
+    }
+
+    /// Returns how far this pose must turn to face `target`, measured in `unit`.
+    ///
+    /// The angle is counterclockwise positive and wrapped to the half-open range of -180 to 180
+    /// degrees.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `target` isn't a `Pose2` or `Point2`.
+    #[method]
+    #[stub(sig = "(self, target: Pose2 | Point2, unit: RotationUnit, /) -> float")]
+    fn angle_to(&self, target: Obj, unit: &RotationUnitObj) -> Result<f32, Exception> {
+        let (x, y) = target_coords(target)?;
+        let angle = Angle::from_radians(self.pose().angle_to(x, y).into());
+        Ok(unit.unit().angle_to_float(angle))
+    }
+
+    fn eq(lhs: &Self, rhs: &Self) -> bool {
+        lhs.x.get() == rhs.x.get()
+            && lhs.y.get() == rhs.y.get()
+            && lhs.heading.get() == rhs.heading.get()
+    }
+
+    /// Implements exact equality with another `Pose2`, moving by a `Transform2` with `+`, and the
+    /// `Transform2` between two poses with `-`.
+    ///
+    /// Unsupported operand combinations follow Python's normal binary-operation fallback.
+    #[binary_op]
+    fn binary_op(op: BinaryOpCode, lhs: &Self, rhs: Obj) -> Obj {
+        match op {
+            BinaryOpCode::Equal => Obj::from_bool(
+                rhs.try_as_obj::<Self>()
+                    .is_some_and(|rhs| Self::eq(lhs, rhs)),
+            ),
+            BinaryOpCode::Add | BinaryOpCode::InplaceAdd => match rhs.try_as_obj::<Transform2>() {
+                Some(rhs) => Obj::from(Self::new(lhs.pose().compose(rhs.pose()))),
+                None => Obj::NULL,
+            },
+            BinaryOpCode::Subtract | BinaryOpCode::InplaceSubtract => {
+                match rhs.try_as_obj::<Self>() {
+                    Some(rhs) => Obj::from(Transform2::new(lhs.pose().relative_to(rhs.pose()))),
+                    None => Obj::NULL,
+                }
+            }
+            _ => Obj::NULL,
+        }
+    }
+
+    /// Formats the pose as `Pose2(x=..., y=..., heading=...)`, with the heading in radians.
+    #[printer]
+    fn printer(&self, print: &mut Print, _kind: PrintKind) {
+        let _ = write!(
+            print,
+            "Pose2(x={}, y={}, heading={})",
+            self.x.get(),
+            self.y.get(),
+            self.heading.get()
+        );
+    }
+}
+
+#[class_methods]
+impl Transform2 {
+    pub fn new(pose: Pose) -> Self {
+        Self {
+            base: Self::OBJ_TYPE.into(),
+            x: Cell::new(pose.x),
+            y: Cell::new(pose.y),
+            rotation: Cell::new(pose.theta),
+        }
+    }
+
+    pub fn pose(&self) -> Pose {
+        Pose::new(self.x.get(), self.y.get(), self.rotation.get())
+    }
+
+    /// Creates a transform that moves by (`x`, `y`) and then turns by `rotation`, measured in
+    /// `unit`.
+    ///
+    /// Every argument is positional-only, and the translation and rotation default to `0.0`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If a number is not numeric, `unit` isn't a `RotationUnit`, a keyword argument
+    ///   is supplied, or more than four positional arguments are given.
+    #[make_new]
+    #[stub(
+        sig = "(self, x: float = 0.0, y: float = 0.0, rotation: float = 0.0, unit: RotationUnit = RotationUnit.RADIANS, /) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(0, 4).assert_nkw(0, 0);
+
+        let x: f32 = reader.next_positional_or(0.0)?;
+        let y: f32 = reader.next_positional_or(0.0)?;
+        let rotation = reader.next_positional_or(0.0)?;
+        let unit = reader.next_positional_or(RotationUnitObj::RADIANS)?.unit();
+
+        Ok(Self {
+            base: ty.into(),
+            x: x.into(),
+            y: y.into(),
+            rotation: Cell::new(unit.float_to_angle(rotation).as_radians() as f32),
+        })
+    }
+
+    #[attr]
+    #[stub(attrs = ["x: float", "y: float", "rotation: float"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let value = match attr.as_str() {
+            "x" => &self.x,
+            "y" => &self.y,
+            "rotation" => &self.rotation,
+            _ => return,
+        };
+
+        handle_op(op, value);
+    }
+
+    /// Returns the rotation measured in `unit`.
+    #[method]
+    fn get_rotation(&self, unit: &RotationUnitObj) -> f32 {
+        unit.unit()
+            .angle_to_float(Angle::from_radians(self.rotation.get() as f64))
+    }
+
+    /// Returns the transform that undoes this one, so that `t + t.inverse()` is the identity.
+    #[method]
+    fn inverse(&self) -> Self {
+        Self::new(self.pose().inverse())
+    }
+
+    fn eq(lhs: &Self, rhs: &Self) -> bool {
+        lhs.x.get() == rhs.x.get()
+            && lhs.y.get() == rhs.y.get()
+            && lhs.rotation.get() == rhs.rotation.get()
+    }
+
+    /// Implements exact equality with another `Transform2` and composition with `+`.
+    ///
+    /// Unsupported operand combinations follow Python's normal binary-operation fallback.
+    #[binary_op]
+    fn binary_op(op: BinaryOpCode, lhs: &Self, rhs: Obj) -> Obj {
+        match op {
+            BinaryOpCode::Equal => Obj::from_bool(
+                rhs.try_as_obj::<Self>()
+                    .is_some_and(|rhs| Self::eq(lhs, rhs)),
+            ),
+            BinaryOpCode::Add | BinaryOpCode::InplaceAdd => match rhs.try_as_obj::<Self>() {
+                Some(rhs) => Obj::from(Self::new(lhs.pose().compose(rhs.pose()))),
+                None => Obj::NULL,
+            },
+            _ => Obj::NULL,
+        }
+    }
+
+    /// Formats the transform as `Transform2(x=..., y=..., rotation=...)`, with the rotation in
+    /// radians.
+    #[printer]
+    fn printer(&self, print: &mut Print, _kind: PrintKind) {
+        let _ = write!(
+            print,
+            "Transform2(x={}, y={}, rotation={})",
+            self.x.get(),
+            self.y.get(),
+            self.rotation.get()
+        );
+    }
+}
+
+/// A PID (proportional-integral-derivative) controller with feedforward.
+///
+/// Each call to `PidController.update` compares a setpoint with a measurement and returns the
+/// output that drives the measurement towards the setpoint, such as a motor voltage. The output is
+/// the sum of:
+///
+/// - a proportional term, `kp * (setpoint_weight * setpoint - measurement)`;
+/// - an integral term, `ki` times the accumulated error, limited to `integral_limit`;
+/// - a derivative term, `kd` times the rate of change of
+///   `derivative_setpoint_weight * setpoint - measurement`, smoothed by a low-pass filter with a
+///   time constant of `derivative_filter` seconds;
+/// - a feedforward term, `ks * sign(velocity) + kv * velocity + ka * acceleration`, where
+///   `velocity` and `acceleration` describe how the setpoint is moving.
+///
+/// The output is then limited to `output_limit`. While the output is limited, the integral term
+/// stops accumulating in the direction that would push it further past the limit.
+///
+/// Setpoint weights below `1.0` soften the response to setpoint changes without affecting how
+/// disturbances are rejected. A `derivative_setpoint_weight` of `0.0` is a common choice to avoid a
+/// spike in the output when the setpoint changes suddenly.
+///
+/// Gains are per second: `dt` passed to `PidController.update` is converted to seconds, `ki` is
+/// multiplied by the error integrated over seconds, and `kd` by the error's rate of change per
+/// second. The gains `kp`, `ki`, `kd`, `ks`, `kv` and `ka` can be read and changed as attributes.
+///
+/// # Continuous input
+///
+/// If `continuous` is a `RotationUnit`, setpoints and measurements are treated as angles in that
+/// unit that wrap around every turn. The controller then always takes the shortest way around, so
+/// a setpoint of 350 degrees and a measurement of 10 degrees give an error of -20 degrees rather
+/// than 340 degrees.
+#[class(qstr!(PidController))]
+#[repr(C)]
+pub struct PidController {
+    base: ObjBase,
+    pid: Cell<Pid>,
+}
+
+/// Parses an optional magnitude limit, which must be non-negative.
+fn parse_limit(obj: Obj) -> Result<Option<f32>, Exception> {
+    if obj.is_none() {
+        return Ok(None);
+    }
+
+    let limit = obj
+        .try_to_float()
+        .or_else(|| obj.try_to_int().map(|i| i as f32))
+        .ok_or_else(|| type_error(c"expected float or None"))?;
+    if limit >= 0.0 {
+        Ok(Some(limit))
+    } else {
+        Err(value_error(c"limit cannot be negative").into())
+    }
+}
+
+#[class_methods]
+impl PidController {
+    /// Creates a PID controller with the given gains.
+    ///
+    /// All other settings are keyword-only and default to a plain PID controller: no feedforward,
+    /// no limits, no derivative filtering, full setpoint weights and discontinuous input.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If an argument has the wrong type or an unknown keyword argument is given.
+    /// - `ValueError`: If `integral_limit`, `output_limit` or `derivative_filter` is negative.
+    ///
+    /// # Examples
+    ///
+    /// Turn a drivetrain to a heading with an inertial sensor:
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// imu = InertialSensor(1)
+    /// left = MotorGroup([2, 3])
+    /// right = MotorGroup([(4, Direction.REVERSE), (5, Direction.REVERSE)])
+    ///
+    /// pid = PidController(0.3, 0.01, 0.02, output_limit=12, continuous=DEGREES)
+    ///
+    /// async def main():
+    ///     while True:
+    ///         voltage = pid.update(90, imu.get_heading(DEGREES), 10, unit=MILLIS)
+    ///         left.set_voltage(voltage)
+    ///         right.set_voltage(-voltage)
+    ///         await vasyncio.Sleep(10, MILLIS)
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    #[make_new]
+    #[stub(
+        sig = "(self, kp: float, ki: float = 0.0, kd: float = 0.0, /, *, ks: float = 0.0, kv: float = 0.0, ka: float = 0.0, integral_limit: float | None = None, output_limit: float | None = None, derivative_filter: float = 0.0, setpoint_weight: float = 1.0, derivative_setpoint_weight: float = 1.0, continuous: RotationUnit | None = None) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(1, 3).assert_nkw(0, 9);
+
+        let kp = reader.next_positional()?;
+        let ki = reader.next_positional_or(0.0)?;
+        let kd = reader.next_positional_or(0.0)?;
+        let mut pid = Pid::new(kp, ki, kd);
+
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "ks" => pid.ks = arg.parse()?,
+                "kv" => pid.kv = arg.parse()?,
+                "ka" => pid.ka = arg.parse()?,
+                "integral_limit" => pid.integral_limit = parse_limit(*arg.obj)?,
+                "output_limit" => pid.output_limit = parse_limit(*arg.obj)?,
+                "derivative_filter" => pid.derivative_filter = arg.parse()?,
+                "setpoint_weight" => pid.setpoint_weight = arg.parse()?,
+                "derivative_setpoint_weight" => pid.derivative_setpoint_weight = arg.parse()?,
+                "continuous" => {
+                    pid.period = if arg.obj.is_none() {
+                        None
+                    } else {
+                        let unit = arg.parse::<&RotationUnitObj>()?.unit();
+                        Some(unit.angle_to_float(Angle::from_turns(1.0)))
+                    }
+                }
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        if pid.derivative_filter < 0.0 || pid.derivative_filter.is_nan() {
+            Err(value_error(c"derivative filter cannot be negative"))?;
+        }
+
+        Ok(Self {
+            base: ty.into(),
+            pid: Cell::new(pid),
+        })
+    }
+
+    /// Returns the output for a new `measurement` of the system, `dt` after the previous update.
+    ///
+    /// `dt` is measured in `unit` and should be the time since the previous call to `update`; it is
+    /// used to integrate and differentiate the error. `velocity` and `acceleration` are the rate of
+    /// change of the setpoint per second and per second squared, used for feedforward. They can be
+    /// left at zero when the setpoint is constant.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `dt` is not positive or is non-finite.
+    ///
+    /// # Examples
+    ///
+    /// Hold an arm at 45 degrees:
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// arm = Motor(1)
+    /// pid = PidController(0.2, 0.0, 0.01, output_limit=Motor.V5_MAX_VOLTAGE)
+    ///
+    /// async def main():
+    ///     while True:
+    ///         arm.set_voltage(pid.update(45, arm.get_position(DEGREES), 10, unit=MILLIS))
+    ///         await vasyncio.Sleep(10, MILLIS)
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    #[method(ty = kw(min = 4))]
+    #[stub(
+        sig = "(self, setpoint: float, measurement: float, dt: float, /, *, unit: TimeUnit = TimeUnit.SECOND, velocity: float = 0.0, acceleration: float = 0.0) -> float"
+    )]
+    fn update(args: &[Obj], kw_map: &Map) -> Result<f32, Exception> {
+        let kwarg_count = kw_map.len();
+        let positional_count = args.len() - kwarg_count * 2;
+        let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+        reader.assert_npos(4, 4).assert_nkw(0, 3);
+
+        let controller = reader.next_positional::<&Self>()?;
+        let setpoint = reader.next_positional()?;
+        let measurement = reader.next_positional()?;
+        let dt = reader.next_positional()?;
+
+        let mut unit: &TimeUnitObj = TimeUnitObj::SECOND;
+        let mut velocity = 0.0;
+        let mut acceleration = 0.0;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "unit" => unit = arg.parse()?,
+                "velocity" => velocity = arg.parse()?,
+                "acceleration" => acceleration = arg.parse()?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        let dt = unit.unit().float_to_dur(dt)?.as_secs_f32();
+        if dt == 0.0 {
+            Err(value_error(c"dt must be positive"))?;
+        }
+
+        let mut pid = controller.pid.get();
+        let output = pid.update(setpoint, measurement, dt, velocity, acceleration);
+        controller.pid.set(pid);
+        Ok(output)
+    }
+
+    /// Clears the accumulated integral and the derivative history.
+    ///
+    /// Call this before reusing the controller for a new movement, so that error accumulated during
+    /// the previous one doesn't carry over.
+    #[method]
+    fn reset(&self) {
+        let mut pid = self.pid.get();
+        pid.reset();
+        self.pid.set(pid);
+    }
+
+    /// Loads, stores, or deletes the mutable gain attributes.
+    ///
+    /// Assigning a non-numeric value raises `TypeError`; deleting a gain resets it to `0.0`.
+    #[attr]
+    #[stub(attrs = ["kp: float", "ki: float", "kd: float", "ks: float", "kv: float", "ka: float"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let mut pid = self.pid.get();
+        let gain = match attr.as_str() {
+            "kp" => &mut pid.kp,
+            "ki" => &mut pid.ki,
+            "kd" => &mut pid.kd,
+            "ks" => &mut pid.ks,
+            "kv" => &mut pid.kv,
+            "ka" => &mut pid.ka,
+            _ => return,
+        };
+
+        handle_op(op, Cell::from_mut(gain));
+        self.pid.set(pid);
+    }
+}
+
 pub fn handle_op(op: AttrOp, val: &Cell<f32>) {
     match op {
         AttrOp::Load { result } => result.return_value(Obj::from_float(val.get())),
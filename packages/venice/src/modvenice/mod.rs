@@ -6,17 +6,25 @@ mod competition;
 mod controller;
 mod display;
 mod distance_sensor;
+mod drive;
 mod electromagnet;
+mod fusion;
 mod gps;
 mod imu;
 mod link;
 mod math;
 mod motor;
+mod odometry;
 mod optical;
+mod path;
+mod profile;
 mod read_only_attr;
 mod rotation_sensor;
 mod serial;
+mod trajectory;
+mod ui;
 mod units;
+mod validate;
 mod vasyncio;
 mod vision;
 
@@ -66,22 +74,29 @@ use crate::modvenice::{
     },
     display::DISPLAY_DICT,
     distance_sensor::{DistanceSensorObj, distance_object::DistanceObjectObj},
+    drive::{DifferentialDrive, HolonomicDrive},
     electromagnet::ElectromagnetObj,
+    fusion::{PoseEstimator, PoseEstimatorFuture},
     gps::GpsSensorObj,
     imu::{InertialOrientationObj, InertialSensorObj},
     link::{LinkTypeObj, RadioLinkObj},
-    math::{EulerZYX, Point2, Quaternion, Vec3},
+    math::{EulerZYX, PidController, Point2, Pose2, Quaternion, Transform2, Vec3},
     motor::{
         MotorObj, brake::BrakeModeObj, direction::DirectionObj, gearset::GearsetObj,
-        motor_type::MotorTypeObj,
+        group::MotorGroupObj, motor_type::MotorTypeObj,
     },
+    odometry::{OdometryFuture, OdometryObj, TrackingWheel},
     optical::{
         OpticalSensorObj,
         gesture::{GestureDirectionObj, GestureObj},
         rgb::{OpticalRawObj, OpticalRgbObj},
     },
+    path::{FollowFuture, PurePursuit, Ramsete},
+    profile::{ProfileState, SCurveProfile, TrapezoidProfile},
     rotation_sensor::RotationSensorObj,
     serial::{SerialPortObj, SerialPortOpenFutureObj},
+    trajectory::{TrajectoryObj, TrajectoryState},
+    ui::UI_DICT,
     units::{rotation::RotationUnitObj, time::TimeUnitObj},
     vasyncio::VASYNCIO_DICT,
     vision::{
@@ -201,10 +216,13 @@ static mut venice_globals: Dict = Dict::new(const_map![
 
     // motor
     qstr!(Motor) => Obj::from_static(MotorObj::OBJ_TYPE),
+    qstr!(MotorGroup) => Obj::from_static(MotorGroupObj::OBJ_TYPE),
     qstr!(Gearset) => Obj::from_static(GearsetObj::OBJ_TYPE),
     qstr!(BrakeMode) => Obj::from_static(BrakeModeObj::OBJ_TYPE),
     qstr!(Direction) => Obj::from_static(DirectionObj::OBJ_TYPE),
     qstr!(MotorType) => Obj::from_static(MotorTypeObj::OBJ_TYPE),
+    qstr!(DifferentialDrive) => Obj::from_static(DifferentialDrive::OBJ_TYPE),
+    qstr!(HolonomicDrive) => Obj::from_static(HolonomicDrive::OBJ_TYPE),
     // controller
     qstr!(Controller) => Obj::from_static(ControllerObj::OBJ_TYPE),
     qstr!(ControllerId) => Obj::from_static(ControllerIdObj::OBJ_TYPE),
@@ -283,6 +301,7 @@ static mut venice_globals: Dict = Dict::new(const_map![
     qstr!(vasyncio) => Obj::from_static(&Module::new(VASYNCIO_DICT)),
     qstr!(battery) => Obj::from_static(&Module::new(BATTERY_DICT)),
     qstr!(display) => Obj::from_static(&Module::new(DISPLAY_DICT)),
+    qstr!(ui) => Obj::from_static(&Module::new(UI_DICT)),
 
     // time
     qstr!(monotonic_time) => monotonic_time_obj,
@@ -292,6 +311,22 @@ static mut venice_globals: Dict = Dict::new(const_map![
     qstr!(Quaternion) => Obj::from_static(Quaternion::OBJ_TYPE),
     qstr!(EulerZYX) => Obj::from_static(EulerZYX::OBJ_TYPE),
     qstr!(Point2) => Obj::from_static(Point2::OBJ_TYPE),
+    qstr!(Pose2) => Obj::from_static(Pose2::OBJ_TYPE),
+    qstr!(Transform2) => Obj::from_static(Transform2::OBJ_TYPE),
+    qstr!(PidController) => Obj::from_static(PidController::OBJ_TYPE),
+    qstr!(TrapezoidProfile) => Obj::from_static(TrapezoidProfile::OBJ_TYPE),
+    qstr!(SCurveProfile) => Obj::from_static(SCurveProfile::OBJ_TYPE),
+    qstr!(ProfileState) => Obj::from_static(ProfileState::OBJ_TYPE),
+    qstr!(TrackingWheel) => Obj::from_static(TrackingWheel::OBJ_TYPE),
+    qstr!(Odometry) => Obj::from_static(OdometryObj::OBJ_TYPE),
+    qstr!(OdometryFuture) => Obj::from_static(OdometryFuture::OBJ_TYPE),
+    qstr!(PoseEstimator) => Obj::from_static(PoseEstimator::OBJ_TYPE),
+    qstr!(PoseEstimatorFuture) => Obj::from_static(PoseEstimatorFuture::OBJ_TYPE),
+    qstr!(PurePursuit) => Obj::from_static(PurePursuit::OBJ_TYPE),
+    qstr!(Ramsete) => Obj::from_static(Ramsete::OBJ_TYPE),
+    qstr!(FollowFuture) => Obj::from_static(FollowFuture::OBJ_TYPE),
+    qstr!(Trajectory) => Obj::from_static(TrajectoryObj::OBJ_TYPE),
+    qstr!(TrajectoryState) => Obj::from_static(TrajectoryState::OBJ_TYPE),
     // color
     qstr!(Color) => Obj::from_static(ColorObj::OBJ_TYPE),
 
@@ -0,0 +1,597 @@
+use argparse::{Args, error_msg};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{type_error, value_error},
+    init::token,
+    obj::{Obj, ObjBase, ObjTrait, ObjType},
+};
+use vexide_devices::{
+    math::Direction,
+    smart::{SmartPort, motor::Motor},
+};
+
+use super::{brake::BrakeModeObj, direction::DirectionObj, gearset::GearsetObj};
+use crate::{
+    alloc::Gc,
+    devices::{self, PortNumber},
+    modvenice::{Exception, units::rotation::RotationUnitObj},
+    registry::SmartGuard,
+};
+
+/// Several motors that are driven together as one.
+///
+/// A `MotorGroup` is useful for mechanisms that are powered by more than one motor, such as one
+/// side of a drivetrain or a double-motor lift. Every command sent to the group is sent to each of
+/// its motors, and readings from the group combine the readings of each motor:
+///
+/// - Position, velocity, voltage and efficiency are averaged.
+/// - Current, power and torque are summed.
+/// - Temperature is the hottest motor's temperature.
+/// - Fault and status flags are combined with a bitwise OR, and the `is_*` flag checks are `True`
+///   if any motor has the flag set.
+///
+/// Each motor's `Direction` is chosen when the group is created, so motors that are mounted facing
+/// the opposite way can be reversed once and then commanded like the rest of the group. Unlike
+/// `Motor`, a group has no `set_direction` method.
+///
+/// The group reserves each of its Smart Ports until it is freed with `MotorGroup.free`.
+///
+/// # Errors from individual motors
+///
+/// Commands are always sent to every motor in the group, even if sending it to an earlier motor
+/// failed, so one disconnected motor won't leave the others running with a stale command. If any
+/// motor failed, the first error is raised once every motor has been commanded. Readings raise as
+/// soon as any motor fails to report.
+#[class(qstr!(MotorGroup))]
+#[repr(C)]
+pub struct MotorGroupObj {
+    base: ObjBase,
+    motors: Vec<SmartGuard<Motor>, Gc>,
+}
+
+fn parse_port(obj: &Obj) -> Result<PortNumber, Exception> {
+    let number = obj
+        .try_to_int()
+        .ok_or_else(|| type_error(c"expected a port number or a (port, Direction) pair"))?;
+    PortNumber::from_i32(number)
+        .map_err(|_| value_error(error_msg!("port '{number}' is not a valid Smart Port")).into())
+}
+
+fn parse_member(obj: &Obj) -> Result<(PortNumber, Direction), Exception> {
+    let Some(pair) = obj.try_array() else {
+        return Ok((parse_port(obj)?, Direction::Forward));
+    };
+
+    let [port, direction] = pair.as_slice() else {
+        return Err(type_error(c"expected a port number or a (port, Direction) pair").into());
+    };
+    let direction = direction
+        .try_as_obj::<DirectionObj>()
+        .ok_or_else(|| type_error(c"expected a Direction"))?;
+
+    Ok((parse_port(port)?, direction.direction()))
+}
+
+impl MotorGroupObj {
+    fn new(motors: Obj, init: impl Fn(SmartPort, Direction) -> Motor) -> Result<Self, Exception> {
+        // Every port is checked before any is locked, so that a bad member doesn't leave the ports
+        // before it reserved.
+        let members = motors
+            .try_array()
+            .ok_or_else(|| type_error(c"expected a list or tuple of motors"))?
+            .iter()
+            .map(parse_member)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if members.is_empty() {
+            Err(value_error(c"a motor group needs at least one motor"))?;
+        }
+
+        for (i, (port, _)) in members.iter().enumerate() {
+            if members[..i].iter().any(|(other, _)| other == port) {
+                Err(value_error(c"the same Smart Port cannot be used twice"))?;
+            }
+            if !devices::port_is_available(*port) {
+                Err(value_error(error_msg!(
+                    "port '{}' is occupied by another device",
+                    port.number()
+                )))?;
+            }
+        }
+
+        let mut guards = Vec::with_capacity_in(members.len(), Gc { token: token() });
+        guards.extend(members.into_iter().map(|(port, direction)| {
+            devices::try_lock_port(port, |port| init(port, direction))
+                .expect("validated Smart Port became unavailable during commit")
+        }));
+
+        Ok(Self {
+            base: Self::OBJ_TYPE.into(),
+            motors: guards,
+        })
+    }
+
+    /// Runs `command` on every motor, returning the first error once all motors have run it.
+    fn command<E>(
+        &self,
+        mut command: impl FnMut(&mut Motor) -> Result<(), E>,
+    ) -> Result<(), Exception>
+    where
+        Exception: From<E>,
+    {
+        let mut result = Ok(());
+        for motor in &self.motors {
+            let outcome = command(&mut motor.borrow_mut());
+            if result.is_ok() {
+                result = outcome;
+            }
+        }
+        Ok(result?)
+    }
+
+    /// Spins every motor at `rpm`, as `MotorGroup.set_velocity` does.
+    pub fn set_rpm(&self, rpm: i32) -> Result<(), Exception> {
+        self.command(|motor| motor.set_velocity(rpm))
+    }
+
+    /// Sets every motor's voltage to `output` times its maximum voltage.
+    pub fn set_output(&self, output: f64) -> Result<(), Exception> {
+        self.command(|motor| {
+            let volts = output * motor.max_voltage();
+            motor.set_voltage(volts)
+        })
+    }
+
+    /// Combines one reading from every motor with `combine`, starting from `init`.
+    fn fold<T, A, E>(
+        &self,
+        init: A,
+        read: impl Fn(&Motor) -> Result<T, E>,
+        combine: impl Fn(A, T) -> A,
+    ) -> Result<A, Exception>
+    where
+        Exception: From<E>,
+    {
+        self.motors
+            .iter()
+            .try_fold(init, |acc, motor| Ok(combine(acc, read(&motor.borrow())?)))
+    }
+
+    fn average<E>(&self, read: impl Fn(&Motor) -> Result<f64, E>) -> Result<f32, Exception>
+    where
+        Exception: From<E>,
+    {
+        let sum = self.fold(0.0, read, |sum, value| sum + value)?;
+        Ok((sum / self.motors.len() as f64) as f32)
+    }
+
+    fn sum<E>(&self, read: impl Fn(&Motor) -> Result<f64, E>) -> Result<f32, Exception>
+    where
+        Exception: From<E>,
+    {
+        Ok(self.fold(0.0, read, |sum, value| sum + value)? as f32)
+    }
+
+    fn any<E>(&self, read: impl Fn(&Motor) -> Result<bool, E>) -> Result<bool, Exception>
+    where
+        Exception: From<E>,
+    {
+        self.fold(false, read, |any, value| any || value)
+    }
+}
+
+#[class_methods]
+impl MotorGroupObj {
+    /// Creates a group of 11W (V5) Smart Motors.
+    ///
+    /// Each entry of `motors` is either a port number, for a motor spinning `Direction.FORWARD`, or
+    /// a `(port, Direction)` pair. Every motor in the group uses `gearset`.
+    ///
+    /// See `MotorGroup.new_exp` to create a group of 5.5W (EXP) Smart Motors.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `motors` isn't a list or tuple of port numbers and `(port, Direction)`
+    ///   pairs.
+    /// - `ValueError`: If `motors` is empty, names an invalid port or the same port twice, or if any
+    ///   of the ports is already in use. In that case, none of the ports are reserved.
+    ///
+    /// # Examples
+    ///
+    /// Drive the left side of a drivetrain, where the back motor is mounted backwards:
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// left = MotorGroup([1, 2, (3, Direction.REVERSE)], Gearset.BLUE)
+    /// left.set_voltage(6)
+    /// ```
+    #[make_new]
+    #[stub(
+        sig = "(self, motors: list[int | tuple[int, Direction]], gearset: Gearset = Gearset.GREEN, /) -> None"
+    )]
+    fn make_new(_: &ObjType, n_pos: usize, n_kw: usize, args: &[Obj]) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(1, 2).assert_nkw(0, 0);
+
+        let motors = reader.next_positional::<Obj>()?;
+        let gearset = reader
+            .next_positional_or::<&GearsetObj>(GearsetObj::GREEN)?
+            .gearset();
+
+        Self::new(motors, |port, direction| {
+            Motor::new(port, gearset, direction)
+        })
+    }
+
+    /// Creates a group of 5.5W (EXP) Smart Motors.
+    ///
+    /// `motors` is given in the same form as for `MotorGroup()`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `motors` isn't a list or tuple of port numbers and `(port, Direction)`
+    ///   pairs.
+    /// - `ValueError`: If `motors` is empty, names an invalid port or the same port twice, or if any
+    ///   of the ports is already in use. In that case, none of the ports are reserved.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// intake = MotorGroup.new_exp([4, (5, Direction.REVERSE)])
+    /// intake.set_voltage(Motor.EXP_MAX_VOLTAGE)
+    /// ```
+    #[method(ty = var_between(min = 1, max = 1), binding = "static")]
+    #[stub(sig = "(motors: list[int | tuple[int, Direction]], /) -> MotorGroup")]
+    fn new_exp(args: &[Obj]) -> Result<Self, Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+        let motors = reader.next_positional::<Obj>()?;
+
+        Self::new(motors, Motor::new_exp)
+    }
+
+    /// Sets the output voltage of every motor in the group.
+    ///
+    /// See `Motor.set_voltage`.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn set_voltage(&self, volts: f32) -> Result<(), Exception> {
+        self.command(|motor| motor.set_voltage(volts as f64))
+    }
+
+    /// Spins every motor in the group at a target velocity in RPM.
+    ///
+    /// See `Motor.set_velocity`.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn set_velocity(&self, rpm: i32) -> Result<(), Exception> {
+        self.set_rpm(rpm)
+    }
+
+    /// Stops every motor in the group with the given `BrakeMode`.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn brake(&self, mode: &BrakeModeObj) -> Result<(), Exception> {
+        self.command(|motor| motor.brake(mode.mode()))
+    }
+
+    /// Sets the gearset of every motor in the group.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is a 5.5W EXP Smart Motor, which has no swappable gearset, or if
+    /// any motor is disconnected.
+    #[method]
+    fn set_gearset(&self, gearset: &GearsetObj) -> Result<(), Exception> {
+        self.command(|motor| motor.set_gearset(gearset.gearset()))
+    }
+
+    /// Sets an absolute position target for every motor in the group to attempt to reach.
+    ///
+    /// Each motor moves to the target as measured by its own encoder, so motors whose positions
+    /// have drifted apart should be reset with `MotorGroup.reset_position` first.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method(ty = var_between(min = 4, max = 4))]
+    #[stub(sig = "(self, position: float, unit: RotationUnit, velocity: int, /) -> None")]
+    fn set_position_target(args: &[Obj]) -> Result<(), Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+
+        let group = reader.next_positional::<&MotorGroupObj>()?;
+        let position_val = reader.next_positional()?;
+        let unit_obj = reader.next_positional::<&RotationUnitObj>()?;
+        let velocity_val = reader.next_positional()?;
+
+        let angle = unit_obj.unit().float_to_angle(position_val);
+        group.command(|motor| motor.set_position_target(angle, velocity_val))
+    }
+
+    /// Changes the output velocity of every motor's profiled movement.
+    ///
+    /// See `Motor.set_profiled_velocity`.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn set_profiled_velocity(&self, velocity: i32) -> Result<(), Exception> {
+        self.command(|motor| motor.set_profiled_velocity(velocity))
+    }
+
+    /// Sets the encoder position of every motor in the group to zero without moving them.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn reset_position(&self) -> Result<(), Exception> {
+        self.command(|motor| motor.reset_position())
+    }
+
+    /// Sets the encoder position of every motor in the group to the given position without moving
+    /// them.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn set_position(&self, position: f32, unit: &RotationUnitObj) -> Result<(), Exception> {
+        let angle = unit.unit().float_to_angle(position);
+        self.command(|motor| motor.set_position(angle))
+    }
+
+    /// Sets the current limit of every motor in the group in amps.
+    ///
+    /// The limit applies to each motor separately, so the group as a whole may draw up to `limit`
+    /// times the number of motors.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn set_current_limit(&self, limit: f32) -> Result<(), Exception> {
+        self.command(|motor| motor.set_current_limit(limit as f64))
+    }
+
+    /// Sets the voltage limit of every motor in the group in volts.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn set_voltage_limit(&self, limit: f32) -> Result<(), Exception> {
+        self.command(|motor| motor.set_voltage_limit(limit as f64))
+    }
+
+    /// Returns the average angular position of the motors in the group.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// lift = MotorGroup([1, (2, Direction.REVERSE)])
+    /// print(f"Lift position: {lift.get_position(DEGREES)}")
+    /// ```
+    #[method]
+    fn get_position(&self, unit: &RotationUnitObj) -> Result<f32, Exception> {
+        let unit = unit.unit();
+        self.average(|motor| Ok::<_, Exception>(unit.angle_to_float(motor.position()?) as f64))
+    }
+
+    /// Returns the average estimated angular velocity of the motors in the group in RPM.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn get_velocity(&self) -> Result<f32, Exception> {
+        self.average(|motor| motor.velocity())
+    }
+
+    /// Returns the average voltage of the motors in the group in volts.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn get_voltage(&self) -> Result<f32, Exception> {
+        self.average(|motor| motor.voltage())
+    }
+
+    /// Returns the average efficiency of the motors in the group from a range of [0.0, 1.0].
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn get_efficiency(&self) -> Result<f32, Exception> {
+        self.average(|motor| motor.efficiency())
+    }
+
+    /// Returns the total electrical current drawn by the motors in the group in amps.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn get_current(&self) -> Result<f32, Exception> {
+        self.sum(|motor| motor.current())
+    }
+
+    /// Returns the total power drawn by the motors in the group in Watts.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn get_power(&self) -> Result<f32, Exception> {
+        self.sum(|motor| motor.power())
+    }
+
+    /// Returns the total torque output of the motors in the group in Nm.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn get_torque(&self) -> Result<f32, Exception> {
+        self.sum(|motor| motor.torque())
+    }
+
+    /// Returns the internal temperature of the hottest motor in the group in increments of 5 °C.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    ///
+    /// # Examples
+    ///
+    /// Stop the group if any of its motors gets too hot:
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// drive = MotorGroup([1, 2, 3])
+    ///
+    /// async def main():
+    ///     drive.set_voltage(12)
+    ///     while drive.get_temperature() <= 50:
+    ///         await vasyncio.Sleep(10, MILLIS)
+    ///     drive.brake(BrakeMode.COAST)
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    #[method]
+    fn get_temperature(&self) -> Result<f32, Exception> {
+        Ok(self.fold(f64::NEG_INFINITY, |motor| motor.temperature(), f64::max)? as f32)
+    }
+
+    /// Returns the status flags of every motor in the group, combined with a bitwise OR.
+    ///
+    /// See `Motor.get_status`.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn get_status(&self) -> Result<i32, Exception> {
+        self.fold(
+            0,
+            |motor| motor.status().map(|status| status.bits() as i32),
+            |flags, status| flags | status,
+        )
+    }
+
+    /// Returns the fault flags of every motor in the group, combined with a bitwise OR.
+    ///
+    /// See `Motor.get_faults`.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn get_faults(&self) -> Result<i32, Exception> {
+        self.fold(
+            0,
+            |motor| motor.faults().map(|faults| faults.bits() as i32),
+            |flags, faults| flags | faults,
+        )
+    }
+
+    /// Returns `True` if any motor in the group has its over temperature flag set.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn is_over_temperature(&self) -> Result<bool, Exception> {
+        self.any(|motor| motor.is_over_temperature())
+    }
+
+    /// Returns `True` if any motor in the group has its over-current flag set.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn is_over_current(&self) -> Result<bool, Exception> {
+        self.any(|motor| motor.is_over_current())
+    }
+
+    /// Returns `True` if a H-bridge (motor driver) fault has occurred in any motor in the group.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn is_driver_fault(&self) -> Result<bool, Exception> {
+        self.any(|motor| motor.is_driver_fault())
+    }
+
+    /// Returns `True` if the H-bridge of any motor in the group has an over-current fault.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+    /// any of the group's ports.
+    #[method]
+    fn is_driver_over_current(&self) -> Result<bool, Exception> {
+        self.any(|motor| motor.is_driver_over_current())
+    }
+
+    /// Release every motor in this group and free their Smart Port locks. This binding will become
+    /// unusable after this call, but you can reuse the underlying Smart Port numbers in new
+    /// devices.
+    ///
+    /// Any attempts to use this group after freeing will result in a `ValueError` being raised.
+    ///
+    /// # Raises
+    ///
+    /// `ValueError`: If the group has already been freed.
+    #[method]
+    fn free(&self) {
+        for motor in &self.motors {
+            motor.free_or_raise();
+        }
+    }
+}
@@ -1,31 +1,50 @@
 pub mod brake;
 pub mod direction;
 pub mod gearset;
+pub mod group;
 pub mod motor_type;
 
+use std::cell::Cell;
+
 use argparse::{Args, error_msg};
 use brake::BrakeModeObj;
 use direction::DirectionObj;
 use gearset::GearsetObj;
 use micropython_macros::{class, class_methods};
 use micropython_rs::{
-    except::type_error,
+    except::{raise_msg, raise_stop_iteration, type_error, value_error},
+    init::token,
+    map::Map,
+    nlr,
     obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
     qstr::Qstr,
 };
+use venice_core::profile::MotionProfile;
 use vexide_devices::{
-    math::Direction,
+    math::{Angle, Direction},
     smart::{
         SmartDevice,
-        motor::{Gearset, Motor, SetGearsetError},
+        motor::{BrakeMode, Gearset, Motor, SetGearsetError},
     },
 };
 
 use crate::{
     devices::{self},
     modvenice::{
-        Exception, device_error, motor::motor_type::MotorTypeObj, read_only_attr::read_only_attr,
-        units::rotation::RotationUnitObj,
+        Exception, device_error,
+        motor::motor_type::MotorTypeObj,
+        profile::profile_from_obj,
+        read_only_attr::read_only_attr,
+        units::{
+            rotation::{RotationUnit, RotationUnitObj},
+            time::TimeUnitObj,
+        },
+        vasyncio::{
+            polled::PolledFuture,
+            time32,
+            until::DEVICE_POLL_INTERVAL,
+            wait::{Deadline, TIMEOUT_ERROR_TYPE, Timeout},
+        },
     },
     registry::SmartGuard,
 };
@@ -91,6 +110,15 @@ pub struct MotorObj {
     guard: SmartGuard<Motor>,
 }
 
+impl MotorObj {
+    /// Sets the motor's voltage to `output` times its maximum voltage.
+    pub fn set_output(&self, output: f64) -> Result<(), Exception> {
+        let mut motor = self.guard.borrow_mut();
+        let volts = output * motor.max_voltage();
+        Ok(motor.set_voltage(volts)?)
+    }
+}
+
 impl From<SetGearsetError> for Exception {
     fn from(value: SetGearsetError) -> Self {
         device_error(error_msg!("{value}"))
@@ -408,6 +436,155 @@ impl MotorObj {
         Ok(())
     }
 
+    /// Moves the motor to an absolute `position`, measured in `unit`, at up to `velocity` RPM, and
+    /// returns a `MoveFuture` that completes once the motor has settled at the position.
+    ///
+    /// The position target is sent to the motor when the future is first awaited. The future then
+    /// completes once the motor's position has stayed within `tolerance` of `position` for
+    /// `settle_time`. `tolerance` is measured in `unit` and defaults to one degree; `settle_time`
+    /// and `timeout` are measured in `time_unit`. A `timeout` of `None` waits without a time limit.
+    ///
+    /// Awaiting the future again sends the position target again and restarts the timeout. If the
+    /// awaiting task is cancelled before the motor settles, the motor brakes.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `tolerance` is negative, or if `settle_time` or `timeout` is negative,
+    ///   non-finite, or too large to represent.
+    ///
+    /// Awaiting the returned future raises:
+    ///
+    /// - `DeviceError`: If no device is connected to the port, if the wrong type of device is
+    ///   connected, or if the motor reports any fault flags (see `Motor.get_faults`) while moving.
+    /// - `vasyncio.TimeoutError`: If the motor hasn't settled at the position before `timeout`
+    ///   elapses. The motor keeps its position target.
+    ///
+    /// # Examples
+    ///
+    /// Raise an arm, then lower it again once it has arrived:
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// arm = Motor(1)
+    ///
+    /// async def main():
+    ///     await arm.move_to(90, 100, DEGREES, tolerance=2, timeout=1500)
+    ///     await arm.move_to(0, 100, DEGREES)
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    #[method(ty = kw(min = 4))]
+    #[stub(
+        sig = "(self, position: float, velocity: int, unit: RotationUnit, /, *, tolerance: float | None = None, settle_time: float = 50, timeout: float | None = None, time_unit: TimeUnit = TimeUnit.MILLIS) -> MoveFuture"
+    )]
+    fn move_to(args: &[Obj], kw_map: &Map) -> Result<MoveFuture, Exception> {
+        let kwarg_count = kw_map.len();
+        let positional_count = args.len() - kwarg_count * 2;
+        let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+        reader.assert_npos(4, 4).assert_nkw(0, 4);
+
+        let motor = reader.next_positional::<Obj>()?;
+        let position = reader.next_positional()?;
+        let velocity = reader.next_positional()?;
+        let unit = reader.next_positional::<&RotationUnitObj>()?.unit();
+
+        let mut tolerance = Angle::from_degrees(1.0);
+        let mut settle_time = 50.0;
+        let mut timeout = Timeout::default();
+        let mut time_unit: &TimeUnitObj = TimeUnitObj::MILLIS;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "tolerance" => {
+                    if !arg.obj.is_none() {
+                        tolerance = unit.float_to_angle(arg.parse()?);
+                    }
+                }
+                "settle_time" => settle_time = arg.parse()?,
+                "timeout" => timeout = arg.parse()?,
+                "time_unit" => time_unit = arg.parse()?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        if tolerance.as_degrees() < 0.0 {
+            Err(value_error(c"tolerance cannot be negative"))?;
+        }
+
+        Ok(MoveFuture {
+            base: MoveFuture::OBJ_TYPE.into(),
+            motor,
+            target: unit.float_to_angle(position).as_degrees() as f32,
+            velocity,
+            tolerance: tolerance.as_degrees() as f32,
+            settle_time: time32::Duration::from_duration(
+                time_unit.unit().float_to_dur(settle_time)?,
+            ),
+            deadline: Deadline::new(timeout.to_duration(time_unit)?),
+            settled_since: Cell::new(None),
+            moving: Cell::new(false),
+            next_poll: Cell::new(None),
+        })
+    }
+
+    /// Drives the motor along a motion `profile`, whose distances are measured in `unit`, and returns
+    /// a `ProfileFuture` that completes when the profile ends.
+    ///
+    /// The profile starts from the motor's position when the future is first awaited. The motor is
+    /// given a position target at the end of the profile, and its profiled velocity is then updated
+    /// every 10 milliseconds to follow the profile's velocity, so the motor's own position
+    /// controller does the tracking. Velocities in the profile are in `unit` per second.
+    ///
+    /// When the future completes, the motor keeps its position target, so it finishes any
+    /// remaining distance and then holds the end position. Use `Motor.move_to` afterwards to wait
+    /// until it has settled there.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `profile` isn't a `TrapezoidProfile` or `SCurveProfile`.
+    ///
+    /// Awaiting the returned future raises:
+    ///
+    /// - `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+    ///   connected.
+    ///
+    /// # Examples
+    ///
+    /// Raise a lift by half a turn of its motor, smoothly:
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// lift = Motor(1)
+    /// profile = SCurveProfile(0.5, 1, 2, 10)
+    ///
+    /// async def main():
+    ///     await lift.follow_profile(profile, TURNS)
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    #[method]
+    #[stub(
+        sig = "(self, profile: TrapezoidProfile | SCurveProfile, unit: RotationUnit, /) -> ProfileFuture"
+    )]
+    fn follow_profile(
+        self_in: Obj,
+        profile: Obj,
+        unit: &RotationUnitObj,
+    ) -> Result<ProfileFuture, Exception> {
+        let profile = profile_from_obj(profile)
+            .ok_or_else(|| type_error(c"expected a TrapezoidProfile or SCurveProfile"))?;
+
+        Ok(ProfileFuture {
+            base: ProfileFuture::OBJ_TYPE.into(),
+            motor: self_in,
+            profile,
+            unit: unit.unit(),
+            start: Cell::new(None),
+            next_poll: Cell::new(None),
+        })
+    }
+
     /// Returns the motor's estimate of its angular velocity in rotations per minute (RPM).
     ///
     /// # Accuracy
@@ -1200,3 +1377,249 @@ impl MotorObj {
         self.guard.free_or_raise();
     }
 }
+
+/// An awaitable that completes once a motor has settled at a position target, created with
+/// `Motor.move_to`.
+///
+/// The motor's position and fault flags are checked every 10 milliseconds, the rate at which the
+/// Brain reads motor data. Between checks, the awaiting task is parked on the event loop, so other
+/// tasks keep running.
+#[class(qstr!(MoveFuture))]
+#[repr(C)]
+pub struct MoveFuture {
+    base: ObjBase,
+    motor: Obj,
+    // in degrees
+    target: f32,
+    velocity: i32,
+    // in degrees
+    tolerance: f32,
+    settle_time: time32::Duration,
+    deadline: Deadline,
+    settled_since: Cell<Option<time32::Instant>>,
+    moving: Cell<bool>,
+    next_poll: Cell<Option<time32::Instant>>,
+}
+
+impl MoveFuture {
+    /// Sends the position target if it hasn't been yet, then returns whether the motor has settled.
+    fn poll(&self) -> Result<bool, Exception> {
+        let mut motor = self.motor.as_obj::<MotorObj>().guard.borrow_mut();
+        if !self.moving.get() {
+            motor.set_position_target(Angle::from_degrees(self.target.into()), self.velocity)?;
+            self.moving.set(true);
+        }
+
+        let faults = motor.faults()?;
+        if !faults.is_empty() {
+            return Err(device_error(error_msg!(
+                "motor reported faults while moving: {faults:?}"
+            )));
+        }
+
+        let now = time32::Instant::now();
+        let error = (motor.position()?.as_degrees() - f64::from(self.target)).abs();
+        let settled_since =
+            (error <= self.tolerance.into()).then(|| self.settled_since.get().unwrap_or(now));
+        self.settled_since.set(settled_since);
+
+        Ok(settled_since.is_some_and(|since| now - since >= self.settle_time))
+    }
+
+    /// Returns the future to its initial state, so that awaiting it again starts a new move.
+    fn finish(&self) {
+        self.deadline.reset();
+        self.settled_since.set(None);
+        self.moving.set(false);
+        self.next_poll.set(None);
+    }
+
+    /// Stops the motor if the move has started, then returns the future to its initial state.
+    fn abandon(&self) {
+        if self.moving.get() {
+            let mut motor = self.motor.as_obj::<MotorObj>().guard.borrow_mut();
+            let _ = motor.brake(BrakeMode::Brake);
+        }
+        self.finish();
+    }
+}
+
+impl PolledFuture for MoveFuture {
+    /// Returns when the motor should next be checked, if the future is waiting.
+    fn next_poll(&self) -> Option<time32::Instant> {
+        self.next_poll.get()
+    }
+}
+
+#[class_methods]
+impl MoveFuture {
+    #[iter]
+    extern "C" fn move_iternext(self_in: Obj) -> Obj {
+        let this = self_in.as_obj::<Self>();
+        this.deadline.start();
+
+        // The motor is released before raising through MicroPython's non-local-return boundary.
+        let result = this.poll();
+        match result {
+            Ok(true) => {
+                this.finish();
+                raise_stop_iteration(token(), Obj::NONE)
+            }
+            Ok(false) if this.deadline.has_passed() => {
+                this.finish();
+                raise_msg(
+                    token(),
+                    TIMEOUT_ERROR_TYPE.as_obj_type(),
+                    c"motor did not reach its target before the timeout",
+                )
+            }
+            Ok(false) => {
+                let next_poll = time32::Instant::now()
+                    .checked_add(DEVICE_POLL_INTERVAL)
+                    .unwrap_or_else(|| value_error(c"poll interval is too large").raise(token()));
+                this.next_poll.set(Some(next_poll));
+                this.pend(self_in)
+            }
+            Err(error) => {
+                this.finish();
+                error.raise(token())
+            }
+        }
+    }
+
+    /// Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
+    ///
+    /// The motor brakes and the move is abandoned before the exception propagates.
+    #[method]
+    #[stub(sig = "(self, exc: BaseException, /) -> Any")]
+    fn throw(&self, exc: Obj) -> Obj {
+        self.abandon();
+        nlr::raise(token(), exc)
+    }
+
+    /// Brakes the motor and abandons the move when the awaiting coroutine is closed.
+    #[method]
+    fn close(&self) {
+        self.abandon();
+    }
+}
+
+/// An awaitable that drives a motor along a motion profile, created with `Motor.follow_profile`.
+///
+/// Between updates to the motor's velocity, the awaiting task is parked on the event loop, so
+/// other tasks keep running.
+#[class(qstr!(ProfileFuture))]
+#[repr(C)]
+pub struct ProfileFuture {
+    base: ObjBase,
+    motor: Obj,
+    profile: MotionProfile,
+    unit: RotationUnit,
+    // When the profile started
+    start: Cell<Option<time32::Instant>>,
+    next_poll: Cell<Option<time32::Instant>>,
+}
+
+impl ProfileFuture {
+    /// Converts a profile velocity in the profile's unit per second to a profiled velocity in RPM.
+    fn rpm(&self, velocity: f32) -> i32 {
+        let rpm = self.unit.float_to_angle(velocity.abs()).as_turns() * 60.0;
+        // The profile is at rest at its ends, but the motor still has to get moving
+        rpm.round().max(1.0) as i32
+    }
+
+    /// Starts the profile if it hasn't been yet, then returns whether the profile has ended.
+    fn poll(&self) -> Result<bool, Exception> {
+        let mut motor = self.motor.as_obj::<MotorObj>().guard.borrow_mut();
+        let now = time32::Instant::now();
+
+        let started = match self.start.get() {
+            Some(started) => started,
+            None => {
+                let position = motor.position()?.as_degrees();
+                let distance = self.unit.float_to_angle(self.profile.distance());
+                motor.set_position_target(
+                    Angle::from_degrees(position + distance.as_degrees()),
+                    self.rpm(0.0),
+                )?;
+                self.start.set(Some(now));
+                now
+            }
+        };
+
+        let elapsed = (now - started).to_duration().as_secs_f32();
+        if elapsed >= self.profile.duration() {
+            return Ok(true);
+        }
+
+        let velocity = self.profile.sample(elapsed).velocity;
+        motor.set_profiled_velocity(self.rpm(velocity))?;
+        Ok(false)
+    }
+
+    /// Returns the future to its initial state, so that awaiting it again restarts the profile.
+    fn finish(&self) {
+        self.start.set(None);
+        self.next_poll.set(None);
+    }
+
+    /// Stops the motor if the profile has started, then returns the future to its initial state.
+    fn abandon(&self) {
+        if self.start.get().is_some() {
+            let mut motor = self.motor.as_obj::<MotorObj>().guard.borrow_mut();
+            let _ = motor.brake(BrakeMode::Brake);
+        }
+        self.finish();
+    }
+}
+
+impl PolledFuture for ProfileFuture {
+    /// Returns when the motor's velocity should next be updated, if the future is waiting.
+    fn next_poll(&self) -> Option<time32::Instant> {
+        self.next_poll.get()
+    }
+}
+
+#[class_methods]
+impl ProfileFuture {
+    #[iter]
+    extern "C" fn profile_iternext(self_in: Obj) -> Obj {
+        let this = self_in.as_obj::<Self>();
+
+        // The motor is released before raising through MicroPython's non-local-return boundary.
+        let result = this.poll();
+        match result {
+            Ok(false) => {
+                let next_poll = time32::Instant::now()
+                    .checked_add(DEVICE_POLL_INTERVAL)
+                    .unwrap_or_else(|| value_error(c"poll interval is too large").raise(token()));
+                this.next_poll.set(Some(next_poll));
+                this.pend(self_in)
+            }
+            Ok(true) => {
+                this.finish();
+                raise_stop_iteration(token(), Obj::NONE)
+            }
+            Err(error) => {
+                this.finish();
+                error.raise(token())
+            }
+        }
+    }
+
+    /// Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
+    ///
+    /// The motor brakes and the profile is abandoned before the exception propagates.
+    #[method]
+    #[stub(sig = "(self, exc: BaseException, /) -> Any")]
+    fn throw(&self, exc: Obj) -> Obj {
+        self.abandon();
+        nlr::raise(token(), exc)
+    }
+
+    /// Brakes the motor and abandons the profile when the awaiting coroutine is closed.
+    #[method]
+    fn close(&self) {
+        self.abandon();
+    }
+}
@@ -0,0 +1,531 @@
+use std::{cell::Cell, f32::consts::PI};
+
+use argparse::{Args, error_msg};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{type_error, value_error},
+    init::token,
+    obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
+    qstr::Qstr,
+};
+use vexide_devices::math::Angle;
+
+use crate::modvenice::{
+    Exception,
+    adi::encoder::AdiEncoderObj,
+    imu::InertialSensorObj,
+    math::{Point2, Pose, Pose2},
+    read_only_attr::read_only_attr,
+    rotation_sensor::RotationSensorObj,
+    units::{rotation::RotationUnitObj, time::TimeUnitObj},
+    validate::{check_finite, check_positive},
+    vasyncio::{polled::PolledFuture, ticker::Schedule, time32},
+};
+
+/// One set of sensor readings taken by [`Odometry::update`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Readings {
+    /// The distance travelled by each vertical wheel. A robot with a single vertical wheel reports
+    /// it twice.
+    pub vertical: [f32; 2],
+    /// The distance travelled by the horizontal wheel, or zero if there isn't one.
+    pub horizontal: f32,
+    /// The counterclockwise rotation of the robot in radians, if it has an inertial sensor.
+    pub rotation: Option<f32>,
+}
+
+/// Arc-based pose tracking from tracking wheel distances, independent of MicroPython.
+///
+/// Between updates, the robot is assumed to move along an arc of constant curvature. Offsets are
+/// measured in the robot's frame, where x points forward and y points left: vertical wheels are
+/// offset along y and the horizontal wheel is offset along x. Vertical wheels read positive when
+/// the robot drives forward and the horizontal wheel reads positive when it slides left.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Odometry {
+    vertical_offsets: [f32; 2],
+    horizontal_offset: f32,
+    pose: Pose,
+    previous: Option<Readings>,
+}
+
+impl Odometry {
+    /// Creates a tracker at the origin.
+    ///
+    /// Returns `None` if the heading can't be derived from the wheels, which happens when
+    /// `has_rotation` is false and the two vertical offsets are equal.
+    pub fn new(
+        vertical_offsets: [f32; 2],
+        horizontal_offset: f32,
+        has_rotation: bool,
+    ) -> Option<Self> {
+        if !has_rotation && vertical_offsets[0] == vertical_offsets[1] {
+            return None;
+        }
+
+        Some(Self {
+            vertical_offsets,
+            horizontal_offset,
+            pose: Pose::default(),
+            previous: None,
+        })
+    }
+
+    pub fn pose(&self) -> Pose {
+        self.pose
+    }
+
+    /// Moves the tracker to `pose` without discarding the previous readings.
+    pub fn set_pose(&mut self, pose: Pose) {
+        self.pose = pose;
+    }
+
+    /// Integrates the motion since the previous readings into the pose.
+    ///
+    /// The first update only records `readings` as the starting point.
+    pub fn update(&mut self, readings: Readings) {
+        let Some(previous) = self.previous.replace(readings) else {
+            return;
+        };
+
+        let vertical = [
+            readings.vertical[0] - previous.vertical[0],
+            readings.vertical[1] - previous.vertical[1],
+        ];
+        let horizontal = readings.horizontal - previous.horizontal;
+
+        let dtheta = match (readings.rotation, previous.rotation) {
+            (Some(rotation), Some(previous)) => rotation - previous,
+            _ => {
+                (vertical[0] - vertical[1]) / (self.vertical_offsets[1] - self.vertical_offsets[0])
+            }
+        };
+
+        // arc lengths travelled by the tracking center
+        let offset = (self.vertical_offsets[0] + self.vertical_offsets[1]) / 2.0;
+        let forward = (vertical[0] + vertical[1]) / 2.0 + offset * dtheta;
+        let sideways = horizontal - self.horizontal_offset * dtheta;
+
+        // turn the arcs into a chord
+        let scale = if dtheta.abs() < 1e-6 {
+            1.0
+        } else {
+            2.0 * (dtheta / 2.0).sin() / dtheta
+        };
+        let (sin, cos) = (self.pose.theta + dtheta / 2.0).sin_cos();
+
+        self.pose.x += scale * (forward * cos - sideways * sin);
+        self.pose.y += scale * (forward * sin + sideways * cos);
+        self.pose.theta += dtheta;
+    }
+}
+
+/// An unpowered wheel whose rotation is measured by a `RotationSensor` or `AdiEncoder`, used by
+/// `Odometry`.
+///
+/// `diameter` sets the unit of every distance that `Odometry` reports. `offset` is the wheel's
+/// distance from the robot's tracking center, in the same unit: to the left for vertical wheels
+/// (negative for wheels on the right), and forward for horizontal wheels (negative for wheels
+/// behind it). Reverse the sensor if a vertical wheel reads negative when the robot drives forward,
+/// or a horizontal wheel reads negative when it slides left.
+///
+/// `diameter` and `offset` are read-only attributes.
+#[class(qstr!(TrackingWheel))]
+#[repr(C)]
+pub struct TrackingWheel {
+    base: ObjBase,
+    sensor: Obj,
+    diameter: f32,
+    offset: f32,
+}
+
+impl TrackingWheel {
+    /// Returns the distance the wheel has travelled.
+    fn distance(&self) -> Result<f32, Exception> {
+        let position = if let Some(sensor) = self.sensor.try_as_obj::<RotationSensorObj>() {
+            sensor.position()?
+        } else {
+            self.sensor.as_obj::<AdiEncoderObj>().position()?
+        };
+
+        Ok(position.as_turns() as f32 * PI * self.diameter)
+    }
+}
+
+#[class_methods]
+impl TrackingWheel {
+    /// Creates a tracking wheel of the given `diameter` measured by `sensor`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `sensor` isn't a `RotationSensor` or `AdiEncoder`, or a number isn't
+    ///   numeric.
+    /// - `ValueError`: If `diameter` isn't positive and finite, or `offset` isn't finite.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// # A 2.75" wheel 1.5" to the right of the tracking center
+    /// right = TrackingWheel(RotationSensor(1), 2.75, -1.5)
+    /// ```
+    #[make_new]
+    #[stub(
+        sig = "(self, sensor: RotationSensor | AdiEncoder, diameter: float, offset: float = 0.0, /) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(2, 3).assert_nkw(0, 0);
+
+        let sensor = reader.next_positional::<Obj>()?;
+        let diameter = check_positive(reader.next_positional()?, "diameter")?;
+        let offset = check_finite(reader.next_positional_or(0.0)?, "offset")?;
+
+        if sensor.try_as_obj::<RotationSensorObj>().is_none()
+            && sensor.try_as_obj::<AdiEncoderObj>().is_none()
+        {
+            Err(type_error(c"expected a RotationSensor or AdiEncoder"))?
+        }
+
+        Ok(Self {
+            base: ty.into(),
+            sensor,
+            diameter,
+            offset,
+        })
+    }
+
+    /// Returns the distance the wheel has travelled since its sensor was last reset, in the unit of
+    /// its diameter.
+    ///
+    /// # Raises
+    ///
+    /// - `DeviceError`: If the sensor can't be read.
+    #[method]
+    fn get_distance(&self) -> Result<f32, Exception> {
+        self.distance()
+    }
+
+    #[attr]
+    #[stub(attrs = ["diameter: float", "offset: float"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+
+        result.return_value(match attr.as_str() {
+            "diameter" => self.diameter,
+            "offset" => self.offset,
+            _ => return,
+        });
+    }
+}
+
+/// Tracks the robot's position and heading from two or three tracking wheels.
+///
+/// The robot's heading comes from an `InertialSensor` if one is given, or otherwise from the
+/// difference between two vertical wheels. A horizontal wheel measures sideways motion; without
+/// one, the robot is assumed not to slide sideways. Each update assumes the robot moved along an
+/// arc since the previous one, which stays accurate while turning and driving at the same time.
+///
+/// Positions are measured in the unit of the tracking wheel diameters, with x pointing forward and
+/// y pointing left of where the robot started. Headings increase counterclockwise from the x axis
+/// and are not wrapped, so they keep counting past a full turn.
+///
+/// Poses are only updated by `Odometry.update` or while `Odometry.run` is awaited. Resetting a
+/// tracking wheel's sensor or the inertial sensor's rotation while tracking makes the pose jump.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// odom = Odometry(
+///     [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+///     TrackingWheel(RotationSensor(3), 2.75, -2.0),
+///     imu=InertialSensor(4),
+/// )
+///
+/// async def track():
+///     await odom.run(10, MILLIS)
+///
+/// async def main():
+///     vasyncio.spawn(track())
+///     while True:
+///         pose = odom.get_pose()
+///         print(pose.x, pose.y, pose.get_heading(DEGREES))
+///         await vasyncio.Sleep(100, MILLIS)
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(Odometry))]
+#[repr(C)]
+pub struct OdometryObj {
+    base: ObjBase,
+    vertical: [Obj; 2],
+    horizontal: Option<Obj>,
+    imu: Option<Obj>,
+    odometry: Cell<Odometry>,
+}
+
+/// An awaitable that updates an `Odometry` on a fixed schedule until it is cancelled, returned by
+/// `Odometry.run`.
+///
+/// The first update happens as soon as the future is awaited and the rest follow once per period.
+/// Like `vasyncio.Ticker`, updates are scheduled from the first one, and periods that are missed
+/// because other tasks ran too long are skipped.
+///
+/// # Raises
+///
+/// - `DeviceError`: If a sensor can't be read.
+#[class(qstr!(OdometryFuture))]
+#[repr(C)]
+pub struct OdometryFuture {
+    base: ObjBase,
+    odometry: Obj,
+    schedule: Schedule,
+}
+
+fn tracking_wheel(obj: Obj) -> Result<Obj, Exception> {
+    if obj.try_as_obj::<TrackingWheel>().is_none() {
+        Err(type_error(c"expected a TrackingWheel"))?
+    }
+    Ok(obj)
+}
+
+impl OdometryObj {
+    /// Reads every sensor and integrates the readings.
+    pub fn integrate(&self) -> Result<(), Exception> {
+        let distance = |wheel: Obj| wheel.as_obj::<TrackingWheel>().distance();
+
+        let first = distance(self.vertical[0])?;
+        let second = if self.vertical[1].inner() == self.vertical[0].inner() {
+            first
+        } else {
+            distance(self.vertical[1])?
+        };
+        let horizontal = self.horizontal.map(distance).transpose()?.unwrap_or(0.0);
+        // the inertial sensor measures clockwise rotation
+        let rotation = match self.imu {
+            Some(imu) => Some(-imu.as_obj::<InertialSensorObj>().rotation()?.as_radians() as f32),
+            None => None,
+        };
+
+        let mut odometry = self.odometry.get();
+        odometry.update(Readings {
+            vertical: [first, second],
+            horizontal,
+            rotation,
+        });
+        self.odometry.set(odometry);
+        Ok(())
+    }
+
+    pub fn pose(&self) -> Pose {
+        self.odometry.get().pose()
+    }
+
+    fn replace_pose(&self, pose: Pose) {
+        let mut odometry = self.odometry.get();
+        odometry.set_pose(pose);
+        self.odometry.set(odometry);
+    }
+}
+
+#[class_methods]
+impl OdometryObj {
+    /// Creates an odometry tracker at the origin.
+    ///
+    /// `vertical` lists one or two tracking wheels parallel to the direction of travel, and
+    /// `horizontal` is an optional tracking wheel perpendicular to it. A single vertical wheel
+    /// needs `imu` to measure the heading.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `vertical` isn't a list or tuple of `TrackingWheel`s, `horizontal` isn't a
+    ///   `TrackingWheel`, or `imu` isn't an `InertialSensor`.
+    /// - `ValueError`: If `vertical` doesn't hold one or two wheels, or if there is no `imu` and
+    ///   the vertical wheels can't measure the heading because there is only one of them or they
+    ///   share an offset.
+    #[make_new]
+    #[stub(
+        sig = "(self, vertical: list[TrackingWheel] | tuple[TrackingWheel, ...], horizontal: TrackingWheel | None = None, /, *, imu: InertialSensor | None = None) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(1, 2).assert_nkw(0, 1);
+
+        let vertical = reader
+            .next_positional::<Obj>()?
+            .try_array()
+            .ok_or_else(|| type_error(c"expected a list or tuple of tracking wheels"))?;
+        let vertical = match vertical.as_slice() {
+            [wheel] => [tracking_wheel(*wheel)?; 2],
+            [first, second] => [tracking_wheel(*first)?, tracking_wheel(*second)?],
+            _ => Err(value_error(c"expected one or two vertical tracking wheels"))?,
+        };
+
+        let horizontal = reader.next_positional_or(Obj::NONE)?;
+        let horizontal = (!horizontal.is_none())
+            .then(|| tracking_wheel(horizontal))
+            .transpose()?;
+
+        let mut imu = None;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "imu" => {
+                    let obj = arg.parse::<Obj>()?;
+                    if !obj.is_none() {
+                        if obj.try_as_obj::<InertialSensorObj>().is_none() {
+                            Err(type_error(c"expected an InertialSensor"))?
+                        }
+                        imu = Some(obj);
+                    }
+                }
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        let offset = |wheel: Obj| wheel.as_obj::<TrackingWheel>().offset;
+        let odometry = Odometry::new(
+            [offset(vertical[0]), offset(vertical[1])],
+            horizontal.map(offset).unwrap_or(0.0),
+            imu.is_some(),
+        )
+        .ok_or_else(|| {
+            value_error(
+                c"without an imu, two vertical tracking wheels with different offsets are needed",
+            )
+        })?;
+
+        Ok(Self {
+            base: ty.into(),
+            vertical,
+            horizontal,
+            imu,
+            odometry: Cell::new(odometry),
+        })
+    }
+
+    /// Reads the sensors once and updates the pose.
+    ///
+    /// The first update only records the starting readings. Use this instead of `Odometry.run` to
+    /// update the pose from your own loop.
+    ///
+    /// # Raises
+    ///
+    /// - `DeviceError`: If a sensor can't be read.
+    #[method]
+    fn update(&self) -> Result<(), Exception> {
+        self.integrate()
+    }
+
+    /// Returns an `OdometryFuture` that updates the pose every `period`, measured in `unit`, until
+    /// it is cancelled.
+    ///
+    /// Await it from a task of its own, spawned with `vasyncio.spawn`, to track the pose in the
+    /// background. Each update runs natively, so tracking at 100 Hz costs little time in Python.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+    #[method(ty = var_between(min = 1, max = 3))]
+    #[stub(
+        sig = "(self, period: float = 10.0, unit: TimeUnit = TimeUnit.MILLIS, /) -> OdometryFuture"
+    )]
+    fn run(args: &[Obj]) -> Result<OdometryFuture, Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+
+        let odometry = reader.next_positional::<Obj>()?;
+        let period = reader.next_positional_or(10.0)?;
+        let unit = reader.next_positional_or(TimeUnitObj::MILLIS)?.unit();
+
+        let period = time32::Duration::from_duration(unit.float_to_dur(period)?);
+        Ok(OdometryFuture {
+            base: OdometryFuture::OBJ_TYPE.into(),
+            odometry,
+            schedule: Schedule::new(period)?,
+        })
+    }
+
+    /// Returns the tracked pose.
+    #[method]
+    fn get_pose(&self) -> Pose2 {
+        Pose2::new(self.odometry.get().pose())
+    }
+
+    /// Returns the position of the tracking center.
+    #[method]
+    fn get_position(&self) -> Point2 {
+        let pose = self.odometry.get().pose();
+        Point2::from_vexide_point2(vexide_devices::math::Point2 {
+            x: pose.x.into(),
+            y: pose.y.into(),
+        })
+    }
+
+    /// Returns the heading of the robot, measured in `unit`.
+    #[method]
+    fn get_heading(&self, unit: &RotationUnitObj) -> f32 {
+        let theta = self.odometry.get().pose().theta;
+        unit.unit()
+            .angle_to_float(Angle::from_radians(theta.into()))
+    }
+
+    /// Moves the tracked pose to `pose`.
+    ///
+    /// The robot doesn't move; later updates continue from the new pose.
+    #[method]
+    fn set_pose(&self, pose: &Pose2) {
+        self.replace_pose(pose.pose());
+    }
+
+    /// Moves the tracked pose back to the origin, facing along the x axis.
+    #[method]
+    fn reset(&self) {
+        self.replace_pose(Pose::default());
+    }
+}
+
+impl PolledFuture for OdometryFuture {
+    /// Returns the deadline of the next update, if the future has been awaited.
+    fn next_poll(&self) -> Option<time32::Instant> {
+        self.schedule.deadline()
+    }
+}
+
+#[class_methods]
+impl OdometryFuture {
+    #[iter]
+    extern "C" fn odometry_iternext(self_in: Obj) -> Obj {
+        let future = self_in.as_obj::<Self>();
+        let odometry = future.odometry.as_obj::<OdometryObj>();
+
+        let due = match future.schedule.deadline() {
+            // take the starting readings as soon as the future is awaited
+            None => {
+                future.schedule.poll();
+                true
+            }
+            Some(_) => future.schedule.poll().is_some(),
+        };
+
+        if due && let Err(error) = odometry.integrate() {
+            // awaiting the future again restarts tracking
+            future.schedule.reset();
+            error.raise(token());
+        }
+
+        future.pend(self_in)
+    }
+}
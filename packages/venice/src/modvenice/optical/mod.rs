@@ -3,7 +3,7 @@ pub mod rgb;
 
 use argparse::Args;
 use micropython_macros::{class, class_methods};
-use micropython_rs::obj::{Obj, ObjBase, ObjType};
+use micropython_rs::obj::{Obj, ObjBase, ObjTrait, ObjType};
 use vexide_devices::smart::optical::OpticalSensor;
 
 use crate::{
@@ -15,6 +15,7 @@ use crate::{
             rgb::{OpticalRawObj, OpticalRgbObj},
         },
         units::time::TimeUnitObj,
+        vasyncio::until::{Threshold, UntilFuture},
     },
     registry::SmartGuard,
 };
@@ -213,6 +214,43 @@ impl OpticalSensorObj {
         Ok(self.guard.borrow().proximity()? as f32)
     }
 
+    /// Waits until the sensor's proximity reading is at least `proximity`.
+    ///
+    /// `proximity` uses the same scale as `OpticalSensor.get_proximity`, where higher values mean
+    /// closer objects. Awaiting the returned `UntilFuture` returns `None`.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If, while awaiting, no device is connected to the port, or if the wrong type
+    /// of device is connected.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// sensor = OpticalSensor(1)
+    /// intake = Motor(2)
+    ///
+    /// async def main():
+    ///     intake.set_voltage(12)
+    ///     await sensor.wait_until_proximity(0.8)
+    ///     intake.brake(BrakeMode.HOLD)
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    #[method]
+    fn wait_until_proximity(self_in: Obj, proximity: f32) -> UntilFuture {
+        UntilFuture::reading(
+            |sensor| {
+                let proximity = sensor.as_obj::<Self>().guard.borrow().proximity()?;
+                Ok(Some(proximity as f32))
+            },
+            self_in,
+            Threshold::AtLeast(proximity),
+        )
+    }
+
     /// Returns the processed RGB color data from the sensor.
     ///
     /// # Raises
@@ -0,0 +1,1112 @@
+use std::{
+    alloc::Allocator,
+    cell::{Cell, RefCell},
+    f32::consts::PI,
+};
+
+use argparse::{Args, error_msg};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{raise_msg, raise_stop_iteration, runtime_error, type_error, value_error},
+    init::token,
+    map::Map,
+    nlr,
+    obj::{Obj, ObjBase, ObjTrait, ObjType},
+    tuple::new_tuple,
+};
+use venice_core::trajectory::{Trajectory, cornering_velocity};
+
+use crate::{
+    alloc::Gc,
+    modvenice::{
+        Exception,
+        fusion::PoseEstimator,
+        math::{Pose, target_coords, wrap_angle},
+        motor::group::MotorGroupObj,
+        odometry::OdometryObj,
+        trajectory::TrajectoryObj,
+        units::time::TimeUnitObj,
+        validate::{check_non_negative, check_positive},
+        vasyncio::{
+            polled::PolledFuture,
+            time32,
+            until::DEVICE_POLL_INTERVAL,
+            wait::{Deadline, TIMEOUT_ERROR_TYPE, Timeout},
+        },
+    },
+};
+
+/// A point on a [`Path`] and the distance along the path to it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Waypoint {
+    x: f32,
+    y: f32,
+    distance: f32,
+}
+
+/// A polyline through a list of waypoints, measured by the distance along it.
+///
+/// Positions before the start and past the end of the path lie on the extensions of its first and
+/// last segments.
+pub struct Path {
+    waypoints: Vec<Waypoint, Gc>,
+}
+
+impl Path {
+    /// Creates a path from `start` through `points`, skipping points that repeat the one before.
+    pub fn new(start: [f32; 2], points: impl IntoIterator<Item = [f32; 2]>) -> Self {
+        let points = points.into_iter();
+        let mut waypoints = Vec::with_capacity_in(points.size_hint().0 + 1, Gc { token: token() });
+        waypoints.push(Waypoint {
+            x: start[0],
+            y: start[1],
+            distance: 0.0,
+        });
+
+        for [x, y] in points {
+            let last = waypoints[waypoints.len() - 1];
+            let distance = last.distance + (x - last.x).hypot(y - last.y);
+            if distance > last.distance {
+                waypoints.push(Waypoint { x, y, distance });
+            }
+        }
+
+        Self { waypoints }
+    }
+
+    /// Returns the position of waypoint `i` and the distance along the path to it.
+    fn waypoint(&self, i: usize) -> [f32; 3] {
+        let Waypoint { x, y, distance } = self.waypoints[i];
+        [x, y, distance]
+    }
+
+    /// Returns the length of the path.
+    pub fn length(&self) -> f32 {
+        self.waypoints[self.waypoints.len() - 1].distance
+    }
+
+    /// Returns the last point of the path.
+    pub fn end(&self) -> [f32; 2] {
+        let last = self.waypoints[self.waypoints.len() - 1];
+        [last.x, last.y]
+    }
+
+    /// Returns the index of the segment containing the point `distance` along the path.
+    ///
+    /// The path must have at least one segment.
+    fn segment_at(&self, distance: f32) -> usize {
+        let after = self.waypoints.partition_point(|w| w.distance <= distance);
+        after.clamp(1, self.waypoints.len() - 1) - 1
+    }
+
+    /// Returns the point `distance` along the path.
+    pub fn point_at(&self, distance: f32) -> [f32; 2] {
+        if self.waypoints.len() < 2 {
+            return self.end();
+        }
+
+        let i = self.segment_at(distance);
+        let ([ax, ay, ad], [bx, by, bd]) = (self.waypoint(i), self.waypoint(i + 1));
+        let t = (distance - ad) / (bd - ad);
+        [ax + (bx - ax) * t, ay + (by - ay) * t]
+    }
+
+    /// Returns the distance along the path to the point nearest `(x, y)`, searching between
+    /// `from` and `to`. Past the end of the path, the search continues along the last segment.
+    pub fn project(&self, x: f32, y: f32, from: f32, to: f32) -> f32 {
+        if self.waypoints.len() < 2 {
+            return 0.0;
+        }
+
+        let last = self.waypoints.len() - 2;
+        let mut best = (f32::INFINITY, from);
+        for i in self.segment_at(from)..=self.segment_at(to).min(last) {
+            let ([ax, ay, ad], [bx, by, bd]) = (self.waypoint(i), self.waypoint(i + 1));
+            let along = ((x - ax) * (bx - ax) + (y - ay) * (by - ay)) / (bd - ad);
+
+            let end = if i == last { to } else { bd.min(to) };
+            let distance = (ad + along).clamp(ad.max(from), end.max(from));
+
+            let [px, py] = self.point_at(distance);
+            let offset = (x - px).hypot(y - py);
+            if offset < best.0 {
+                best = (offset, distance);
+            }
+        }
+        best.1
+    }
+
+    /// Returns the direction of the path `distance` along it, measured counterclockwise from the
+    /// x axis, averaged over a window of `spread` either side.
+    fn heading_at(&self, distance: f32, spread: f32) -> f32 {
+        let [x0, y0] = self.point_at(distance - spread);
+        let [x1, y1] = self.point_at(distance + spread);
+        (y1 - y0).atan2(x1 - x0)
+    }
+}
+
+/// Returns the left and right wheel velocities that move a differential drive at `linear` velocity
+/// while turning at `angular` radians per second, scaled so that neither exceeds `max_velocity`.
+fn wheel_velocities(linear: f32, angular: f32, track_width: f32, max_velocity: f32) -> (f32, f32) {
+    let left = linear - angular * track_width / 2.0;
+    let right = linear + angular * track_width / 2.0;
+    let fastest = left.abs().max(right.abs());
+    if fastest > max_velocity {
+        let scale = max_velocity / fastest;
+        (left * scale, right * scale)
+    } else {
+        (left, right)
+    }
+}
+
+/// Adaptive pure pursuit steering for a differential drive, independent of MicroPython.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pursuit {
+    pub track_width: f32,
+    pub max_velocity: f32,
+    /// The acceleration limit, which may be infinite.
+    pub max_acceleration: f32,
+    pub lookahead: f32,
+    /// The extra lookahead per unit of velocity.
+    pub lookahead_gain: f32,
+    /// The largest lookahead, which may be infinite.
+    pub max_lookahead: f32,
+    pub tolerance: f32,
+    // the distance along the path to the point nearest the robot
+    progress: f32,
+    velocity: f32,
+    finished: bool,
+}
+
+impl Pursuit {
+    pub fn new(
+        track_width: f32,
+        max_velocity: f32,
+        max_acceleration: f32,
+        lookahead: f32,
+        lookahead_gain: f32,
+        max_lookahead: f32,
+        tolerance: f32,
+    ) -> Self {
+        Self {
+            track_width,
+            max_velocity,
+            max_acceleration,
+            lookahead,
+            lookahead_gain,
+            max_lookahead,
+            tolerance,
+            progress: 0.0,
+            velocity: 0.0,
+            finished: false,
+        }
+    }
+
+    /// Starts again from the beginning of a path.
+    pub fn restart(&mut self) {
+        self.progress = 0.0;
+        self.velocity = 0.0;
+        self.finished = false;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns the wheel velocities that steer a robot at `pose` along `path`, `dt` seconds after
+    /// the previous update, or `None` once it has reached the end.
+    pub fn update(&mut self, path: &Path, pose: Pose, dt: f32) -> Option<(f32, f32)> {
+        if self.finished {
+            return None;
+        }
+
+        let lookahead =
+            (self.lookahead + self.lookahead_gain * self.velocity.abs()).min(self.max_lookahead);
+        let Pose { x, y, theta } = pose;
+        self.progress = path.project(x, y, self.progress, self.progress + lookahead);
+
+        let [end_x, end_y] = path.end();
+        let remaining = path.length() - self.progress;
+        if remaining <= 0.0 || (end_x - x).hypot(end_y - y) <= self.tolerance {
+            self.finished = true;
+            self.velocity = 0.0;
+            return None;
+        }
+
+        // Past the end, the target slides along the last segment, so the robot drives straight
+        // through the end point rather than circling it
+        let [target_x, target_y] = path.point_at(self.progress + lookahead);
+        let (dx, dy) = (target_x - x, target_y - y);
+        let (sin, cos) = theta.sin_cos();
+        let local_x = dx * cos + dy * sin;
+        let local_y = dy * cos - dx * sin;
+        let squared = local_x * local_x + local_y * local_y;
+        let curvature = if squared == 0.0 {
+            0.0
+        } else if local_x < 0.0 {
+            // A target behind the robot would give a straight line away from it if it were dead
+            // astern, so turn as sharply as for a target directly beside it
+            2.0 * local_y.signum() / squared.sqrt()
+        } else {
+            2.0 * local_y / squared
+        };
+
+        let mut velocity = cornering_velocity(self.max_velocity, curvature, self.track_width);
+        if self.max_acceleration.is_finite() {
+            velocity = velocity
+                .min((2.0 * self.max_acceleration * remaining).sqrt())
+                .min(self.velocity + self.max_acceleration * dt);
+        }
+        self.velocity = velocity;
+
+        Some(wheel_velocities(
+            velocity,
+            velocity * curvature,
+            self.track_width,
+            self.max_velocity,
+        ))
+    }
+}
+
+/// RAMSETE tracking of a moving reference along a path for a differential drive, independent of
+/// MicroPython.
+///
+/// Along a path, the reference starts at rest and speeds up at `max_acceleration`, slowing for
+/// curves so that neither wheel has to exceed `max_velocity` and for the end of the path so that it
+/// stops there. Along a [`Trajectory`], the reference keeps to the trajectory's timing instead. `b`
+/// is in radians squared per unit squared.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tracking {
+    pub track_width: f32,
+    pub max_velocity: f32,
+    pub max_acceleration: f32,
+    pub b: f32,
+    pub zeta: f32,
+    // the distance along the path to the reference
+    reference: f32,
+    velocity: f32,
+    // the time since the start of a trajectory
+    elapsed: f32,
+    finished: bool,
+}
+
+impl Tracking {
+    pub fn new(
+        track_width: f32,
+        max_velocity: f32,
+        max_acceleration: f32,
+        b: f32,
+        zeta: f32,
+    ) -> Self {
+        Self {
+            track_width,
+            max_velocity,
+            max_acceleration,
+            b,
+            zeta,
+            reference: 0.0,
+            velocity: 0.0,
+            elapsed: 0.0,
+            finished: false,
+        }
+    }
+
+    /// Starts again from the beginning of a path or trajectory.
+    pub fn restart(&mut self) {
+        self.reference = 0.0;
+        self.velocity = 0.0;
+        self.elapsed = 0.0;
+        self.finished = false;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns the wheel velocities that steer a robot at `pose` towards the reference on `path`,
+    /// after moving the reference on by `dt` seconds, or `None` once it has reached the end.
+    pub fn update(&mut self, path: &Path, pose: Pose, dt: f32) -> Option<(f32, f32)> {
+        let remaining = path.length() - self.reference;
+        if self.finished || remaining <= 0.0 {
+            self.finished = true;
+            self.velocity = 0.0;
+            return None;
+        }
+
+        // Corners are smoothed over a track width, which the robot can't turn more sharply than
+        let spread = self.track_width / 2.0;
+        let curvature = |distance: f32| {
+            let turn = path.heading_at(distance + spread, spread)
+                - path.heading_at(distance - spread, spread);
+            wrap_angle(turn) / (2.0 * spread)
+        };
+
+        let step = self.velocity * dt;
+        self.velocity = (self.velocity + self.max_acceleration * dt)
+            .min(cornering_velocity(
+                self.max_velocity,
+                curvature(self.reference + step),
+                self.track_width,
+            ))
+            .min((2.0 * self.max_acceleration * (remaining - step).max(0.0)).sqrt());
+        self.reference = (self.reference + step).min(path.length());
+
+        let [x, y] = path.point_at(self.reference);
+        let heading = path.heading_at(self.reference, spread);
+        Some(self.steer(
+            pose,
+            Pose::new(x, y, heading),
+            self.velocity,
+            self.velocity * curvature(self.reference),
+        ))
+    }
+
+    /// Returns the wheel velocities that steer a robot at `pose` towards where `trajectory` plans
+    /// it to be, `dt` seconds after the previous update, or `None` once the trajectory has ended.
+    pub fn update_trajectory<A: Allocator>(
+        &mut self,
+        trajectory: &Trajectory<A>,
+        pose: Pose,
+        dt: f32,
+    ) -> Option<(f32, f32)> {
+        self.elapsed += dt;
+        if self.finished || self.elapsed >= trajectory.duration() {
+            self.finished = true;
+            return None;
+        }
+
+        let sample = trajectory.sample(self.elapsed);
+        Some(self.steer(
+            pose,
+            sample.pose,
+            sample.velocity,
+            sample.angular_velocity(),
+        ))
+    }
+
+    /// Applies the RAMSETE control law to a robot at `pose` following a reference at `reference`
+    /// that moves at `velocity` while turning at `angular_velocity`.
+    fn steer(
+        &self,
+        pose: Pose,
+        reference: Pose,
+        velocity: f32,
+        angular_velocity: f32,
+    ) -> (f32, f32) {
+        let (dx, dy) = (reference.x - pose.x, reference.y - pose.y);
+        let (sin, cos) = pose.theta.sin_cos();
+        let error_x = dx * cos + dy * sin;
+        let error_y = dy * cos - dx * sin;
+        let error_theta = wrap_angle(reference.theta - pose.theta);
+        let sinc = if error_theta.abs() < 1e-6 {
+            1.0
+        } else {
+            error_theta.sin() / error_theta
+        };
+
+        let gain = 2.0 * self.zeta * (angular_velocity.powi(2) + self.b * velocity.powi(2)).sqrt();
+        let linear = velocity * error_theta.cos() + gain * error_x;
+        let angular = angular_velocity + gain * error_theta + self.b * velocity * sinc * error_y;
+
+        wheel_velocities(linear, angular, self.track_width, self.max_velocity)
+    }
+}
+
+type Waypoints = Vec<[f32; 2], Gc>;
+
+fn pose_source(obj: Obj) -> Result<Obj, Exception> {
+    if obj.try_as_obj::<OdometryObj>().is_none() && obj.try_as_obj::<PoseEstimator>().is_none() {
+        Err(type_error(c"expected an Odometry or PoseEstimator"))?
+    }
+    Ok(obj)
+}
+
+fn read_pose(source: Obj) -> Result<Pose, Exception> {
+    if let Some(odometry) = source.try_as_obj::<OdometryObj>() {
+        return Ok(odometry.pose());
+    }
+
+    source
+        .as_obj::<PoseEstimator>()
+        .pose()
+        .ok_or_else(|| runtime_error(c"the pose estimator has no estimate yet").into())
+}
+
+/// What a follower has been asked to follow.
+enum Route {
+    /// Waypoints to pass through in straight lines, starting from wherever the robot is.
+    Waypoints(Waypoints),
+    /// A `Trajectory`, which starts at its own first waypoint.
+    Trajectory(Obj),
+}
+
+impl Route {
+    fn trajectory(&self) -> Option<&Trajectory<Gc>> {
+        match self {
+            Route::Waypoints(_) => None,
+            Route::Trajectory(obj) => Some(obj.as_obj::<TrajectoryObj>().trajectory()),
+        }
+    }
+
+    /// Builds the path to follow, starting from `start` unless the route is a trajectory.
+    fn path(&self, start: Pose) -> Result<Path, Exception> {
+        match self {
+            Route::Waypoints(waypoints) => {
+                if waypoints.is_empty() {
+                    Err(runtime_error(c"there is no path to follow"))?
+                }
+                Ok(Path::new([start.x, start.y], waypoints.iter().copied()))
+            }
+            Route::Trajectory(obj) => {
+                let trajectory = obj.as_obj::<TrajectoryObj>().trajectory();
+                let start = trajectory.sample(0.0).pose;
+                Ok(Path::new([start.x, start.y], trajectory.positions()))
+            }
+        }
+    }
+}
+
+fn parse_route(obj: Obj) -> Result<Route, Exception> {
+    if obj.try_as_obj::<TrajectoryObj>().is_some() {
+        return Ok(Route::Trajectory(obj));
+    }
+
+    let items = obj
+        .try_array()
+        .ok_or_else(|| type_error(c"expected a Trajectory or a list or tuple of waypoints"))?;
+    if items.is_empty() {
+        Err(value_error(c"a path needs at least one waypoint"))?
+    }
+
+    let mut waypoints = Vec::with_capacity_in(items.len(), Gc { token: token() });
+    for item in items {
+        let (x, y) = target_coords(item)?;
+        waypoints.push([x, y]);
+    }
+    Ok(Route::Waypoints(waypoints))
+}
+
+/// The route a follower was given, and the path along it once following has started.
+struct Course {
+    route: RefCell<Route>,
+    path: RefCell<Option<Path>>,
+    previous: Cell<Option<time32::Instant>>,
+}
+
+impl Course {
+    fn new() -> Self {
+        Self {
+            route: RefCell::new(Route::Waypoints(Vec::new_in(Gc { token: token() }))),
+            path: RefCell::new(None),
+            previous: Cell::new(None),
+        }
+    }
+
+    fn set(&self, route: Route) {
+        *self.route.borrow_mut() = route;
+        *self.path.borrow_mut() = None;
+        self.previous.set(None);
+    }
+
+    /// Reads the robot's pose from `source` and passes it to `update` with the path, the
+    /// trajectory if there is one, and the time in seconds since the previous update.
+    fn advance(
+        &self,
+        source: Obj,
+        update: impl FnOnce(&Path, Option<&Trajectory<Gc>>, Pose, f32) -> Option<(f32, f32)>,
+    ) -> Result<Option<(f32, f32)>, Exception> {
+        let pose = read_pose(source)?;
+
+        let route = self.route.borrow();
+        let mut path = self.path.borrow_mut();
+        if path.is_none() {
+            *path = Some(route.path(pose)?);
+        }
+
+        let now = time32::Instant::now();
+        let dt = match self.previous.replace(Some(now)) {
+            Some(previous) => (now - previous).to_duration().as_secs_f32(),
+            None => 0.0,
+        };
+
+        Ok(path
+            .as_ref()
+            .and_then(|path| update(path, route.trajectory(), pose, dt)))
+    }
+}
+
+/// A path follower for a differential drive that steers towards a point a set distance ahead on
+/// the path.
+///
+/// `PurePursuit` drives forwards through a list of `Point2` or `Pose2` waypoints, using the pose
+/// reported by `source`, which is an `Odometry` or a `PoseEstimator`. The path runs in straight
+/// lines from the robot's position when following starts, through each waypoint in turn. Only
+/// the positions of `Pose2` waypoints are used; the robot's heading follows the path. A
+/// `Trajectory` can be followed instead, in which case only its shape is used: the path runs along
+/// the trajectory's curves from its first waypoint, and the controller sets its own speed.
+///
+/// On each update, the controller finds the point on the path nearest the robot, then steers along
+/// the arc that passes through the point `lookahead` further along. A longer lookahead follows the
+/// path more smoothly but cuts corners more. With a `lookahead_gain`, the lookahead grows by that
+/// much per unit of velocity, up to `max_lookahead`, so that the robot looks further ahead when it
+/// is moving quickly.
+///
+/// The robot slows for tight turns so that neither wheel exceeds `max_velocity`. With a
+/// `max_acceleration`, it also speeds up gradually and slows down so that it can stop at the end
+/// of the path. The path is finished once the robot is within `tolerance` of its last waypoint or
+/// has passed it.
+///
+/// Distances are measured in the unit of the pose source, velocities in that unit per second and
+/// accelerations in that unit per second squared. The source must be kept up to date while the
+/// path is followed, such as by awaiting `Odometry.run` in another task.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// odom = Odometry(
+///     [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+///     imu=InertialSensor(3),
+/// )
+/// left = MotorGroup([(11, Direction.REVERSE), (12, Direction.REVERSE)], Gearset.BLUE)
+/// right = MotorGroup([13, 14], Gearset.BLUE)
+/// pursuit = PurePursuit(odom, 12.0, 50.0, 10.0, max_acceleration=80.0)
+///
+/// async def track():
+///     await odom.run(10, MILLIS)
+///
+/// async def main():
+///     vasyncio.spawn(track())
+///     path = [Point2(24, 0), Point2(48, 24), Point2(48, 48)]
+///     await pursuit.follow(path, left, right, 3.25, gear_ratio=0.6, timeout=5000)
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(PurePursuit))]
+#[repr(C)]
+pub struct PurePursuit {
+    base: ObjBase,
+    source: Obj,
+    course: Course,
+    pursuit: Cell<Pursuit>,
+}
+
+/// A path follower for a differential drive that tracks a reference moving along the path, using
+/// the RAMSETE control law.
+///
+/// `Ramsete` drives forwards through a list of `Point2` or `Pose2` waypoints, or along a
+/// `Trajectory`, using the pose reported by `source`, which is an `Odometry` or a
+/// `PoseEstimator`. A list of waypoints makes a path that runs in straight lines from the robot's
+/// position when following starts, through each waypoint in turn. Only the positions of `Pose2`
+/// waypoints are used; the robot's heading follows the path.
+///
+/// A reference point moves along the path, speeding up at `max_acceleration`, slowing for curves
+/// so that neither wheel has to exceed `max_velocity`, and slowing so that it stops at the end. The
+/// controller drives the robot at the reference's velocity, corrected for how far the robot is
+/// behind, beside, or turned away from it. Unlike `PurePursuit`, this keeps the robot to a
+/// schedule, so it arrives at a predictable time. Corners are rounded off over one track width.
+/// The path is finished once the reference reaches the end, so the robot stops close behind it.
+///
+/// Along a `Trajectory`, the reference instead keeps to the trajectory's own timing, starting when
+/// following starts, and `max_acceleration` has no effect. The trajectory is finished once its
+/// duration has passed.
+///
+/// `b` and `zeta` tune the controller: a larger `b` corrects errors more aggressively, and `zeta`,
+/// between 0 and 1, damps the correction. The defaults of 2.0 and 0.7 suit most robots when
+/// distances are in metres; set `scale` to the number of source units in a metre, such as 39.37
+/// for inches, to keep them suitable.
+///
+/// Distances are measured in the unit of the pose source, velocities in that unit per second and
+/// accelerations in that unit per second squared. The source must be kept up to date while the
+/// path is followed, such as by awaiting `Odometry.run` in another task.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// odom = Odometry(
+///     [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+///     imu=InertialSensor(3),
+/// )
+/// left = MotorGroup([(11, Direction.REVERSE), (12, Direction.REVERSE)], Gearset.BLUE)
+/// right = MotorGroup([13, 14], Gearset.BLUE)
+/// ramsete = Ramsete(odom, 12.0, 50.0, 80.0, scale=39.37)
+///
+/// async def track():
+///     await odom.run(10, MILLIS)
+///
+/// async def main():
+///     vasyncio.spawn(track())
+///     path = [Point2(24, 0), Point2(48, 24), Point2(48, 48)]
+///     await ramsete.follow(path, left, right, 3.25, gear_ratio=0.6)
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(Ramsete))]
+#[repr(C)]
+pub struct Ramsete {
+    base: ObjBase,
+    source: Obj,
+    course: Course,
+    tracking: Cell<Tracking>,
+}
+
+/// An awaitable that drives two motor groups along a path until the end is reached, returned by
+/// `PurePursuit.follow` and `Ramsete.follow`.
+///
+/// Between updates to the motors' velocities, the awaiting task is parked on the event loop, so
+/// other tasks keep running. When the future completes, times out or fails, both motor groups are
+/// told to hold a velocity of zero, including when the awaiting task is cancelled.
+///
+/// # Raises
+///
+/// - `DeviceError`: If a motor or sensor can't be used.
+/// - `RuntimeError`: If the pose source is a `PoseEstimator` that has no estimate yet.
+/// - `vasyncio.TimeoutError`: If the end of the path hasn't been reached before the timeout.
+#[class(qstr!(FollowFuture))]
+#[repr(C)]
+pub struct FollowFuture {
+    base: ObjBase,
+    follower: Obj,
+    left: Obj,
+    right: Obj,
+    // motor RPM per unit of wheel velocity
+    rpm_per_velocity: f32,
+    deadline: Deadline,
+    next_poll: Cell<Option<time32::Instant>>,
+}
+
+impl PurePursuit {
+    /// Returns the wheel velocities for the robot's current pose, or `None` once the path is
+    /// finished.
+    fn step(&self) -> Result<Option<(f32, f32)>, Exception> {
+        self.course.advance(self.source, |path, _, pose, dt| {
+            let mut pursuit = self.pursuit.get();
+            let velocities = pursuit.update(path, pose, dt);
+            self.pursuit.set(pursuit);
+            velocities
+        })
+    }
+
+    fn replace_path(&self, route: Route) {
+        self.course.set(route);
+        let mut pursuit = self.pursuit.get();
+        pursuit.restart();
+        self.pursuit.set(pursuit);
+    }
+}
+
+#[class_methods]
+impl PurePursuit {
+    /// Creates a pure pursuit controller that reads the robot's pose from `source`.
+    ///
+    /// `track_width` is the distance between the left and right wheels. `max_lookahead` and
+    /// `max_acceleration` are unlimited if `None`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `source` isn't an `Odometry` or `PoseEstimator`, or a number isn't
+    ///   numeric.
+    /// - `ValueError`: If `track_width`, `max_velocity`, `lookahead`, `max_lookahead` or
+    ///   `max_acceleration` isn't positive and finite, if `lookahead_gain` or `tolerance` is
+    ///   negative or non-finite, or if `max_lookahead` is less than `lookahead`.
+    #[make_new]
+    #[stub(
+        sig = "(self, source: Odometry | PoseEstimator, track_width: float, max_velocity: float, lookahead: float, /, *, lookahead_gain: float = 0.0, max_lookahead: float | None = None, max_acceleration: float | None = None, tolerance: float = 1.0) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(4, 4).assert_nkw(0, 4);
+
+        let source = pose_source(reader.next_positional::<Obj>()?)?;
+        let track_width = check_positive(reader.next_positional::<f32>()?, "track_width")?;
+        let max_velocity = check_positive(reader.next_positional::<f32>()?, "max_velocity")?;
+        let lookahead = check_positive(reader.next_positional::<f32>()?, "lookahead")?;
+
+        let mut lookahead_gain = 0.0;
+        let mut max_lookahead = f32::INFINITY;
+        let mut max_acceleration = f32::INFINITY;
+        let mut tolerance = 1.0;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "lookahead_gain" => {
+                    lookahead_gain = check_non_negative(arg.parse::<f32>()?, arg.kw)?;
+                }
+                "max_lookahead" => {
+                    if !arg.obj.is_none() {
+                        max_lookahead = check_positive(arg.parse::<f32>()?, arg.kw)?;
+                    }
+                }
+                "max_acceleration" => {
+                    if !arg.obj.is_none() {
+                        max_acceleration = check_positive(arg.parse::<f32>()?, arg.kw)?;
+                    }
+                }
+                "tolerance" => tolerance = check_non_negative(arg.parse::<f32>()?, arg.kw)?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        if max_lookahead < lookahead {
+            Err(value_error(c"max_lookahead cannot be less than lookahead"))?
+        }
+
+        Ok(Self {
+            base: ty.into(),
+            source,
+            course: Course::new(),
+            pursuit: Cell::new(Pursuit::new(
+                track_width,
+                max_velocity,
+                max_acceleration,
+                lookahead,
+                lookahead_gain,
+                max_lookahead,
+                tolerance,
+            )),
+        })
+    }
+
+    /// Sets the waypoints or trajectory to follow, starting again at the next update.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+    ///   waypoints.
+    /// - `ValueError`: If `path` is empty.
+    #[method]
+    #[stub(sig = "(self, path: list[Point2 | Pose2] | Trajectory, /) -> None")]
+    fn set_path(&self, path: Obj) -> Result<(), Exception> {
+        self.replace_path(parse_route(path)?);
+        Ok(())
+    }
+
+    /// Reads the robot's pose and returns the left and right wheel velocities that steer it along
+    /// the path, or `(0.0, 0.0)` once the path is finished.
+    ///
+    /// Use this instead of `PurePursuit.follow` to drive the wheels from your own loop. The
+    /// acceleration limit assumes that it is called regularly.
+    ///
+    /// # Raises
+    ///
+    /// - `RuntimeError`: If no path has been set, or if the pose source is a `PoseEstimator` that
+    ///   has no estimate yet.
+    #[method]
+    #[stub(sig = "(self, /) -> tuple[float, float]")]
+    fn calculate(&self) -> Result<Obj, Exception> {
+        let (left, right) = self.step()?.unwrap_or((0.0, 0.0));
+        Ok(new_tuple(&[Obj::from(left), Obj::from(right)]))
+    }
+
+    /// Returns whether the robot has reached the end of the path.
+    #[method]
+    fn is_finished(&self) -> bool {
+        self.pursuit.get().is_finished()
+    }
+
+    /// Sets the waypoints or trajectory to follow, then returns a `FollowFuture` that drives the
+    /// `left` and `right` motor groups along it until the path is finished.
+    ///
+    /// `wheel_diameter` is measured in the unit of the pose source, and `gear_ratio` is the number
+    /// of turns of the wheels per turn of the motors. `timeout`, measured in `time_unit`, is
+    /// unlimited if `None`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+    ///   waypoints, or if `left` or `right` isn't a `MotorGroup`.
+    /// - `ValueError`: If `path` is empty, if `wheel_diameter` or `gear_ratio` isn't positive and
+    ///   finite, or if `timeout` is negative, non-finite, or too large to represent.
+    #[method(ty = kw(min = 5))]
+    #[stub(
+        sig = "(self, path: list[Point2 | Pose2] | Trajectory, left: MotorGroup, right: MotorGroup, wheel_diameter: float, /, *, gear_ratio: float = 1.0, timeout: float | None = None, time_unit: TimeUnit = TimeUnit.MILLIS) -> FollowFuture"
+    )]
+    fn follow(args: &[Obj], kw_map: &Map) -> Result<FollowFuture, Exception> {
+        let (route, future) = FollowFuture::parse(args, kw_map)?;
+        future.follower.as_obj::<Self>().replace_path(route);
+        Ok(future)
+    }
+}
+
+impl Ramsete {
+    /// Returns the wheel velocities for the robot's current pose, or `None` once the path is
+    /// finished.
+    fn step(&self) -> Result<Option<(f32, f32)>, Exception> {
+        self.course
+            .advance(self.source, |path, trajectory, pose, dt| {
+                let mut tracking = self.tracking.get();
+                let velocities = match trajectory {
+                    Some(trajectory) => tracking.update_trajectory(trajectory, pose, dt),
+                    None => tracking.update(path, pose, dt),
+                };
+                self.tracking.set(tracking);
+                velocities
+            })
+    }
+
+    fn replace_path(&self, route: Route) {
+        self.course.set(route);
+        let mut tracking = self.tracking.get();
+        tracking.restart();
+        self.tracking.set(tracking);
+    }
+}
+
+#[class_methods]
+impl Ramsete {
+    /// Creates a RAMSETE controller that reads the robot's pose from `source`.
+    ///
+    /// `track_width` is the distance between the left and right wheels.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `source` isn't an `Odometry` or `PoseEstimator`, or a number isn't
+    ///   numeric.
+    /// - `ValueError`: If `track_width`, `max_velocity`, `max_acceleration`, `b` or `scale` isn't
+    ///   positive and finite, or if `zeta` isn't between 0 and 1.
+    #[make_new]
+    #[stub(
+        sig = "(self, source: Odometry | PoseEstimator, track_width: float, max_velocity: float, max_acceleration: float, /, *, b: float = 2.0, zeta: float = 0.7, scale: float = 1.0) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(4, 4).assert_nkw(0, 3);
+
+        let source = pose_source(reader.next_positional::<Obj>()?)?;
+        let track_width = check_positive(reader.next_positional::<f32>()?, "track_width")?;
+        let max_velocity = check_positive(reader.next_positional::<f32>()?, "max_velocity")?;
+        let max_acceleration =
+            check_positive(reader.next_positional::<f32>()?, "max_acceleration")?;
+
+        let mut b = 2.0;
+        let mut zeta = 0.7;
+        let mut scale = 1.0;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "b" => b = check_positive(arg.parse::<f32>()?, arg.kw)?,
+                "zeta" => zeta = arg.parse::<f32>()?,
+                "scale" => scale = check_positive(arg.parse::<f32>()?, arg.kw)?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        if !(zeta > 0.0 && zeta < 1.0) {
+            Err(value_error(c"zeta must be between 0 and 1"))?
+        }
+
+        Ok(Self {
+            base: ty.into(),
+            source,
+            course: Course::new(),
+            tracking: Cell::new(Tracking::new(
+                track_width,
+                max_velocity,
+                max_acceleration,
+                b / (scale * scale),
+                zeta,
+            )),
+        })
+    }
+
+    /// Sets the waypoints or trajectory to follow, starting again at the next update.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+    ///   waypoints.
+    /// - `ValueError`: If `path` is empty.
+    #[method]
+    #[stub(sig = "(self, path: list[Point2 | Pose2] | Trajectory, /) -> None")]
+    fn set_path(&self, path: Obj) -> Result<(), Exception> {
+        self.replace_path(parse_route(path)?);
+        Ok(())
+    }
+
+    /// Moves the reference along the path, reads the robot's pose, and returns the left and right
+    /// wheel velocities that steer it towards the reference, or `(0.0, 0.0)` once the path is
+    /// finished.
+    ///
+    /// Use this instead of `Ramsete.follow` to drive the wheels from your own loop. The reference
+    /// moves by the time since the previous call, so call it regularly.
+    ///
+    /// # Raises
+    ///
+    /// - `RuntimeError`: If no path has been set, or if the pose source is a `PoseEstimator` that
+    ///   has no estimate yet.
+    #[method]
+    #[stub(sig = "(self, /) -> tuple[float, float]")]
+    fn calculate(&self) -> Result<Obj, Exception> {
+        let (left, right) = self.step()?.unwrap_or((0.0, 0.0));
+        Ok(new_tuple(&[Obj::from(left), Obj::from(right)]))
+    }
+
+    /// Returns whether the reference has reached the end of the path.
+    #[method]
+    fn is_finished(&self) -> bool {
+        self.tracking.get().is_finished()
+    }
+
+    /// Sets the waypoints or trajectory to follow, then returns a `FollowFuture` that drives the
+    /// `left` and `right` motor groups along it until the path is finished.
+    ///
+    /// See `PurePursuit.follow`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+    ///   waypoints, or if `left` or `right` isn't a `MotorGroup`.
+    /// - `ValueError`: If `path` is empty, if `wheel_diameter` or `gear_ratio` isn't positive and
+    ///   finite, or if `timeout` is negative, non-finite, or too large to represent.
+    #[method(ty = kw(min = 5))]
+    #[stub(
+        sig = "(self, path: list[Point2 | Pose2] | Trajectory, left: MotorGroup, right: MotorGroup, wheel_diameter: float, /, *, gear_ratio: float = 1.0, timeout: float | None = None, time_unit: TimeUnit = TimeUnit.MILLIS) -> FollowFuture"
+    )]
+    fn follow(args: &[Obj], kw_map: &Map) -> Result<FollowFuture, Exception> {
+        let (route, future) = FollowFuture::parse(args, kw_map)?;
+        future.follower.as_obj::<Self>().replace_path(route);
+        Ok(future)
+    }
+}
+
+fn motor_group(obj: Obj) -> Result<Obj, Exception> {
+    if obj.try_as_obj::<MotorGroupObj>().is_none() {
+        Err(type_error(c"expected a MotorGroup"))?
+    }
+    Ok(obj)
+}
+
+impl FollowFuture {
+    /// Parses the arguments of a `follow` method, returning the new route and the future.
+    fn parse(args: &[Obj], kw_map: &Map) -> Result<(Route, Self), Exception> {
+        let kwarg_count = kw_map.len();
+        let positional_count = args.len() - kwarg_count * 2;
+        let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+        reader.assert_npos(5, 5).assert_nkw(0, 3);
+
+        let follower = reader.next_positional::<Obj>()?;
+        let route = parse_route(reader.next_positional::<Obj>()?)?;
+        let left = motor_group(reader.next_positional::<Obj>()?)?;
+        let right = motor_group(reader.next_positional::<Obj>()?)?;
+        let wheel_diameter = check_positive(reader.next_positional::<f32>()?, "wheel_diameter")?;
+
+        let mut gear_ratio = 1.0;
+        let mut timeout = Timeout::default();
+        let mut time_unit: &TimeUnitObj = TimeUnitObj::MILLIS;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "gear_ratio" => gear_ratio = check_positive(arg.parse::<f32>()?, arg.kw)?,
+                "timeout" => timeout = arg.parse()?,
+                "time_unit" => time_unit = arg.parse()?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        let future = Self {
+            base: Self::OBJ_TYPE.into(),
+            follower,
+            left,
+            right,
+            rpm_per_velocity: 60.0 / (PI * wheel_diameter * gear_ratio),
+            deadline: Deadline::new(timeout.to_duration(time_unit)?),
+            next_poll: Cell::new(None),
+        };
+        Ok((route, future))
+    }
+
+    fn step(&self) -> Result<Option<(f32, f32)>, Exception> {
+        match self.follower.try_as_obj::<PurePursuit>() {
+            Some(pursuit) => pursuit.step(),
+            None => self.follower.as_obj::<Ramsete>().step(),
+        }
+    }
+
+    fn drive(&self, (left, right): (f32, f32)) -> Result<(), Exception> {
+        let rpm = |velocity: f32| (velocity * self.rpm_per_velocity).round() as i32;
+        let left = self.left.as_obj::<MotorGroupObj>().set_rpm(rpm(left));
+        let right = self.right.as_obj::<MotorGroupObj>().set_rpm(rpm(right));
+        left.and(right)
+    }
+
+    /// Stops both motor groups and clears the timeout, so that awaiting the future again starts
+    /// afresh.
+    fn finish(&self) {
+        let _ = self.drive((0.0, 0.0));
+        self.deadline.reset();
+        self.next_poll.set(None);
+    }
+}
+
+impl PolledFuture for FollowFuture {
+    /// Returns when the motors' velocities should next be updated, if the future is waiting.
+    fn next_poll(&self) -> Option<time32::Instant> {
+        self.next_poll.get()
+    }
+}
+
+#[class_methods]
+impl FollowFuture {
+    #[iter]
+    extern "C" fn follow_iternext(self_in: Obj) -> Obj {
+        let this = self_in.as_obj::<Self>();
+        this.deadline.start();
+
+        match this.step() {
+            Ok(Some(_)) if this.deadline.has_passed() => {
+                this.finish();
+                raise_msg(
+                    token(),
+                    TIMEOUT_ERROR_TYPE.as_obj_type(),
+                    c"robot did not reach the end of the path before the timeout",
+                )
+            }
+            Ok(Some(velocities)) => {
+                if let Err(error) = this.drive(velocities) {
+                    this.finish();
+                    error.raise(token());
+                }
+
+                let next_poll = time32::Instant::now()
+                    .checked_add(DEVICE_POLL_INTERVAL)
+                    .unwrap_or_else(|| value_error(c"poll interval is too large").raise(token()));
+                this.next_poll.set(Some(next_poll));
+                this.pend(self_in)
+            }
+            Ok(None) => {
+                this.finish();
+                raise_stop_iteration(token(), Obj::NONE)
+            }
+            Err(error) => {
+                this.finish();
+                error.raise(token())
+            }
+        }
+    }
+
+    /// Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
+    ///
+    /// Both motor groups are stopped before the exception propagates.
+    #[method]
+    #[stub(sig = "(self, exc: BaseException, /) -> Any")]
+    fn throw(&self, exc: Obj) -> Obj {
+        self.finish();
+        nlr::raise(token(), exc)
+    }
+
+    /// Stops both motor groups when the awaiting coroutine is closed.
+    #[method]
+    fn close(&self) {
+        self.finish();
+    }
+}
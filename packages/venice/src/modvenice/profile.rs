@@ -0,0 +1,284 @@
+use std::{fmt::Write, time::Duration};
+
+use argparse::Args;
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{type_error, value_error},
+    obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
+    print::{Print, PrintKind},
+    qstr::Qstr,
+};
+use venice_core::profile::{MotionProfile, ProfileSample};
+
+use crate::modvenice::{
+    Exception,
+    read_only_attr::read_only_attr,
+    units::time::{TimeConversionError, TimeUnitObj},
+    validate::{check_finite, check_positive},
+};
+
+/// A trapezoidal motion profile.
+///
+/// The profile moves over `distance` by accelerating at `max_acceleration` until it reaches
+/// `max_velocity`, cruising, and then decelerating to a stop at the end. If the distance is too
+/// short to reach `max_velocity`, it decelerates as soon as it reaches the halfway point instead.
+///
+/// Distances may be measured in any unit, as long as velocities are in that unit per second and
+/// accelerations in that unit per second squared. A negative `distance` moves backwards.
+///
+/// Use `Motor.follow_profile` to drive a motor along the profile.
+#[class(qstr!(TrapezoidProfile))]
+#[repr(C)]
+pub struct TrapezoidProfile {
+    base: ObjBase,
+    profile: MotionProfile,
+}
+
+/// An S-curve motion profile.
+///
+/// Like `TrapezoidProfile`, the profile accelerates, cruises and decelerates over `distance`, but
+/// the acceleration ramps up and down at no more than `max_jerk` rather than changing instantly.
+/// This makes the motion smoother, which reduces wear and keeps loads on an arm or lift from
+/// swinging, at the cost of taking slightly longer.
+///
+/// Distances may be measured in any unit, as long as velocities are in that unit per second,
+/// accelerations in that unit per second squared, and jerks in that unit per second cubed. A
+/// negative `distance` moves backwards.
+///
+/// Use `Motor.follow_profile` to drive a motor along the profile.
+#[class(qstr!(SCurveProfile))]
+#[repr(C)]
+pub struct SCurveProfile {
+    base: ObjBase,
+    profile: MotionProfile,
+}
+
+/// The state of a motion profile at one point in time, returned by `TrapezoidProfile.sample` and
+/// `SCurveProfile.sample`.
+///
+/// `position`, `velocity` and `acceleration` are read-only `float` attributes, measured relative to
+/// the start of the profile in the profile's units.
+#[class(qstr!(ProfileState))]
+#[repr(C)]
+pub struct ProfileState {
+    base: ObjBase,
+    sample: ProfileSample,
+}
+
+/// Returns the profile held by a `TrapezoidProfile` or `SCurveProfile`.
+pub fn profile_from_obj(obj: Obj) -> Option<MotionProfile> {
+    obj.try_as_obj::<TrapezoidProfile>()
+        .map(|profile| profile.profile)
+        .or_else(|| {
+            obj.try_as_obj::<SCurveProfile>()
+                .map(|profile| profile.profile)
+        })
+}
+
+/// Returns the profile held by `obj`, the `self` of a profile method.
+fn profile_arg(obj: Obj) -> Result<MotionProfile, Exception> {
+    profile_from_obj(obj).ok_or_else(|| type_error(c"expected a motion profile").into())
+}
+
+fn invalid_limits() -> Exception {
+    value_error(c"profile limits must be positive and finite").into()
+}
+
+/// Samples `profile` for a Python `sample` method taking `(self, t, unit=TimeUnit.SECOND)`.
+fn sample_args(args: &[Obj]) -> Result<ProfileState, Exception> {
+    let mut reader = Args::new(args.len(), 0, args).reader();
+
+    let profile = profile_arg(reader.next_positional::<Obj>()?)?;
+    let t = reader.next_positional::<f32>()?;
+    let unit = reader.next_positional_or(TimeUnitObj::SECOND)?.unit();
+
+    // the profile clamps times before its start anyway
+    let t = unit.float_to_dur(t.max(0.0))?.as_secs_f32();
+
+    Ok(ProfileState {
+        base: ProfileState::OBJ_TYPE.into(),
+        sample: profile.sample(t),
+    })
+}
+
+/// Returns `profile`'s duration for a Python `duration` method taking
+/// `(self, unit=TimeUnit.SECOND)`.
+fn duration_args(args: &[Obj]) -> Result<f32, Exception> {
+    let mut reader = Args::new(args.len(), 0, args).reader();
+
+    let profile = profile_arg(reader.next_positional::<Obj>()?)?;
+    let unit = reader.next_positional_or(TimeUnitObj::SECOND)?.unit();
+
+    let duration = Duration::try_from_secs_f32(profile.duration())
+        .map_err(|_| TimeConversionError::OutOfRange)?;
+    Ok(unit.dur_to_float(duration))
+}
+
+#[class_methods]
+impl TrapezoidProfile {
+    /// Plans a trapezoidal profile over `distance`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If an argument is not numeric.
+    /// - `ValueError`: If `max_velocity` or `max_acceleration` isn't positive and finite, or if
+    ///   `distance` isn't finite.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// # Move 90 degrees, at up to 180 degrees/s, accelerating at 360 degrees/s^2
+    /// profile = TrapezoidProfile(90, 180, 360)
+    /// print(profile.duration(SECOND))
+    /// ```
+    #[make_new]
+    #[stub(
+        sig = "(self, distance: float, max_velocity: float, max_acceleration: float, /) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(3, 3).assert_nkw(0, 0);
+
+        let distance = check_finite(reader.next_positional()?, "distance")?;
+        let max_velocity = check_positive(reader.next_positional()?, "max_velocity")?;
+        let max_acceleration = check_positive(reader.next_positional()?, "max_acceleration")?;
+
+        Ok(Self {
+            base: ty.into(),
+            profile: MotionProfile::new(distance, max_velocity, max_acceleration, f32::INFINITY)
+                .ok_or_else(invalid_limits)?,
+        })
+    }
+
+    /// Returns the state of the profile `t` after it starts, measured in `unit`.
+    ///
+    /// Times before the start or after the end of the profile return the state at the start or
+    /// end, where the profile is at rest.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `t` isn't finite, or is too large to represent.
+    #[method(ty = var_between(min = 2, max = 3))]
+    #[stub(sig = "(self, t: float, unit: TimeUnit = TimeUnit.SECOND, /) -> ProfileState")]
+    fn sample(args: &[Obj]) -> Result<ProfileState, Exception> {
+        sample_args(args)
+    }
+
+    /// Returns the time taken to complete the profile, measured in `unit`.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If the duration is too large to represent.
+    #[method(ty = var_between(min = 1, max = 2))]
+    #[stub(sig = "(self, unit: TimeUnit = TimeUnit.SECOND, /) -> float")]
+    fn duration(args: &[Obj]) -> Result<f32, Exception> {
+        duration_args(args)
+    }
+}
+
+#[class_methods]
+impl SCurveProfile {
+    /// Plans an S-curve profile over `distance`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If an argument is not numeric.
+    /// - `ValueError`: If `max_velocity`, `max_acceleration` or `max_jerk` isn't positive and
+    ///   finite, or if `distance` isn't finite.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// # Move 2 turns, at up to 1 turn/s, accelerating at up to 2 turns/s^2
+    /// profile = SCurveProfile(2, 1, 2, 8)
+    /// state = profile.sample(500, MILLIS)
+    /// print(state.position, state.velocity, state.acceleration)
+    /// ```
+    #[make_new]
+    #[stub(
+        sig = "(self, distance: float, max_velocity: float, max_acceleration: float, max_jerk: float, /) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(4, 4).assert_nkw(0, 0);
+
+        let distance = check_finite(reader.next_positional()?, "distance")?;
+        let max_velocity = check_positive(reader.next_positional()?, "max_velocity")?;
+        let max_acceleration = check_positive(reader.next_positional()?, "max_acceleration")?;
+        let max_jerk = check_positive(reader.next_positional()?, "max_jerk")?;
+
+        Ok(Self {
+            base: ty.into(),
+            profile: MotionProfile::new(distance, max_velocity, max_acceleration, max_jerk)
+                .ok_or_else(invalid_limits)?,
+        })
+    }
+
+    /// Returns the state of the profile `t` after it starts, measured in `unit`.
+    ///
+    /// Times before the start or after the end of the profile return the state at the start or
+    /// end, where the profile is at rest.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `t` isn't finite, or is too large to represent.
+    #[method(ty = var_between(min = 2, max = 3))]
+    #[stub(sig = "(self, t: float, unit: TimeUnit = TimeUnit.SECOND, /) -> ProfileState")]
+    fn sample(args: &[Obj]) -> Result<ProfileState, Exception> {
+        sample_args(args)
+    }
+
+    /// Returns the time taken to complete the profile, measured in `unit`.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If the duration is too large to represent.
+    #[method(ty = var_between(min = 1, max = 2))]
+    #[stub(sig = "(self, unit: TimeUnit = TimeUnit.SECOND, /) -> float")]
+    fn duration(args: &[Obj]) -> Result<f32, Exception> {
+        duration_args(args)
+    }
+}
+
+#[class_methods]
+impl ProfileState {
+    #[attr]
+    #[stub(attrs = ["position: float", "velocity: float", "acceleration: float"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let value = match attr.as_str() {
+            "position" => self.sample.position,
+            "velocity" => self.sample.velocity,
+            "acceleration" => self.sample.acceleration,
+            _ => return,
+        };
+
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+        result.return_value(value);
+    }
+
+    /// Formats the state as `ProfileState(position=..., velocity=..., acceleration=...)`.
+    #[printer]
+    fn printer(&self, print: &mut Print, _kind: PrintKind) {
+        let _ = write!(
+            print,
+            "ProfileState(position={}, velocity={}, acceleration={})",
+            self.sample.position, self.sample.velocity, self.sample.acceleration
+        );
+    }
+}
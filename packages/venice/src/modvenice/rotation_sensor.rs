@@ -1,7 +1,10 @@
 use argparse::Args;
 use micropython_macros::{class, class_methods};
-use micropython_rs::obj::{Obj, ObjBase, ObjType};
-use vexide_devices::{math::Direction, smart::rotation::RotationSensor};
+use micropython_rs::obj::{Obj, ObjBase, ObjTrait, ObjType};
+use vexide_devices::{
+    math::{Angle, Direction},
+    smart::rotation::RotationSensor,
+};
 
 use crate::{
     devices::{self},
@@ -9,6 +12,7 @@ use crate::{
         Exception,
         motor::direction::DirectionObj,
         units::{rotation::RotationUnitObj, time::TimeUnitObj},
+        vasyncio::until::{Threshold, UntilFuture},
     },
     registry::SmartGuard,
 };
@@ -20,6 +24,13 @@ pub struct RotationSensorObj {
     guard: SmartGuard<RotationSensor>,
 }
 
+impl RotationSensorObj {
+    /// Returns the total accumulated rotation of the sensor.
+    pub fn position(&self) -> Result<Angle, Exception> {
+        Ok(self.guard.borrow_mut().position()?)
+    }
+}
+
 /// A rotation sensor plugged into a Smart Port.
 ///
 /// This class provides an interface to interact with the VEX V5 Rotation Sensor, which measures
@@ -117,6 +128,46 @@ impl RotationSensorObj {
         Ok(unit.unit().angle_to_float(position))
     }
 
+    /// Waits until the sensor's position reaches `position`, measured in `unit`.
+    ///
+    /// The returned `UntilFuture` compares the position when it is awaited against `position`, then
+    /// completes once the sensor has moved as far as `position` in that direction. Because the
+    /// position is polled, the sensor may be slightly past `position` by the time the await
+    /// completes. Awaiting it returns `None`.
+    ///
+    /// # Raises
+    ///
+    /// `DeviceError`: If, while awaiting, no device is connected to the port, or if the wrong type
+    /// of device is connected.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// lift = Motor(1)
+    /// sensor = RotationSensor(2)
+    ///
+    /// async def main():
+    ///     lift.set_voltage(8)
+    ///     await sensor.wait_until_position(90, DEGREES)
+    ///     lift.brake(BrakeMode.HOLD)
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    #[method]
+    fn wait_until_position(self_in: Obj, position: f32, unit: &RotationUnitObj) -> UntilFuture {
+        let target = unit.unit().float_to_angle(position).as_degrees() as f32;
+        UntilFuture::reading(
+            |sensor| {
+                let position = sensor.as_obj::<Self>().guard.borrow_mut().position()?;
+                Ok(Some(position.as_degrees() as f32))
+            },
+            self_in,
+            Threshold::Reach(target),
+        )
+    }
+
     /// Sets the sensor's position reading.
     ///
     /// # Raises
@@ -0,0 +1,226 @@
+use std::{fmt::Write, time::Duration};
+
+use argparse::Args;
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{type_error, value_error},
+    init::token,
+    obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
+    print::{Print, PrintKind},
+    qstr::Qstr,
+};
+use venice_core::trajectory::{Constraints, Trajectory, TrajectorySample};
+
+use crate::{
+    alloc::Gc,
+    modvenice::{
+        Exception,
+        math::Pose2,
+        read_only_attr::read_only_attr,
+        units::time::{TimeConversionError, TimeUnitObj},
+        validate::check_positive,
+    },
+};
+
+/// A smooth path through a list of poses, timed so that a differential drive can follow it.
+///
+/// Each pair of waypoints in a row is joined by a curve that leaves the first along its heading
+/// and arrives at the second along its heading, so the headings shape the path. The robot starts
+/// and ends at rest, and moves along the curves as fast as it can while keeping both wheels under
+/// `max_velocity`, which makes it slow down for tight curves, and never speeding up or slowing
+/// down faster than `max_acceleration`. `track_width` is the distance between the left and right
+/// wheels.
+///
+/// Distances are measured in the waypoints' unit, velocities in that unit per second, and
+/// accelerations in that unit per second squared. Headings should point along the direction of
+/// travel, since a trajectory only drives forwards.
+///
+/// Pass a trajectory to `Ramsete.follow` to drive along it on schedule, or to `PurePursuit.follow`
+/// to follow its shape.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// trajectory = Trajectory(
+///     [Pose2(0, 0, 0, DEGREES), Pose2(48, 24, 90, DEGREES), Pose2(24, 60, 180, DEGREES)],
+///     12.0,
+///     50.0,
+///     80.0,
+/// )
+/// print(trajectory.duration(SECOND))
+/// state = trajectory.sample(1.0)
+/// print(state.pose, state.velocity)
+/// ```
+#[class(qstr!(Trajectory))]
+#[repr(C)]
+pub struct TrajectoryObj {
+    base: ObjBase,
+    trajectory: Trajectory<Gc>,
+}
+
+/// The state of a `Trajectory` at one point in time, returned by `Trajectory.sample`.
+///
+/// `time` is in seconds since the start. `pose` is the `Pose2` the robot should be at, and
+/// `distance` is how far along the trajectory it is. `velocity` and `acceleration` are measured
+/// along the path, `curvature` is in radians per unit travelled and `angular_velocity` in radians
+/// per second, both positive when turning counterclockwise. All are read-only attributes.
+#[class(qstr!(TrajectoryState))]
+#[repr(C)]
+pub struct TrajectoryState {
+    base: ObjBase,
+    sample: TrajectorySample,
+}
+
+impl TrajectoryObj {
+    pub fn trajectory(&self) -> &Trajectory<Gc> {
+        &self.trajectory
+    }
+}
+
+#[class_methods]
+impl TrajectoryObj {
+    /// Plans a trajectory through `waypoints`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `waypoints` isn't a list or tuple of `Pose2`, or a limit isn't numeric.
+    /// - `ValueError`: If there are fewer than two waypoints, if two waypoints in a row share a
+    ///   position, or if a limit isn't positive and finite.
+    #[make_new]
+    #[stub(
+        sig = "(self, waypoints: list[Pose2], track_width: float, max_velocity: float, max_acceleration: float, /) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(4, 4).assert_nkw(0, 0);
+
+        let waypoints = reader
+            .next_positional::<Obj>()?
+            .try_array()
+            .ok_or_else(|| type_error(c"expected a list or tuple of Pose2"))?
+            .iter()
+            .map(|waypoint| {
+                waypoint
+                    .try_as_obj::<Pose2>()
+                    .map(Pose2::pose)
+                    .ok_or_else(|| type_error(c"expected a Pose2"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let constraints = Constraints {
+            track_width: check_positive(reader.next_positional()?, "track_width")?,
+            max_velocity: check_positive(reader.next_positional()?, "max_velocity")?,
+            max_acceleration: check_positive(reader.next_positional()?, "max_acceleration")?,
+        };
+
+        if waypoints.len() < 2 {
+            Err(value_error(c"a trajectory needs at least two waypoints"))?
+        }
+        if waypoints
+            .windows(2)
+            .any(|pair| pair[0].distance_to(pair[1].x, pair[1].y) == 0.0)
+        {
+            Err(value_error(
+                c"waypoints in a row must be at different positions",
+            ))?
+        }
+
+        Ok(Self {
+            base: ty.into(),
+            trajectory: Trajectory::new(&waypoints, constraints, Gc { token: token() })
+                .ok_or_else(|| value_error(c"trajectory limits must be positive and finite"))?,
+        })
+    }
+
+    /// Returns the state of the trajectory `t` after it starts, measured in `unit`.
+    ///
+    /// Times before the start or after the end of the trajectory return the state at the first or
+    /// last waypoint, where the robot is at rest.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `t` isn't finite, or is too large to represent.
+    #[method(ty = var_between(min = 2, max = 3))]
+    #[stub(sig = "(self, t: float, unit: TimeUnit = TimeUnit.SECOND, /) -> TrajectoryState")]
+    fn sample(args: &[Obj]) -> Result<TrajectoryState, Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+
+        let this = reader.next_positional::<&TrajectoryObj>()?;
+        let t = reader.next_positional::<f32>()?;
+        let unit = reader.next_positional_or(TimeUnitObj::SECOND)?.unit();
+
+        // the trajectory clamps times before its start anyway
+        let t = unit.float_to_dur(t.max(0.0))?.as_secs_f32();
+        Ok(TrajectoryState {
+            base: TrajectoryState::OBJ_TYPE.into(),
+            sample: this.trajectory.sample(t),
+        })
+    }
+
+    /// Returns the time taken to follow the trajectory, measured in `unit`.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If the duration is too large to represent.
+    #[method(ty = var_between(min = 1, max = 2))]
+    #[stub(sig = "(self, unit: TimeUnit = TimeUnit.SECOND, /) -> float")]
+    fn duration(args: &[Obj]) -> Result<f32, Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+
+        let this = reader.next_positional::<&TrajectoryObj>()?;
+        let unit = reader.next_positional_or(TimeUnitObj::SECOND)?.unit();
+
+        let duration = Duration::try_from_secs_f32(this.trajectory.duration())
+            .map_err(|_| TimeConversionError::OutOfRange)?;
+        Ok(unit.dur_to_float(duration))
+    }
+
+    /// Returns the length of the trajectory.
+    #[method]
+    fn length(&self) -> f32 {
+        self.trajectory.length()
+    }
+}
+
+#[class_methods]
+impl TrajectoryState {
+    #[attr]
+    #[stub(
+        attrs = ["time: float", "distance: float", "pose: Pose2", "velocity: float", "acceleration: float", "curvature: float", "angular_velocity: float"]
+    )]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+
+        let sample = &self.sample;
+        let value = match attr.as_str() {
+            "time" => sample.time,
+            "distance" => sample.distance,
+            "pose" => return result.return_value(Pose2::new(sample.pose)),
+            "velocity" => sample.velocity,
+            "acceleration" => sample.acceleration,
+            "curvature" => sample.curvature,
+            "angular_velocity" => sample.angular_velocity(),
+            _ => return,
+        };
+        result.return_value(value);
+    }
+
+    /// Formats the state as `TrajectoryState(time=..., pose=Pose2(...), velocity=...)`.
+    #[printer]
+    fn printer(&self, print: &mut Print, _kind: PrintKind) {
+        let sample = &self.sample;
+        let _ = write!(
+            print,
+            "TrajectoryState(time={}, pose=Pose2(x={}, y={}, heading={}), velocity={})",
+            sample.time, sample.pose.x, sample.pose.y, sample.pose.theta, sample.velocity
+        );
+    }
+}
@@ -0,0 +1,733 @@
+use std::cell::Cell;
+
+use argparse::{Args, error_msg};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{type_error, value_error},
+    init::token,
+    obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
+    qstr::Qstr,
+};
+use vexide_devices::{display::Display, math::Point2};
+
+use super::{
+    ACCENT, Align, Bounds, Core, MUTED, PRESSED, SURFACE, TEXT, Touch, Widget, draw_text, fill,
+    layout, parse_text, parse_widget, screen::WidgetFuture, stroke, widgets::parse_callback,
+};
+use crate::{
+    alloc::Gc,
+    modvenice::{Exception, read_only_attr::read_only_attr, validate::check_non_negative},
+};
+
+/// The height of a `TabView`'s tab bar.
+const TAB_BAR_HEIGHT: i32 = 36;
+/// The height of a `Dialog`'s title strip and of its buttons.
+const DIALOG_STRIP_HEIGHT: i32 = 36;
+
+/// Children placed one after another, shared by `Row` and `Column`.
+struct Linear {
+    children: Vec<Obj, Gc>,
+    weights: Vec<f32, Gc>,
+    spacing: i32,
+    padding: i32,
+}
+
+impl Linear {
+    fn parse(n_pos: usize, n_kw: usize, args: &[Obj]) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(1, 1).assert_nkw(0, 3);
+
+        let items = reader
+            .next_positional::<Obj>()?
+            .try_array()
+            .ok_or_else(|| type_error(c"expected a list or tuple of widgets"))?;
+        // The GC allocator can't grow a buffer, so it's allocated at its full size
+        let mut children = Vec::with_capacity_in(items.len(), Gc { token: token() });
+        for item in &items {
+            children.push(parse_widget(*item)?);
+        }
+
+        let mut weights = None;
+        let mut spacing = 6;
+        let mut padding = 0;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "weights" => {
+                    if !arg.obj.is_none() {
+                        weights = Some(parse_weights(*arg.obj, children.len())?);
+                    }
+                }
+                "spacing" => spacing = arg.parse::<i32>()?,
+                "padding" => padding = arg.parse::<i32>()?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+        if spacing < 0 || padding < 0 {
+            Err(value_error(c"spacing and padding cannot be negative"))?
+        }
+
+        let weights = match weights {
+            Some(weights) => weights,
+            None => {
+                let mut weights = Vec::with_capacity_in(children.len(), Gc { token: token() });
+                weights.resize(children.len(), 1.0);
+                weights
+            }
+        };
+
+        Ok(Self {
+            children,
+            weights,
+            spacing,
+            padding,
+        })
+    }
+
+    /// Splits `bounds` between the children, along the x axis if `horizontal` is set.
+    fn arrange(&self, bounds: Bounds, horizontal: bool) {
+        let bounds = bounds.inset(self.padding);
+        let length = if horizontal {
+            bounds.width
+        } else {
+            bounds.height
+        };
+        let gaps = self.spacing * (self.children.len() as i32 - 1).max(0);
+        let available = (length - gaps).max(0) as f32;
+        let total: f32 = self.weights.iter().sum();
+
+        // Each child ends where its share of the cumulative weight does, so rounding never leaves
+        // a gap at the end
+        let mut weight_so_far = 0.0;
+        let mut start = 0;
+        for (index, (child, weight)) in self.children.iter().zip(&self.weights).enumerate() {
+            weight_so_far += weight;
+            let end = (available * weight_so_far / total).round() as i32;
+            let offset = start + self.spacing * index as i32;
+            let child_bounds = if horizontal {
+                Bounds {
+                    x: bounds.x + offset,
+                    width: end - start,
+                    ..bounds
+                }
+            } else {
+                Bounds {
+                    y: bounds.y + offset,
+                    height: end - start,
+                    ..bounds
+                }
+            };
+            layout(*child, child_bounds);
+            start = end;
+        }
+    }
+}
+
+fn parse_weights(obj: Obj, len: usize) -> Result<Vec<f32, Gc>, Exception> {
+    let items = obj
+        .try_array()
+        .ok_or_else(|| type_error(c"expected a list or tuple of weights"))?;
+    if items.len() != len {
+        Err(value_error(c"there must be one weight per child"))?
+    }
+
+    let mut weights = Vec::with_capacity_in(len, Gc { token: token() });
+    for item in items {
+        let weight = item
+            .try_to_int()
+            .map(|weight| weight as f32)
+            .or(item.try_to_float())
+            .ok_or_else(|| type_error(c"expected a number"))?;
+        weights.push(check_non_negative(weight, "weights")?);
+    }
+    if len > 0 && weights.iter().all(|&weight| weight == 0.0) {
+        Err(value_error(c"at least one weight must be positive"))?
+    }
+    Ok(weights)
+}
+
+/// Widgets placed side by side from left to right, available as `ui.Row`.
+///
+/// Each child gets a share of the row's width in proportion to its weight, after `padding` is
+/// taken from every edge and `spacing` is left between neighbours.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// status = ui.Label("Ready")
+/// start = ui.Button("Start")
+/// screen = ui.Screen(ui.Row([status, start], weights=[2, 1], padding=8))
+/// ```
+#[class(qstr!(Row))]
+#[repr(C)]
+pub struct Row {
+    base: ObjBase,
+    core: Core,
+    linear: Linear,
+}
+
+/// Widgets stacked from top to bottom, available as `ui.Column`.
+///
+/// Each child gets a share of the column's height in proportion to its weight, after `padding`
+/// is taken from every edge and `spacing` is left between neighbours.
+#[class(qstr!(Column))]
+#[repr(C)]
+pub struct Column {
+    base: ObjBase,
+    core: Core,
+    linear: Linear,
+}
+
+impl Widget for Row {
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn draw(&self, _display: &mut Display) {}
+
+    fn children(&self) -> &[Obj] {
+        &self.linear.children
+    }
+
+    fn arrange(&self) {
+        self.linear.arrange(self.core.bounds(), true);
+    }
+}
+
+#[class_methods]
+impl Row {
+    /// Creates a row of `children`, sized according to `weights`, or equally if it is `None`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `children` isn't a list or tuple of widgets, or a `Dialog` is in it.
+    /// - `ValueError`: If there isn't one weight per child, a weight is negative or non-finite,
+    ///   every weight is zero, or `spacing` or `padding` is negative.
+    #[make_new]
+    #[stub(
+        sig = "(self, children: list[Widget] | tuple[Widget, ...], /, *, weights: list[float] | tuple[float, ...] | None = None, spacing: int = 6, padding: int = 0) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        Ok(Self {
+            base: ty.into(),
+            core: Core::new(None),
+            linear: Linear::parse(n_pos, n_kw, args)?,
+        })
+    }
+}
+
+impl Widget for Column {
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn draw(&self, _display: &mut Display) {}
+
+    fn children(&self) -> &[Obj] {
+        &self.linear.children
+    }
+
+    fn arrange(&self) {
+        self.linear.arrange(self.core.bounds(), false);
+    }
+}
+
+#[class_methods]
+impl Column {
+    /// Creates a column of `children`, sized according to `weights`, or equally if it is `None`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `children` isn't a list or tuple of widgets, or a `Dialog` is in it.
+    /// - `ValueError`: If there isn't one weight per child, a weight is negative or non-finite,
+    ///   every weight is zero, or `spacing` or `padding` is negative.
+    #[make_new]
+    #[stub(
+        sig = "(self, children: list[Widget] | tuple[Widget, ...], /, *, weights: list[float] | tuple[float, ...] | None = None, spacing: int = 6, padding: int = 0) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        Ok(Self {
+            base: ty.into(),
+            core: Core::new(None),
+            linear: Linear::parse(n_pos, n_kw, args)?,
+        })
+    }
+}
+
+/// Pages of widgets with a bar of tabs to switch between them, available as `ui.TabView`.
+///
+/// Only the selected page is shown and receives touches. Tapping a tab selects its page and
+/// raises an event with its index. The read-only `index` attribute is the index of the selected
+/// page.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// tabs = ui.TabView([
+///     ("Drive", ui.Label("Battery OK")),
+///     ("Auton", ui.List(["Left", "Right", "Skills"])),
+/// ])
+/// screen = ui.Screen(tabs)
+/// ```
+#[class(qstr!(TabView))]
+#[repr(C)]
+pub struct TabView {
+    base: ObjBase,
+    core: Core,
+    titles: Vec<Obj, Gc>,
+    contents: Vec<Obj, Gc>,
+    index: Cell<usize>,
+    pressed_tab: Cell<Option<usize>>,
+}
+
+impl TabView {
+    /// Returns the bounds of the tab bar and of the pages below it.
+    fn split(&self) -> (Bounds, Bounds) {
+        self.core.bounds().split_top(TAB_BAR_HEIGHT)
+    }
+
+    fn tab_bounds(&self, index: usize) -> Bounds {
+        let (bar, _) = self.split();
+        let count = self.titles.len() as i32;
+        let start = bar.width * index as i32 / count;
+        let end = bar.width * (index as i32 + 1) / count;
+        Bounds {
+            x: bar.x + start,
+            width: end - start,
+            ..bar
+        }
+    }
+
+    fn tab_at(&self, point: Point2<i16>) -> Option<usize> {
+        (0..self.titles.len()).find(|&index| self.tab_bounds(index).contains(point))
+    }
+
+    fn check_index(&self, index: i32) -> Result<usize, Exception> {
+        match usize::try_from(index) {
+            Ok(index) if index < self.titles.len() => Ok(index),
+            _ => Err(value_error(c"index is out of range"))?,
+        }
+    }
+}
+
+impl Widget for TabView {
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn draw(&self, display: &mut Display) {
+        for (index, title) in self.titles.iter().enumerate() {
+            let tab = self.tab_bounds(index);
+            let color = if index == self.index.get() {
+                ACCENT
+            } else if self.pressed_tab.get() == Some(index) {
+                PRESSED
+            } else {
+                SURFACE
+            };
+            let tab = Bounds {
+                width: tab.width - 1,
+                ..tab
+            };
+            fill(display, tab, color);
+            draw_text(display, *title, tab, Align::Center, TEXT);
+        }
+    }
+
+    fn children(&self) -> &[Obj] {
+        let index = self.index.get();
+        &self.contents[index..=index]
+    }
+
+    fn arrange(&self) {
+        // Every page is laid out so that switching tabs doesn't need to
+        let (_, pages) = self.split();
+        for content in &self.contents {
+            layout(*content, pages.inset(4));
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+
+    fn touch(&self, touch: Touch) -> Option<Obj> {
+        match touch {
+            Touch::Press(point) => {
+                self.pressed_tab.set(self.tab_at(point));
+                self.core.invalidate();
+                None
+            }
+            Touch::Drag(point) => {
+                let over = self.tab_at(point);
+                if self.pressed_tab.get().is_some() && over != self.pressed_tab.get() {
+                    self.pressed_tab.set(None);
+                    self.core.invalidate();
+                }
+                None
+            }
+            Touch::Release(point) => {
+                let index = self
+                    .pressed_tab
+                    .take()
+                    .filter(|&index| self.tab_at(point) == Some(index))?;
+                // Redrawing the tab view redraws the newly selected page along with it
+                self.index.set(index);
+                self.core.invalidate();
+                Some(Obj::from(index as i32))
+            }
+        }
+    }
+}
+
+#[class_methods]
+impl TabView {
+    /// Creates a tab view from `(title, widget)` pairs, with the page at `index` selected.
+    ///
+    /// `on_change` is called with a page's index each time its tab is tapped. If it is an
+    /// `async` function, the coroutine it returns is spawned as a task.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `tabs` isn't a list or tuple of `(str, Widget)` tuples, a `Dialog` is in
+    ///   it, or `on_change` isn't callable.
+    /// - `ValueError`: If `tabs` is empty, a title contains a NUL character, or `index` is out of
+    ///   range.
+    #[make_new]
+    #[stub(
+        sig = "(self, tabs: list[tuple[str, Widget]] | tuple[tuple[str, Widget], ...], /, *, index: int = 0, on_change: Callable[[int], Any] | None = None) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(1, 1).assert_nkw(0, 2);
+
+        let tabs = reader
+            .next_positional::<Obj>()?
+            .try_array()
+            .ok_or_else(|| type_error(c"expected a list or tuple of (title, widget) tuples"))?;
+        if tabs.is_empty() {
+            Err(value_error(c"a TabView needs at least one tab"))?
+        }
+
+        let mut titles = Vec::with_capacity_in(tabs.len(), Gc { token: token() });
+        let mut contents = Vec::with_capacity_in(tabs.len(), Gc { token: token() });
+        for tab in tabs {
+            let Some([title, content]) = tab.try_array().and_then(|pair| pair.try_into().ok())
+            else {
+                Err(type_error(c"expected a (title, widget) tuple"))?
+            };
+            titles.push(parse_text(title)?);
+            contents.push(parse_widget(content)?);
+        }
+
+        let mut index = 0;
+        let mut callback = None;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "index" => index = arg.parse::<i32>()?,
+                "on_change" => callback = parse_callback(arg)?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        let tab_view = Self {
+            base: ty.into(),
+            core: Core::new(callback),
+            titles,
+            contents,
+            index: Cell::new(0),
+            pressed_tab: Cell::new(None),
+        };
+        tab_view.index.set(tab_view.check_index(index)?);
+        Ok(tab_view)
+    }
+
+    #[attr]
+    #[stub(attrs = ["index: int"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+        result.return_value(match attr.as_str() {
+            "index" => Obj::from(self.index.get() as i32),
+            _ => return,
+        });
+    }
+
+    /// Shows the page at `index` without raising an event.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `index` is out of range.
+    #[method]
+    fn select(&self, index: i32) -> Result<(), Exception> {
+        let index = self.check_index(index)?;
+        if self.index.replace(index) != index {
+            self.core.invalidate();
+        }
+        Ok(())
+    }
+
+    /// Returns a `WidgetFuture` that completes with a page's index the next time its tab is
+    /// tapped.
+    #[method]
+    #[stub(sig = "(self, /) -> WidgetFuture")]
+    fn wait(self_in: Obj) -> WidgetFuture {
+        WidgetFuture::new(self_in)
+    }
+}
+
+/// A modal message with a row of buttons, available as `ui.Dialog`.
+///
+/// A dialog is shown over everything else with `Screen.open`, and while it is open, nothing
+/// else on the screen can be touched. Tapping one of its buttons closes it and raises an event
+/// with the button's index. The read-only `is_open` attribute is whether it is being shown.
+///
+/// The message is drawn on a single line, so it should be short.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// root = ui.Label("Driver control")
+/// screen = ui.Screen(root)
+/// confirm = ui.Dialog("Reset odometry?", "Put the robot in its corner.", buttons=["No", "Yes"])
+///
+/// async def main():
+///     vasyncio.spawn(screen.run())
+///     screen.open(confirm)
+///     if await confirm.wait() == 1:
+///         print("resetting")
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(Dialog))]
+#[repr(C)]
+pub struct Dialog {
+    base: ObjBase,
+    core: Core,
+    title: Obj,
+    message: Obj,
+    buttons: Vec<Obj, Gc>,
+    open: Cell<bool>,
+    pressed: Cell<Option<usize>>,
+}
+
+impl Dialog {
+    pub fn is_open(&self) -> bool {
+        self.open.get()
+    }
+
+    /// Marks the dialog as shown, to be drawn at the next update.
+    pub fn show(&self) {
+        self.open.set(true);
+        self.pressed.set(None);
+        self.core.invalidate();
+    }
+
+    /// Marks the dialog as closed, to be cleared from the screen at the next update.
+    pub fn hide(&self) {
+        self.open.set(false);
+    }
+
+    fn button_bounds(&self, index: usize) -> Bounds {
+        let bounds = self.core.bounds().inset(8);
+        let row = Bounds {
+            y: bounds.y + bounds.height - DIALOG_STRIP_HEIGHT,
+            height: DIALOG_STRIP_HEIGHT,
+            ..bounds
+        };
+        let count = self.buttons.len() as i32;
+        let gaps = 8 * (count - 1);
+        let start = (row.width - gaps) * index as i32 / count + 8 * index as i32;
+        let end = (row.width - gaps) * (index as i32 + 1) / count + 8 * index as i32;
+        Bounds {
+            x: row.x + start,
+            width: end - start,
+            ..row
+        }
+    }
+
+    fn button_at(&self, point: Point2<i16>) -> Option<usize> {
+        (0..self.buttons.len()).find(|&index| self.button_bounds(index).contains(point))
+    }
+}
+
+impl Widget for Dialog {
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn draw(&self, display: &mut Display) {
+        let bounds = self.core.bounds();
+        fill(display, bounds, SURFACE);
+        stroke(display, bounds, ACCENT);
+
+        let (title, rest) = bounds.inset(1).split_top(DIALOG_STRIP_HEIGHT);
+        fill(display, title, ACCENT);
+        draw_text(display, self.title, title, Align::Left, TEXT);
+
+        let message = Bounds {
+            height: rest.height - DIALOG_STRIP_HEIGHT - 8,
+            ..rest
+        };
+        draw_text(display, self.message, message, Align::Left, MUTED);
+
+        for (index, text) in self.buttons.iter().enumerate() {
+            let button = self.button_bounds(index);
+            let color = if self.pressed.get() == Some(index) {
+                PRESSED
+            } else {
+                SURFACE
+            };
+            fill(display, button, color);
+            stroke(display, button, ACCENT);
+            draw_text(display, *text, button, Align::Center, TEXT);
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+
+    fn touch(&self, touch: Touch) -> Option<Obj> {
+        match touch {
+            Touch::Press(point) => {
+                self.pressed.set(self.button_at(point));
+                self.core.invalidate();
+                None
+            }
+            Touch::Drag(point) => {
+                if self.pressed.get().is_some() && self.button_at(point) != self.pressed.get() {
+                    self.pressed.set(None);
+                    self.core.invalidate();
+                }
+                None
+            }
+            Touch::Release(point) => {
+                let index = self
+                    .pressed
+                    .take()
+                    .filter(|&index| self.button_at(point) == Some(index))?;
+                self.hide();
+                Some(Obj::from(index as i32))
+            }
+        }
+    }
+}
+
+#[class_methods]
+impl Dialog {
+    /// Creates a dialog showing `title` and `message` above a row of `buttons`.
+    ///
+    /// The dialog isn't shown until it is passed to `Screen.open`. `on_choose` is called with a
+    /// button's index each time the dialog is closed by tapping one. If it is an `async`
+    /// function, the coroutine it returns is spawned as a task.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `title`, `message` or a button's text isn't a string, or `on_choose`
+    ///   isn't callable.
+    /// - `ValueError`: If a string contains a NUL character, or `buttons` is empty.
+    #[make_new]
+    #[stub(
+        sig = "(self, title: str, message: str, /, *, buttons: list[str] | tuple[str, ...] = [\"OK\"], on_choose: Callable[[int], Any] | None = None) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(2, 2).assert_nkw(0, 2);
+
+        let title = parse_text(reader.next_positional::<Obj>()?)?;
+        let message = parse_text(reader.next_positional::<Obj>()?)?;
+
+        let mut buttons = None;
+        let mut callback = None;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "buttons" => {
+                    let items = arg
+                        .obj
+                        .try_array()
+                        .ok_or_else(|| type_error(c"expected a list or tuple of strings"))?;
+                    if items.is_empty() {
+                        Err(value_error(c"a Dialog needs at least one button"))?
+                    }
+                    let mut parsed = Vec::with_capacity_in(items.len(), Gc { token: token() });
+                    for item in items {
+                        parsed.push(parse_text(item)?);
+                    }
+                    buttons = Some(parsed);
+                }
+                "on_choose" => callback = parse_callback(arg)?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+        let buttons = buttons.unwrap_or_else(|| {
+            let mut buttons = Vec::with_capacity_in(1, Gc { token: token() });
+            buttons.push(Obj::from_qstr(qstr!(OK)));
+            buttons
+        });
+
+        Ok(Self {
+            base: ty.into(),
+            core: Core::new(callback),
+            title,
+            message,
+            buttons,
+            open: Cell::new(false),
+            pressed: Cell::new(None),
+        })
+    }
+
+    #[attr]
+    #[stub(attrs = ["is_open: bool"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+        result.return_value(match attr.as_str() {
+            "is_open" => Obj::from(self.open.get()),
+            _ => return,
+        });
+    }
+
+    /// Closes the dialog without raising an event.
+    ///
+    /// The screen behind it is redrawn at the next update.
+    #[method]
+    fn close(&self) {
+        self.hide();
+    }
+
+    /// Returns a `WidgetFuture` that completes with a button's index the next time the dialog is
+    /// closed by tapping one.
+    #[method]
+    #[stub(sig = "(self, /) -> WidgetFuture")]
+    fn wait(self_in: Obj) -> WidgetFuture {
+        WidgetFuture::new(self_in)
+    }
+}
@@ -0,0 +1,287 @@
+pub mod containers;
+pub mod screen;
+pub mod widgets;
+
+use std::cell::Cell;
+
+use argparse::Callable;
+use micropython_rs::{
+    const_dict,
+    except::{type_error, value_error},
+    map::Dict,
+    obj::{Obj, ObjTrait},
+};
+use vexide_devices::{
+    color::Color,
+    display::{Display, Font, FontFamily, FontSize, Rect, Text},
+    math::Point2,
+};
+
+use self::{
+    containers::{Column, Dialog, Row, TabView},
+    screen::{Screen, ScreenFuture, WidgetFuture},
+    widgets::{Button, Label, ListView, Slider, Toggle},
+};
+use crate::modvenice::Exception;
+
+pub const UI_DICT: &Dict = const_dict![
+    qstr!(__name__) => Obj::from_qstr(qstr!(ui)),
+
+    // screen
+    qstr!(Screen) => Obj::from_static(Screen::OBJ_TYPE),
+    qstr!(ScreenFuture) => Obj::from_static(ScreenFuture::OBJ_TYPE),
+    qstr!(WidgetFuture) => Obj::from_static(WidgetFuture::OBJ_TYPE),
+    // widgets
+    qstr!(Label) => Obj::from_static(Label::OBJ_TYPE),
+    qstr!(Button) => Obj::from_static(Button::OBJ_TYPE),
+    qstr!(Toggle) => Obj::from_static(Toggle::OBJ_TYPE),
+    qstr!(Slider) => Obj::from_static(Slider::OBJ_TYPE),
+    qstr!(List) => Obj::from_static(ListView::OBJ_TYPE),
+    // containers
+    qstr!(Row) => Obj::from_static(Row::OBJ_TYPE),
+    qstr!(Column) => Obj::from_static(Column::OBJ_TYPE),
+    qstr!(TabView) => Obj::from_static(TabView::OBJ_TYPE),
+    qstr!(Dialog) => Obj::from_static(Dialog::OBJ_TYPE),
+];
+
+// The colors every widget is drawn with
+const BACKGROUND: Color = Color::new(0x12, 0x14, 0x1a);
+const SURFACE: Color = Color::new(0x2b, 0x30, 0x3b);
+const PRESSED: Color = Color::new(0x45, 0x4c, 0x5b);
+const ACCENT: Color = Color::new(0x2f, 0x80, 0xed);
+const MUTED: Color = Color::new(0x8b, 0x92, 0xa0);
+const TEXT: Color = Color::WHITE;
+
+/// A widget's position and size on the display, in pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Bounds {
+    /// The writable part of the display.
+    pub const SCREEN: Self = Self {
+        x: 0,
+        y: 0,
+        width: 480,
+        height: 240,
+    };
+
+    pub fn contains(self, point: Point2<i16>) -> bool {
+        let (x, y) = (i32::from(point.x), i32::from(point.y));
+        (self.x..self.x + self.width).contains(&x) && (self.y..self.y + self.height).contains(&y)
+    }
+
+    /// Shrinks the bounds by `amount` on every side.
+    pub fn inset(self, amount: i32) -> Self {
+        Self {
+            x: self.x + amount,
+            y: self.y + amount,
+            width: (self.width - amount * 2).max(0),
+            height: (self.height - amount * 2).max(0),
+        }
+    }
+
+    /// Splits the bounds into a strip `height` pixels tall at the top and the rest.
+    pub fn split_top(self, height: i32) -> (Self, Self) {
+        let height = height.clamp(0, self.height);
+        let top = Self { height, ..self };
+        let rest = Self {
+            y: self.y + height,
+            height: self.height - height,
+            ..self
+        };
+        (top, rest)
+    }
+
+    /// Returns bounds of the given size centered within these ones.
+    pub fn centered(self, width: i32, height: i32) -> Self {
+        let (width, height) = (width.min(self.width), height.min(self.height));
+        Self {
+            x: self.x + (self.width - width) / 2,
+            y: self.y + (self.height - height) / 2,
+            width,
+            height,
+        }
+    }
+
+    fn rect(self) -> Rect {
+        let clamp = |value: i32| value.clamp(i16::MIN.into(), i16::MAX.into()) as i16;
+        Rect {
+            top_left: Point2 {
+                x: clamp(self.x),
+                y: clamp(self.y),
+            },
+            bottom_right: Point2 {
+                x: clamp(self.x + self.width),
+                y: clamp(self.y + self.height),
+            },
+        }
+    }
+}
+
+/// How text is placed within its bounds.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Center,
+}
+
+fn fill(display: &mut Display, bounds: Bounds, color: Color) {
+    if bounds.width > 0 && bounds.height > 0 {
+        display.fill(&bounds.rect(), color);
+    }
+}
+
+fn stroke(display: &mut Display, bounds: Bounds, color: Color) {
+    if bounds.width > 0 && bounds.height > 0 {
+        display.stroke(&bounds.rect(), color);
+    }
+}
+
+/// Draws `text`, a string checked by [`parse_text`], vertically centered in `bounds`.
+fn draw_text(display: &mut Display, text: Obj, bounds: Bounds, align: Align, color: Color) {
+    let Some(Ok(text)) = text.get_cstr() else {
+        return;
+    };
+    let font = Font::new(FontSize::MEDIUM, FontFamily::Proportional);
+    let measured = Text::new(text, font, Point2 { x: 0, y: 0 });
+    let width = i32::from(measured.width());
+    let height = i32::from(measured.height());
+
+    let x = match align {
+        Align::Left => bounds.x + 6,
+        Align::Center => bounds.x + (bounds.width - width) / 2,
+    };
+    let y = bounds.y + (bounds.height - height) / 2;
+    let text = Text::new(
+        text,
+        font,
+        Point2 {
+            x: x as i16,
+            y: y as i16,
+        },
+    );
+    display.draw_text(&text, color, None);
+}
+
+/// Checks that `obj` is a string that can be drawn, returning it to be kept by a widget.
+fn parse_text(obj: Obj) -> Result<Obj, Exception> {
+    match obj.get_cstr() {
+        Some(Ok(_)) => Ok(obj),
+        Some(Err(_)) => Err(value_error(c"text cannot contain a NUL character"))?,
+        None => Err(type_error(c"expected a str"))?,
+    }
+}
+
+/// A touch on a widget, which keeps receiving touches from the press until the release.
+#[derive(Clone, Copy)]
+pub enum Touch {
+    Press(Point2<i16>),
+    Drag(Point2<i16>),
+    Release(Point2<i16>),
+}
+
+/// State shared by every widget.
+pub struct Core {
+    bounds: Cell<Bounds>,
+    dirty: Cell<bool>,
+    // the number of events the widget has raised, and the value of the latest
+    events: Cell<u32>,
+    value: Cell<Obj>,
+    callback: Option<Callable>,
+}
+
+impl Core {
+    pub fn new(callback: Option<Callable>) -> Self {
+        Self {
+            bounds: Cell::new(Bounds::default()),
+            dirty: Cell::new(true),
+            events: Cell::new(0),
+            value: Cell::new(Obj::NONE),
+            callback,
+        }
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        self.bounds.get()
+    }
+
+    /// Marks the widget to be drawn again at the next update.
+    pub fn invalidate(&self) {
+        self.dirty.set(true);
+    }
+
+    pub fn events(&self) -> u32 {
+        self.events.get()
+    }
+
+    pub fn value(&self) -> Obj {
+        self.value.get()
+    }
+
+    /// Records an event, returning the callback to pass its value to.
+    fn emit(&self, value: Obj) -> Option<Callable> {
+        self.events.set(self.events.get().wrapping_add(1));
+        self.value.set(value);
+        self.callback
+    }
+}
+
+/// The behavior of a widget class.
+pub trait Widget {
+    fn core(&self) -> &Core;
+
+    /// Draws the widget within its bounds, which have already been cleared to the background.
+    fn draw(&self, display: &mut Display);
+
+    /// Returns the widgets shown inside this one.
+    fn children(&self) -> &[Obj] {
+        &[]
+    }
+
+    /// Places the widget's children within its bounds.
+    fn arrange(&self) {}
+
+    /// Returns whether the widget responds to touches.
+    fn is_interactive(&self) -> bool {
+        false
+    }
+
+    /// Handles a touch, returning the value of the event it raises, if any.
+    fn touch(&self, _touch: Touch) -> Option<Obj> {
+        None
+    }
+}
+
+/// Returns the widget behind `obj`, if it is one.
+pub fn widget(obj: &Obj) -> Option<&dyn Widget> {
+    obj.try_as_obj::<Label>()
+        .map(|w| w as &dyn Widget)
+        .or_else(|| obj.try_as_obj::<Button>().map(|w| w as &dyn Widget))
+        .or_else(|| obj.try_as_obj::<Toggle>().map(|w| w as &dyn Widget))
+        .or_else(|| obj.try_as_obj::<Slider>().map(|w| w as &dyn Widget))
+        .or_else(|| obj.try_as_obj::<ListView>().map(|w| w as &dyn Widget))
+        .or_else(|| obj.try_as_obj::<Row>().map(|w| w as &dyn Widget))
+        .or_else(|| obj.try_as_obj::<Column>().map(|w| w as &dyn Widget))
+        .or_else(|| obj.try_as_obj::<TabView>().map(|w| w as &dyn Widget))
+        .or_else(|| obj.try_as_obj::<Dialog>().map(|w| w as &dyn Widget))
+}
+
+fn parse_widget(obj: Obj) -> Result<Obj, Exception> {
+    if widget(&obj).is_none() || obj.try_as_obj::<Dialog>().is_some() {
+        Err(type_error(c"expected a widget other than a Dialog"))?
+    }
+    Ok(obj)
+}
+
+/// Places `obj` at `bounds` and lays out everything inside it.
+fn layout(obj: Obj, bounds: Bounds) {
+    if let Some(widget) = widget(&obj) {
+        widget.core().bounds.set(bounds);
+        widget.arrange();
+    }
+}
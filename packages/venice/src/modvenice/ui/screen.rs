@@ -0,0 +1,399 @@
+use std::cell::Cell;
+
+use argparse::{Args, Callable};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{raise_stop_iteration, type_error, value_error},
+    generator::GEN_INSTANCE_TYPE,
+    init::token,
+    obj::{Obj, ObjBase, ObjTrait, ObjType},
+};
+use vexide_devices::{
+    display::{Display, RenderMode, TouchState},
+    math::Point2,
+};
+
+use super::{BACKGROUND, Bounds, Touch, containers::Dialog, fill, layout, parse_widget, widget};
+use crate::{
+    devices::lock_display,
+    modvenice::{
+        Exception,
+        units::time::TimeUnitObj,
+        vasyncio::{
+            event_loop, polled::PolledFuture, ticker::Schedule, time32, until::DEVICE_POLL_INTERVAL,
+        },
+    },
+};
+
+/// The size of an open `Dialog`.
+const DIALOG_SIZE: [i32; 2] = [360, 160];
+
+/// A tree of widgets shown on the display, available as `ui.Screen`.
+///
+/// The screen reads the touchscreen and redraws whatever has changed each time it is updated,
+/// either by calling `update` in a loop or by awaiting the future returned by `run` in a task of
+/// its own. Drawing is double buffered, so a screen should be the only thing drawing to the
+/// display while it is in use.
+///
+/// Widgets raise events when they are used. Each event calls the widget's callback, if it has
+/// one, and completes any `WidgetFuture` waiting on it.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// count = 0
+/// label = ui.Label("Pressed 0 times", centered=True)
+///
+/// def pressed(_):
+///     global count
+///     count += 1
+///     label.set_text("Pressed " + str(count) + " times")
+///
+/// screen = ui.Screen(ui.Column([label, ui.Button("Press me", on_press=pressed)], padding=8))
+/// vasyncio.run(screen.run())
+/// ```
+#[class(qstr!(Screen))]
+#[repr(C)]
+pub struct Screen {
+    base: ObjBase,
+    root: Obj,
+    dialog: Cell<Option<Obj>>,
+    // the widget receiving the current touch
+    target: Cell<Option<Obj>>,
+    touching: Cell<bool>,
+    full_redraw: Cell<bool>,
+}
+
+/// Returns the deepest widget in the tree under `obj` that responds to touches at `point`.
+fn hit_test(obj: Obj, point: Point2<i16>) -> Option<Obj> {
+    let widget = widget(&obj)?;
+    if !widget.core().bounds().contains(point) {
+        return None;
+    }
+    widget
+        .children()
+        .iter()
+        .find_map(|child| hit_test(*child, point))
+        .or_else(|| widget.is_interactive().then_some(obj))
+}
+
+/// Draws every widget in the tree under `obj` that needs it, returning whether any were drawn.
+///
+/// A widget that is drawn is drawn along with everything inside it, since clearing its bounds
+/// erases its children.
+fn draw_tree(obj: Obj, display: &mut Display, force: bool) -> bool {
+    let Some(widget) = widget(&obj) else {
+        return false;
+    };
+    let core = widget.core();
+    let redraw = core.dirty.replace(false) || force;
+    if redraw {
+        fill(display, core.bounds(), BACKGROUND);
+        widget.draw(display);
+    }
+
+    let mut drawn = redraw;
+    for child in widget.children() {
+        drawn |= draw_tree(*child, display, redraw);
+    }
+    drawn
+}
+
+impl Screen {
+    /// Sends a touch to the widget receiving it, returning the event it raised, if any.
+    fn send(&self, target: Obj, touch: Touch) -> Option<(Option<Callable>, Obj)> {
+        let widget = widget(&target)?;
+        let value = widget.touch(touch)?;
+        Some((widget.core().emit(value), value))
+    }
+
+    /// Handles the latest touch, returning the event it raised, if any.
+    fn handle_touch(&self, display: &Display) -> Option<(Option<Callable>, Obj)> {
+        let status = display.touch_status();
+        let pressed = matches!(status.state, TouchState::Pressed | TouchState::Held);
+
+        match (self.touching.replace(pressed), pressed) {
+            (false, true) => {
+                // While a dialog is open, nothing behind it can be touched
+                let top = self.dialog.get().unwrap_or(self.root);
+                let target = hit_test(top, status.point);
+                self.target.set(target);
+                self.send(target?, Touch::Press(status.point))
+            }
+            (true, true) => self.send(self.target.get()?, Touch::Drag(status.point)),
+            (true, false) => self.send(self.target.take()?, Touch::Release(status.point)),
+            (false, false) => None,
+        }
+    }
+
+    /// Reads the touchscreen, redraws what has changed and then calls the callback of any event
+    /// that was raised.
+    pub fn refresh(&self) {
+        let event = {
+            let mut display = lock_display();
+            let event = self.handle_touch(&display);
+
+            if let Some(dialog) = self.dialog.get()
+                && !dialog.as_obj::<Dialog>().is_open()
+            {
+                self.dialog.set(None);
+                self.full_redraw.set(true);
+            }
+
+            let drawn = if self.full_redraw.replace(false) {
+                display.set_render_mode(RenderMode::DoubleBuffered);
+                fill(&mut display, Bounds::SCREEN, BACKGROUND);
+                layout(self.root, Bounds::SCREEN);
+                draw_tree(self.root, &mut display, true);
+                if let Some(dialog) = self.dialog.get() {
+                    draw_tree(dialog, &mut display, true);
+                }
+                true
+            } else if let Some(dialog) = self.dialog.get() {
+                draw_tree(dialog, &mut display, false)
+            } else {
+                draw_tree(self.root, &mut display, false)
+            };
+            if drawn {
+                display.render();
+            }
+            event
+        };
+
+        // The display is unlocked first, since the callback may draw or update another screen
+        if let Some((Some(callback), value)) = event {
+            let result = callback.call(0, &[value]);
+            if result.is(GEN_INSTANCE_TYPE) {
+                event_loop::spawn_coro(result);
+            }
+        }
+    }
+}
+
+#[class_methods]
+impl Screen {
+    /// Creates a screen showing `root`, which fills the whole display.
+    ///
+    /// Nothing is drawn until the screen is first updated.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `root` isn't a widget, or is a `Dialog`.
+    #[make_new]
+    #[stub(sig = "(self, root: Widget, /) -> None")]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(1, 1).assert_nkw(0, 0);
+
+        Ok(Self {
+            base: ty.into(),
+            root: parse_widget(reader.next_positional::<Obj>()?)?,
+            dialog: Cell::new(None),
+            target: Cell::new(None),
+            touching: Cell::new(false),
+            full_redraw: Cell::new(true),
+        })
+    }
+
+    /// Reads the touchscreen and redraws whatever has changed since the last update.
+    ///
+    /// If a touch raised an event, its callback is called once drawing is done. Callbacks that
+    /// are `async` functions are spawned as tasks, so they need a running event loop.
+    ///
+    /// # Raises
+    ///
+    /// - `RuntimeError`: If an `async` callback is called outside of an event loop.
+    ///
+    /// Any exception raised by a callback propagates out of the update.
+    #[method]
+    fn update(&self) {
+        self.refresh();
+    }
+
+    /// Returns a `ScreenFuture` that updates the screen every `period`, measured in `unit`, until
+    /// it is cancelled.
+    ///
+    /// Await it from a task of its own, spawned with `vasyncio.spawn`.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+    #[method(ty = var_between(min = 1, max = 3))]
+    #[stub(
+        sig = "(self, period: float = 20.0, unit: TimeUnit = TimeUnit.MILLIS, /) -> ScreenFuture"
+    )]
+    fn run(args: &[Obj]) -> Result<ScreenFuture, Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+
+        let screen = reader.next_positional::<Obj>()?;
+        let period = reader.next_positional_or(20.0)?;
+        let unit = reader.next_positional_or(TimeUnitObj::MILLIS)?.unit();
+
+        let period = time32::Duration::from_duration(unit.float_to_dur(period)?);
+        Ok(ScreenFuture {
+            base: ScreenFuture::OBJ_TYPE.into(),
+            screen,
+            schedule: Schedule::new(period)?,
+        })
+    }
+
+    /// Shows `dialog` in the middle of the screen, on top of everything else.
+    ///
+    /// Until it is closed, touches only reach the dialog. A dialog that is already open is
+    /// closed first.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `dialog` isn't a `Dialog`.
+    #[method]
+    #[stub(sig = "(self, dialog: Dialog, /) -> None")]
+    fn open(&self, dialog_obj: Obj) -> Result<(), Exception> {
+        let Some(dialog) = dialog_obj.try_as_obj::<Dialog>() else {
+            Err(type_error(c"expected a Dialog"))?
+        };
+        if let Some(open) = self.dialog.replace(Some(dialog_obj)) {
+            open.as_obj::<Dialog>().hide();
+            // The old dialog may be larger than the new one
+            self.full_redraw.set(true);
+        }
+        // A touch that started behind the dialog no longer reaches anything
+        self.target.set(None);
+
+        layout(
+            dialog_obj,
+            Bounds::SCREEN.centered(DIALOG_SIZE[0], DIALOG_SIZE[1]),
+        );
+        dialog.show();
+        Ok(())
+    }
+
+    /// Closes the open dialog without raising an event, if there is one.
+    #[method]
+    fn close(&self) {
+        if let Some(dialog) = self.dialog.take() {
+            dialog.as_obj::<Dialog>().hide();
+            self.target.set(None);
+            self.full_redraw.set(true);
+        }
+    }
+
+    /// Lays out and draws every widget again at the next update.
+    ///
+    /// Call this after drawing to the display with anything other than the screen.
+    #[method]
+    fn redraw(&self) {
+        self.full_redraw.set(true);
+    }
+}
+
+/// An awaitable that updates a `Screen` on a fixed schedule until it is cancelled, returned by
+/// `Screen.run`.
+///
+/// The first update happens as soon as the future is awaited and the rest follow once per period.
+/// Like `vasyncio.Ticker`, updates are scheduled from the first one, and periods that are missed
+/// because other tasks ran too long are skipped.
+#[class(qstr!(ScreenFuture))]
+#[repr(C)]
+pub struct ScreenFuture {
+    base: ObjBase,
+    screen: Obj,
+    schedule: Schedule,
+}
+
+impl PolledFuture for ScreenFuture {
+    /// Returns the deadline of the next update, if the future has been awaited.
+    fn next_poll(&self) -> Option<time32::Instant> {
+        self.schedule.deadline()
+    }
+}
+
+#[class_methods]
+impl ScreenFuture {
+    #[iter]
+    extern "C" fn screen_iternext(self_in: Obj) -> Obj {
+        let future = self_in.as_obj::<Self>();
+
+        let due = match future.schedule.deadline() {
+            // draw the screen as soon as the future is awaited
+            None => {
+                future.schedule.poll();
+                true
+            }
+            Some(_) => future.schedule.poll().is_some(),
+        };
+        if due {
+            future.screen.as_obj::<Screen>().refresh();
+        }
+
+        future.pend(self_in)
+    }
+}
+
+/// An awaitable that completes with the value of a widget's next event, returned by the `wait`
+/// method of each widget.
+///
+/// Events are raised by `Screen.update`, so the widget's screen must be updated, for example by
+/// another task awaiting `Screen.run`, for the future to complete.
+#[class(qstr!(WidgetFuture))]
+#[repr(C)]
+pub struct WidgetFuture {
+    base: ObjBase,
+    widget: Obj,
+    // the number of events the widget had raised when the future was first polled
+    start: Cell<Option<u32>>,
+    next_poll: Cell<Option<time32::Instant>>,
+}
+
+impl WidgetFuture {
+    pub fn new(widget: Obj) -> Self {
+        Self {
+            base: Self::OBJ_TYPE.into(),
+            widget,
+            start: Cell::new(None),
+            next_poll: Cell::new(None),
+        }
+    }
+}
+
+impl PolledFuture for WidgetFuture {
+    /// Returns when the widget should next be checked for an event, if the future is waiting.
+    fn next_poll(&self) -> Option<time32::Instant> {
+        self.next_poll.get()
+    }
+}
+
+#[class_methods]
+impl WidgetFuture {
+    #[iter]
+    extern "C" fn widget_iternext(self_in: Obj) -> Obj {
+        let future = self_in.as_obj::<Self>();
+        let core = widget(&future.widget)
+            .expect("WidgetFuture is only created for widgets")
+            .core();
+
+        let start = match future.start.get() {
+            Some(start) => start,
+            None => {
+                future.start.set(Some(core.events()));
+                core.events()
+            }
+        };
+        if core.events() != start {
+            future.next_poll.set(None);
+            raise_stop_iteration(token(), core.value());
+        }
+
+        let next_poll = time32::Instant::now()
+            .checked_add(DEVICE_POLL_INTERVAL)
+            .unwrap_or_else(|| value_error(c"poll interval is too large").raise(token()));
+        future.next_poll.set(Some(next_poll));
+        future.pend(self_in)
+    }
+}
@@ -0,0 +1,933 @@
+use std::cell::{Cell, RefCell};
+
+use argparse::{Args, Callable, KeywordArg, error_msg};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{type_error, value_error},
+    init::token,
+    obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
+    qstr::Qstr,
+};
+use vexide_devices::{
+    color::Color,
+    display::{Circle, Display},
+    math::Point2,
+};
+
+use super::{
+    ACCENT, Align, Bounds, Core, MUTED, PRESSED, SURFACE, TEXT, Touch, Widget, draw_text, fill,
+    parse_text, screen::WidgetFuture, stroke,
+};
+use crate::{
+    alloc::Gc,
+    modvenice::{
+        Exception, color::ColorObj, read_only_attr::read_only_attr, validate::check_positive,
+    },
+};
+
+/// The height of each row of a `List`.
+const ROW_HEIGHT: i32 = 34;
+/// The radius of a `Slider`'s knob.
+const KNOB_RADIUS: i32 = 10;
+
+/// Parses a callback keyword argument, which may be `None`.
+pub(super) fn parse_callback(arg: KeywordArg) -> Result<Option<Callable>, Exception> {
+    if arg.obj.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(arg.parse::<Callable>()?))
+}
+
+/// A line of text, available as `ui.Label`.
+///
+/// The read-only `text` attribute is the text being shown. Text longer than the label's width
+/// isn't wrapped or cut off.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// status = ui.Label("Waiting for sensors", centered=True)
+/// screen = ui.Screen(status)
+/// screen.update()
+/// ```
+#[class(qstr!(Label))]
+#[repr(C)]
+pub struct Label {
+    base: ObjBase,
+    core: Core,
+    text: Cell<Obj>,
+    color: Color,
+    align: Align,
+}
+
+/// A button that raises an event when tapped, available as `ui.Button`.
+///
+/// A tap counts once the finger is lifted, and only if it is lifted over the button, so sliding
+/// off a button cancels it. The read-only `text` attribute is the button's text and
+/// `is_pressed` is whether it is being held down.
+#[class(qstr!(Button))]
+#[repr(C)]
+pub struct Button {
+    base: ObjBase,
+    core: Core,
+    text: Cell<Obj>,
+    pressed: Cell<bool>,
+}
+
+/// An on/off switch with a label, available as `ui.Toggle`.
+///
+/// Tapping the toggle flips its value and raises an event with the new value. The read-only
+/// `text` attribute is the toggle's label and `value` is whether it is on.
+#[class(qstr!(Toggle))]
+#[repr(C)]
+pub struct Toggle {
+    base: ObjBase,
+    core: Core,
+    text: Cell<Obj>,
+    value: Cell<bool>,
+    pressed: Cell<bool>,
+}
+
+/// A horizontal slider for picking a number in a range, available as `ui.Slider`.
+///
+/// The knob follows the finger while it is dragged, and an event with the new value is raised
+/// when the finger is lifted. The read-only `value`, `min` and `max` attributes are the current
+/// value and the ends of the range.
+#[class(qstr!(Slider))]
+#[repr(C)]
+pub struct Slider {
+    base: ObjBase,
+    core: Core,
+    min: f32,
+    max: f32,
+    step: f32,
+    value: Cell<f32>,
+    dragging: Cell<bool>,
+}
+
+/// A scrolling list of text items, one of which can be selected, available as `ui.List`.
+///
+/// Tapping an item selects it and raises an event with its index. Dragging up or down scrolls
+/// through items that don't fit. The read-only `selected` attribute is the index of the selected
+/// item, or `None`.
+///
+/// # Examples
+///
+/// Pick an autonomous routine before the match:
+///
+/// ```python
+/// from venice import *
+///
+/// routines = ["Left side", "Right side", "Skills"]
+/// picker = ui.List(routines, selected=0)
+/// screen = ui.Screen(picker)
+///
+/// async def main():
+///     vasyncio.spawn(screen.run())
+///     while True:
+///         index = await picker.wait()
+///         print("selected", routines[index])
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(List))]
+#[repr(C)]
+pub struct ListView {
+    base: ObjBase,
+    core: Core,
+    items: RefCell<Vec<Obj, Gc>>,
+    selected: Cell<Option<usize>>,
+    // the index of the first visible item
+    first: Cell<usize>,
+    // where the current touch started, and the first visible item at the time
+    press: Cell<Option<(i16, usize)>>,
+    pressed_item: Cell<Option<usize>>,
+}
+
+/// Returns the fill color of a control, depending on whether it is being pressed.
+fn surface(pressed: bool) -> Color {
+    if pressed { PRESSED } else { SURFACE }
+}
+
+impl Label {
+    fn set(&self, text: Obj) {
+        self.text.set(text);
+        self.core.invalidate();
+    }
+}
+
+impl Widget for Label {
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn draw(&self, display: &mut Display) {
+        draw_text(
+            display,
+            self.text.get(),
+            self.core.bounds(),
+            self.align,
+            self.color,
+        );
+    }
+}
+
+#[class_methods]
+impl Label {
+    /// Creates a label showing `text` in `color`.
+    ///
+    /// Text is left-aligned unless `centered` is `True`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `text` isn't a string or `color` isn't a `Color`.
+    /// - `ValueError`: If `text` contains a NUL character.
+    #[make_new]
+    #[stub(
+        sig = "(self, text: str, /, *, color: Color = Color.WHITE, centered: bool = False) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(1, 1).assert_nkw(0, 2);
+
+        let text = parse_text(reader.next_positional::<Obj>()?)?;
+        let mut color = TEXT;
+        let mut align = Align::Left;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "color" => color = arg.parse::<&ColorObj>()?.color(),
+                "centered" => {
+                    if arg.parse::<bool>()? {
+                        align = Align::Center;
+                    }
+                }
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        Ok(Self {
+            base: ty.into(),
+            core: Core::new(None),
+            text: Cell::new(text),
+            color,
+            align,
+        })
+    }
+
+    #[attr]
+    #[stub(attrs = ["text: str"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+        result.return_value(match attr.as_str() {
+            "text" => self.text.get(),
+            _ => return,
+        });
+    }
+
+    /// Changes the label's text.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `text` isn't a string.
+    /// - `ValueError`: If `text` contains a NUL character.
+    #[method]
+    #[stub(sig = "(self, text: str, /) -> None")]
+    fn set_text(&self, text: Obj) -> Result<(), Exception> {
+        self.set(parse_text(text)?);
+        Ok(())
+    }
+}
+
+impl Widget for Button {
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn draw(&self, display: &mut Display) {
+        let bounds = self.core.bounds().inset(2);
+        fill(display, bounds, surface(self.pressed.get()));
+        stroke(display, bounds, ACCENT);
+        draw_text(display, self.text.get(), bounds, Align::Center, TEXT);
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+
+    fn touch(&self, touch: Touch) -> Option<Obj> {
+        let (point, released) = match touch {
+            Touch::Press(point) | Touch::Drag(point) => (point, false),
+            Touch::Release(point) => (point, true),
+        };
+        let over = self.core.bounds().contains(point);
+
+        if self.pressed.replace(over && !released) != (over && !released) {
+            self.core.invalidate();
+        }
+        (released && over).then_some(Obj::NONE)
+    }
+}
+
+#[class_methods]
+impl Button {
+    /// Creates a button showing `text`.
+    ///
+    /// `on_press` is called with `None` each time the button is tapped. If it is an `async`
+    /// function, the coroutine it returns is spawned as a task.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `text` isn't a string or `on_press` isn't callable.
+    /// - `ValueError`: If `text` contains a NUL character.
+    #[make_new]
+    #[stub(sig = "(self, text: str, /, *, on_press: Callable[[None], Any] | None = None) -> None")]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(1, 1).assert_nkw(0, 1);
+
+        let text = parse_text(reader.next_positional::<Obj>()?)?;
+        let mut callback = None;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "on_press" => callback = parse_callback(arg)?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        Ok(Self {
+            base: ty.into(),
+            core: Core::new(callback),
+            text: Cell::new(text),
+            pressed: Cell::new(false),
+        })
+    }
+
+    #[attr]
+    #[stub(attrs = ["text: str", "is_pressed: bool"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+        result.return_value(match attr.as_str() {
+            "text" => self.text.get(),
+            "is_pressed" => Obj::from(self.pressed.get()),
+            _ => return,
+        });
+    }
+
+    /// Changes the button's text.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `text` isn't a string.
+    /// - `ValueError`: If `text` contains a NUL character.
+    #[method]
+    #[stub(sig = "(self, text: str, /) -> None")]
+    fn set_text(&self, text: Obj) -> Result<(), Exception> {
+        self.text.set(parse_text(text)?);
+        self.core.invalidate();
+        Ok(())
+    }
+
+    /// Returns a `WidgetFuture` that completes with `None` the next time the button is tapped.
+    #[method]
+    #[stub(sig = "(self, /) -> WidgetFuture")]
+    fn wait(self_in: Obj) -> WidgetFuture {
+        WidgetFuture::new(self_in)
+    }
+}
+
+impl Widget for Toggle {
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn draw(&self, display: &mut Display) {
+        let bounds = self.core.bounds();
+        draw_text(display, self.text.get(), bounds, Align::Left, TEXT);
+
+        let track = Bounds {
+            x: bounds.x + bounds.width - 56,
+            width: 48,
+            ..bounds.centered(48, 24)
+        };
+        let on = self.value.get();
+        let track_color = if on {
+            ACCENT
+        } else {
+            surface(self.pressed.get())
+        };
+        fill(display, track, track_color);
+        let knob = Bounds {
+            x: if on { track.x + 26 } else { track.x + 2 },
+            ..track.centered(20, 20)
+        };
+        fill(display, knob, TEXT);
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+
+    fn touch(&self, touch: Touch) -> Option<Obj> {
+        let (point, released) = match touch {
+            Touch::Press(point) | Touch::Drag(point) => (point, false),
+            Touch::Release(point) => (point, true),
+        };
+        let over = self.core.bounds().contains(point);
+
+        if self.pressed.replace(over && !released) != (over && !released) {
+            self.core.invalidate();
+        }
+        if !(released && over) {
+            return None;
+        }
+
+        let value = !self.value.get();
+        self.value.set(value);
+        Some(Obj::from(value))
+    }
+}
+
+#[class_methods]
+impl Toggle {
+    /// Creates a toggle labeled `text`, which starts on if `value` is `True`.
+    ///
+    /// `on_change` is called with the new value each time the toggle is tapped. If it is an
+    /// `async` function, the coroutine it returns is spawned as a task.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `text` isn't a string or `on_change` isn't callable.
+    /// - `ValueError`: If `text` contains a NUL character.
+    #[make_new]
+    #[stub(
+        sig = "(self, text: str, /, *, value: bool = False, on_change: Callable[[bool], Any] | None = None) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(1, 1).assert_nkw(0, 2);
+
+        let text = parse_text(reader.next_positional::<Obj>()?)?;
+        let mut value = false;
+        let mut callback = None;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "value" => value = arg.parse()?,
+                "on_change" => callback = parse_callback(arg)?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        Ok(Self {
+            base: ty.into(),
+            core: Core::new(callback),
+            text: Cell::new(text),
+            value: Cell::new(value),
+            pressed: Cell::new(false),
+        })
+    }
+
+    #[attr]
+    #[stub(attrs = ["text: str", "value: bool"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+        result.return_value(match attr.as_str() {
+            "text" => self.text.get(),
+            "value" => Obj::from(self.value.get()),
+            _ => return,
+        });
+    }
+
+    /// Turns the toggle on or off without raising an event.
+    #[method]
+    fn set_value(&self, value: bool) {
+        if self.value.replace(value) != value {
+            self.core.invalidate();
+        }
+    }
+
+    /// Returns a `WidgetFuture` that completes with the toggle's new value the next time it is
+    /// tapped.
+    #[method]
+    #[stub(sig = "(self, /) -> WidgetFuture")]
+    fn wait(self_in: Obj) -> WidgetFuture {
+        WidgetFuture::new(self_in)
+    }
+}
+
+impl Slider {
+    /// Clamps `value` to the slider's range and rounds it to a whole number of steps.
+    fn snap(&self, value: f32) -> f32 {
+        let value = if self.step > 0.0 {
+            self.min + ((value - self.min) / self.step).round() * self.step
+        } else {
+            value
+        };
+        value.clamp(self.min, self.max)
+    }
+
+    /// Returns the part of the slider's bounds that the knob's center moves along.
+    fn track(&self) -> Bounds {
+        let bounds = self.core.bounds();
+        Bounds {
+            x: bounds.x + KNOB_RADIUS + 2,
+            width: (bounds.width - (KNOB_RADIUS + 2) * 2).max(1),
+            ..bounds
+        }
+    }
+
+    fn set(&self, value: f32) {
+        if self.value.replace(value) != value {
+            self.core.invalidate();
+        }
+    }
+}
+
+impl Widget for Slider {
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn draw(&self, display: &mut Display) {
+        let track = self.track();
+        let fraction = (self.value.get() - self.min) / (self.max - self.min);
+        let knob_x = track.x + (fraction * track.width as f32).round() as i32;
+
+        let bar = track.centered(track.width, 6);
+        let (filled, rest) = (
+            Bounds {
+                width: knob_x - bar.x,
+                ..bar
+            },
+            Bounds {
+                x: knob_x,
+                width: bar.x + bar.width - knob_x,
+                ..bar
+            },
+        );
+        fill(display, filled, ACCENT);
+        fill(display, rest, SURFACE);
+
+        let center = Point2 {
+            x: knob_x as i16,
+            y: (track.y + track.height / 2) as i16,
+        };
+        let knob = Circle::new(center, KNOB_RADIUS as u16);
+        display.fill(&knob, if self.dragging.get() { MUTED } else { TEXT });
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+
+    fn touch(&self, touch: Touch) -> Option<Obj> {
+        match touch {
+            Touch::Press(point) | Touch::Drag(point) => {
+                let track = self.track();
+                let fraction = (i32::from(point.x) - track.x) as f32 / track.width as f32;
+                self.set(self.snap(self.min + fraction * (self.max - self.min)));
+                if !self.dragging.replace(true) {
+                    self.core.invalidate();
+                }
+                None
+            }
+            Touch::Release(_) => {
+                self.dragging.set(false);
+                self.core.invalidate();
+                Some(Obj::from(self.value.get()))
+            }
+        }
+    }
+}
+
+#[class_methods]
+impl Slider {
+    /// Creates a slider from `min` to `max`, starting at `value`, or at `min` if `value` is
+    /// `None`.
+    ///
+    /// If `step` is given, the value is rounded to the nearest whole number of steps from `min`.
+    /// `on_change` is called with the new value each time the knob is let go. If it is an `async`
+    /// function, the coroutine it returns is spawned as a task.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If a number isn't numeric or `on_change` isn't callable.
+    /// - `ValueError`: If `min` or `max` isn't finite, `min` isn't less than `max`, `step` isn't
+    ///   positive and finite, or `value` is outside the range.
+    #[make_new]
+    #[stub(
+        sig = "(self, min: float = 0.0, max: float = 1.0, /, *, value: float | None = None, step: float | None = None, on_change: Callable[[float], Any] | None = None) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(0, 2).assert_nkw(0, 3);
+
+        let min = reader.next_positional_or(0.0f32)?;
+        let max = reader.next_positional_or(1.0f32)?;
+        if !min.is_finite() || !max.is_finite() || min >= max {
+            Err(value_error(
+                c"min must be less than max, and both must be finite",
+            ))?
+        }
+
+        let mut value = min;
+        let mut step = 0.0;
+        let mut callback = None;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "value" => {
+                    if !arg.obj.is_none() {
+                        value = arg.parse::<f32>()?;
+                        if !(min..=max).contains(&value) {
+                            Err(value_error(c"value must be between min and max"))?
+                        }
+                    }
+                }
+                "step" => {
+                    if !arg.obj.is_none() {
+                        step = check_positive(arg.parse::<f32>()?, arg.kw)?;
+                    }
+                }
+                "on_change" => callback = parse_callback(arg)?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        let slider = Self {
+            base: ty.into(),
+            core: Core::new(callback),
+            min,
+            max,
+            step,
+            value: Cell::new(value),
+            dragging: Cell::new(false),
+        };
+        slider.value.set(slider.snap(value));
+        Ok(slider)
+    }
+
+    #[attr]
+    #[stub(attrs = ["value: float", "min: float", "max: float"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+        result.return_value(match attr.as_str() {
+            "value" => Obj::from(self.value.get()),
+            "min" => Obj::from(self.min),
+            "max" => Obj::from(self.max),
+            _ => return,
+        });
+    }
+
+    /// Moves the knob to `value`, clamped to the range and rounded to a whole number of steps,
+    /// without raising an event.
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `value` is NaN.
+    #[method]
+    fn set_value(&self, value: f32) -> Result<(), Exception> {
+        if value.is_nan() {
+            Err(value_error(c"value cannot be NaN"))?
+        }
+        self.set(self.snap(value));
+        Ok(())
+    }
+
+    /// Returns a `WidgetFuture` that completes with the slider's new value the next time the knob
+    /// is let go.
+    #[method]
+    #[stub(sig = "(self, /) -> WidgetFuture")]
+    fn wait(self_in: Obj) -> WidgetFuture {
+        WidgetFuture::new(self_in)
+    }
+}
+
+fn parse_items(obj: Obj) -> Result<Vec<Obj, Gc>, Exception> {
+    let items = obj
+        .try_array()
+        .ok_or_else(|| type_error(c"expected a list or tuple of strings"))?;
+
+    // The GC allocator can't grow a buffer, so it's allocated at its full size
+    let mut parsed = Vec::with_capacity_in(items.len(), Gc { token: token() });
+    for item in items {
+        parsed.push(parse_text(item)?);
+    }
+    Ok(parsed)
+}
+
+impl ListView {
+    /// Returns how many rows fit in the list's bounds.
+    fn visible_rows(&self) -> usize {
+        (self.core.bounds().height / ROW_HEIGHT).max(0) as usize
+    }
+
+    /// Returns the largest index of the first visible item that doesn't leave empty rows.
+    fn max_first(&self) -> usize {
+        self.items
+            .borrow()
+            .len()
+            .saturating_sub(self.visible_rows())
+    }
+
+    /// Returns the index of the item shown at `point`, if there is one.
+    fn item_at(&self, point: Point2<i16>) -> Option<usize> {
+        let bounds = self.core.bounds();
+        if !bounds.contains(point) {
+            return None;
+        }
+        let row = ((i32::from(point.y) - bounds.y) / ROW_HEIGHT) as usize;
+        let index = self.first.get() + row;
+        (row < self.visible_rows() && index < self.items.borrow().len()).then_some(index)
+    }
+
+    /// Scrolls so that the item at `index` is visible.
+    fn reveal(&self, index: usize) {
+        let first = self.first.get();
+        let rows = self.visible_rows().max(1);
+        if index < first {
+            self.first.set(index);
+        } else if index >= first + rows {
+            self.first.set(index + 1 - rows);
+        }
+    }
+
+    fn check_index(&self, index: Obj) -> Result<Option<usize>, Exception> {
+        if index.is_none() {
+            return Ok(None);
+        }
+        let index = index
+            .try_to_int()
+            .ok_or_else(|| type_error(c"expected an int or None"))?;
+        match usize::try_from(index) {
+            Ok(index) if index < self.items.borrow().len() => Ok(Some(index)),
+            _ => Err(value_error(c"index is out of range"))?,
+        }
+    }
+}
+
+impl Widget for ListView {
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn draw(&self, display: &mut Display) {
+        let bounds = self.core.bounds();
+        let items = self.items.borrow();
+        let first = self.first.get();
+        let rows = self.visible_rows();
+
+        for (row, index) in (first..items.len()).take(rows).enumerate() {
+            let row_bounds = Bounds {
+                y: bounds.y + row as i32 * ROW_HEIGHT,
+                height: ROW_HEIGHT - 1,
+                ..bounds
+            };
+            if self.selected.get() == Some(index) {
+                fill(display, row_bounds, ACCENT);
+            } else {
+                fill(
+                    display,
+                    row_bounds,
+                    surface(self.pressed_item.get() == Some(index)),
+                );
+            }
+            draw_text(display, items[index], row_bounds, Align::Left, TEXT);
+        }
+
+        // A scroll bar shows where the visible rows are when not everything fits
+        if items.len() > rows && rows > 0 {
+            let height = (bounds.height * rows as i32 / items.len() as i32).max(8);
+            let scroll_bar = Bounds {
+                x: bounds.x + bounds.width - 4,
+                y: bounds.y + (bounds.height - height) * first as i32 / self.max_first() as i32,
+                width: 3,
+                height,
+            };
+            fill(display, scroll_bar, MUTED);
+        }
+    }
+
+    fn arrange(&self) {
+        // The new size may fit more rows, or need scrolling to keep the selection visible
+        self.first.set(self.first.get().min(self.max_first()));
+        if let Some(index) = self.selected.get() {
+            self.reveal(index);
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+
+    fn touch(&self, touch: Touch) -> Option<Obj> {
+        match touch {
+            Touch::Press(point) => {
+                self.press.set(Some((point.y, self.first.get())));
+                self.pressed_item.set(self.item_at(point));
+                self.core.invalidate();
+                None
+            }
+            Touch::Drag(point) => {
+                let (start_y, start_first) = self.press.get()?;
+                let distance = i32::from(point.y) - i32::from(start_y);
+                if distance.abs() < ROW_HEIGHT / 2 {
+                    return None;
+                }
+
+                // Dragging scrolls instead of selecting, a row at a time
+                let rows = (distance - distance.signum() * ROW_HEIGHT / 2) / ROW_HEIGHT;
+                let first = (start_first as i32 - rows).clamp(0, self.max_first() as i32);
+                self.first.set(first as usize);
+                self.pressed_item.set(None);
+                self.core.invalidate();
+                None
+            }
+            Touch::Release(point) => {
+                self.press.set(None);
+                let pressed = self.pressed_item.take();
+                self.core.invalidate();
+
+                let index = pressed.filter(|&index| self.item_at(point) == Some(index))?;
+                self.selected.set(Some(index));
+                Some(Obj::from(index as i32))
+            }
+        }
+    }
+}
+
+#[class_methods]
+impl ListView {
+    /// Creates a list showing `items`, with the item at index `selected` selected.
+    ///
+    /// `on_change` is called with an item's index each time it is tapped. If it is an `async`
+    /// function, the coroutine it returns is spawned as a task.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `items` isn't a list or tuple of strings, `selected` isn't an int or
+    ///   `None`, or `on_change` isn't callable.
+    /// - `ValueError`: If an item contains a NUL character or `selected` is out of range.
+    #[make_new]
+    #[stub(
+        sig = "(self, items: list[str] | tuple[str, ...], /, *, selected: int | None = None, on_change: Callable[[int], Any] | None = None) -> None"
+    )]
+    fn make_new(
+        ty: &'static ObjType,
+        n_pos: usize,
+        n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(1, 1).assert_nkw(0, 2);
+
+        let items = parse_items(reader.next_positional::<Obj>()?)?;
+        let mut selected = Obj::NONE;
+        let mut callback = None;
+        while let Some(arg) = reader.next_kw() {
+            match arg.kw {
+                "selected" => selected = *arg.obj,
+                "on_change" => callback = parse_callback(arg)?,
+                _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+            }
+        }
+
+        let list = Self {
+            base: ty.into(),
+            core: Core::new(callback),
+            items: RefCell::new(items),
+            selected: Cell::new(None),
+            first: Cell::new(0),
+            press: Cell::new(None),
+            pressed_item: Cell::new(None),
+        };
+        list.selected.set(list.check_index(selected)?);
+        Ok(list)
+    }
+
+    #[attr]
+    #[stub(attrs = ["selected: int | None"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let AttrOp::Load { result } = op else {
+            read_only_attr::<Self>()
+        };
+        result.return_value(match attr.as_str() {
+            "selected" => match self.selected.get() {
+                Some(index) => Obj::from(index as i32),
+                None => Obj::NONE,
+            },
+            _ => return,
+        });
+    }
+
+    /// Replaces the list's items, clearing the selection and scrolling back to the top.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `items` isn't a list or tuple of strings.
+    /// - `ValueError`: If an item contains a NUL character.
+    #[method]
+    #[stub(sig = "(self, items: list[str] | tuple[str, ...], /) -> None")]
+    fn set_items(&self, items: Obj) -> Result<(), Exception> {
+        *self.items.borrow_mut() = parse_items(items)?;
+        self.selected.set(None);
+        self.first.set(0);
+        self.pressed_item.set(None);
+        self.core.invalidate();
+        Ok(())
+    }
+
+    /// Selects the item at `index`, scrolling to it, or clears the selection if `index` is
+    /// `None`, without raising an event.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `index` isn't an int or `None`.
+    /// - `ValueError`: If `index` is out of range.
+    #[method]
+    #[stub(sig = "(self, index: int | None, /) -> None")]
+    fn select(&self, index: Obj) -> Result<(), Exception> {
+        let index = self.check_index(index)?;
+        self.selected.set(index);
+        if let Some(index) = index {
+            self.reveal(index);
+        }
+        self.core.invalidate();
+        Ok(())
+    }
+
+    /// Returns a `WidgetFuture` that completes with an item's index the next time one is tapped.
+    #[method]
+    #[stub(sig = "(self, /) -> WidgetFuture")]
+    fn wait(self_in: Obj) -> WidgetFuture {
+        WidgetFuture::new(self_in)
+    }
+}
@@ -0,0 +1,35 @@
+use argparse::error_msg;
+use micropython_rs::except::value_error;
+
+use crate::modvenice::Exception;
+
+/// Returns `value`, or a `ValueError` naming the argument `name` if it isn't finite.
+pub fn check_finite(value: f32, name: &str) -> Result<f32, Exception> {
+    if !value.is_finite() {
+        Err(value_error(error_msg!("{} must be finite", name)))?
+    }
+    Ok(value)
+}
+
+/// Returns `value`, or a `ValueError` naming the argument `name` if it isn't positive and finite.
+pub fn check_positive(value: f32, name: &str) -> Result<f32, Exception> {
+    if value <= 0.0 || !value.is_finite() {
+        Err(value_error(error_msg!(
+            "{} must be positive and finite",
+            name
+        )))?
+    }
+    Ok(value)
+}
+
+/// Returns `value`, or a `ValueError` naming the argument `name` if it is negative or isn't
+/// finite.
+pub fn check_non_negative(value: f32, name: &str) -> Result<f32, Exception> {
+    if value < 0.0 || !value.is_finite() {
+        Err(value_error(error_msg!(
+            "{} must be non-negative and finite",
+            name
+        )))?
+    }
+    Ok(value)
+}
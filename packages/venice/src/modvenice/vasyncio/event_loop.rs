@@ -1,26 +1,52 @@
 use std::{
     cell::{Cell, RefCell},
+    cmp::Reverse,
     collections::{binary_heap::BinaryHeap, vec_deque::VecDeque},
 };
 
+use argparse::{ArgParser, Args, Callable, CallableParser, IntParser, error_msg};
 use micropython_macros::{class, class_methods, fun};
 use micropython_rs::{
-    except::{RUNTIME_ERROR_TYPE, raise_msg, runtime_error, type_error, value_error},
+    except::{
+        RUNTIME_ERROR_TYPE, exception_matches, new_exception, new_exception_type, print_exception,
+        raise_msg, runtime_error, type_error, value_error,
+    },
     fun::{Fun1, Fun2},
     generator::{GEN_INSTANCE_TYPE, VmReturnKind, resume_gen},
     init::token,
+    list::new_list,
+    map::Map,
     nlr::{self, push_nlr_callback},
-    obj::{Obj, ObjBase, ObjTrait, ObjType},
+    obj::{Obj, ObjBase, ObjFullType, ObjTrait, ObjType},
 };
 use vex_sdk::vexTasksRun;
 
-use super::{sleep::Sleep, task::Task, time32};
-use crate::{alloc::Gc, modvenice::Exception, obj::alloc_obj};
+use super::{
+    polled,
+    sleep::Sleep,
+    sync::Lock,
+    task::{CANCELLED_ERROR_TYPE, Task},
+    time32,
+    wait::{GatherFuture, Timeout, WaitForFuture, WaitFuture},
+};
+use crate::{
+    alloc::Gc,
+    modvenice::{
+        Exception,
+        units::time::{TimeUnit, TimeUnitObj},
+    },
+    obj::alloc_obj,
+};
 
+/// Passed to the exception handler when a single resume of a task overruns the budget set with
+/// `EventLoop.set_watchdog`.
+pub static WATCHDOG_ERROR_TYPE: ObjFullType =
+    new_exception_type(qstr!(WatchdogError), RUNTIME_ERROR_TYPE);
+
+// A deadline for whatever the task is parked on, which is recorded in `Task::waiting_on`.
 struct Sleeper {
     task: Obj,
     deadline: time32::Instant,
-    sleep: Obj,
 }
 
 impl PartialEq for Sleeper {
@@ -68,6 +94,17 @@ pub struct EventLoop {
     base: ObjBase,
     ready: RefCell<VecDeque<Obj, Gc>>,
     sleepers: RefCell<BinaryHeap<Sleeper, Gc>>,
+    // task currently being resumed by `tick_task`, or null
+    current: Cell<Obj>,
+    // every task spawned onto this loop that has not finished yet
+    tasks: RefCell<Vec<Obj, Gc>>,
+    next_task_id: Cell<u32>,
+    // longest a single resume may run before the watchdog reports it
+    watchdog: Cell<Option<time32::Duration>>,
+    // called with `(task, exc)` when a task fails and nothing observes it
+    exception_handler: Cell<Option<Callable>>,
+    // `(device, lock)` pairs handed out by `vasyncio.reserve`
+    reservations: RefCell<Vec<(Obj, Obj), Gc>>,
 }
 
 thread_local! {
@@ -81,15 +118,233 @@ impl EventLoop {
             base: Self::OBJ_TYPE.into(),
             ready: RefCell::new(VecDeque::new_in(gc)),
             sleepers: RefCell::new(BinaryHeap::new_in(gc)),
+            current: Cell::new(Obj::NULL),
+            tasks: RefCell::new(Vec::new_in(gc)),
+            next_task_id: Cell::new(0),
+            watchdog: Cell::new(None),
+            exception_handler: Cell::new(None),
+            reservations: RefCell::new(Vec::new_in(gc)),
         }
     }
 
-    pub fn spawn(&self, coro: Obj) -> Obj {
-        let task = alloc_obj(Task::new(coro));
-        self.ready.borrow_mut().push_back(task);
+    /// Spawns `coro` as a new task named `name`, or with a default name if `name` is null.
+    pub fn spawn(self_in: Obj, coro: Obj, name: Obj) -> Obj {
+        let eloop = self_in.as_obj::<EventLoop>();
+        let id = eloop.next_task_id.get();
+        eloop.next_task_id.set(id.wrapping_add(1));
+
+        let task = alloc_obj(Task::new(coro, self_in, id, name));
+        eloop.tasks.borrow_mut().push(task);
+        eloop.ready.borrow_mut().push_back(task);
         task
     }
 
+    /// Returns the lock that reserves `device` on this loop, creating it on first use.
+    fn reservation(&self, device: Obj) -> Obj {
+        let mut reservations = self.reservations.borrow_mut();
+        if let Some(&(_, lock)) = reservations
+            .iter()
+            .find(|(reserved, _)| reserved.inner() == device.inner())
+        {
+            return lock;
+        }
+
+        let lock = alloc_obj(Lock::new());
+        reservations.push((device, lock));
+        lock
+    }
+
+    /// Returns a list of every task on this loop that has not finished yet.
+    pub fn live_tasks(&self) -> Obj {
+        new_list(&self.tasks.borrow())
+    }
+
+    fn retire(&self, task_obj: Obj) {
+        self.tasks
+            .borrow_mut()
+            .retain(|task| task.inner() != task_obj.inner());
+    }
+
+    /// Passes the failure of a task nothing is awaiting to the exception handler, or raises it out
+    /// of the loop if there is no handler.
+    fn report_unobserved(&self, task_obj: Obj, exc: Obj) {
+        match self.exception_handler.get() {
+            Some(handler) => {
+                handler.call(0, &[task_obj, exc]);
+            }
+            None => nlr::raise(token(), exc),
+        }
+    }
+
+    /// Passes the failure of a supervised task that is about to restart to the exception handler,
+    /// or prints it if there is no handler.
+    fn report_restart(&self, task_obj: Obj, exc: Obj) {
+        match self.exception_handler.get() {
+            Some(handler) => {
+                handler.call(0, &[task_obj, exc]);
+            }
+            None => {
+                let name = task_obj.as_obj::<Task>().name();
+                eprintln!(
+                    "vasyncio: restarting task '{}' after an exception:",
+                    name.get_str().unwrap_or_default(),
+                );
+                print_exception(token(), exc);
+            }
+        }
+    }
+
+    /// Reports a resume that overran the watchdog budget as a `WatchdogError`, which is passed to
+    /// the exception handler, or printed as a warning if there is no handler.
+    fn check_watchdog(&self, task_obj: Obj, run_time: time32::Duration) {
+        let Some(budget) = self.watchdog.get() else {
+            return;
+        };
+        if run_time <= budget {
+            return;
+        }
+
+        let name = task_obj.as_obj::<Task>().name();
+        let unit = TimeUnit::Millis;
+        let exc = Exception::new(
+            WATCHDOG_ERROR_TYPE.as_obj_type(),
+            error_msg!(
+                "task '{}' ran for {:.1} ms without yielding (budget is {:.1} ms)",
+                name.get_str().unwrap_or_default(),
+                unit.dur_to_float(run_time.to_duration()),
+                unit.dur_to_float(budget.to_duration()),
+            ),
+        );
+
+        // an overrun is a warning, so it never stops the loop
+        match self.exception_handler.get() {
+            Some(handler) => {
+                handler.call(0, &[task_obj, exc.0.to_obj(token())]);
+            }
+            None => eprintln!("vasyncio: {}", exc.0.msg.as_ref().to_string_lossy()),
+        }
+    }
+
+    /// Schedules a `CancelledError` to be thrown into a task's coroutine.
+    ///
+    /// The error is delivered by `tick_task` the next time the task is resumed, so a suspended task
+    /// is detached from whatever it is parked on and moved to the front of the ready queue. A task
+    /// that is currently running is left alone; it receives the error as soon as it yields.
+    pub fn cancel(&self, task_obj: Obj) {
+        let task = task_obj.as_obj::<Task>();
+        task.request_cancel();
+
+        if self.current.get().inner() == task_obj.inner() {
+            return;
+        }
+
+        self.unpark(task_obj);
+
+        let mut ready = self.ready.borrow_mut();
+        ready.retain(|ready_task| ready_task.inner() != task_obj.inner());
+        ready.push_front(task_obj);
+    }
+
+    /// Parks a task on `awaitable` until it is woken by the awaitable or `deadline` passes.
+    fn park(&self, task_obj: Obj, awaitable: Obj, deadline: Option<time32::Instant>) {
+        task_obj.as_obj::<Task>().set_waiting_on(awaitable);
+        if let Some(deadline) = deadline {
+            self.sleepers.borrow_mut().push(Sleeper {
+                task: task_obj,
+                deadline,
+            });
+        }
+    }
+
+    /// Removes a parked task from every wait list and the sleeper heap.
+    ///
+    /// A task may be registered in several places at once, for example in the wait lists of all
+    /// tasks passed to `vasyncio.wait` as well as in the sleeper heap for its timeout. Whichever
+    /// fires first must withdraw the others so the task isn't scheduled twice.
+    fn unpark(&self, task_obj: Obj) {
+        let task = task_obj.as_obj::<Task>();
+        let awaitable = task.waiting_on();
+        if awaitable.is_null() {
+            return;
+        }
+        task.clear_waiting_on();
+
+        if let Some(awaited_task) = awaitable.try_as_obj::<Task>() {
+            awaited_task.remove_waiting_task(task_obj);
+            return;
+        }
+
+        if let Some(wait) = awaitable.try_as_obj::<WaitFuture>() {
+            for awaited_task in wait.tasks() {
+                awaited_task.as_obj::<Task>().remove_waiting_task(task_obj);
+            }
+        } else if let Some(gather) = awaitable.try_as_obj::<GatherFuture>() {
+            for awaited_task in gather.tasks() {
+                awaited_task.as_obj::<Task>().remove_waiting_task(task_obj);
+            }
+        } else if let Some(wait_for) = awaitable.try_as_obj::<WaitForFuture>() {
+            wait_for
+                .task()
+                .as_obj::<Task>()
+                .remove_waiting_task(task_obj);
+        }
+
+        self.sleepers
+            .borrow_mut()
+            .retain(|sleeper| sleeper.task.inner() != task_obj.inner());
+    }
+
+    /// Reschedules a task parked on `awaitable`, such as a `SyncFuture` that was just granted.
+    ///
+    /// Does nothing if the task has since moved on to something else.
+    pub fn wake(&self, task_obj: Obj, awaitable: Obj) {
+        if task_obj.as_obj::<Task>().waiting_on().inner() != awaitable.inner() {
+            return;
+        }
+
+        self.unpark(task_obj);
+        self.ready.borrow_mut().push_back(task_obj);
+    }
+
+    /// Removes the ready task with the highest effective priority from the ready queue.
+    ///
+    /// A task's effective priority is its priority plus one for every `AGING_INTERVAL` times it
+    /// was passed over in favor of another task, so lower-priority tasks still get to run while
+    /// higher-priority tasks stay busy. Ties go to the task closest to the front of the queue.
+    fn pop_ready(&self) -> Option<Obj> {
+        let mut ready = self.ready.borrow_mut();
+        let (index, _) = ready
+            .iter()
+            .enumerate()
+            .map(|(index, task)| (index, task.as_obj::<Task>().effective_priority()))
+            .max_by_key(|&(index, priority)| (priority, Reverse(index)))?;
+
+        let task_obj = ready.remove(index)?;
+        task_obj.as_obj::<Task>().clear_passed_over();
+        for passed_over in ready.iter() {
+            passed_over.as_obj::<Task>().pass_over();
+        }
+        Some(task_obj)
+    }
+
+    fn wake_waiting_tasks(&self, task: &Task) {
+        while let Some(waiting) = task.pop_waiting_task() {
+            self.unpark(waiting);
+            self.ready.borrow_mut().push_front(waiting);
+        }
+    }
+
+    /// Returns the single task that `awaitable` completes with, if there is one.
+    fn awaited_task(awaitable: Obj) -> Option<Obj> {
+        if awaitable.try_as_obj::<Task>().is_some() {
+            Some(awaitable)
+        } else {
+            awaitable
+                .try_as_obj::<WaitForFuture>()
+                .map(|wait_for| wait_for.task())
+        }
+    }
+
     fn await_would_cycle(waiting_task: Obj, mut awaited_task: Obj) -> bool {
         loop {
             if waiting_task.inner() == awaited_task.inner() {
@@ -97,10 +352,16 @@ impl EventLoop {
             }
 
             let dependency = awaited_task.as_obj::<Task>().waiting_on();
-            if dependency.is_null() {
-                return false;
+            match Self::awaited_task(dependency) {
+                Some(dependency) => awaited_task = dependency,
+                None => return false,
             }
-            awaited_task = dependency;
+        }
+    }
+
+    fn assert_no_await_cycle(waiting_task: Obj, awaited_task: Obj) {
+        if Self::await_would_cycle(waiting_task, awaited_task) {
+            runtime_error(c"task await cycle detected").raise(token());
         }
     }
 
@@ -109,20 +370,36 @@ impl EventLoop {
     /// Child coroutines and custom awaitables must delegate their yielded objects to this root coroutine.
     /// Scheduling a child through this method would incorrectly treat child completion as task
     /// completion.
+    ///
+    /// Native awaitables re-check their own condition every time they are resumed. Parking a task
+    /// on one of them only avoids resuming it before it could possibly make progress, so a spurious
+    /// wakeup is harmless.
     fn tick_task(&self, task_obj: Obj) {
         let task = task_obj.as_obj::<Task>();
         let coro = task.coro();
         assert!(coro.is(GEN_INSTANCE_TYPE));
 
-        let result = resume_gen(coro, Obj::NONE, Obj::NULL);
+        let throw_val = if task.take_cancel_request() {
+            new_exception(token(), CANCELLED_ERROR_TYPE.as_obj_type())
+        } else {
+            Obj::NULL
+        };
+
+        let start = time32::Instant::now();
+        let result = resume_gen(coro, Obj::NONE, throw_val);
+        let run_time = time32::Instant::now() - start;
+        task.record_resume(run_time);
+
         match result.return_kind {
             VmReturnKind::Normal => {
-                let mut ready = self.ready.borrow_mut();
                 task.complete_with(result.obj);
-                while let Some(waiting) = task.pop_waiting_task() {
-                    waiting.as_obj::<Task>().clear_waiting_on();
-                    ready.push_front(waiting);
-                }
+                self.retire(task_obj);
+                self.wake_waiting_tasks(task);
+            }
+            // The task was cancelled while it was running, so whatever it yielded is abandoned
+            // and the error is thrown in on the next tick.
+            VmReturnKind::Yield if task.is_cancel_requested() => {
+                self.ready.borrow_mut().push_front(task_obj);
             }
             VmReturnKind::Yield => {
                 if let Some(sleep) = result.obj.try_as_obj::<Sleep>() {
@@ -131,52 +408,102 @@ impl EventLoop {
                         .unwrap_or_else(|| {
                             value_error(c"sleep deadline is too large").raise(token())
                         });
-                    self.sleepers.borrow_mut().push(Sleeper {
-                        task: task_obj,
-                        deadline,
-                        sleep: result.obj,
-                    });
+                    self.park(task_obj, result.obj, Some(deadline));
                 } else if let Some(awaited_task) = result.obj.try_as_obj::<Task>() {
                     if awaited_task.is_complete() {
                         self.ready.borrow_mut().push_front(task_obj);
                     } else {
-                        if Self::await_would_cycle(task_obj, result.obj) {
-                            runtime_error(c"task await cycle detected").raise(token());
+                        Self::assert_no_await_cycle(task_obj, result.obj);
+                        self.park(task_obj, result.obj, None);
+                        awaited_task.add_waiting_task(task_obj);
+                    }
+                } else if let Some(gather) = result.obj.try_as_obj::<GatherFuture>() {
+                    for awaited_task in gather.tasks() {
+                        if !awaited_task.as_obj::<Task>().is_complete() {
+                            Self::assert_no_await_cycle(task_obj, *awaited_task);
                         }
-                        task.set_waiting_on(result.obj);
+                    }
+                    self.park(task_obj, result.obj, None);
+                    for awaited_task in gather.pending_tasks() {
                         awaited_task.add_waiting_task(task_obj);
                     }
+                } else if let Some(wait) = result.obj.try_as_obj::<WaitFuture>() {
+                    // No cycle check here: with a timeout or `FIRST_COMPLETED`, waiting on a task
+                    // that waits on us does not necessarily deadlock.
+                    self.park(task_obj, result.obj, wait.deadline());
+                    for awaited_task in wait.pending_tasks() {
+                        awaited_task.add_waiting_task(task_obj);
+                    }
+                } else if let Some(wait_for) = result.obj.try_as_obj::<WaitForFuture>() {
+                    Self::assert_no_await_cycle(task_obj, wait_for.task());
+                    self.park(task_obj, result.obj, wait_for.deadline());
+                    wait_for.task().as_obj::<Task>().add_waiting_task(task_obj);
+                } else if let Some(next_poll) = polled::take_pending(result.obj) {
+                    self.park(task_obj, result.obj, next_poll);
                 } else {
                     self.ready.borrow_mut().push_back(task_obj);
                 }
             }
-            VmReturnKind::Exception => nlr::raise(token(), result.obj),
+            VmReturnKind::Exception => {
+                let exc = result.obj;
+                let cancelled = exception_matches(exc, CANCELLED_ERROR_TYPE.as_obj_type());
+
+                if !cancelled && task.can_restart() {
+                    self.report_restart(task_obj, exc);
+                    task.restart();
+                    self.ready.borrow_mut().push_back(task_obj);
+                } else {
+                    task.fail_with(exc);
+                    self.retire(task_obj);
+                    self.wake_waiting_tasks(task);
+
+                    if !cancelled && !task.is_observed() {
+                        self.report_unobserved(task_obj, exc);
+                    }
+                }
+            }
         }
+
+        // The task is rescheduled first, so that an exception raised by the handler doesn't lose it
+        self.check_watchdog(task_obj, run_time);
     }
 
     // returns:
     // true -> no more tasks/sleepers to run, stop
     // false -> tasks/sleepers still in queues
     pub fn tick(&self) -> bool {
-        let mut ready = self.ready.borrow_mut();
-        let mut sleepers = self.sleepers.borrow_mut();
-
         let now = super::time32::Instant::now();
-        while let Some(sleeper) = sleepers.peek()
-            && sleeper.deadline <= now
-        {
-            let sleeper = sleepers.pop().unwrap();
-            sleeper.sleep.as_obj::<Sleep>().complete();
-            ready.push_back(sleeper.task);
+        loop {
+            let expired = {
+                let mut sleepers = self.sleepers.borrow_mut();
+                if sleepers
+                    .peek()
+                    .is_some_and(|sleeper| sleeper.deadline <= now)
+                {
+                    sleepers.pop()
+                } else {
+                    None
+                }
+            };
+            let Some(sleeper) = expired else {
+                break;
+            };
+
+            let task = sleeper.task.as_obj::<Task>();
+            if let Some(sleep) = task.waiting_on().try_as_obj::<Sleep>() {
+                sleep.complete();
+            }
+            self.unpark(sleeper.task);
+            self.ready.borrow_mut().push_back(sleeper.task);
         }
 
-        let task_obj = ready.pop_front();
         // let the task access the event loop while it's running
-        drop(ready);
-        drop(sleepers);
+        let task_obj = self.pop_ready();
 
         if let Some(task_obj) = task_obj {
+            self.current.set(task_obj);
             self.tick_task(task_obj);
+            self.current.set(Obj::NULL);
         }
 
         unsafe { vexTasksRun() };
@@ -221,7 +548,7 @@ impl EventLoop {
             type_error(c"expected coroutine").raise(token());
         }
 
-        self_in.as_obj::<EventLoop>().spawn(coro)
+        EventLoop::spawn(self_in, coro, Obj::NULL)
     }
 
     /// Schedules coroutine object `coro` on this loop and returns an awaitable `Task`.
@@ -243,7 +570,10 @@ impl EventLoop {
         push_nlr_callback(
             token(),
             || self_in.as_obj::<EventLoop>().run(),
-            || RUNNING_LOOP.set(prev_loop),
+            || {
+                self_in.as_obj::<EventLoop>().current.set(Obj::NULL);
+                RUNNING_LOOP.set(prev_loop);
+            },
             true,
         );
         Obj::NONE
@@ -252,8 +582,10 @@ impl EventLoop {
     /// Runs scheduled tasks until no ready tasks or pending sleeps remain.
     ///
     /// While this method is running, `vasyncio.get_running_loop` returns this loop and
-    /// `vasyncio.spawn` adds tasks to it. An exception raised by a task stops the loop and is
-    /// propagated to the caller.
+    /// `vasyncio.spawn` adds tasks to it. An exception raised by a task that nothing observes stops
+    /// the loop and is propagated to the caller, unless an exception handler is set with
+    /// `EventLoop.set_exception_handler`. Exceptions delivered to awaiting tasks and the
+    /// `CancelledError` of a cancelled task do not stop the loop.
     ///
     /// # Raises
     ///
@@ -262,13 +594,113 @@ impl EventLoop {
     #[constant(qstr!(run))]
     #[stub(sig = "(self, /) -> None")]
     const RUN: &Fun1 = &Fun1::new(Self::py_run);
+
+    /// Reports whenever a single resume of a task runs for longer than `budget`, measured in
+    /// `unit`.
+    ///
+    /// A task only gives other tasks a chance to run when it reaches an `await`, so a task that
+    /// computes for a long time, or loops without awaiting, stalls the whole loop. With a watchdog
+    /// budget set, the loop creates a `vasyncio.WatchdogError` naming the offending task and how
+    /// long it ran, and passes it with the task to the exception handler set with
+    /// `EventLoop.set_exception_handler`. If there is no handler, the loop prints the message as a
+    /// warning instead. Either way, the task and the loop keep running. Pass `None` to turn the
+    /// watchdog off again.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// async def main():
+    ///     vasyncio.get_running_loop().set_watchdog(5, MILLIS)
+    ///     ...
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    ///
+    /// # Raises
+    ///
+    /// - `ValueError`: If `budget` is negative, non-finite, or too large to represent.
+    #[method(ty = var_between(min = 2, max = 3))]
+    #[stub(sig = "(self, budget: float | None, unit: TimeUnit = TimeUnit.SECOND, /) -> None")]
+    fn set_watchdog(args: &[Obj]) -> Result<(), Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+
+        let eloop = reader.next_positional::<&Self>()?;
+        let budget = reader.next_positional::<Timeout>()?;
+        let unit = reader.next_positional_or(TimeUnitObj::SECOND)?;
+
+        eloop.watchdog.set(budget.to_duration(unit)?);
+        Ok(())
+    }
+
+    /// Returns a list of the tasks on this loop that have not finished yet.
+    #[method]
+    #[stub(sig = "(self, /) -> list[Task]")]
+    fn all_tasks(&self) -> Obj {
+        self.live_tasks()
+    }
+
+    /// Sets the function called when a task fails with an exception that nothing observes.
+    ///
+    /// A task's failure is observed if another task is awaiting it when it fails. Otherwise
+    /// `handler` is called with the failed task and its exception, and the loop keeps running the
+    /// remaining tasks, so one crashing background task doesn't stop the rest of the program.
+    /// `handler` is also called each time a supervised task fails before it is restarted, and with
+    /// a `vasyncio.WatchdogError` for each resume that overruns the budget set with
+    /// `EventLoop.set_watchdog`.
+    ///
+    /// Pass `None` to restore the default behavior, where an unobserved exception propagates out
+    /// of the running loop, and supervised task failures and watchdog overruns are printed.
+    /// Exceptions raised by `handler` itself propagate out of the loop.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// def log_failure(task, exc):
+    ///     print("task", task.get_name(), "failed:", exc)
+    ///
+    /// async def telemetry():
+    ///     raise RuntimeError("radio disconnected")
+    ///
+    /// async def main():
+    ///     vasyncio.get_running_loop().set_exception_handler(log_failure)
+    ///     vasyncio.spawn(telemetry())
+    ///     # ... keep driving ...
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `handler` is neither callable nor `None`.
+    #[method]
+    #[stub(sig = "(self, handler: Callable[[Task, BaseException], Any] | None, /) -> None")]
+    fn set_exception_handler(&self, handler: Obj) -> Result<(), Exception> {
+        let handler = if handler.is_none() {
+            None
+        } else {
+            Some(
+                CallableParser
+                    .parse(&handler)
+                    .map_err(|_| type_error(c"exception handler must be callable or None"))?,
+            )
+        };
+
+        self.exception_handler.set(handler);
+        Ok(())
+    }
 }
 
 /// Runs coroutine object `coro` on a new event loop until no work remains.
 ///
 /// The loop also waits for tasks spawned into it and pending `Sleep` objects before returning
-/// `None`. The root coroutine's return value is discarded, and an exception from any task stops
-/// the loop and is propagated to the caller.
+/// `None`. The root coroutine's return value is discarded. An exception from a task that nothing
+/// observes, including the root coroutine, stops the loop and is propagated to the caller unless
+/// an exception handler is set with `EventLoop.set_exception_handler`. A `CancelledError` that ends
+/// a cancelled task does not stop the loop.
 ///
 /// # Examples
 ///
@@ -295,9 +727,9 @@ pub fn run(coro: Obj) -> Obj {
         type_error(c"expected coroutine").raise(token());
     }
 
-    let eloop = EventLoop::new();
-    eloop.spawn(coro);
-    EventLoop::py_run(alloc_obj(eloop))
+    let eloop = alloc_obj(EventLoop::new());
+    EventLoop::spawn(eloop, coro, Obj::NULL);
+    EventLoop::py_run(eloop)
 }
 
 /// Spawns a new asynchronous task that can be controlled with the returned `Task` handle.
@@ -305,6 +737,12 @@ pub fn run(coro: Obj) -> Obj {
 /// Call this from code already executing under `vasyncio.run` or `EventLoop.run`. Awaiting the task
 /// yields the coroutine's return value whether the task is still running or has already completed.
 ///
+/// The task is named `name` if it is given, or `Task-<n>` otherwise. The name identifies the task
+/// when it is printed and in watchdog errors.
+///
+/// When several tasks are ready to run, the loop resumes the one with the highest `priority`
+/// first; see `Task.set_priority`. Tasks default to priority `0`.
+///
 /// # Examples
 ///
 /// ```python
@@ -321,19 +759,159 @@ pub fn run(coro: Obj) -> Obj {
 /// vasyncio.run(main())
 /// ```
 ///
+/// Run a drive control loop ahead of a logging task:
+///
+/// ```python
+/// from venice import *
+///
+/// async def drive():
+///     ...
+///
+/// async def log():
+///     ...
+///
+/// async def main():
+///     vasyncio.spawn(drive(), name="drive", priority=10)
+///     vasyncio.spawn(log(), name="log", priority=-10)
+///
+/// vasyncio.run(main())
+/// ```
+///
 /// # Raises
 ///
 /// - `RuntimeError`: If no event loop is running.
-/// - `TypeError`: If `coro` is not a coroutine object.
-#[fun]
-#[stub(sig = "(coro: Any, /) -> Task")]
-pub fn spawn(coro: Obj) -> Obj {
+/// - `TypeError`: If `coro` is not a coroutine object, `name` is not a `str`, or `priority` is not
+///   an `int`.
+#[fun(ty = kw(min = 1))]
+#[stub(sig = "(coro: Any, /, *, name: str | None = None, priority: int = 0) -> Task")]
+pub fn spawn(args: &[Obj], kw_map: &Map) -> Result<Obj, Exception> {
+    let kwarg_count = kw_map.len();
+    let positional_count = args.len() - kwarg_count * 2;
+    let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+    reader.assert_npos(1, 1).assert_nkw(0, 2);
+
+    let coro = reader.next_positional::<Obj>()?;
+
+    let mut name = Obj::NULL;
+    let mut priority = 0;
+    while let Some(arg) = reader.next_kw() {
+        match arg.kw {
+            "name" => name = task_name(arg.parse()?)?,
+            "priority" => priority = arg.parse()?,
+            _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+        }
+    }
+
+    if !coro.is(GEN_INSTANCE_TYPE) {
+        Err(type_error(c"expected coroutine"))?
+    }
+
+    let task = EventLoop::spawn(running_loop(), coro, name);
+    task.as_obj::<Task>().prioritize(priority);
+    Ok(task)
+}
+
+/// Spawns a supervised task that is restarted whenever it fails.
+///
+/// `factory` is called with no arguments to create the task's coroutine, so it is usually an
+/// `async def` function. When the coroutine raises an exception other than `CancelledError`, the
+/// exception is reported to the loop's exception handler, or printed if there is none, and
+/// `factory` is called again to create a fresh coroutine that continues as the same task. After
+/// `max_restarts` restarts, the next failure ends the task like any other exception; `None` allows
+/// unlimited restarts. Cancelling a supervised task stops it for good.
+///
+/// Tasks awaiting a supervised task keep waiting across restarts, and receive its return value
+/// once a coroutine returns. `name` and `priority` work as they do for `vasyncio.spawn`.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// async def telemetry():
+///     while True:
+///         ...  # may raise if the radio disconnects
+///         await vasyncio.Sleep(100, MILLIS)
+///
+/// async def main():
+///     vasyncio.supervise(telemetry, name="telemetry", max_restarts=5)
+///     # ... drive ...
+///
+/// vasyncio.run(main())
+/// ```
+///
+/// # Raises
+///
+/// - `RuntimeError`: If no event loop is running.
+/// - `TypeError`: If `factory` isn't callable, doesn't return a coroutine object, `name` is not a
+///   `str`, or `priority` is not an `int`.
+/// - `ValueError`: If `max_restarts` is negative.
+#[fun(ty = kw(min = 1))]
+#[stub(
+    sig = "(factory: Callable[[], Any], /, *, name: str | None = None, priority: int = 0, max_restarts: int | None = None) -> Task"
+)]
+pub fn supervise(args: &[Obj], kw_map: &Map) -> Result<Obj, Exception> {
+    let kwarg_count = kw_map.len();
+    let positional_count = args.len() - kwarg_count * 2;
+    let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+    reader.assert_npos(1, 1).assert_nkw(0, 3);
+
+    let factory = reader.next_positional::<Callable>()?;
+
+    let mut name = Obj::NULL;
+    let mut priority = 0;
+    let mut max_restarts = None;
+    while let Some(arg) = reader.next_kw() {
+        match arg.kw {
+            "name" => name = task_name(arg.parse()?)?,
+            "priority" => priority = arg.parse()?,
+            "max_restarts" => {
+                let max_obj = arg.parse::<Obj>()?;
+                if !max_obj.is_none() {
+                    let max = arg.parse_with(IntParser::<u32>::new(0..=i32::MAX))?;
+                    max_restarts = Some(max);
+                }
+            }
+            _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+        }
+    }
+
+    let eloop = running_loop();
+    let coro = factory.call(0, &[]);
+    if !coro.is(GEN_INSTANCE_TYPE) {
+        Err(type_error(
+            c"supervised task factory must return a coroutine",
+        ))?
+    }
+
+    let task = EventLoop::spawn(eloop, coro, name);
+    task.as_obj::<Task>().prioritize(priority);
+    task.as_obj::<Task>().supervise(factory, max_restarts);
+    Ok(task)
+}
+
+/// Validates a `name` argument, returning null for `None`.
+fn task_name(name: Obj) -> Result<Obj, Exception> {
+    if name.is_none() {
+        Ok(Obj::NULL)
+    } else if name.get_str().is_some() {
+        Ok(name)
+    } else {
+        Err(type_error(c"task name must be a str or None"))
+    }
+}
+
+/// Spawns `coro` onto the running event loop with a default task name.
+pub fn spawn_coro(coro: Obj) -> Obj {
+    EventLoop::py_spawn(running_loop(), coro)
+}
+
+fn running_loop() -> Obj {
     let eloop = RUNNING_LOOP.get();
     if eloop.is_none() {
         raise_msg(token(), RUNTIME_ERROR_TYPE, c"no running event loop");
     }
-
-    EventLoop::py_spawn(eloop, coro)
+    eloop
 }
 
 /// Returns the event loop currently executing tasks, or `None` outside `vasyncio.run` or
@@ -343,3 +921,86 @@ pub fn spawn(coro: Obj) -> Obj {
 pub fn get_running_loop() -> Obj {
     RUNNING_LOOP.get()
 }
+
+/// Returns the task currently being resumed by the running event loop, or null if there is none.
+pub fn running_task() -> Obj {
+    let eloop = RUNNING_LOOP.get();
+    if eloop.is_none() {
+        return Obj::NULL;
+    }
+
+    eloop.as_obj::<EventLoop>().current.get()
+}
+
+/// Returns the task that is currently running, or `None` outside a task.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// async def main():
+///     print("running in", vasyncio.current_task().get_name())
+///
+/// vasyncio.run(main())
+/// ```
+#[fun]
+#[stub(sig = "() -> Task | None")]
+pub fn current_task() -> Obj {
+    let task = running_task();
+    if task.is_null() { Obj::NONE } else { task }
+}
+
+/// Returns a list of the tasks on the running event loop that have not finished yet.
+///
+/// Together with `Task.stats`, this helps find the task that is stalling the loop.
+///
+/// # Raises
+///
+/// - `RuntimeError`: If no event loop is running.
+#[fun]
+#[stub(sig = "() -> list[Task]")]
+pub fn all_tasks() -> Obj {
+    running_loop().as_obj::<EventLoop>().live_tasks()
+}
+
+/// Returns the lock that reserves `device` for the task holding it.
+///
+/// Every call with the same device object on the running loop returns the same `Lock`, so tasks
+/// that each take the reservation with `async with` never command the device at the same time. A
+/// task that enters the block while another task holds it waits until the other task leaves its
+/// block, and the reservation is released when the block exits, even if it raises or the task is
+/// cancelled.
+///
+/// Reservations are only respected by code that takes them; they do not stop a task from using the
+/// device directly. Reservations are keyed on the device object, so every task should share one
+/// object for each device rather than creating its own.
+///
+/// # Raises
+///
+/// - `RuntimeError`: If no event loop is running.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// intake = Motor(1)
+///
+/// async def score():
+///     async with vasyncio.reserve(intake):
+///         intake.set_voltage(12.0)
+///         await vasyncio.Sleep(500, MILLIS)
+///         intake.set_voltage(0.0)
+///
+/// async def clear_jam():
+///     async with vasyncio.reserve(intake):
+///         intake.set_voltage(-12.0)
+///         await vasyncio.Sleep(200, MILLIS)
+///         intake.set_voltage(0.0)
+/// ```
+#[fun]
+#[stub(sig = "(device: Any, /) -> Lock")]
+pub fn reserve(device: Obj) -> Obj {
+    running_loop().as_obj::<EventLoop>().reservation(device)
+}
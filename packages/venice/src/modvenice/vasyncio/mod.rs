@@ -5,22 +5,73 @@ use micropython_rs::{
 };
 
 use crate::modvenice::vasyncio::{
-    event_loop::{EventLoop, get_running_loop_obj, run_obj, spawn_obj},
+    event_loop::{
+        EventLoop, WATCHDOG_ERROR_TYPE, all_tasks_obj, current_task_obj, get_running_loop_obj,
+        reserve_obj, run_obj, spawn_obj, supervise_obj,
+    },
+    queue::{QUEUE_EMPTY_TYPE, QUEUE_FULL_TYPE, Queue},
     sleep::Sleep,
-    task::Task,
+    stream::{SampleStream, sample_obj},
+    sync::{Condition, Event, ExitFuture, Lock, Semaphore, SyncFuture},
+    task::{CANCELLED_ERROR_TYPE, Task, TaskStats},
+    ticker::Ticker,
+    until::{UntilFuture, until_obj},
+    wait::{
+        GatherFuture, ReturnWhenObj, TIMEOUT_ERROR_TYPE, WaitForFuture, WaitFuture, gather_obj,
+        wait_for_obj, wait_obj,
+    },
 };
 
 pub mod event_loop;
+pub mod polled;
+pub mod queue;
 pub mod sleep;
+pub mod stream;
+pub mod sync;
 pub mod task;
+pub mod ticker;
 pub mod time32;
+pub mod until;
+pub mod wait;
 
 pub const VASYNCIO_DICT: &Dict = const_dict![
     qstr!(__name__) => Obj::from_qstr(qstr!(vasyncio)),
+    qstr!(CancelledError) => Obj::from_static(CANCELLED_ERROR_TYPE.as_obj_type()),
+    qstr!(QueueEmpty) => Obj::from_static(QUEUE_EMPTY_TYPE.as_obj_type()),
+    qstr!(QueueFull) => Obj::from_static(QUEUE_FULL_TYPE.as_obj_type()),
+    qstr!(TimeoutError) => Obj::from_static(TIMEOUT_ERROR_TYPE.as_obj_type()),
+    qstr!(WatchdogError) => Obj::from_static(WATCHDOG_ERROR_TYPE.as_obj_type()),
+    qstr!(Condition) => Obj::from_static(Condition::OBJ_TYPE),
+    qstr!(Event) => Obj::from_static(Event::OBJ_TYPE),
     qstr!(EventLoop) => Obj::from_static(EventLoop::OBJ_TYPE),
+    qstr!(ExitFuture) => Obj::from_static(ExitFuture::OBJ_TYPE),
+    qstr!(GatherFuture) => Obj::from_static(GatherFuture::OBJ_TYPE),
+    qstr!(Lock) => Obj::from_static(Lock::OBJ_TYPE),
+    qstr!(Queue) => Obj::from_static(Queue::OBJ_TYPE),
+    qstr!(ReturnWhen) => Obj::from_static(ReturnWhenObj::OBJ_TYPE),
+    qstr!(SampleStream) => Obj::from_static(SampleStream::OBJ_TYPE),
+    qstr!(FIRST_COMPLETED) => Obj::from_static(ReturnWhenObj::FIRST_COMPLETED),
+    qstr!(FIRST_EXCEPTION) => Obj::from_static(ReturnWhenObj::FIRST_EXCEPTION),
+    qstr!(ALL_COMPLETED) => Obj::from_static(ReturnWhenObj::ALL_COMPLETED),
+    qstr!(Semaphore) => Obj::from_static(Semaphore::OBJ_TYPE),
     qstr!(Sleep) => Obj::from_static(Sleep::OBJ_TYPE),
+    qstr!(SyncFuture) => Obj::from_static(SyncFuture::OBJ_TYPE),
     qstr!(Task) => Obj::from_static(Task::OBJ_TYPE),
+    qstr!(TaskStats) => Obj::from_static(TaskStats::OBJ_TYPE),
+    qstr!(Ticker) => Obj::from_static(Ticker::OBJ_TYPE),
+    qstr!(UntilFuture) => Obj::from_static(UntilFuture::OBJ_TYPE),
+    qstr!(WaitFuture) => Obj::from_static(WaitFuture::OBJ_TYPE),
+    qstr!(WaitForFuture) => Obj::from_static(WaitForFuture::OBJ_TYPE),
+    qstr!(all_tasks) => all_tasks_obj,
+    qstr!(current_task) => current_task_obj,
+    qstr!(gather) => gather_obj,
     qstr!(get_running_loop) => get_running_loop_obj,
+    qstr!(reserve) => reserve_obj,
     qstr!(run) => run_obj,
+    qstr!(sample) => sample_obj,
     qstr!(spawn) => spawn_obj,
+    qstr!(supervise) => supervise_obj,
+    qstr!(until) => until_obj,
+    qstr!(wait) => wait_obj,
+    qstr!(wait_for) => wait_for_obj,
 ];
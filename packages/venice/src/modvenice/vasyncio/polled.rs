@@ -0,0 +1,47 @@
+use std::cell::Cell;
+
+use micropython_rs::obj::{Obj, ObjTrait};
+
+use super::time32;
+
+/// A native awaitable that yields itself while it waits and checks its own condition each time it
+/// is resumed.
+///
+/// A polled future yields [`PolledFuture::pend`] from `__next__` instead of returning itself
+/// directly, which tells the event loop to park the awaiting task until
+/// [`PolledFuture::next_poll`], or until something else wakes it if that is `None`. The loop never
+/// needs to know the future's type, so futures can be defined in any module. Resuming the task
+/// early is harmless.
+pub trait PolledFuture: ObjTrait {
+    /// Returns when the awaiting task should next be resumed.
+    fn next_poll(&self) -> Option<time32::Instant>;
+
+    /// Returns `self_in`, which must be this future, for `__next__` to yield to the event loop.
+    fn pend(&self, self_in: Obj) -> Obj {
+        PENDING.set(Some(Pending {
+            future: self_in,
+            next_poll: self.next_poll(),
+        }));
+        self_in
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Pending {
+    future: Obj,
+    next_poll: Option<time32::Instant>,
+}
+
+thread_local! {
+    // the future most recently yielded through `PolledFuture::pend`
+    static PENDING: Cell<Option<Pending>> = const { Cell::new(None) };
+}
+
+/// Returns when a task that yielded `obj` should next be resumed, or `None` if `obj` wasn't
+/// yielded through [`PolledFuture::pend`].
+pub fn take_pending(obj: Obj) -> Option<Option<time32::Instant>> {
+    PENDING
+        .take()
+        .filter(|pending| pending.future.inner() == obj.inner())
+        .map(|pending| pending.next_poll)
+}
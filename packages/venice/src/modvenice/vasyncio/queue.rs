@@ -0,0 +1,229 @@
+use std::{cell::RefCell, collections::vec_deque::VecDeque};
+
+use argparse::Args;
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{EXCEPTION_TYPE, new_exception_type, raise_msg},
+    init::token,
+    obj::{AttrOp, Obj, ObjBase, ObjFullType, ObjTrait, ObjType},
+    qstr::Qstr,
+};
+
+use super::sync::{SyncFuture, SyncOp, Waiters};
+use crate::{
+    alloc::Gc,
+    modvenice::{Exception, read_only_attr::read_only_attr},
+};
+
+/// Raised by `Queue.get_nowait` when the queue is empty.
+pub static QUEUE_EMPTY_TYPE: ObjFullType = new_exception_type(qstr!(QueueEmpty), EXCEPTION_TYPE);
+
+/// Raised by `Queue.put_nowait` when the queue is full.
+pub static QUEUE_FULL_TYPE: ObjFullType = new_exception_type(qstr!(QueueFull), EXCEPTION_TYPE);
+
+/// A first-in, first-out queue for passing items between tasks.
+///
+/// A `Queue` connects producer tasks, such as a sensor-polling loop, to consumer tasks, such as a
+/// control loop. `Queue.get` waits until an item is available and `Queue.put` waits until there is
+/// room for one, so a bounded queue slows a producer down when its consumer falls behind. Waiting
+/// tasks are parked on the event loop and served in the order they started waiting.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// optical = OpticalSensor(1)
+/// readings = vasyncio.Queue(4)
+///
+/// async def poll_proximity():
+///     while True:
+///         await readings.put(optical.get_proximity())
+///         await vasyncio.Sleep(10, MILLIS)
+///
+/// async def control():
+///     while True:
+///         reading = await readings.get()
+///         print("proximity:", reading)
+///
+/// async def main():
+///     vasyncio.spawn(poll_proximity())
+///     await control()
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(Queue))]
+#[repr(C)]
+pub struct Queue {
+    base: ObjBase,
+    maxsize: i32,
+    items: RefCell<VecDeque<Obj, Gc>>,
+    getters: Waiters,
+    putters: Waiters,
+}
+
+impl Queue {
+    pub(super) fn getters(&self) -> &Waiters {
+        &self.getters
+    }
+
+    pub(super) fn putters(&self) -> &Waiters {
+        &self.putters
+    }
+
+    fn len(&self) -> usize {
+        self.items.borrow().len()
+    }
+
+    fn is_full(&self) -> bool {
+        self.maxsize > 0 && self.len() >= self.maxsize as usize
+    }
+
+    /// Takes the next item if one is available and no other task is already waiting for one.
+    ///
+    /// Taking an item frees a slot, which is immediately filled by the longest-waiting putter.
+    pub(super) fn try_get(&self) -> Option<Obj> {
+        if !self.getters.is_empty() {
+            return None;
+        }
+
+        let item = self.items.borrow_mut().pop_front()?;
+        if let Some(putter) = self.putters.pop() {
+            let put_item = putter.as_obj::<SyncFuture>().item();
+            self.items.borrow_mut().push_back(put_item);
+            SyncFuture::grant(putter);
+        }
+        Some(item)
+    }
+
+    /// Adds an item if there is room and no other task is already waiting to put one.
+    ///
+    /// If a task is waiting in `Queue.get`, the item is handed to it directly.
+    pub(super) fn try_put(&self, item: Obj) -> bool {
+        if !self.putters.is_empty() {
+            return false;
+        }
+
+        if let Some(getter) = self.getters.pop() {
+            SyncFuture::grant_item(getter, item);
+            return true;
+        }
+
+        if self.is_full() {
+            return false;
+        }
+        self.items.borrow_mut().push_back(item);
+        true
+    }
+
+    /// Returns an item that was handed to a getter which was cancelled before it could resume.
+    ///
+    /// The item goes to the next waiting getter, or back to the front of the queue so that item
+    /// order is preserved. This may briefly overfill a bounded queue.
+    pub(super) fn requeue(&self, item: Obj) {
+        match self.getters.pop() {
+            Some(getter) => SyncFuture::grant_item(getter, item),
+            None => self.items.borrow_mut().push_front(item),
+        }
+    }
+}
+
+#[class_methods]
+impl Queue {
+    /// Creates a queue that holds at most `maxsize` items.
+    ///
+    /// If `maxsize` is less than or equal to zero, the queue is unbounded and `Queue.put` never
+    /// waits.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If keyword arguments or more than one positional argument are supplied, or
+    ///   if `maxsize` isn't an `int`.
+    #[make_new]
+    #[stub(sig = "(self, maxsize: int = 0, /) -> None")]
+    fn make_new(_: &ObjType, n_pos: usize, n_kw: usize, args: &[Obj]) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(0, 1).assert_nkw(0, 0);
+
+        let maxsize = reader.next_positional_or(0)?;
+
+        Ok(Self {
+            base: Self::OBJ_TYPE.into(),
+            maxsize,
+            items: RefCell::new(VecDeque::new_in(Gc { token: token() })),
+            getters: Waiters::new(),
+            putters: Waiters::new(),
+        })
+    }
+
+    #[attr]
+    #[stub(attrs = ["maxsize: int"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        if attr.as_str() != "maxsize" {
+            return;
+        }
+
+        match op {
+            AttrOp::Load { result } => result.return_value(Obj::from_int(self.maxsize)),
+            _ => read_only_attr::<Self>(),
+        }
+    }
+
+    /// Returns the number of items in the queue.
+    #[method]
+    fn qsize(&self) -> i32 {
+        self.len() as i32
+    }
+
+    /// Returns `True` if the queue has no items.
+    #[method]
+    fn empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `True` if the queue holds `maxsize` items.
+    ///
+    /// An unbounded queue is never full.
+    #[method]
+    fn full(&self) -> bool {
+        self.is_full()
+    }
+
+    /// Waits until an item is available, then removes and returns it.
+    #[method]
+    #[stub(sig = "(self, /) -> SyncFuture")]
+    fn get(self_in: Obj) -> SyncFuture {
+        SyncFuture::new(SyncOp::QueueGet, self_in)
+    }
+
+    /// Waits until there is room in the queue, then adds `item` to it.
+    #[method]
+    #[stub(sig = "(self, item: Any, /) -> SyncFuture")]
+    fn put(self_in: Obj, item: Obj) -> SyncFuture {
+        SyncFuture::with_item(SyncOp::QueuePut, self_in, item)
+    }
+
+    /// Removes and returns an item without waiting.
+    ///
+    /// # Raises
+    ///
+    /// - `QueueEmpty`: If no item is available.
+    #[method]
+    fn get_nowait(&self) -> Obj {
+        self.try_get().unwrap_or_else(|| {
+            raise_msg(token(), QUEUE_EMPTY_TYPE.as_obj_type(), c"queue is empty")
+        })
+    }
+
+    /// Adds `item` to the queue without waiting.
+    ///
+    /// # Raises
+    ///
+    /// - `QueueFull`: If the queue is full.
+    #[method]
+    fn put_nowait(&self, item: Obj) {
+        if !self.try_put(item) {
+            raise_msg(token(), QUEUE_FULL_TYPE.as_obj_type(), c"queue is full")
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use argparse::{Args, Callable};
+use micropython_macros::{class, class_methods, fun};
+use micropython_rs::{
+    except::raise_stop_iteration,
+    init::token,
+    obj::{Obj, ObjBase, ObjTrait},
+};
+
+use super::{polled::PolledFuture, ticker::Schedule, time32};
+use crate::modvenice::{Exception, units::time::TimeUnitObj};
+
+/// Reads one sample from `device`.
+pub type SampleFn = fn(device: Obj) -> Result<Obj, Exception>;
+
+enum Source {
+    Callable(Callable),
+    Device { read: SampleFn, device: Obj },
+}
+
+/// An asynchronous iterator that produces a sample on a fixed schedule.
+///
+/// Iterating with `async for` waits for each tick of the schedule and then takes a sample, so the
+/// loop body runs once per period. Like `Ticker`, ticks are scheduled from the first sample rather
+/// than from when the loop body finished, and ticks that are missed because the loop body ran too
+/// long are skipped instead of being delivered in a burst. The stream never ends on its own; leave
+/// the loop with `break` or `return`.
+///
+/// Users receive this from `vasyncio.sample` or from device helpers such as `Controller.states`
+/// rather than constructing it directly.
+///
+/// # Raises
+///
+/// Any exception raised while taking a sample propagates out of the `async for` loop.
+#[class(qstr!(SampleStream))]
+#[repr(C)]
+pub struct SampleStream {
+    base: ObjBase,
+    source: Source,
+    schedule: Schedule,
+}
+
+impl SampleStream {
+    /// Creates a stream that reads `read(device)` every `period`.
+    pub fn device(
+        read: SampleFn,
+        device: Obj,
+        period: time32::Duration,
+    ) -> Result<Self, Exception> {
+        Ok(Self {
+            base: Self::OBJ_TYPE.into(),
+            source: Source::Device { read, device },
+            schedule: Schedule::new(period)?,
+        })
+    }
+
+    fn take_sample(&self) -> Result<Obj, Exception> {
+        match &self.source {
+            Source::Callable(source) => Ok(source.call(0, &[])),
+            Source::Device { read, device } => read(*device),
+        }
+    }
+}
+
+impl PolledFuture for SampleStream {
+    /// Returns the deadline of the next sample, if the stream is waiting for one.
+    fn next_poll(&self) -> Option<time32::Instant> {
+        self.schedule.deadline()
+    }
+}
+
+#[class_methods]
+impl SampleStream {
+    /// Returns the stream itself.
+    #[method]
+    #[stub(sig = "(self, /) -> SampleStream")]
+    fn __aiter__(self_in: Obj) -> Obj {
+        self_in
+    }
+
+    /// Waits for the next tick of the schedule, then returns a new sample.
+    #[method]
+    #[stub(sig = "(self, /) -> SampleStream")]
+    fn __anext__(self_in: Obj) -> Obj {
+        self_in
+    }
+
+    #[iter]
+    extern "C" fn stream_iternext(self_in: Obj) -> Obj {
+        let stream = self_in.as_obj::<Self>();
+        if stream.schedule.poll().is_none() {
+            return stream.pend(self_in);
+        }
+
+        match stream.take_sample() {
+            Ok(sample) => raise_stop_iteration(token(), sample),
+            Err(error) => error.raise(token()),
+        }
+    }
+}
+
+/// Returns an asynchronous iterator that calls `source()` every `period`, measured in `unit`.
+///
+/// Each iteration of an `async for` loop over the stream waits for the next period to elapse and
+/// then produces the value `source` returns. The awaiting task is parked between samples, so other
+/// tasks keep running. See `SampleStream` for how late samples are handled.
+///
+/// # Examples
+///
+/// Print a rotation sensor's position 20 times per second:
+///
+/// ```python
+/// from venice import *
+///
+/// sensor = RotationSensor(1)
+///
+/// async def main():
+///     async for position in vasyncio.sample(lambda: sensor.get_position(DEGREES), 50, MILLIS):
+///         print(position)
+///
+/// vasyncio.run(main())
+/// ```
+///
+/// # Raises
+///
+/// - `TypeError`: If `source` isn't callable.
+/// - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+#[fun(ty = var_between(min = 3, max = 3))]
+#[stub(sig = "(source: Callable[[], Any], period: float, unit: TimeUnit, /) -> SampleStream")]
+pub fn sample(args: &[Obj]) -> Result<SampleStream, Exception> {
+    let mut reader = Args::new(args.len(), 0, args).reader();
+
+    let source = reader.next_positional::<Callable>()?;
+    let period = reader.next_positional()?;
+    let unit = reader.next_positional::<&TimeUnitObj>()?.unit();
+
+    let period = time32::Duration::from_duration(unit.float_to_dur(period)?);
+    Ok(SampleStream {
+        base: SampleStream::OBJ_TYPE.into(),
+        source: Source::Callable(source),
+        schedule: Schedule::new(period)?,
+    })
+}
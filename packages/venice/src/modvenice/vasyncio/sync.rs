@@ -0,0 +1,852 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::vec_deque::VecDeque,
+};
+
+use argparse::{Args, IntParser, error_msg, type_name};
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{raise_stop_iteration, runtime_error, type_error},
+    init::token,
+    nlr,
+    obj::{Obj, ObjBase, ObjTrait, ObjType},
+};
+
+use super::{
+    event_loop::{self, EventLoop},
+    polled::PolledFuture,
+    queue::Queue,
+    task::Task,
+    time32,
+};
+use crate::{alloc::Gc, modvenice::Exception, obj::alloc_obj};
+
+/// FIFO queue of `SyncFuture`s parked on a synchronization primitive.
+pub(super) struct Waiters(RefCell<VecDeque<Obj, Gc>>);
+
+impl Waiters {
+    pub(super) fn new() -> Self {
+        Self(RefCell::new(VecDeque::new_in(Gc { token: token() })))
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+
+    fn push(&self, future: Obj) {
+        self.0.borrow_mut().push_back(future);
+    }
+
+    pub(super) fn pop(&self) -> Option<Obj> {
+        self.0.borrow_mut().pop_front()
+    }
+
+    fn remove(&self, future: Obj) {
+        self.0
+            .borrow_mut()
+            .retain(|waiter| waiter.inner() != future.inner());
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum SyncOp {
+    EventWait,
+    LockAcquire,
+    SemaphoreAcquire,
+    ConditionWait,
+    QueueGet,
+    QueuePut,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SyncState {
+    /// Not yet polled.
+    Start,
+    /// Parked in the wait queue of `primitive`.
+    Queued,
+    /// A `Condition` waiter that was notified and must now reacquire the underlying lock.
+    Notified,
+    /// A `Condition` waiter parked in the wait queue of the underlying lock.
+    LockQueued,
+    /// The event was set, ownership of the lock or semaphore was handed to this future, or the
+    /// queue item was transferred.
+    Granted,
+    Complete,
+}
+
+/// An awaitable returned by the waiting methods of `vasyncio` synchronization primitives.
+///
+/// Users receive it from `Event.wait`, `Lock.acquire`, `Semaphore.acquire`, `Condition.acquire`,
+/// `Condition.wait`, `Queue.get`, or `Queue.put`, or by entering an `async with` block on a
+/// `Lock`, `Semaphore`, or `Condition`, rather than constructing it directly. Awaiting it
+/// parks the current task on the primitive until it can continue. It then returns the item for
+/// `Queue.get`, `None` for `Queue.put`, and `True` otherwise. Waiting tasks are served in the order
+/// they started waiting.
+///
+/// If the awaiting task is cancelled, the future removes itself from the primitive's queue. Any
+/// lock or semaphore slot that was handed to it in the meantime is passed on to the next waiter, and
+/// a queue item handed to a cancelled getter is put back at the front of the queue, so cancelled
+/// tasks never leak ownership or drop items.
+///
+/// # Raises
+///
+/// - `RuntimeError`: If the future is awaited again after completing.
+#[class(qstr!(SyncFuture))]
+#[repr(C)]
+pub struct SyncFuture {
+    base: ObjBase,
+    op: SyncOp,
+    primitive: Obj,
+    state: Cell<SyncState>,
+    // task that parked this future, woken when the future is granted
+    task: Cell<Obj>,
+    // exception to raise once a cancelled `Condition` waiter has reacquired its lock
+    pending_exc: Cell<Obj>,
+    // item being put into or taken out of a queue
+    item: Cell<Obj>,
+}
+
+impl SyncFuture {
+    pub(super) fn new(op: SyncOp, primitive: Obj) -> Self {
+        Self::with_item(op, primitive, Obj::NONE)
+    }
+
+    pub(super) fn with_item(op: SyncOp, primitive: Obj, item: Obj) -> Self {
+        Self {
+            base: Self::OBJ_TYPE.into(),
+            op,
+            primitive,
+            state: Cell::new(SyncState::Start),
+            task: Cell::new(Obj::NULL),
+            pending_exc: Cell::new(Obj::NULL),
+            item: Cell::new(item),
+        }
+    }
+
+    pub(super) fn item(&self) -> Obj {
+        self.item.get()
+    }
+
+    fn waiters(&self) -> &Waiters {
+        match self.op {
+            SyncOp::EventWait => &self.primitive.as_obj::<Event>().waiters,
+            SyncOp::LockAcquire => &self.primitive.as_obj::<Lock>().waiters,
+            SyncOp::SemaphoreAcquire => &self.primitive.as_obj::<Semaphore>().waiters,
+            SyncOp::ConditionWait => &self.primitive.as_obj::<Condition>().waiters,
+            SyncOp::QueueGet => self.primitive.as_obj::<Queue>().getters(),
+            SyncOp::QueuePut => self.primitive.as_obj::<Queue>().putters(),
+        }
+    }
+
+    fn condition_lock(&self) -> &Lock {
+        self.primitive.as_obj::<Condition>().lock.as_obj::<Lock>()
+    }
+
+    fn enqueue(&self, self_in: Obj, waiters: &Waiters, state: SyncState) {
+        self.task.set(event_loop::running_task());
+        self.state.set(state);
+        waiters.push(self_in);
+    }
+
+    /// Marks the future as granted and wakes its task if it is parked on this future.
+    pub(super) fn grant(future_obj: Obj) {
+        Self::resume_with(future_obj, SyncState::Granted);
+    }
+
+    /// Hands a queue item to a parked `Queue.get` future.
+    pub(super) fn grant_item(future_obj: Obj, item: Obj) {
+        future_obj.as_obj::<SyncFuture>().item.set(item);
+        Self::grant(future_obj);
+    }
+
+    fn resume_with(future_obj: Obj, state: SyncState) {
+        let future = future_obj.as_obj::<SyncFuture>();
+        future.state.set(state);
+
+        let task = future.task.get();
+        if !task.is_null() {
+            task.as_obj::<Task>()
+                .event_loop()
+                .as_obj::<EventLoop>()
+                .wake(task, future_obj);
+        }
+    }
+
+    /// Tries to reacquire the lock of a `Condition` waiter, queueing on the lock if it is taken.
+    ///
+    /// Returns whether the lock was acquired.
+    fn reacquire(&self, self_in: Obj) -> bool {
+        let lock = self.condition_lock();
+        if lock.try_acquire() {
+            self.state.set(SyncState::Complete);
+            true
+        } else {
+            self.state.set(SyncState::LockQueued);
+            lock.waiters.push(self_in);
+            false
+        }
+    }
+
+    fn complete(&self) -> ! {
+        self.state.set(SyncState::Complete);
+
+        let pending_exc = self.pending_exc.replace(Obj::NULL);
+        if !pending_exc.is_null() {
+            nlr::raise(token(), pending_exc);
+        }
+        let result = match self.op {
+            SyncOp::QueueGet => self.item.replace(Obj::NONE),
+            SyncOp::QueuePut => Obj::NONE,
+            _ => Obj::TRUE,
+        };
+        raise_stop_iteration(token(), result)
+    }
+
+    /// Returns anything granted to this future that the awaiting task will never observe.
+    fn release_grant(&self) {
+        match self.op {
+            SyncOp::EventWait => {}
+            SyncOp::LockAcquire => self.primitive.as_obj::<Lock>().release_inner(),
+            SyncOp::SemaphoreAcquire => self.primitive.as_obj::<Semaphore>().release_inner(),
+            // the lock was reacquired for the task, which still owns it
+            SyncOp::ConditionWait => {}
+            SyncOp::QueueGet => self
+                .primitive
+                .as_obj::<Queue>()
+                .requeue(self.item.replace(Obj::NONE)),
+            // the item is already in the queue
+            SyncOp::QueuePut => {}
+        }
+    }
+}
+
+impl PolledFuture for SyncFuture {
+    /// Returns `None`, since the primitive wakes the task once the future is granted.
+    fn next_poll(&self) -> Option<time32::Instant> {
+        None
+    }
+}
+
+#[class_methods]
+impl SyncFuture {
+    #[iter]
+    extern "C" fn sync_iternext(self_in: Obj) -> Obj {
+        let this = self_in.as_obj::<Self>();
+
+        match this.state.get() {
+            SyncState::Start => {
+                let ready = match this.op {
+                    SyncOp::EventWait => this.primitive.as_obj::<Event>().flag.get(),
+                    SyncOp::LockAcquire => this.primitive.as_obj::<Lock>().try_acquire(),
+                    SyncOp::SemaphoreAcquire => this.primitive.as_obj::<Semaphore>().try_acquire(),
+                    SyncOp::ConditionWait => {
+                        let lock = this.condition_lock();
+                        if !lock.locked.get() {
+                            runtime_error(c"cannot wait on un-acquired lock").raise(token());
+                        }
+                        lock.release_inner();
+                        false
+                    }
+                    SyncOp::QueueGet => match this.primitive.as_obj::<Queue>().try_get() {
+                        Some(item) => {
+                            this.item.set(item);
+                            true
+                        }
+                        None => false,
+                    },
+                    SyncOp::QueuePut => this.primitive.as_obj::<Queue>().try_put(this.item.get()),
+                };
+
+                if ready {
+                    this.complete();
+                }
+                this.enqueue(self_in, this.waiters(), SyncState::Queued);
+                this.pend(self_in)
+            }
+            // a spurious wakeup
+            SyncState::Queued | SyncState::LockQueued => this.pend(self_in),
+            SyncState::Notified => {
+                if this.reacquire(self_in) {
+                    this.complete();
+                }
+                this.pend(self_in)
+            }
+            SyncState::Granted => this.complete(),
+            SyncState::Complete => {
+                runtime_error(c"SyncFuture cannot be awaited more than once").raise(token())
+            }
+        }
+    }
+
+    /// Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
+    ///
+    /// The future leaves its wait queue before the exception propagates. A `Condition` waiter first
+    /// reacquires its lock so the lock is held when the exception reaches the caller, as it would be
+    /// after a normal return.
+    #[method]
+    #[stub(sig = "(self, exc: BaseException, /) -> Any")]
+    fn throw(self_in: Obj, exc: Obj) -> Obj {
+        let this = self_in.as_obj::<Self>();
+
+        match this.state.get() {
+            SyncState::Queued => this.waiters().remove(self_in),
+            SyncState::Granted => this.release_grant(),
+            SyncState::Start | SyncState::Notified | SyncState::LockQueued => {}
+            SyncState::Complete => nlr::raise(token(), exc),
+        }
+
+        if this.op == SyncOp::ConditionWait && this.state.get() != SyncState::Granted {
+            this.pending_exc.set(exc);
+            if this.state.get() == SyncState::LockQueued || !this.reacquire(self_in) {
+                // keep waiting for the lock; the exception is raised once it is acquired
+                return self_in;
+            }
+        }
+
+        this.state.set(SyncState::Complete);
+        this.pending_exc.set(Obj::NULL);
+        nlr::raise(token(), exc)
+    }
+
+    /// Leaves the wait queue when the awaiting coroutine is closed.
+    #[method]
+    fn close(self_in: Obj) {
+        let this = self_in.as_obj::<Self>();
+
+        match this.state.get() {
+            SyncState::Queued => this.waiters().remove(self_in),
+            SyncState::LockQueued => this.condition_lock().waiters.remove(self_in),
+            SyncState::Granted => this.release_grant(),
+            SyncState::Start | SyncState::Notified | SyncState::Complete => {}
+        }
+
+        this.state.set(SyncState::Complete);
+    }
+}
+
+/// An awaitable returned when leaving an `async with` block on a `Lock`, `Semaphore`, or
+/// `Condition`.
+///
+/// The primitive is released as soon as the block is left, so awaiting this completes immediately.
+/// It returns `False`, so an exception raised inside the block keeps propagating.
+#[class(qstr!(ExitFuture))]
+#[repr(C)]
+pub struct ExitFuture {
+    base: ObjBase,
+}
+
+impl ExitFuture {
+    fn new() -> Self {
+        Self {
+            base: Self::OBJ_TYPE.into(),
+        }
+    }
+}
+
+#[class_methods]
+impl ExitFuture {
+    #[iter]
+    extern "C" fn exit_iternext(_self_in: Obj) -> Obj {
+        raise_stop_iteration(token(), Obj::FALSE)
+    }
+}
+
+/// An event that tasks can wait for.
+///
+/// An `Event` holds a flag that starts out clear. `Event.set` sets the flag and wakes every task
+/// waiting in `Event.wait`; while the flag is set, `Event.wait` returns immediately. `Event.clear`
+/// resets the flag. Waiting tasks are parked on the event loop instead of polling.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// optical = OpticalSensor(1)
+/// ring_detected = vasyncio.Event()
+///
+/// async def watch_intake():
+///     while optical.get_proximity() < 0.5:
+///         await vasyncio.Sleep(10, MILLIS)
+///     ring_detected.set()
+///
+/// async def main():
+///     vasyncio.spawn(watch_intake())
+///     await ring_detected.wait()
+///     print("ring detected")
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(Event))]
+#[repr(C)]
+pub struct Event {
+    base: ObjBase,
+    flag: Cell<bool>,
+    waiters: Waiters,
+}
+
+#[class_methods]
+impl Event {
+    /// Creates an event whose flag is clear.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If any arguments are supplied.
+    #[make_new]
+    #[stub(sig = "(self, /) -> None")]
+    fn make_new(
+        _: &ObjType,
+        _n_args: usize,
+        _n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        if args.len() != 0 {
+            Err(type_error(
+                c"constructor does not accept arguments; just call Event()",
+            ))?
+        }
+
+        Ok(Self {
+            base: Self::OBJ_TYPE.into(),
+            flag: Cell::new(false),
+            waiters: Waiters::new(),
+        })
+    }
+
+    /// Returns `True` if the event's flag is set.
+    #[method]
+    fn is_set(&self) -> bool {
+        self.flag.get()
+    }
+
+    /// Sets the event's flag and wakes every task waiting for it.
+    #[method]
+    fn set(&self) {
+        self.flag.set(true);
+        while let Some(waiter) = self.waiters.pop() {
+            SyncFuture::grant(waiter);
+        }
+    }
+
+    /// Clears the event's flag so that subsequent calls to `Event.wait` block again.
+    #[method]
+    fn clear(&self) {
+        self.flag.set(false);
+    }
+
+    /// Waits until the event's flag is set.
+    ///
+    /// Returns `True` immediately if the flag is already set. A task woken by `Event.set` returns
+    /// `True` even if the flag is cleared again before the task resumes.
+    #[method]
+    #[stub(sig = "(self, /) -> SyncFuture")]
+    fn wait(self_in: Obj) -> SyncFuture {
+        SyncFuture::new(SyncOp::EventWait, self_in)
+    }
+}
+
+/// A mutual exclusion lock for tasks.
+///
+/// A `Lock` guarantees exclusive access to a shared resource, such as a drivetrain or an intake,
+/// across tasks. `Lock.acquire` waits until the lock is free and then takes it; `Lock.release`
+/// frees it and hands it to the longest-waiting task. Locks are not owned by a particular task, so
+/// any task may release a lock.
+///
+/// A lock can also be held for the duration of an `async with` block, which acquires it on entry
+/// and releases it on exit, even if the block raises.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// drivetrain_lock = vasyncio.Lock()
+///
+/// async def auto_align():
+///     async with drivetrain_lock:
+///         ...
+/// ```
+#[class(qstr!(Lock))]
+#[repr(C)]
+pub struct Lock {
+    base: ObjBase,
+    locked: Cell<bool>,
+    waiters: Waiters,
+}
+
+impl Lock {
+    pub fn new() -> Self {
+        Self {
+            base: Self::OBJ_TYPE.into(),
+            locked: Cell::new(false),
+            waiters: Waiters::new(),
+        }
+    }
+
+    /// Takes the lock if it is free and no other task is already waiting for it.
+    fn try_acquire(&self) -> bool {
+        if self.locked.get() || !self.waiters.is_empty() {
+            return false;
+        }
+        self.locked.set(true);
+        true
+    }
+
+    /// Frees the lock, handing it directly to the next waiter if there is one.
+    fn release_inner(&self) {
+        match self.waiters.pop() {
+            Some(waiter) => SyncFuture::grant(waiter),
+            None => self.locked.set(false),
+        }
+    }
+
+    fn release_checked(&self) -> Result<(), Exception> {
+        if !self.locked.get() {
+            Err(runtime_error(c"Lock is not acquired"))?
+        }
+        self.release_inner();
+        Ok(())
+    }
+}
+
+#[class_methods]
+impl Lock {
+    /// Creates an unlocked lock.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If any arguments are supplied.
+    #[make_new]
+    #[stub(sig = "(self, /) -> None")]
+    fn make_new(
+        _: &ObjType,
+        _n_args: usize,
+        _n_kw: usize,
+        args: &[Obj],
+    ) -> Result<Self, Exception> {
+        if args.len() != 0 {
+            Err(type_error(
+                c"constructor does not accept arguments; just call Lock()",
+            ))?
+        }
+
+        Ok(Self::new())
+    }
+
+    /// Returns `True` if the lock is held.
+    #[method]
+    fn locked(&self) -> bool {
+        self.locked.get()
+    }
+
+    /// Waits until the lock is free, then takes it.
+    ///
+    /// Returns `True` once the lock is held. Tasks acquire the lock in the order they started
+    /// waiting.
+    #[method]
+    #[stub(sig = "(self, /) -> SyncFuture")]
+    fn acquire(self_in: Obj) -> SyncFuture {
+        SyncFuture::new(SyncOp::LockAcquire, self_in)
+    }
+
+    /// Releases the lock, waking the next task waiting to acquire it.
+    ///
+    /// # Raises
+    ///
+    /// - `RuntimeError`: If the lock isn't held.
+    #[method]
+    fn release(&self) -> Result<(), Exception> {
+        self.release_checked()
+    }
+
+    /// Acquires the lock on entry to an `async with` block.
+    #[method]
+    #[stub(sig = "(self, /) -> SyncFuture")]
+    fn __aenter__(self_in: Obj) -> SyncFuture {
+        SyncFuture::new(SyncOp::LockAcquire, self_in)
+    }
+
+    /// Releases the lock on exit from an `async with` block.
+    ///
+    /// # Raises
+    ///
+    /// - `RuntimeError`: If the lock was released inside the block.
+    #[method(ty = var_between(min = 4, max = 4))]
+    #[stub(sig = "(self, exc_type: Any, exc: Any, tb: Any, /) -> ExitFuture")]
+    fn __aexit__(args: &[Obj]) -> Result<ExitFuture, Exception> {
+        args[0].as_obj::<Self>().release_checked()?;
+        Ok(ExitFuture::new())
+    }
+}
+
+/// A counter-based limit on how many tasks may use a resource at once.
+///
+/// A `Semaphore` holds a number of free slots. `Semaphore.acquire` waits until a slot is free and
+/// takes it; `Semaphore.release` returns a slot, handing it to the longest-waiting task if there is
+/// one. An `async with` block holds a slot for its duration.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// # Allow at most two tasks to use the radio at a time.
+/// radio_slots = vasyncio.Semaphore(2)
+///
+/// async def send_telemetry():
+///     async with radio_slots:
+///         ...
+/// ```
+#[class(qstr!(Semaphore))]
+#[repr(C)]
+pub struct Semaphore {
+    base: ObjBase,
+    value: Cell<i32>,
+    waiters: Waiters,
+}
+
+impl Semaphore {
+    fn try_acquire(&self) -> bool {
+        if self.value.get() == 0 || !self.waiters.is_empty() {
+            return false;
+        }
+        self.value.set(self.value.get() - 1);
+        true
+    }
+
+    fn release_inner(&self) {
+        match self.waiters.pop() {
+            Some(waiter) => SyncFuture::grant(waiter),
+            None => self.value.set(self.value.get().saturating_add(1)),
+        }
+    }
+}
+
+#[class_methods]
+impl Semaphore {
+    /// Creates a semaphore with `value` free slots.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If keyword arguments or more than one positional argument are supplied.
+    /// - `ValueError`: If `value` is negative.
+    #[make_new]
+    #[stub(sig = "(self, value: int = 1, /) -> None")]
+    fn make_new(_: &ObjType, n_pos: usize, n_kw: usize, args: &[Obj]) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(0, 1).assert_nkw(0, 0);
+
+        let value = if n_pos == 0 {
+            1
+        } else {
+            reader.next_positional_with(IntParser::new(0..=i32::MAX))?
+        };
+
+        Ok(Self {
+            base: Self::OBJ_TYPE.into(),
+            value: Cell::new(value),
+            waiters: Waiters::new(),
+        })
+    }
+
+    /// Returns `True` if no slot is free.
+    #[method]
+    fn locked(&self) -> bool {
+        self.value.get() == 0
+    }
+
+    /// Waits until a slot is free, then takes it.
+    ///
+    /// Returns `True` once a slot is held. Tasks acquire slots in the order they started waiting.
+    #[method]
+    #[stub(sig = "(self, /) -> SyncFuture")]
+    fn acquire(self_in: Obj) -> SyncFuture {
+        SyncFuture::new(SyncOp::SemaphoreAcquire, self_in)
+    }
+
+    /// Returns a slot, waking the next task waiting to acquire one.
+    #[method]
+    fn release(&self) {
+        self.release_inner();
+    }
+
+    /// Acquires a slot on entry to an `async with` block.
+    #[method]
+    #[stub(sig = "(self, /) -> SyncFuture")]
+    fn __aenter__(self_in: Obj) -> SyncFuture {
+        SyncFuture::new(SyncOp::SemaphoreAcquire, self_in)
+    }
+
+    /// Returns the slot on exit from an `async with` block.
+    #[method(ty = var_between(min = 4, max = 4))]
+    #[stub(sig = "(self, exc_type: Any, exc: Any, tb: Any, /) -> ExitFuture")]
+    fn __aexit__(args: &[Obj]) -> ExitFuture {
+        args[0].as_obj::<Self>().release_inner();
+        ExitFuture::new()
+    }
+}
+
+/// A condition variable that lets tasks wait for a notification while sharing a `Lock`.
+///
+/// A task must hold the condition's lock to call `Condition.wait`, which releases the lock, waits
+/// for `Condition.notify` or `Condition.notify_all`, then reacquires the lock before returning.
+/// Notifications are not remembered, so a waiting task should check its condition in a loop.
+/// An `async with` block holds the condition's lock for its duration.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// rings = []
+/// ring_added = vasyncio.Condition()
+///
+/// async def consumer():
+///     async with ring_added:
+///         while not rings:
+///             await ring_added.wait()
+///         print("scoring", rings.pop(0))
+///
+/// async def producer():
+///     async with ring_added:
+///         rings.append("red")
+///         ring_added.notify()
+/// ```
+#[class(qstr!(Condition))]
+#[repr(C)]
+pub struct Condition {
+    base: ObjBase,
+    lock: Obj,
+    waiters: Waiters,
+}
+
+#[class_methods]
+impl Condition {
+    /// Creates a condition variable using `lock`, or a new `Lock` if `lock` is `None`.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If `lock` is neither a `Lock` nor `None`, or if keyword arguments or more
+    ///   than one positional argument are supplied.
+    #[make_new]
+    #[stub(sig = "(self, lock: Lock | None = None, /) -> None")]
+    fn make_new(_: &ObjType, n_pos: usize, n_kw: usize, args: &[Obj]) -> Result<Self, Exception> {
+        let mut reader = Args::new(n_pos, n_kw, args).reader();
+        reader.assert_npos(0, 1).assert_nkw(0, 0);
+
+        let lock = reader.next_positional_or(Obj::NONE)?;
+        let lock = if lock.is_none() {
+            alloc_obj(Lock::new())
+        } else if lock.try_as_obj::<Lock>().is_some() {
+            lock
+        } else {
+            Err(type_error(error_msg!(
+                "expected 'Lock' or 'None', found '{}'",
+                type_name(&lock)
+            )))?
+        };
+
+        Ok(Self {
+            base: Self::OBJ_TYPE.into(),
+            lock,
+            waiters: Waiters::new(),
+        })
+    }
+
+    /// Returns `True` if the underlying lock is held.
+    #[method]
+    fn locked(&self) -> bool {
+        self.lock.as_obj::<Lock>().locked.get()
+    }
+
+    /// Waits until the underlying lock is free, then takes it.
+    #[method]
+    #[stub(sig = "(self, /) -> SyncFuture")]
+    fn acquire(&self) -> SyncFuture {
+        SyncFuture::new(SyncOp::LockAcquire, self.lock)
+    }
+
+    /// Releases the underlying lock.
+    ///
+    /// # Raises
+    ///
+    /// - `RuntimeError`: If the lock isn't held.
+    #[method]
+    fn release(&self) -> Result<(), Exception> {
+        self.lock.as_obj::<Lock>().release_checked()
+    }
+
+    /// Acquires the underlying lock on entry to an `async with` block.
+    #[method]
+    #[stub(sig = "(self, /) -> SyncFuture")]
+    fn __aenter__(&self) -> SyncFuture {
+        SyncFuture::new(SyncOp::LockAcquire, self.lock)
+    }
+
+    /// Releases the underlying lock on exit from an `async with` block.
+    ///
+    /// # Raises
+    ///
+    /// - `RuntimeError`: If the lock was released inside the block.
+    #[method(ty = var_between(min = 4, max = 4))]
+    #[stub(sig = "(self, exc_type: Any, exc: Any, tb: Any, /) -> ExitFuture")]
+    fn __aexit__(args: &[Obj]) -> Result<ExitFuture, Exception> {
+        args[0]
+            .as_obj::<Self>()
+            .lock
+            .as_obj::<Lock>()
+            .release_checked()?;
+        Ok(ExitFuture::new())
+    }
+
+    /// Releases the underlying lock, waits until notified, then reacquires the lock.
+    ///
+    /// Returns `True` once the lock is held again. If the waiting task is cancelled, the lock is
+    /// still reacquired before `CancelledError` propagates.
+    ///
+    /// # Raises
+    ///
+    /// - `RuntimeError`: If the underlying lock isn't held when the wait starts.
+    #[method]
+    #[stub(sig = "(self, /) -> SyncFuture")]
+    fn wait(self_in: Obj) -> SyncFuture {
+        SyncFuture::new(SyncOp::ConditionWait, self_in)
+    }
+
+    /// Wakes up to `n` tasks waiting on this condition.
+    ///
+    /// Woken tasks resume once they have reacquired the underlying lock.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If keyword arguments or more than one positional argument are supplied.
+    /// - `ValueError`: If `n` is negative.
+    #[method(ty = var_between(min = 1, max = 2))]
+    #[stub(sig = "(self, n: int = 1, /) -> None")]
+    fn notify(args: &[Obj]) -> Result<(), Exception> {
+        let mut reader = Args::new(args.len(), 0, args).reader();
+
+        let this = reader.next_positional::<&Self>()?;
+        let n = if args.len() == 1 {
+            1
+        } else {
+            reader.next_positional_with(IntParser::new(0..=i32::MAX))?
+        };
+
+        for _ in 0..n {
+            let Some(waiter) = this.waiters.pop() else {
+                break;
+            };
+            SyncFuture::resume_with(waiter, SyncState::Notified);
+        }
+        Ok(())
+    }
+
+    /// Wakes every task waiting on this condition.
+    #[method]
+    fn notify_all(&self) {
+        while let Some(waiter) = self.waiters.pop() {
+            SyncFuture::resume_with(waiter, SyncState::Notified);
+        }
+    }
+}
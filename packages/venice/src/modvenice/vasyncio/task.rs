@@ -1,13 +1,36 @@
 use std::cell::{Cell, RefCell};
 
+use argparse::Callable;
 use micropython_macros::{class, class_methods};
 use micropython_rs::{
-    except::raise_stop_iteration,
+    except::{
+        BASE_EXCEPTION_TYPE, exception_matches, new_exception_type, raise_stop_iteration,
+        runtime_error, type_error,
+    },
+    generator::GEN_INSTANCE_TYPE,
     init::token,
-    obj::{Obj, ObjBase, ObjTrait},
+    nlr,
+    obj::{AttrOp, Obj, ObjBase, ObjFullType, ObjTrait},
+    print::{Print, PrintKind},
+    qstr::Qstr,
+    str::Str,
 };
 
-use crate::alloc::Gc;
+use super::{event_loop::EventLoop, time32};
+use crate::{
+    alloc::Gc,
+    modvenice::{Exception, read_only_attr::read_only_attr, units::time::TimeUnitObj},
+};
+
+/// Number of times a ready task must be passed over before its effective priority rises by one.
+pub const AGING_INTERVAL: u32 = 4;
+
+/// Raised inside a task's coroutine when the task is cancelled.
+///
+/// Like CPython's `asyncio.CancelledError`, this derives from `BaseException` so that a broad
+/// `except Exception:` clause does not accidentally swallow a cancellation.
+pub static CANCELLED_ERROR_TYPE: ObjFullType =
+    new_exception_type(qstr!(CancelledError), BASE_EXCEPTION_TYPE);
 
 /// A spawned task.
 ///
@@ -17,8 +40,16 @@ use crate::alloc::Gc;
 /// `vasyncio` submodule and is not constructed by users. Awaiting a task cooperatively waits for
 /// its coroutine and returns that coroutine's return value, including when the task completed
 /// before the await began. Direct or transitive cycles between awaited tasks raise `RuntimeError`.
-/// A coroutine exception propagates out of the running event loop. `Task` objects cannot be
-/// cancelled.
+///
+/// If the coroutine raises an exception, the task stores it and every task awaiting it receives the
+/// same exception. A `CancelledError` marks the task as cancelled. Any other exception that nothing
+/// observes, because no task is awaiting the failed task, is passed to the loop's exception
+/// handler; see `EventLoop.set_exception_handler`. Without a handler, it propagates out of the
+/// running event loop. Tasks created with `vasyncio.supervise` are restarted instead of failing.
+///
+/// Every task has a name, which defaults to `Task-<n>` and can be chosen with the `name` argument
+/// of `vasyncio.spawn`. The loop also keeps run time statistics for each task; see `Task.stats`.
+/// Tasks can be given a scheduling priority; see `Task.set_priority`.
 ///
 /// # Examples
 ///
@@ -42,26 +73,149 @@ use crate::alloc::Gc;
 #[repr(C)]
 pub struct Task {
     base: ObjBase,
-    // generator object
-    coro: Obj,
+    // generator object, replaced when a supervised task restarts
+    coro: Cell<Obj>,
     waiting_tasks: RefCell<Vec<Obj, Gc>>,
     waiting_on: Cell<Obj>,
     return_val: Cell<Obj>,
+    // exception the coroutine exited by raising, including `CancelledError`
+    error: Cell<Obj>,
+    cancel_requested: Cell<bool>,
+    // whether anything has awaited the task or asked for its outcome
+    observed: Cell<bool>,
+    // creates a fresh coroutine when a supervised task restarts
+    factory: Cell<Option<Callable>>,
+    max_restarts: Cell<Option<u32>>,
+    restarts: Cell<u32>,
+    event_loop: Obj,
+    // sequence number used for the default name
+    id: u32,
+    // str object, or null to use the default name
+    name: Cell<Obj>,
+    priority: Cell<i32>,
+    // times the task was ready but another task was resumed instead, for starvation protection
+    passed_over: Cell<u32>,
+    resumes: Cell<u32>,
+    total_run_time: Cell<time32::Duration>,
+    max_run_time: Cell<time32::Duration>,
 }
 
 impl Task {
-    pub fn new(coro: Obj) -> Self {
+    pub fn new(coro: Obj, event_loop: Obj, id: u32, name: Obj) -> Self {
         Self {
             base: Self::OBJ_TYPE.into(),
-            coro,
+            coro: Cell::new(coro),
             waiting_tasks: RefCell::new(Vec::new_in(Gc { token: token() })),
             waiting_on: Cell::new(Obj::NULL),
             return_val: Cell::new(Obj::NULL),
+            error: Cell::new(Obj::NULL),
+            cancel_requested: Cell::new(false),
+            observed: Cell::new(false),
+            factory: Cell::new(None),
+            max_restarts: Cell::new(None),
+            restarts: Cell::new(0),
+            event_loop,
+            id,
+            name: Cell::new(name),
+            priority: Cell::new(0),
+            passed_over: Cell::new(0),
+            resumes: Cell::new(0),
+            total_run_time: Cell::new(time32::Duration::ZERO),
+            max_run_time: Cell::new(time32::Duration::ZERO),
+        }
+    }
+
+    /// Returns the task's name as a str object, generating the default name if none was given.
+    pub fn name(&self) -> Obj {
+        let name = self.name.get();
+        if !name.is_null() {
+            return name;
         }
+
+        let name = Str::new(&format!("Task-{}", self.id));
+        self.name.set(name);
+        name
+    }
+
+    pub fn prioritize(&self, priority: i32) {
+        self.priority.set(priority);
+    }
+
+    /// Returns the priority used to pick the next ready task, raised by how long it has waited.
+    pub fn effective_priority(&self) -> i64 {
+        self.priority.get() as i64 + (self.passed_over.get() / AGING_INTERVAL) as i64
+    }
+
+    pub fn pass_over(&self) {
+        self.passed_over
+            .set(self.passed_over.get().saturating_add(1));
+    }
+
+    pub fn clear_passed_over(&self) {
+        self.passed_over.set(0);
+    }
+
+    /// Accounts for one resume of the task's coroutine that ran for `run_time`.
+    pub fn record_resume(&self, run_time: time32::Duration) {
+        self.resumes.set(self.resumes.get().saturating_add(1));
+        if let Some(total) = self.total_run_time.get().checked_add(run_time) {
+            self.total_run_time.set(total);
+        }
+        if run_time > self.max_run_time.get() {
+            self.max_run_time.set(run_time);
+        }
+    }
+
+    pub fn event_loop(&self) -> Obj {
+        self.event_loop
     }
 
     pub fn coro(&self) -> Obj {
-        self.coro
+        self.coro.get()
+    }
+
+    /// Makes the task restart with a fresh coroutine from `factory` when it fails, at most
+    /// `max_restarts` times.
+    pub fn supervise(&self, factory: Callable, max_restarts: Option<u32>) {
+        self.factory.set(Some(factory));
+        self.max_restarts.set(max_restarts);
+    }
+
+    /// Returns `true` if the task is supervised and has restarts left.
+    pub fn can_restart(&self) -> bool {
+        self.factory.get().is_some()
+            && self
+                .max_restarts
+                .get()
+                .is_none_or(|max| self.restarts.get() < max)
+    }
+
+    /// Replaces the task's coroutine with a fresh one from its factory.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If the factory doesn't return a coroutine object.
+    pub fn restart(&self) {
+        let Some(factory) = self.factory.get() else {
+            return;
+        };
+
+        let coro = factory.call(0, &[]);
+        if !coro.is(GEN_INSTANCE_TYPE) {
+            type_error(c"supervised task factory must return a coroutine").raise(token());
+        }
+
+        self.coro.set(coro);
+        self.restarts.set(self.restarts.get().saturating_add(1));
+    }
+
+    /// Marks the task's outcome as handled, so that a failure isn't reported as unobserved.
+    pub fn observe(&self) {
+        self.observed.set(true);
+    }
+
+    pub fn is_observed(&self) -> bool {
+        self.observed.get()
     }
 
     pub fn add_waiting_task(&self, task: Obj) {
@@ -72,6 +226,12 @@ impl Task {
         self.waiting_tasks.borrow_mut().pop()
     }
 
+    pub fn remove_waiting_task(&self, task: Obj) {
+        self.waiting_tasks
+            .borrow_mut()
+            .retain(|waiting| waiting.inner() != task.inner());
+    }
+
     pub fn waiting_on(&self) -> Obj {
         self.waiting_on.get()
     }
@@ -84,13 +244,61 @@ impl Task {
         self.waiting_on.set(Obj::NULL);
     }
 
+    pub fn return_value(&self) -> Obj {
+        self.return_val.get()
+    }
+
+    pub fn error(&self) -> Obj {
+        self.error.get()
+    }
+
     pub fn is_complete(&self) -> bool {
-        !self.return_val.get().is_null()
+        !self.return_val.get().is_null() || self.has_failed()
+    }
+
+    /// Returns `true` if the coroutine exited by raising an exception, including `CancelledError`.
+    pub fn has_failed(&self) -> bool {
+        !self.error.get().is_null()
     }
 
     pub fn complete_with(&self, val: Obj) {
+        self.cancel_requested.set(false);
         self.return_val.set(val);
     }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.has_failed() && exception_matches(self.error.get(), CANCELLED_ERROR_TYPE.as_obj_type())
+    }
+
+    pub fn fail_with(&self, exc: Obj) {
+        self.cancel_requested.set(false);
+        self.error.set(exc);
+    }
+
+    pub fn request_cancel(&self) {
+        self.cancel_requested.set(true);
+    }
+
+    pub fn is_cancel_requested(&self) -> bool {
+        self.cancel_requested.get()
+    }
+
+    /// Clears a pending cancellation request, returning whether one was pending.
+    pub fn take_cancel_request(&self) -> bool {
+        self.cancel_requested.replace(false)
+    }
+
+    /// Requests cancellation of `task_obj` through the event loop it was spawned on, returning
+    /// `false` if the task had already finished.
+    pub fn cancel_task(task_obj: Obj) -> bool {
+        let task = task_obj.as_obj::<Task>();
+        if task.is_complete() {
+            return false;
+        }
+
+        task.event_loop.as_obj::<EventLoop>().cancel(task_obj);
+        true
+    }
 }
 
 #[class_methods]
@@ -98,10 +306,243 @@ impl Task {
     #[iter]
     extern "C" fn task_iternext(self_in: Obj) -> Obj {
         let task = self_in.as_obj::<Task>();
-        if !task.is_complete() {
+        task.observe();
+        if task.has_failed() {
+            nlr::raise(token(), task.error.get())
+        } else if !task.is_complete() {
             self_in
         } else {
             raise_stop_iteration(token(), task.return_val.get())
         }
     }
+
+    /// Requests cancellation of this task.
+    ///
+    /// A `CancelledError` is thrown into the task's coroutine at the `await` point where it is
+    /// currently suspended, the next time the event loop runs. Pending sleeps are abandoned, so
+    /// `try`/`finally` blocks in the coroutine run promptly and can perform cleanup such as
+    /// stopping motors. If the coroutine lets the error propagate, the task becomes cancelled and
+    /// every task awaiting it receives the same `CancelledError`. A coroutine may catch the error
+    /// to refuse cancellation, in which case it keeps running normally.
+    ///
+    /// A task that cancels itself receives the `CancelledError` at its next `await` point.
+    ///
+    /// Returns `True` if cancellation was requested, or `False` if the task had already finished.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// motor = Motor(1)
+    ///
+    /// async def spin():
+    ///     try:
+    ///         motor.set_voltage(6)
+    ///         await vasyncio.Sleep(10, SECOND)
+    ///     finally:
+    ///         motor.brake(BrakeMode.COAST)
+    ///
+    /// async def main():
+    ///     task = vasyncio.spawn(spin())
+    ///     await vasyncio.Sleep(1, SECOND)
+    ///     task.cancel()
+    ///
+    ///     try:
+    ///         await task
+    ///     except vasyncio.CancelledError:
+    ///         print("spin was cancelled")
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    #[method]
+    #[stub(sig = "(self, /) -> bool")]
+    fn cancel(self_in: Obj) -> bool {
+        Self::cancel_task(self_in)
+    }
+
+    /// Returns `True` if the task was cancelled.
+    #[method]
+    fn cancelled(&self) -> bool {
+        self.is_cancelled()
+    }
+
+    /// Returns `True` if the task has finished, either by returning, by raising an exception, or by
+    /// being cancelled.
+    #[method]
+    fn done(&self) -> bool {
+        self.is_complete()
+    }
+
+    /// Returns the task's return value.
+    ///
+    /// # Raises
+    ///
+    /// - The exception the task raised, if it failed or was cancelled.
+    /// - `RuntimeError`: If the task hasn't finished.
+    #[method]
+    #[stub(sig = "(self, /) -> Any")]
+    fn result(&self) -> Result<Obj, Exception> {
+        if !self.is_complete() {
+            Err(runtime_error(c"task is not done"))?
+        }
+
+        self.observe();
+        if self.has_failed() {
+            nlr::raise(token(), self.error.get())
+        }
+        Ok(self.return_val.get())
+    }
+
+    /// Returns the exception the task raised, or `None` if it returned normally.
+    ///
+    /// Retrieving the exception marks the failure as handled.
+    ///
+    /// # Raises
+    ///
+    /// - `CancelledError`: If the task was cancelled.
+    /// - `RuntimeError`: If the task hasn't finished.
+    #[method]
+    #[stub(sig = "(self, /) -> BaseException | None")]
+    fn exception(&self) -> Result<Obj, Exception> {
+        if !self.is_complete() {
+            Err(runtime_error(c"task is not done"))?
+        }
+
+        self.observe();
+        if self.is_cancelled() {
+            nlr::raise(token(), self.error.get())
+        }
+        Ok(if self.has_failed() {
+            self.error.get()
+        } else {
+            Obj::NONE
+        })
+    }
+
+    /// Returns the task's name.
+    #[method]
+    #[stub(sig = "(self, /) -> str")]
+    fn get_name(&self) -> Obj {
+        self.name()
+    }
+
+    /// Renames the task.
+    #[method]
+    fn set_name(&self, name: &str) {
+        self.name.set(Str::new(name));
+    }
+
+    /// Returns the task's scheduling priority.
+    #[method]
+    fn get_priority(&self) -> i32 {
+        self.priority.get()
+    }
+
+    /// Changes the task's scheduling priority.
+    ///
+    /// When several tasks are ready to run, the event loop resumes the one with the highest
+    /// priority first, and tasks with equal priority run in the order they became ready. To keep
+    /// busy high-priority tasks from starving the rest, a ready task's priority is temporarily
+    /// raised by one for every few times another task is resumed ahead of it, and drops back once
+    /// it runs.
+    ///
+    /// Priorities only decide which ready task runs next. A running task is never interrupted, so
+    /// a high-priority task still has to wait for the current task to yield.
+    #[method]
+    fn set_priority(&self, priority: i32) {
+        self.priority.set(priority);
+    }
+
+    /// Returns a snapshot of the task's run time statistics.
+    ///
+    /// The event loop measures how long each resume of the task's coroutine runs before it yields
+    /// again. A task whose maximum run time is large is blocking other tasks from running, usually
+    /// because of a long loop without an `await`.
+    ///
+    /// # Examples
+    ///
+    /// ```python
+    /// from venice import *
+    ///
+    /// async def main():
+    ///     await vasyncio.Sleep(5, SECOND)
+    ///     for task in vasyncio.all_tasks():
+    ///         stats = task.stats()
+    ///         print(task.get_name(), stats.resumes, stats.max_time(MILLIS))
+    ///
+    /// vasyncio.run(main())
+    /// ```
+    #[method]
+    fn stats(&self) -> TaskStats {
+        TaskStats {
+            base: TaskStats::OBJ_TYPE.into(),
+            resumes: self.resumes.get(),
+            restarts: self.restarts.get(),
+            total_time: self.total_run_time.get(),
+            max_time: self.max_run_time.get(),
+        }
+    }
+
+    #[printer]
+    fn printer(&self, print: &mut Print, _kind: PrintKind) {
+        let state = if self.is_cancelled() {
+            "cancelled"
+        } else if self.is_complete() {
+            "done"
+        } else {
+            "pending"
+        };
+        let name = self.name();
+        let name = name.get_str().unwrap_or_default();
+        print.print(&format!("<Task '{name}' {state}>"));
+    }
+}
+
+/// Run time statistics of a `Task`, returned by `Task.stats`.
+///
+/// `resumes` is the number of times the event loop has resumed the task's coroutine, and
+/// `restarts` is the number of times a supervised task has been restarted after failing. The run
+/// time of a resume is measured from when the loop resumes the coroutine until it yields, returns,
+/// or raises.
+#[class(qstr!(TaskStats))]
+#[repr(C)]
+pub struct TaskStats {
+    base: ObjBase,
+    resumes: u32,
+    restarts: u32,
+    total_time: time32::Duration,
+    max_time: time32::Duration,
+}
+
+#[class_methods]
+impl TaskStats {
+    #[attr]
+    #[stub(attrs = ["resumes: int", "restarts: int"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let count = match attr.as_str() {
+            "resumes" => self.resumes,
+            "restarts" => self.restarts,
+            _ => return,
+        };
+
+        match op {
+            AttrOp::Load { result } => {
+                result.return_value(Obj::from_int(count.min(i32::MAX as u32) as i32))
+            }
+            _ => read_only_attr::<Self>(),
+        }
+    }
+
+    /// Returns the combined run time of every resume, measured in `unit`.
+    #[method]
+    fn total_time(&self, unit: &TimeUnitObj) -> f32 {
+        unit.unit().dur_to_float(self.total_time.to_duration())
+    }
+
+    /// Returns the run time of the longest single resume, measured in `unit`.
+    #[method]
+    fn max_time(&self, unit: &TimeUnitObj) -> f32 {
+        unit.unit().dur_to_float(self.max_time.to_duration())
+    }
 }
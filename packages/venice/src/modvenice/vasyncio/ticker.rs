@@ -0,0 +1,213 @@
+use std::cell::Cell;
+
+use argparse::Args;
+use micropython_macros::{class, class_methods};
+use micropython_rs::{
+    except::{raise_stop_iteration, value_error},
+    init::token,
+    obj::{AttrOp, Obj, ObjBase, ObjTrait, ObjType},
+    qstr::Qstr,
+};
+
+use super::{polled::PolledFuture, time32};
+use crate::modvenice::{Exception, read_only_attr::read_only_attr, units::time::TimeUnitObj};
+
+/// A periodic timer whose ticks fire on a fixed schedule.
+///
+/// Awaiting a `Ticker` waits until its next tick. Unlike sleeping for the period at the end of a
+/// loop, tick deadlines are computed from the time of the first tick rather than from when the
+/// previous iteration finished, so the time spent doing work between ticks does not make the loop
+/// drift. This keeps control loops, such as PID or odometry updates, running at a consistent rate.
+///
+/// The first tick fires one period after the ticker is first awaited. If an iteration runs long
+/// and the deadline has already passed when the ticker is awaited, it returns immediately and counts
+/// an *overrun*. If an entire period or more has been lost, the ticks that were skipped are counted
+/// as *missed* and the schedule resumes at the next deadline that is still in the future, so the
+/// ticker never fires a burst of ticks to catch up.
+///
+/// Awaiting a `Ticker` returns the number of ticks that were missed since the previous tick.
+///
+/// The `ticks`, `missed`, and `overruns` attributes report how many ticks have fired, how many
+/// were skipped, and how many fired late.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// motor = Motor(1)
+///
+/// async def main():
+///     ticker = vasyncio.Ticker(10, MILLIS)
+///     while True:
+///         missed = await ticker
+///         if missed:
+///             print("control loop missed", missed, "ticks")
+///         motor.set_voltage(6.0)
+///
+/// vasyncio.run(main())
+/// ```
+#[class(qstr!(Ticker))]
+#[repr(C)]
+pub struct Ticker {
+    base: ObjBase,
+    schedule: Schedule,
+}
+
+/// A drift-free schedule of periodic deadlines.
+pub(crate) struct Schedule {
+    period: time32::Duration,
+    deadline: Cell<Option<time32::Instant>>,
+    // whether the current await has yielded to the event loop
+    waited: Cell<bool>,
+    ticks: Cell<u32>,
+    missed: Cell<u32>,
+    overruns: Cell<u32>,
+}
+
+impl Schedule {
+    pub(crate) fn new(period: time32::Duration) -> Result<Self, Exception> {
+        if period.as_micros() == 0 {
+            Err(value_error(c"period must be greater than zero"))?
+        }
+
+        Ok(Self {
+            period,
+            deadline: Cell::new(None),
+            waited: Cell::new(false),
+            ticks: Cell::new(0),
+            missed: Cell::new(0),
+            overruns: Cell::new(0),
+        })
+    }
+
+    /// Returns the deadline of the next tick, if the schedule has started.
+    pub(crate) fn deadline(&self) -> Option<time32::Instant> {
+        self.deadline.get()
+    }
+
+    /// Restarts the schedule the next time it is polled, keeping its counters.
+    pub(crate) fn reset(&self) {
+        self.deadline.set(None);
+        self.waited.set(false);
+    }
+
+    /// Starts the schedule if needed and checks whether a tick is due.
+    ///
+    /// Returns the number of ticks missed since the previous tick if one is due, or `None` if the
+    /// caller should wait until `Schedule::deadline`.
+    pub(crate) fn poll(&self) -> Option<u32> {
+        let now = time32::Instant::now();
+
+        let deadline = match self.deadline.get() {
+            Some(deadline) => deadline,
+            None => {
+                let deadline = Self::add_to(now, self.period);
+                self.deadline.set(Some(deadline));
+                deadline
+            }
+        };
+
+        if now < deadline {
+            self.waited.set(true);
+            return None;
+        }
+
+        let period_micros = self.period.as_micros();
+        let missed = (now - deadline).as_micros() / period_micros;
+        let next_deadline = Self::add_to(
+            deadline,
+            time32::Duration::from_micros(period_micros.saturating_mul(missed + 1)),
+        );
+        self.deadline.set(Some(next_deadline));
+
+        let missed = missed.min(u32::MAX as u64) as u32;
+        if !self.waited.replace(false) {
+            self.overruns.set(self.overruns.get().saturating_add(1));
+        }
+        self.ticks.set(self.ticks.get().saturating_add(1));
+        self.missed.set(self.missed.get().saturating_add(missed));
+
+        Some(missed)
+    }
+
+    fn add_to(instant: time32::Instant, duration: time32::Duration) -> time32::Instant {
+        instant
+            .checked_add(duration)
+            .unwrap_or_else(|| value_error(c"ticker deadline is too large").raise(token()))
+    }
+}
+
+impl PolledFuture for Ticker {
+    /// Returns the deadline of the next tick, if the ticker has been awaited.
+    fn next_poll(&self) -> Option<time32::Instant> {
+        self.schedule.deadline()
+    }
+}
+
+#[class_methods]
+impl Ticker {
+    /// Creates a ticker that fires every `period`, measured in `unit`.
+    ///
+    /// Use `MILLIS` for milliseconds or `SECOND` for seconds. The schedule starts the first time the
+    /// ticker is awaited.
+    ///
+    /// # Raises
+    ///
+    /// - `TypeError`: If any keyword argument is supplied.
+    /// - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+    #[make_new]
+    #[stub(sig = "(self, period: float, unit: TimeUnit, /) -> None")]
+    fn make_new(_: &ObjType, n_pos: usize, n_kw: usize, args: &[Obj]) -> Result<Self, Exception> {
+        let mut args = Args::new(n_pos, n_kw, args).reader();
+        args.assert_npos(2, 2).assert_nkw(0, 0);
+
+        let period = args.next_positional()?;
+        let unit = args.next_positional::<&TimeUnitObj>()?.unit();
+
+        let period = time32::Duration::from_duration(unit.float_to_dur(period)?);
+
+        Ok(Self {
+            base: Self::OBJ_TYPE.into(),
+            schedule: Schedule::new(period)?,
+        })
+    }
+
+    #[attr]
+    #[stub(attrs = ["ticks: int", "missed: int", "overruns: int"])]
+    fn attr(&self, attr: Qstr, op: AttrOp) {
+        let count = match attr.as_str() {
+            "ticks" => self.schedule.ticks.get(),
+            "missed" => self.schedule.missed.get(),
+            "overruns" => self.schedule.overruns.get(),
+            _ => return,
+        };
+
+        match op {
+            AttrOp::Load { result } => {
+                result.return_value(Obj::from_int(count.min(i32::MAX as u32) as i32))
+            }
+            _ => read_only_attr::<Self>(),
+        }
+    }
+
+    /// Restarts the schedule so that the next tick fires one period after the ticker is next
+    /// awaited.
+    ///
+    /// The `ticks`, `missed`, and `overruns` counters are left unchanged.
+    #[method]
+    fn reset(&self) {
+        self.schedule.reset();
+    }
+
+    #[iter]
+    extern "C" fn ticker_iternext(self_in: Obj) -> Obj {
+        let ticker = self_in.as_obj::<Self>();
+        match ticker.schedule.poll() {
+            Some(missed) => {
+                raise_stop_iteration(token(), Obj::from_int(missed.min(i32::MAX as u32) as i32))
+            }
+            None => ticker.pend(self_in),
+        }
+    }
+}
@@ -35,6 +35,8 @@ impl Nanoseconds {
 }
 
 impl Duration {
+    pub const ZERO: Self = Self::new(0, Nanoseconds(0));
+
     pub const fn new(secs: u64, nanos: Nanoseconds) -> Self {
         Self {
             secs_hi: (secs >> 32) as u32,
@@ -47,6 +49,10 @@ impl Duration {
         Self::new(duration.as_secs(), Nanoseconds(duration.subsec_nanos()))
     }
 
+    pub const fn to_duration(self) -> std::time::Duration {
+        std::time::Duration::new(self.secs(), self.nanos.0)
+    }
+
     pub const fn secs(&self) -> u64 {
         (self.secs_lo as u64) | (self.secs_hi as u64) << 32
     }
@@ -57,6 +63,10 @@ impl Duration {
         Self::new(secs, Nanoseconds(nanos as u32))
     }
 
+    pub const fn as_micros(&self) -> u64 {
+        self.secs() * MICROS_PER_SEC + self.nanos.0 as u64 / NANOS_PER_MICROS
+    }
+
     pub const fn checked_add(self, rhs: Self) -> Option<Self> {
         let (nanos, carry) = self.nanos.overflowing_add(rhs.nanos);
         let Some(secs) = self.secs().checked_add(rhs.secs()) else {
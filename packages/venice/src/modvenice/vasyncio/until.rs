@@ -0,0 +1,209 @@
+use std::cell::Cell;
+
+use argparse::{Args, Callable, error_msg};
+use micropython_macros::{class, class_methods, fun};
+use micropython_rs::{
+    except::{raise_stop_iteration, type_error, value_error},
+    init::token,
+    map::Map,
+    obj::{Obj, ObjBase, ObjTrait},
+};
+
+use super::{polled::PolledFuture, time32};
+use crate::modvenice::{Exception, units::time::TimeUnitObj};
+
+/// How often device conditions are polled, matching the rate at which VEXos updates Smart devices.
+pub const DEVICE_POLL_INTERVAL: time32::Duration = time32::Duration::from_micros(10_000);
+
+/// Reads a value from `device`, or returns `None` if no reading is currently available.
+pub type ReadFn = fn(device: Obj) -> Result<Option<f32>, Exception>;
+
+/// The value a device reading is waiting for.
+#[derive(Clone, Copy)]
+pub enum Threshold {
+    /// Waits until the reading is at least the given value.
+    AtLeast(f32),
+    /// Waits until the reading is at most the given value.
+    AtMost(f32),
+    /// Waits until the reading reaches the given value from whichever side it starts on.
+    Reach(f32),
+}
+
+impl Threshold {
+    fn is_met(self, reading: f32) -> bool {
+        match self {
+            Self::AtLeast(target) => reading >= target,
+            Self::AtMost(target) => reading <= target,
+            Self::Reach(target) => reading == target,
+        }
+    }
+}
+
+enum Condition {
+    Predicate(Callable),
+    Reading {
+        read: ReadFn,
+        device: Obj,
+        // `Reach` is resolved to `AtLeast` or `AtMost` by the first reading
+        threshold: Cell<Threshold>,
+    },
+}
+
+/// An awaitable that completes once a condition becomes true.
+///
+/// The condition is checked each time the future is awaited, and then again every poll interval
+/// until it holds. Between checks, the awaiting task is parked on the event loop, so other tasks
+/// keep running. Awaiting an `UntilFuture` again checks its condition afresh.
+///
+/// Users receive this from `vasyncio.until` or from device helpers such as
+/// `RotationSensor.wait_until_position` rather than constructing it directly.
+///
+/// To give up on a condition that might never become true, await the future from a coroutine
+/// passed to `vasyncio.wait_for`.
+///
+/// # Raises
+///
+/// Any exception raised by the predicate or by the device reading propagates out of the await.
+#[class(qstr!(UntilFuture))]
+#[repr(C)]
+pub struct UntilFuture {
+    base: ObjBase,
+    condition: Condition,
+    poll: time32::Duration,
+    next_poll: Cell<Option<time32::Instant>>,
+}
+
+impl UntilFuture {
+    /// Creates a future that waits until `read(device)` meets `threshold`.
+    pub fn reading(read: ReadFn, device: Obj, threshold: Threshold) -> Self {
+        Self {
+            base: Self::OBJ_TYPE.into(),
+            condition: Condition::Reading {
+                read,
+                device,
+                threshold: Cell::new(threshold),
+            },
+            poll: DEVICE_POLL_INTERVAL,
+            next_poll: Cell::new(None),
+        }
+    }
+
+    /// Checks the condition, returning the value to complete the await with if it holds.
+    fn check(&self) -> Result<Option<Obj>, Exception> {
+        match &self.condition {
+            Condition::Predicate(predicate) => {
+                let value = predicate.call(0, &[]);
+                Ok(value.is_true().then_some(value))
+            }
+            Condition::Reading {
+                read,
+                device,
+                threshold,
+            } => {
+                let Some(reading) = read(*device)? else {
+                    return Ok(None);
+                };
+
+                if let Threshold::Reach(target) = threshold.get() {
+                    threshold.set(if reading < target {
+                        Threshold::AtLeast(target)
+                    } else {
+                        Threshold::AtMost(target)
+                    });
+                }
+
+                Ok(threshold.get().is_met(reading).then_some(Obj::NONE))
+            }
+        }
+    }
+}
+
+impl PolledFuture for UntilFuture {
+    /// Returns when the condition should next be checked, if the future is waiting.
+    fn next_poll(&self) -> Option<time32::Instant> {
+        self.next_poll.get()
+    }
+}
+
+#[class_methods]
+impl UntilFuture {
+    #[iter]
+    extern "C" fn until_iternext(self_in: Obj) -> Obj {
+        let until = self_in.as_obj::<Self>();
+        match until.check() {
+            Ok(Some(value)) => {
+                until.next_poll.set(None);
+                raise_stop_iteration(token(), value)
+            }
+            Ok(None) => {
+                let next_poll = time32::Instant::now()
+                    .checked_add(until.poll)
+                    .unwrap_or_else(|| value_error(c"poll interval is too large").raise(token()));
+                until.next_poll.set(Some(next_poll));
+                until.pend(self_in)
+            }
+            Err(error) => {
+                until.next_poll.set(None);
+                error.raise(token())
+            }
+        }
+    }
+}
+
+/// Waits until `predicate()` returns a truthy value.
+///
+/// `predicate` is called with no arguments when the returned future is awaited, and then every
+/// `poll` interval, measured in `unit`, until it returns a truthy value. Awaiting the future
+/// returns that value. The awaiting task is parked between calls, so other tasks keep running.
+///
+/// `predicate` should return quickly, since the event loop cannot run other tasks while it runs.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// optical = OpticalSensor(1)
+/// intake = Motor(2)
+///
+/// async def main():
+///     intake.set_voltage(12)
+///     await vasyncio.until(lambda: optical.get_proximity() > 0.8, poll=20)
+///     intake.brake(BrakeMode.HOLD)
+///
+/// vasyncio.run(main())
+/// ```
+///
+/// # Raises
+///
+/// - `TypeError`: If `predicate` isn't callable.
+/// - `ValueError`: If `poll` is negative, non-finite, or too large to represent.
+#[fun(ty = kw(min = 1))]
+#[stub(
+    sig = "(predicate: Callable[[], Any], /, *, poll: float = 10, unit: TimeUnit = TimeUnit.MILLIS) -> UntilFuture"
+)]
+pub fn until(args: &[Obj], kw_map: &Map) -> Result<UntilFuture, Exception> {
+    let kwarg_count = kw_map.len();
+    let positional_count = args.len() - kwarg_count * 2;
+    let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+    reader.assert_npos(1, 1).assert_nkw(0, 2);
+
+    let predicate = reader.next_positional::<Callable>()?;
+
+    let mut poll = 10.0;
+    let mut unit: &TimeUnitObj = TimeUnitObj::MILLIS;
+    while let Some(arg) = reader.next_kw() {
+        match arg.kw {
+            "poll" => poll = arg.parse()?,
+            "unit" => unit = arg.parse()?,
+            _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+        }
+    }
+
+    Ok(UntilFuture {
+        base: UntilFuture::OBJ_TYPE.into(),
+        condition: Condition::Predicate(predicate),
+        poll: time32::Duration::from_duration(unit.unit().float_to_dur(poll)?),
+        next_poll: Cell::new(None),
+    })
+}
@@ -0,0 +1,553 @@
+use std::cell::Cell;
+
+use argparse::{ArgParser, Args, DefaultParser, FloatParser, ParseError, error_msg};
+use micropython_macros::{class, class_methods, fun};
+use micropython_rs::{
+    except::{
+        EXCEPTION_TYPE, new_exception_type, raise_msg, raise_stop_iteration, type_error,
+        value_error,
+    },
+    generator::GEN_INSTANCE_TYPE,
+    init::token,
+    list::new_list,
+    map::Map,
+    nlr,
+    obj::{Obj, ObjBase, ObjFullType, ObjTrait},
+    print::{Print, PrintKind},
+    tuple::new_tuple,
+};
+
+use super::{event_loop, task::Task, time32};
+use crate::{
+    alloc::Gc,
+    modvenice::{Exception, units::time::TimeUnitObj},
+};
+
+/// Raised by `vasyncio.wait_for` when the awaited task does not finish before its timeout.
+pub static TIMEOUT_ERROR_TYPE: ObjFullType =
+    new_exception_type(qstr!(TimeoutError), EXCEPTION_TYPE);
+
+/// A timeout in the caller's `TimeUnit`, or `None` to wait forever.
+#[derive(Default)]
+pub(crate) struct Timeout(Option<f32>);
+#[derive(Default)]
+pub(crate) struct TimeoutParser;
+
+impl<'a> ArgParser<'a> for TimeoutParser {
+    type Output = Timeout;
+
+    fn parse(&self, obj: &'a Obj) -> Result<Self::Output, ParseError> {
+        if obj.is_none() {
+            return Ok(Timeout(None));
+        }
+
+        FloatParser
+            .parse(obj)
+            .map(|timeout| Timeout(Some(timeout)))
+            .map_err(|_| ParseError::TypeError {
+                expected: "float or None",
+            })
+    }
+}
+
+impl DefaultParser<'_> for Timeout {
+    type Parser = TimeoutParser;
+}
+
+impl Timeout {
+    pub(crate) fn to_duration(
+        &self,
+        unit: &TimeUnitObj,
+    ) -> Result<Option<time32::Duration>, Exception> {
+        self.0
+            .map(|timeout| {
+                Ok(time32::Duration::from_duration(
+                    unit.unit().float_to_dur(timeout)?,
+                ))
+            })
+            .transpose()
+    }
+}
+
+/// A deadline that starts counting down the first time its future is polled.
+pub(crate) struct Deadline {
+    timeout: Option<time32::Duration>,
+    deadline: Cell<Option<time32::Instant>>,
+}
+
+impl Deadline {
+    pub(crate) fn new(timeout: Option<time32::Duration>) -> Self {
+        Self {
+            timeout,
+            deadline: Cell::new(None),
+        }
+    }
+
+    pub(crate) fn start(&self) {
+        if let Some(timeout) = self.timeout
+            && self.deadline.get().is_none()
+        {
+            let deadline = time32::Instant::now()
+                .checked_add(timeout)
+                .unwrap_or_else(|| value_error(c"timeout deadline is too large").raise(token()));
+            self.deadline.set(Some(deadline));
+        }
+    }
+
+    pub(crate) fn get(&self) -> Option<time32::Instant> {
+        self.deadline.get()
+    }
+
+    /// Stops the countdown, so that it starts afresh the next time the future is polled.
+    pub(crate) fn reset(&self) {
+        self.deadline.set(None);
+    }
+
+    pub(crate) fn has_passed(&self) -> bool {
+        self.deadline
+            .get()
+            .is_some_and(|deadline| deadline <= time32::Instant::now())
+    }
+}
+
+/// Returns `aw` if it is a `Task`, or spawns it onto the running loop if it is a coroutine.
+///
+/// The task is marked as observed, since its outcome is reported through the returned future.
+fn ensure_task(aw: Obj) -> Obj {
+    let task = if aw.try_as_obj::<Task>().is_some() {
+        aw
+    } else if aw.is(GEN_INSTANCE_TYPE) {
+        event_loop::spawn_coro(aw)
+    } else {
+        type_error(c"expected coroutine or Task").raise(token())
+    };
+
+    task.as_obj::<Task>().observe();
+    task
+}
+
+/// Raises the exception `task` failed with, or completes the current await with its result.
+fn finish_with(task: &Task) -> ! {
+    if task.has_failed() {
+        nlr::raise(token(), task.error())
+    } else {
+        raise_stop_iteration(token(), task.return_value())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ReturnWhen {
+    FirstCompleted,
+    FirstException,
+    AllCompleted,
+}
+
+/// Selects when `vasyncio.wait` returns.
+///
+/// `ReturnWhen` is exported from `vasyncio` and isn't constructed directly; use one of its
+/// singleton constants, which are also exported from `vasyncio` as `FIRST_COMPLETED`,
+/// `FIRST_EXCEPTION`, and `ALL_COMPLETED`.
+#[class(qstr!(ReturnWhen))]
+#[repr(C)]
+pub struct ReturnWhenObj {
+    base: ObjBase,
+    when: ReturnWhen,
+}
+
+#[class_methods]
+impl ReturnWhenObj {
+    const fn new(when: ReturnWhen) -> Self {
+        Self {
+            base: ObjBase::new(Self::OBJ_TYPE),
+            when,
+        }
+    }
+
+    /// Return as soon as any task finishes.
+    #[constant]
+    pub const FIRST_COMPLETED: &Self = &Self::new(ReturnWhen::FirstCompleted);
+    /// Return as soon as any task fails with an exception other than `CancelledError`, or once
+    /// every task has finished if none fails.
+    #[constant]
+    pub const FIRST_EXCEPTION: &Self = &Self::new(ReturnWhen::FirstException);
+    /// Return once every task has finished.
+    #[constant]
+    pub const ALL_COMPLETED: &Self = &Self::new(ReturnWhen::AllCompleted);
+
+    #[printer]
+    fn printer(&self, print: &mut Print, _kind: PrintKind) {
+        print.print(match self.when {
+            ReturnWhen::FirstCompleted => "ReturnWhen.FIRST_COMPLETED",
+            ReturnWhen::FirstException => "ReturnWhen.FIRST_EXCEPTION",
+            ReturnWhen::AllCompleted => "ReturnWhen.ALL_COMPLETED",
+        });
+    }
+}
+
+/// An awaitable returned by `vasyncio.gather`.
+///
+/// Awaiting it waits for every task to finish and returns a list of their return values, in the
+/// order the awaitables were passed to `gather`.
+#[class(qstr!(GatherFuture))]
+#[repr(C)]
+pub struct GatherFuture {
+    base: ObjBase,
+    tasks: Vec<Obj, Gc>,
+}
+
+impl GatherFuture {
+    pub fn tasks(&self) -> &[Obj] {
+        &self.tasks
+    }
+
+    pub fn pending_tasks(&self) -> impl Iterator<Item = &Task> {
+        self.tasks
+            .iter()
+            .map(|task| task.as_obj::<Task>())
+            .filter(|task| !task.is_complete())
+    }
+}
+
+#[class_methods]
+impl GatherFuture {
+    #[iter]
+    extern "C" fn gather_iternext(self_in: Obj) -> Obj {
+        let this = self_in.as_obj::<Self>();
+
+        // The event loop parks us on every unfinished task, so a failed child is surfaced
+        // immediately rather than after the tasks before it finish.
+        if let Some(failed) = this
+            .tasks
+            .iter()
+            .map(|task| task.as_obj::<Task>())
+            .find(|task| task.has_failed())
+        {
+            nlr::raise(token(), failed.error());
+        }
+
+        if this.pending_tasks().next().is_some() {
+            return self_in;
+        }
+
+        let results: Vec<Obj> = this
+            .tasks
+            .iter()
+            .map(|task| task.as_obj::<Task>().return_value())
+            .collect();
+        raise_stop_iteration(token(), new_list(&results))
+    }
+}
+
+/// An awaitable returned by `vasyncio.wait`.
+///
+/// Awaiting it returns a `(done, pending)` tuple of task lists once its `ReturnWhen` condition is
+/// met or its timeout elapses.
+#[class(qstr!(WaitFuture))]
+#[repr(C)]
+pub struct WaitFuture {
+    base: ObjBase,
+    tasks: Vec<Obj, Gc>,
+    deadline: Deadline,
+    return_when: ReturnWhen,
+}
+
+impl WaitFuture {
+    pub fn tasks(&self) -> &[Obj] {
+        &self.tasks
+    }
+
+    pub fn pending_tasks(&self) -> impl Iterator<Item = &Task> {
+        self.tasks
+            .iter()
+            .map(|task| task.as_obj::<Task>())
+            .filter(|task| !task.is_complete())
+    }
+
+    pub fn deadline(&self) -> Option<time32::Instant> {
+        self.deadline.get()
+    }
+}
+
+#[class_methods]
+impl WaitFuture {
+    #[iter]
+    extern "C" fn wait_iternext(self_in: Obj) -> Obj {
+        let this = self_in.as_obj::<Self>();
+        this.deadline.start();
+
+        let (done, pending): (Vec<Obj>, Vec<Obj>) = this
+            .tasks
+            .iter()
+            .copied()
+            .partition(|task| task.as_obj::<Task>().is_complete());
+
+        let finished = pending.is_empty()
+            || match this.return_when {
+                ReturnWhen::FirstCompleted => !done.is_empty(),
+                ReturnWhen::FirstException => done.iter().any(|task| {
+                    let task = task.as_obj::<Task>();
+                    task.has_failed() && !task.is_cancelled()
+                }),
+                ReturnWhen::AllCompleted => false,
+            };
+
+        if finished || this.deadline.has_passed() {
+            raise_stop_iteration(token(), new_tuple(&[new_list(&done), new_list(&pending)]));
+        }
+
+        self_in
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum WaitForState {
+    Waiting,
+    Cancelling,
+}
+
+/// An awaitable returned by `vasyncio.wait_for`.
+///
+/// Awaiting it returns the task's result if the task finishes in time. Otherwise, the task is
+/// cancelled and `TimeoutError` is raised once the cancellation has been delivered.
+#[class(qstr!(WaitForFuture))]
+#[repr(C)]
+pub struct WaitForFuture {
+    base: ObjBase,
+    task: Obj,
+    deadline: Deadline,
+    state: Cell<WaitForState>,
+}
+
+impl WaitForFuture {
+    pub fn task(&self) -> Obj {
+        self.task
+    }
+
+    pub fn deadline(&self) -> Option<time32::Instant> {
+        self.deadline.get()
+    }
+}
+
+#[class_methods]
+impl WaitForFuture {
+    #[iter]
+    extern "C" fn wait_for_iternext(self_in: Obj) -> Obj {
+        let this = self_in.as_obj::<Self>();
+        let task = this.task.as_obj::<Task>();
+
+        match this.state.get() {
+            WaitForState::Waiting => {
+                this.deadline.start();
+
+                if task.is_complete() {
+                    finish_with(task);
+                }
+
+                if !this.deadline.has_passed() {
+                    return self_in;
+                }
+
+                // Wait for the task to observe its cancellation so that its cleanup has run by the
+                // time the caller sees the `TimeoutError`.
+                Task::cancel_task(this.task);
+                this.state.set(WaitForState::Cancelling);
+                this.task
+            }
+            WaitForState::Cancelling => {
+                if !task.is_complete() {
+                    return this.task;
+                }
+
+                raise_msg(
+                    token(),
+                    TIMEOUT_ERROR_TYPE.as_obj_type(),
+                    c"task did not finish before the timeout",
+                )
+            }
+        }
+    }
+}
+
+/// Runs awaitables concurrently and returns an awaitable for all of their results.
+///
+/// Each awaitable in `aws` may be a coroutine object, which is spawned onto the running event loop
+/// as a `Task`, or an existing `Task`. Awaiting the returned object waits for every task to finish
+/// and returns a list of their return values in the same order as `aws`.
+///
+/// If any task fails, its exception, such as the `CancelledError` of a cancelled task, is raised in
+/// the awaiting task as soon as it happens. The remaining tasks are not cancelled and keep running.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// async def double(x):
+///     await vasyncio.Sleep(x, MILLIS)
+///     return x * 2
+///
+/// async def main():
+///     results = await vasyncio.gather(double(10), double(20), double(30))
+///     assert results == [20, 40, 60]
+///
+/// vasyncio.run(main())
+/// ```
+///
+/// # Raises
+///
+/// - `RuntimeError`: If a coroutine is passed and no event loop is running.
+/// - `TypeError`: If an awaitable is neither a coroutine object nor a `Task`.
+#[fun(ty = var(min = 0))]
+#[stub(sig = "(*aws: Any) -> GatherFuture")]
+pub fn gather(args: &[Obj]) -> GatherFuture {
+    let mut tasks = Vec::with_capacity_in(args.len(), Gc { token: token() });
+    tasks.extend(args.iter().map(|aw| ensure_task(*aw)));
+
+    GatherFuture {
+        base: GatherFuture::OBJ_TYPE.into(),
+        tasks,
+    }
+}
+
+/// Waits for the tasks in `tasks` until the `return_when` condition is met or `timeout` elapses.
+///
+/// `tasks` is a list or tuple of coroutine objects or `Task`s; coroutines are spawned onto the
+/// running event loop. `timeout` is measured in `unit` and starts when the returned object is first
+/// awaited. A `timeout` of `None` waits without a time limit.
+///
+/// Awaiting the returned object produces a `(done, pending)` tuple of lists of tasks. Unlike
+/// `wait_for`, tasks that are still pending when the timeout elapses are neither cancelled nor
+/// reported as an error.
+///
+/// # Examples
+///
+/// Drive until a limit switch is pressed or two seconds pass, whichever happens first:
+///
+/// ```python
+/// from venice import *
+///
+/// switch = AdiDigitalIn("A")
+///
+/// async def wait_for_switch():
+///     while not switch.is_high():
+///         await vasyncio.Sleep(10, MILLIS)
+///
+/// async def main():
+///     done, pending = await vasyncio.wait(
+///         [wait_for_switch()],
+///         timeout=2,
+///         return_when=vasyncio.FIRST_COMPLETED,
+///     )
+///
+///     if pending:
+///         print("switch was not pressed in time")
+///         for task in pending:
+///             task.cancel()
+///
+/// vasyncio.run(main())
+/// ```
+///
+/// # Raises
+///
+/// - `RuntimeError`: If a coroutine is passed and no event loop is running.
+/// - `TypeError`: If `tasks` isn't a list or tuple, or if an item is neither a coroutine object nor
+///   a `Task`.
+/// - `ValueError`: If `timeout` is negative, non-finite, or too large to represent.
+#[fun(ty = kw(min = 1))]
+#[stub(
+    sig = "(tasks: list[Any] | tuple[Any, ...], /, *, timeout: float | None = None, unit: TimeUnit = TimeUnit.SECOND, return_when: ReturnWhen = ReturnWhen.ALL_COMPLETED) -> WaitFuture"
+)]
+pub fn wait(args: &[Obj], kw_map: &Map) -> Result<WaitFuture, Exception> {
+    let kwarg_count = kw_map.len();
+    let positional_count = args.len() - kwarg_count * 2;
+    let mut reader = Args::new(positional_count, kwarg_count, args).reader();
+    reader.assert_npos(1, 1).assert_nkw(0, 3);
+
+    let aws = reader.next_positional::<Obj>()?;
+
+    let mut timeout = Timeout(None);
+    let mut unit: &TimeUnitObj = TimeUnitObj::SECOND;
+    let mut return_when: &ReturnWhenObj = ReturnWhenObj::ALL_COMPLETED;
+
+    while let Some(arg) = reader.next_kw() {
+        match arg.kw {
+            "timeout" => timeout = arg.parse()?,
+            "unit" => unit = arg.parse()?,
+            "return_when" => return_when = arg.parse()?,
+            _ => Err(type_error(error_msg!("unknown argument '{}'", arg.kw)))?,
+        }
+    }
+
+    let aws = aws
+        .try_array()
+        .ok_or_else(|| type_error(c"expected a list or tuple of coroutines or tasks"))?;
+    let mut tasks = Vec::with_capacity_in(aws.len(), Gc { token: token() });
+    tasks.extend(aws.into_iter().map(ensure_task));
+
+    Ok(WaitFuture {
+        base: WaitFuture::OBJ_TYPE.into(),
+        tasks,
+        deadline: Deadline::new(timeout.to_duration(unit)?),
+        return_when: return_when.when,
+    })
+}
+
+/// Waits for `aw` to finish, cancelling it if `timeout` elapses first.
+///
+/// `aw` may be a coroutine object, which is spawned onto the running event loop, or a `Task`.
+/// `timeout` is measured in `unit` and starts when the returned object is first awaited. A
+/// `timeout` of `None` waits without a time limit.
+///
+/// Awaiting the returned object produces the task's return value. If the timeout elapses first,
+/// the task is cancelled, the awaiting task waits until the cancellation has been delivered so that
+/// the task's `finally` blocks have run, and then `TimeoutError` is raised.
+///
+/// # Examples
+///
+/// ```python
+/// from venice import *
+///
+/// motor = Motor(1)
+/// switch = AdiDigitalIn("A")
+///
+/// async def drive_until_switch():
+///     try:
+///         motor.set_voltage(6)
+///         while not switch.is_high():
+///             await vasyncio.Sleep(10, MILLIS)
+///     finally:
+///         motor.brake(BrakeMode.BRAKE)
+///
+/// async def main():
+///     try:
+///         await vasyncio.wait_for(drive_until_switch(), 2, SECOND)
+///     except vasyncio.TimeoutError:
+///         print("gave up after two seconds")
+///
+/// vasyncio.run(main())
+/// ```
+///
+/// # Raises
+///
+/// - `RuntimeError`: If a coroutine is passed and no event loop is running.
+/// - `TypeError`: If `aw` is neither a coroutine object nor a `Task`.
+/// - `ValueError`: If `timeout` is negative, non-finite, or too large to represent.
+#[fun(ty = var_between(min = 2, max = 3))]
+#[stub(
+    sig = "(aw: Any, timeout: float | None, unit: TimeUnit = TimeUnit.SECOND, /) -> WaitForFuture"
+)]
+pub fn wait_for(args: &[Obj]) -> Result<WaitForFuture, Exception> {
+    let mut reader = Args::new(args.len(), 0, args).reader();
+
+    let aw = reader.next_positional::<Obj>()?;
+    let timeout = reader.next_positional::<Timeout>()?;
+    let unit = reader.next_positional_or(TimeUnitObj::SECOND)?;
+
+    let deadline = Deadline::new(timeout.to_duration(unit)?);
+    Ok(WaitForFuture {
+        base: WaitForFuture::OBJ_TYPE.into(),
+        task: ensure_task(aw),
+        deadline,
+        state: Cell::new(WaitForState::Waiting),
+    })
+}
@@ -75,6 +75,13 @@ impl<P> Registry<P> {
         self.generations.is_active(generation)
     }
 
+    pub fn is_available(&self) -> bool {
+        matches!(
+            self.device.try_lock().as_deref(),
+            Ok(RegistryDevice::Available(_))
+        )
+    }
+
     pub fn try_lock<'a, D, I>(
         &'a self,
         init: I,
@@ -2565,7 +2565,8 @@ class CompetitionRuntime:
 
     Users receive this type from `Competition.run`; it cannot be constructed directly. Its awaitable
     protocol is designed for the `vasyncio` event loop and understands `vasyncio.Sleep` and task objects
-    returned by `vasyncio.spawn` when phase routines await them.
+    returned by `vasyncio.spawn` when phase routines await them. Other `vasyncio` awaitables, such as
+    the one returned by `vasyncio.wait_for`, are polled again each time the runtime is resumed.
 
     # Raises
 
@@ -2659,6 +2660,41 @@ class Controller:
         """
         ...
 
+    def states(self) -> SampleStream:
+        """
+        Returns an asynchronous iterator over the controller's state.
+
+        Each iteration of an `async for` loop waits for the next controller update, which VEXos
+        delivers every `Controller.UPDATE_INTERVAL_MS` milliseconds, then produces the same
+        `ControllerState` that `Controller.read_state` would return.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        controller = Controller()
+        left = Motor(1)
+        right = Motor(2, Direction.REVERSE)
+
+        async def main():
+        async for state in controller.states():
+        left.set_voltage(state.left_stick.y * 12)
+        right.set_voltage(state.right_stick.y * 12)
+
+        vasyncio.run(main())
+        ```
+
+        # Raises
+
+        While iterating:
+
+        - `ValueError`: If the controller binding has been freed.
+        - `DeviceError`: If access to controller data is restricted by competition control, or the
+        controller is not connected.
+        """
+        ...
+
     def get_connection(self, /) -> ControllerConnection:
         """
         Returns the controller's connection type as a root-importable `ControllerConnection`.
@@ -3127,6 +3163,402 @@ class JoystickState:
     x_raw: int
     y_raw: int
 
+class Canvas:
+    """
+    An image in memory that can be drawn on and then drawn on the display, available as
+    `display.Canvas`.
+
+    Canvases have the same drawing functions as the `display` module, as methods, except for
+    `draw_text`, since text is rendered by the display itself. Unlike on the display, shapes drawn
+    with `antialias=True` are blended with whatever is already on the canvas, so they don't take a
+    `bg_color`.
+
+    Every pixel has an alpha channel, and a new canvas is fully transparent. `blit` draws one
+    canvas or `Image` on another, blending the pixels that are partly transparent, which lets a
+    sprite be drawn over a background drawn once ahead of time. `draw` puts the canvas on the
+    display, leaving its fully transparent pixels undrawn. With
+    `display.RenderMode.DOUBLE_BUFFERED`, composing each frame on a canvas and drawing it in one go
+    keeps moving sprites from flickering.
+
+    Canvases are held in memory at four bytes per pixel, so a full-screen 480 by 240 canvas takes
+    460800 bytes. The read-only `width` and `height` attributes are the canvas's size in pixels.
+    Canvases print as `Canvas(width=..., height=...)`.
+
+    # Examples
+
+    Draw a robot moving over a field map:
+
+    ```python
+    from venice import *
+
+    field = display.Canvas(240, 240, color=Color(40, 40, 40))
+    for i in range(1, 6):
+    field.draw_line(i * 40, 0, i * 40, 239, Color.GRAY)
+    field.draw_line(0, i * 40, 239, i * 40, Color.GRAY)
+
+    robot = display.Canvas(21, 21)
+    robot.fill_circle(10, 10, 10, Color.BLUE)
+    robot.draw_line(10, 10, 20, 10, Color.WHITE, thickness=3)
+
+    frame = display.Canvas(240, 240)
+    display.set_render_mode(display.RenderMode.DOUBLE_BUFFERED)
+
+    async def main():
+    ticker = vasyncio.Ticker(20, MILLIS)
+    x = 0
+    while True:
+    await ticker
+    frame.blit(field, 0, 0)
+    frame.blit(robot, x - 10, 110)
+    frame.draw(120, 0)
+    display.render()
+    x = (x + 1) % 240
+
+    vasyncio.run(main())
+    ```
+    """
+
+    width: int
+    height: int
+    def __init__(
+        self, width: int, height: int, /, *, color: Color | None = None
+    ) -> None:
+        """
+        Creates a canvas that's `width` by `height` pixels, filled with `color`, or fully
+        transparent if `color` is `None`.
+
+        # Raises
+
+        - `ValueError`: If `width` or `height` is zero.
+        """
+        ...
+
+    def erase(self, color: Color) -> None:
+        """Fills the whole canvas with `color`."""
+        ...
+
+    def clear(self) -> None:
+        """Makes the whole canvas fully transparent."""
+        ...
+
+    def draw_pixel(self, x: int, y: int, color: Color, /) -> None:
+        """Like `display.draw_pixel`, but draws on the canvas."""
+        ...
+
+    def draw_line(
+        self,
+        start_x: int,
+        start_y: int,
+        end_x: int,
+        end_y: int,
+        color: Color | LinearGradient,
+        /,
+        *,
+        thickness: int = 1,
+        antialias: bool = False,
+    ) -> None:
+        """
+        Like `display.draw_line`, but draws on the canvas.
+
+        Lines on a canvas are always rasterized, even when they're one pixel wide and not
+        anti-aliased.
+
+        # Raises
+
+        - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+        - `ValueError`: If `thickness` is zero.
+        """
+        ...
+
+    def draw_circle(self, x: int, y: int, radius: int, color: Color, /) -> None:
+        """Like `display.draw_circle`, but draws on the canvas."""
+        ...
+
+    def fill_circle(self, x: int, y: int, radius: int, color: Color, /) -> None:
+        """Like `display.fill_circle`, but draws on the canvas."""
+        ...
+
+    def draw_rect(
+        self, x: int, y: int, width: int, height: int, color: Color, /
+    ) -> None:
+        """Like `display.draw_rect`, but draws on the canvas."""
+        ...
+
+    def fill_rect(
+        self, x: int, y: int, width: int, height: int, color: Color, /
+    ) -> None:
+        """Like `display.fill_rect`, but draws on the canvas."""
+        ...
+
+    def draw_buffer(
+        self, x: int, y: int, width: int, height: int, buffer: Any, /
+    ) -> None:
+        """
+        Like `display.draw_buffer`, but draws on the canvas.
+
+        # Raises
+
+        - `TypeError`: If `buffer` doesn't support the readable buffer protocol.
+        - `ValueError`: If the buffer is unaligned, its readable byte length isn't a multiple of
+        four, or its pixel count doesn't equal `width * height`.
+        """
+        ...
+
+    def draw_polygon(
+        self,
+        points: list[tuple[int, int]] | tuple[tuple[int, int], ...],
+        color: Color | LinearGradient,
+        /,
+        *,
+        thickness: int = 1,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.draw_polygon`, but draws on the canvas."""
+        ...
+
+    def fill_polygon(
+        self,
+        points: list[tuple[int, int]] | tuple[tuple[int, int], ...],
+        color: Color | LinearGradient,
+        /,
+        *,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.fill_polygon`, but draws on the canvas."""
+        ...
+
+    def draw_triangle(
+        self,
+        x0: int,
+        y0: int,
+        x1: int,
+        y1: int,
+        x2: int,
+        y2: int,
+        color: Color | LinearGradient,
+        /,
+        *,
+        thickness: int = 1,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.draw_triangle`, but draws on the canvas."""
+        ...
+
+    def fill_triangle(
+        self,
+        x0: int,
+        y0: int,
+        x1: int,
+        y1: int,
+        x2: int,
+        y2: int,
+        color: Color | LinearGradient,
+        /,
+        *,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.fill_triangle`, but draws on the canvas."""
+        ...
+
+    def draw_arc(
+        self,
+        x: int,
+        y: int,
+        radius: int,
+        start: float,
+        end: float,
+        color: Color | LinearGradient,
+        /,
+        *,
+        thickness: int = 1,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.draw_arc`, but draws on the canvas."""
+        ...
+
+    def fill_pie(
+        self,
+        x: int,
+        y: int,
+        radius: int,
+        start: float,
+        end: float,
+        color: Color | LinearGradient,
+        /,
+        *,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.fill_pie`, but draws on the canvas."""
+        ...
+
+    def draw_round_rect(
+        self,
+        x: int,
+        y: int,
+        width: int,
+        height: int,
+        radius: int,
+        color: Color | LinearGradient,
+        /,
+        *,
+        thickness: int = 1,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.draw_round_rect`, but draws on the canvas."""
+        ...
+
+    def fill_round_rect(
+        self,
+        x: int,
+        y: int,
+        width: int,
+        height: int,
+        radius: int,
+        color: Color | LinearGradient,
+        /,
+        *,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.fill_round_rect`, but draws on the canvas."""
+        ...
+
+    def blit(
+        self,
+        source: Canvas | Image,
+        x: int,
+        y: int,
+        /,
+        *,
+        region: tuple[int, int, int, int] | None = None,
+        alpha: int = 255,
+        blend: bool = True,
+    ) -> None:
+        """
+        Draws `source`, a `Canvas` or `Image`, on this canvas with its top-left corner at (`x`,
+        `y`).
+
+        `region`, as `(x, y, width, height)` in the source's pixels, draws only that part of it,
+        which copies part of a canvas to another place on itself if `source` is this canvas.
+        Pixels are blended over the canvas by their alpha, scaled by `alpha` out of 255 so that the
+        whole source can be faded. With `blend=False`, pixels are instead copied as they are,
+        transparency included, and `alpha` is ignored. Anything that falls outside the canvas is
+        clipped.
+
+        # Raises
+
+        - `TypeError`: If `source` isn't a `Canvas` or `Image`, or `region` isn't a tuple of four
+        integers.
+        - `ValueError`: If `region` is empty or extends outside the source, or `alpha` is outside
+        0 to 255.
+        """
+        ...
+
+    def draw(
+        self,
+        x: int,
+        y: int,
+        /,
+        *,
+        region: tuple[int, int, int, int] | None = None,
+        bg_color: Color = Color.BLACK,
+    ) -> None:
+        """
+        Draws the canvas with its top-left corner at (`x`, `y`) on the display.
+
+        `region`, as `(x, y, width, height)` in the canvas's pixels, draws only that part of the
+        canvas. Fully transparent pixels are left undrawn. Since the display can't be read back,
+        partly transparent pixels are blended with `bg_color`, which should be the color behind
+        them. Anything that falls outside the display is clipped.
+
+        # Raises
+
+        - `TypeError`: If `region` isn't a tuple of four integers.
+        - `ValueError`: If `region` is empty or extends outside the canvas.
+        """
+        ...
+
+class Image:
+    """
+    A decoded picture that can be drawn on the display, available as `display.Image`.
+
+    Images are decoded from PNG, BMP or QOI data, recognized from their first bytes. Every PNG
+    color type, bit depth and interlacing method is supported, as are uncompressed BMPs with 1, 4,
+    8, 16, 24 or 32 bits per pixel. Images can have at most 460800 pixels, four times as many as
+    the 480 by 240 display, though a full-screen image is usually as large as is useful.
+
+    Decoded images are held in memory at four bytes per pixel, whatever their format. QOI decodes
+    the fastest and PNG files are the smallest to bundle.
+
+    The read-only `width` and `height` attributes are the image's size in pixels. Images print as
+    `Image(width=..., height=...)`.
+
+    # Examples
+
+    Draw a logo bundled with the program in the middle of the display:
+
+    ```python
+    from venice import *
+
+    logo = display.Image(open("logo.png", "rb"))
+    logo.draw(240 - logo.width // 2, 120 - logo.height // 2)
+    ```
+
+    Draw the second of a row of 32 by 32 icons at double size, leaving magenta pixels undrawn:
+
+    ```python
+    from venice import *
+
+    icons = display.Image(open("icons.bmp", "rb"))
+    icons.draw(10, 10, region=(32, 0, 32, 32), scale=2.0, key=Color(255, 0, 255))
+    ```
+    """
+
+    width: int
+    height: int
+    def __init__(self, source: bytes | bytearray | memoryview | IO[bytes], /) -> None:
+        """
+        Decodes an image from `source`, which is a bytes-like object holding the image's data or a
+        file opened with `open`.
+
+        A file is read from its current position to its end.
+
+        # Raises
+
+        - `TypeError`: If `source` isn't a bytes-like object or a file.
+        - `ValueError`: If the data isn't a PNG, BMP or QOI image, is corrupt or truncated, uses a
+        feature that isn't supported, or has too many pixels.
+        - `MemoryError`: If there isn't enough memory to read or decode the image.
+        - `OSError`: If the file can't be read.
+        """
+        ...
+
+    def draw(
+        self,
+        x: int,
+        y: int,
+        /,
+        *,
+        region: tuple[int, int, int, int] | None = None,
+        scale: float = 1.0,
+        key: Color | None = None,
+    ) -> None:
+        """
+        Draws the image with its top-left corner at (`x`, `y`) on the display.
+
+        `region`, as `(x, y, width, height)` in the image's pixels, draws only that part of the
+        image, such as one icon from a sheet of them. `scale` stretches or shrinks the image,
+        picking the nearest pixel rather than blending them. Pixels that are less than half
+        opaque, or that match `key`, are left undrawn so that whatever is behind them shows
+        through. Anything that falls outside the display is clipped.
+
+        # Raises
+
+        - `TypeError`: If `region` isn't a tuple of four integers or `key` isn't a `Color`.
+        - `ValueError`: If `region` is empty or extends outside the image, or `scale` isn't
+        positive and finite.
+        """
+        ...
+
 class RenderMode:
     """
     The rendering mode for the VEX V5's display, available as `display.RenderMode`.
@@ -3233,80 +3665,245 @@ class TouchEvent:
     is_released: bool
     is_held: bool
 
-class DistanceObject:
+class Plot:
     """
-    Readings from a physical object detected by a Distance Sensor.
+    A scrolling strip chart of one or more channels of samples, available as `display.Plot`.
 
-    Instances are returned by `DistanceSensor.get_object` and can't be constructed directly. All
-    attributes are read-only.
+    Every call to `push` records one sample per channel, and `draw` shows the samples pushed since
+    it was last called. Time runs down the plot: the newest sample is on the bottom row, one row
+    per sample, and values are plotted across its width. Since the display can only scroll
+    vertically, this lets `draw` move the older samples up with `display.scroll_region` and draw
+    just the new rows, so feeding a plot from a task at 50 Hz takes little time.
 
-    - `distance` is the distance of the object from the sensor in millimeters.
-    - `relative_size` is a guess at the object's "relative size". This is a unitless value from 0 to
-    400. An 18" x 30" grey card returns approximately 75 in typical room lighting. If the sensor
-    isn't able to determine an object's size, `None` is returned. It's unknown what the sensor is
-    actually measuring here, so use this data with a grain of salt.
-    - `velocity` is the approach velocity of the object in m/s. This is calculated by the Brain by
-    differentiating `distance` with respect to time and applying a simple low-pass filter.
-    - `confidence` is the confidence in the distance measurement from 0.0 to 1.0.
-    """
+    The plot keeps as many samples as it has rows, and older samples are discarded. Each channel is
+    drawn in its own color and named in a legend at the top, above the ends of the value axis.
 
-    confidence: float
-    distance: int
-    velocity: float
-    relative_size: int | None
+    The value axis is fixed if `min` and `max` are given. Otherwise it scales automatically,
+    growing whenever a sample falls outside of it, which redraws the whole plot. `NaN` samples leave
+    a gap in their channel's line.
 
-class DistanceSensor:
-    """
-    Distance Sensor.
+    The read-only `min` and `max` attributes are the ends of the value axis, or `None` if it scales
+    automatically and nothing has been pushed yet.
 
-    This class provides an interface to interact with the VEX V5 Distance Sensor, which uses a Class
-    1 laser to measure the distance, object size classification, and relative velocity of a single
-    object.
+    # Examples
 
-    # Hardware Overview
+    Plot a flywheel's velocity against its target:
 
-    The sensor uses a narrow-beam Class 1 laser (similar to phone proximity sensors) for precise
-    detection. It measures distances from 20mm to 2000mm with varying accuracy (±15mm below 200mm, ±5%
-    above 200mm).
+    ```python
+    from venice import *
 
-    The sensor can classify detected objects by relative size, helping distinguish between walls and
-    field elements. It also measures the relative approach velocity between the sensor and target.
+    flywheel = Motor(1)
+    target = 3000.0
+    plot = display.Plot(0, 0, 480, 240, channels=[("target", Color.GRAY), ("rpm", Color.LIME)])
 
-    Due to the use of a laser, measurements are single-point and highly directional, meaning that
-    objects will only be detected when they are directly in front of the sensor's field of view.
+    async def main():
+    ticker = vasyncio.Ticker(20, MILLIS)
+    while True:
+    await ticker
+    plot.push(target, flywheel.get_velocity())
+    plot.draw()
 
-    Like all other Smart devices, VEXos will process sensor updates every 10mS.
+    vasyncio.run(main())
+    ```
     """
-    def __init__(self, port: int, /) -> None:
-        """
-        Creates a new distance sensor from Smart Port `port`.
-
-        # Examples
 
-        ```python
-        from venice import *
+    min: float | None
+    max: float | None
+    def __init__(
+        self,
+        x: int,
+        y: int,
+        width: int,
+        height: int,
+        /,
+        *,
+        channels: list[tuple[str, Color]] | tuple[tuple[str, Color], ...],
+        min: float | None = None,
+        max: float | None = None,
+        grid: int = 4,
+        background: Color = Color.BLACK,
+    ) -> None:
+        """
+        Creates a plot filling the region whose top-left corner is at `x` and `y`, which is
+        `width` by `height` pixels.
 
-        sensor = DistanceSensor(1)
-        ```
+        `channels` lists each channel's name and color, in the order their samples are pushed.
+        The value axis runs from `min` to `max`, or scales automatically if they are `None`, and
+        is divided by `grid` gridlines into equal parts. Nothing is drawn until `draw` is called.
 
         # Raises
 
-        - `ValueError`: If `port` isn't from 1 through 21 or is already in use.
-        - `TypeError`: If `port` is not an integer.
+        - `TypeError`: If `channels` isn't a list or tuple of `(str, Color)` tuples, or an axis
+        limit isn't a number or `None`.
+        - `ValueError`: If there are no channels or more than eight, a channel name contains a
+        NUL character, only one of `min` and `max` is given, `min` isn't less than `max`, `grid`
+        is more than 16, the region is too small to plot in, or the region exceeds the
+        supported coordinate range.
         """
         ...
 
-    def get_status(self) -> int:
+    def push(self, *values: float) -> None:
         """
-        Returns the internal status code of the distance sensor.
-
-        The status code of the signature can tell you if the sensor is still initializing or if it is
-        working correctly.
+        Records one sample for each channel, in the order the channels were given.
 
-        - If the distance sensor is still initializing, the status code will be 0x00.
-        - If it is done initializing and functioning correctly, the status code will be 0x82 or 0x86.
+        The samples are shown the next time `draw` is called.
 
-        # Examples
+        # Raises
+
+        - `TypeError`: If a sample isn't a number.
+        - `ValueError`: If there isn't one sample per channel.
+        """
+        ...
+
+    def draw(self) -> None:
+        """
+        Shows the samples pushed since the plot was last drawn.
+
+        The first draw, and any after the value axis changes or `redraw` is called, draws the
+        whole plot. Otherwise only the new rows are drawn, after scrolling the rest up. With
+        `RenderMode.DOUBLE_BUFFERED`, `display.render` must still be called for them to appear.
+        """
+        ...
+
+    def redraw(self) -> None:
+        """
+        Draws the whole plot again at the next call to `draw`.
+
+        Call this after drawing over the plot with anything else.
+        """
+        ...
+
+    def clear(self) -> None:
+        """
+        Discards every sample.
+
+        An automatic value axis starts over from the next sample pushed.
+        """
+        ...
+
+    def set_range(self, min: float | None, max: float | None, /) -> None:
+        """
+        Fixes the value axis to run from `min` to `max`, or makes it scale automatically if both
+        are `None`.
+
+        An automatic axis is fitted to the samples already in the plot.
+
+        # Raises
+
+        - `TypeError`: If an axis limit isn't a number or `None`.
+        - `ValueError`: If only one of `min` and `max` is given, or `min` isn't less than `max`.
+        """
+        ...
+
+class LinearGradient:
+    """
+    A color that changes gradually along a line, available as `display.LinearGradient`.
+
+    A gradient can be passed instead of a `Color` to any of the shape drawing functions, such as
+    `display.fill_polygon` or `display.fill_round_rect`. Pixels are colored by how far along the
+    line from (`start_x`, `start_y`) to (`end_x`, `end_y`) they are, and the colors at the ends
+    continue past them.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    # Fade the top of the display from blue to black.
+    sky = display.LinearGradient(0, 0, 0, 119, Color(0, 80, 200), Color.BLACK)
+    display.fill_round_rect(0, 0, 480, 120, 0, sky)
+    ```
+    """
+    def __init__(
+        self,
+        start_x: int,
+        start_y: int,
+        end_x: int,
+        end_y: int,
+        start_color: Color,
+        end_color: Color,
+        /,
+    ) -> None:
+        """
+        Creates a gradient from `start_color` at (`start_x`, `start_y`) to `end_color` at
+        (`end_x`, `end_y`).
+        """
+        ...
+
+class DistanceObject:
+    """
+    Readings from a physical object detected by a Distance Sensor.
+
+    Instances are returned by `DistanceSensor.get_object` and can't be constructed directly. All
+    attributes are read-only.
+
+    - `distance` is the distance of the object from the sensor in millimeters.
+    - `relative_size` is a guess at the object's "relative size". This is a unitless value from 0 to
+    400. An 18" x 30" grey card returns approximately 75 in typical room lighting. If the sensor
+    isn't able to determine an object's size, `None` is returned. It's unknown what the sensor is
+    actually measuring here, so use this data with a grain of salt.
+    - `velocity` is the approach velocity of the object in m/s. This is calculated by the Brain by
+    differentiating `distance` with respect to time and applying a simple low-pass filter.
+    - `confidence` is the confidence in the distance measurement from 0.0 to 1.0.
+    """
+
+    confidence: float
+    distance: int
+    velocity: float
+    relative_size: int | None
+
+class DistanceSensor:
+    """
+    Distance Sensor.
+
+    This class provides an interface to interact with the VEX V5 Distance Sensor, which uses a Class
+    1 laser to measure the distance, object size classification, and relative velocity of a single
+    object.
+
+    # Hardware Overview
+
+    The sensor uses a narrow-beam Class 1 laser (similar to phone proximity sensors) for precise
+    detection. It measures distances from 20mm to 2000mm with varying accuracy (±15mm below 200mm, ±5%
+    above 200mm).
+
+    The sensor can classify detected objects by relative size, helping distinguish between walls and
+    field elements. It also measures the relative approach velocity between the sensor and target.
+
+    Due to the use of a laser, measurements are single-point and highly directional, meaning that
+    objects will only be detected when they are directly in front of the sensor's field of view.
+
+    Like all other Smart devices, VEXos will process sensor updates every 10mS.
+    """
+    def __init__(self, port: int, /) -> None:
+        """
+        Creates a new distance sensor from Smart Port `port`.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = DistanceSensor(1)
+        ```
+
+        # Raises
+
+        - `ValueError`: If `port` isn't from 1 through 21 or is already in use.
+        - `TypeError`: If `port` is not an integer.
+        """
+        ...
+
+    def get_status(self) -> int:
+        """
+        Returns the internal status code of the distance sensor.
+
+        The status code of the signature can tell you if the sensor is still initializing or if it is
+        working correctly.
+
+        - If the distance sensor is still initializing, the status code will be 0x00.
+        - If it is done initializing and functioning correctly, the status code will be 0x82 or 0x86.
+
+        # Examples
 
         A simple initialization state check:
 
@@ -3376,6 +3973,41 @@ class DistanceSensor:
         """
         ...
 
+    def wait_until_within(self, distance: float) -> UntilFuture:
+        """
+        Waits until an object is detected no more than `distance` millimeters from the sensor.
+
+        Awaiting the returned `UntilFuture` returns `None`.
+
+        # Examples
+
+        Drive forward until a wall is 100mm away:
+
+        ```python
+        from venice import *
+
+        sensor = DistanceSensor(1)
+        left = Motor(2)
+        right = Motor(3, Direction.REVERSE)
+
+        async def main():
+        left.set_voltage(6)
+        right.set_voltage(6)
+        await sensor.wait_until_within(100)
+        left.brake(BrakeMode.BRAKE)
+        right.brake(BrakeMode.BRAKE)
+
+        vasyncio.run(main())
+        ```
+
+        # Raises
+
+        - `ValueError`: If the sensor binding is freed while awaiting.
+        - `DeviceError`: If, while awaiting, no sensor is connected to the port, or it has an
+        unknown status code.
+        """
+        ...
+
     def free(self) -> None:
         """
         Releases this sensor and frees its Smart Port lock.
@@ -3388,6 +4020,200 @@ class DistanceSensor:
         """
         ...
 
+class DifferentialDrive:
+    """
+    A drivetrain with a left and a right side, such as a tank drive, driven from joystick inputs.
+
+    Each side is a `Motor` or a `MotorGroup`, commanded by voltage as a fraction of its motors'
+    maximum voltage. Inputs are between -1 and 1, as read from a `Controller`'s sticks, with
+    positive values driving forwards or turning clockwise, to the right.
+
+    Before they are used, inputs are clamped to between -1 and 1 and then shaped. Inputs within
+    `deadband` of zero are ignored, so a stick that doesn't quite centre doesn't creep, and the
+    remaining range is stretched back out to 1 so that control starts smoothly at the dead band's
+    edge. `curve` then shapes the input: a number raises its magnitude to that power, so that 2 or 3
+    gives finer control at low speeds, and a function is called with the input and returns the
+    shaped one. When the inputs would ask for more than full power on either side, both sides are
+    scaled down together, so the robot keeps its heading rather than turning unexpectedly.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    left = MotorGroup([(1, Direction.REVERSE), (2, Direction.REVERSE)])
+    right = MotorGroup([3, 4])
+    drive = DifferentialDrive(left, right, deadband=0.05, curve=2.0)
+    controller = Controller()
+
+    async def main():
+    while True:
+    state = controller.read_state()
+    drive.arcade(state.left_stick.y, state.right_stick.x)
+    await vasyncio.Sleep(Controller.UPDATE_INTERVAL_MS, MILLIS)
+
+    vasyncio.run(main())
+    ```
+    """
+    def __init__(
+        self,
+        left: Motor | MotorGroup,
+        right: Motor | MotorGroup,
+        /,
+        *,
+        deadband: float = 0.0,
+        curve: float | Callable[[float], float] = 1.0,
+    ) -> None:
+        """
+        Creates a drivetrain from its `left` and `right` sides.
+
+        `deadband` is between 0 and 1, and `curve` is a positive exponent or a function from a
+        float to a float.
+
+        # Raises
+
+        - `TypeError`: If `left` or `right` isn't a `Motor` or `MotorGroup`, or `curve` isn't a
+        number or callable.
+        - `ValueError`: If `deadband` isn't at least 0 and less than 1, or `curve` is a number that
+        isn't positive and finite.
+        """
+        ...
+
+    def tank(self, left: float, right: float) -> None:
+        """
+        Drives each side directly from its own input, such as the left and right sticks' `y`.
+
+        # Raises
+
+        - `DeviceError`: If a motor can't be used.
+        - Any exception raised by the input curve.
+        """
+        ...
+
+    def arcade(self, forward: float, turn: float) -> None:
+        """
+        Drives forwards at `forward` while turning clockwise at `turn`.
+
+        Turning is added to one side and taken from the other, so the robot turns in place when
+        `forward` is zero.
+
+        # Raises
+
+        - `DeviceError`: If a motor can't be used.
+        - Any exception raised by the input curve.
+        """
+        ...
+
+    def curvature(self, throttle: float, curvature: float) -> None:
+        """
+        Drives forwards at `throttle` along a curve set by `curvature`, which is clockwise when
+        positive.
+
+        Unlike `DifferentialDrive.arcade`, the turning rate grows with speed, so the same
+        `curvature` traces the same arc whether the robot is moving quickly or slowly. This makes
+        the robot easier to steer at speed. When `throttle` is zero after the dead band, the robot
+        turns in place at `curvature` instead.
+
+        # Raises
+
+        - `DeviceError`: If a motor can't be used.
+        - Any exception raised by the input curve.
+        """
+        ...
+
+class HolonomicDrive:
+    """
+    A drivetrain with four omni or mecanum wheels that can drive in any direction, such as an
+    X-drive or a mecanum drive, driven from joystick inputs.
+
+    Each wheel is a `Motor` or a `MotorGroup`, commanded by voltage as a fraction of its motors'
+    maximum voltage. Inputs are between -1 and 1, as read from a `Controller`'s sticks, with
+    positive values driving forwards, moving right, or turning clockwise. The wheels are expected to
+    be set up so that a positive voltage on each drives the robot forwards.
+
+    Inputs are clamped, given a dead band and shaped by a curve as in `DifferentialDrive`. When the
+    inputs would ask for more than full power on any wheel, every wheel is scaled down together, so
+    the robot keeps moving in the requested direction.
+
+    With an `imu`, `HolonomicDrive.field_oriented` drives relative to the field instead of the
+    robot: forwards is the direction the robot faced when the sensor's rotation was last reset,
+    however the robot has turned since.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    imu = InertialSensor(5)
+    drive = HolonomicDrive(
+    Motor(1), Motor(2, Direction.REVERSE), Motor(3), Motor(4, Direction.REVERSE), imu=imu,
+    )
+    controller = Controller()
+
+    async def main():
+    await imu.calibrate()
+    while True:
+    state = controller.read_state()
+    drive.field_oriented(state.left_stick.y, state.left_stick.x, state.right_stick.x)
+    await vasyncio.Sleep(Controller.UPDATE_INTERVAL_MS, MILLIS)
+
+    vasyncio.run(main())
+    ```
+    """
+    def __init__(
+        self,
+        front_left: Motor | MotorGroup,
+        front_right: Motor | MotorGroup,
+        back_left: Motor | MotorGroup,
+        back_right: Motor | MotorGroup,
+        /,
+        *,
+        imu: InertialSensor | None = None,
+        deadband: float = 0.0,
+        curve: float | Callable[[float], float] = 1.0,
+    ) -> None:
+        """
+        Creates a drivetrain from its four wheels.
+
+        `deadband` and `curve` are as in `DifferentialDrive`. `imu` is needed for
+        `HolonomicDrive.field_oriented`.
+
+        # Raises
+
+        - `TypeError`: If a wheel isn't a `Motor` or `MotorGroup`, `imu` isn't an
+        `InertialSensor`, or `curve` isn't a number or callable.
+        - `ValueError`: If `deadband` isn't at least 0 and less than 1, or `curve` is a number that
+        isn't positive and finite.
+        """
+        ...
+
+    def arcade(self, forward: float, strafe: float, turn: float, /) -> None:
+        """
+        Drives relative to the robot: forwards at `forward`, right at `strafe`, and clockwise at
+        `turn`.
+
+        # Raises
+
+        - `DeviceError`: If a motor can't be used.
+        - Any exception raised by the input curve.
+        """
+        ...
+
+    def field_oriented(self, forward: float, strafe: float, turn: float, /) -> None:
+        """
+        Drives relative to the field: towards the field's forwards at `forward` and its right at
+        `strafe`, whichever way the robot faces, while turning clockwise at `turn`.
+
+        The robot's heading is read from `imu`.
+
+        # Raises
+
+        - `RuntimeError`: If the drivetrain has no `imu`.
+        - `DeviceError`: If a motor or the inertial sensor can't be used.
+        - Any exception raised by the input curve.
+        """
+        ...
+
 class Electromagnet:
     """
     An electromagnet plugged into a smart port.
@@ -3567,34 +4393,175 @@ class Electromagnet:
         """
         ...
 
-class GpsSensor:
+class PoseEstimator:
     """
-    A GPS sensor plugged into a Smart Port.
+    Fuses wheel odometry with GPS fixes into a smoothed estimate of the robot's pose on the field.
 
-    This module provides an interface to interact with the VEX V5 Game Position System (GPS) Sensor,
-    which uses computer vision and an inertial measurement unit (IMU) to provide absolute position
-    tracking within a VEX Robotics Competition field.
+    Between fixes, the estimate follows the motion measured by an `Odometry`, including the heading
+    from its inertial sensor if it has one, and its uncertainty grows as the robot drives. Each GPS
+    fix is weighted by the sensor's reported error (`GpsSensor.get_error`), so precise fixes correct
+    the estimate quickly while noisy ones barely move it. Fixes that disagree with the estimate by
+    much more than the uncertainty of both allows are rejected as outliers. If 25 fixes in a row are
+    rejected, the estimate is assumed to be wrong, such as after the robot was pushed, and restarts
+    from the next fix. A GPS reading is only fused once, when it changes, unless it stays the same
+    for a tenth of a second while the robot stands still.
 
-    # Hardware Description
+    The estimate is in the GPS sensor's field frame, with x and y measured in the odometry's unit.
+    Set `scale` to the number of odometry units in a metre, such as 39.37 for inches. Headings are
+    counterclockwise from the positive x axis, as with `Pose2.from_gps`.
 
-    The GPS sensor combines a monochrome camera and an IMU for robust position tracking through
-    visual odometry. It works by detecting QR-like patterns on the field perimeter, using both the
-    pattern sequence's and apparent size for position determination. The integrated IMU provides
-    motion tracking for position estimation when visual tracking is unavailable or unreliable.
+    There is no estimate until the first GPS fix is accepted, unless one is set with
+    `PoseEstimator.set_pose`.
 
-    The sensor has specific operating ranges: it requires a minimum distance of 20 inches from the
-    field perimeter for reliable readings, has a deadzone between 0-13.5 inches, and maintains
-    accuracy up to 12 feet from the perimeter.
+    The `outliers` attribute counts the fixes that have been rejected.
 
-    Sensor fusion between the camera and IMU helps maintain position tracking through dead zones and
-    areas of inconsistent visual detection.
+    # Examples
 
-    Further information about the sensor's method of operation can be found in [IFI's patent](https://docs.google.com/viewerng/viewer?url=https://patentimages.storage.googleapis.com/4f/74/30/eccf334da0ae38/WO2020219788A1.pdf).
-    """
-    def __init__(
-        self,
-        port: int,
-        offset: Point2,
+    ```python
+    from venice import *
+
+    odom = Odometry(
+    [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+    imu=InertialSensor(3),
+    )
+    gps = GpsSensor(4, Point2(0, 0), Point2(0, 0), 0, DEGREES)
+    estimator = PoseEstimator(odom, gps, scale=39.37)
+
+    async def track():
+    await estimator.run(10, MILLIS)
+
+    async def main():
+    vasyncio.spawn(track())
+    while True:
+    pose = estimator.get_pose()
+    if pose is not None:
+    print(pose)
+    await vasyncio.Sleep(100, MILLIS)
+
+    vasyncio.run(main())
+    ```
+    """
+
+    outliers: int
+    def __init__(
+        self,
+        odometry: Odometry,
+        gps: GpsSensor,
+        /,
+        *,
+        scale: float = 1.0,
+        odometry_error: float = 0.02,
+        turn_error: float = 0.02,
+        heading_error: float = 0.05,
+        gate: float = 4.0,
+    ) -> None:
+        """
+        Creates a pose estimator that fuses `odometry` with fixes from `gps`.
+
+        `scale` is the number of odometry units in a metre. `odometry_error` is the standard
+        deviation of the odometry's position error as a fraction of the distance travelled, and
+        `turn_error` is the standard deviation of its heading error as a fraction of the angle
+        turned. `heading_error` is the standard deviation of the GPS heading in radians; make it
+        large to rely on the odometry's heading instead. `gate` is how many standard deviations a
+        fix may be from the estimate before it is rejected.
+
+        # Raises
+
+        - `TypeError`: If `odometry` isn't an `Odometry`, `gps` isn't a `GpsSensor`, or a number
+        isn't numeric.
+        - `ValueError`: If `scale`, `heading_error` or `gate` isn't positive and finite, or
+        `odometry_error` or `turn_error` is negative or non-finite.
+        """
+        ...
+
+    def update(self) -> None:
+        """
+        Updates the odometry, then fuses the latest GPS fix into the estimate.
+
+        Use this instead of `PoseEstimator.run` to update the estimate from your own loop.
+
+        # Raises
+
+        - `DeviceError`: If a sensor can't be read.
+        """
+        ...
+
+    def run(
+        self, period: float = 10.0, unit: TimeUnit = TimeUnit.MILLIS, /
+    ) -> PoseEstimatorFuture:
+        """
+        Returns a `PoseEstimatorFuture` that updates the estimate every `period`, measured in
+        `unit`, until it is cancelled.
+
+        Each update also updates the odometry, so there is no need to run it separately.
+
+        # Raises
+
+        - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+        """
+        ...
+
+    def get_pose(self, /) -> Pose2 | None:
+        """Returns the estimated pose, or `None` if there is no estimate yet."""
+        ...
+
+    def get_covariance(self, /) -> tuple[tuple[float, float, float], ...]:
+        """
+        Returns the covariance of the estimate as three rows of three, in the order x, y, heading.
+
+        Positions are in odometry units and headings are in radians, so the square root of the
+        first entry is the standard deviation of x.
+        """
+        ...
+
+    def set_pose(self, pose: Pose2) -> None:
+        """Sets the estimate to `pose`, treating it as exact until the robot moves."""
+        ...
+
+    def reset(self) -> None:
+        """Discards the estimate so that the next accepted GPS fix starts a new one."""
+        ...
+
+class PoseEstimatorFuture:
+    """
+    An awaitable that updates a `PoseEstimator` on a fixed schedule until it is cancelled, returned
+    by `PoseEstimator.run`.
+
+    Scheduling works like `OdometryFuture`.
+
+    # Raises
+
+    - `DeviceError`: If a sensor can't be read.
+    """
+
+class GpsSensor:
+    """
+    A GPS sensor plugged into a Smart Port.
+
+    This module provides an interface to interact with the VEX V5 Game Position System (GPS) Sensor,
+    which uses computer vision and an inertial measurement unit (IMU) to provide absolute position
+    tracking within a VEX Robotics Competition field.
+
+    # Hardware Description
+
+    The GPS sensor combines a monochrome camera and an IMU for robust position tracking through
+    visual odometry. It works by detecting QR-like patterns on the field perimeter, using both the
+    pattern sequence's and apparent size for position determination. The integrated IMU provides
+    motion tracking for position estimation when visual tracking is unavailable or unreliable.
+
+    The sensor has specific operating ranges: it requires a minimum distance of 20 inches from the
+    field perimeter for reliable readings, has a deadzone between 0-13.5 inches, and maintains
+    accuracy up to 12 feet from the perimeter.
+
+    Sensor fusion between the camera and IMU helps maintain position tracking through dead zones and
+    areas of inconsistent visual detection.
+
+    Further information about the sensor's method of operation can be found in [IFI's patent](https://docs.google.com/viewerng/viewer?url=https://patentimages.storage.googleapis.com/4f/74/30/eccf334da0ae38/WO2020219788A1.pdf).
+    """
+    def __init__(
+        self,
+        port: int,
+        offset: Point2,
         initial_position: Point2,
         initial_heading: float,
         initial_heading_unit: RotationUnit,
@@ -5220,6 +6187,317 @@ class Point2:
         """
         ...
 
+class Pose2:
+    """
+    A robot's position and heading on the field.
+
+    `x` and `y` are mutable `float` attributes whose unit depends on the API that produced the pose;
+    `Odometry` uses the unit of its tracking wheel diameters and `Pose2.from_gps` uses metres.
+    `heading` is a mutable `float` attribute in radians, measured counterclockwise from the positive
+    x axis. Use `Pose2.get_heading` to read it in another unit. Deleting an attribute resets it to
+    `0.0`.
+
+    Adding a `Transform2` to a pose moves it by that transform in the pose's own frame, and
+    subtracting two poses returns the `Transform2` between them, so `a + (b - a) == b` up to
+    rounding. Poses support exact equality, return `False` when compared with another type, and
+    have a readable `Pose2(x=..., y=..., heading=...)` representation.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    start = Pose2(0, 0, 90, DEGREES)
+    # Drive 24 units forward, then turn 90 degrees left
+    end = start + Transform2(24, 0, 90, DEGREES)
+    print(end.x, end.y, end.get_heading(DEGREES))
+    ```
+    """
+
+    x: float
+    y: float
+    heading: float
+    def __init__(
+        self,
+        x: float = 0.0,
+        y: float = 0.0,
+        heading: float = 0.0,
+        unit: RotationUnit = RotationUnit.RADIANS,
+        /,
+    ) -> None:
+        """
+        Creates a pose at (`x`, `y`) with a heading of `heading`, measured in `unit`.
+
+        Every argument is positional-only, and the coordinates and heading default to `0.0`.
+
+        # Raises
+
+        - `TypeError`: If a number is not numeric, `unit` isn't a `RotationUnit`, a keyword argument
+        is supplied, or more than four positional arguments are given.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        pose = Pose2(12.0, -6.0, 45, DEGREES)
+        ```
+        """
+        ...
+
+    @staticmethod
+    def from_gps(gps: GpsSensor, /) -> Pose2:
+        """
+        Returns the pose reported by `gps`, in metres.
+
+        The GPS heading, which is measured clockwise with 0 pointing along the positive y axis, is
+        converted to a counterclockwise heading from the positive x axis.
+
+        # Raises
+
+        - `DeviceError`: If the sensor can't be read.
+        """
+        ...
+
+    def get_heading(self, unit: RotationUnit) -> float:
+        """Returns the heading measured in `unit`."""
+        ...
+
+    def transform_by(self, transform: Transform2) -> Pose2:
+        """Returns this pose moved by `transform` in its own frame. Equivalent to `self + transform`."""
+        ...
+
+    def relative_to(self, origin: Pose2) -> Pose2:
+        """
+        Returns this pose as seen from `origin`, as if `origin` were at (0, 0) facing along the
+        positive x axis.
+        """
+        ...
+
+    def interpolate(self, other: Pose2, t: float) -> Pose2:
+        """
+        Returns the pose a fraction `t` of the way from this pose to `other`.
+
+        The position is interpolated in a straight line and the heading turns the short way around.
+        `t` is not clamped, so values outside [0, 1] extrapolate.
+        """
+        ...
+
+    def distance_to(self, target: Pose2 | Point2, /) -> float:
+        """
+        Returns the straight-line distance from this pose to `target`.
+
+        # Raises
+
+        - `TypeError`: If `target` isn't a `Pose2` or `Point2`.
+        """
+        ...
+
+    def angle_to(self, target: Pose2 | Point2, unit: RotationUnit, /) -> float:
+        """
+        Returns how far this pose must turn to face `target`, measured in `unit`.
+
+        The angle is counterclockwise positive and wrapped to the half-open range of -180 to 180
+        degrees.
+
+        # Raises
+
+        - `TypeError`: If `target` isn't a `Pose2` or `Point2`.
+        """
+        ...
+
+class Transform2:
+    """
+    A rigid motion in the plane: a translation by (`x`, `y`) followed by a turn of `rotation`.
+
+    The translation is measured in the frame of whatever the transform is applied to, so
+    `Transform2(1, 0, 0)` always means one unit forward. `x` and `y` are mutable `float` attributes,
+    and `rotation` is a mutable `float` attribute in radians, counterclockwise positive. Use
+    `Transform2.get_rotation` to read it in another unit. Deleting an attribute resets it to `0.0`.
+
+    Adding two transforms composes them, applying the left one first. Transforms support exact
+    equality, return `False` when compared with another type, and have a readable
+    `Transform2(x=..., y=..., rotation=...)` representation.
+    """
+
+    x: float
+    y: float
+    rotation: float
+    def __init__(
+        self,
+        x: float = 0.0,
+        y: float = 0.0,
+        rotation: float = 0.0,
+        unit: RotationUnit = RotationUnit.RADIANS,
+        /,
+    ) -> None:
+        """
+        Creates a transform that moves by (`x`, `y`) and then turns by `rotation`, measured in
+        `unit`.
+
+        Every argument is positional-only, and the translation and rotation default to `0.0`.
+
+        # Raises
+
+        - `TypeError`: If a number is not numeric, `unit` isn't a `RotationUnit`, a keyword argument
+        is supplied, or more than four positional arguments are given.
+        """
+        ...
+
+    def get_rotation(self, unit: RotationUnit) -> float:
+        """Returns the rotation measured in `unit`."""
+        ...
+
+    def inverse(self) -> Transform2:
+        """Returns the transform that undoes this one, so that `t + t.inverse()` is the identity."""
+        ...
+
+class PidController:
+    """
+    A PID (proportional-integral-derivative) controller with feedforward.
+
+    Each call to `PidController.update` compares a setpoint with a measurement and returns the
+    output that drives the measurement towards the setpoint, such as a motor voltage. The output is
+    the sum of:
+
+    - a proportional term, `kp * (setpoint_weight * setpoint - measurement)`;
+    - an integral term, `ki` times the accumulated error, limited to `integral_limit`;
+    - a derivative term, `kd` times the rate of change of
+    `derivative_setpoint_weight * setpoint - measurement`, smoothed by a low-pass filter with a
+    time constant of `derivative_filter` seconds;
+    - a feedforward term, `ks * sign(velocity) + kv * velocity + ka * acceleration`, where
+    `velocity` and `acceleration` describe how the setpoint is moving.
+
+    The output is then limited to `output_limit`. While the output is limited, the integral term
+    stops accumulating in the direction that would push it further past the limit.
+
+    Setpoint weights below `1.0` soften the response to setpoint changes without affecting how
+    disturbances are rejected. A `derivative_setpoint_weight` of `0.0` is a common choice to avoid a
+    spike in the output when the setpoint changes suddenly.
+
+    Gains are per second: `dt` passed to `PidController.update` is converted to seconds, `ki` is
+    multiplied by the error integrated over seconds, and `kd` by the error's rate of change per
+    second. The gains `kp`, `ki`, `kd`, `ks`, `kv` and `ka` can be read and changed as attributes.
+
+    # Continuous input
+
+    If `continuous` is a `RotationUnit`, setpoints and measurements are treated as angles in that
+    unit that wrap around every turn. The controller then always takes the shortest way around, so
+    a setpoint of 350 degrees and a measurement of 10 degrees give an error of -20 degrees rather
+    than 340 degrees.
+    """
+
+    kp: float
+    ki: float
+    kd: float
+    ks: float
+    kv: float
+    ka: float
+    def __init__(
+        self,
+        kp: float,
+        ki: float = 0.0,
+        kd: float = 0.0,
+        /,
+        *,
+        ks: float = 0.0,
+        kv: float = 0.0,
+        ka: float = 0.0,
+        integral_limit: float | None = None,
+        output_limit: float | None = None,
+        derivative_filter: float = 0.0,
+        setpoint_weight: float = 1.0,
+        derivative_setpoint_weight: float = 1.0,
+        continuous: RotationUnit | None = None,
+    ) -> None:
+        """
+        Creates a PID controller with the given gains.
+
+        All other settings are keyword-only and default to a plain PID controller: no feedforward,
+        no limits, no derivative filtering, full setpoint weights and discontinuous input.
+
+        # Raises
+
+        - `TypeError`: If an argument has the wrong type or an unknown keyword argument is given.
+        - `ValueError`: If `integral_limit`, `output_limit` or `derivative_filter` is negative.
+
+        # Examples
+
+        Turn a drivetrain to a heading with an inertial sensor:
+
+        ```python
+        from venice import *
+
+        imu = InertialSensor(1)
+        left = MotorGroup([2, 3])
+        right = MotorGroup([(4, Direction.REVERSE), (5, Direction.REVERSE)])
+
+        pid = PidController(0.3, 0.01, 0.02, output_limit=12, continuous=DEGREES)
+
+        async def main():
+        while True:
+        voltage = pid.update(90, imu.get_heading(DEGREES), 10, unit=MILLIS)
+        left.set_voltage(voltage)
+        right.set_voltage(-voltage)
+        await vasyncio.Sleep(10, MILLIS)
+
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
+    def update(
+        self,
+        setpoint: float,
+        measurement: float,
+        dt: float,
+        /,
+        *,
+        unit: TimeUnit = TimeUnit.SECOND,
+        velocity: float = 0.0,
+        acceleration: float = 0.0,
+    ) -> float:
+        """
+        Returns the output for a new `measurement` of the system, `dt` after the previous update.
+
+        `dt` is measured in `unit` and should be the time since the previous call to `update`; it is
+        used to integrate and differentiate the error. `velocity` and `acceleration` are the rate of
+        change of the setpoint per second and per second squared, used for feedforward. They can be
+        left at zero when the setpoint is constant.
+
+        # Raises
+
+        - `ValueError`: If `dt` is not positive or is non-finite.
+
+        # Examples
+
+        Hold an arm at 45 degrees:
+
+        ```python
+        from venice import *
+
+        arm = Motor(1)
+        pid = PidController(0.2, 0.0, 0.01, output_limit=Motor.V5_MAX_VOLTAGE)
+
+        async def main():
+        while True:
+        arm.set_voltage(pid.update(45, arm.get_position(DEGREES), 10, unit=MILLIS))
+        await vasyncio.Sleep(10, MILLIS)
+
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
+    def reset(self) -> None:
+        """
+        Clears the accumulated integral and the derivative history.
+
+        Call this before reusing the controller for a new movement, so that error accumulated during
+        the previous one doesn't carry over.
+        """
+        ...
+
 class BrakeMode:
     """
     Determines the behavior a motor should use when braking with `Motor.brake`.
@@ -5267,542 +6545,541 @@ class Gearset:
     BLUE: ClassVar[Gearset]
     """6:1 gear ratio with a rated maximum speed of 600 RPM."""
 
-class Motor:
+class MotorGroup:
     """
-    A motor plugged into a Smart Port.
+    Several motors that are driven together as one.
 
-    This class provides abstractions for interacting with VEX Smart Motors, supporting both the 11W
-    and 5.5W variants.
+    A `MotorGroup` is useful for mechanisms that are powered by more than one motor, such as one
+    side of a drivetrain or a double-motor lift. Every command sent to the group is sent to each of
+    its motors, and readings from the group combine the readings of each motor:
 
-    # Overview
+    - Position, velocity, voltage and efficiency are averaged.
+    - Current, power and torque are summed.
+    - Temperature is the hottest motor's temperature.
+    - Fault and status flags are combined with a bitwise OR, and the `is_*` flag checks are `True`
+    if any motor has the flag set.
 
-    The V5 Smart Motors come in two variants: [an 11W model](https://www.vexrobotics.com/276-4840.html),
-    with interchangeable gear cartridges and [a 5.5W model](https://www.vexrobotics.com/276-4842.html),
-    with a fixed gearing. The 11W motor supports three cartridge options, which will gear the motor
-    down from its base RPM of 3600: a red cartridge providing 100 RPM output, a green cartridge for
-    200 RPM, and a blue cartridge for 600 RPM. The 5.5W motor comes with a non-interchangeable 200
-    RPM gear cartridge.
-
-    Smart Motors feature several integrated sensors, including an encoder for measuring the velocity
-    and position of the motor, a temperature sensor for detecting overheats, and sensors for
-    measuring output voltage, current, and efficiency.
-
-    Communication between a Smart motor and the V5 Brain occur at two different intervals. While
-    the motor communicates with the Brain every 5 milliseconds (and commands can be written to the
-    motor every 5mS), the Brain only reads data from the motor every 10mS. This effectively places
-    the date *write* interval at 5mS and the data *read* interval at 10mS.
-
-    More in-depth specs for the 11W motor can be found [here](https://kb.vex.com/hc/en-us/articles/360060929971-Understanding-V5-Smart-Motors).
-
-    # Current Limitations
-
-    There are some cases where VEXos or the motor itself may decide to limit output current:
-
-    - **Stall Prevention**: The stall current on 11W motors is limited to 2.5A. This limitation
-    eliminates the need for automatic resetting fuses (PTC devices) in the motor, which can
-    disrupt operation. By restricting the stall current to 2.5A, the motor effectively avoids
-    undesirable performance dips and ensures that users do not inadvertently cause stall
-    situations.
-
-    - **Motor Count**: Robots that use 8 or fewer 11W motors will have the aforementioned current limit
-    of 2.5A set for each motor. Robots using more than 8 motors, will have a lower default current limit
-    per-motor than 2.5A. This limit is determined in VEXos by a calculation accounting for the number of
-    motors plugged in, and the user's manually set current limits using `Motor.set_current_limit`. For
-    more information regarding the current limiting behavior of VEXos, see [this forum post](https://www.vexforum.com/t/how-does-the-decreased-current-affect-the-robot-when-using-more-than-8-motors/72650/4).
-
-    - **Temperature Management**: Motors have an onboard sensor for measuring internal temperature.
-    If the motor determines that it is overheating, it will throttle its output current and warn
-    the user.
+    Each motor's `Direction` is chosen when the group is created, so motors that are mounted facing
+    the opposite way can be reversed once and then commanded like the rest of the group. Unlike
+    `Motor`, a group has no `set_direction` method.
 
-    # Motor Control
+    The group reserves each of its Smart Ports until it is freed with `MotorGroup.free`.
 
-    Each motor contains a sophisticated control system built around a Cortex M0+ microcontroller.
-    The microcontroller continuously monitors position, speed, direction, voltage, current, and
-    temperature through integrated sensors.
+    # Errors from individual motors
 
-    The onboard motor firmware implements a set of pre-tuned PID (Proportional-Integral-Derivative)
-    controllers operating on a 10-millisecond cycle for position and velocity control. Motors also
-    have braking functionality for holding a specific position under load.
+    Commands are always sent to every motor in the group, even if sending it to an earlier motor
+    failed, so one disconnected motor won't leave the others running with a stale command. If any
+    motor failed, the first error is raised once every motor has been commanded. Readings raise as
+    soon as any motor fails to report.
     """
-
-    is_exp: bool
-    is_v5: bool
-    max_voltage: float
-    motor_type: MotorType
-    V5_MAX_VOLTAGE: ClassVar[float]
-    """The maximum voltage value that can be sent to a V5 `Motor`."""
-    EXP_MAX_VOLTAGE: ClassVar[float]
-    """The maximum voltage value that can be sent to an EXP `Motor`."""
-    WRITE_INTERVAL_MS: ClassVar[int]
-    """The interval at which the Brain will send new packets to a `Motor`, in milliseconds."""
-
-    @staticmethod
-    def new_v5(
-        port: int,
-        direction: Direction = Direction.FORWARD,
+    def __init__(
+        self,
+        motors: list[int | tuple[int, Direction]],
         gearset: Gearset = Gearset.GREEN,
         /,
-    ) -> Motor: ...
-    @staticmethod
-    def new_exp(port: int, direction: Direction = Direction.FORWARD, /) -> Motor:
+    ) -> None:
         """
-        Creates a new 5.5W (EXP) Smart Motor.
+        Creates a group of 11W (V5) Smart Motors.
 
-        See `Motor()` or `Motor.new_v5` to create a 11W (V5) Smart Motor.
+        Each entry of `motors` is either a port number, for a motor spinning `Direction.FORWARD`, or
+        a `(port, Direction)` pair. Every motor in the group uses `gearset`.
+
+        See `MotorGroup.new_exp` to create a group of 5.5W (EXP) Smart Motors.
+
+        # Raises
+
+        - `TypeError`: If `motors` isn't a list or tuple of port numbers and `(port, Direction)`
+        pairs.
+        - `ValueError`: If `motors` is empty, names an invalid port or the same port twice, or if any
+        of the ports is already in use. In that case, none of the ports are reserved.
 
         # Examples
 
+        Drive the left side of a drivetrain, where the back motor is mounted backwards:
+
         ```python
         from venice import *
 
-        motor = Motor(1)
-        assert motor.is_exp
-        assert motor.max_voltage == Motor.EXP_MAX_VOLTAGE
+        left = MotorGroup([1, 2, (3, Direction.REVERSE)], Gearset.BLUE)
+        left.set_voltage(6)
         ```
         """
         ...
 
-    def __init__(
-        self,
-        port: int,
-        direction: Direction = Direction.FORWARD,
-        gearset: Gearset = Gearset.GREEN,
-        /,
-    ) -> None:
+    @staticmethod
+    def new_exp(motors: list[int | tuple[int, Direction]], /) -> MotorGroup:
         """
-        Creates a new 11W (V5) Smart Motor.
+        Creates a group of 5.5W (EXP) Smart Motors.
 
-        See `Motor.new_exp` to create a 5.5W (EXP) Smart Motor.
+        `motors` is given in the same form as for `MotorGroup()`.
+
+        # Raises
+
+        - `TypeError`: If `motors` isn't a list or tuple of port numbers and `(port, Direction)`
+        pairs.
+        - `ValueError`: If `motors` is empty, names an invalid port or the same port twice, or if any
+        of the ports is already in use. In that case, none of the ports are reserved.
 
         # Examples
 
         ```python
         from venice import *
 
-        motor = Motor(1)
-        assert motor.is_v5
-        assert motor.max_voltage == Motor.V5_MAX_VOLTAGE
+        intake = MotorGroup.new_exp([4, (5, Direction.REVERSE)])
+        intake.set_voltage(Motor.EXP_MAX_VOLTAGE)
         ```
         """
         ...
 
     def set_voltage(self, volts: float) -> None:
         """
-        Sets the motor's output voltage.
+        Sets the output voltage of every motor in the group.
 
-        This voltage value spans from -12 (fully spinning reverse) to +12 (fully spinning forwards)
-        volts, and controls the raw output of the motor.
+        See `Motor.set_voltage`.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
-
-        # Examples
-
-        Give the motor full power:
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        ```python
-        from venice import *
+    def set_velocity(self, rpm: int) -> None:
+        """
+        Spins every motor in the group at a target velocity in RPM.
 
-        v5_motor = Motor(1)
-        exp_motor = Motor(2)
+        See `Motor.set_velocity`.
 
-        v5_motor.set_voltage(v5_motor.max_voltage)
-        exp_motor.set_voltage(exp_motor.max_voltage)
-        ```
+        # Raises
 
-        Drive the motor based on a controller joystick:
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        ```python
-        from venice import *
+    def brake(self, mode: BrakeMode) -> None:
+        """
+        Stops every motor in the group with the given `BrakeMode`.
 
-        motor = Motor(1)
-        controller = Controller()
+        # Raises
 
-        async def main():
-        while True:
-        controller_state = controller.get_state()
-        voltage = controller_state.left_stick.x * motor.max_voltage
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        motor.set_voltage(voltage)
+    def set_gearset(self, gearset: Gearset) -> None:
+        """
+        Sets the gearset of every motor in the group.
 
-        await vasyncio.Sleep(Motor.WRITE_INTERVAL_MS, MILLIS)
+        # Raises
 
-        vasyncio.run(main)
-        ```
+        `DeviceError`: If any motor is a 5.5W EXP Smart Motor, which has no swappable gearset, or if
+        any motor is disconnected.
         """
         ...
 
-    def set_velocity(self, rpm: int) -> None:
+    def set_position_target(
+        self, position: float, unit: RotationUnit, velocity: int, /
+    ) -> None:
         """
-        Spins the motor at a target velocity.
+        Sets an absolute position target for every motor in the group to attempt to reach.
 
-        This velocity corresponds to different actual speeds in RPM depending on the gearset used
-        for the motor. Velocity is held with an internal PID controller to ensure consistent
-        speed, as opposed to setting the motor's voltage.
+        Each motor moves to the target as measured by its own encoder, so motors whose positions
+        have drifted apart should be reset with `MotorGroup.reset_position` first.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        # Examples
+    def set_profiled_velocity(self, velocity: int) -> None:
+        """
+        Changes the output velocity of every motor's profiled movement.
 
-        Spin a motor at 100 RPM:
+        See `Motor.set_profiled_velocity`.
 
-        ```python
-        from venice import *
+        # Raises
 
-        motor = Motor(1)
-        motor.set_velocity(100)
-        ```
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def brake(self, mode: BrakeMode) -> None:
+    def reset_position(self) -> None:
         """
-        Stops this motor with the given `BrakeMode`.
+        Sets the encoder position of every motor in the group to zero without moving them.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        # Examples
+    def set_position(self, position: float, unit: RotationUnit) -> None:
+        """
+        Sets the encoder position of every motor in the group to the given position without moving
+        them.
 
-        ```python
-        from venice import *
+        # Raises
 
-        motor = Motor(1)
-        motor.brake(BrakeMode.HOLD)
-        ```
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def set_gearset(self, gearset: Gearset) -> None:
+    def set_current_limit(self, limit: float) -> None:
         """
-        Sets the gearset of an 11W motor.
+        Sets the current limit of every motor in the group in amps.
 
-        # Raises
+        The limit applies to each motor separately, so the group as a whole may draw up to `limit`
+        times the number of motors.
 
-        `DeviceError`: If the motor is a 5.5W EXP Smart Motor, which has no swappable gearset, or if
-        no motor is connected to the port.
+        # Raises
 
-        # Examples
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        ```python
-        from venice import *
+    def set_voltage_limit(self, limit: float) -> None:
+        """
+        Sets the voltage limit of every motor in the group in volts.
 
-        # This must be a V5 motor
-        motor = Motor(1, Direction.FORWARD, Gearset.GREEN)
+        # Raises
 
-        # Set the motor to use the red gearset
-        motor.set_gearset(Gearset.RED)
-        ```
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def get_gearset(self, /) -> Gearset:
+    def get_position(self, unit: RotationUnit) -> float:
         """
-        Returns the gearset of the motor
-
-        For 5.5W motors, this will always be returned as `Gearset::Green`.
+        Returns the average angular position of the motors in the group.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
 
         # Examples
 
-        Print the gearset of a motor:
-
         ```python
         from venice import *
 
-        def print_gearset(motor: Motor):
-        try:
-        gearset = motor.gearset()
-        except:
-        print("Failed to get gearset. Is this an EXP motor?")
-        return
-
-        if gearset == Gearset.RED:
-        print("Motor is using the red gearset")
-        elif gearset == Gearset.GREEN:
-        print("Motor is using the green gearset")
-        elif gearset == Gearset.BLUE:
-        print("Motor is using the blue gearset")
+        lift = MotorGroup([1, (2, Direction.REVERSE)])
+        print(f"Lift position: {lift.get_position(DEGREES)}")
         ```
         """
         ...
 
-    def set_position_target(
-        self, position: float, unit: RotationUnit, velocity: int, /
-    ) -> None:
+    def get_velocity(self) -> float:
         """
-        Sets an absolute position target for the motor to attempt to reach.
+        Returns the average estimated angular velocity of the motors in the group in RPM.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
-
-        # Examples
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        ```python
-        from venice import *
+    def get_voltage(self) -> float:
+        """
+        Returns the average voltage of the motors in the group in volts.
 
-        motor = Motor(1)
+        # Raises
 
-        # Turn the motor to a position of 90 degrees at 200rpm.
-        motor.set_position_target(90, DEGREES, 200)
-        ```
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def get_velocity(self) -> float: ...
-    def get_power(self) -> float: ...
-    def get_torque(self) -> float:
+    def get_efficiency(self) -> float:
         """
-        Returns the torque output of the motor in Nm.
+        Returns the average efficiency of the motors in the group from a range of [0.0, 1.0].
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        # Examples
+    def get_current(self) -> float:
+        """
+        Returns the total electrical current drawn by the motors in the group in amps.
 
-        Print the torque output of a motor:
+        # Raises
 
-        ```python
-        from venice import *
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        motor = Motor(1)
+    def get_power(self) -> float:
+        """
+        Returns the total power drawn by the motors in the group in Watts.
 
-        async def main():
-        while True:
-        torque = motor.get_torque()
-        print(f"Torque: {torque:.2}Nm")
-        await vasyncio.Sleep(10, MILLIS)
+        # Raises
 
-        vasyncio.run(main)
-        ```
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def get_voltage(self) -> float:
+    def get_torque(self) -> float:
         """
-        Returns the voltage the motor is drawing in volts.
+        Returns the total torque output of the motors in the group in Nm.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
+
+    def get_temperature(self) -> float:
+        """
+        Returns the internal temperature of the hottest motor in the group in increments of 5 °C.
+
+        # Raises
+
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
 
         # Examples
 
-        Print the voltage drawn by a motor:
+        Stop the group if any of its motors gets too hot:
 
         ```python
         from venice import *
 
-        motor = Motor(1)
+        drive = MotorGroup([1, 2, 3])
 
         async def main():
-        while True:
-        voltage = motor.get_voltage()
-        print(f"Voltage: {voltage:.2}V")
+        drive.set_voltage(12)
+        while drive.get_temperature() <= 50:
         await vasyncio.Sleep(10, MILLIS)
+        drive.brake(BrakeMode.COAST)
 
-        vasyncio.run(main)
+        vasyncio.run(main())
         ```
         """
         ...
 
-    def get_raw_position(self) -> int:
+    def get_status(self) -> int:
         """
-        Returns the most recently recorded raw encoder tick data from the motor's IME.
-
-        The motor's integrated encoder has a TPR of 4096. Gearset is not taken into consideration
-        when dealing with the raw value, meaning this measurement will be taken relative to the
-        motor's internal position *before* being geared down from 3600RPM.
+        Returns the status flags of every motor in the group, combined with a bitwise OR.
 
-        Methods such as `Motor.reset_position` and `Motor.set_position` do not change the value of
-        this raw measurement.
+        See `Motor.get_status`.
 
         # Raises
 
-        - `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
-
-        # Examples
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        ```python
-        from venice import *
+    def get_faults(self) -> int:
+        """
+        Returns the fault flags of every motor in the group, combined with a bitwise OR.
 
-        async def main():
-        motor = Motor(1)
-        while True:
-        raw_pos = motor.get_raw_position()
-        print(f"Raw Position: {raw_pos}")
+        See `Motor.get_faults`.
 
-        await vasyncio.Sleep(Motor.WRITE_INTERVAL_MS, MILLIS)
+        # Raises
 
-        vasyncio.run(main())
-        ```
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def get_current(self) -> float:
+    def is_over_temperature(self) -> bool:
         """
-        Returns the electrical current draw of the motor in amps.
+        Returns `True` if any motor in the group has its over temperature flag set.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        # Examples
+    def is_over_current(self) -> bool:
+        """
+        Returns `True` if any motor in the group has its over-current flag set.
 
-        Print the current draw of a motor:
+        # Raises
 
-        ```python
-        from venice import *
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        motor = Motor(1)
+    def is_driver_fault(self) -> bool:
+        """
+        Returns `True` if a H-bridge (motor driver) fault has occurred in any motor in the group.
 
-        async def main():
-        motor.set_voltage(motor.max_voltage)
-        while True:
-        current = motor.get_current()
-        print(f"Current: {current:.2}A")
+        # Raises
 
-        await vasyncio.Sleep(10, MILLIS)
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        vasyncio.run(main)
-        ```
+    def is_driver_over_current(self) -> bool:
+        """
+        Returns `True` if the H-bridge of any motor in the group has an over-current fault.
+
+        # Raises
+
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def get_efficiency(self) -> float:
+    def free(self) -> None:
         """
-        Returns the efficiency of the motor from a range of [0.0, 1.0].
+        Release every motor in this group and free their Smart Port locks. This binding will become
+        unusable after this call, but you can reuse the underlying Smart Port numbers in new
+        devices.
 
-        An efficiency of 1.0 means that the motor is moving electrically while drawing no electrical
-        power, and an efficiency of 0.0 means that the motor is drawing power but not moving.
+        Any attempts to use this group after freeing will result in a `ValueError` being raised.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `ValueError`: If the group has already been freed.
+        """
+        ...
 
-        # Examples
+class Motor:
+    """
+    A motor plugged into a Smart Port.
 
-        Print the efficiency of a motor:
+    This class provides abstractions for interacting with VEX Smart Motors, supporting both the 11W
+    and 5.5W variants.
 
-        ```python
-        from venice import *
+    # Overview
 
-        motor = Motor(1)
+    The V5 Smart Motors come in two variants: [an 11W model](https://www.vexrobotics.com/276-4840.html),
+    with interchangeable gear cartridges and [a 5.5W model](https://www.vexrobotics.com/276-4842.html),
+    with a fixed gearing. The 11W motor supports three cartridge options, which will gear the motor
+    down from its base RPM of 3600: a red cartridge providing 100 RPM output, a green cartridge for
+    200 RPM, and a blue cartridge for 600 RPM. The 5.5W motor comes with a non-interchangeable 200
+    RPM gear cartridge.
 
-        async def main():
-        motor.set_voltage(motor.max_voltage)
-        while True:
-        efficiency = motor.get_efficiency()
-        print(f"Current: {efficiency:.2}")
+    Smart Motors feature several integrated sensors, including an encoder for measuring the velocity
+    and position of the motor, a temperature sensor for detecting overheats, and sensors for
+    measuring output voltage, current, and efficiency.
 
-        await vasyncio.Sleep(10, MILLIS)
-        ```
-        """
-        ...
+    Communication between a Smart motor and the V5 Brain occur at two different intervals. While
+    the motor communicates with the Brain every 5 milliseconds (and commands can be written to the
+    motor every 5mS), the Brain only reads data from the motor every 10mS. This effectively places
+    the date *write* interval at 5mS and the data *read* interval at 10mS.
 
-    def get_current_limit(self) -> float:
-        """
-        Returns the current limit for the motor in amps.
+    More in-depth specs for the 11W motor can be found [here](https://kb.vex.com/hc/en-us/articles/360060929971-Understanding-V5-Smart-Motors).
 
-        This limit can be configured with the `Motor.set_current_limit` method.
+    # Current Limitations
 
-        # Raises
+    There are some cases where VEXos or the motor itself may decide to limit output current:
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+    - **Stall Prevention**: The stall current on 11W motors is limited to 2.5A. This limitation
+    eliminates the need for automatic resetting fuses (PTC devices) in the motor, which can
+    disrupt operation. By restricting the stall current to 2.5A, the motor effectively avoids
+    undesirable performance dips and ensures that users do not inadvertently cause stall
+    situations.
 
-        # Examples
+    - **Motor Count**: Robots that use 8 or fewer 11W motors will have the aforementioned current limit
+    of 2.5A set for each motor. Robots using more than 8 motors, will have a lower default current limit
+    per-motor than 2.5A. This limit is determined in VEXos by a calculation accounting for the number of
+    motors plugged in, and the user's manually set current limits using `Motor.set_current_limit`. For
+    more information regarding the current limiting behavior of VEXos, see [this forum post](https://www.vexforum.com/t/how-does-the-decreased-current-affect-the-robot-when-using-more-than-8-motors/72650/4).
 
-        Print the current limit of a motor:
+    - **Temperature Management**: Motors have an onboard sensor for measuring internal temperature.
+    If the motor determines that it is overheating, it will throttle its output current and warn
+    the user.
 
-        ```python
-        from venice import *
+    # Motor Control
 
-        motor = Motor(1)
-        current_limit = motor.get_current_limit()
-        print(f"Current Limit: {current_limit:.2}A")
-        ```
-        """
-        ...
+    Each motor contains a sophisticated control system built around a Cortex M0+ microcontroller.
+    The microcontroller continuously monitors position, speed, direction, voltage, current, and
+    temperature through integrated sensors.
 
-    def get_voltage_limit(self) -> float:
-        """
-        Returns the voltage limit for the motor if one has been explicitly set.
+    The onboard motor firmware implements a set of pre-tuned PID (Proportional-Integral-Derivative)
+    controllers operating on a 10-millisecond cycle for position and velocity control. Motors also
+    have braking functionality for holding a specific position under load.
+    """
 
-        # Raises
+    is_exp: bool
+    is_v5: bool
+    max_voltage: float
+    motor_type: MotorType
+    V5_MAX_VOLTAGE: ClassVar[float]
+    """The maximum voltage value that can be sent to a V5 `Motor`."""
+    EXP_MAX_VOLTAGE: ClassVar[float]
+    """The maximum voltage value that can be sent to an EXP `Motor`."""
+    WRITE_INTERVAL_MS: ClassVar[int]
+    """The interval at which the Brain will send new packets to a `Motor`, in milliseconds."""
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+    @staticmethod
+    def new_v5(
+        port: int,
+        direction: Direction = Direction.FORWARD,
+        gearset: Gearset = Gearset.GREEN,
+        /,
+    ) -> Motor: ...
+    @staticmethod
+    def new_exp(port: int, direction: Direction = Direction.FORWARD, /) -> Motor:
+        """
+        Creates a new 5.5W (EXP) Smart Motor.
 
-        # Examples
+        See `Motor()` or `Motor.new_v5` to create a 11W (V5) Smart Motor.
 
-        Print the voltage limit of a motor:
+        # Examples
 
         ```python
         from venice import *
 
         motor = Motor(1)
-        voltage_limit = motor.get_voltage_limit()
-        print(f"Voltage Limit: {voltage_limit:.2}V")
+        assert motor.is_exp
+        assert motor.max_voltage == Motor.EXP_MAX_VOLTAGE
         ```
         """
         ...
 
-    def get_temperature(self) -> float:
+    def __init__(
+        self,
+        port: int,
+        direction: Direction = Direction.FORWARD,
+        gearset: Gearset = Gearset.GREEN,
+        /,
+    ) -> None:
         """
-        Returns the internal temperature recorded by the motor in increments of 5 °C.
-
-        # Raises
+        Creates a new 11W (V5) Smart Motor.
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        See `Motor.new_exp` to create a 5.5W (EXP) Smart Motor.
 
         # Examples
 
-        Turn off the motor if it gets too hot:
-
         ```python
         from venice import *
 
         motor = Motor(1)
-
-        async def main():
-        motor.set_voltage(12)
-        while True:
-        if motor.get_temperature() > 30:
-        motor.brake(BrakeMode.COAST)
-        else:
-        motor.set_voltage(12)
-        await vasyncio.Sleep(10, MILLIS)
+        assert motor.is_v5
+        assert motor.max_voltage == Motor.V5_MAX_VOLTAGE
         ```
         """
         ...
 
-    def set_profiled_velocity(self, velocity: int) -> None:
+    def set_voltage(self, volts: float) -> None:
         """
-        Changes the output velocity for a profiled movement (motor_move_absolute or
-        motor_move_relative).
+        Sets the motor's output voltage.
 
-        This will have no effect if the motor is not following a profiled movement.
+        This voltage value spans from -12 (fully spinning reverse) to +12 (fully spinning forwards)
+        volts, and controls the raw output of the motor.
 
         # Raises
 
@@ -5811,50 +7088,47 @@ class Motor:
 
         # Examples
 
+        Give the motor full power:
+
         ```python
         from venice import *
 
-        motor = Motor(1)
+        v5_motor = Motor(1)
+        exp_motor = Motor(2)
 
-        # Set the motor's target to a Position so that changing the velocity isn't a noop.
-        motor.set_position_target(90, DEGREES, 200)
-        motor.set_profiled_velocity(100)
+        v5_motor.set_voltage(v5_motor.max_voltage)
+        exp_motor.set_voltage(exp_motor.max_voltage)
         ```
-        """
-        ...
 
-    def reset_position(self) -> None:
-        """
-        Sets the current encoder position to zero without moving the motor.
-
-        Analogous to taring or resetting the encoder to the current position.
-
-        # Raises
-
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
-
-        # Examples
-
-        Move the motor in increments of 10 degrees:
+        Drive the motor based on a controller joystick:
 
         ```python
         from venice import *
 
         motor = Motor(1)
+        controller = Controller()
 
         async def main():
         while True:
-        motor.set_position_target(10, DEGREES, 200)
-        await vasyncio.Sleep(1, SECOND)
-        motor.reset_position()
+        controller_state = controller.get_state()
+        voltage = controller_state.left_stick.x * motor.max_voltage
+
+        motor.set_voltage(voltage)
+
+        await vasyncio.Sleep(Motor.WRITE_INTERVAL_MS, MILLIS)
+
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def set_current_limit(self, limit: float) -> None:
+    def set_velocity(self, rpm: int) -> None:
         """
-        Sets the current limit for the motor in amps.
+        Spins the motor at a target velocity.
+
+        This velocity corresponds to different actual speeds in RPM depending on the gearset used
+        for the motor. Velocity is held with an internal PID controller to ensure consistent
+        speed, as opposed to setting the motor's voltage.
 
         # Raises
 
@@ -5863,20 +7137,20 @@ class Motor:
 
         # Examples
 
-        Limit the current draw of a motor to 2.5A:
+        Spin a motor at 100 RPM:
 
         ```python
         from venice import *
 
         motor = Motor(1)
-        motor.set_current_limit(2.5)
+        motor.set_velocity(100)
         ```
         """
         ...
 
-    def set_voltage_limit(self, limit: float) -> None:
+    def brake(self, mode: BrakeMode) -> None:
         """
-        Sets the voltage limit for the motor in volts.
+        Stops this motor with the given `BrakeMode`.
 
         # Raises
 
@@ -5885,54 +7159,43 @@ class Motor:
 
         # Examples
 
-        Limit the voltage of a motor to 4V:
-
         ```python
         from venice import *
 
         motor = Motor(1)
-        motor.set_voltage_limit(4)
-        # Will appear as if the voltage was set to only 4V
-        motor.set_voltage(12)
+        motor.brake(BrakeMode.HOLD)
         ```
         """
         ...
 
-    def is_over_temperature(self) -> bool:
+    def set_gearset(self, gearset: Gearset) -> None:
         """
-        Returns `True` if the motor's over temperature flag is set.
+        Sets the gearset of an 11W motor.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If the motor is a 5.5W EXP Smart Motor, which has no swappable gearset, or if
+        no motor is connected to the port.
 
         # Examples
 
-        Turn off the motor if it gets too hot:
-
         ```python
         from venice import *
 
-        motor = Motor(1)
-
-        async def main():
-        motor.set_voltage(12)
-        while True:
-        if motor.is_over_temperature():
-        motor.brake(BrakeMode.COAST)
-        else:
-        motor.set_voltage(12)
-        await vasyncio.Sleep(10, MILLIS)
+        # This must be a V5 motor
+        motor = Motor(1, Direction.FORWARD, Gearset.GREEN)
 
-        vasyncio.run(main)
+        # Set the motor to use the red gearset
+        motor.set_gearset(Gearset.RED)
         ```
         """
         ...
 
-    def is_over_current(self) -> bool:
+    def get_gearset(self, /) -> Gearset:
         """
-        Returns `True` if the motor's over-current flag is set.
+        Returns the gearset of the motor
+
+        For 5.5W motors, this will always be returned as `Gearset::Green`.
 
         # Raises
 
@@ -5941,31 +7204,33 @@ class Motor:
 
         # Examples
 
-        Print a warning if the motor draws too much current:
+        Print the gearset of a motor:
 
         ```python
         from venice import *
 
-        motor = Motor(1)
-
-        async def main():
-        motor.set_voltage(12)
-        while True:
-        if motor.is_over_current():
-        print("Warning: Motor is drawing too much current")
-        current = motor.get_current()
-        print(f"Current: {current:.2}A")
-
-        await vasyncio.Sleep(10, MILLIS)
+        def print_gearset(motor: Motor):
+        try:
+        gearset = motor.gearset()
+        except:
+        print("Failed to get gearset. Is this an EXP motor?")
+        return
 
-        vasyncio.run(main)
+        if gearset == Gearset.RED:
+        print("Motor is using the red gearset")
+        elif gearset == Gearset.GREEN:
+        print("Motor is using the green gearset")
+        elif gearset == Gearset.BLUE:
+        print("Motor is using the blue gearset")
         ```
         """
         ...
 
-    def is_driver_fault(self) -> bool:
+    def set_position_target(
+        self, position: float, unit: RotationUnit, velocity: int, /
+    ) -> None:
         """
-        Returns `True` if a H-bridge (motor driver) fault has occurred.
+        Sets an absolute position target for the motor to attempt to reach.
 
         # Raises
 
@@ -5974,102 +7239,119 @@ class Motor:
 
         # Examples
 
-        Print a warning if the motor's H-bridge has a fault:
-
         ```python
         from venice import *
 
         motor = Motor(1)
 
-        async def main():
-        motor.set_voltage(12)
-        while True:
-        if motor.is_driver_fault():
-        print("Warning: Motor has a H-bridge fault")
-        current = motor.get_current()
-        print(f"Current: {current:.2}A")
-
-        await vasyncio.Sleep(10, MILLIS)
-
-        vasyncio.run(main)
+        # Turn the motor to a position of 90 degrees at 200rpm.
+        motor.set_position_target(90, DEGREES, 200)
         ```
         """
         ...
 
-    def is_driver_over_current(self) -> bool:
+    def move_to(
+        self,
+        position: float,
+        velocity: int,
+        unit: RotationUnit,
+        /,
+        *,
+        tolerance: float | None = None,
+        settle_time: float = 50,
+        timeout: float | None = None,
+        time_unit: TimeUnit = TimeUnit.MILLIS,
+    ) -> MoveFuture:
         """
-        Returns `True` if the motor's H-bridge has an over-current fault.
+        Moves the motor to an absolute `position`, measured in `unit`, at up to `velocity` RPM, and
+        returns a `MoveFuture` that completes once the motor has settled at the position.
+
+        The position target is sent to the motor when the future is first awaited. The future then
+        completes once the motor's position has stayed within `tolerance` of `position` for
+        `settle_time`. `tolerance` is measured in `unit` and defaults to one degree; `settle_time`
+        and `timeout` are measured in `time_unit`. A `timeout` of `None` waits without a time limit.
+
+        Awaiting the future again sends the position target again and restarts the timeout. If the
+        awaiting task is cancelled before the motor settles, the motor brakes.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        - `ValueError`: If `tolerance` is negative, or if `settle_time` or `timeout` is negative,
+        non-finite, or too large to represent.
+
+        Awaiting the returned future raises:
+
+        - `DeviceError`: If no device is connected to the port, if the wrong type of device is
+        connected, or if the motor reports any fault flags (see `Motor.get_faults`) while moving.
+        - `vasyncio.TimeoutError`: If the motor hasn't settled at the position before `timeout`
+        elapses. The motor keeps its position target.
 
         # Examples
 
-        Print a warning if it draws too much current:
+        Raise an arm, then lower it again once it has arrived:
 
-        ```no_run
+        ```python
         from venice import *
 
-        motor = Motor(1)
+        arm = Motor(1)
 
         async def main():
-        motor.set_voltage(12)
-        while True:
-        if motor.is_driver_over_current():
-        print("Warning: Motor is drawing too much current")
-        current = motor.get_current()
-        print(f"Current: {current:.2}A")
-
-        await vasyncio.Sleep(10, MILLIS)
+        await arm.move_to(90, 100, DEGREES, tolerance=2, timeout=1500)
+        await arm.move_to(0, 100, DEGREES)
 
-        vasyncio.run(main)
+        vasyncio.run(main())
         ```
         """
         ...
 
-    def get_position(self, unit: RotationUnit) -> float:
+    def follow_profile(
+        self, profile: TrapezoidProfile | SCurveProfile, unit: RotationUnit, /
+    ) -> ProfileFuture:
         """
-        Returns the angular position of the motor as measured by the IME (integrated motor encoder).
+        Drives the motor along a motion `profile`, whose distances are measured in `unit`, and returns
+        a `ProfileFuture` that completes when the profile ends.
 
-        # Gearing affects position!
+        The profile starts from the motor's position when the future is first awaited. The motor is
+        given a position target at the end of the profile, and its profiled velocity is then updated
+        every 10 milliseconds to follow the profile's velocity, so the motor's own position
+        controller does the tracking. Velocities in the profile are in `unit` per second.
 
-        Position measurements are dependent on the Motor's `Gearset`, and may be reported
-        incorrectly if the motor is configured with the incorrect gearset variant. Make sure
-        that the motor is configured with the same gearset as its physical cartridge color.
+        When the future completes, the motor keeps its position target, so it finishes any
+        remaining distance and then holds the end position. Use `Motor.move_to` afterwards to wait
+        until it has settled there.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        - `TypeError`: If `profile` isn't a `TrapezoidProfile` or `SCurveProfile`.
+
+        Awaiting the returned future raises:
+
+        - `DeviceError`: If no device is connected to the port, or if the wrong type of device is
         connected.
 
         # Examples
 
-        Print the current position of a motor:
+        Raise a lift by half a turn of its motor, smoothly:
 
         ```python
         from venice import *
 
-        motor = Motor(1)
+        lift = Motor(1)
+        profile = SCurveProfile(0.5, 1, 2, 10)
 
         async def main():
-        while True:
-        position = motor.get_position(DEGREES)
-        print(f"Position: {position}")
-        await vasyncio.Sleep(10, MILLIS)
+        await lift.follow_profile(profile, TURNS)
 
-        vasyncio.run(main)
+        vasyncio.run(main())
         ```
         """
         ...
 
-    def set_position(self, position: float, unit: RotationUnit) -> None:
+    def get_velocity(self) -> float: ...
+    def get_power(self) -> float: ...
+    def get_torque(self) -> float:
         """
-        Sets the current encoder position to the given position without moving the motor.
-
-        Analogous to taring or resetting the encoder so that the new position is equal to the given
-        position.
+        Returns the torque output of the motor in Nm.
 
         # Raises
 
@@ -6078,39 +7360,27 @@ class Motor:
 
         # Examples
 
-        Set the current position of the motor to 90 degrees:
+        Print the torque output of a motor:
 
         ```python
         from venice import *
 
         motor = Motor(1)
-        motor.set_position(90, DEGREES)
-        ```
-
-        Reset the position of the motor to 0 degrees (analogous to `Motor.reset_position`):
 
-        ```python
-        from venice import *
+        async def main():
+        while True:
+        torque = motor.get_torque()
+        print(f"Torque: {torque:.2}Nm")
+        await vasyncio.Sleep(10, MILLIS)
 
-        motor = Motor(1)
-        motor.set_position(0, DEGREES)
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def set_direction(self, direction: Direction) -> None:
+    def get_voltage(self) -> float:
         """
-        Sets the motor to operate in a given `Direction`.
-
-        This determines which way the motor considers to be “forwards”. You can use the marking on
-        the back of the motor as a reference:
-
-        - When `Direction.FORWARD` is specified, positive velocity/voltage values will cause the
-        motor to rotate **with the arrow on the back**. Position will increase as the motor
-        rotates **with the arrow**.
-        - When `Direction::REVERSE` is specified, positive velocity/voltage values will cause the
-        motor to rotate **against the arrow on the back**. Position will increase as the motor
-        rotates **against the arrow**.
+        Returns the voltage the motor is drawing in volts.
 
         # Raises
 
@@ -6119,22 +7389,38 @@ class Motor:
 
         # Examples
 
+        Print the voltage drawn by a motor:
+
         ```python
         from venice import *
 
-        motor = Motor(1, Direction.FORWARD)
-        motor.set_direction(Direction.REVERSE)
+        motor = Motor(1)
+
+        async def main():
+        while True:
+        voltage = motor.get_voltage()
+        print(f"Voltage: {voltage:.2}V")
+        await vasyncio.Sleep(10, MILLIS)
+
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def get_direction(self, /) -> Direction:
+    def get_raw_position(self) -> int:
         """
-        Returns the `Direction` of this motor.
+        Returns the most recently recorded raw encoder tick data from the motor's IME.
+
+        The motor's integrated encoder has a TPR of 4096. Gearset is not taken into consideration
+        when dealing with the raw value, meaning this measurement will be taken relative to the
+        motor's internal position *before* being geared down from 3600RPM.
+
+        Methods such as `Motor.reset_position` and `Motor.set_position` do not change the value of
+        this raw measurement.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        - `DeviceError`: If no device is connected to the port, or if the wrong type of device is
         connected.
 
         # Examples
@@ -6142,43 +7428,56 @@ class Motor:
         ```python
         from venice import *
 
-        def print_motor_direction(motor: Motor):
-        if motor.get_direction() == Direction.FORWARD:
-        print("Motor is set to forwards")
-        else:
-        print("Motor is set to reverse")
+        async def main():
+        motor = Motor(1)
+        while True:
+        raw_pos = motor.get_raw_position()
+        print(f"Raw Position: {raw_pos}")
+
+        await vasyncio.Sleep(Motor.WRITE_INTERVAL_MS, MILLIS)
+
+        vasyncio.run(main())
         ```
         """
         ...
 
-    def get_status(self) -> int:
+    def get_current(self) -> float:
         """
-        Returns the status flags of a motor.
+        Returns the electrical current draw of the motor in amps.
 
-        # Errors
+        # Raises
 
         `DeviceError`: If no device is connected to the port, or if the wrong type of device is
         connected.
 
         # Examples
 
-        Check if a motor is "busy" (busy only occurs if communicating with the motor fails)
+        Print the current draw of a motor:
 
         ```python
         from venice import *
 
-        BUSY_FLAG = 0x01
+        motor = Motor(1)
 
-        def is_motor_busy(motor: Motor) -> bool:
-        # Test if the motor status bits contain `BUSY_FLAG`
-        motor.get_status() & BUSY_FLAG == BUSY_FLAG
+        async def main():
+        motor.set_voltage(motor.max_voltage)
+        while True:
+        current = motor.get_current()
+        print(f"Current: {current:.2}A")
+
+        await vasyncio.Sleep(10, MILLIS)
+
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def get_faults(self) -> int:
+    def get_efficiency(self) -> float:
         """
-        Returns the fault flags of the motor.
+        Returns the efficiency of the motor from a range of [0.0, 1.0].
+
+        An efficiency of 1.0 means that the motor is moving electrically while drawing no electrical
+        power, and an efficiency of 0.0 means that the motor is drawing power but not moving.
 
         # Raises
 
@@ -6187,155 +7486,75 @@ class Motor:
 
         # Examples
 
-        Check if a motor is over temperature:
+        Print the efficiency of a motor:
 
         ```python
         from venice import *
 
-        OVER_TEMPERATURE_FLAG = 0x01
-
         motor = Motor(1)
 
         async def main():
+        motor.set_voltage(motor.max_voltage)
         while True:
-        faults = motor.get_faults()
-        print(f"Status: 0x{status:08x}")
+        efficiency = motor.get_efficiency()
+        print(f"Current: {efficiency:.2}")
 
-        # Test if `faults` contains `OVER_TEMPERATURE_FLAG`
-        if faults & OVER_TEMPERATURE_FLAG == OVER_TEMPERATURE_FLAG:
-        print("Warning: Motor is over temperature!")
         await vasyncio.Sleep(10, MILLIS)
-
-        vasyncio.run(main)
         ```
         """
         ...
 
-    def free(self) -> None:
+    def get_current_limit(self) -> float:
         """
-        Release this device and free its Smart Port lock. This binding will become unusable after
-        this call, but you can reuse the underlying Smart Port number in a new device.
+        Returns the current limit for the motor in amps.
 
-        Any attempts to use this device after freeing will result in a `ValueError` being raised.
+        This limit can be configured with the `Motor.set_current_limit` method.
 
         # Raises
 
-        `ValueError`: If the device has already been freed.
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
 
         # Examples
 
-        Construct a `Motor`, free it, then construct a `RotationSensor` with the same Smart Port:
+        Print the current limit of a motor:
 
         ```python
         from venice import *
 
         motor = Motor(1)
-        motor.free()
-        # `motor` is now unusable, but port 1 can be used in another device, such as a `RotationSensor`
-        rotation_sensor = RotationSensor(1)
+        current_limit = motor.get_current_limit()
+        print(f"Current Limit: {current_limit:.2}A")
         ```
         """
         ...
 
-class MotorType:
-    """
-    Represents the type of a Smart Motor: either an 11W (V5) or 5.5W (EXP) motor.
-
-    Values are returned by the read-only
-    `Motor.motor_type` attribute. `MotorType` is root-importable and isn't constructed directly;
-    use its singleton constants. Values have readable representations such as `MotorType.V5`.
-    """
-
-    V5: ClassVar[MotorType]
-    """An 11W Smart Motor."""
-    EXP: ClassVar[MotorType]
-    """A 5.5W Smart Motor."""
-
-class Gesture:
-    """Gesture data from an `OpticalSensor`."""
-
-    direction: GestureDirection
-    count: int
-    up: int
-    down: int
-    left: int
-    right: int
-    gesture_type: int
-    def get_time(self, unit: TimeUnit) -> float: ...
-
-class GestureDirection:
-    """Represents a gesture and its direction."""
-
-    UP: ClassVar[GestureDirection]
-    """Up gesture."""
-    DOWN: ClassVar[GestureDirection]
-    """Down gesture."""
-    LEFT: ClassVar[GestureDirection]
-    """Left gesture."""
-    RIGHT: ClassVar[GestureDirection]
-    """Right gesture."""
-
-class OpticalSensor:
-    """
-    An optical sensor plugged into a Smart Port.
-
-    This class provides an interface to interact with the V5 Optical Sensor, which combines ambient
-    light sensing, color detection, proximity measurement, and gesture recognition capabilities.
-
-    # Hardware Overview
-
-    The optical sensor provides multi-modal optical sensing with an integrated white LED for
-    low-light operation.
-
-    ## Color Detection
-
-    Color data reported as RGB, HSV, and grayscale data, with optimal performance at distances under
-    100mm. The proximity sensing uses reflected light intensity, making readings dependent on both
-    ambient lighting and target reflectivity.
-
-    ## Gesture Detection
-
-    The optical sensor can detect four distinct motions (up, down, left, right) of objects passing
-    over the sensor.
-    """
-
-    MIN_INTEGRATION_TIME_MS: ClassVar[int]
-    """
-    The smallest integration time you can set on an optical sensor, in milliseconds.
-
-    Source: <https://www.vexforum.com/t/v5-optical-sensor-refresh-rate/109632/9>
-    """
-    MAX_INTEGRATION_TIME_MS: ClassVar[int]
-    """
-    The largest integration time you can set on an optical sensor, in milliseconds.
+    def get_voltage_limit(self) -> float:
+        """
+        Returns the voltage limit for the motor if one has been explicitly set.
 
-    Source: <https://www.vexforum.com/t/v5-optical-sensor-refresh-rate/109632/9>
-    """
-    GESTURE_UPDATE_INTERVAL_MS: ClassVar[int]
-    """
-    The interval that gesture detection through `OpticalSensor.get_last_gesture` provides new
-    data at.
-    """
+        # Raises
 
-    def __init__(self, port: int, /) -> None:
-        """
-        Creates a new optical sensor.
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
 
         # Examples
 
+        Print the voltage limit of a motor:
+
         ```python
         from venice import *
 
-        sensor = OpticalSensor(1)
+        motor = Motor(1)
+        voltage_limit = motor.get_voltage_limit()
+        print(f"Voltage Limit: {voltage_limit:.2}V")
         ```
         """
         ...
 
-    def get_hue(self) -> float:
+    def get_temperature(self) -> float:
         """
-        Returns the detected color's hue.
-
-        Hue has a range of `0` to `359.999`.
+        Returns the internal temperature recorded by the motor in increments of 5 °C.
 
         # Raises
 
@@ -6344,22 +7563,31 @@ class OpticalSensor:
 
         # Examples
 
+        Turn off the motor if it gets too hot:
+
         ```python
         from venice import *
 
-        sensor = OpticalSensor(1)
+        motor = Motor(1)
 
-        hue = sensor.get_hue()
-        print(f"Detected color hue: {hue:.1}°")
+        async def main():
+        motor.set_voltage(12)
+        while True:
+        if motor.get_temperature() > 30:
+        motor.brake(BrakeMode.COAST)
+        else:
+        motor.set_voltage(12)
+        await vasyncio.Sleep(10, MILLIS)
         ```
         """
         ...
 
-    def get_saturation(self) -> float:
+    def set_profiled_velocity(self, velocity: int) -> None:
         """
-        Returns the detected color's saturation.
+        Changes the output velocity for a profiled movement (motor_move_absolute or
+        motor_move_relative).
 
-        Saturation has a range `0` to `1.0`.
+        This will have no effect if the motor is not following a profiled movement.
 
         # Raises
 
@@ -6371,25 +7599,20 @@ class OpticalSensor:
         ```python
         from venice import *
 
-        sensor = OpticalSensor(1)
-
-        saturation = sensor.get_saturation()
-        print(f"Color saturation: {saturation:.0%}")
+        motor = Motor(1)
 
-        # Check if color is muted or vibrant
-        if saturation < 0.5:
-        print("Muted color detected")
-        else:
-        print("Vibrant color detected")
+        # Set the motor's target to a Position so that changing the velocity isn't a noop.
+        motor.set_position_target(90, DEGREES, 200)
+        motor.set_profiled_velocity(100)
         ```
         """
         ...
 
-    def get_brightness(self) -> float:
+    def reset_position(self) -> None:
         """
-        Returns the detected color's brightness.
+        Sets the current encoder position to zero without moving the motor.
 
-        Brightness values range from `0` to `1.0`.
+        Analogous to taring or resetting the encoder to the current position.
 
         # Raises
 
@@ -6398,31 +7621,25 @@ class OpticalSensor:
 
         # Examples
 
+        Move the motor in increments of 10 degrees:
+
         ```python
         from venice import *
 
-        sensor = OpticalSensor(1)
-
-        brightness = sensor.get_brightness()
-        print(f"Color brightness: {brightness:.0%}")
+        motor = Motor(1)
 
-        # Check if color is dark or bright
-        if brightness < 0.3:
-        print("Dark color detected")
-        elif brightness > 0.7:
-        print("Bright color detected")
-        else:
-        print("Medium brightness color detected")
+        async def main():
+        while True:
+        motor.set_position_target(10, DEGREES, 200)
+        await vasyncio.Sleep(1, SECOND)
+        motor.reset_position()
         ```
         """
         ...
 
-    def get_proximity(self) -> float:
+    def set_current_limit(self, limit: float) -> None:
         """
-        Returns an analog proximity value from `0` to `1.0`.
-
-        A reading of 1.0 indicates that the object is close to the sensor, while 0.0 indicates that
-        no object is detected in range of the sensor.
+        Sets the current limit for the motor in amps.
 
         # Raises
 
@@ -6431,28 +7648,20 @@ class OpticalSensor:
 
         # Examples
 
+        Limit the current draw of a motor to 2.5A:
+
         ```python
         from venice import *
 
-        sensor = OpticalSensor(1)
-
-        # Monitor proximity with thresholds
-        prox = sensor.get_proximity()
-        if prox > 0.8:
-        print("Object very close!")
-        elif prox > 0.5:
-        print("Object nearby")
-        elif prox > 0.2:
-        print("Object detected")
-        else:
-        print("No object in range")
+        motor = Motor(1)
+        motor.set_current_limit(2.5)
         ```
         """
         ...
 
-    def get_color(self) -> OpticalRgb:
+    def set_voltage_limit(self, limit: float) -> None:
         """
-        Returns the processed RGB color data from the sensor.
+        Sets the voltage limit for the motor in volts.
 
         # Raises
 
@@ -6461,38 +7670,22 @@ class OpticalSensor:
 
         # Examples
 
+        Limit the voltage of a motor to 4V:
+
         ```python
         from venice import *
 
-        sensor = OpticalSensor(1)
-
-        rgb = sensor.get_color()
-        print(f"Color reading: R={rgb.r}, G={rgb.g}, B={rgb.b}")
-
-        # Example: Check if object is primarily red
-        # Note that you should probably use `OpticalSensor.get_hue` instead for this
-        if rgb.r > rgb.g && rgb.r > rgb.b:
-        print("Object is primarily red!")
+        motor = Motor(1)
+        motor.set_voltage_limit(4)
+        # Will appear as if the voltage was set to only 4V
+        motor.set_voltage(12)
         ```
         """
         ...
 
-    def get_raw_color(self) -> OpticalRaw:
+    def is_over_temperature(self) -> bool:
         """
-        Returns the raw, unprocessed RGBC color data from the sensor.
-
-        # Raises
-
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
-        """
-        ...
-
-    def get_last_gesture(self) -> Gesture | None:
-        """
-        Returns the most recent gesture data from the sensor, or `None` if no gesture was detected.
-
-        Gesture data updates every 500 milliseconds.
+        Returns `True` if the motor's over temperature flag is set.
 
         # Raises
 
@@ -6501,27 +7694,30 @@ class OpticalSensor:
 
         # Examples
 
+        Turn off the motor if it gets too hot:
+
         ```python
         from venice import *
 
-        sensor = OpticalSensor(1)
+        motor = Motor(1)
 
         async def main():
+        motor.set_voltage(12)
         while True:
-        gesture = sensor.get_last_gesture()
-        if direction:
-        print(f"Direction: {gesture.direction}")
-
-        await vasyncio.Sleep(25, MILLIS)
+        if motor.is_over_temperature():
+        motor.brake(BrakeMode.COAST)
+        else:
+        motor.set_voltage(12)
+        await vasyncio.Sleep(10, MILLIS)
 
         vasyncio.run(main)
         ```
         """
         ...
 
-    def get_led_brightness(self) -> float:
+    def is_over_current(self) -> bool:
         """
-        Returns the intensity/brightness of the sensor's LED indicator as a number from [0.0-1.0].
+        Returns `True` if the motor's over-current flag is set.
 
         # Raises
 
@@ -6530,63 +7726,64 @@ class OpticalSensor:
 
         # Examples
 
+        Print a warning if the motor draws too much current:
+
         ```python
         from venice import *
 
-        sensor = OpticalSensor(1)
+        motor = Motor(1)
 
-        brightness = sensor.get_led_brightness()
-        print(f"LED brightness: {brightness:.1%}")
+        async def main():
+        motor.set_voltage(12)
+        while True:
+        if motor.is_over_current():
+        print("Warning: Motor is drawing too much current")
+        current = motor.get_current()
+        print(f"Current: {current:.2}A")
+
+        await vasyncio.Sleep(10, MILLIS)
+
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def set_led_brightness(self, brightness: float) -> None:
+    def is_driver_fault(self) -> bool:
         """
-        Set the intensity of (intensity/brightness) of the sensor's LED indicator.
-
-        Intensity is expressed as a number from [0.0, 1.0].
+        Returns `True` if a H-bridge (motor driver) fault has occurred.
 
-        # Python
+        # Raises
 
         `DeviceError`: If no device is connected to the port, or if the wrong type of device is
         connected.
 
         # Examples
 
+        Print a warning if the motor's H-bridge has a fault:
+
         ```python
         from venice import *
 
-        sensor = OpticalSensor(1)
+        motor = Motor(1)
 
         async def main():
-        for _ in range(3):
-        # Turn LED on
-        try:
-        sensor.set_led_brightness(1)
-        except DeviceError as e:
-        print(f"Failed to turn LED on: {e}")
-
-        await vasyncio.Sleep(250, MILLIS)
+        motor.set_voltage(12)
+        while True:
+        if motor.is_driver_fault():
+        print("Warning: Motor has a H-bridge fault")
+        current = motor.get_current()
+        print(f"Current: {current:.2}A")
 
-        # Turn LED off
-        try:
-        sensor.set_led_brightness(0)
-        except DeviceError as e:
-        print(f"Failed to turn LED off: {e}")
+        await vasyncio.Sleep(10, MILLIS)
 
-        await vasyncio.Sleep(250, MILLIS)
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def get_integration_time(self, unit: TimeUnit) -> float:
+    def is_driver_over_current(self) -> bool:
         """
-        Returns integration time of the optical sensor in milliseconds, with minimum time being 3ms
-        and the maximum time being 712ms.
-
-        The default integration time for the sensor is 103mS, unless otherwise set with
-        `OpticalSensor.set_integration_time`.
+        Returns `True` if the motor's H-bridge has an over-current fault.
 
         # Raises
 
@@ -6595,56 +7792,69 @@ class OpticalSensor:
 
         # Examples
 
-        ```python
+        Print a warning if it draws too much current:
+
+        ```no_run
         from venice import *
 
-        sensor = OpticalSensor(1)
+        motor = Motor(1)
 
-        # Set integration time to 50 milliseconds.
-        sensor.set_integration_time(50, MILLIS)
+        async def main():
+        motor.set_voltage(12)
+        while True:
+        if motor.is_driver_over_current():
+        print("Warning: Motor is drawing too much current")
+        current = motor.get_current()
+        print(f"Current: {current:.2}A")
 
-        # Log out the new integration time.
-        integration_time = sensor.get_integration_time(MILLIS)
-        print(f"Integration time: {integration_time}ms")
+        await vasyncio.Sleep(10, MILLIS)
+
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def set_integration_time(self, time: float, unit: TimeUnit) -> None:
+    def get_position(self, unit: RotationUnit) -> float:
         """
-        Set the integration time of the optical sensor.
-
-        Lower integration time results in faster update rates with lower accuracy due to less
-        available light being read by the sensor.
+        Returns the angular position of the motor as measured by the IME (integrated motor encoder).
 
-        The `time` value must be a duration between 3 and 712 milliseconds. If the integration
-        time is out of this range, it will be clamped to fit inside it. See
-        <https://www.vexforum.com/t/v5-optical-sensor-refresh-rate/109632/9> for more information.
+        # Gearing affects position!
 
-        The default integration time for the sensor is 103mS, unless otherwise set.
+        Position measurements are dependent on the Motor's `Gearset`, and may be reported
+        incorrectly if the motor is configured with the incorrect gearset variant. Make sure
+        that the motor is configured with the same gearset as its physical cartridge color.
 
         # Raises
 
-        - `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
         connected.
-        - `ValueError`: If `time` is negative, non-finite, or too large to represent.
 
         # Examples
 
+        Print the current position of a motor:
+
         ```python
         from venice import *
 
-        sensor = OpticalSensor(1)
+        motor = Motor(1)
 
-        # Set integration time to 50 milliseconds.
-        sensor.set_integration_time(50, MILLIS)
+        async def main():
+        while True:
+        position = motor.get_position(DEGREES)
+        print(f"Position: {position}")
+        await vasyncio.Sleep(10, MILLIS)
+
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def get_status(self) -> int:
+    def set_position(self, position: float, unit: RotationUnit) -> None:
         """
-        Returns the internal status code of the optical sensor.
+        Sets the current encoder position to the given position without moving the motor.
+
+        Analogous to taring or resetting the encoder so that the new position is equal to the given
+        position.
 
         # Raises
 
@@ -6653,85 +7863,59 @@ class OpticalSensor:
 
         # Examples
 
+        Set the current position of the motor to 90 degrees:
+
         ```python
         from venice import *
 
-        sensor = OpticalSensor(1)
-
-        status = sensor.get_status()
-        print(f"Status: 0x{status:08x}")
+        motor = Motor(1)
+        motor.set_position(90, DEGREES)
         ```
-        """
-        ...
 
-    def free(self) -> None:
-        """
-        Release this device and free its Smart Port lock. This binding will become unusable after
-        this call, but you can reuse the underlying Smart Port number in a new device.
-
-        Any attempts to use this device after freeing will result in a `ValueError` being raised.
-
-        # Raises
-
-        `ValueError`: If the device has already been freed.
-
-        # Examples
-
-        Construct a `Motor`, free it, then construct a `RotationSensor` with the same Smart Port:
+        Reset the position of the motor to 0 degrees (analogous to `Motor.reset_position`):
 
         ```python
         from venice import *
 
         motor = Motor(1)
-        motor.free()
-        # `motor` is now unusable, but port 1 can be used in another device, such as a `RotationSensor`
-        rotation_sensor = RotationSensor(1)
+        motor.set_position(0, DEGREES)
         ```
         """
         ...
 
-class OpticalRgb:
-    """RGB data from an `OpticalSensor`."""
-
-    r: float
-    g: float
-    b: float
-    brightness: float
-
-class OpticalRaw:
-    """Represents the raw RGBC data an `OpticalSensor`."""
+    def set_direction(self, direction: Direction) -> None:
+        """
+        Sets the motor to operate in a given `Direction`.
 
-    r: int
-    g: int
-    b: int
-    clear: int
+        This determines which way the motor considers to be “forwards”. You can use the marking on
+        the back of the motor as a reference:
 
-class RotationSensor:
-    MIN_DATA_INTERVAL_MS: ClassVar[int]
-    """The minimum data rate that you can set a rotation sensor to, in milliseconds."""
-    TICKS_PER_REVOLUTION: ClassVar[int]
-    """The amount of unique sensor readings per one revolution of the sensor."""
+        - When `Direction.FORWARD` is specified, positive velocity/voltage values will cause the
+        motor to rotate **with the arrow on the back**. Position will increase as the motor
+        rotates **with the arrow**.
+        - When `Direction::REVERSE` is specified, positive velocity/voltage values will cause the
+        motor to rotate **against the arrow on the back**. Position will increase as the motor
+        rotates **against the arrow**.
 
-    def __init__(self, port: int, direction: Direction = Direction.FORWARD, /) -> None:
-        """
-        Creates a new rotation sensor on the given port.
+        # Raises
 
-        Whether or not the sensor should be reversed on creation can be specified.
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
 
         # Examples
 
-        ```
+        ```python
         from venice import *
 
-        sensor = RotationSensor(1)
+        motor = Motor(1, Direction.FORWARD)
+        motor.set_direction(Direction.REVERSE)
         ```
         """
         ...
 
-    def get_angle(self, unit: RotationUnit) -> float: ...
-    def get_position(self, unit: RotationUnit) -> float:
+    def get_direction(self, /) -> Direction:
         """
-        Returns the total accumulated rotation of the sensor over time.
+        Returns the `Direction` of this motor.
 
         # Raises
 
@@ -6743,1108 +7927,1300 @@ class RotationSensor:
         ```python
         from venice import *
 
-        sensor = RotationSensor(1)
-        position = sensor.get_position(DEGREES)
-
-        print(f"Position in degrees: {position}")
+        def print_motor_direction(motor: Motor):
+        if motor.get_direction() == Direction.FORWARD:
+        print("Motor is set to forwards")
+        else:
+        print("Motor is set to reverse")
         ```
         """
         ...
 
-    def set_position(self, position: float, unit: RotationUnit) -> None:
+    def get_status(self) -> int:
         """
-        Sets the sensor's position reading.
+        Returns the status flags of a motor.
 
-        # Raises
+        # Errors
 
         `DeviceError`: If no device is connected to the port, or if the wrong type of device is
         connected.
 
         # Examples
 
+        Check if a motor is "busy" (busy only occurs if communicating with the motor fails)
+
         ```python
         from venice import *
 
-        sensor = RotationSensor(1)
+        BUSY_FLAG = 0x01
 
-        # Set position to 15 degrees
-        sensor.set_position(15, DEGREES)
+        def is_motor_busy(motor: Motor) -> bool:
+        # Test if the motor status bits contain `BUSY_FLAG`
+        motor.get_status() & BUSY_FLAG == BUSY_FLAG
         ```
         """
         ...
 
-    def get_velocity(self) -> float:
+    def get_faults(self) -> int:
         """
-        Returns the sensor's current velocity in degrees per second.
+        Returns the fault flags of the motor.
 
         # Raises
 
         `DeviceError`: If no device is connected to the port, or if the wrong type of device is
         connected.
 
-        ```python
-        from venice import *
-
-        sensor = RotationSensor(1)
-
-        velocity = sensor.get_velocity()
-        print(f"Velocity in RPM: {velocity / 6}") # 1rpm = 6dps
-        ```
-        """
-        ...
+        # Examples
 
-    def reset_position(self) -> None:
-        """
-        Reset's the sensor's position reading to zero.
+        Check if a motor is over temperature:
 
-        # Raises
+        ```python
+        from venice import *
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        OVER_TEMPERATURE_FLAG = 0x01
 
-        # Examples
+        motor = Motor(1)
 
-        ```python
-        from venice import *
+        async def main():
+        while True:
+        faults = motor.get_faults()
+        print(f"Status: 0x{status:08x}")
 
-        sensor = RotationSensor(1)
+        # Test if `faults` contains `OVER_TEMPERATURE_FLAG`
+        if faults & OVER_TEMPERATURE_FLAG == OVER_TEMPERATURE_FLAG:
+        print("Warning: Motor is over temperature!")
+        await vasyncio.Sleep(10, MILLIS)
 
-        print(f"Before reset: {sensor.get_position()}")
-        sensor.reset_position()
-        print(f"After reset: {sensor.get_position()}")
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def set_direction(self, direction: Direction) -> None:
+    def free(self) -> None:
         """
-        Sets the sensor to operate in a given `Direction`.
-
-        This determines which way the sensor considers to be “forwards”. You can use the marking on
-        the top of the motor as a reference:
+        Release this device and free its Smart Port lock. This binding will become unusable after
+        this call, but you can reuse the underlying Smart Port number in a new device.
 
-        - When `Direction.FORWARD` is specified, positive velocity/voltage values will cause the
-        motor to rotate **with the arrow on the top**. Position will increase as the motor rotates
-        **with the arrow**.
-        - When `Direction.REVERSE` is specified, positive velocity/voltage values will cause the
-        motor to rotate **against the arrow on the top**. Position will increase as the motor
-        rotates **against the arrow**.
+        Any attempts to use this device after freeing will result in a `ValueError` being raised.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `ValueError`: If the device has already been freed.
 
         # Examples
 
-        Set the sensor's direction to `Direction.Reverse`.
+        Construct a `Motor`, free it, then construct a `RotationSensor` with the same Smart Port:
 
         ```python
         from venice import *
 
-        sensor = Rotation(1)
-
-        # Reverse the sensor
-        sensor.set_direction(Direction.REVERSE)
+        motor = Motor(1)
+        motor.free()
+        # `motor` is now unusable, but port 1 can be used in another device, such as a `RotationSensor`
+        rotation_sensor = RotationSensor(1)
         ```
+        """
+        ...
 
-        Reverse the sensor's direction (set to opposite of the previous direction):
-
-        ```python
-        from venice import *
+class MoveFuture:
+    """
+    An awaitable that completes once a motor has settled at a position target, created with
+    `Motor.move_to`.
 
-        sensor = RotationSensor(1)
+    The motor's position and fault flags are checked every 10 milliseconds, the rate at which the
+    Brain reads motor data. Between checks, the awaiting task is parked on the event loop, so other
+    tasks keep running.
+    """
+    def throw(self, exc: BaseException, /) -> Any:
+        """
+        Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
 
-        # Reverse the sensor
-        sensor.set_direction(~sensor.get_direction())
-        ```
+        The motor brakes and the move is abandoned before the exception propagates.
         """
         ...
 
-    def get_direction(self, /) -> Direction: ...
-    def get_status(self) -> int:
+    def close(self) -> None:
+        """Brakes the motor and abandons the move when the awaiting coroutine is closed."""
+        ...
+
+class ProfileFuture:
+    """
+    An awaitable that drives a motor along a motion profile, created with `Motor.follow_profile`.
+
+    Between updates to the motor's velocity, the awaiting task is parked on the event loop, so
+    other tasks keep running.
+    """
+    def throw(self, exc: BaseException, /) -> Any:
         """
-        Returns the sensor's internal status code.
+        Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
 
-        # Raises
+        The motor brakes and the profile is abandoned before the exception propagates.
+        """
+        ...
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+    def close(self) -> None:
+        """Brakes the motor and abandons the profile when the awaiting coroutine is closed."""
+        ...
 
-        # Examples
+class MotorType:
+    """
+    Represents the type of a Smart Motor: either an 11W (V5) or 5.5W (EXP) motor.
 
-        ```python
-        from venice import *
+    Values are returned by the read-only
+    `Motor.motor_type` attribute. `MotorType` is root-importable and isn't constructed directly;
+    use its singleton constants. Values have readable representations such as `MotorType.V5`.
+    """
 
-        sensor = RotationSensor(1)
-        status = sensor.get_status()
-        print(f"Status: 0x{status:08x}")
-        ```
-        """
-        ...
+    V5: ClassVar[MotorType]
+    """An 11W Smart Motor."""
+    EXP: ClassVar[MotorType]
+    """A 5.5W Smart Motor."""
 
-    def set_data_interval(self, interval: float, unit: TimeUnit) -> None:
-        """
-        Sets the internal computation speed of the rotation sensor.
+class TrackingWheel:
+    """
+    An unpowered wheel whose rotation is measured by a `RotationSensor` or `AdiEncoder`, used by
+    `Odometry`.
 
-        This method does NOT change the rate at which user code can read data off the sensor, as the
-        brain will only talk to the device every 10mS regardless of how fast data is being sent or
-        computed.
+    `diameter` sets the unit of every distance that `Odometry` reports. `offset` is the wheel's
+    distance from the robot's tracking center, in the same unit: to the left for vertical wheels
+    (negative for wheels on the right), and forward for horizontal wheels (negative for wheels
+    behind it). Reverse the sensor if a vertical wheel reads negative when the robot drives forward,
+    or a horizontal wheel reads negative when it slides left.
 
-        This duration should be above `Self.MIN_DATA_INTERVAL` (5 milliseconds).
+    `diameter` and `offset` are read-only attributes.
+    """
+
+    diameter: float
+    offset: float
+    def __init__(
+        self,
+        sensor: RotationSensor | AdiEncoder,
+        diameter: float,
+        offset: float = 0.0,
+        /,
+    ) -> None:
+        """
+        Creates a tracking wheel of the given `diameter` measured by `sensor`.
 
         # Raises
 
-        - `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
-        - `ValueError`: If `interval` is negative, non-finite, or too large to represent.
+        - `TypeError`: If `sensor` isn't a `RotationSensor` or `AdiEncoder`, or a number isn't
+        numeric.
+        - `ValueError`: If `diameter` isn't positive and finite, or `offset` isn't finite.
 
         # Examples
 
         ```python
         from venice import *
 
-        sensor = RotationSensor(1)
-
-        # Set to minimum interval.
-        sensor.set_data_interval(RotationSensor.MIN_DATA_INTERVAL)
+        # A 2.75" wheel 1.5" to the right of the tracking center
+        right = TrackingWheel(RotationSensor(1), 2.75, -1.5)
         ```
         """
         ...
 
-    def free(self) -> None:
+    def get_distance(self) -> float:
         """
-        Release this device and free its Smart Port lock. This binding will become unusable after
-        this call, but you can reuse the underlying Smart Port number in a new device.
-
-        Any attempts to use this device after freeing will result in a `ValueError` being raised.
+        Returns the distance the wheel has travelled since its sensor was last reset, in the unit of
+        its diameter.
 
         # Raises
 
-        `ValueError`: If the device has already been freed.
-
-        # Examples
-
-        Construct a `RotationSensor`, free it, then construct a `Motor` with the same Smart Port:
-
-        ```python
-        from venice import *
-
-        sensor = RotationSensor(1)
-        sensor.free()
-        # `sensor` is now unusable, but port 1 can be used in another device, such as a `Motor`
-        motor = Motor(1)
-        ```
+        - `DeviceError`: If the sensor can't be read.
         """
         ...
 
-class SerialPort:
+class Odometry:
     """
-    A Smart Port configured as a generic RS-485 serial port.
+    Tracks the robot's position and heading from two or three tracking wheels.
 
-    This class provides an interface for using V5 Smart Ports as serial communication ports over
-    RS-485. It allows bidirectional communication with any device that speaks serial over the V5's
-    RS-485 interface.
+    The robot's heading comes from an `InertialSensor` if one is given, or otherwise from the
+    difference between two vertical wheels. A horizontal wheel measures sideways motion; without
+    one, the robot is assumed not to slide sideways. Each update assumes the robot moved along an
+    arc since the previous one, which stays accurate while turning and driving at the same time.
 
-    # Hardware Description
+    Positions are measured in the unit of the tracking wheel diameters, with x pointing forward and
+    y pointing left of where the robot started. Headings increase counterclockwise from the x axis
+    and are not wrapped, so they keep counting past a full turn.
 
-    V5 Smart Ports provide half-duplex RS-485 serial communication at up to an allowed 921600 baud for
-    user programs.
+    Poses are only updated by `Odometry.update` or while `Odometry.run` is awaited. Resetting a
+    tracking wheel's sensor or the inertial sensor's rotation while tracking makes the pose jump.
 
-    The ports supply 12.8V VCC nominally (VCC is wired directly to the V5's battery lines, providing
-    voltage somewhere in the range of 12-14V). Writes to the serial port are buffered, but are
-    automatically flushed by VEXos as fast as possible (down to ~10µs or so).
+    # Examples
 
-    Open a port with `await SerialPort.open(...)`; `SerialPort` cannot be constructed directly. The
-    object implements the MicroPython stream methods `read`, `read1`, `write`, `write1`, `flush`, and
-    `ioctl`. Reads consume the bytes currently available rather than waiting for the requested
-    amount and return `bytes`. After `free` releases the Smart Port, stream and device operations
-    raise `ValueError`.
-    """
+    ```python
+    from venice import *
 
-    INTERNAL_BUFFER_SIZE: ClassVar[int]
-    """The length of the serial FIFO input and output buffers, in bytes. Its value is 1024."""
-    MAX_BAUD_RATE: ClassVar[int]
-    """The maximum user-configurable baud rate for generic serial under normal conditions. Its value is 921600."""
+    odom = Odometry(
+    [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+    TrackingWheel(RotationSensor(3), 2.75, -2.0),
+    imu=InertialSensor(4),
+    )
 
-    @staticmethod
-    def open(port_number: int, baud_rate: int, /) -> SerialPortOpenFuture:
+    async def track():
+    await odom.run(10, MILLIS)
+
+    async def main():
+    vasyncio.spawn(track())
+    while True:
+    pose = odom.get_pose()
+    print(pose.x, pose.y, pose.get_heading(DEGREES))
+    await vasyncio.Sleep(100, MILLIS)
+
+    vasyncio.run(main())
+    ```
+    """
+    def __init__(
+        self,
+        vertical: list[TrackingWheel] | tuple[TrackingWheel, ...],
+        horizontal: TrackingWheel | None = None,
+        /,
+        *,
+        imu: InertialSensor | None = None,
+    ) -> None:
         """
-        Opens and configures a generic serial port on Smart Port `port_number`.
+        Creates an odometry tracker at the origin.
 
-        This configures a Smart Port to act as a generic serial controller capable of sending and receiving
-        data. Providing `baud_rate`, or the transmission rate of bits, is required. It must be from
-        1 through `SerialPort.MAX_BAUD_RATE` (921600). Await the returned `SerialPortOpenFuture` to
-        obtain the usable port.
+        `vertical` lists one or two tracking wheels parallel to the direction of travel, and
+        `horizontal` is an optional tracking wheel perpendicular to it. A single vertical wheel
+        needs `imu` to measure the heading.
 
-        # Examples
+        # Raises
 
-        ```python
-        from venice import *
+        - `TypeError`: If `vertical` isn't a list or tuple of `TrackingWheel`s, `horizontal` isn't a
+        `TrackingWheel`, or `imu` isn't an `InertialSensor`.
+        - `ValueError`: If `vertical` doesn't hold one or two wheels, or if there is no `imu` and
+        the vertical wheels can't measure the heading because there is only one of them or they
+        share an offset.
+        """
+        ...
 
-        async def main():
-        serial = await SerialPort.open(1, 115200)
+    def update(self) -> None:
+        """
+        Reads the sensors once and updates the pose.
 
-        vasyncio.run(main())
-        ```
+        The first update only records the starting readings. Use this instead of `Odometry.run` to
+        update the pose from your own loop.
 
         # Raises
 
-        - `ValueError`: If `port_number` is outside 1 through 21, its Smart Port is occupied, or
-        `baud_rate` is outside 1 through `SerialPort.MAX_BAUD_RATE`.
-        - `TypeError`: If either argument is not an integer.
+        - `DeviceError`: If a sensor can't be read.
         """
         ...
 
-    def set_baud_rate(self, baud_rate: int) -> None:
+    def run(
+        self, period: float = 10.0, unit: TimeUnit = TimeUnit.MILLIS, /
+    ) -> OdometryFuture:
         """
-        Configures the baud rate of the serial port.
-
-        Baud rate determines the speed of communication over the data channel. It must be from 1
-        through `SerialPort.MAX_BAUD_RATE` (921600).
+        Returns an `OdometryFuture` that updates the pose every `period`, measured in `unit`, until
+        it is cancelled.
 
-        # Examples
+        Await it from a task of its own, spawned with `vasyncio.spawn`, to track the pose in the
+        background. Each update runs natively, so tracking at 100 Hz costs little time in Python.
 
-        ```python
-        from venice import *
+        # Raises
 
-        async def main():
-        serial = await SerialPort.open(1, 115200)
+        - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+        """
+        ...
 
-        # Change to 9600 baud.
-        serial.set_baud_rate(9600)
+    def get_pose(self) -> Pose2:
+        """Returns the tracked pose."""
+        ...
 
-        vasyncio.run(main())
-        ```
+    def get_position(self) -> Point2:
+        """Returns the position of the tracking center."""
+        ...
 
-        # Raises
+    def get_heading(self, unit: RotationUnit) -> float:
+        """Returns the heading of the robot, measured in `unit`."""
+        ...
 
-        - `TypeError`: If `baud_rate` is not an integer.
-        - `ValueError`: If `baud_rate` is outside 1 through `SerialPort.MAX_BAUD_RATE`, or the port
-        has been freed.
+    def set_pose(self, pose: Pose2) -> None:
         """
-        ...
+        Moves the tracked pose to `pose`.
 
-    def clear_buffers(self) -> None:
+        The robot doesn't move; later updates continue from the new pose.
         """
-        Clears the internal input and output FIFO buffers.
+        ...
 
-        This can be useful to reset state and remove old, potentially unneeded data from the input FIFO
-        buffer or to cancel sending any data in the output FIFO buffer.
+    def reset(self) -> None:
+        """Moves the tracked pose back to the origin, facing along the x axis."""
+        ...
 
-        # This is not the same thing as "flushing".
+class OdometryFuture:
+    """
+    An awaitable that updates an `Odometry` on a fixed schedule until it is cancelled, returned by
+    `Odometry.run`.
 
-        This method does not cause the data in the output buffer to be written. It simply clears the
-        internal buffers. Unlike standard output, generic serial does not use buffered I/O (the FIFO
-        buffers are written as soon as possible).
+    The first update happens as soon as the future is awaited and the rest follow once per period.
+    Like `vasyncio.Ticker`, updates are scheduled from the first one, and periods that are missed
+    because other tasks ran too long are skipped.
 
-        # Examples
+    # Raises
 
-        ```python
-        from venice import *
+    - `DeviceError`: If a sensor can't be read.
+    """
 
-        async def main():
-        serial = await SerialPort.open(1, 115200)
+class Gesture:
+    """Gesture data from an `OpticalSensor`."""
 
-        serial.clear_buffers()
-        serial.write(b"Buffers are clear!")
+    direction: GestureDirection
+    count: int
+    up: int
+    down: int
+    left: int
+    right: int
+    gesture_type: int
+    def get_time(self, unit: TimeUnit) -> float: ...
 
-        vasyncio.run(main())
-        ```
+class GestureDirection:
+    """Represents a gesture and its direction."""
 
-        # Raises
+    UP: ClassVar[GestureDirection]
+    """Up gesture."""
+    DOWN: ClassVar[GestureDirection]
+    """Down gesture."""
+    LEFT: ClassVar[GestureDirection]
+    """Left gesture."""
+    RIGHT: ClassVar[GestureDirection]
+    """Right gesture."""
 
-        - `ValueError`: If the port has been freed.
-        """
-        ...
+class OpticalSensor:
+    """
+    An optical sensor plugged into a Smart Port.
 
-    def free(self) -> None:
-        """
-        Releases this binding's Smart Port lock and makes the object unusable.
+    This class provides an interface to interact with the V5 Optical Sensor, which combines ambient
+    light sensing, color detection, proximity measurement, and gesture recognition capabilities.
 
-        The port number can then be used to construct another device.
+    # Hardware Overview
 
-        # Raises
+    The optical sensor provides multi-modal optical sensing with an integrated white LED for
+    low-light operation.
 
-        - `ValueError`: If the port has already been freed.
-        """
-        ...
+    ## Color Detection
 
-    def read(self, size: int = -1, /) -> bytes:
+    Color data reported as RGB, HSV, and grayscale data, with optimal performance at distances under
+    100mm. The proximity sensing uses reflected light intensity, making readings dependent on both
+    ambient lighting and target reflectivity.
+
+    ## Gesture Detection
+
+    The optical sensor can detect four distinct motions (up, down, left, right) of objects passing
+    over the sensor.
+    """
+
+    MIN_INTEGRATION_TIME_MS: ClassVar[int]
+    """
+    The smallest integration time you can set on an optical sensor, in milliseconds.
+
+    Source: <https://www.vexforum.com/t/v5-optical-sensor-refresh-rate/109632/9>
+    """
+    MAX_INTEGRATION_TIME_MS: ClassVar[int]
+    """
+    The largest integration time you can set on an optical sensor, in milliseconds.
+
+    Source: <https://www.vexforum.com/t/v5-optical-sensor-refresh-rate/109632/9>
+    """
+    GESTURE_UPDATE_INTERVAL_MS: ClassVar[int]
+    """
+    The interval that gesture detection through `OpticalSensor.get_last_gesture` provides new
+    data at.
+    """
+
+    def __init__(self, port: int, /) -> None:
         """
-        Reads up to `size` currently available bytes from the serial port's FIFO input buffer.
+        Creates a new optical sensor.
 
-        `size=-1`, the default, drains all data currently available. A nonnegative `size` limits the result;
-        values below -1 are invalid MicroPython stream sizes. An empty FIFO returns `b""` without
-        waiting.
+        # Examples
 
-        # Raises
+        ```python
+        from venice import *
 
-        - `TypeError`: If `size` is not an integer.
-        - `MemoryError`: If `size` is below -1 or is too large to allocate the result.
-        - `OSError`: If the serial read fails.
-        - `ValueError`: If the port has been freed.
+        sensor = OpticalSensor(1)
+        ```
         """
         ...
 
-    def read1(self, size: int = -1, /) -> bytes:
+    def get_hue(self) -> float:
         """
-        Reads up to `size` currently available bytes from the serial port's FIFO input buffer with
-        single-read semantics.
+        Returns the detected color's hue.
 
-        For a nonnegative `size`, MicroPython performs at most one low-level read. `size=-1`, the default,
-        instead drains all data currently available. An empty FIFO returns `b""`.
+        Hue has a range of `0` to `359.999`.
 
         # Raises
 
-        - `TypeError`: If `size` is not an integer.
-        - `MemoryError`: If `size` is below -1 or is too large to allocate the result.
-        - `OSError`: If the serial read fails.
-        - `ValueError`: If the port has been freed.
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = OpticalSensor(1)
+
+        hue = sensor.get_hue()
+        print(f"Detected color hue: {hue:.1}°")
+        ```
         """
         ...
 
-    def write(self, buffer: bytes | bytearray | memoryview, /) -> int:
+    def get_saturation(self) -> float:
         """
-        Writes `buffer` to the serial port's FIFO output buffer and returns the number of bytes accepted.
+        Returns the detected color's saturation.
 
-        `buffer` must provide a readable buffer, such as `bytes`, `bytearray`, or `memoryview`. MicroPython
-        repeats low-level writes while progress is made, so this method attempts to enqueue the complete
-        buffer.
+        Saturation has a range `0` to `1.0`.
 
         # Raises
 
-        - `TypeError`: If `buffer` does not support the readable buffer protocol.
-        - `OSError`: If the serial write fails.
-        - `ValueError`: If the port has been freed.
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = OpticalSensor(1)
+
+        saturation = sensor.get_saturation()
+        print(f"Color saturation: {saturation:.0%}")
+
+        # Check if color is muted or vibrant
+        if saturation < 0.5:
+        print("Muted color detected")
+        else:
+        print("Vibrant color detected")
+        ```
         """
         ...
 
-    def write1(self, buffer: bytes | bytearray | memoryview, /) -> int:
+    def get_brightness(self) -> float:
         """
-        Performs one low-level write from `buffer` to the serial port's FIFO output buffer and returns the
-        number of bytes accepted.
+        Returns the detected color's brightness.
 
-        This can return fewer bytes than the length of `buffer` when the output FIFO lacks space. `buffer`
-        must be `bytes`, `bytearray`, `memoryview`, or another readable buffer object.
+        Brightness values range from `0` to `1.0`.
 
         # Raises
 
-        - `TypeError`: If `buffer` does not support the readable buffer protocol.
-        - `OSError`: If the serial write fails.
-        - `ValueError`: If the port has been freed.
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = OpticalSensor(1)
+
+        brightness = sensor.get_brightness()
+        print(f"Color brightness: {brightness:.0%}")
+
+        # Check if color is dark or bright
+        if brightness < 0.3:
+        print("Dark color detected")
+        elif brightness > 0.7:
+        print("Bright color detected")
+        else:
+        print("Medium brightness color detected")
+        ```
         """
         ...
 
-    def flush(self, /) -> None:
+    def get_proximity(self) -> float:
         """
-        Completes the MicroPython flush operation and returns `None`.
+        Returns an analog proximity value from `0` to `1.0`.
 
-        The underlying generic-serial flush is a no-op because VEXos already transmits queued output as
-        quickly as possible. This method does not call `SerialPort.clear_buffers`.
+        A reading of 1.0 indicates that the object is close to the sensor, while 0.0 indicates that
+        no object is detected in range of the sensor.
 
         # Raises
 
-        - `ValueError`: If the port has been freed.
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = OpticalSensor(1)
+
+        # Monitor proximity with thresholds
+        prox = sensor.get_proximity()
+        if prox > 0.8:
+        print("Object very close!")
+        elif prox > 0.5:
+        print("Object nearby")
+        elif prox > 0.2:
+        print("Object detected")
+        else:
+        print("No object in range")
+        ```
         """
         ...
 
-    def ioctl(self, request: int, arg: int = 0, /) -> int:
+    def wait_until_proximity(self, proximity: float) -> UntilFuture:
         """
-        Performs a low-level MicroPython stream control `request` with integer `arg`, which defaults
-        to `0`.
+        Waits until the sensor's proximity reading is at least `proximity`.
 
-        Request `1` flushes the stream. Request `3` polls the flags in `arg`: readable is `0x01`, writable
-        is `0x04`, and an I/O error is returned as `0x08`. Other requests are unsupported.
+        `proximity` uses the same scale as `OpticalSensor.get_proximity`, where higher values mean
+        closer objects. Awaiting the returned `UntilFuture` returns `None`.
 
         # Raises
 
-        - `TypeError`: If `request` or `arg` is not an integer.
-        - `OSError`: If `request` is unsupported.
-        - `ValueError`: If the port has been freed.
-        """
-        ...
+        `DeviceError`: If, while awaiting, no device is connected to the port, or if the wrong type
+        of device is connected.
 
-class SerialPortOpenFuture:
-    """
-    An awaitable that opens and configures a `SerialPort`.
+        # Examples
 
-    If the port was not previously configured as a generic serial port, this may take a few milliseconds
-    to complete. Awaiting this object returns the opened `SerialPort`. Users receive it from
-    `SerialPort.open`; it cannot be constructed directly. Awaiting the same instance more than once
-    raises `RuntimeError`.
-    """
+        ```python
+        from venice import *
 
-class RotationUnit:
-    """
-    A unit selector for angular values.
+        sensor = OpticalSensor(1)
+        intake = Motor(2)
 
-    Venice APIs accept a numeric angle together with one of these singleton values and return numeric
-    angles in the selected unit. Angles are signed displacements from some rotation representing zero;
-    they are unbounded and are not automatically made modular. One turn equals 360 degrees or `2 * pi`
-    radians.
+        async def main():
+        intake.set_voltage(12)
+        await sensor.wait_until_proximity(0.8)
+        intake.brake(BrakeMode.HOLD)
 
-    This class is not constructed directly. Use `RotationUnit.RADIANS`, `RotationUnit.DEGREES`, or
-    `RotationUnit.TURNS`, which are also exported at the package root as `RADIANS`, `DEGREES`, and
-    `TURNS`. Values have readable representations such as `RotationUnit.DEGREES`.
-    """
+        vasyncio.run(main())
+        ```
+        """
+        ...
 
-    RADIANS: ClassVar[RotationUnit]
-    """Selects the number of radians rotated; also root-importable as `RADIANS`."""
-    DEGREES: ClassVar[RotationUnit]
-    """Selects the number of degrees rotated; also root-importable as `DEGREES`."""
-    TURNS: ClassVar[RotationUnit]
-    """Selects the number of turns (revolutions) rotated; also root-importable as `TURNS`."""
+    def get_color(self) -> OpticalRgb:
+        """
+        Returns the processed RGB color data from the sensor.
 
-class TimeUnit:
-    """
-    A unit selector for time intervals and durations.
+        # Raises
 
-    Venice time APIs interpret or return numeric values in the selected unit. One second equals 1,000
-    milliseconds.
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
 
-    This class is not constructed directly. Use `TimeUnit.MILLIS` or `TimeUnit.SECOND`, which are also
-    exported at the package root as `MILLIS` and the singular `SECOND`. Values have readable
-    representations such as `TimeUnit.SECOND`.
-    """
+        # Examples
 
-    MILLIS: ClassVar[TimeUnit]
-    """Selects milliseconds; also root-importable as `MILLIS`."""
-    SECOND: ClassVar[TimeUnit]
-    """Selects seconds; also root-importable as the singular name `SECOND`."""
+        ```python
+        from venice import *
 
-class EventLoop:
-    """
-    A cooperative scheduler for Venice coroutine tasks and timed sleeps.
+        sensor = OpticalSensor(1)
 
-    A *task* is a lightweight, non-blocking unit of execution. Tasks allow you to cooperatively perform
-    work in the background without blocking other code from running.
+        rgb = sensor.get_color()
+        print(f"Color reading: R={rgb.r}, G={rgb.g}, B={rgb.b}")
 
-    - Tasks are **lightweight**. Because tasks are scheduled and managed by Venice, creating new tasks
-    or switching between tasks does not require a context switch and has fairly low overhead.
-    Creating, running, and destroying large numbers of tasks is relatively cheap in comparison to
-    traditional threads.
-    - Tasks are scheduled **cooperatively**. A task will run until it voluntarily yields using an
-    `await` point, giving control back to the event loop. The loop then switches to executing a
-    different task.
-    - Tasks are **non-blocking**. When a task cannot continue executing, it should yield, allowing the
-    event loop to schedule another task in its place. Tasks should not perform operations that could
-    block the CPU for a long period without an `await` point, because this prevents other tasks from
-    executing as well. This includes long-running tight loops without `await` points.
+        # Example: Check if object is primarily red
+        # Note that you should probably use `OpticalSensor.get_hue` instead for this
+        if rgb.r > rgb.g && rgb.r > rgb.b:
+        print("Object is primarily red!")
+        ```
+        """
+        ...
 
-    The loop runs one ready task at a time. Users normally call `vasyncio.run` instead of managing an
-    event loop directly.
-    """
-    def __init__(self, /) -> None:
+    def get_raw_color(self) -> OpticalRaw:
         """
-        Creates an empty event loop.
+        Returns the raw, unprocessed RGBC color data from the sensor.
 
         # Raises
 
-        - `TypeError`: If any positional or keyword arguments are supplied.
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
         """
         ...
 
-    def spawn(self, coro: Any, /) -> Task:
+    def get_last_gesture(self) -> Gesture | None:
         """
-        Schedules coroutine object `coro` on this loop and returns an awaitable `Task`.
+        Returns the most recent gesture data from the sensor, or `None` if no gesture was detected.
 
-        Scheduling does not run the coroutine until the loop is running. Awaiting the returned task
-        yields its return value whether the task is still running or has already finished.
+        Gesture data updates every 500 milliseconds.
 
         # Raises
 
-        - `TypeError`: If `coro` is not a coroutine object.
-        """
-        ...
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
 
-    def run(self, /) -> None:
-        """
-        Runs scheduled tasks until no ready tasks or pending sleeps remain.
+        # Examples
 
-        While this method is running, `vasyncio.get_running_loop` returns this loop and
-        `vasyncio.spawn` adds tasks to it. An exception raised by a task stops the loop and is
-        propagated to the caller.
+        ```python
+        from venice import *
 
-        # Raises
+        sensor = OpticalSensor(1)
 
-        - `RuntimeError`: If tasks form a direct or transitive await cycle.
-        - `ValueError`: If a `Sleep` deadline is too large to represent.
+        async def main():
+        while True:
+        gesture = sensor.get_last_gesture()
+        if direction:
+        print(f"Direction: {gesture.direction}")
+
+        await vasyncio.Sleep(25, MILLIS)
+
+        vasyncio.run(main)
+        ```
         """
         ...
 
-class Sleep:
-    """
-    An awaitable that will complete after a given duration.
-
-    Awaiting a `Sleep` effectively yields the current task for a period of time. Constructing it does
-    not block and does not start a separate task. When the duration has elapsed, awaiting it returns
-    `None`.
-    """
-    def __init__(self, interval: float, unit: TimeUnit, /) -> None:
+    def get_led_brightness(self) -> float:
         """
-        Waits until `interval`, measured in `unit`, has elapsed.
+        Returns the intensity/brightness of the sensor's LED indicator as a number from [0.0-1.0].
 
-        This constructor returns an awaitable that will complete after the given duration, effectively
-        yielding the current task for a period of time. Use `MILLIS` for milliseconds or `SECOND` for
-        seconds. `interval` must be finite, non-negative, and small enough to represent.
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
 
         # Examples
 
         ```python
         from venice import *
 
-        async def main():
-        print("See you in 5 minutes.")
-        await vasyncio.Sleep(300, SECOND)
-        print("Hello again!")
+        sensor = OpticalSensor(1)
 
-        vasyncio.run(main())
+        brightness = sensor.get_led_brightness()
+        print(f"LED brightness: {brightness:.1%}")
         ```
-
-        # Raises
-
-        - `TypeError`: If any keyword argument is supplied.
-        - `ValueError`: If `interval` is negative, non-finite, or too large to represent.
         """
         ...
 
-class Task:
-    """
-    A spawned task.
+    def set_led_brightness(self, brightness: float) -> None:
+        """
+        Set the intensity of (intensity/brightness) of the sensor's LED indicator.
 
-    A `Task` can be awaited to retrieve the output of its coroutine.
+        Intensity is expressed as a number from [0.0, 1.0].
 
-    `EventLoop.spawn` and `vasyncio.spawn` return tasks; `Task` is not directly exported from the
-    `vasyncio` submodule and is not constructed by users. Awaiting a task cooperatively waits for
-    its coroutine and returns that coroutine's return value, including when the task completed
-    before the await began. Direct or transitive cycles between awaited tasks raise `RuntimeError`.
-    A coroutine exception propagates out of the running event loop. `Task` objects cannot be
-    cancelled.
+        # Python
 
-    # Examples
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
 
-    ```python
-    from venice import *
+        # Examples
 
-    async def work():
-    print("Hello from a task!")
-    return 1 + 2
+        ```python
+        from venice import *
 
-    async def main():
-    # Spawn a coroutine onto the event loop.
-    task = vasyncio.spawn(work())
+        sensor = OpticalSensor(1)
 
-    # Wait for the task's output.
-    assert await task == 3
+        async def main():
+        for _ in range(3):
+        # Turn LED on
+        try:
+        sensor.set_led_brightness(1)
+        except DeviceError as e:
+        print(f"Failed to turn LED on: {e}")
 
-    vasyncio.run(main())
-    ```
-    """
+        await vasyncio.Sleep(250, MILLIS)
 
-class VisionCode:
-    """
-    A vision detection code.
+        # Turn LED off
+        try:
+        sensor.set_led_brightness(0)
+        except DeviceError as e:
+        print(f"Failed to turn LED off: {e}")
 
-    This class is root-importable. Codes are a special type of detection signature that group multiple `VisionSignature` objects
-    together. A `VisionCode` can associate 2-5 color signatures together, detecting the resulting
-    object when its color signatures are present close to each other.
+        await vasyncio.Sleep(250, MILLIS)
+        ```
+        """
+        ...
 
-    These codes work very similarly to
-    [Pixy2 Color Codes](https://docs.pixycam.com/wiki/doku.php?id=wiki:v2:using_color_codes).
+    def get_integration_time(self, unit: TimeUnit) -> float:
+        """
+        Returns integration time of the optical sensor in milliseconds, with minimum time being 3ms
+        and the maximum time being 712ms.
 
-    The read-only `sig1` and `sig2` attributes are required signature IDs from 1 to 7; read-only `sig3`,
-    `sig4`, and `sig5` contain additional IDs or `None`. Codes compare equal when all five slots
-    match, return `False` when compared with another type, and have a readable `VisionCode(...)`
-    representation.
-    """
+        The default integration time for the sensor is 103mS, unless otherwise set with
+        `OpticalSensor.set_integration_time`.
 
-    sig1: int
-    sig2: int
-    sig3: int | None
-    sig4: int | None
-    sig5: int | None
-    @overload
-    def __init__(self, sig1: int, sig2: int, /) -> None:
-        """
-        Creates a new vision code.
+        # Raises
 
-        Two signatures, `sig1` and `sig2`, are required to create a vision code, with an additional three
-        optional signatures, `sig3`, `sig4`, and `sig5`. Each supplied ID must be an integer from 1 to 7.
-        Although the generated annotation includes `None` for the trailing slots, the runtime accepts
-        omission rather than an explicitly passed `None`. All arguments are positional-only.
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
 
         # Examples
 
         ```python
         from venice import *
 
-        # Create a vision code associated with signatures 1, 2, and 3.
-        code = VisionCode(1, 2, 3)
-        ```
+        sensor = OpticalSensor(1)
 
-        # Raises
+        # Set integration time to 50 milliseconds.
+        sensor.set_integration_time(50, MILLIS)
 
-        - `TypeError`: If a supplied ID is not an integer, `None` is passed explicitly, a keyword argument
-        is supplied, or the argument count is outside two to five.
-        - `ValueError`: If a supplied signature ID is outside the inclusive range 1 to 7.
+        # Log out the new integration time.
+        integration_time = sensor.get_integration_time(MILLIS)
+        print(f"Integration time: {integration_time}ms")
+        ```
         """
         ...
-    @overload
-    def __init__(self, sig1: int, sig2: int, sig3: int, /) -> None: ...
-    @overload
-    def __init__(self, sig1: int, sig2: int, sig3: int, sig4: int, /) -> None: ...
-    @overload
-    def __init__(
-        self, sig1: int, sig2: int, sig3: int, sig4: int, sig5: int, /
-    ) -> None: ...
-    @staticmethod
-    def from_id(id: int, /) -> VisionCode:
+
+    def set_integration_time(self, time: float, unit: TimeUnit) -> None:
         """
-        Creates a `VisionCode` from a bit representation of its signature IDs.
+        Set the integration time of the optical sensor.
 
-        `id` must be from 0 through 32767 and is interpreted as five three-bit signature slots.
-        `sig1` and `sig2` must be nonzero; zero means an absent optional slot, and populated optional
-        slots must be contiguous.
+        Lower integration time results in faster update rates with lower accuracy due to less
+        available light being read by the sensor.
+
+        The `time` value must be a duration between 3 and 712 milliseconds. If the integration
+        time is out of this range, it will be clamped to fit inside it. See
+        <https://www.vexforum.com/t/v5-optical-sensor-refresh-rate/109632/9> for more information.
+
+        The default integration time for the sensor is 103mS, unless otherwise set.
+
+        # Raises
+
+        - `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+        - `ValueError`: If `time` is negative, non-finite, or too large to represent.
 
         # Examples
 
         ```python
         from venice import *
 
-        sig_1_id = 1
-        sig_2_id = 2
-
-        # Store IDs 1 and 2 in the first two of the five three-bit slots.
-        code_id = (sig_1_id << 12) | (sig_2_id << 9)
+        sensor = OpticalSensor(1)
 
-        # Create a VisionCode from signatures 1 and 2.
-        code = VisionCode.from_id(code_id)
-        ```
-
-        # Raises
-
-        - `TypeError`: If `id` is not an integer.
-        - `ValueError`: If `id` is outside 0 through 32767, either required slot is zero, or a
-        populated optional slot follows an empty one.
+        # Set integration time to 50 milliseconds.
+        sensor.set_integration_time(50, MILLIS)
+        ```
         """
         ...
 
-class LedMode:
-    """
-    Vision Sensor LED mode.
+    def get_status(self) -> int:
+        """
+        Returns the internal status code of the optical sensor.
 
-    Represents the states that the integrated LED indicator on a Vision Sensor can be in. `LedMode` is
-    an abstract base and cannot be instantiated directly. Construct its associated-only variants as
-    `LedMode.Auto()` or `LedMode.Manual(...)`; the variants are not package-root imports.
-    """
+        # Raises
 
-    Auto: ClassVar[type[Auto]]
-    """The associated Automatic Mode class, constructed as `LedMode.Auto()`."""
-    Manual: ClassVar[type[Manual]]
-    """The associated Manual Mode class, constructed as `LedMode.Manual(...)`."""
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
 
-    def __init__(self, /) -> None:
-        """
-        Rejects direct construction of the abstract `LedMode` base class.
+        # Examples
 
-        Use `LedMode.Auto()` or `LedMode.Manual(r, g, b, brightness)` instead.
+        ```python
+        from venice import *
 
-        # Raises
+        sensor = OpticalSensor(1)
 
-        - `TypeError`: Always.
+        status = sensor.get_status()
+        print(f"Status: 0x{status:08x}")
+        ```
         """
         ...
 
-class Auto:
-    """
-    Automatic Mode.
-
-    When in automatic mode, the integrated LED will display the color of the most prominent detected
-    object's signature color. This associated-only class is constructed as `LedMode.Auto()` and is not
-    package-root importable. Construction always returns the same singleton, which prints as
-    `LedMode.Auto()`.
-    """
-    def __init__(self, /) -> None:
+    def free(self) -> None:
         """
-        Returns the singleton Automatic Mode.
+        Release this device and free its Smart Port lock. This binding will become unusable after
+        this call, but you can reuse the underlying Smart Port number in a new device.
+
+        Any attempts to use this device after freeing will result in a `ValueError` being raised.
 
         # Raises
 
-        - `TypeError`: If any positional or keyword arguments are supplied.
+        `ValueError`: If the device has already been freed.
+
+        # Examples
+
+        Construct a `Motor`, free it, then construct a `RotationSensor` with the same Smart Port:
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+        motor.free()
+        # `motor` is now unusable, but port 1 can be used in another device, such as a `RotationSensor`
+        rotation_sensor = RotationSensor(1)
+        ```
         """
         ...
 
-class Manual:
-    """
-    Manual Mode.
-
-    When in manual mode, the integrated LED will display a user-set RGB color code and brightness
-    percentage from 0.0-1.0. This associated-only class is constructed as `LedMode.Manual(...)` and is
-    not package-root importable. Its read-only `r`, `g`, and `b` attributes are RGB channels from 0 to
-    255; its read-only `brightness` attribute is the intended normalized brightness. Values compare by
-    these four attributes, return `False` when compared with another type, and print as
-    `LedMode.Manual(r=..., g=..., b=..., brightness=...)`.
-    """
+class OpticalRgb:
+    """RGB data from an `OpticalSensor`."""
 
+    r: float
+    g: float
+    b: float
     brightness: float
+
+class OpticalRaw:
+    """Represents the raw RGBC data an `OpticalSensor`."""
+
     r: int
     g: int
     b: int
-    def __init__(self, r: int, g: int, b: int, brightness: float, /) -> None:
-        """
-        Creates a Manual Mode with RGB channels `r`, `g`, and `b` and normalized `brightness`.
-
-        RGB channels are positional-only integers from 0 to 255. `brightness` is positional-only, accepts
-        an integer or float, and is intended to range from 0.0 for off to 1.0 for full brightness; the
-        constructor does not enforce that brightness range.
+    clear: int
 
-        # Raises
+class PurePursuit:
+    """
+    A path follower for a differential drive that steers towards a point a set distance ahead on
+    the path.
 
-        - `TypeError`: If an RGB channel is not an integer, `brightness` is not numeric, a keyword argument
-        is supplied, or the argument count is not exactly four.
-        - `ValueError`: If an RGB channel is outside the inclusive range 0 to 255.
-        """
-        ...
+    `PurePursuit` drives forwards through a list of `Point2` or `Pose2` waypoints, using the pose
+    reported by `source`, which is an `Odometry` or a `PoseEstimator`. The path runs in straight
+    lines from the robot's position when following starts, through each waypoint in turn. Only
+    the positions of `Pose2` waypoints are used; the robot's heading follows the path. A
+    `Trajectory` can be followed instead, in which case only its shape is used: the path runs along
+    the trajectory's curves from its first waypoint, and the controller sets its own speed.
 
-class VisionSensor:
-    """
-    A Vision Sensor plugged into a Smart Port.
+    On each update, the controller finds the point on the path nearest the robot, then steers along
+    the arc that passes through the point `lookahead` further along. A longer lookahead follows the
+    path more smoothly but cuts corners more. With a `lookahead_gain`, the lookahead grows by that
+    much per unit of velocity, up to `max_lookahead`, so that the robot looks further ahead when it
+    is moving quickly.
 
-    This class provides an interface for interacting with the VEX Vision Sensor.
+    The robot slows for tight turns so that neither wheel exceeds `max_velocity`. With a
+    `max_acceleration`, it also speeds up gradually and slows down so that it can stop at the end
+    of the path. The path is finished once the robot is within `tolerance` of its last waypoint or
+    has passed it.
 
-    # Hardware Overview
+    Distances are measured in the unit of the pose source, velocities in that unit per second and
+    accelerations in that unit per second squared. The source must be kept up to date while the
+    path is followed, such as by awaiting `Odometry.run` in another task.
 
-    The VEX Vision Sensor is a device powered by an ARM Cortex M4 and Cortex M0 coprocessor with a
-    color camera for the purpose of performing object recognition. The sensor can be trained to
-    locate objects by color. The camera module itself is very similar internally to the Pixy2
-    camera, and performs its own onboard image processing. Manually processing raw image data from
-    the sensor is not currently possible.
+    # Examples
 
-    Every 20 milliseconds, the camera provides a list of the objects found matching up to seven
-    unique `VisionSignature`s. The object’s height, width, and location is provided. Multi-colored
-    objects may also be programmed through the use of `VisionCode`s.
+    ```python
+    from venice import *
 
-    The Vision Sensor has USB for a direct connection to a computer, where it can be configured
-    using VEX's proprietary vision utility tool to generate color signatures. The Vision Sensor also
-    has Wi-Fi Direct and can act as web server, allowing a live video feed of the camera from any
-    computer equipped with a browser and Wi-Fi.
-    """
+    odom = Odometry(
+    [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+    imu=InertialSensor(3),
+    )
+    left = MotorGroup([(11, Direction.REVERSE), (12, Direction.REVERSE)], Gearset.BLUE)
+    right = MotorGroup([13, 14], Gearset.BLUE)
+    pursuit = PurePursuit(odom, 12.0, 50.0, 10.0, max_acceleration=80.0)
 
-    HORIZONTAL_RESOLUTION: ClassVar[int]
-    """
-    The horizontal resolution of the vision sensor.
+    async def track():
+    await odom.run(10, MILLIS)
 
-    This value is based on the `VISION_FOV_WIDTH` macro constant in PROS.
-    """
-    VERTICAL_RESOLUTION: ClassVar[int]
-    """
-    The vertical resolution of the vision sensor.
+    async def main():
+    vasyncio.spawn(track())
+    path = [Point2(24, 0), Point2(48, 24), Point2(48, 48)]
+    await pursuit.follow(path, left, right, 3.25, gear_ratio=0.6, timeout=5000)
 
-    This value is based on the `VISION_FOV_HEIGHT` macro constant in PROS.
+    vasyncio.run(main())
+    ```
     """
-    HORIZONTAL_FOV: ClassVar[float]
-    """The horizontal FOV of the vision sensor in degrees."""
-    VERTICAL_FOV: ClassVar[float]
-    """The vertical FOV of the vision sensor in degrees."""
-    DIAGONAL_FOV: ClassVar[float]
-    """The diagonal FOV of the vision sensor in degrees."""
-    UPDATE_INTERVAL_MS: ClassVar[int]
-    """The update rate of the vision sensor, in milliseconds."""
-
-    def __init__(self, port: int, /) -> None:
+    def __init__(
+        self,
+        source: Odometry | PoseEstimator,
+        track_width: float,
+        max_velocity: float,
+        lookahead: float,
+        /,
+        *,
+        lookahead_gain: float = 0.0,
+        max_lookahead: float | None = None,
+        max_acceleration: float | None = None,
+        tolerance: float = 1.0,
+    ) -> None:
         """
-        Creates a new vision sensor.
+        Creates a pure pursuit controller that reads the robot's pose from `source`.
 
-        # Examples
+        `track_width` is the distance between the left and right wheels. `max_lookahead` and
+        `max_acceleration` are unlimited if `None`.
 
-        ```python
-        from venice import *
+        # Raises
 
-        sensor = VisionSensor(1)
-        ```
+        - `TypeError`: If `source` isn't an `Odometry` or `PoseEstimator`, or a number isn't
+        numeric.
+        - `ValueError`: If `track_width`, `max_velocity`, `lookahead`, `max_lookahead` or
+        `max_acceleration` isn't positive and finite, if `lookahead_gain` or `tolerance` is
+        negative or non-finite, or if `max_lookahead` is less than `lookahead`.
         """
         ...
 
-    def set_signature(self, id: int, signature: VisionSignature, /) -> None:
+    def set_path(self, path: list[Point2 | Pose2] | Trajectory, /) -> None:
         """
-        Adds a detection signature to the sensor's onboard memory.
+        Sets the waypoints or trajectory to follow, starting again at the next update.
 
-        This signature will be used to identify objects when using `VisionSensor.get_objects`.
+        # Raises
 
-        The sensor can store up to 7 unique signatures, with each signature slot denoted by the `id`
-        parameter. If a signature with an ID matching an existing signature on the sensor is added,
-        then the existing signature will be overwritten with the new one.
+        - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+        waypoints.
+        - `ValueError`: If `path` is empty.
+        """
+        ...
 
-        # Volatile Memory
+    def calculate(self, /) -> tuple[float, float]:
+        """
+        Reads the robot's pose and returns the left and right wheel velocities that steer it along
+        the path, or `(0.0, 0.0)` once the path is finished.
 
-        The memory on the Vision Sensor is *volatile* and will therefore be wiped when the sensor
-        loses power. As a result, this function should be called every time the sensor is used on
-        program start.
+        Use this instead of `PurePursuit.follow` to drive the wheels from your own loop. The
+        acceleration limit assumes that it is called regularly.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
-        `ValueError`: If the given signature ID is not in the interval [1, 7].
+        - `RuntimeError`: If no path has been set, or if the pose source is a `PoseEstimator` that
+        has no estimate yet.
+        """
+        ...
 
-        # Examples
+    def is_finished(self) -> bool:
+        """Returns whether the robot has reached the end of the path."""
+        ...
 
-        ```python
-        from venice import *
+    def follow(
+        self,
+        path: list[Point2 | Pose2] | Trajectory,
+        left: MotorGroup,
+        right: MotorGroup,
+        wheel_diameter: float,
+        /,
+        *,
+        gear_ratio: float = 1.0,
+        timeout: float | None = None,
+        time_unit: TimeUnit = TimeUnit.MILLIS,
+    ) -> FollowFuture:
+        """
+        Sets the waypoints or trajectory to follow, then returns a `FollowFuture` that drives the
+        `left` and `right` motor groups along it until the path is finished.
 
-        sensor = VisionSensor(1)
+        `wheel_diameter` is measured in the unit of the pose source, and `gear_ratio` is the number
+        of turns of the wheels per turn of the motors. `timeout`, measured in `time_unit`, is
+        unlimited if `None`.
 
-        # These signatures can be generated using VEX's vision utility
-        example_signature = VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1)
+        # Raises
 
-        # Set signature 1 on the sensor.
-        sensor.set_signature(1, example_signature)
-        ```
+        - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+        waypoints, or if `left` or `right` isn't a `MotorGroup`.
+        - `ValueError`: If `path` is empty, if `wheel_diameter` or `gear_ratio` isn't positive and
+        finite, or if `timeout` is negative, non-finite, or too large to represent.
         """
         ...
 
-    def get_signature(self, id: int, /) -> VisionSignature | None:
-        """
-        Returns a signature from the sensor's onboard volatile memory.
+class Ramsete:
+    """
+    A path follower for a differential drive that tracks a reference moving along the path, using
+    the RAMSETE control law.
 
-        # Raises
+    `Ramsete` drives forwards through a list of `Point2` or `Pose2` waypoints, or along a
+    `Trajectory`, using the pose reported by `source`, which is an `Odometry` or a
+    `PoseEstimator`. A list of waypoints makes a path that runs in straight lines from the robot's
+    position when following starts, through each waypoint in turn. Only the positions of `Pose2`
+    waypoints are used; the robot's heading follows the path.
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
-        `ValueError`: If the given signature ID is not in the interval [1, 7].
+    A reference point moves along the path, speeding up at `max_acceleration`, slowing for curves
+    so that neither wheel has to exceed `max_velocity`, and slowing so that it stops at the end. The
+    controller drives the robot at the reference's velocity, corrected for how far the robot is
+    behind, beside, or turned away from it. Unlike `PurePursuit`, this keeps the robot to a
+    schedule, so it arrives at a predictable time. Corners are rounded off over one track width.
+    The path is finished once the reference reaches the end, so the robot stops close behind it.
 
-        # Examples
+    Along a `Trajectory`, the reference instead keeps to the trajectory's own timing, starting when
+    following starts, and `max_acceleration` has no effect. The trajectory is finished once its
+    duration has passed.
 
-        ```python
-        from venice import *
+    `b` and `zeta` tune the controller: a larger `b` corrects errors more aggressively, and `zeta`,
+    between 0 and 1, damps the correction. The defaults of 2.0 and 0.7 suit most robots when
+    distances are in metres; set `scale` to the number of source units in a metre, such as 39.37
+    for inches, to keep them suitable.
 
-        sensor = VisionSensor(1)
+    Distances are measured in the unit of the pose source, velocities in that unit per second and
+    accelerations in that unit per second squared. The source must be kept up to date while the
+    path is followed, such as by awaiting `Odometry.run` in another task.
 
-        # Set an example signature in the sensor's first slot.
-        sensor.set_signature(1, VisionSignature(10049, 11513, -425, 1, -212, 4.1))
+    # Examples
 
-        # Read signature 1 off the sensor.
-        # This should be the same as the one we just set.
-        signature = sensor.get_signature(1)
-        print(signature)
-        ```
+    ```python
+    from venice import *
+
+    odom = Odometry(
+    [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+    imu=InertialSensor(3),
+    )
+    left = MotorGroup([(11, Direction.REVERSE), (12, Direction.REVERSE)], Gearset.BLUE)
+    right = MotorGroup([13, 14], Gearset.BLUE)
+    ramsete = Ramsete(odom, 12.0, 50.0, 80.0, scale=39.37)
+
+    async def track():
+    await odom.run(10, MILLIS)
+
+    async def main():
+    vasyncio.spawn(track())
+    path = [Point2(24, 0), Point2(48, 24), Point2(48, 48)]
+    await ramsete.follow(path, left, right, 3.25, gear_ratio=0.6)
+
+    vasyncio.run(main())
+    ```
+    """
+    def __init__(
+        self,
+        source: Odometry | PoseEstimator,
+        track_width: float,
+        max_velocity: float,
+        max_acceleration: float,
+        /,
+        *,
+        b: float = 2.0,
+        zeta: float = 0.7,
+        scale: float = 1.0,
+    ) -> None:
+        """
+        Creates a RAMSETE controller that reads the robot's pose from `source`.
+
+        `track_width` is the distance between the left and right wheels.
+
+        # Raises
+
+        - `TypeError`: If `source` isn't an `Odometry` or `PoseEstimator`, or a number isn't
+        numeric.
+        - `ValueError`: If `track_width`, `max_velocity`, `max_acceleration`, `b` or `scale` isn't
+        positive and finite, or if `zeta` isn't between 0 and 1.
         """
         ...
 
-    def get_signatures(self, /) -> tuple[VisionSignature | None, ...]:
+    def set_path(self, path: list[Point2 | Pose2] | Trajectory, /) -> None:
         """
-        Returns all signatures currently stored on the sensor's onboard volatile memory.
+        Sets the waypoints or trajectory to follow, starting again at the next update.
 
         # Raises
 
-        - `DeviceError`: If there was not a sensor connected to the port, or if a read operation
-        failed, or there was no signature previously set in the slot(s) specified in the
-        `VisionCode`.
+        - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+        waypoints.
+        - `ValueError`: If `path` is empty.
+        """
+        ...
 
-        # Examples
+    def calculate(self, /) -> tuple[float, float]:
+        """
+        Moves the reference along the path, reads the robot's pose, and returns the left and right
+        wheel velocities that steer it towards the reference, or `(0.0, 0.0)` once the path is
+        finished.
 
-        ```python
-        from venice import *
+        Use this instead of `Ramsete.follow` to drive the wheels from your own loop. The reference
+        moves by the time since the previous call, so call it regularly.
 
-        sensor = VisionSensor(1)
+        # Raises
 
-        # A bunch of random color signatures.
-        sig_1 = VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1)
-        sig_2 = VisionSignature(8973, 11143, 10058, -2119, -1053, -1586, 5.4)
-        sig_3 = VisionSignature(-3665, -2917, -3292, 4135, 10193, 7164, 2.0)
-        sig_4 = VisionSignature(-5845, -4809, -5328, -5495, -4151, -4822, 3.1)
+        - `RuntimeError`: If no path has been set, or if the pose source is a `PoseEstimator` that
+        has no estimate yet.
+        """
+        ...
 
-        # Set signatures 1-4.
-        sensor.set_signature(1, sig_1)
-        sensor.set_signature(2, sig_2)
-        sensor.set_signature(3, sig_3)
-        sensor.set_signature(4, sig_4)
-
-        # Read back the signatures from the sensor's memory.
-        # These should be the signatures that we just set.
-        signatures = sensor.get_signatures()
-        for signature in signatures:
-        if signature:
-        print(f"Found sig saved on sensor: {signature}")
-        ```
-        """
+    def is_finished(self) -> bool:
+        """Returns whether the reference has reached the end of the path."""
         ...
 
-    def add_code(self, code: VisionCode) -> None:
+    def follow(
+        self,
+        path: list[Point2 | Pose2] | Trajectory,
+        left: MotorGroup,
+        right: MotorGroup,
+        wheel_diameter: float,
+        /,
+        *,
+        gear_ratio: float = 1.0,
+        timeout: float | None = None,
+        time_unit: TimeUnit = TimeUnit.MILLIS,
+    ) -> FollowFuture:
         """
-        Registers a color code to the sensor's onboard memory. This code will be used to identify
-        objects when using `VisionSensor.get_objects`.
+        Sets the waypoints or trajectory to follow, then returns a `FollowFuture` that drives the
+        `left` and `right` motor groups along it until the path is finished.
 
-        Color codes are effectively "signature groups" that the sensor will use to identify objects
-        containing the color of their signatures next to each other.
-
-        # Volatile Memory
-
-        The onboard memory of the Vision Sensor is *volatile* and will therefore be wiped when the
-        sensor loses its power source. As a result, this function should be called every time the
-        sensor is used on program start.
+        See `PurePursuit.follow`.
 
         # Raises
 
-        - `DeviceError`: If there was not a sensor connected to the port, or if a read operation
-        failed, or there was no signature previously set in the slot(s) specified in the
-        `VisionCode`.
-        `ValueError`: If the given signature ID is not in the interval [1, 7].
+        - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+        waypoints, or if `left` or `right` isn't a `MotorGroup`.
+        - `ValueError`: If `path` is empty, if `wheel_diameter` or `gear_ratio` isn't positive and
+        finite, or if `timeout` is negative, non-finite, or too large to represent.
+        """
+        ...
 
-        # Examples
+class FollowFuture:
+    """
+    An awaitable that drives two motor groups along a path until the end is reached, returned by
+    `PurePursuit.follow` and `Ramsete.follow`.
 
-        ```python
-        from venice import *
+    Between updates to the motors' velocities, the awaiting task is parked on the event loop, so
+    other tasks keep running. When the future completes, times out or fails, both motor groups are
+    told to hold a velocity of zero, including when the awaiting task is cancelled.
 
-        sensor = VisionSensor(1)
+    # Raises
 
-        # Two color signatures.
-        sig_1 = VisionSensor(10049, 11513, 10781, -425, 1, -212, 4.1)
-        sig_2 = VisionSensor(8973, 11143, 10058, -2119, -1053, -1586, 5.4)
+    - `DeviceError`: If a motor or sensor can't be used.
+    - `RuntimeError`: If the pose source is a `PoseEstimator` that has no estimate yet.
+    - `vasyncio.TimeoutError`: If the end of the path hasn't been reached before the timeout.
+    """
+    def throw(self, exc: BaseException, /) -> Any:
+        """
+        Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
 
-        # Store the signatures on the sensor.
-        sensor.set_signature(1, sig_1)
-        sensor.set_signature(2, sig_2)
+        Both motor groups are stopped before the exception propagates.
+        """
+        ...
 
-        # Create a code associating signatures 1 and 2 together.
-        code = VisionCode(1, 2)
+    def close(self) -> None:
+        """Stops both motor groups when the awaiting coroutine is closed."""
+        ...
 
-        # Register our code on the sensor. When we call `VisionSensor.get_objects`, the associated
-        # signatures will be returned as a single object if their colors are detected next to each
-        # other.
-        sensor.add_code(code)
+class TrapezoidProfile:
+    """
+    A trapezoidal motion profile.
 
-        # Scan for objects.
-        for object in sensor.get_objects():
-        # Filter only objects matching the code we just set.
-        if obj.source == DetectionSource.Code(code):
-        print(obj)
-        ```
-        """
-        ...
+    The profile moves over `distance` by accelerating at `max_acceleration` until it reaches
+    `max_velocity`, cruising, and then decelerating to a stop at the end. If the distance is too
+    short to reach `max_velocity`, it decelerates as soon as it reaches the halfway point instead.
 
-    def get_led_mode(self, /) -> LedMode:
+    Distances may be measured in any unit, as long as velocities are in that unit per second and
+    accelerations in that unit per second squared. A negative `distance` moves backwards.
+
+    Use `Motor.follow_profile` to drive a motor along the profile.
+    """
+    def __init__(
+        self, distance: float, max_velocity: float, max_acceleration: float, /
+    ) -> None:
         """
-        Returns the user-set behavior of the LED indicator on the sensor.
+        Plans a trapezoidal profile over `distance`.
 
         # Raises
 
-        - `DeviceError`: If there was not a sensor connected to the port, or if a read operation
-        failed, or there was no signature previously set in the slot(s) specified in the
-        `VisionCode`.
+        - `TypeError`: If an argument is not numeric.
+        - `ValueError`: If `max_velocity` or `max_acceleration` isn't positive and finite, or if
+        `distance` isn't finite.
 
         # Examples
 
         ```python
         from venice import *
 
-        sensor = VisionSensor(1)
+        # Move 90 degrees, at up to 180 degrees/s, accelerating at 360 degrees/s^2
+        profile = TrapezoidProfile(90, 180, 360)
+        print(profile.duration(SECOND))
+        ```
+        """
+        ...
 
-        async def main():
-        # Set the LED to red at 100% brightness.
-        sensor.set_led_mode(LedMode.Manual(255, 0, 0, 1))
+    def sample(self, t: float, unit: TimeUnit = TimeUnit.SECOND, /) -> ProfileState:
+        """
+        Returns the state of the profile `t` after it starts, measured in `unit`.
 
-        # Give the sensor time to update.
-        await vasyncio.Sleep(VisionSensor.UPDATE_INTERVAL_MS, MILLIS)
+        Times before the start or after the end of the profile return the state at the start or
+        end, where the profile is at rest.
 
-        # Check the sensor's reported LED mode. Should be the same as what we just set.
-        led_mode = sensor.get_led_mode()
-        assert led_mode == LedMode.Manual(255, 0, 0, 1)
+        # Raises
 
-        vasyncio.run(main)
-        ```
+        - `ValueError`: If `t` isn't finite, or is too large to represent.
         """
         ...
 
-    def get_objects(self, /) -> tuple[VisionObject, ...]:
+    def duration(self, unit: TimeUnit = TimeUnit.SECOND, /) -> float:
         """
-        Returns a `tuple` of objects detected by the sensor.
+        Returns the time taken to complete the profile, measured in `unit`.
 
         # Raises
 
-        - `DeviceError`: If there was not a sensor connected to the port, or if the sensor is in
-        Wi-Fi mode, or if the sensor failed to read an object.
+        - `ValueError`: If the duration is too large to represent.
+        """
+        ...
 
-        # Examples
+class SCurveProfile:
+    """
+    An S-curve motion profile.
 
-        With one signature:
+    Like `TrapezoidProfile`, the profile accelerates, cruises and decelerates over `distance`, but
+    the acceleration ramps up and down at no more than `max_jerk` rather than changing instantly.
+    This makes the motion smoother, which reduces wear and keeps loads on an arm or lift from
+    swinging, at the cost of taking slightly longer.
 
-        ```python
-        from venice import *
+    Distances may be measured in any unit, as long as velocities are in that unit per second,
+    accelerations in that unit per second squared, and jerks in that unit per second cubed. A
+    negative `distance` moves backwards.
 
-        sensor = VisionSensor(1)
+    Use `Motor.follow_profile` to drive a motor along the profile.
+    """
+    def __init__(
+        self,
+        distance: float,
+        max_velocity: float,
+        max_acceleration: float,
+        max_jerk: float,
+        /,
+    ) -> None:
+        """
+        Plans an S-curve profile over `distance`.
 
-        # Set a color signature on the sensor's first slot.
-        sensor.set_signature(1, VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1))
+        # Raises
 
-        # Scan for detected objects.
-        for _ in sensor.get_objects():
-        print("Object detected")
-        ```
+        - `TypeError`: If an argument is not numeric.
+        - `ValueError`: If `max_velocity`, `max_acceleration` or `max_jerk` isn't positive and
+        finite, or if `distance` isn't finite.
 
-        With multiple signatures:
+        # Examples
 
         ```python
         from venice import *
 
-        sensor = VisionSensor(1)
+        # Move 2 turns, at up to 1 turn/s, accelerating at up to 2 turns/s^2
+        profile = SCurveProfile(2, 1, 2, 8)
+        state = profile.sample(500, MILLIS)
+        print(state.position, state.velocity, state.acceleration)
+        ```
+        """
+        ...
 
-        # Two color signatures.
-        sig_1 = VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1)
-        sig_2 = VisionSignature(8973, 11143, 10058, -2119, -1053, -1586, 5.4)
+    def sample(self, t: float, unit: TimeUnit = TimeUnit.SECOND, /) -> ProfileState:
+        """
+        Returns the state of the profile `t` after it starts, measured in `unit`.
 
-        # Store the signatures on the sensor.
+        Times before the start or after the end of the profile return the state at the start or
+        end, where the profile is at rest.
 
-        sensor.set_signature(1, sig_1)
-        sensor.set_signature(2, sig_2)
+        # Raises
 
-        # Scan for objects.
-        for object in sensor.get_objects():
-        # Identify which signature the detected object matches.
-        if object.source == DetectionSource.Signature(1):
-        print(f"Detected object matching sig_1: {object}")
-        elif object.source == DetectionSource.Signature(2):
-        print(f"Detected object matching sig_2: {object}")
-        ```
+        - `ValueError`: If `t` isn't finite, or is too large to represent.
         """
         ...
 
-    def get_object_count(self) -> int:
+    def duration(self, unit: TimeUnit = TimeUnit.SECOND, /) -> float:
         """
-        Returns the number of objects detected by the sensor.
+        Returns the time taken to complete the profile, measured in `unit`.
 
         # Raises
 
-        - `DeviceError`: If there was not a sensor connected to the port, or if the sensor is in
-        Wi-Fi mode, or if the sensor failed to read an object.
+        - `ValueError`: If the duration is too large to represent.
+        """
+        ...
 
-        # Examples
+class ProfileState:
+    """
+    The state of a motion profile at one point in time, returned by `TrapezoidProfile.sample` and
+    `SCurveProfile.sample`.
 
-        ```python
-        from venice import *
+    `position`, `velocity` and `acceleration` are read-only `float` attributes, measured relative to
+    the start of the profile in the profile's units.
+    """
 
-        sensor = VisionSensor(1)
+    position: float
+    velocity: float
+    acceleration: float
 
-        async def main():
-        # Set a color signature on the sensor's first slot.
-        sensor.set_signature(1, VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1))
-        while True:
-        object_count = sensor.get_object_count()
-        print(f"Sensor is currently detecting {object_count} objects.")
+class RotationSensor:
+    MIN_DATA_INTERVAL_MS: ClassVar[int]
+    """The minimum data rate that you can set a rotation sensor to, in milliseconds."""
+    TICKS_PER_REVOLUTION: ClassVar[int]
+    """The amount of unique sensor readings per one revolution of the sensor."""
 
-        await vasyncio.Sleep(VisionSensor.UPDATE_INTERVAL_MS, MILLIS)
+    def __init__(self, port: int, direction: Direction = Direction.FORWARD, /) -> None:
+        """
+        Creates a new rotation sensor on the given port.
 
-        vasyncio.run(main)
+        Whether or not the sensor should be reversed on creation can be specified.
+
+        # Examples
+
+        ```
+        from venice import *
+
+        sensor = RotationSensor(1)
         ```
         """
         ...
 
-    def get_brightness(self) -> float:
+    def get_angle(self, unit: RotationUnit) -> float: ...
+    def get_position(self, unit: RotationUnit) -> float:
         """
-        Returns the current brightness setting of the vision sensor as a percentage.
-
-        The returned result should be from `0.0` (0%) to `1.0` (100%).
+        Returns the total accumulated rotation of the sensor over time.
 
         # Raises
 
@@ -7856,56 +9232,49 @@ class VisionSensor:
         ```python
         from venice import *
 
-        sensor = VisionSensor(1)
-
-        async def main():
-        # Set brightness to 50%
-        sensor.set_brightness(0.5)
-
-        # Give the sensor time to update.
-        await vasyncio.Sleep(VisionSensor.UPDATE_INTERVAL_MS, MILLIS)
-
-        # Read brightness. Should be 50%, since we just set it.
-        assert sensor.get_brightness() == 0.5
+        sensor = RotationSensor(1)
+        position = sensor.get_position(DEGREES)
 
-        vasyncio.run(main)
+        print(f"Position in degrees: {position}")
         ```
         """
         ...
 
-    def get_white_balance(self, /) -> WhiteBalance:
+    def wait_until_position(self, position: float, unit: RotationUnit) -> UntilFuture:
         """
-        Returns the current white balance of the vision sensor as an RGB color.
+        Waits until the sensor's position reaches `position`, measured in `unit`.
+
+        The returned `UntilFuture` compares the position when it is awaited against `position`, then
+        completes once the sensor has moved as far as `position` in that direction. Because the
+        position is polled, the sensor may be slightly past `position` by the time the await
+        completes. Awaiting it returns `None`.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If, while awaiting, no device is connected to the port, or if the wrong type
+        of device is connected.
 
         # Examples
 
         ```python
         from venice import *
 
-        async def main():
-        # Set white balance to manual.
-        sensor.set_white_balance(WhiteBalance.Manual(255, 255, 255))
-
-        # Give the sensor time to update.
-        await vasyncio.Sleep(VisionSensor.UPDATE_INTERVAL_MS, MILLIS)
+        lift = Motor(1)
+        sensor = RotationSensor(2)
 
-        # Read white balance. Should be the same as what we just set.
-        white_balance = sensor.get_white_balance()
-        assert white_balance == WhiteBalance.Manual(255, 255, 255)
+        async def main():
+        lift.set_voltage(8)
+        await sensor.wait_until_position(90, DEGREES)
+        lift.brake(BrakeMode.HOLD)
 
-        vasyncio.run(main)
+        vasyncio.run(main())
         ```
         """
         ...
 
-    def set_brightness(self, brightness: float) -> None:
+    def set_position(self, position: float, unit: RotationUnit) -> None:
         """
-        Sets the brightness percentage of the vision sensor. Should be between 0.0 and 1.0.
+        Sets the sensor's position reading.
 
         # Raises
 
@@ -7917,45 +9286,37 @@ class VisionSensor:
         ```python
         from venice import *
 
-        sensor = VisionSensor(1)
+        sensor = RotationSensor(1)
 
-        # Set brightness to 50%
-        sensor.set_brightness(0.5)
+        # Set position to 15 degrees
+        sensor.set_position(15, DEGREES)
         ```
         """
         ...
 
-    def set_white_balance(self, balance: WhiteBalance, /) -> None:
+    def get_velocity(self) -> float:
         """
-        Sets the white balance of the vision sensor.
-
-        White balance can be either automatically set or manually set through an RGB color.
+        Returns the sensor's current velocity in degrees per second.
 
         # Raises
 
         `DeviceError`: If no device is connected to the port, or if the wrong type of device is
         connected.
 
-        # Examples
-
         ```python
         from venice import *
 
-        sensor = VisionSensor(1)
+        sensor = RotationSensor(1)
 
-        # Set white balance to manual.
-        sensor.set_white_balance(WhiteBalance.Manual(255, 255, 255))
+        velocity = sensor.get_velocity()
+        print(f"Velocity in RPM: {velocity / 6}") # 1rpm = 6dps
         ```
         """
         ...
 
-    def set_led_mode(self, mode: LedMode, /) -> None:
+    def reset_position(self) -> None:
         """
-        Configure the behavior of the LED indicator on the sensor.
-
-        The default behavior is represented by `LedMode.Auto`, which will display the color of
-        the most prominent detected object's signature color. Alternatively, the LED can be
-        configured to display a single RGB color.
+        Reset's the sensor's position reading to zero.
 
         # Raises
 
@@ -7967,41 +9328,64 @@ class VisionSensor:
         ```python
         from venice import *
 
-        sensor = VisionSensor(1)
+        sensor = RotationSensor(1)
 
-        # Set the LED to red at 100% brightness.
-        sensor.set_led_mode(LedMode.Manual(255, 0, 0, 1))
+        print(f"Before reset: {sensor.get_position()}")
+        sensor.reset_position()
+        print(f"After reset: {sensor.get_position()}")
         ```
         """
         ...
 
-    def set_mode(self, mode: VisionMode) -> None:
+    def set_direction(self, direction: Direction) -> None:
         """
-        Sets the vision sensor's detection mode. See `VisionMode` for more information on what each
-        mode does.
-
-        # Raises
+        Sets the sensor to operate in a given `Direction`.
+
+        This determines which way the sensor considers to be “forwards”. You can use the marking on
+        the top of the motor as a reference:
+
+        - When `Direction.FORWARD` is specified, positive velocity/voltage values will cause the
+        motor to rotate **with the arrow on the top**. Position will increase as the motor rotates
+        **with the arrow**.
+        - When `Direction.REVERSE` is specified, positive velocity/voltage values will cause the
+        motor to rotate **against the arrow on the top**. Position will increase as the motor
+        rotates **against the arrow**.
+
+        # Raises
 
         `DeviceError`: If no device is connected to the port, or if the wrong type of device is
         connected.
 
         # Examples
 
+        Set the sensor's direction to `Direction.Reverse`.
+
         ```python
         from venice import *
 
-        sensor = VisionSensor(1)
+        sensor = Rotation(1)
 
-        # Place the sensor into "Wi-Fi mode," allowing you to connect to it via a hotspot and
-        # receive a video stream of its camera from another device.
-        sensor.set_mode(VisionMode.WIFI)
+        # Reverse the sensor
+        sensor.set_direction(Direction.REVERSE)
+        ```
+
+        Reverse the sensor's direction (set to opposite of the previous direction):
+
+        ```python
+        from venice import *
+
+        sensor = RotationSensor(1)
+
+        # Reverse the sensor
+        sensor.set_direction(~sensor.get_direction())
         ```
         """
         ...
 
-    def get_mode(self, /) -> VisionMode:
+    def get_direction(self, /) -> Direction: ...
+    def get_status(self) -> int:
         """
-        Returns the current detection mode that the sensor is using.
+        Returns the sensor's internal status code.
 
         # Raises
 
@@ -8013,20 +9397,38 @@ class VisionSensor:
         ```python
         from venice import *
 
-        sensor = VisionSensor(1)
+        sensor = RotationSensor(1)
+        status = sensor.get_status()
+        print(f"Status: 0x{status:08x}")
+        ```
+        """
+        ...
 
-        async def main():
-        # Place the sensor into "Wi-Fi mode," allowing you to connect to it via a hotspot and
-        # receive a video stream of its camera from another device.
-        sensor.set_mode(VisionMode.WIFI)
+    def set_data_interval(self, interval: float, unit: TimeUnit) -> None:
+        """
+        Sets the internal computation speed of the rotation sensor.
 
-        await vasyncio.Sleep(VisionSensor.UPDATE_INTERVAL_MS, MILLIS)
+        This method does NOT change the rate at which user code can read data off the sensor, as the
+        brain will only talk to the device every 10mS regardless of how fast data is being sent or
+        computed.
 
-        # Since we just set the mode, we can the mode off the sensor to verify that it's now in
-        # Wi-Fi mode.
-        assert sensor.get_mode() == VisionMode.WIFI
+        This duration should be above `Self.MIN_DATA_INTERVAL` (5 milliseconds).
 
-        vasyncio.run(main)
+        # Raises
+
+        - `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+        - `ValueError`: If `interval` is negative, non-finite, or too large to represent.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = RotationSensor(1)
+
+        # Set to minimum interval.
+        sensor.set_data_interval(RotationSensor.MIN_DATA_INTERVAL)
         ```
         """
         ...
@@ -8044,722 +9446,3900 @@ class VisionSensor:
 
         # Examples
 
-        Construct a `Motor`, free it, then construct a `RotationSensor` with the same Smart Port:
+        Construct a `RotationSensor`, free it, then construct a `Motor` with the same Smart Port:
 
         ```python
         from venice import *
 
+        sensor = RotationSensor(1)
+        sensor.free()
+        # `sensor` is now unusable, but port 1 can be used in another device, such as a `Motor`
         motor = Motor(1)
-        motor.free()
-        # `motor` is now unusable, but port 1 can be used in another device, such as a `RotationSensor`
-        rotation_sensor = RotationSensor(1)
         ```
         """
         ...
 
-class VisionMode:
+class SerialPort:
     """
-    A possible "detection mode" for the Vision Sensor.
+    A Smart Port configured as a generic RS-485 serial port.
 
-    This class is root-importable. Pass one of its singleton values to `VisionSensor.set_mode`; the
-    current value is returned by `VisionSensor.get_mode`. `VisionMode` isn't constructed directly.
-    Values have readable representations such as `VisionMode.COLOR_DETECTION`.
-    """
+    This class provides an interface for using V5 Smart Ports as serial communication ports over
+    RS-485. It allows bidirectional communication with any device that speaks serial over the V5's
+    RS-485 interface.
 
-    COLOR_DETECTION: ClassVar[VisionMode]
-    """Uses color signatures and codes to identify objects in blocks."""
-    LINE_DETECTION: ClassVar[VisionMode]
-    """Uses line tracking to identify lines."""
-    MIXED_DETECTION: ClassVar[VisionMode]
-    """Both color signatures and lines will be detected as objects."""
-    WIFI: ClassVar[VisionMode]
-    """
-    Sets the sensor into "Wi-Fi mode", which disables all forms of object detection and enables the
-    sensor's onboard Wi-Fi hotspot for streaming camera data over a web server.
+    # Hardware Description
 
-    Once enabled, the sensor will create a wireless network with an SSID in the format of `VISION_XXXX`.
-    The sensor's camera feed is available at `192.168.1.1`.
+    V5 Smart Ports provide half-duplex RS-485 serial communication at up to an allowed 921600 baud for
+    user programs.
 
-    This mode will be automatically disabled when connected to field control.
-    """
-    TEST: ClassVar[VisionMode]
-    """Unknown use."""
+    The ports supply 12.8V VCC nominally (VCC is wired directly to the V5's battery lines, providing
+    voltage somewhere in the range of 12-14V). Writes to the serial port are buffered, but are
+    automatically flushed by VEXos as fast as possible (down to ~10µs or so).
 
-class VisionObject:
+    Open a port with `await SerialPort.open(...)`; `SerialPort` cannot be constructed directly. The
+    object implements the MicroPython stream methods `read`, `read1`, `write`, `write1`, `flush`, and
+    `ioctl`. Reads consume the bytes currently available rather than waiting for the requested
+    amount and return `bytes`. After `free` releases the Smart Port, stream and device operations
+    raise `ValueError`.
     """
-    A detected vision object.
-
-    This root-importable class contains metadata about objects detected by the Vision Sensor.
-    Instances are returned by `VisionSensor.get_objects` after adding signatures and color codes to
-    the sensor, and cannot be constructed directly. All attributes are read-only.
-
-    - `source` is the signature or color code used to detect this object.
-    - `width` is the width of the detected object's bounding box in pixels.
-    - `height` is the height of the detected object's bounding box in pixels.
-    - `offset_x` and `offset_y` are the top-left coordinate of the detected object relative to the
-    top-left of the camera's field of view.
-    - `center_x` and `center_y` are the center coordinate of the detected object relative to the
-    top-left of the camera's field of view.
 
-    The readable representation summarizes the source and all six pixel measurements.
-    """
+    INTERNAL_BUFFER_SIZE: ClassVar[int]
+    """The length of the serial FIFO input and output buffers, in bytes. Its value is 1024."""
+    MAX_BAUD_RATE: ClassVar[int]
+    """The maximum user-configurable baud rate for generic serial under normal conditions. Its value is 921600."""
 
-    source: DetectionSource
-    width: int
-    height: int
-    offset_x: int
-    offset_y: int
-    center_x: int
-    center_y: int
-    def get_angle(self, unit: RotationUnit) -> float:
+    @staticmethod
+    def open(port_number: int, baud_rate: int, /) -> SerialPortOpenFuture:
         """
-        Returns the approximate rotation of the detected object's bounding box in `unit`.
+        Opens and configures a generic serial port on Smart Port `port_number`.
 
-        The underlying sensor reports tenths of a degree, which Venice converts to `DEGREES`, `RADIANS`, or
-        `TURNS`.
+        This configures a Smart Port to act as a generic serial controller capable of sending and receiving
+        data. Providing `baud_rate`, or the transmission rate of bits, is required. It must be from
+        1 through `SerialPort.MAX_BAUD_RATE` (921600). Await the returned `SerialPortOpenFuture` to
+        obtain the usable port.
 
         # Examples
 
         ```python
         from venice import *
 
-        sensor = VisionSensor(1)
-        for object in sensor.get_objects():
-        print(object.get_angle(DEGREES))
-        ```
-        """
-        ...
-
-class VisionSignature:
-    """
-    A vision detection color signature.
-
-    This class is root-importable. Vision signatures contain information used by the Vision Sensor to detect objects of a certain
-    color. These signatures are typically generated through VEX's Vision Utility tool rather than
-    written by hand.
-
-    # Format & Detection Overview
+        async def main():
+        serial = await SerialPort.open(1, 115200)
 
-    Vision signatures operate in a version of the Y'UV color space, specifically using the "U" and
-    "V" chroma components for edge detection purposes. The read-only `u_min`, `u_max`, and `u_mean`
-    attributes place three threshold values on the U chroma values detected by the sensor. The
-    read-only `v_min`, `v_max`, and `v_mean` attributes do the same for the V component. These values
-    are then transformed to a 3D lookup table to detect actual colors.
+        vasyncio.run(main())
+        ```
 
-    The read-only `range` attribute works as a scale factor or threshold for how lenient edge detection
-    should be. It ranges from 0-11 in Vision Utility. Higher values increase the range of brightness
-    that the sensor considers part of the signature, so lighter and darker shades are detected more
-    often. The read-only `flags` attribute is the signature's flags and is initialized to 0.
+        # Raises
 
-    Signatures can additionally be grouped together into `VisionCode` objects, which narrow the filter
-    for object detection by requiring two colors. Signatures compare equal when all thresholds,
-    `range`, and `flags` match, return `False` when compared with another type, and have a readable
-    representation containing those eight values.
-    """
+        - `ValueError`: If `port_number` is outside 1 through 21, its Smart Port is occupied, or
+        `baud_rate` is outside 1 through `SerialPort.MAX_BAUD_RATE`.
+        - `TypeError`: If either argument is not an integer.
+        """
+        ...
 
-    u_min: int
-    u_max: int
-    u_mean: int
-    v_min: int
-    v_max: int
-    v_mean: int
-    range: float
-    flags: int
-    def __init__(
-        self,
-        u_min: int,
-        u_max: int,
-        u_mean: int,
-        v_min: int,
-        v_max: int,
-        v_mean: int,
-        range: float,
-        /,
-    ) -> None:
+    def set_baud_rate(self, baud_rate: int) -> None:
         """
-        Creates a `VisionSignature`.
+        Configures the baud rate of the serial port.
 
-        `u_min`, `u_max`, and `u_mean` are the minimum, maximum, and mean values on the U axis. `v_min`,
-        `v_max`, and `v_mean` are the corresponding values on the V axis. `range` is the detection range
-        scale factor. All seven arguments are positional-only; `flags` is set to 0.
+        Baud rate determines the speed of communication over the data channel. It must be from 1
+        through `SerialPort.MAX_BAUD_RATE` (921600).
 
         # Examples
 
         ```python
         from venice import *
 
-        my_signature = VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1)
+        async def main():
+        serial = await SerialPort.open(1, 115200)
+
+        # Change to 9600 baud.
+        serial.set_baud_rate(9600)
+
+        vasyncio.run(main())
         ```
 
         # Raises
 
-        - `TypeError`: If a threshold is not an integer, `range` is not numeric, a keyword argument is
-        supplied, or the argument count is not exactly seven.
+        - `TypeError`: If `baud_rate` is not an integer.
+        - `ValueError`: If `baud_rate` is outside 1 through `SerialPort.MAX_BAUD_RATE`, or the port
+        has been freed.
         """
         ...
 
-class DetectionSource:
-    """
-    The detection method used to identify a `VisionObject`.
+    def clear_buffers(self) -> None:
+        """
+        Clears the internal input and output FIFO buffers.
 
-    `DetectionSource` is an abstract base and cannot be instantiated directly. Construct its
-    associated-only variants as `DetectionSource.Signature(...)`, `DetectionSource.Code(...)`, or
-    `DetectionSource.Line()`. The variant classes are not package-root imports.
-    """
+        This can be useful to reset state and remove old, potentially unneeded data from the input FIFO
+        buffer or to cancel sending any data in the output FIFO buffer.
 
-    Signature: ClassVar[type[Signature]]
-    """The associated class for a normal Vision signature source, constructed as `DetectionSource.Signature(id)`."""
-    Code: ClassVar[type[Code]]
-    """The associated class for multiple signatures joined in a color code, constructed as `DetectionSource.Code(code)`."""
-    Line: ClassVar[type[Line]]
-    """The associated class for line detection, constructed as `DetectionSource.Line()`."""
+        # This is not the same thing as "flushing".
 
-    def __init__(self, /) -> None:
+        This method does not cause the data in the output buffer to be written. It simply clears the
+        internal buffers. Unlike standard output, generic serial does not use buffered I/O (the FIFO
+        buffers are written as soon as possible).
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        async def main():
+        serial = await SerialPort.open(1, 115200)
+
+        serial.clear_buffers()
+        serial.write(b"Buffers are clear!")
+
+        vasyncio.run(main())
+        ```
+
+        # Raises
+
+        - `ValueError`: If the port has been freed.
         """
-        Rejects direct construction of the abstract `DetectionSource` base class.
+        ...
+
+    def free(self) -> None:
+        """
+        Releases this binding's Smart Port lock and makes the object unusable.
+
+        The port number can then be used to construct another device.
+
+        # Raises
+
+        - `ValueError`: If the port has already been freed.
+        """
+        ...
+
+    def read(self, size: int = -1, /) -> bytes:
+        """
+        Reads up to `size` currently available bytes from the serial port's FIFO input buffer.
+
+        `size=-1`, the default, drains all data currently available. A nonnegative `size` limits the result;
+        values below -1 are invalid MicroPython stream sizes. An empty FIFO returns `b""` without
+        waiting.
+
+        # Raises
+
+        - `TypeError`: If `size` is not an integer.
+        - `MemoryError`: If `size` is below -1 or is too large to allocate the result.
+        - `OSError`: If the serial read fails.
+        - `ValueError`: If the port has been freed.
+        """
+        ...
+
+    def read1(self, size: int = -1, /) -> bytes:
+        """
+        Reads up to `size` currently available bytes from the serial port's FIFO input buffer with
+        single-read semantics.
+
+        For a nonnegative `size`, MicroPython performs at most one low-level read. `size=-1`, the default,
+        instead drains all data currently available. An empty FIFO returns `b""`.
+
+        # Raises
+
+        - `TypeError`: If `size` is not an integer.
+        - `MemoryError`: If `size` is below -1 or is too large to allocate the result.
+        - `OSError`: If the serial read fails.
+        - `ValueError`: If the port has been freed.
+        """
+        ...
+
+    def write(self, buffer: bytes | bytearray | memoryview, /) -> int:
+        """
+        Writes `buffer` to the serial port's FIFO output buffer and returns the number of bytes accepted.
+
+        `buffer` must provide a readable buffer, such as `bytes`, `bytearray`, or `memoryview`. MicroPython
+        repeats low-level writes while progress is made, so this method attempts to enqueue the complete
+        buffer.
+
+        # Raises
+
+        - `TypeError`: If `buffer` does not support the readable buffer protocol.
+        - `OSError`: If the serial write fails.
+        - `ValueError`: If the port has been freed.
+        """
+        ...
+
+    def write1(self, buffer: bytes | bytearray | memoryview, /) -> int:
+        """
+        Performs one low-level write from `buffer` to the serial port's FIFO output buffer and returns the
+        number of bytes accepted.
+
+        This can return fewer bytes than the length of `buffer` when the output FIFO lacks space. `buffer`
+        must be `bytes`, `bytearray`, `memoryview`, or another readable buffer object.
+
+        # Raises
+
+        - `TypeError`: If `buffer` does not support the readable buffer protocol.
+        - `OSError`: If the serial write fails.
+        - `ValueError`: If the port has been freed.
+        """
+        ...
+
+    def flush(self, /) -> None:
+        """
+        Completes the MicroPython flush operation and returns `None`.
+
+        The underlying generic-serial flush is a no-op because VEXos already transmits queued output as
+        quickly as possible. This method does not call `SerialPort.clear_buffers`.
+
+        # Raises
+
+        - `ValueError`: If the port has been freed.
+        """
+        ...
+
+    def ioctl(self, request: int, arg: int = 0, /) -> int:
+        """
+        Performs a low-level MicroPython stream control `request` with integer `arg`, which defaults
+        to `0`.
+
+        Request `1` flushes the stream. Request `3` polls the flags in `arg`: readable is `0x01`, writable
+        is `0x04`, and an I/O error is returned as `0x08`. Other requests are unsupported.
+
+        # Raises
+
+        - `TypeError`: If `request` or `arg` is not an integer.
+        - `OSError`: If `request` is unsupported.
+        - `ValueError`: If the port has been freed.
+        """
+        ...
+
+class SerialPortOpenFuture:
+    """
+    An awaitable that opens and configures a `SerialPort`.
+
+    If the port was not previously configured as a generic serial port, this may take a few milliseconds
+    to complete. Awaiting this object returns the opened `SerialPort`. Users receive it from
+    `SerialPort.open`; it cannot be constructed directly. Awaiting the same instance more than once
+    raises `RuntimeError`.
+    """
+
+class Trajectory:
+    """
+    A smooth path through a list of poses, timed so that a differential drive can follow it.
+
+    Each pair of waypoints in a row is joined by a curve that leaves the first along its heading
+    and arrives at the second along its heading, so the headings shape the path. The robot starts
+    and ends at rest, and moves along the curves as fast as it can while keeping both wheels under
+    `max_velocity`, which makes it slow down for tight curves, and never speeding up or slowing
+    down faster than `max_acceleration`. `track_width` is the distance between the left and right
+    wheels.
+
+    Distances are measured in the waypoints' unit, velocities in that unit per second, and
+    accelerations in that unit per second squared. Headings should point along the direction of
+    travel, since a trajectory only drives forwards.
+
+    Pass a trajectory to `Ramsete.follow` to drive along it on schedule, or to `PurePursuit.follow`
+    to follow its shape.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    trajectory = Trajectory(
+    [Pose2(0, 0, 0, DEGREES), Pose2(48, 24, 90, DEGREES), Pose2(24, 60, 180, DEGREES)],
+    12.0,
+    50.0,
+    80.0,
+    )
+    print(trajectory.duration(SECOND))
+    state = trajectory.sample(1.0)
+    print(state.pose, state.velocity)
+    ```
+    """
+    def __init__(
+        self,
+        waypoints: list[Pose2],
+        track_width: float,
+        max_velocity: float,
+        max_acceleration: float,
+        /,
+    ) -> None:
+        """
+        Plans a trajectory through `waypoints`.
+
+        # Raises
+
+        - `TypeError`: If `waypoints` isn't a list or tuple of `Pose2`, or a limit isn't numeric.
+        - `ValueError`: If there are fewer than two waypoints, if two waypoints in a row share a
+        position, or if a limit isn't positive and finite.
+        """
+        ...
+
+    def sample(self, t: float, unit: TimeUnit = TimeUnit.SECOND, /) -> TrajectoryState:
+        """
+        Returns the state of the trajectory `t` after it starts, measured in `unit`.
+
+        Times before the start or after the end of the trajectory return the state at the first or
+        last waypoint, where the robot is at rest.
+
+        # Raises
+
+        - `ValueError`: If `t` isn't finite, or is too large to represent.
+        """
+        ...
+
+    def duration(self, unit: TimeUnit = TimeUnit.SECOND, /) -> float:
+        """
+        Returns the time taken to follow the trajectory, measured in `unit`.
+
+        # Raises
+
+        - `ValueError`: If the duration is too large to represent.
+        """
+        ...
+
+    def length(self) -> float:
+        """Returns the length of the trajectory."""
+        ...
+
+class TrajectoryState:
+    """
+    The state of a `Trajectory` at one point in time, returned by `Trajectory.sample`.
+
+    `time` is in seconds since the start. `pose` is the `Pose2` the robot should be at, and
+    `distance` is how far along the trajectory it is. `velocity` and `acceleration` are measured
+    along the path, `curvature` is in radians per unit travelled and `angular_velocity` in radians
+    per second, both positive when turning counterclockwise. All are read-only attributes.
+    """
+
+    time: float
+    distance: float
+    pose: Pose2
+    velocity: float
+    acceleration: float
+    curvature: float
+    angular_velocity: float
+
+class Row:
+    """
+    Widgets placed side by side from left to right, available as `ui.Row`.
+
+    Each child gets a share of the row's width in proportion to its weight, after `padding` is
+    taken from every edge and `spacing` is left between neighbours.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    status = ui.Label("Ready")
+    start = ui.Button("Start")
+    screen = ui.Screen(ui.Row([status, start], weights=[2, 1], padding=8))
+    ```
+    """
+    def __init__(
+        self,
+        children: list[Widget] | tuple[Widget, ...],
+        /,
+        *,
+        weights: list[float] | tuple[float, ...] | None = None,
+        spacing: int = 6,
+        padding: int = 0,
+    ) -> None:
+        """
+        Creates a row of `children`, sized according to `weights`, or equally if it is `None`.
+
+        # Raises
+
+        - `TypeError`: If `children` isn't a list or tuple of widgets, or a `Dialog` is in it.
+        - `ValueError`: If there isn't one weight per child, a weight is negative or non-finite,
+        every weight is zero, or `spacing` or `padding` is negative.
+        """
+        ...
+
+class Column:
+    """
+    Widgets stacked from top to bottom, available as `ui.Column`.
+
+    Each child gets a share of the column's height in proportion to its weight, after `padding`
+    is taken from every edge and `spacing` is left between neighbours.
+    """
+    def __init__(
+        self,
+        children: list[Widget] | tuple[Widget, ...],
+        /,
+        *,
+        weights: list[float] | tuple[float, ...] | None = None,
+        spacing: int = 6,
+        padding: int = 0,
+    ) -> None:
+        """
+        Creates a column of `children`, sized according to `weights`, or equally if it is `None`.
+
+        # Raises
+
+        - `TypeError`: If `children` isn't a list or tuple of widgets, or a `Dialog` is in it.
+        - `ValueError`: If there isn't one weight per child, a weight is negative or non-finite,
+        every weight is zero, or `spacing` or `padding` is negative.
+        """
+        ...
+
+class TabView:
+    """
+    Pages of widgets with a bar of tabs to switch between them, available as `ui.TabView`.
+
+    Only the selected page is shown and receives touches. Tapping a tab selects its page and
+    raises an event with its index. The read-only `index` attribute is the index of the selected
+    page.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    tabs = ui.TabView([
+    ("Drive", ui.Label("Battery OK")),
+    ("Auton", ui.List(["Left", "Right", "Skills"])),
+    ])
+    screen = ui.Screen(tabs)
+    ```
+    """
+
+    index: int
+    def __init__(
+        self,
+        tabs: list[tuple[str, Widget]] | tuple[tuple[str, Widget], ...],
+        /,
+        *,
+        index: int = 0,
+        on_change: Callable[[int], Any] | None = None,
+    ) -> None:
+        """
+        Creates a tab view from `(title, widget)` pairs, with the page at `index` selected.
+
+        `on_change` is called with a page's index each time its tab is tapped. If it is an
+        `async` function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If `tabs` isn't a list or tuple of `(str, Widget)` tuples, a `Dialog` is in
+        it, or `on_change` isn't callable.
+        - `ValueError`: If `tabs` is empty, a title contains a NUL character, or `index` is out of
+        range.
+        """
+        ...
+
+    def select(self, index: int) -> None:
+        """
+        Shows the page at `index` without raising an event.
+
+        # Raises
+
+        - `ValueError`: If `index` is out of range.
+        """
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """
+        Returns a `WidgetFuture` that completes with a page's index the next time its tab is
+        tapped.
+        """
+        ...
+
+class Dialog:
+    """
+    A modal message with a row of buttons, available as `ui.Dialog`.
+
+    A dialog is shown over everything else with `Screen.open`, and while it is open, nothing
+    else on the screen can be touched. Tapping one of its buttons closes it and raises an event
+    with the button's index. The read-only `is_open` attribute is whether it is being shown.
+
+    The message is drawn on a single line, so it should be short.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    root = ui.Label("Driver control")
+    screen = ui.Screen(root)
+    confirm = ui.Dialog("Reset odometry?", "Put the robot in its corner.", buttons=["No", "Yes"])
+
+    async def main():
+    vasyncio.spawn(screen.run())
+    screen.open(confirm)
+    if await confirm.wait() == 1:
+    print("resetting")
+
+    vasyncio.run(main())
+    ```
+    """
+
+    is_open: bool
+    def __init__(
+        self,
+        title: str,
+        message: str,
+        /,
+        *,
+        buttons: list[str] | tuple[str, ...] = ["OK"],
+        on_choose: Callable[[int], Any] | None = None,
+    ) -> None:
+        """
+        Creates a dialog showing `title` and `message` above a row of `buttons`.
+
+        The dialog isn't shown until it is passed to `Screen.open`. `on_choose` is called with a
+        button's index each time the dialog is closed by tapping one. If it is an `async`
+        function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If `title`, `message` or a button's text isn't a string, or `on_choose`
+        isn't callable.
+        - `ValueError`: If a string contains a NUL character, or `buttons` is empty.
+        """
+        ...
+
+    def close(self) -> None:
+        """
+        Closes the dialog without raising an event.
+
+        The screen behind it is redrawn at the next update.
+        """
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """
+        Returns a `WidgetFuture` that completes with a button's index the next time the dialog is
+        closed by tapping one.
+        """
+        ...
+
+class Screen:
+    """
+    A tree of widgets shown on the display, available as `ui.Screen`.
+
+    The screen reads the touchscreen and redraws whatever has changed each time it is updated,
+    either by calling `update` in a loop or by awaiting the future returned by `run` in a task of
+    its own. Drawing is double buffered, so a screen should be the only thing drawing to the
+    display while it is in use.
+
+    Widgets raise events when they are used. Each event calls the widget's callback, if it has
+    one, and completes any `WidgetFuture` waiting on it.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    count = 0
+    label = ui.Label("Pressed 0 times", centered=True)
+
+    def pressed(_):
+    global count
+    count += 1
+    label.set_text("Pressed " + str(count) + " times")
+
+    screen = ui.Screen(ui.Column([label, ui.Button("Press me", on_press=pressed)], padding=8))
+    vasyncio.run(screen.run())
+    ```
+    """
+    def __init__(self, root: Widget, /) -> None:
+        """
+        Creates a screen showing `root`, which fills the whole display.
+
+        Nothing is drawn until the screen is first updated.
+
+        # Raises
+
+        - `TypeError`: If `root` isn't a widget, or is a `Dialog`.
+        """
+        ...
+
+    def update(self) -> None:
+        """
+        Reads the touchscreen and redraws whatever has changed since the last update.
+
+        If a touch raised an event, its callback is called once drawing is done. Callbacks that
+        are `async` functions are spawned as tasks, so they need a running event loop.
+
+        # Raises
+
+        - `RuntimeError`: If an `async` callback is called outside of an event loop.
+
+        Any exception raised by a callback propagates out of the update.
+        """
+        ...
+
+    def run(
+        self, period: float = 20.0, unit: TimeUnit = TimeUnit.MILLIS, /
+    ) -> ScreenFuture:
+        """
+        Returns a `ScreenFuture` that updates the screen every `period`, measured in `unit`, until
+        it is cancelled.
+
+        Await it from a task of its own, spawned with `vasyncio.spawn`.
+
+        # Raises
+
+        - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+        """
+        ...
+
+    def open(self, dialog: Dialog, /) -> None:
+        """
+        Shows `dialog` in the middle of the screen, on top of everything else.
+
+        Until it is closed, touches only reach the dialog. A dialog that is already open is
+        closed first.
+
+        # Raises
+
+        - `TypeError`: If `dialog` isn't a `Dialog`.
+        """
+        ...
+
+    def close(self) -> None:
+        """Closes the open dialog without raising an event, if there is one."""
+        ...
+
+    def redraw(self) -> None:
+        """
+        Lays out and draws every widget again at the next update.
+
+        Call this after drawing to the display with anything other than the screen.
+        """
+        ...
+
+class ScreenFuture:
+    """
+    An awaitable that updates a `Screen` on a fixed schedule until it is cancelled, returned by
+    `Screen.run`.
+
+    The first update happens as soon as the future is awaited and the rest follow once per period.
+    Like `vasyncio.Ticker`, updates are scheduled from the first one, and periods that are missed
+    because other tasks ran too long are skipped.
+    """
+
+class WidgetFuture:
+    """
+    An awaitable that completes with the value of a widget's next event, returned by the `wait`
+    method of each widget.
+
+    Events are raised by `Screen.update`, so the widget's screen must be updated, for example by
+    another task awaiting `Screen.run`, for the future to complete.
+    """
+
+class Label:
+    """
+    A line of text, available as `ui.Label`.
+
+    The read-only `text` attribute is the text being shown. Text longer than the label's width
+    isn't wrapped or cut off.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    status = ui.Label("Waiting for sensors", centered=True)
+    screen = ui.Screen(status)
+    screen.update()
+    ```
+    """
+
+    text: str
+    def __init__(
+        self, text: str, /, *, color: Color = Color.WHITE, centered: bool = False
+    ) -> None:
+        """
+        Creates a label showing `text` in `color`.
+
+        Text is left-aligned unless `centered` is `True`.
+
+        # Raises
+
+        - `TypeError`: If `text` isn't a string or `color` isn't a `Color`.
+        - `ValueError`: If `text` contains a NUL character.
+        """
+        ...
+
+    def set_text(self, text: str, /) -> None:
+        """
+        Changes the label's text.
+
+        # Raises
+
+        - `TypeError`: If `text` isn't a string.
+        - `ValueError`: If `text` contains a NUL character.
+        """
+        ...
+
+class Button:
+    """
+    A button that raises an event when tapped, available as `ui.Button`.
+
+    A tap counts once the finger is lifted, and only if it is lifted over the button, so sliding
+    off a button cancels it. The read-only `text` attribute is the button's text and
+    `is_pressed` is whether it is being held down.
+    """
+
+    text: str
+    is_pressed: bool
+    def __init__(
+        self, text: str, /, *, on_press: Callable[[None], Any] | None = None
+    ) -> None:
+        """
+        Creates a button showing `text`.
+
+        `on_press` is called with `None` each time the button is tapped. If it is an `async`
+        function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If `text` isn't a string or `on_press` isn't callable.
+        - `ValueError`: If `text` contains a NUL character.
+        """
+        ...
+
+    def set_text(self, text: str, /) -> None:
+        """
+        Changes the button's text.
+
+        # Raises
+
+        - `TypeError`: If `text` isn't a string.
+        - `ValueError`: If `text` contains a NUL character.
+        """
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """Returns a `WidgetFuture` that completes with `None` the next time the button is tapped."""
+        ...
+
+class Toggle:
+    """
+    An on/off switch with a label, available as `ui.Toggle`.
+
+    Tapping the toggle flips its value and raises an event with the new value. The read-only
+    `text` attribute is the toggle's label and `value` is whether it is on.
+    """
+
+    text: str
+    value: bool
+    def __init__(
+        self,
+        text: str,
+        /,
+        *,
+        value: bool = False,
+        on_change: Callable[[bool], Any] | None = None,
+    ) -> None:
+        """
+        Creates a toggle labeled `text`, which starts on if `value` is `True`.
+
+        `on_change` is called with the new value each time the toggle is tapped. If it is an
+        `async` function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If `text` isn't a string or `on_change` isn't callable.
+        - `ValueError`: If `text` contains a NUL character.
+        """
+        ...
+
+    def set_value(self, value: bool) -> None:
+        """Turns the toggle on or off without raising an event."""
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """
+        Returns a `WidgetFuture` that completes with the toggle's new value the next time it is
+        tapped.
+        """
+        ...
+
+class Slider:
+    """
+    A horizontal slider for picking a number in a range, available as `ui.Slider`.
+
+    The knob follows the finger while it is dragged, and an event with the new value is raised
+    when the finger is lifted. The read-only `value`, `min` and `max` attributes are the current
+    value and the ends of the range.
+    """
+
+    value: float
+    min: float
+    max: float
+    def __init__(
+        self,
+        min: float = 0.0,
+        max: float = 1.0,
+        /,
+        *,
+        value: float | None = None,
+        step: float | None = None,
+        on_change: Callable[[float], Any] | None = None,
+    ) -> None:
+        """
+        Creates a slider from `min` to `max`, starting at `value`, or at `min` if `value` is
+        `None`.
+
+        If `step` is given, the value is rounded to the nearest whole number of steps from `min`.
+        `on_change` is called with the new value each time the knob is let go. If it is an `async`
+        function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If a number isn't numeric or `on_change` isn't callable.
+        - `ValueError`: If `min` or `max` isn't finite, `min` isn't less than `max`, `step` isn't
+        positive and finite, or `value` is outside the range.
+        """
+        ...
+
+    def set_value(self, value: float) -> None:
+        """
+        Moves the knob to `value`, clamped to the range and rounded to a whole number of steps,
+        without raising an event.
+
+        # Raises
+
+        - `ValueError`: If `value` is NaN.
+        """
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """
+        Returns a `WidgetFuture` that completes with the slider's new value the next time the knob
+        is let go.
+        """
+        ...
+
+class List:
+    """
+    A scrolling list of text items, one of which can be selected, available as `ui.List`.
+
+    Tapping an item selects it and raises an event with its index. Dragging up or down scrolls
+    through items that don't fit. The read-only `selected` attribute is the index of the selected
+    item, or `None`.
+
+    # Examples
+
+    Pick an autonomous routine before the match:
+
+    ```python
+    from venice import *
+
+    routines = ["Left side", "Right side", "Skills"]
+    picker = ui.List(routines, selected=0)
+    screen = ui.Screen(picker)
+
+    async def main():
+    vasyncio.spawn(screen.run())
+    while True:
+    index = await picker.wait()
+    print("selected", routines[index])
+
+    vasyncio.run(main())
+    ```
+    """
+
+    selected: int | None
+    def __init__(
+        self,
+        items: list[str] | tuple[str, ...],
+        /,
+        *,
+        selected: int | None = None,
+        on_change: Callable[[int], Any] | None = None,
+    ) -> None:
+        """
+        Creates a list showing `items`, with the item at index `selected` selected.
+
+        `on_change` is called with an item's index each time it is tapped. If it is an `async`
+        function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If `items` isn't a list or tuple of strings, `selected` isn't an int or
+        `None`, or `on_change` isn't callable.
+        - `ValueError`: If an item contains a NUL character or `selected` is out of range.
+        """
+        ...
+
+    def set_items(self, items: list[str] | tuple[str, ...], /) -> None:
+        """
+        Replaces the list's items, clearing the selection and scrolling back to the top.
+
+        # Raises
+
+        - `TypeError`: If `items` isn't a list or tuple of strings.
+        - `ValueError`: If an item contains a NUL character.
+        """
+        ...
+
+    def select(self, index: int | None, /) -> None:
+        """
+        Selects the item at `index`, scrolling to it, or clears the selection if `index` is
+        `None`, without raising an event.
+
+        # Raises
+
+        - `TypeError`: If `index` isn't an int or `None`.
+        - `ValueError`: If `index` is out of range.
+        """
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """Returns a `WidgetFuture` that completes with an item's index the next time one is tapped."""
+        ...
+
+class RotationUnit:
+    """
+    A unit selector for angular values.
+
+    Venice APIs accept a numeric angle together with one of these singleton values and return numeric
+    angles in the selected unit. Angles are signed displacements from some rotation representing zero;
+    they are unbounded and are not automatically made modular. One turn equals 360 degrees or `2 * pi`
+    radians.
+
+    This class is not constructed directly. Use `RotationUnit.RADIANS`, `RotationUnit.DEGREES`, or
+    `RotationUnit.TURNS`, which are also exported at the package root as `RADIANS`, `DEGREES`, and
+    `TURNS`. Values have readable representations such as `RotationUnit.DEGREES`.
+    """
+
+    RADIANS: ClassVar[RotationUnit]
+    """Selects the number of radians rotated; also root-importable as `RADIANS`."""
+    DEGREES: ClassVar[RotationUnit]
+    """Selects the number of degrees rotated; also root-importable as `DEGREES`."""
+    TURNS: ClassVar[RotationUnit]
+    """Selects the number of turns (revolutions) rotated; also root-importable as `TURNS`."""
+
+class TimeUnit:
+    """
+    A unit selector for time intervals and durations.
+
+    Venice time APIs interpret or return numeric values in the selected unit. One second equals 1,000
+    milliseconds.
+
+    This class is not constructed directly. Use `TimeUnit.MILLIS` or `TimeUnit.SECOND`, which are also
+    exported at the package root as `MILLIS` and the singular `SECOND`. Values have readable
+    representations such as `TimeUnit.SECOND`.
+    """
+
+    MILLIS: ClassVar[TimeUnit]
+    """Selects milliseconds; also root-importable as `MILLIS`."""
+    SECOND: ClassVar[TimeUnit]
+    """Selects seconds; also root-importable as the singular name `SECOND`."""
+
+class EventLoop:
+    """
+    A cooperative scheduler for Venice coroutine tasks and timed sleeps.
+
+    A *task* is a lightweight, non-blocking unit of execution. Tasks allow you to cooperatively perform
+    work in the background without blocking other code from running.
+
+    - Tasks are **lightweight**. Because tasks are scheduled and managed by Venice, creating new tasks
+    or switching between tasks does not require a context switch and has fairly low overhead.
+    Creating, running, and destroying large numbers of tasks is relatively cheap in comparison to
+    traditional threads.
+    - Tasks are scheduled **cooperatively**. A task will run until it voluntarily yields using an
+    `await` point, giving control back to the event loop. The loop then switches to executing a
+    different task.
+    - Tasks are **non-blocking**. When a task cannot continue executing, it should yield, allowing the
+    event loop to schedule another task in its place. Tasks should not perform operations that could
+    block the CPU for a long period without an `await` point, because this prevents other tasks from
+    executing as well. This includes long-running tight loops without `await` points.
+
+    The loop runs one ready task at a time. Users normally call `vasyncio.run` instead of managing an
+    event loop directly.
+    """
+    def __init__(self, /) -> None:
+        """
+        Creates an empty event loop.
+
+        # Raises
+
+        - `TypeError`: If any positional or keyword arguments are supplied.
+        """
+        ...
+
+    def spawn(self, coro: Any, /) -> Task:
+        """
+        Schedules coroutine object `coro` on this loop and returns an awaitable `Task`.
+
+        Scheduling does not run the coroutine until the loop is running. Awaiting the returned task
+        yields its return value whether the task is still running or has already finished.
+
+        # Raises
+
+        - `TypeError`: If `coro` is not a coroutine object.
+        """
+        ...
+
+    def run(self, /) -> None:
+        """
+        Runs scheduled tasks until no ready tasks or pending sleeps remain.
+
+        While this method is running, `vasyncio.get_running_loop` returns this loop and
+        `vasyncio.spawn` adds tasks to it. An exception raised by a task that nothing observes stops
+        the loop and is propagated to the caller, unless an exception handler is set with
+        `EventLoop.set_exception_handler`. Exceptions delivered to awaiting tasks and the
+        `CancelledError` of a cancelled task do not stop the loop.
+
+        # Raises
+
+        - `RuntimeError`: If tasks form a direct or transitive await cycle.
+        - `ValueError`: If a `Sleep` deadline is too large to represent.
+        """
+        ...
+
+    def set_watchdog(
+        self, budget: float | None, unit: TimeUnit = TimeUnit.SECOND, /
+    ) -> None:
+        """
+        Reports whenever a single resume of a task runs for longer than `budget`, measured in
+        `unit`.
+
+        A task only gives other tasks a chance to run when it reaches an `await`, so a task that
+        computes for a long time, or loops without awaiting, stalls the whole loop. With a watchdog
+        budget set, the loop creates a `vasyncio.WatchdogError` naming the offending task and how
+        long it ran, and passes it with the task to the exception handler set with
+        `EventLoop.set_exception_handler`. If there is no handler, the loop prints the message as a
+        warning instead. Either way, the task and the loop keep running. Pass `None` to turn the
+        watchdog off again.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        async def main():
+        vasyncio.get_running_loop().set_watchdog(5, MILLIS)
+        ...
+
+        vasyncio.run(main())
+        ```
+
+        # Raises
+
+        - `ValueError`: If `budget` is negative, non-finite, or too large to represent.
+        """
+        ...
+
+    def all_tasks(self, /) -> list[Task]:
+        """Returns a list of the tasks on this loop that have not finished yet."""
+        ...
+
+    def set_exception_handler(
+        self, handler: Callable[[Task, BaseException], Any] | None, /
+    ) -> None:
+        """
+        Sets the function called when a task fails with an exception that nothing observes.
+
+        A task's failure is observed if another task is awaiting it when it fails. Otherwise
+        `handler` is called with the failed task and its exception, and the loop keeps running the
+        remaining tasks, so one crashing background task doesn't stop the rest of the program.
+        `handler` is also called each time a supervised task fails before it is restarted, and with
+        a `vasyncio.WatchdogError` for each resume that overruns the budget set with
+        `EventLoop.set_watchdog`.
+
+        Pass `None` to restore the default behavior, where an unobserved exception propagates out
+        of the running loop, and supervised task failures and watchdog overruns are printed.
+        Exceptions raised by `handler` itself propagate out of the loop.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        def log_failure(task, exc):
+        print("task", task.get_name(), "failed:", exc)
+
+        async def telemetry():
+        raise RuntimeError("radio disconnected")
+
+        async def main():
+        vasyncio.get_running_loop().set_exception_handler(log_failure)
+        vasyncio.spawn(telemetry())
+        # ... keep driving ...
+
+        vasyncio.run(main())
+        ```
+
+        # Raises
+
+        - `TypeError`: If `handler` is neither callable nor `None`.
+        """
+        ...
+
+class Queue:
+    """
+    A first-in, first-out queue for passing items between tasks.
+
+    A `Queue` connects producer tasks, such as a sensor-polling loop, to consumer tasks, such as a
+    control loop. `Queue.get` waits until an item is available and `Queue.put` waits until there is
+    room for one, so a bounded queue slows a producer down when its consumer falls behind. Waiting
+    tasks are parked on the event loop and served in the order they started waiting.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    optical = OpticalSensor(1)
+    readings = vasyncio.Queue(4)
+
+    async def poll_proximity():
+    while True:
+    await readings.put(optical.get_proximity())
+    await vasyncio.Sleep(10, MILLIS)
+
+    async def control():
+    while True:
+    reading = await readings.get()
+    print("proximity:", reading)
+
+    async def main():
+    vasyncio.spawn(poll_proximity())
+    await control()
+
+    vasyncio.run(main())
+    ```
+    """
+
+    maxsize: int
+    def __init__(self, maxsize: int = 0, /) -> None:
+        """
+        Creates a queue that holds at most `maxsize` items.
+
+        If `maxsize` is less than or equal to zero, the queue is unbounded and `Queue.put` never
+        waits.
+
+        # Raises
+
+        - `TypeError`: If keyword arguments or more than one positional argument are supplied, or
+        if `maxsize` isn't an `int`.
+        """
+        ...
+
+    def qsize(self) -> int:
+        """Returns the number of items in the queue."""
+        ...
+
+    def empty(self) -> bool:
+        """Returns `True` if the queue has no items."""
+        ...
+
+    def full(self) -> bool:
+        """
+        Returns `True` if the queue holds `maxsize` items.
+
+        An unbounded queue is never full.
+        """
+        ...
+
+    def get(self, /) -> SyncFuture:
+        """Waits until an item is available, then removes and returns it."""
+        ...
+
+    def put(self, item: Any, /) -> SyncFuture:
+        """Waits until there is room in the queue, then adds `item` to it."""
+        ...
+
+    def get_nowait(self) -> Any:
+        """
+        Removes and returns an item without waiting.
+
+        # Raises
+
+        - `QueueEmpty`: If no item is available.
+        """
+        ...
+
+    def put_nowait(self, item: Any) -> None:
+        """
+        Adds `item` to the queue without waiting.
+
+        # Raises
+
+        - `QueueFull`: If the queue is full.
+        """
+        ...
+
+class Sleep:
+    """
+    An awaitable that will complete after a given duration.
+
+    Awaiting a `Sleep` effectively yields the current task for a period of time. Constructing it does
+    not block and does not start a separate task. When the duration has elapsed, awaiting it returns
+    `None`.
+    """
+    def __init__(self, interval: float, unit: TimeUnit, /) -> None:
+        """
+        Waits until `interval`, measured in `unit`, has elapsed.
+
+        This constructor returns an awaitable that will complete after the given duration, effectively
+        yielding the current task for a period of time. Use `MILLIS` for milliseconds or `SECOND` for
+        seconds. `interval` must be finite, non-negative, and small enough to represent.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        async def main():
+        print("See you in 5 minutes.")
+        await vasyncio.Sleep(300, SECOND)
+        print("Hello again!")
+
+        vasyncio.run(main())
+        ```
+
+        # Raises
+
+        - `TypeError`: If any keyword argument is supplied.
+        - `ValueError`: If `interval` is negative, non-finite, or too large to represent.
+        """
+        ...
+
+class SampleStream:
+    """
+    An asynchronous iterator that produces a sample on a fixed schedule.
+
+    Iterating with `async for` waits for each tick of the schedule and then takes a sample, so the
+    loop body runs once per period. Like `Ticker`, ticks are scheduled from the first sample rather
+    than from when the loop body finished, and ticks that are missed because the loop body ran too
+    long are skipped instead of being delivered in a burst. The stream never ends on its own; leave
+    the loop with `break` or `return`.
+
+    Users receive this from `vasyncio.sample` or from device helpers such as `Controller.states`
+    rather than constructing it directly.
+
+    # Raises
+
+    Any exception raised while taking a sample propagates out of the `async for` loop.
+    """
+    def __aiter__(self, /) -> SampleStream:
+        """Returns the stream itself."""
+        ...
+
+    def __anext__(self, /) -> SampleStream:
+        """Waits for the next tick of the schedule, then returns a new sample."""
+        ...
+
+class SyncFuture:
+    """
+    An awaitable returned by the waiting methods of `vasyncio` synchronization primitives.
+
+    Users receive it from `Event.wait`, `Lock.acquire`, `Semaphore.acquire`, `Condition.acquire`,
+    `Condition.wait`, `Queue.get`, or `Queue.put`, or by entering an `async with` block on a
+    `Lock`, `Semaphore`, or `Condition`, rather than constructing it directly. Awaiting it
+    parks the current task on the primitive until it can continue. It then returns the item for
+    `Queue.get`, `None` for `Queue.put`, and `True` otherwise. Waiting tasks are served in the order
+    they started waiting.
+
+    If the awaiting task is cancelled, the future removes itself from the primitive's queue. Any
+    lock or semaphore slot that was handed to it in the meantime is passed on to the next waiter, and
+    a queue item handed to a cancelled getter is put back at the front of the queue, so cancelled
+    tasks never leak ownership or drop items.
+
+    # Raises
+
+    - `RuntimeError`: If the future is awaited again after completing.
+    """
+    def throw(self, exc: BaseException, /) -> Any:
+        """
+        Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
+
+        The future leaves its wait queue before the exception propagates. A `Condition` waiter first
+        reacquires its lock so the lock is held when the exception reaches the caller, as it would be
+        after a normal return.
+        """
+        ...
+
+    def close(self) -> None:
+        """Leaves the wait queue when the awaiting coroutine is closed."""
+        ...
+
+class ExitFuture:
+    """
+    An awaitable returned when leaving an `async with` block on a `Lock`, `Semaphore`, or
+    `Condition`.
+
+    The primitive is released as soon as the block is left, so awaiting this completes immediately.
+    It returns `False`, so an exception raised inside the block keeps propagating.
+    """
+
+class Event:
+    """
+    An event that tasks can wait for.
+
+    An `Event` holds a flag that starts out clear. `Event.set` sets the flag and wakes every task
+    waiting in `Event.wait`; while the flag is set, `Event.wait` returns immediately. `Event.clear`
+    resets the flag. Waiting tasks are parked on the event loop instead of polling.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    optical = OpticalSensor(1)
+    ring_detected = vasyncio.Event()
+
+    async def watch_intake():
+    while optical.get_proximity() < 0.5:
+    await vasyncio.Sleep(10, MILLIS)
+    ring_detected.set()
+
+    async def main():
+    vasyncio.spawn(watch_intake())
+    await ring_detected.wait()
+    print("ring detected")
+
+    vasyncio.run(main())
+    ```
+    """
+    def __init__(self, /) -> None:
+        """
+        Creates an event whose flag is clear.
+
+        # Raises
+
+        - `TypeError`: If any arguments are supplied.
+        """
+        ...
+
+    def is_set(self) -> bool:
+        """Returns `True` if the event's flag is set."""
+        ...
+
+    def set(self) -> None:
+        """Sets the event's flag and wakes every task waiting for it."""
+        ...
+
+    def clear(self) -> None:
+        """Clears the event's flag so that subsequent calls to `Event.wait` block again."""
+        ...
+
+    def wait(self, /) -> SyncFuture:
+        """
+        Waits until the event's flag is set.
+
+        Returns `True` immediately if the flag is already set. A task woken by `Event.set` returns
+        `True` even if the flag is cleared again before the task resumes.
+        """
+        ...
+
+class Lock:
+    """
+    A mutual exclusion lock for tasks.
+
+    A `Lock` guarantees exclusive access to a shared resource, such as a drivetrain or an intake,
+    across tasks. `Lock.acquire` waits until the lock is free and then takes it; `Lock.release`
+    frees it and hands it to the longest-waiting task. Locks are not owned by a particular task, so
+    any task may release a lock.
+
+    A lock can also be held for the duration of an `async with` block, which acquires it on entry
+    and releases it on exit, even if the block raises.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    drivetrain_lock = vasyncio.Lock()
+
+    async def auto_align():
+    async with drivetrain_lock:
+    ...
+    ```
+    """
+    def __init__(self, /) -> None:
+        """
+        Creates an unlocked lock.
+
+        # Raises
+
+        - `TypeError`: If any arguments are supplied.
+        """
+        ...
+
+    def locked(self) -> bool:
+        """Returns `True` if the lock is held."""
+        ...
+
+    def acquire(self, /) -> SyncFuture:
+        """
+        Waits until the lock is free, then takes it.
+
+        Returns `True` once the lock is held. Tasks acquire the lock in the order they started
+        waiting.
+        """
+        ...
+
+    def release(self) -> None:
+        """
+        Releases the lock, waking the next task waiting to acquire it.
+
+        # Raises
+
+        - `RuntimeError`: If the lock isn't held.
+        """
+        ...
+
+    def __aenter__(self, /) -> SyncFuture:
+        """Acquires the lock on entry to an `async with` block."""
+        ...
+
+    def __aexit__(self, exc_type: Any, exc: Any, tb: Any, /) -> ExitFuture:
+        """
+        Releases the lock on exit from an `async with` block.
+
+        # Raises
+
+        - `RuntimeError`: If the lock was released inside the block.
+        """
+        ...
+
+class Semaphore:
+    """
+    A counter-based limit on how many tasks may use a resource at once.
+
+    A `Semaphore` holds a number of free slots. `Semaphore.acquire` waits until a slot is free and
+    takes it; `Semaphore.release` returns a slot, handing it to the longest-waiting task if there is
+    one. An `async with` block holds a slot for its duration.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    # Allow at most two tasks to use the radio at a time.
+    radio_slots = vasyncio.Semaphore(2)
+
+    async def send_telemetry():
+    async with radio_slots:
+    ...
+    ```
+    """
+    def __init__(self, value: int = 1, /) -> None:
+        """
+        Creates a semaphore with `value` free slots.
+
+        # Raises
+
+        - `TypeError`: If keyword arguments or more than one positional argument are supplied.
+        - `ValueError`: If `value` is negative.
+        """
+        ...
+
+    def locked(self) -> bool:
+        """Returns `True` if no slot is free."""
+        ...
+
+    def acquire(self, /) -> SyncFuture:
+        """
+        Waits until a slot is free, then takes it.
+
+        Returns `True` once a slot is held. Tasks acquire slots in the order they started waiting.
+        """
+        ...
+
+    def release(self) -> None:
+        """Returns a slot, waking the next task waiting to acquire one."""
+        ...
+
+    def __aenter__(self, /) -> SyncFuture:
+        """Acquires a slot on entry to an `async with` block."""
+        ...
+
+    def __aexit__(self, exc_type: Any, exc: Any, tb: Any, /) -> ExitFuture:
+        """Returns the slot on exit from an `async with` block."""
+        ...
+
+class Condition:
+    """
+    A condition variable that lets tasks wait for a notification while sharing a `Lock`.
+
+    A task must hold the condition's lock to call `Condition.wait`, which releases the lock, waits
+    for `Condition.notify` or `Condition.notify_all`, then reacquires the lock before returning.
+    Notifications are not remembered, so a waiting task should check its condition in a loop.
+    An `async with` block holds the condition's lock for its duration.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    rings = []
+    ring_added = vasyncio.Condition()
+
+    async def consumer():
+    async with ring_added:
+    while not rings:
+    await ring_added.wait()
+    print("scoring", rings.pop(0))
+
+    async def producer():
+    async with ring_added:
+    rings.append("red")
+    ring_added.notify()
+    ```
+    """
+    def __init__(self, lock: Lock | None = None, /) -> None:
+        """
+        Creates a condition variable using `lock`, or a new `Lock` if `lock` is `None`.
+
+        # Raises
+
+        - `TypeError`: If `lock` is neither a `Lock` nor `None`, or if keyword arguments or more
+        than one positional argument are supplied.
+        """
+        ...
+
+    def locked(self) -> bool:
+        """Returns `True` if the underlying lock is held."""
+        ...
+
+    def acquire(self, /) -> SyncFuture:
+        """Waits until the underlying lock is free, then takes it."""
+        ...
+
+    def release(self) -> None:
+        """
+        Releases the underlying lock.
+
+        # Raises
+
+        - `RuntimeError`: If the lock isn't held.
+        """
+        ...
+
+    def __aenter__(self, /) -> SyncFuture:
+        """Acquires the underlying lock on entry to an `async with` block."""
+        ...
+
+    def __aexit__(self, exc_type: Any, exc: Any, tb: Any, /) -> ExitFuture:
+        """
+        Releases the underlying lock on exit from an `async with` block.
+
+        # Raises
+
+        - `RuntimeError`: If the lock was released inside the block.
+        """
+        ...
+
+    def wait(self, /) -> SyncFuture:
+        """
+        Releases the underlying lock, waits until notified, then reacquires the lock.
+
+        Returns `True` once the lock is held again. If the waiting task is cancelled, the lock is
+        still reacquired before `CancelledError` propagates.
+
+        # Raises
+
+        - `RuntimeError`: If the underlying lock isn't held when the wait starts.
+        """
+        ...
+
+    def notify(self, n: int = 1, /) -> None:
+        """
+        Wakes up to `n` tasks waiting on this condition.
+
+        Woken tasks resume once they have reacquired the underlying lock.
+
+        # Raises
+
+        - `TypeError`: If keyword arguments or more than one positional argument are supplied.
+        - `ValueError`: If `n` is negative.
+        """
+        ...
+
+    def notify_all(self) -> None:
+        """Wakes every task waiting on this condition."""
+        ...
+
+class Task:
+    """
+    A spawned task.
+
+    A `Task` can be awaited to retrieve the output of its coroutine.
+
+    `EventLoop.spawn` and `vasyncio.spawn` return tasks; `Task` is not directly exported from the
+    `vasyncio` submodule and is not constructed by users. Awaiting a task cooperatively waits for
+    its coroutine and returns that coroutine's return value, including when the task completed
+    before the await began. Direct or transitive cycles between awaited tasks raise `RuntimeError`.
+
+    If the coroutine raises an exception, the task stores it and every task awaiting it receives the
+    same exception. A `CancelledError` marks the task as cancelled. Any other exception that nothing
+    observes, because no task is awaiting the failed task, is passed to the loop's exception
+    handler; see `EventLoop.set_exception_handler`. Without a handler, it propagates out of the
+    running event loop. Tasks created with `vasyncio.supervise` are restarted instead of failing.
+
+    Every task has a name, which defaults to `Task-<n>` and can be chosen with the `name` argument
+    of `vasyncio.spawn`. The loop also keeps run time statistics for each task; see `Task.stats`.
+    Tasks can be given a scheduling priority; see `Task.set_priority`.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    async def work():
+    print("Hello from a task!")
+    return 1 + 2
+
+    async def main():
+    # Spawn a coroutine onto the event loop.
+    task = vasyncio.spawn(work())
+
+    # Wait for the task's output.
+    assert await task == 3
+
+    vasyncio.run(main())
+    ```
+    """
+    def cancel(self, /) -> bool:
+        """
+        Requests cancellation of this task.
+
+        A `CancelledError` is thrown into the task's coroutine at the `await` point where it is
+        currently suspended, the next time the event loop runs. Pending sleeps are abandoned, so
+        `try`/`finally` blocks in the coroutine run promptly and can perform cleanup such as
+        stopping motors. If the coroutine lets the error propagate, the task becomes cancelled and
+        every task awaiting it receives the same `CancelledError`. A coroutine may catch the error
+        to refuse cancellation, in which case it keeps running normally.
+
+        A task that cancels itself receives the `CancelledError` at its next `await` point.
+
+        Returns `True` if cancellation was requested, or `False` if the task had already finished.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+
+        async def spin():
+        try:
+        motor.set_voltage(6)
+        await vasyncio.Sleep(10, SECOND)
+        finally:
+        motor.brake(BrakeMode.COAST)
+
+        async def main():
+        task = vasyncio.spawn(spin())
+        await vasyncio.Sleep(1, SECOND)
+        task.cancel()
+
+        try:
+        await task
+        except vasyncio.CancelledError:
+        print("spin was cancelled")
+
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
+    def cancelled(self) -> bool:
+        """Returns `True` if the task was cancelled."""
+        ...
+
+    def done(self) -> bool:
+        """
+        Returns `True` if the task has finished, either by returning, by raising an exception, or by
+        being cancelled.
+        """
+        ...
+
+    def result(self, /) -> Any:
+        """
+        Returns the task's return value.
+
+        # Raises
+
+        - The exception the task raised, if it failed or was cancelled.
+        - `RuntimeError`: If the task hasn't finished.
+        """
+        ...
+
+    def exception(self, /) -> BaseException | None:
+        """
+        Returns the exception the task raised, or `None` if it returned normally.
+
+        Retrieving the exception marks the failure as handled.
+
+        # Raises
+
+        - `CancelledError`: If the task was cancelled.
+        - `RuntimeError`: If the task hasn't finished.
+        """
+        ...
+
+    def get_name(self, /) -> str:
+        """Returns the task's name."""
+        ...
+
+    def set_name(self, name: str) -> None:
+        """Renames the task."""
+        ...
+
+    def get_priority(self) -> int:
+        """Returns the task's scheduling priority."""
+        ...
+
+    def set_priority(self, priority: int) -> None:
+        """
+        Changes the task's scheduling priority.
+
+        When several tasks are ready to run, the event loop resumes the one with the highest
+        priority first, and tasks with equal priority run in the order they became ready. To keep
+        busy high-priority tasks from starving the rest, a ready task's priority is temporarily
+        raised by one for every few times another task is resumed ahead of it, and drops back once
+        it runs.
+
+        Priorities only decide which ready task runs next. A running task is never interrupted, so
+        a high-priority task still has to wait for the current task to yield.
+        """
+        ...
+
+    def stats(self) -> TaskStats:
+        """
+        Returns a snapshot of the task's run time statistics.
+
+        The event loop measures how long each resume of the task's coroutine runs before it yields
+        again. A task whose maximum run time is large is blocking other tasks from running, usually
+        because of a long loop without an `await`.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        async def main():
+        await vasyncio.Sleep(5, SECOND)
+        for task in vasyncio.all_tasks():
+        stats = task.stats()
+        print(task.get_name(), stats.resumes, stats.max_time(MILLIS))
+
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
+class TaskStats:
+    """
+    Run time statistics of a `Task`, returned by `Task.stats`.
+
+    `resumes` is the number of times the event loop has resumed the task's coroutine, and
+    `restarts` is the number of times a supervised task has been restarted after failing. The run
+    time of a resume is measured from when the loop resumes the coroutine until it yields, returns,
+    or raises.
+    """
+
+    resumes: int
+    restarts: int
+    def total_time(self, unit: TimeUnit) -> float:
+        """Returns the combined run time of every resume, measured in `unit`."""
+        ...
+
+    def max_time(self, unit: TimeUnit) -> float:
+        """Returns the run time of the longest single resume, measured in `unit`."""
+        ...
+
+class Ticker:
+    """
+    A periodic timer whose ticks fire on a fixed schedule.
+
+    Awaiting a `Ticker` waits until its next tick. Unlike sleeping for the period at the end of a
+    loop, tick deadlines are computed from the time of the first tick rather than from when the
+    previous iteration finished, so the time spent doing work between ticks does not make the loop
+    drift. This keeps control loops, such as PID or odometry updates, running at a consistent rate.
+
+    The first tick fires one period after the ticker is first awaited. If an iteration runs long
+    and the deadline has already passed when the ticker is awaited, it returns immediately and counts
+    an *overrun*. If an entire period or more has been lost, the ticks that were skipped are counted
+    as *missed* and the schedule resumes at the next deadline that is still in the future, so the
+    ticker never fires a burst of ticks to catch up.
+
+    Awaiting a `Ticker` returns the number of ticks that were missed since the previous tick.
+
+    The `ticks`, `missed`, and `overruns` attributes report how many ticks have fired, how many
+    were skipped, and how many fired late.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    motor = Motor(1)
+
+    async def main():
+    ticker = vasyncio.Ticker(10, MILLIS)
+    while True:
+    missed = await ticker
+    if missed:
+    print("control loop missed", missed, "ticks")
+    motor.set_voltage(6.0)
+
+    vasyncio.run(main())
+    ```
+    """
+
+    ticks: int
+    missed: int
+    overruns: int
+    def __init__(self, period: float, unit: TimeUnit, /) -> None:
+        """
+        Creates a ticker that fires every `period`, measured in `unit`.
+
+        Use `MILLIS` for milliseconds or `SECOND` for seconds. The schedule starts the first time the
+        ticker is awaited.
+
+        # Raises
+
+        - `TypeError`: If any keyword argument is supplied.
+        - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+        """
+        ...
+
+    def reset(self) -> None:
+        """
+        Restarts the schedule so that the next tick fires one period after the ticker is next
+        awaited.
+
+        The `ticks`, `missed`, and `overruns` counters are left unchanged.
+        """
+        ...
+
+class UntilFuture:
+    """
+    An awaitable that completes once a condition becomes true.
+
+    The condition is checked each time the future is awaited, and then again every poll interval
+    until it holds. Between checks, the awaiting task is parked on the event loop, so other tasks
+    keep running. Awaiting an `UntilFuture` again checks its condition afresh.
+
+    Users receive this from `vasyncio.until` or from device helpers such as
+    `RotationSensor.wait_until_position` rather than constructing it directly.
+
+    To give up on a condition that might never become true, await the future from a coroutine
+    passed to `vasyncio.wait_for`.
+
+    # Raises
+
+    Any exception raised by the predicate or by the device reading propagates out of the await.
+    """
+
+class ReturnWhen:
+    """
+    Selects when `vasyncio.wait` returns.
+
+    `ReturnWhen` is exported from `vasyncio` and isn't constructed directly; use one of its
+    singleton constants, which are also exported from `vasyncio` as `FIRST_COMPLETED`,
+    `FIRST_EXCEPTION`, and `ALL_COMPLETED`.
+    """
+
+    FIRST_COMPLETED: ClassVar[ReturnWhen]
+    """Return as soon as any task finishes."""
+    FIRST_EXCEPTION: ClassVar[ReturnWhen]
+    """
+    Return as soon as any task fails with an exception other than `CancelledError`, or once
+    every task has finished if none fails.
+    """
+    ALL_COMPLETED: ClassVar[ReturnWhen]
+    """Return once every task has finished."""
+
+class GatherFuture:
+    """
+    An awaitable returned by `vasyncio.gather`.
+
+    Awaiting it waits for every task to finish and returns a list of their return values, in the
+    order the awaitables were passed to `gather`.
+    """
+
+class WaitFuture:
+    """
+    An awaitable returned by `vasyncio.wait`.
+
+    Awaiting it returns a `(done, pending)` tuple of task lists once its `ReturnWhen` condition is
+    met or its timeout elapses.
+    """
+
+class WaitForFuture:
+    """
+    An awaitable returned by `vasyncio.wait_for`.
+
+    Awaiting it returns the task's result if the task finishes in time. Otherwise, the task is
+    cancelled and `TimeoutError` is raised once the cancellation has been delivered.
+    """
+
+class VisionCode:
+    """
+    A vision detection code.
+
+    This class is root-importable. Codes are a special type of detection signature that group multiple `VisionSignature` objects
+    together. A `VisionCode` can associate 2-5 color signatures together, detecting the resulting
+    object when its color signatures are present close to each other.
+
+    These codes work very similarly to
+    [Pixy2 Color Codes](https://docs.pixycam.com/wiki/doku.php?id=wiki:v2:using_color_codes).
+
+    The read-only `sig1` and `sig2` attributes are required signature IDs from 1 to 7; read-only `sig3`,
+    `sig4`, and `sig5` contain additional IDs or `None`. Codes compare equal when all five slots
+    match, return `False` when compared with another type, and have a readable `VisionCode(...)`
+    representation.
+    """
+
+    sig1: int
+    sig2: int
+    sig3: int | None
+    sig4: int | None
+    sig5: int | None
+    @overload
+    def __init__(self, sig1: int, sig2: int, /) -> None:
+        """
+        Creates a new vision code.
+
+        Two signatures, `sig1` and `sig2`, are required to create a vision code, with an additional three
+        optional signatures, `sig3`, `sig4`, and `sig5`. Each supplied ID must be an integer from 1 to 7.
+        Although the generated annotation includes `None` for the trailing slots, the runtime accepts
+        omission rather than an explicitly passed `None`. All arguments are positional-only.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        # Create a vision code associated with signatures 1, 2, and 3.
+        code = VisionCode(1, 2, 3)
+        ```
+
+        # Raises
+
+        - `TypeError`: If a supplied ID is not an integer, `None` is passed explicitly, a keyword argument
+        is supplied, or the argument count is outside two to five.
+        - `ValueError`: If a supplied signature ID is outside the inclusive range 1 to 7.
+        """
+        ...
+    @overload
+    def __init__(self, sig1: int, sig2: int, sig3: int, /) -> None: ...
+    @overload
+    def __init__(self, sig1: int, sig2: int, sig3: int, sig4: int, /) -> None: ...
+    @overload
+    def __init__(
+        self, sig1: int, sig2: int, sig3: int, sig4: int, sig5: int, /
+    ) -> None: ...
+    @staticmethod
+    def from_id(id: int, /) -> VisionCode:
+        """
+        Creates a `VisionCode` from a bit representation of its signature IDs.
+
+        `id` must be from 0 through 32767 and is interpreted as five three-bit signature slots.
+        `sig1` and `sig2` must be nonzero; zero means an absent optional slot, and populated optional
+        slots must be contiguous.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sig_1_id = 1
+        sig_2_id = 2
+
+        # Store IDs 1 and 2 in the first two of the five three-bit slots.
+        code_id = (sig_1_id << 12) | (sig_2_id << 9)
+
+        # Create a VisionCode from signatures 1 and 2.
+        code = VisionCode.from_id(code_id)
+        ```
+
+        # Raises
+
+        - `TypeError`: If `id` is not an integer.
+        - `ValueError`: If `id` is outside 0 through 32767, either required slot is zero, or a
+        populated optional slot follows an empty one.
+        """
+        ...
+
+class LedMode:
+    """
+    Vision Sensor LED mode.
+
+    Represents the states that the integrated LED indicator on a Vision Sensor can be in. `LedMode` is
+    an abstract base and cannot be instantiated directly. Construct its associated-only variants as
+    `LedMode.Auto()` or `LedMode.Manual(...)`; the variants are not package-root imports.
+    """
+
+    Auto: ClassVar[type[Auto]]
+    """The associated Automatic Mode class, constructed as `LedMode.Auto()`."""
+    Manual: ClassVar[type[Manual]]
+    """The associated Manual Mode class, constructed as `LedMode.Manual(...)`."""
+
+    def __init__(self, /) -> None:
+        """
+        Rejects direct construction of the abstract `LedMode` base class.
+
+        Use `LedMode.Auto()` or `LedMode.Manual(r, g, b, brightness)` instead.
+
+        # Raises
+
+        - `TypeError`: Always.
+        """
+        ...
+
+class Auto:
+    """
+    Automatic Mode.
+
+    When in automatic mode, the integrated LED will display the color of the most prominent detected
+    object's signature color. This associated-only class is constructed as `LedMode.Auto()` and is not
+    package-root importable. Construction always returns the same singleton, which prints as
+    `LedMode.Auto()`.
+    """
+    def __init__(self, /) -> None:
+        """
+        Returns the singleton Automatic Mode.
+
+        # Raises
+
+        - `TypeError`: If any positional or keyword arguments are supplied.
+        """
+        ...
+
+class Manual:
+    """
+    Manual Mode.
+
+    When in manual mode, the integrated LED will display a user-set RGB color code and brightness
+    percentage from 0.0-1.0. This associated-only class is constructed as `LedMode.Manual(...)` and is
+    not package-root importable. Its read-only `r`, `g`, and `b` attributes are RGB channels from 0 to
+    255; its read-only `brightness` attribute is the intended normalized brightness. Values compare by
+    these four attributes, return `False` when compared with another type, and print as
+    `LedMode.Manual(r=..., g=..., b=..., brightness=...)`.
+    """
+
+    brightness: float
+    r: int
+    g: int
+    b: int
+    def __init__(self, r: int, g: int, b: int, brightness: float, /) -> None:
+        """
+        Creates a Manual Mode with RGB channels `r`, `g`, and `b` and normalized `brightness`.
+
+        RGB channels are positional-only integers from 0 to 255. `brightness` is positional-only, accepts
+        an integer or float, and is intended to range from 0.0 for off to 1.0 for full brightness; the
+        constructor does not enforce that brightness range.
+
+        # Raises
+
+        - `TypeError`: If an RGB channel is not an integer, `brightness` is not numeric, a keyword argument
+        is supplied, or the argument count is not exactly four.
+        - `ValueError`: If an RGB channel is outside the inclusive range 0 to 255.
+        """
+        ...
+
+class VisionSensor:
+    """
+    A Vision Sensor plugged into a Smart Port.
+
+    This class provides an interface for interacting with the VEX Vision Sensor.
+
+    # Hardware Overview
+
+    The VEX Vision Sensor is a device powered by an ARM Cortex M4 and Cortex M0 coprocessor with a
+    color camera for the purpose of performing object recognition. The sensor can be trained to
+    locate objects by color. The camera module itself is very similar internally to the Pixy2
+    camera, and performs its own onboard image processing. Manually processing raw image data from
+    the sensor is not currently possible.
+
+    Every 20 milliseconds, the camera provides a list of the objects found matching up to seven
+    unique `VisionSignature`s. The object’s height, width, and location is provided. Multi-colored
+    objects may also be programmed through the use of `VisionCode`s.
+
+    The Vision Sensor has USB for a direct connection to a computer, where it can be configured
+    using VEX's proprietary vision utility tool to generate color signatures. The Vision Sensor also
+    has Wi-Fi Direct and can act as web server, allowing a live video feed of the camera from any
+    computer equipped with a browser and Wi-Fi.
+    """
+
+    HORIZONTAL_RESOLUTION: ClassVar[int]
+    """
+    The horizontal resolution of the vision sensor.
+
+    This value is based on the `VISION_FOV_WIDTH` macro constant in PROS.
+    """
+    VERTICAL_RESOLUTION: ClassVar[int]
+    """
+    The vertical resolution of the vision sensor.
+
+    This value is based on the `VISION_FOV_HEIGHT` macro constant in PROS.
+    """
+    HORIZONTAL_FOV: ClassVar[float]
+    """The horizontal FOV of the vision sensor in degrees."""
+    VERTICAL_FOV: ClassVar[float]
+    """The vertical FOV of the vision sensor in degrees."""
+    DIAGONAL_FOV: ClassVar[float]
+    """The diagonal FOV of the vision sensor in degrees."""
+    UPDATE_INTERVAL_MS: ClassVar[int]
+    """The update rate of the vision sensor, in milliseconds."""
+
+    def __init__(self, port: int, /) -> None:
+        """
+        Creates a new vision sensor.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+        ```
+        """
+        ...
+
+    def set_signature(self, id: int, signature: VisionSignature, /) -> None:
+        """
+        Adds a detection signature to the sensor's onboard memory.
+
+        This signature will be used to identify objects when using `VisionSensor.get_objects`.
+
+        The sensor can store up to 7 unique signatures, with each signature slot denoted by the `id`
+        parameter. If a signature with an ID matching an existing signature on the sensor is added,
+        then the existing signature will be overwritten with the new one.
+
+        # Volatile Memory
+
+        The memory on the Vision Sensor is *volatile* and will therefore be wiped when the sensor
+        loses power. As a result, this function should be called every time the sensor is used on
+        program start.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+        `ValueError`: If the given signature ID is not in the interval [1, 7].
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        # These signatures can be generated using VEX's vision utility
+        example_signature = VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1)
+
+        # Set signature 1 on the sensor.
+        sensor.set_signature(1, example_signature)
+        ```
+        """
+        ...
+
+    def get_signature(self, id: int, /) -> VisionSignature | None:
+        """
+        Returns a signature from the sensor's onboard volatile memory.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+        `ValueError`: If the given signature ID is not in the interval [1, 7].
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        # Set an example signature in the sensor's first slot.
+        sensor.set_signature(1, VisionSignature(10049, 11513, -425, 1, -212, 4.1))
+
+        # Read signature 1 off the sensor.
+        # This should be the same as the one we just set.
+        signature = sensor.get_signature(1)
+        print(signature)
+        ```
+        """
+        ...
+
+    def get_signatures(self, /) -> tuple[VisionSignature | None, ...]:
+        """
+        Returns all signatures currently stored on the sensor's onboard volatile memory.
+
+        # Raises
+
+        - `DeviceError`: If there was not a sensor connected to the port, or if a read operation
+        failed, or there was no signature previously set in the slot(s) specified in the
+        `VisionCode`.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        # A bunch of random color signatures.
+        sig_1 = VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1)
+        sig_2 = VisionSignature(8973, 11143, 10058, -2119, -1053, -1586, 5.4)
+        sig_3 = VisionSignature(-3665, -2917, -3292, 4135, 10193, 7164, 2.0)
+        sig_4 = VisionSignature(-5845, -4809, -5328, -5495, -4151, -4822, 3.1)
+
+        # Set signatures 1-4.
+        sensor.set_signature(1, sig_1)
+        sensor.set_signature(2, sig_2)
+        sensor.set_signature(3, sig_3)
+        sensor.set_signature(4, sig_4)
+
+        # Read back the signatures from the sensor's memory.
+        # These should be the signatures that we just set.
+        signatures = sensor.get_signatures()
+        for signature in signatures:
+        if signature:
+        print(f"Found sig saved on sensor: {signature}")
+        ```
+        """
+        ...
+
+    def add_code(self, code: VisionCode) -> None:
+        """
+        Registers a color code to the sensor's onboard memory. This code will be used to identify
+        objects when using `VisionSensor.get_objects`.
+
+        Color codes are effectively "signature groups" that the sensor will use to identify objects
+        containing the color of their signatures next to each other.
+
+        # Volatile Memory
+
+        The onboard memory of the Vision Sensor is *volatile* and will therefore be wiped when the
+        sensor loses its power source. As a result, this function should be called every time the
+        sensor is used on program start.
+
+        # Raises
+
+        - `DeviceError`: If there was not a sensor connected to the port, or if a read operation
+        failed, or there was no signature previously set in the slot(s) specified in the
+        `VisionCode`.
+        `ValueError`: If the given signature ID is not in the interval [1, 7].
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        # Two color signatures.
+        sig_1 = VisionSensor(10049, 11513, 10781, -425, 1, -212, 4.1)
+        sig_2 = VisionSensor(8973, 11143, 10058, -2119, -1053, -1586, 5.4)
+
+        # Store the signatures on the sensor.
+        sensor.set_signature(1, sig_1)
+        sensor.set_signature(2, sig_2)
+
+        # Create a code associating signatures 1 and 2 together.
+        code = VisionCode(1, 2)
+
+        # Register our code on the sensor. When we call `VisionSensor.get_objects`, the associated
+        # signatures will be returned as a single object if their colors are detected next to each
+        # other.
+        sensor.add_code(code)
+
+        # Scan for objects.
+        for object in sensor.get_objects():
+        # Filter only objects matching the code we just set.
+        if obj.source == DetectionSource.Code(code):
+        print(obj)
+        ```
+        """
+        ...
+
+    def get_led_mode(self, /) -> LedMode:
+        """
+        Returns the user-set behavior of the LED indicator on the sensor.
+
+        # Raises
+
+        - `DeviceError`: If there was not a sensor connected to the port, or if a read operation
+        failed, or there was no signature previously set in the slot(s) specified in the
+        `VisionCode`.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        async def main():
+        # Set the LED to red at 100% brightness.
+        sensor.set_led_mode(LedMode.Manual(255, 0, 0, 1))
+
+        # Give the sensor time to update.
+        await vasyncio.Sleep(VisionSensor.UPDATE_INTERVAL_MS, MILLIS)
+
+        # Check the sensor's reported LED mode. Should be the same as what we just set.
+        led_mode = sensor.get_led_mode()
+        assert led_mode == LedMode.Manual(255, 0, 0, 1)
+
+        vasyncio.run(main)
+        ```
+        """
+        ...
+
+    def get_objects(self, /) -> tuple[VisionObject, ...]:
+        """
+        Returns a `tuple` of objects detected by the sensor.
+
+        # Raises
+
+        - `DeviceError`: If there was not a sensor connected to the port, or if the sensor is in
+        Wi-Fi mode, or if the sensor failed to read an object.
+
+        # Examples
+
+        With one signature:
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        # Set a color signature on the sensor's first slot.
+        sensor.set_signature(1, VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1))
+
+        # Scan for detected objects.
+        for _ in sensor.get_objects():
+        print("Object detected")
+        ```
+
+        With multiple signatures:
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        # Two color signatures.
+        sig_1 = VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1)
+        sig_2 = VisionSignature(8973, 11143, 10058, -2119, -1053, -1586, 5.4)
+
+        # Store the signatures on the sensor.
+
+        sensor.set_signature(1, sig_1)
+        sensor.set_signature(2, sig_2)
+
+        # Scan for objects.
+        for object in sensor.get_objects():
+        # Identify which signature the detected object matches.
+        if object.source == DetectionSource.Signature(1):
+        print(f"Detected object matching sig_1: {object}")
+        elif object.source == DetectionSource.Signature(2):
+        print(f"Detected object matching sig_2: {object}")
+        ```
+        """
+        ...
+
+    def get_object_count(self) -> int:
+        """
+        Returns the number of objects detected by the sensor.
+
+        # Raises
+
+        - `DeviceError`: If there was not a sensor connected to the port, or if the sensor is in
+        Wi-Fi mode, or if the sensor failed to read an object.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        async def main():
+        # Set a color signature on the sensor's first slot.
+        sensor.set_signature(1, VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1))
+        while True:
+        object_count = sensor.get_object_count()
+        print(f"Sensor is currently detecting {object_count} objects.")
+
+        await vasyncio.Sleep(VisionSensor.UPDATE_INTERVAL_MS, MILLIS)
+
+        vasyncio.run(main)
+        ```
+        """
+        ...
+
+    def get_brightness(self) -> float:
+        """
+        Returns the current brightness setting of the vision sensor as a percentage.
+
+        The returned result should be from `0.0` (0%) to `1.0` (100%).
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        async def main():
+        # Set brightness to 50%
+        sensor.set_brightness(0.5)
+
+        # Give the sensor time to update.
+        await vasyncio.Sleep(VisionSensor.UPDATE_INTERVAL_MS, MILLIS)
+
+        # Read brightness. Should be 50%, since we just set it.
+        assert sensor.get_brightness() == 0.5
+
+        vasyncio.run(main)
+        ```
+        """
+        ...
+
+    def get_white_balance(self, /) -> WhiteBalance:
+        """
+        Returns the current white balance of the vision sensor as an RGB color.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        async def main():
+        # Set white balance to manual.
+        sensor.set_white_balance(WhiteBalance.Manual(255, 255, 255))
+
+        # Give the sensor time to update.
+        await vasyncio.Sleep(VisionSensor.UPDATE_INTERVAL_MS, MILLIS)
+
+        # Read white balance. Should be the same as what we just set.
+        white_balance = sensor.get_white_balance()
+        assert white_balance == WhiteBalance.Manual(255, 255, 255)
+
+        vasyncio.run(main)
+        ```
+        """
+        ...
+
+    def set_brightness(self, brightness: float) -> None:
+        """
+        Sets the brightness percentage of the vision sensor. Should be between 0.0 and 1.0.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        # Set brightness to 50%
+        sensor.set_brightness(0.5)
+        ```
+        """
+        ...
+
+    def set_white_balance(self, balance: WhiteBalance, /) -> None:
+        """
+        Sets the white balance of the vision sensor.
+
+        White balance can be either automatically set or manually set through an RGB color.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        # Set white balance to manual.
+        sensor.set_white_balance(WhiteBalance.Manual(255, 255, 255))
+        ```
+        """
+        ...
+
+    def set_led_mode(self, mode: LedMode, /) -> None:
+        """
+        Configure the behavior of the LED indicator on the sensor.
+
+        The default behavior is represented by `LedMode.Auto`, which will display the color of
+        the most prominent detected object's signature color. Alternatively, the LED can be
+        configured to display a single RGB color.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        # Set the LED to red at 100% brightness.
+        sensor.set_led_mode(LedMode.Manual(255, 0, 0, 1))
+        ```
+        """
+        ...
+
+    def set_mode(self, mode: VisionMode) -> None:
+        """
+        Sets the vision sensor's detection mode. See `VisionMode` for more information on what each
+        mode does.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        # Place the sensor into "Wi-Fi mode," allowing you to connect to it via a hotspot and
+        # receive a video stream of its camera from another device.
+        sensor.set_mode(VisionMode.WIFI)
+        ```
+        """
+        ...
+
+    def get_mode(self, /) -> VisionMode:
+        """
+        Returns the current detection mode that the sensor is using.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+
+        async def main():
+        # Place the sensor into "Wi-Fi mode," allowing you to connect to it via a hotspot and
+        # receive a video stream of its camera from another device.
+        sensor.set_mode(VisionMode.WIFI)
+
+        await vasyncio.Sleep(VisionSensor.UPDATE_INTERVAL_MS, MILLIS)
+
+        # Since we just set the mode, we can the mode off the sensor to verify that it's now in
+        # Wi-Fi mode.
+        assert sensor.get_mode() == VisionMode.WIFI
+
+        vasyncio.run(main)
+        ```
+        """
+        ...
+
+    def free(self) -> None:
+        """
+        Release this device and free its Smart Port lock. This binding will become unusable after
+        this call, but you can reuse the underlying Smart Port number in a new device.
+
+        Any attempts to use this device after freeing will result in a `ValueError` being raised.
+
+        # Raises
+
+        `ValueError`: If the device has already been freed.
+
+        # Examples
+
+        Construct a `Motor`, free it, then construct a `RotationSensor` with the same Smart Port:
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+        motor.free()
+        # `motor` is now unusable, but port 1 can be used in another device, such as a `RotationSensor`
+        rotation_sensor = RotationSensor(1)
+        ```
+        """
+        ...
+
+class VisionMode:
+    """
+    A possible "detection mode" for the Vision Sensor.
+
+    This class is root-importable. Pass one of its singleton values to `VisionSensor.set_mode`; the
+    current value is returned by `VisionSensor.get_mode`. `VisionMode` isn't constructed directly.
+    Values have readable representations such as `VisionMode.COLOR_DETECTION`.
+    """
+
+    COLOR_DETECTION: ClassVar[VisionMode]
+    """Uses color signatures and codes to identify objects in blocks."""
+    LINE_DETECTION: ClassVar[VisionMode]
+    """Uses line tracking to identify lines."""
+    MIXED_DETECTION: ClassVar[VisionMode]
+    """Both color signatures and lines will be detected as objects."""
+    WIFI: ClassVar[VisionMode]
+    """
+    Sets the sensor into "Wi-Fi mode", which disables all forms of object detection and enables the
+    sensor's onboard Wi-Fi hotspot for streaming camera data over a web server.
+
+    Once enabled, the sensor will create a wireless network with an SSID in the format of `VISION_XXXX`.
+    The sensor's camera feed is available at `192.168.1.1`.
+
+    This mode will be automatically disabled when connected to field control.
+    """
+    TEST: ClassVar[VisionMode]
+    """Unknown use."""
+
+class VisionObject:
+    """
+    A detected vision object.
+
+    This root-importable class contains metadata about objects detected by the Vision Sensor.
+    Instances are returned by `VisionSensor.get_objects` after adding signatures and color codes to
+    the sensor, and cannot be constructed directly. All attributes are read-only.
+
+    - `source` is the signature or color code used to detect this object.
+    - `width` is the width of the detected object's bounding box in pixels.
+    - `height` is the height of the detected object's bounding box in pixels.
+    - `offset_x` and `offset_y` are the top-left coordinate of the detected object relative to the
+    top-left of the camera's field of view.
+    - `center_x` and `center_y` are the center coordinate of the detected object relative to the
+    top-left of the camera's field of view.
+
+    The readable representation summarizes the source and all six pixel measurements.
+    """
+
+    source: DetectionSource
+    width: int
+    height: int
+    offset_x: int
+    offset_y: int
+    center_x: int
+    center_y: int
+    def get_angle(self, unit: RotationUnit) -> float:
+        """
+        Returns the approximate rotation of the detected object's bounding box in `unit`.
+
+        The underlying sensor reports tenths of a degree, which Venice converts to `DEGREES`, `RADIANS`, or
+        `TURNS`.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = VisionSensor(1)
+        for object in sensor.get_objects():
+        print(object.get_angle(DEGREES))
+        ```
+        """
+        ...
+
+class VisionSignature:
+    """
+    A vision detection color signature.
+
+    This class is root-importable. Vision signatures contain information used by the Vision Sensor to detect objects of a certain
+    color. These signatures are typically generated through VEX's Vision Utility tool rather than
+    written by hand.
+
+    # Format & Detection Overview
+
+    Vision signatures operate in a version of the Y'UV color space, specifically using the "U" and
+    "V" chroma components for edge detection purposes. The read-only `u_min`, `u_max`, and `u_mean`
+    attributes place three threshold values on the U chroma values detected by the sensor. The
+    read-only `v_min`, `v_max`, and `v_mean` attributes do the same for the V component. These values
+    are then transformed to a 3D lookup table to detect actual colors.
+
+    The read-only `range` attribute works as a scale factor or threshold for how lenient edge detection
+    should be. It ranges from 0-11 in Vision Utility. Higher values increase the range of brightness
+    that the sensor considers part of the signature, so lighter and darker shades are detected more
+    often. The read-only `flags` attribute is the signature's flags and is initialized to 0.
+
+    Signatures can additionally be grouped together into `VisionCode` objects, which narrow the filter
+    for object detection by requiring two colors. Signatures compare equal when all thresholds,
+    `range`, and `flags` match, return `False` when compared with another type, and have a readable
+    representation containing those eight values.
+    """
+
+    u_min: int
+    u_max: int
+    u_mean: int
+    v_min: int
+    v_max: int
+    v_mean: int
+    range: float
+    flags: int
+    def __init__(
+        self,
+        u_min: int,
+        u_max: int,
+        u_mean: int,
+        v_min: int,
+        v_max: int,
+        v_mean: int,
+        range: float,
+        /,
+    ) -> None:
+        """
+        Creates a `VisionSignature`.
+
+        `u_min`, `u_max`, and `u_mean` are the minimum, maximum, and mean values on the U axis. `v_min`,
+        `v_max`, and `v_mean` are the corresponding values on the V axis. `range` is the detection range
+        scale factor. All seven arguments are positional-only; `flags` is set to 0.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        my_signature = VisionSignature(10049, 11513, 10781, -425, 1, -212, 4.1)
+        ```
+
+        # Raises
+
+        - `TypeError`: If a threshold is not an integer, `range` is not numeric, a keyword argument is
+        supplied, or the argument count is not exactly seven.
+        """
+        ...
+
+class DetectionSource:
+    """
+    The detection method used to identify a `VisionObject`.
+
+    `DetectionSource` is an abstract base and cannot be instantiated directly. Construct its
+    associated-only variants as `DetectionSource.Signature(...)`, `DetectionSource.Code(...)`, or
+    `DetectionSource.Line()`. The variant classes are not package-root imports.
+    """
+
+    Signature: ClassVar[type[Signature]]
+    """The associated class for a normal Vision signature source, constructed as `DetectionSource.Signature(id)`."""
+    Code: ClassVar[type[Code]]
+    """The associated class for multiple signatures joined in a color code, constructed as `DetectionSource.Code(code)`."""
+    Line: ClassVar[type[Line]]
+    """The associated class for line detection, constructed as `DetectionSource.Line()`."""
+
+    def __init__(self, /) -> None:
+        """
+        Rejects direct construction of the abstract `DetectionSource` base class.
+
+        Use one of the associated variant classes instead.
+
+        # Raises
+
+        - `TypeError`: Always.
+        """
+        ...
+
+class Signature:
+    """
+    A normal Vision signature not associated with a color code was used to detect a `VisionObject`.
+
+    Construct this associated-only class as `DetectionSource.Signature(id)`; it is not package-root
+    importable. The read-only integer attribute `id` is the matching signature slot from 1 to 7. Values
+    compare equal when their IDs match, return `False` when compared with another type, and print as
+    `DetectionSource.Signature(id=...)`.
+    """
+
+    id: int
+    def __init__(self, id: int, /) -> None:
+        """
+        Creates a normal Vision signature source for signature slot `id`.
+
+        `id` is a positional-only integer from 1 to 7.
+
+        # Raises
+
+        - `TypeError`: If `id` is not an integer, a keyword argument is supplied, or the argument count is
+        not one.
+        - `ValueError`: If `id` is outside the inclusive range 1 to 7.
+        """
+        ...
+
+class Code:
+    """
+    Multiple signatures joined in a color code were used to detect a `VisionObject`.
+
+    Construct this associated-only class as `DetectionSource.Code(code)`; it is not package-root
+    importable. The read-only `code` attribute is the matching `VisionCode`. Values compare equal
+    when their codes match, return `False` when compared with another type, and print as
+    `DetectionSource.Code(code=...)`.
+    """
+
+    code: VisionCode
+    def __init__(self, code: VisionCode, /) -> None:
+        """
+        Creates a source indicating that multiple signatures joined in `code` detected the object.
+
+        `code` must be a positional-only `VisionCode`.
+
+        # Raises
+
+        - `TypeError`: If `code` is not a `VisionCode`, a keyword argument is supplied, or the argument
+        count is not one.
+        """
+        ...
+
+class Line:
+    """
+    Line detection was used to find a `VisionObject`.
+
+    Construct this associated-only class as `DetectionSource.Line()`; it is not package-root
+    importable. Construction always returns the same singleton, which prints as
+    `DetectionSource.Line()`.
+    """
+    def __init__(self, /) -> None:
+        """
+        Returns the singleton source indicating that line detection found the object.
+
+        # Raises
+
+        - `TypeError`: If any positional or keyword arguments are supplied.
+        """
+        ...
+
+class WhiteBalance:
+    """
+    Vision Sensor white balance mode.
+
+    Represents a white balance configuration for the Vision Sensor's camera. `WhiteBalance` is an
+    abstract base and cannot be instantiated directly. Construct its associated-only variants as
+    `WhiteBalance.Auto()`, `WhiteBalance.StartupAuto()`, or `WhiteBalance.Manual(...)`; the variants are
+    not package-root imports.
+    """
+
+    Auto: ClassVar[type[Auto]]
+    """The associated Automatic Mode class, constructed as `WhiteBalance.Auto()`."""
+    StartupAuto: ClassVar[type[StartupAuto]]
+    """The associated "Startup" Automatic Mode class, constructed as `WhiteBalance.StartupAuto()`."""
+    Manual: ClassVar[type[Manual]]
+    """The associated Manual Mode class, constructed as `WhiteBalance.Manual(r, g, b)`."""
+
+    def __init__(self, /) -> None:
+        """
+        Rejects direct construction of the abstract `WhiteBalance` base class.
+
+        Use one of the associated variant classes instead.
+
+        # Raises
+
+        - `TypeError`: Always.
+        """
+        ...
+
+class Auto:
+    """
+    Automatic Mode.
+
+    The sensor will automatically adjust the camera's white balance, using the brightest part of the
+    image as a white point. This associated-only class is constructed as `WhiteBalance.Auto()` and is
+    not package-root importable. Construction always returns the same singleton, which prints as
+    `WhiteBalance.Auto()`.
+    """
+    def __init__(self, /) -> None:
+        """
+        Returns the singleton Automatic Mode.
+
+        # Raises
+
+        - `TypeError`: If any positional or keyword arguments are supplied.
+        """
+        ...
+
+class StartupAuto:
+    """
+    "Startup" Automatic Mode.
+
+    The sensor will automatically adjust the camera's white balance, but will only perform this
+    adjustment once on power-on. This associated-only class is constructed as
+    `WhiteBalance.StartupAuto()` and is not package-root importable. Construction always returns the
+    same singleton, which prints as `WhiteBalance.StartupAuto()`.
+    """
+    def __init__(self, /) -> None:
+        """
+        Returns the singleton "Startup" Automatic Mode.
+
+        # Raises
+
+        - `TypeError`: If any positional or keyword arguments are supplied.
+        """
+        ...
+
+class Manual:
+    """
+    Manual Mode.
+
+    This mode allows for manual control over white balance using an RGB color. This associated-only class is
+    constructed as `WhiteBalance.Manual(r, g, b)` and is not package-root importable. Its read-only
+    integer attributes `r`, `g`, and `b` are the red, green, and blue white-point channels from 0 to
+    255. Values compare by these three channels, return `False` when compared with another type,
+    and print as `WhiteBalance.Manual(r=..., g=..., b=...)`.
+    """
+
+    r: int
+    g: int
+    b: int
+    def __init__(self, r: int, g: int, b: int, /) -> None:
+        """
+        Creates a Manual Mode with RGB channels `r`, `g`, and `b`.
+
+        Each channel is a positional-only integer from 0 to 255.
+
+        # Raises
+
+        - `TypeError`: If a channel is not an integer, a keyword argument is supplied, or the argument
+        count is not exactly three.
+        - `ValueError`: If a channel is outside the inclusive range 0 to 255.
+        """
+        ...
+
+def get_capacity() -> float:
+    """
+    Returns the charge capacity of the robot's battery in the range of [0.0, 1.0].
+
+    A value of `0.0` indicates a completely empty battery, while a value of `1.0` indicates a
+    fully-charged battery.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    capacity = battery.get_capacity()
+    print(f"Battery at {capacity:.0%} capacity")
+
+    if capacity < 0.2:
+    print("Warning: Low battery!")
+    ```
+    """
+    ...
+
+def get_current() -> float:
+    """
+    Returns the electric current of the robot's battery in amps.
+
+    Maximum current draw on the V5 battery is 20 Amps.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    current = battery.get_current()
+
+    print(f"Drawing {current} amps")
+    ```
+    """
+    ...
+
+def get_temperature() -> int:
+    """
+    Returns the internal temperature of the robot's battery in degrees Celsius.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    temp = battery.get_temperature()
+    print(f"Battery temperature: {temp}°C")
+
+    # Check if battery is too hot
+    if temp > 45:
+    print("Warning: Battery temperature critical!")
+    ```
+    """
+    ...
+
+def get_voltage() -> float:
+    """
+    Returns the robot's battery voltage in volts.
+
+    Nominal battery voltage on the V5 brain is 12.8V.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    voltage = battery.get_voltage()
+    print("Battery voltage: {voltage} V")
+    ```
+    """
+    ...
+
+def draw_pixel(x: int, y: int, color: Color) -> None:
+    """
+    Draws a filled pixel to the display with the specified `color`.
+
+    `x` and `y` are pixel coordinates. The writable display is 480 pixels wide by 240 pixels high, with
+    its origin at the top-left.
+    """
+    ...
+
+def draw_line(
+    start_x: int,
+    start_y: int,
+    end_x: int,
+    end_y: int,
+    color: Color | LinearGradient,
+    /,
+    *,
+    thickness: int = 1,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
+    """
+    Draws a line to the display with the specified `color`.
+
+    `start_x` and `start_y` are the start point of the line; `end_x` and `end_y` are the end point. The
+    line width is one pixel. Coordinates are measured from the display's top-left, and both supplied
+    endpoints are passed directly to the display SDK.
+
+    With `thickness` above one or `antialias=True`, the line is instead rasterized like the other
+    shapes, such as `display.draw_arc`: it's `thickness` pixels wide with rounded ends, and its
+    anti-aliased edges are blended with `bg_color`. `color` can then also be a `LinearGradient`.
+
+    # Raises
+
+    - `TypeError`: If `color` isn't a `Color`, or a `LinearGradient` when the line is rasterized.
+    - `ValueError`: If `thickness` is zero.
+    """
+    ...
+
+def draw_circle(x: int, y: int, radius: int, color: Color, /) -> None:
+    """
+    Draws a outlined circle to the display with the specified `color`.
+
+    `x` and `y` are the center point of the circle, and `radius` is its radius in pixels. Circles are
+    not antialiased.
+    """
+    ...
+
+def fill_circle(x: int, y: int, radius: int, color: Color, /) -> None:
+    """
+    Draws a filled circle to the display with the specified `color`.
+
+    `x` and `y` are the center point of the circle, and `radius` is its radius in pixels. Circles are
+    not antialiased.
+    """
+    ...
+
+def draw_rect(x: int, y: int, width: int, height: int, color: Color, /) -> None:
+    """
+    Draws an outlined rectangular region of the display with the specified `color`.
+
+    `x` and `y` are the top-left coordinate of the rectangle. `width` and `height` are its dimensions
+    in pixels. The bottom right point is not included in the shape's bounds. Thus, the area of the
+    drawn rectangle is `width * height` pixels.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    # Draw a 20x20 rectangle which has a top-left point at (30, 40).
+    display.draw_rect(30, 40, 20, 20, Color.WHITE)
+    ```
+
+    # Raises
+
+    - `ValueError`: If a coordinate, dimension, or resulting endpoint is outside its supported
+    integer range.
+    """
+    ...
+
+def fill_rect(x: int, y: int, width: int, height: int, color: Color, /) -> None:
+    """
+    Draws a filled rectangular region of the display with the specified `color`.
+
+    `x` and `y` are the top-left coordinate of the rectangle. `width` and `height` are its dimensions
+    in pixels. The bottom right point is not included in the shape's bounds. Thus, the area of the
+    drawn rectangle is `width * height` pixels.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    # Draw a 20x20 rectangle which has a top-left point at (30, 40).
+    display.fill_rect(30, 40, 20, 20, Color.WHITE)
+    ```
+
+    # Raises
+
+    - `ValueError`: If a coordinate, dimension, or resulting endpoint is outside its supported
+    integer range.
+    """
+    ...
+
+def draw_buffer(x: int, y: int, width: int, height: int, buffer: Any, /) -> None:
+    """
+    Draws a buffer of pixels to a specified region of the display.
+
+    This function copies the pixels in `buffer` to the specified region of the display. `x` and `y`
+    are the region's top-left corner, and `width` and `height` are measured in pixels. `buffer` must be
+    a readable, suitably aligned buffer containing exactly `width * height` packed four-byte
+    `0xRRGGBB` color values in row-major order.
+
+    # Raises
+
+    - `TypeError`: If `buffer` doesn't support the readable buffer protocol.
+    - `ValueError`: If the buffer is unaligned, its readable byte length isn't a multiple of four,
+    its pixel count doesn't equal `width * height`, or the region exceeds the supported coordinate
+    range.
+    """
+    ...
+
+def draw_text(
+    text: str,
+    x: int,
+    y: int,
+    /,
+    *,
+    font_size: FontSize = FontSize.MEDIUM,
+    font_family: FontFamily = FontFamily.PROPORTIONAL,
+    color: Color = Color.WHITE,
+    bg_color: Color | None = None,
+) -> None:
+    """
+    Draws a line of `text` with the specified `color` and `bg_color` to the display.
+
+    `x` and `y` are the top-left corner coordinates of the text. `font_size` defaults to
+    `FontSize.MEDIUM`, `font_family` defaults to `FontFamily.PROPORTIONAL`, and `color` defaults to
+    `Color.WHITE`. Omitting `bg_color` or passing it as `None` gives a transparent background;
+    supply a `Color` to paint it.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    # Write red text with a blue background to the display.
+    display.draw_text(
+    "Hello, World!",
+    10,
+    10,
+    font_size=display.FontSize.MEDIUM,
+    font_family=display.FontFamily.MONOSPACE,
+    color=Color(255, 0, 0),
+    bg_color=Color(0, 0, 255),
+    )
+    ```
+
+    # Raises
+
+    - `ValueError`: If `text` contains a NUL character.
+    """
+    ...
+
+def print(*values: object, sep: str = " ", end: str = "\n") -> None:
+    """
+    Writes `values` to the Brain display's scrolling text area.
+
+    Values are converted to strings and joined with `sep`, which defaults to one space, then `end` is
+    appended, which defaults to a newline. Text uses the default white font, wraps after 48 characters,
+    and scrolls upward after 12 visible lines.
+
+    # Raises
+
+    - `TypeError`: If a value isn't printable or if `sep` or `end` isn't a string.
+    """
+    ...
+
+def scroll(start: int, offset: int) -> None:
+    """
+    Scrolls the pixels at or below the specified y-coordinate `start`.
+
+    This function y-offsets the pixels in the display buffer which are at or below `start` by `offset`
+    pixels. Positive values move the pixels upwards, and pixels that are moved out of the scroll region
+    are discarded. Empty spaces are then filled with the display's background color.
+    """
+    ...
+
+def scroll_region(x: int, y: int, width: int, height: int, offset: int, /) -> None:
+    """
+    Scrolls a region of the display.
+
+    This function y-offsets the pixels in the display buffer which are contained in the specified
+    scroll region by `offset` pixels. `x` and `y` are the region's top-left corner; `width` and `height`
+    are measured in pixels. Positive offset values move the pixels upwards, and pixels that are moved
+    out of the scroll region are discarded. Empty spaces are then filled with the display's background
+    color.
+
+    # Raises
+
+    - `ValueError`: If `x`, `y`, or `offset` is outside the supported signed 16-bit range, `width` or
+    `height` is outside the unsigned 16-bit range, or the region's endpoint exceeds the supported
+    coordinate range.
+    """
+    ...
+
+def set_render_mode(render_mode: RenderMode) -> None:
+    """
+    Sets `render_mode` for the display.
+
+    For more information on render modes, see `display.RenderMode`.
+    """
+    ...
 
-        Use one of the associated variant classes instead.
+def render() -> None:
+    """
+    Flushes the display's double buffer if it is enabled.
 
-        # Raises
+    This is a no-op with `RenderMode.IMMEDIATE`, but is necessary for anything to be displayed when
+    using `RenderMode.DOUBLE_BUFFERED`.
+    """
+    ...
 
-        - `TypeError`: Always.
-        """
-        ...
+def erase(color: Color) -> None:
+    """Clears the entire 480-by-240-pixel writable display, filling it with the specified `color`."""
+    ...
 
-class Signature:
+def get_touch_status() -> TouchEvent:
     """
-    A normal Vision signature not associated with a color code was used to detect a `VisionObject`.
+    Returns the last recorded state of the display's touchscreen as a `TouchEvent`.
 
-    Construct this associated-only class as `DetectionSource.Signature(id)`; it is not package-root
-    importable. The read-only integer attribute `id` is the matching signature slot from 1 to 7. Values
-    compare equal when their IDs match, return `False` when compared with another type, and print as
-    `DetectionSource.Signature(id=...)`.
+    See `display.TouchEvent` for more information.
     """
+    ...
 
-    id: int
-    def __init__(self, id: int, /) -> None:
-        """
-        Creates a normal Vision signature source for signature slot `id`.
+def is_now_pressed() -> bool:
+    """Returns whether the touchscreen's last recorded touch has just been pressed."""
+    ...
 
-        `id` is a positional-only integer from 1 to 7.
+def is_pressed() -> bool:
+    """Returns whether the touchscreen's last recorded touch has been pressed or is being held."""
+    ...
 
-        # Raises
+def is_released() -> bool:
+    """Returns whether the touchscreen's last recorded touch has been released."""
+    ...
 
-        - `TypeError`: If `id` is not an integer, a keyword argument is supplied, or the argument count is
-        not one.
-        - `ValueError`: If `id` is outside the inclusive range 1 to 7.
-        """
-        ...
+def is_held() -> bool:
+    """Returns whether the touchscreen's last recorded touch is still being held."""
+    ...
 
-class Code:
+def draw_polygon(
+    points: list[tuple[int, int]] | tuple[tuple[int, int], ...],
+    color: Color | LinearGradient,
+    /,
+    *,
+    thickness: int = 1,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
     """
-    Multiple signatures joined in a color code were used to detect a `VisionObject`.
+    Draws the outline of a closed polygon through `points`, a list of `(x, y)` tuples.
 
-    Construct this associated-only class as `DetectionSource.Code(code)`; it is not package-root
-    importable. The read-only `code` attribute is the matching `VisionCode`. Values compare equal
-    when their codes match, return `False` when compared with another type, and print as
-    `DetectionSource.Code(code=...)`.
-    """
+    The outline is `thickness` pixels wide, centered on the edges, with rounded corners. With
+    `antialias=True`, its edges are smoothed by blending them with `bg_color`, which should be the
+    color behind the outline, since the display can't be read back.
 
-    code: VisionCode
-    def __init__(self, code: VisionCode, /) -> None:
-        """
-        Creates a source indicating that multiple signatures joined in `code` detected the object.
+    # Raises
 
-        `code` must be a positional-only `VisionCode`.
+    - `TypeError`: If `points` isn't a list or tuple of `(x, y)` tuples, or `color` isn't a `Color`
+    or `LinearGradient`.
+    - `ValueError`: If `points` is empty, a coordinate is outside the 16-bit range, or `thickness`
+    is zero.
+    """
+    ...
 
-        # Raises
+def fill_polygon(
+    points: list[tuple[int, int]] | tuple[tuple[int, int], ...],
+    color: Color | LinearGradient,
+    /,
+    *,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
+    """
+    Fills the polygon through `points`, a list of `(x, y)` tuples.
 
-        - `TypeError`: If `code` is not a `VisionCode`, a keyword argument is supplied, or the argument
-        count is not one.
-        """
-        ...
+    Where edges cross, areas are filled by the even-odd rule, so the middle of a five-pointed star
+    is left empty. With `antialias=True`, the edges are smoothed by blending them with `bg_color`.
 
-class Line:
+    # Raises
+
+    - `TypeError`: If `points` isn't a list or tuple of `(x, y)` tuples, or `color` isn't a `Color`
+    or `LinearGradient`.
+    - `ValueError`: If `points` is empty or a coordinate is outside the 16-bit range.
     """
-    Line detection was used to find a `VisionObject`.
+    ...
 
-    Construct this associated-only class as `DetectionSource.Line()`; it is not package-root
-    importable. Construction always returns the same singleton, which prints as
-    `DetectionSource.Line()`.
+def draw_triangle(
+    x0: int,
+    y0: int,
+    x1: int,
+    y1: int,
+    x2: int,
+    y2: int,
+    color: Color | LinearGradient,
+    /,
+    *,
+    thickness: int = 1,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
     """
-    def __init__(self, /) -> None:
-        """
-        Returns the singleton source indicating that line detection found the object.
+    Draws the outline of the triangle with corners at (`x0`, `y0`), (`x1`, `y1`) and (`x2`, `y2`).
 
-        # Raises
+    `thickness`, `antialias` and `bg_color` work like they do for `display.draw_polygon`.
 
-        - `TypeError`: If any positional or keyword arguments are supplied.
-        """
-        ...
+    # Raises
 
-class WhiteBalance:
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    - `ValueError`: If `thickness` is zero.
     """
-    Vision Sensor white balance mode.
+    ...
 
-    Represents a white balance configuration for the Vision Sensor's camera. `WhiteBalance` is an
-    abstract base and cannot be instantiated directly. Construct its associated-only variants as
-    `WhiteBalance.Auto()`, `WhiteBalance.StartupAuto()`, or `WhiteBalance.Manual(...)`; the variants are
-    not package-root imports.
+def fill_triangle(
+    x0: int,
+    y0: int,
+    x1: int,
+    y1: int,
+    x2: int,
+    y2: int,
+    color: Color | LinearGradient,
+    /,
+    *,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
     """
+    Fills the triangle with corners at (`x0`, `y0`), (`x1`, `y1`) and (`x2`, `y2`).
 
-    Auto: ClassVar[type[Auto]]
-    """The associated Automatic Mode class, constructed as `WhiteBalance.Auto()`."""
-    StartupAuto: ClassVar[type[StartupAuto]]
-    """The associated "Startup" Automatic Mode class, constructed as `WhiteBalance.StartupAuto()`."""
-    Manual: ClassVar[type[Manual]]
-    """The associated Manual Mode class, constructed as `WhiteBalance.Manual(r, g, b)`."""
+    `antialias` and `bg_color` work like they do for `display.fill_polygon`.
 
-    def __init__(self, /) -> None:
-        """
-        Rejects direct construction of the abstract `WhiteBalance` base class.
+    # Raises
 
-        Use one of the associated variant classes instead.
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    """
+    ...
 
-        # Raises
+def draw_arc(
+    x: int,
+    y: int,
+    radius: int,
+    start: float,
+    end: float,
+    color: Color | LinearGradient,
+    /,
+    *,
+    thickness: int = 1,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
+    """
+    Draws part of the outline of the circle centered at `x` and `y`, from the angle `start` to
+    `end`.
 
-        - `TypeError`: Always.
-        """
-        ...
+    Angles are in degrees, measured clockwise from the right, so 90 is straight down. The arc runs
+    clockwise from `start` to `end`, and is a whole circle if `end` is at least 360 degrees past
+    `start`. The ends of the arc are rounded. `thickness`, `antialias` and `bg_color` work like
+    they do for `display.draw_polygon`.
 
-class Auto:
+    # Raises
+
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    - `ValueError`: If an angle isn't finite or `thickness` is zero.
     """
-    Automatic Mode.
+    ...
 
-    The sensor will automatically adjust the camera's white balance, using the brightest part of the
-    image as a white point. This associated-only class is constructed as `WhiteBalance.Auto()` and is
-    not package-root importable. Construction always returns the same singleton, which prints as
-    `WhiteBalance.Auto()`.
+def fill_pie(
+    x: int,
+    y: int,
+    radius: int,
+    start: float,
+    end: float,
+    color: Color | LinearGradient,
+    /,
+    *,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
     """
-    def __init__(self, /) -> None:
-        """
-        Returns the singleton Automatic Mode.
+    Fills a slice of the circle centered at `x` and `y`, from the angle `start` to `end`.
 
-        # Raises
+    Angles work like they do for `display.draw_arc`. `antialias` and `bg_color` work like they do
+    for `display.fill_polygon`.
 
-        - `TypeError`: If any positional or keyword arguments are supplied.
-        """
-        ...
+    # Raises
 
-class StartupAuto:
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    - `ValueError`: If an angle isn't finite.
     """
-    "Startup" Automatic Mode.
+    ...
 
-    The sensor will automatically adjust the camera's white balance, but will only perform this
-    adjustment once on power-on. This associated-only class is constructed as
-    `WhiteBalance.StartupAuto()` and is not package-root importable. Construction always returns the
-    same singleton, which prints as `WhiteBalance.StartupAuto()`.
+def draw_round_rect(
+    x: int,
+    y: int,
+    width: int,
+    height: int,
+    radius: int,
+    color: Color | LinearGradient,
+    /,
+    *,
+    thickness: int = 1,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
     """
-    def __init__(self, /) -> None:
-        """
-        Returns the singleton "Startup" Automatic Mode.
+    Draws the outline of a rectangle with rounded corners.
 
-        # Raises
+    `x` and `y` are the top-left corner, and `width` and `height` are measured in pixels. Corners
+    are rounded with `radius`, which is reduced if it doesn't fit. The outline is drawn inside the
+    rectangle, `thickness` pixels wide. `antialias` and `bg_color` work like they do for
+    `display.draw_polygon`.
 
-        - `TypeError`: If any positional or keyword arguments are supplied.
-        """
-        ...
+    # Raises
 
-class Manual:
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    - `ValueError`: If `thickness` is zero.
     """
-    Manual Mode.
+    ...
 
-    This mode allows for manual control over white balance using an RGB color. This associated-only class is
-    constructed as `WhiteBalance.Manual(r, g, b)` and is not package-root importable. Its read-only
-    integer attributes `r`, `g`, and `b` are the red, green, and blue white-point channels from 0 to
-    255. Values compare by these three channels, return `False` when compared with another type,
-    and print as `WhiteBalance.Manual(r=..., g=..., b=...)`.
+def fill_round_rect(
+    x: int,
+    y: int,
+    width: int,
+    height: int,
+    radius: int,
+    color: Color | LinearGradient,
+    /,
+    *,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
     """
+    Fills a rectangle with rounded corners.
 
-    r: int
-    g: int
-    b: int
-    def __init__(self, r: int, g: int, b: int, /) -> None:
-        """
-        Creates a Manual Mode with RGB channels `r`, `g`, and `b`.
-
-        Each channel is a positional-only integer from 0 to 255.
+    The rectangle is placed like it is for `display.draw_round_rect`. `antialias` and `bg_color`
+    work like they do for `display.fill_polygon`.
 
-        # Raises
+    # Raises
 
-        - `TypeError`: If a channel is not an integer, a keyword argument is supplied, or the argument
-        count is not exactly three.
-        - `ValueError`: If a channel is outside the inclusive range 0 to 255.
-        """
-        ...
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    """
+    ...
 
-def get_capacity() -> float:
+def monotonic_time(*args: Any) -> Any: ...
+def run(coro: Any, /) -> None:
     """
-    Returns the charge capacity of the robot's battery in the range of [0.0, 1.0].
+    Runs coroutine object `coro` on a new event loop until no work remains.
 
-    A value of `0.0` indicates a completely empty battery, while a value of `1.0` indicates a
-    fully-charged battery.
+    The loop also waits for tasks spawned into it and pending `Sleep` objects before returning
+    `None`. The root coroutine's return value is discarded. An exception from a task that nothing
+    observes, including the root coroutine, stops the loop and is propagated to the caller unless
+    an exception handler is set with `EventLoop.set_exception_handler`. A `CancelledError` that ends
+    a cancelled task does not stop the loop.
 
     # Examples
 
     ```python
     from venice import *
 
-    capacity = battery.get_capacity()
-    print(f"Battery at {capacity:.0%} capacity")
+    async def main():
+    print("start")
+    await vasyncio.Sleep(100, MILLIS)
+    print("done")
 
-    if capacity < 0.2:
-    print("Warning: Low battery!")
+    vasyncio.run(main())
     ```
+
+    # Raises
+
+    - `TypeError`: If `coro` is not a coroutine object.
+    - `RuntimeError`: If tasks form a direct or transitive await cycle.
+    - `ValueError`: If a `Sleep` deadline is too large to represent.
     """
     ...
 
-def get_current() -> float:
+def spawn(coro: Any, /, *, name: str | None = None, priority: int = 0) -> Task:
     """
-    Returns the electric current of the robot's battery in amps.
+    Spawns a new asynchronous task that can be controlled with the returned `Task` handle.
 
-    Maximum current draw on the V5 battery is 20 Amps.
+    Call this from code already executing under `vasyncio.run` or `EventLoop.run`. Awaiting the task
+    yields the coroutine's return value whether the task is still running or has already completed.
+
+    The task is named `name` if it is given, or `Task-<n>` otherwise. The name identifies the task
+    when it is printed and in watchdog errors.
+
+    When several tasks are ready to run, the loop resumes the one with the highest `priority`
+    first; see `Task.set_priority`. Tasks default to priority `0`.
 
     # Examples
 
     ```python
     from venice import *
 
-    current = battery.get_current()
+    async def answer():
+    await vasyncio.Sleep(10, MILLIS)
+    return 42
 
-    print(f"Drawing {current} amps")
+    async def main():
+    task = vasyncio.spawn(answer())
+    print(await task)
+
+    vasyncio.run(main())
+    ```
+
+    Run a drive control loop ahead of a logging task:
+
+    ```python
+    from venice import *
+
+    async def drive():
+    ...
+
+    async def log():
+    ...
+
+    async def main():
+    vasyncio.spawn(drive(), name="drive", priority=10)
+    vasyncio.spawn(log(), name="log", priority=-10)
+
+    vasyncio.run(main())
     ```
+
+    # Raises
+
+    - `RuntimeError`: If no event loop is running.
+    - `TypeError`: If `coro` is not a coroutine object, `name` is not a `str`, or `priority` is not
+    an `int`.
     """
     ...
 
-def get_temperature() -> int:
+def supervise(
+    factory: Callable[[], Any],
+    /,
+    *,
+    name: str | None = None,
+    priority: int = 0,
+    max_restarts: int | None = None,
+) -> Task:
     """
-    Returns the internal temperature of the robot's battery in degrees Celsius.
+    Spawns a supervised task that is restarted whenever it fails.
+
+    `factory` is called with no arguments to create the task's coroutine, so it is usually an
+    `async def` function. When the coroutine raises an exception other than `CancelledError`, the
+    exception is reported to the loop's exception handler, or printed if there is none, and
+    `factory` is called again to create a fresh coroutine that continues as the same task. After
+    `max_restarts` restarts, the next failure ends the task like any other exception; `None` allows
+    unlimited restarts. Cancelling a supervised task stops it for good.
+
+    Tasks awaiting a supervised task keep waiting across restarts, and receive its return value
+    once a coroutine returns. `name` and `priority` work as they do for `vasyncio.spawn`.
 
     # Examples
 
     ```python
     from venice import *
 
-    temp = battery.get_temperature()
-    print(f"Battery temperature: {temp}°C")
+    async def telemetry():
+    while True:
+    ...  # may raise if the radio disconnects
+    await vasyncio.Sleep(100, MILLIS)
 
-    # Check if battery is too hot
-    if temp > 45:
-    print("Warning: Battery temperature critical!")
+    async def main():
+    vasyncio.supervise(telemetry, name="telemetry", max_restarts=5)
+    # ... drive ...
+
+    vasyncio.run(main())
     ```
+
+    # Raises
+
+    - `RuntimeError`: If no event loop is running.
+    - `TypeError`: If `factory` isn't callable, doesn't return a coroutine object, `name` is not a
+    `str`, or `priority` is not an `int`.
+    - `ValueError`: If `max_restarts` is negative.
     """
     ...
 
-def get_voltage() -> float:
+def get_running_loop() -> EventLoop | None:
     """
-    Returns the robot's battery voltage in volts.
+    Returns the event loop currently executing tasks, or `None` outside `vasyncio.run` or
+    `EventLoop.run`.
+    """
+    ...
 
-    Nominal battery voltage on the V5 brain is 12.8V.
+def current_task() -> Task | None:
+    """
+    Returns the task that is currently running, or `None` outside a task.
 
     # Examples
 
     ```python
     from venice import *
 
-    voltage = battery.get_voltage()
-    print("Battery voltage: {voltage} V")
+    async def main():
+    print("running in", vasyncio.current_task().get_name())
+
+    vasyncio.run(main())
     ```
     """
     ...
 
-def draw_pixel(x: int, y: int, color: Color) -> None:
+def all_tasks() -> list[Task]:
     """
-    Draws a filled pixel to the display with the specified `color`.
+    Returns a list of the tasks on the running event loop that have not finished yet.
 
-    `x` and `y` are pixel coordinates. The writable display is 480 pixels wide by 240 pixels high, with
-    its origin at the top-left.
-    """
-    ...
+    Together with `Task.stats`, this helps find the task that is stalling the loop.
 
-def draw_line(
-    start_x: int, start_y: int, end_x: int, end_y: int, color: Color, /
-) -> None:
-    """
-    Draws a line to the display with the specified `color`.
+    # Raises
 
-    `start_x` and `start_y` are the start point of the line; `end_x` and `end_y` are the end point. The
-    line width is one pixel. Coordinates are measured from the display's top-left, and both supplied
-    endpoints are passed directly to the display SDK.
+    - `RuntimeError`: If no event loop is running.
     """
     ...
 
-def draw_circle(x: int, y: int, radius: int, color: Color, /) -> None:
-    """
-    Draws a outlined circle to the display with the specified `color`.
-
-    `x` and `y` are the center point of the circle, and `radius` is its radius in pixels. Circles are
-    not antialiased.
+def reserve(device: Any, /) -> Lock:
     """
-    ...
+    Returns the lock that reserves `device` for the task holding it.
 
-def fill_circle(x: int, y: int, radius: int, color: Color, /) -> None:
-    """
-    Draws a filled circle to the display with the specified `color`.
+    Every call with the same device object on the running loop returns the same `Lock`, so tasks
+    that each take the reservation with `async with` never command the device at the same time. A
+    task that enters the block while another task holds it waits until the other task leaves its
+    block, and the reservation is released when the block exits, even if it raises or the task is
+    cancelled.
 
-    `x` and `y` are the center point of the circle, and `radius` is its radius in pixels. Circles are
-    not antialiased.
-    """
-    ...
+    Reservations are only respected by code that takes them; they do not stop a task from using the
+    device directly. Reservations are keyed on the device object, so every task should share one
+    object for each device rather than creating its own.
 
-def draw_rect(x: int, y: int, width: int, height: int, color: Color, /) -> None:
-    """
-    Draws an outlined rectangular region of the display with the specified `color`.
+    # Raises
 
-    `x` and `y` are the top-left coordinate of the rectangle. `width` and `height` are its dimensions
-    in pixels. The bottom right point is not included in the shape's bounds. Thus, the area of the
-    drawn rectangle is `width * height` pixels.
+    - `RuntimeError`: If no event loop is running.
 
     # Examples
 
     ```python
     from venice import *
 
-    # Draw a 20x20 rectangle which has a top-left point at (30, 40).
-    display.draw_rect(30, 40, 20, 20, Color.WHITE)
-    ```
+    intake = Motor(1)
 
-    # Raises
+    async def score():
+        async with vasyncio.reserve(intake):
+            intake.set_voltage(12.0)
+            await vasyncio.Sleep(500, MILLIS)
+            intake.set_voltage(0.0)
 
-    - `ValueError`: If a coordinate, dimension, or resulting endpoint is outside its supported
-    integer range.
+    async def clear_jam():
+        async with vasyncio.reserve(intake):
+            intake.set_voltage(-12.0)
+            await vasyncio.Sleep(200, MILLIS)
+            intake.set_voltage(0.0)
+    ```
     """
     ...
 
-def fill_rect(x: int, y: int, width: int, height: int, color: Color, /) -> None:
+def sample(source: Callable[[], Any], period: float, unit: TimeUnit, /) -> SampleStream:
     """
-    Draws a filled rectangular region of the display with the specified `color`.
+    Returns an asynchronous iterator that calls `source()` every `period`, measured in `unit`.
 
-    `x` and `y` are the top-left coordinate of the rectangle. `width` and `height` are its dimensions
-    in pixels. The bottom right point is not included in the shape's bounds. Thus, the area of the
-    drawn rectangle is `width * height` pixels.
+    Each iteration of an `async for` loop over the stream waits for the next period to elapse and
+    then produces the value `source` returns. The awaiting task is parked between samples, so other
+    tasks keep running. See `SampleStream` for how late samples are handled.
 
     # Examples
 
+    Print a rotation sensor's position 20 times per second:
+
     ```python
     from venice import *
 
-    # Draw a 20x20 rectangle which has a top-left point at (30, 40).
-    display.fill_rect(30, 40, 20, 20, Color.WHITE)
-    ```
-
-    # Raises
-
-    - `ValueError`: If a coordinate, dimension, or resulting endpoint is outside its supported
-    integer range.
-    """
-    ...
+    sensor = RotationSensor(1)
 
-def draw_buffer(x: int, y: int, width: int, height: int, buffer: Any, /) -> None:
-    """
-    Draws a buffer of pixels to a specified region of the display.
+    async def main():
+    async for position in vasyncio.sample(lambda: sensor.get_position(DEGREES), 50, MILLIS):
+    print(position)
 
-    This function copies the pixels in `buffer` to the specified region of the display. `x` and `y`
-    are the region's top-left corner, and `width` and `height` are measured in pixels. `buffer` must be
-    a readable, suitably aligned buffer containing exactly `width * height` packed four-byte
-    `0xRRGGBB` color values in row-major order.
+    vasyncio.run(main())
+    ```
 
     # Raises
 
-    - `TypeError`: If `buffer` doesn't support the readable buffer protocol.
-    - `ValueError`: If the buffer is unaligned, its readable byte length isn't a multiple of four,
-    its pixel count doesn't equal `width * height`, or the region exceeds the supported coordinate
-    range.
+    - `TypeError`: If `source` isn't callable.
+    - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
     """
     ...
 
-def draw_text(
-    text: str,
-    x: int,
-    y: int,
+def until(
+    predicate: Callable[[], Any],
     /,
     *,
-    font_size: FontSize = FontSize.MEDIUM,
-    font_family: FontFamily = FontFamily.PROPORTIONAL,
-    color: Color = Color.WHITE,
-    bg_color: Color | None = None,
-) -> None:
+    poll: float = 10,
+    unit: TimeUnit = TimeUnit.MILLIS,
+) -> UntilFuture:
     """
-    Draws a line of `text` with the specified `color` and `bg_color` to the display.
+    Waits until `predicate()` returns a truthy value.
 
-    `x` and `y` are the top-left corner coordinates of the text. `font_size` defaults to
-    `FontSize.MEDIUM`, `font_family` defaults to `FontFamily.PROPORTIONAL`, and `color` defaults to
-    `Color.WHITE`. Omitting `bg_color` or passing it as `None` gives a transparent background;
-    supply a `Color` to paint it.
+    `predicate` is called with no arguments when the returned future is awaited, and then every
+    `poll` interval, measured in `unit`, until it returns a truthy value. Awaiting the future
+    returns that value. The awaiting task is parked between calls, so other tasks keep running.
+
+    `predicate` should return quickly, since the event loop cannot run other tasks while it runs.
 
     # Examples
 
     ```python
     from venice import *
 
-    # Write red text with a blue background to the display.
-    display.draw_text(
-    "Hello, World!",
-    10,
-    10,
-    font_size=display.FontSize.MEDIUM,
-    font_family=display.FontFamily.MONOSPACE,
-    color=Color(255, 0, 0),
-    bg_color=Color(0, 0, 255),
-    )
-    ```
-
-    # Raises
-
-    - `ValueError`: If `text` contains a NUL character.
-    """
-    ...
+    optical = OpticalSensor(1)
+    intake = Motor(2)
 
-def print(*values: object, sep: str = " ", end: str = "\n") -> None:
-    """
-    Writes `values` to the Brain display's scrolling text area.
+    async def main():
+    intake.set_voltage(12)
+    await vasyncio.until(lambda: optical.get_proximity() > 0.8, poll=20)
+    intake.brake(BrakeMode.HOLD)
 
-    Values are converted to strings and joined with `sep`, which defaults to one space, then `end` is
-    appended, which defaults to a newline. Text uses the default white font, wraps after 48 characters,
-    and scrolls upward after 12 visible lines.
+    vasyncio.run(main())
+    ```
 
     # Raises
 
-    - `TypeError`: If a value isn't printable or if `sep` or `end` isn't a string.
+    - `TypeError`: If `predicate` isn't callable.
+    - `ValueError`: If `poll` is negative, non-finite, or too large to represent.
     """
     ...
 
-def scroll(start: int, offset: int) -> None:
+def gather(*aws: Any) -> GatherFuture:
     """
-    Scrolls the pixels at or below the specified y-coordinate `start`.
+    Runs awaitables concurrently and returns an awaitable for all of their results.
 
-    This function y-offsets the pixels in the display buffer which are at or below `start` by `offset`
-    pixels. Positive values move the pixels upwards, and pixels that are moved out of the scroll region
-    are discarded. Empty spaces are then filled with the display's background color.
-    """
-    ...
+    Each awaitable in `aws` may be a coroutine object, which is spawned onto the running event loop
+    as a `Task`, or an existing `Task`. Awaiting the returned object waits for every task to finish
+    and returns a list of their return values in the same order as `aws`.
 
-def scroll_region(x: int, y: int, width: int, height: int, offset: int, /) -> None:
-    """
-    Scrolls a region of the display.
+    If any task fails, its exception, such as the `CancelledError` of a cancelled task, is raised in
+    the awaiting task as soon as it happens. The remaining tasks are not cancelled and keep running.
 
-    This function y-offsets the pixels in the display buffer which are contained in the specified
-    scroll region by `offset` pixels. `x` and `y` are the region's top-left corner; `width` and `height`
-    are measured in pixels. Positive offset values move the pixels upwards, and pixels that are moved
-    out of the scroll region are discarded. Empty spaces are then filled with the display's background
-    color.
+    # Examples
 
-    # Raises
+    ```python
+    from venice import *
 
-    - `ValueError`: If `x`, `y`, or `offset` is outside the supported signed 16-bit range, `width` or
-    `height` is outside the unsigned 16-bit range, or the region's endpoint exceeds the supported
-    coordinate range.
-    """
-    ...
+    async def double(x):
+    await vasyncio.Sleep(x, MILLIS)
+    return x * 2
 
-def set_render_mode(render_mode: RenderMode) -> None:
-    """
-    Sets `render_mode` for the display.
+    async def main():
+    results = await vasyncio.gather(double(10), double(20), double(30))
+    assert results == [20, 40, 60]
 
-    For more information on render modes, see `display.RenderMode`.
-    """
-    ...
+    vasyncio.run(main())
+    ```
 
-def render() -> None:
-    """
-    Flushes the display's double buffer if it is enabled.
+    # Raises
 
-    This is a no-op with `RenderMode.IMMEDIATE`, but is necessary for anything to be displayed when
-    using `RenderMode.DOUBLE_BUFFERED`.
+    - `RuntimeError`: If a coroutine is passed and no event loop is running.
+    - `TypeError`: If an awaitable is neither a coroutine object nor a `Task`.
     """
     ...
 
-def erase(color: Color) -> None:
-    """Clears the entire 480-by-240-pixel writable display, filling it with the specified `color`."""
-    ...
-
-def get_touch_status() -> TouchEvent:
-    """
-    Returns the last recorded state of the display's touchscreen as a `TouchEvent`.
-
-    See `display.TouchEvent` for more information.
+def wait(
+    tasks: list[Any] | tuple[Any, ...],
+    /,
+    *,
+    timeout: float | None = None,
+    unit: TimeUnit = TimeUnit.SECOND,
+    return_when: ReturnWhen = ReturnWhen.ALL_COMPLETED,
+) -> WaitFuture:
     """
-    ...
-
-def is_now_pressed() -> bool:
-    """Returns whether the touchscreen's last recorded touch has just been pressed."""
-    ...
-
-def is_pressed() -> bool:
-    """Returns whether the touchscreen's last recorded touch has been pressed or is being held."""
-    ...
-
-def is_released() -> bool:
-    """Returns whether the touchscreen's last recorded touch has been released."""
-    ...
-
-def is_held() -> bool:
-    """Returns whether the touchscreen's last recorded touch is still being held."""
-    ...
+    Waits for the tasks in `tasks` until the `return_when` condition is met or `timeout` elapses.
 
-def monotonic_time(*args: Any) -> Any: ...
-def run(coro: Any, /) -> None:
-    """
-    Runs coroutine object `coro` on a new event loop until no work remains.
+    `tasks` is a list or tuple of coroutine objects or `Task`s; coroutines are spawned onto the
+    running event loop. `timeout` is measured in `unit` and starts when the returned object is first
+    awaited. A `timeout` of `None` waits without a time limit.
 
-    The loop also waits for tasks spawned into it and pending `Sleep` objects before returning
-    `None`. The root coroutine's return value is discarded, and an exception from any task stops
-    the loop and is propagated to the caller.
+    Awaiting the returned object produces a `(done, pending)` tuple of lists of tasks. Unlike
+    `wait_for`, tasks that are still pending when the timeout elapses are neither cancelled nor
+    reported as an error.
 
     # Examples
 
+    Drive until a limit switch is pressed or two seconds pass, whichever happens first:
+
     ```python
     from venice import *
 
+    switch = AdiDigitalIn("A")
+
+    async def wait_for_switch():
+    while not switch.is_high():
+    await vasyncio.Sleep(10, MILLIS)
+
     async def main():
-    print("start")
-    await vasyncio.Sleep(100, MILLIS)
-    print("done")
+    done, pending = await vasyncio.wait(
+    [wait_for_switch()],
+    timeout=2,
+    return_when=vasyncio.FIRST_COMPLETED,
+    )
+
+    if pending:
+    print("switch was not pressed in time")
+    for task in pending:
+    task.cancel()
 
     vasyncio.run(main())
     ```
 
     # Raises
 
-    - `TypeError`: If `coro` is not a coroutine object.
-    - `RuntimeError`: If tasks form a direct or transitive await cycle.
-    - `ValueError`: If a `Sleep` deadline is too large to represent.
+    - `RuntimeError`: If a coroutine is passed and no event loop is running.
+    - `TypeError`: If `tasks` isn't a list or tuple, or if an item is neither a coroutine object nor
+    a `Task`.
+    - `ValueError`: If `timeout` is negative, non-finite, or too large to represent.
     """
     ...
 
-def spawn(coro: Any, /) -> Task:
+def wait_for(
+    aw: Any, timeout: float | None, unit: TimeUnit = TimeUnit.SECOND, /
+) -> WaitForFuture:
     """
-    Spawns a new asynchronous task that can be controlled with the returned `Task` handle.
+    Waits for `aw` to finish, cancelling it if `timeout` elapses first.
 
-    Call this from code already executing under `vasyncio.run` or `EventLoop.run`. Awaiting the task
-    yields the coroutine's return value whether the task is still running or has already completed.
+    `aw` may be a coroutine object, which is spawned onto the running event loop, or a `Task`.
+    `timeout` is measured in `unit` and starts when the returned object is first awaited. A
+    `timeout` of `None` waits without a time limit.
+
+    Awaiting the returned object produces the task's return value. If the timeout elapses first,
+    the task is cancelled, the awaiting task waits until the cancellation has been delivered so that
+    the task's `finally` blocks have run, and then `TimeoutError` is raised.
 
     # Examples
 
     ```python
     from venice import *
 
-    async def answer():
+    motor = Motor(1)
+    switch = AdiDigitalIn("A")
+
+    async def drive_until_switch():
+    try:
+    motor.set_voltage(6)
+    while not switch.is_high():
     await vasyncio.Sleep(10, MILLIS)
-    return 42
+    finally:
+    motor.brake(BrakeMode.BRAKE)
 
     async def main():
-    task = vasyncio.spawn(answer())
-    print(await task)
+    try:
+    await vasyncio.wait_for(drive_until_switch(), 2, SECOND)
+    except vasyncio.TimeoutError:
+    print("gave up after two seconds")
 
     vasyncio.run(main())
     ```
 
     # Raises
 
-    - `RuntimeError`: If no event loop is running.
-    - `TypeError`: If `coro` is not a coroutine object.
-    """
-    ...
-
-def get_running_loop() -> EventLoop | None:
-    """
-    Returns the event loop currently executing tasks, or `None` outside `vasyncio.run` or
-    `EventLoop.run`.
+    - `RuntimeError`: If a coroutine is passed and no event loop is running.
+    - `TypeError`: If `aw` is neither a coroutine object nor a `Task`.
+    - `ValueError`: If `timeout` is negative, non-finite, or too large to represent.
     """
     ...
@@ -19,6 +19,8 @@ from __future__ import annotations
 
 from typing import Any, Callable, ClassVar, overload
 
+from . import vasyncio
+
 class AdiAccelerometerSensitivity:
     """The jumper setting of the accelerometer."""
 
@@ -2675,6 +2677,41 @@ class Controller:
         """
         ...
 
+    def states(self) -> vasyncio.SampleStream:
+        """
+        Returns an asynchronous iterator over the controller's state.
+
+        Each iteration of an `async for` loop waits for the next controller update, which VEXos
+        delivers every `Controller.UPDATE_INTERVAL_MS` milliseconds, then produces the same
+        `ControllerState` that `Controller.read_state` would return.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        controller = Controller()
+        left = Motor(1)
+        right = Motor(2, Direction.REVERSE)
+
+        async def main():
+        async for state in controller.states():
+        left.set_voltage(state.left_stick.y * 12)
+        right.set_voltage(state.right_stick.y * 12)
+
+        vasyncio.run(main())
+        ```
+
+        # Raises
+
+        While iterating:
+
+        - `ValueError`: If the controller binding has been freed.
+        - `DeviceError`: If access to controller data is restricted by competition control, or the
+        controller is not connected.
+        """
+        ...
+
     def get_connection(self, /) -> ControllerConnection:
         """
         Returns the controller's connection type as a root-importable `ControllerConnection`.
@@ -3286,6 +3323,41 @@ class DistanceSensor:
         """
         ...
 
+    def wait_until_within(self, distance: float) -> vasyncio.UntilFuture:
+        """
+        Waits until an object is detected no more than `distance` millimeters from the sensor.
+
+        Awaiting the returned `UntilFuture` returns `None`.
+
+        # Examples
+
+        Drive forward until a wall is 100mm away:
+
+        ```python
+        from venice import *
+
+        sensor = DistanceSensor(1)
+        left = Motor(2)
+        right = Motor(3, Direction.REVERSE)
+
+        async def main():
+        left.set_voltage(6)
+        right.set_voltage(6)
+        await sensor.wait_until_within(100)
+        left.brake(BrakeMode.BRAKE)
+        right.brake(BrakeMode.BRAKE)
+
+        vasyncio.run(main())
+        ```
+
+        # Raises
+
+        - `ValueError`: If the sensor binding is freed while awaiting.
+        - `DeviceError`: If, while awaiting, no sensor is connected to the port, or it has an
+        unknown status code.
+        """
+        ...
+
     def free(self) -> None:
         """
         Releases this sensor and frees its Smart Port lock.
@@ -5130,227 +5202,1870 @@ class Point2:
         """
         ...
 
-class BrakeMode:
+class Pose2:
     """
-    Determines the behavior a motor should use when braking with `Motor.brake`.
+    A robot's position and heading on the field.
 
-    `BrakeMode` is root-importable and isn't constructed directly; use one of its singleton
-    constants. Each constant has a readable representation such as `BrakeMode.BRAKE`.
-    """
+    `x` and `y` are mutable `float` attributes whose unit depends on the API that produced the pose;
+    `Odometry` uses the unit of its tracking wheel diameters and `Pose2.from_gps` uses metres.
+    `heading` is a mutable `float` attribute in radians, measured counterclockwise from the positive
+    x axis. Use `Pose2.get_heading` to read it in another unit. Deleting an attribute resets it to
+    `0.0`.
 
-    BRAKE: ClassVar[BrakeMode]
-    """Motor uses regenerative braking to slow down faster."""
-    COAST: ClassVar[BrakeMode]
-    """Motor never brakes."""
-    HOLD: ClassVar[BrakeMode]
-    """Motor exerts force holding itself in the same position."""
+    Adding a `Transform2` to a pose moves it by that transform in the pose's own frame, and
+    subtracting two poses returns the `Transform2` between them, so `a + (b - a) == b` up to
+    rounding. Poses support exact equality, return `False` when compared with another type, and
+    have a readable `Pose2(x=..., y=..., heading=...)` representation.
 
-class Direction:
-    """
-    A rotational direction.
+    # Examples
 
-    `Direction` is root-importable and isn't constructed directly; use `Direction.FORWARD` or
-    `Direction.REVERSE`. Applying Python's `~` operator returns the opposite singleton, so
-    `~Direction.FORWARD` is `Direction.REVERSE`. Each value has a readable representation such as
-    `Direction.FORWARD`.
+    ```python
+    from venice import *
+
+    start = Pose2(0, 0, 90, DEGREES)
+    # Drive 24 units forward, then turn 90 degrees left
+    end = start + Transform2(24, 0, 90, DEGREES)
+    print(end.x, end.y, end.get_heading(DEGREES))
+    ```
     """
 
-    FORWARD: ClassVar[Direction]
-    """Rotates in the forward direction."""
-    REVERSE: ClassVar[Direction]
-    """Rotates in the reverse direction."""
+    x: float
+    y: float
+    heading: float
+    def __init__(
+        self,
+        x: float = 0.0,
+        y: float = 0.0,
+        heading: float = 0.0,
+        unit: RotationUnit = RotationUnit.RADIANS,
+        /,
+    ) -> None:
+        """
+        Creates a pose at (`x`, `y`) with a heading of `heading`, measured in `unit`.
 
-class Gearset:
-    """
-    Internal gearset used by VEX Smart motors.
+        Every argument is positional-only, and the coordinates and heading default to `0.0`.
 
-    The selected value must match an 11W motor's physical cartridge so position and velocity are
-    scaled correctly. EXP motors have a fixed 200 RPM gearset reported as `Gearset.GREEN`.
-    `Gearset` is root-importable and isn't constructed directly; use one of its singleton constants.
-    Values have readable representations such as `Gearset.GREEN`.
-    """
+        # Raises
 
-    RED: ClassVar[Gearset]
-    """36:1 gear ratio with a rated maximum speed of 100 RPM."""
-    GREEN: ClassVar[Gearset]
-    """18:1 gear ratio with a rated maximum speed of 200 RPM."""
-    BLUE: ClassVar[Gearset]
-    """6:1 gear ratio with a rated maximum speed of 600 RPM."""
+        - `TypeError`: If a number is not numeric, `unit` isn't a `RotationUnit`, a keyword argument
+        is supplied, or more than four positional arguments are given.
 
-class Motor:
-    """
-    A motor plugged into a Smart Port.
+        # Examples
 
-    This class provides abstractions for interacting with VEX Smart Motors, supporting both the 11W
-    and 5.5W variants.
+        ```python
+        from venice import *
 
-    # Overview
+        pose = Pose2(12.0, -6.0, 45, DEGREES)
+        ```
+        """
+        ...
 
-    The V5 Smart Motors come in two variants: [an 11W model](https://www.vexrobotics.com/276-4840.html),
-    with interchangeable gear cartridges and [a 5.5W model](https://www.vexrobotics.com/276-4842.html),
-    with a fixed gearing. The 11W motor supports three cartridge options, which will gear the motor
-    down from its base RPM of 3600: a red cartridge providing 100 RPM output, a green cartridge for
-    200 RPM, and a blue cartridge for 600 RPM. The 5.5W motor comes with a non-interchangeable 200
-    RPM gear cartridge.
+    @staticmethod
+    def from_gps(gps: GpsSensor, /) -> Pose2:
+        """
+        Returns the pose reported by `gps`, in metres.
 
-    Smart Motors feature several integrated sensors, including an encoder for measuring the velocity
-    and position of the motor, a temperature sensor for detecting overheats, and sensors for
-    measuring output voltage, current, and efficiency.
+        The GPS heading, which is measured clockwise with 0 pointing along the positive y axis, is
+        converted to a counterclockwise heading from the positive x axis.
 
-    Communication between a Smart motor and the V5 Brain occur at two different intervals. While
-    the motor communicates with the Brain every 5 milliseconds (and commands can be written to the
-    motor every 5mS), the Brain only reads data from the motor every 10mS. This effectively places
-    the date *write* interval at 5mS and the data *read* interval at 10mS.
+        # Raises
 
-    More in-depth specs for the 11W motor can be found [here](https://kb.vex.com/hc/en-us/articles/360060929971-Understanding-V5-Smart-Motors).
+        - `DeviceError`: If the sensor can't be read.
+        """
+        ...
 
-    # Current Limitations
+    def get_heading(self, unit: RotationUnit) -> float:
+        """Returns the heading measured in `unit`."""
+        ...
 
-    There are some cases where VEXos or the motor itself may decide to limit output current:
+    def transform_by(self, transform: Transform2) -> Pose2:
+        """Returns this pose moved by `transform` in its own frame. Equivalent to `self + transform`."""
+        ...
 
-    - **Stall Prevention**: The stall current on 11W motors is limited to 2.5A. This limitation
-    eliminates the need for automatic resetting fuses (PTC devices) in the motor, which can
-    disrupt operation. By restricting the stall current to 2.5A, the motor effectively avoids
-    undesirable performance dips and ensures that users do not inadvertently cause stall
-    situations.
+    def relative_to(self, origin: Pose2) -> Pose2:
+        """
+        Returns this pose as seen from `origin`, as if `origin` were at (0, 0) facing along the
+        positive x axis.
+        """
+        ...
 
-    - **Motor Count**: Robots that use 8 or fewer 11W motors will have the aforementioned current limit
-    of 2.5A set for each motor. Robots using more than 8 motors, will have a lower default current limit
-    per-motor than 2.5A. This limit is determined in VEXos by a calculation accounting for the number of
-    motors plugged in, and the user's manually set current limits using `Motor.set_current_limit`. For
-    more information regarding the current limiting behavior of VEXos, see [this forum post](https://www.vexforum.com/t/how-does-the-decreased-current-affect-the-robot-when-using-more-than-8-motors/72650/4).
+    def interpolate(self, other: Pose2, t: float) -> Pose2:
+        """
+        Returns the pose a fraction `t` of the way from this pose to `other`.
 
-    - **Temperature Management**: Motors have an onboard sensor for measuring internal temperature.
-    If the motor determines that it is overheating, it will throttle its output current and warn
-    the user.
+        The position is interpolated in a straight line and the heading turns the short way around.
+        `t` is not clamped, so values outside [0, 1] extrapolate.
+        """
+        ...
 
-    # Motor Control
+    def distance_to(self, target: Pose2 | Point2, /) -> float:
+        """
+        Returns the straight-line distance from this pose to `target`.
 
-    Each motor contains a sophisticated control system built around a Cortex M0+ microcontroller.
-    The microcontroller continuously monitors position, speed, direction, voltage, current, and
-    temperature through integrated sensors.
+        # Raises
 
-    The onboard motor firmware implements a set of pre-tuned PID (Proportional-Integral-Derivative)
-    controllers operating on a 10-millisecond cycle for position and velocity control. Motors also
-    have braking functionality for holding a specific position under load.
+        - `TypeError`: If `target` isn't a `Pose2` or `Point2`.
+        """
+        ...
+
+    def angle_to(self, target: Pose2 | Point2, unit: RotationUnit, /) -> float:
+        """
+        Returns how far this pose must turn to face `target`, measured in `unit`.
+
+        The angle is counterclockwise positive and wrapped to the half-open range of -180 to 180
+        degrees.
+
+        # Raises
+
+        - `TypeError`: If `target` isn't a `Pose2` or `Point2`.
+        """
+        ...
+
+class Transform2:
     """
+    A rigid motion in the plane: a translation by (`x`, `y`) followed by a turn of `rotation`.
 
-    is_exp: bool
-    is_v5: bool
-    max_voltage: float
-    motor_type: MotorType
-    V5_MAX_VOLTAGE: ClassVar[float]
-    """The maximum voltage value that can be sent to a V5 `Motor`."""
-    EXP_MAX_VOLTAGE: ClassVar[float]
-    """The maximum voltage value that can be sent to an EXP `Motor`."""
-    WRITE_INTERVAL_MS: ClassVar[int]
-    """The interval at which the Brain will send new packets to a `Motor`, in milliseconds."""
+    The translation is measured in the frame of whatever the transform is applied to, so
+    `Transform2(1, 0, 0)` always means one unit forward. `x` and `y` are mutable `float` attributes,
+    and `rotation` is a mutable `float` attribute in radians, counterclockwise positive. Use
+    `Transform2.get_rotation` to read it in another unit. Deleting an attribute resets it to `0.0`.
 
-    @staticmethod
-    def new_v5(
-        port: int,
-        direction: Direction = Direction.FORWARD,
-        gearset: Gearset = Gearset.GREEN,
+    Adding two transforms composes them, applying the left one first. Transforms support exact
+    equality, return `False` when compared with another type, and have a readable
+    `Transform2(x=..., y=..., rotation=...)` representation.
+    """
+
+    x: float
+    y: float
+    rotation: float
+    def __init__(
+        self,
+        x: float = 0.0,
+        y: float = 0.0,
+        rotation: float = 0.0,
+        unit: RotationUnit = RotationUnit.RADIANS,
         /,
-    ) -> Motor: ...
-    @staticmethod
-    def new_exp(port: int, direction: Direction = Direction.FORWARD, /) -> Motor:
+    ) -> None:
         """
-        Creates a new 5.5W (EXP) Smart Motor.
-
-        See `Motor()` or `Motor.new_v5` to create a 11W (V5) Smart Motor.
+        Creates a transform that moves by (`x`, `y`) and then turns by `rotation`, measured in
+        `unit`.
 
-        # Examples
+        Every argument is positional-only, and the translation and rotation default to `0.0`.
 
-        ```python
-        from venice import *
+        # Raises
 
-        motor = Motor(1)
-        assert motor.is_exp
-        assert motor.max_voltage == Motor.EXP_MAX_VOLTAGE
-        ```
+        - `TypeError`: If a number is not numeric, `unit` isn't a `RotationUnit`, a keyword argument
+        is supplied, or more than four positional arguments are given.
         """
         ...
 
+    def get_rotation(self, unit: RotationUnit) -> float:
+        """Returns the rotation measured in `unit`."""
+        ...
+
+    def inverse(self) -> Transform2:
+        """Returns the transform that undoes this one, so that `t + t.inverse()` is the identity."""
+        ...
+
+class PidController:
+    """
+    A PID (proportional-integral-derivative) controller with feedforward.
+
+    Each call to `PidController.update` compares a setpoint with a measurement and returns the
+    output that drives the measurement towards the setpoint, such as a motor voltage. The output is
+    the sum of:
+
+    - a proportional term, `kp * (setpoint_weight * setpoint - measurement)`;
+    - an integral term, `ki` times the accumulated error, limited to `integral_limit`;
+    - a derivative term, `kd` times the rate of change of
+    `derivative_setpoint_weight * setpoint - measurement`, smoothed by a low-pass filter with a
+    time constant of `derivative_filter` seconds;
+    - a feedforward term, `ks * sign(velocity) + kv * velocity + ka * acceleration`, where
+    `velocity` and `acceleration` describe how the setpoint is moving.
+
+    The output is then limited to `output_limit`. While the output is limited, the integral term
+    stops accumulating in the direction that would push it further past the limit.
+
+    Setpoint weights below `1.0` soften the response to setpoint changes without affecting how
+    disturbances are rejected. A `derivative_setpoint_weight` of `0.0` is a common choice to avoid a
+    spike in the output when the setpoint changes suddenly.
+
+    Gains are per second: `dt` passed to `PidController.update` is converted to seconds, `ki` is
+    multiplied by the error integrated over seconds, and `kd` by the error's rate of change per
+    second. The gains `kp`, `ki`, `kd`, `ks`, `kv` and `ka` can be read and changed as attributes.
+
+    # Continuous input
+
+    If `continuous` is a `RotationUnit`, setpoints and measurements are treated as angles in that
+    unit that wrap around every turn. The controller then always takes the shortest way around, so
+    a setpoint of 350 degrees and a measurement of 10 degrees give an error of -20 degrees rather
+    than 340 degrees.
+    """
+
+    kp: float
+    ki: float
+    kd: float
+    ks: float
+    kv: float
+    ka: float
     def __init__(
         self,
-        port: int,
-        direction: Direction = Direction.FORWARD,
-        gearset: Gearset = Gearset.GREEN,
+        kp: float,
+        ki: float = 0.0,
+        kd: float = 0.0,
         /,
+        *,
+        ks: float = 0.0,
+        kv: float = 0.0,
+        ka: float = 0.0,
+        integral_limit: float | None = None,
+        output_limit: float | None = None,
+        derivative_filter: float = 0.0,
+        setpoint_weight: float = 1.0,
+        derivative_setpoint_weight: float = 1.0,
+        continuous: RotationUnit | None = None,
     ) -> None:
         """
-        Creates a new 11W (V5) Smart Motor.
+        Creates a PID controller with the given gains.
 
-        See `Motor.new_exp` to create a 5.5W (EXP) Smart Motor.
+        All other settings are keyword-only and default to a plain PID controller: no feedforward,
+        no limits, no derivative filtering, full setpoint weights and discontinuous input.
+
+        # Raises
+
+        - `TypeError`: If an argument has the wrong type or an unknown keyword argument is given.
+        - `ValueError`: If `integral_limit`, `output_limit` or `derivative_filter` is negative.
 
         # Examples
 
+        Turn a drivetrain to a heading with an inertial sensor:
+
         ```python
         from venice import *
 
-        motor = Motor(1)
-        assert motor.is_v5
-        assert motor.max_voltage == Motor.V5_MAX_VOLTAGE
+        imu = InertialSensor(1)
+        left = MotorGroup([2, 3])
+        right = MotorGroup([(4, Direction.REVERSE), (5, Direction.REVERSE)])
+
+        pid = PidController(0.3, 0.01, 0.02, output_limit=12, continuous=DEGREES)
+
+        async def main():
+        while True:
+        voltage = pid.update(90, imu.get_heading(DEGREES), 10, unit=MILLIS)
+        left.set_voltage(voltage)
+        right.set_voltage(-voltage)
+        await vasyncio.Sleep(10, MILLIS)
+
+        vasyncio.run(main())
         ```
         """
         ...
 
-    def set_voltage(self, volts: float) -> None:
+    def update(
+        self,
+        setpoint: float,
+        measurement: float,
+        dt: float,
+        /,
+        *,
+        unit: TimeUnit = TimeUnit.SECOND,
+        velocity: float = 0.0,
+        acceleration: float = 0.0,
+    ) -> float:
         """
-        Sets the motor's output voltage.
+        Returns the output for a new `measurement` of the system, `dt` after the previous update.
 
-        This voltage value spans from -12 (fully spinning reverse) to +12 (fully spinning forwards)
-        volts, and controls the raw output of the motor.
+        `dt` is measured in `unit` and should be the time since the previous call to `update`; it is
+        used to integrate and differentiate the error. `velocity` and `acceleration` are the rate of
+        change of the setpoint per second and per second squared, used for feedforward. They can be
+        left at zero when the setpoint is constant.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        - `ValueError`: If `dt` is not positive or is non-finite.
 
         # Examples
 
-        Give the motor full power:
+        Hold an arm at 45 degrees:
 
         ```python
         from venice import *
 
-        v5_motor = Motor(1)
-        exp_motor = Motor(2)
+        arm = Motor(1)
+        pid = PidController(0.2, 0.0, 0.01, output_limit=Motor.V5_MAX_VOLTAGE)
 
-        v5_motor.set_voltage(v5_motor.max_voltage)
-        exp_motor.set_voltage(exp_motor.max_voltage)
-        ```
+        async def main():
+        while True:
+        arm.set_voltage(pid.update(45, arm.get_position(DEGREES), 10, unit=MILLIS))
+        await vasyncio.Sleep(10, MILLIS)
 
-        Drive the motor based on a controller joystick:
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
+    def reset(self) -> None:
+        """
+        Clears the accumulated integral and the derivative history.
+
+        Call this before reusing the controller for a new movement, so that error accumulated during
+        the previous one doesn't carry over.
+        """
+        ...
+
+class TrapezoidProfile:
+    """
+    A trapezoidal motion profile.
+
+    The profile moves over `distance` by accelerating at `max_acceleration` until it reaches
+    `max_velocity`, cruising, and then decelerating to a stop at the end. If the distance is too
+    short to reach `max_velocity`, it decelerates as soon as it reaches the halfway point instead.
+
+    Distances may be measured in any unit, as long as velocities are in that unit per second and
+    accelerations in that unit per second squared. A negative `distance` moves backwards.
+
+    Use `Motor.follow_profile` to drive a motor along the profile.
+    """
+    def __init__(
+        self, distance: float, max_velocity: float, max_acceleration: float, /
+    ) -> None:
+        """
+        Plans a trapezoidal profile over `distance`.
+
+        # Raises
+
+        - `TypeError`: If an argument is not numeric.
+        - `ValueError`: If `max_velocity` or `max_acceleration` isn't positive and finite, or if
+        `distance` isn't finite.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        # Move 90 degrees, at up to 180 degrees/s, accelerating at 360 degrees/s^2
+        profile = TrapezoidProfile(90, 180, 360)
+        print(profile.duration(SECOND))
+        ```
+        """
+        ...
+
+    def sample(self, t: float, unit: TimeUnit = TimeUnit.SECOND, /) -> ProfileState:
+        """
+        Returns the state of the profile `t` after it starts, measured in `unit`.
+
+        Times before the start or after the end of the profile return the state at the start or
+        end, where the profile is at rest.
+
+        # Raises
+
+        - `ValueError`: If `t` isn't finite, or is too large to represent.
+        """
+        ...
+
+    def duration(self, unit: TimeUnit = TimeUnit.SECOND, /) -> float:
+        """
+        Returns the time taken to complete the profile, measured in `unit`.
+
+        # Raises
+
+        - `ValueError`: If the duration is too large to represent.
+        """
+        ...
+
+class SCurveProfile:
+    """
+    An S-curve motion profile.
+
+    Like `TrapezoidProfile`, the profile accelerates, cruises and decelerates over `distance`, but
+    the acceleration ramps up and down at no more than `max_jerk` rather than changing instantly.
+    This makes the motion smoother, which reduces wear and keeps loads on an arm or lift from
+    swinging, at the cost of taking slightly longer.
+
+    Distances may be measured in any unit, as long as velocities are in that unit per second,
+    accelerations in that unit per second squared, and jerks in that unit per second cubed. A
+    negative `distance` moves backwards.
+
+    Use `Motor.follow_profile` to drive a motor along the profile.
+    """
+    def __init__(
+        self,
+        distance: float,
+        max_velocity: float,
+        max_acceleration: float,
+        max_jerk: float,
+        /,
+    ) -> None:
+        """
+        Plans an S-curve profile over `distance`.
+
+        # Raises
+
+        - `TypeError`: If an argument is not numeric.
+        - `ValueError`: If `max_velocity`, `max_acceleration` or `max_jerk` isn't positive and
+        finite, or if `distance` isn't finite.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        # Move 2 turns, at up to 1 turn/s, accelerating at up to 2 turns/s^2
+        profile = SCurveProfile(2, 1, 2, 8)
+        state = profile.sample(500, MILLIS)
+        print(state.position, state.velocity, state.acceleration)
+        ```
+        """
+        ...
+
+    def sample(self, t: float, unit: TimeUnit = TimeUnit.SECOND, /) -> ProfileState:
+        """
+        Returns the state of the profile `t` after it starts, measured in `unit`.
+
+        Times before the start or after the end of the profile return the state at the start or
+        end, where the profile is at rest.
+
+        # Raises
+
+        - `ValueError`: If `t` isn't finite, or is too large to represent.
+        """
+        ...
+
+    def duration(self, unit: TimeUnit = TimeUnit.SECOND, /) -> float:
+        """
+        Returns the time taken to complete the profile, measured in `unit`.
+
+        # Raises
+
+        - `ValueError`: If the duration is too large to represent.
+        """
+        ...
+
+class ProfileState:
+    """
+    The state of a motion profile at one point in time, returned by `TrapezoidProfile.sample` and
+    `SCurveProfile.sample`.
+
+    `position`, `velocity` and `acceleration` are read-only `float` attributes, measured relative to
+    the start of the profile in the profile's units.
+    """
+
+    position: float
+    velocity: float
+    acceleration: float
+
+class TrackingWheel:
+    """
+    An unpowered wheel whose rotation is measured by a `RotationSensor` or `AdiEncoder`, used by
+    `Odometry`.
+
+    `diameter` sets the unit of every distance that `Odometry` reports. `offset` is the wheel's
+    distance from the robot's tracking center, in the same unit: to the left for vertical wheels
+    (negative for wheels on the right), and forward for horizontal wheels (negative for wheels
+    behind it). Reverse the sensor if a vertical wheel reads negative when the robot drives forward,
+    or a horizontal wheel reads negative when it slides left.
+
+    `diameter` and `offset` are read-only attributes.
+    """
+
+    diameter: float
+    offset: float
+    def __init__(
+        self,
+        sensor: RotationSensor | AdiEncoder,
+        diameter: float,
+        offset: float = 0.0,
+        /,
+    ) -> None:
+        """
+        Creates a tracking wheel of the given `diameter` measured by `sensor`.
+
+        # Raises
+
+        - `TypeError`: If `sensor` isn't a `RotationSensor` or `AdiEncoder`, or a number isn't
+        numeric.
+        - `ValueError`: If `diameter` isn't positive and finite, or `offset` isn't finite.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        # A 2.75" wheel 1.5" to the right of the tracking center
+        right = TrackingWheel(RotationSensor(1), 2.75, -1.5)
+        ```
+        """
+        ...
+
+    def get_distance(self) -> float:
+        """
+        Returns the distance the wheel has travelled since its sensor was last reset, in the unit of
+        its diameter.
+
+        # Raises
+
+        - `DeviceError`: If the sensor can't be read.
+        """
+        ...
+
+class Odometry:
+    """
+    Tracks the robot's position and heading from two or three tracking wheels.
+
+    The robot's heading comes from an `InertialSensor` if one is given, or otherwise from the
+    difference between two vertical wheels. A horizontal wheel measures sideways motion; without
+    one, the robot is assumed not to slide sideways. Each update assumes the robot moved along an
+    arc since the previous one, which stays accurate while turning and driving at the same time.
+
+    Positions are measured in the unit of the tracking wheel diameters, with x pointing forward and
+    y pointing left of where the robot started. Headings increase counterclockwise from the x axis
+    and are not wrapped, so they keep counting past a full turn.
+
+    Poses are only updated by `Odometry.update` or while `Odometry.run` is awaited. Resetting a
+    tracking wheel's sensor or the inertial sensor's rotation while tracking makes the pose jump.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    odom = Odometry(
+    [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+    TrackingWheel(RotationSensor(3), 2.75, -2.0),
+    imu=InertialSensor(4),
+    )
+
+    async def track():
+    await odom.run(10, MILLIS)
+
+    async def main():
+    vasyncio.spawn(track())
+    while True:
+    pose = odom.get_pose()
+    print(pose.x, pose.y, pose.get_heading(DEGREES))
+    await vasyncio.Sleep(100, MILLIS)
+
+    vasyncio.run(main())
+    ```
+    """
+    def __init__(
+        self,
+        vertical: list[TrackingWheel] | tuple[TrackingWheel, ...],
+        horizontal: TrackingWheel | None = None,
+        /,
+        *,
+        imu: InertialSensor | None = None,
+    ) -> None:
+        """
+        Creates an odometry tracker at the origin.
+
+        `vertical` lists one or two tracking wheels parallel to the direction of travel, and
+        `horizontal` is an optional tracking wheel perpendicular to it. A single vertical wheel
+        needs `imu` to measure the heading.
+
+        # Raises
+
+        - `TypeError`: If `vertical` isn't a list or tuple of `TrackingWheel`s, `horizontal` isn't a
+        `TrackingWheel`, or `imu` isn't an `InertialSensor`.
+        - `ValueError`: If `vertical` doesn't hold one or two wheels, or if there is no `imu` and
+        the vertical wheels can't measure the heading because there is only one of them or they
+        share an offset.
+        """
+        ...
+
+    def update(self) -> None:
+        """
+        Reads the sensors once and updates the pose.
+
+        The first update only records the starting readings. Use this instead of `Odometry.run` to
+        update the pose from your own loop.
+
+        # Raises
+
+        - `DeviceError`: If a sensor can't be read.
+        """
+        ...
+
+    def run(
+        self, period: float = 10.0, unit: TimeUnit = TimeUnit.MILLIS, /
+    ) -> OdometryFuture:
+        """
+        Returns an `OdometryFuture` that updates the pose every `period`, measured in `unit`, until
+        it is cancelled.
+
+        Await it from a task of its own, spawned with `vasyncio.spawn`, to track the pose in the
+        background. Each update runs natively, so tracking at 100 Hz costs little time in Python.
+
+        # Raises
+
+        - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+        """
+        ...
+
+    def get_pose(self) -> Pose2:
+        """Returns the tracked pose."""
+        ...
+
+    def get_position(self) -> Point2:
+        """Returns the position of the tracking center."""
+        ...
+
+    def get_heading(self, unit: RotationUnit) -> float:
+        """Returns the heading of the robot, measured in `unit`."""
+        ...
+
+    def set_pose(self, pose: Pose2) -> None:
+        """
+        Moves the tracked pose to `pose`.
+
+        The robot doesn't move; later updates continue from the new pose.
+        """
+        ...
+
+    def reset(self) -> None:
+        """Moves the tracked pose back to the origin, facing along the x axis."""
+        ...
+
+class OdometryFuture:
+    """
+    An awaitable that updates an `Odometry` on a fixed schedule until it is cancelled, returned by
+    `Odometry.run`.
+
+    The first update happens as soon as the future is awaited and the rest follow once per period.
+    Like `vasyncio.Ticker`, updates are scheduled from the first one, and periods that are missed
+    because other tasks ran too long are skipped.
+
+    # Raises
+
+    - `DeviceError`: If a sensor can't be read.
+    """
+
+class PoseEstimator:
+    """
+    Fuses wheel odometry with GPS fixes into a smoothed estimate of the robot's pose on the field.
+
+    Between fixes, the estimate follows the motion measured by an `Odometry`, including the heading
+    from its inertial sensor if it has one, and its uncertainty grows as the robot drives. Each GPS
+    fix is weighted by the sensor's reported error (`GpsSensor.get_error`), so precise fixes correct
+    the estimate quickly while noisy ones barely move it. Fixes that disagree with the estimate by
+    much more than the uncertainty of both allows are rejected as outliers. If 25 fixes in a row are
+    rejected, the estimate is assumed to be wrong, such as after the robot was pushed, and restarts
+    from the next fix. A GPS reading is only fused once, when it changes, unless it stays the same
+    for a tenth of a second while the robot stands still.
+
+    The estimate is in the GPS sensor's field frame, with x and y measured in the odometry's unit.
+    Set `scale` to the number of odometry units in a metre, such as 39.37 for inches. Headings are
+    counterclockwise from the positive x axis, as with `Pose2.from_gps`.
+
+    There is no estimate until the first GPS fix is accepted, unless one is set with
+    `PoseEstimator.set_pose`.
+
+    The `outliers` attribute counts the fixes that have been rejected.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    odom = Odometry(
+    [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+    imu=InertialSensor(3),
+    )
+    gps = GpsSensor(4, Point2(0, 0), Point2(0, 0), 0, DEGREES)
+    estimator = PoseEstimator(odom, gps, scale=39.37)
+
+    async def track():
+    await estimator.run(10, MILLIS)
+
+    async def main():
+    vasyncio.spawn(track())
+    while True:
+    pose = estimator.get_pose()
+    if pose is not None:
+    print(pose)
+    await vasyncio.Sleep(100, MILLIS)
+
+    vasyncio.run(main())
+    ```
+    """
+
+    outliers: int
+    def __init__(
+        self,
+        odometry: Odometry,
+        gps: GpsSensor,
+        /,
+        *,
+        scale: float = 1.0,
+        odometry_error: float = 0.02,
+        turn_error: float = 0.02,
+        heading_error: float = 0.05,
+        gate: float = 4.0,
+    ) -> None:
+        """
+        Creates a pose estimator that fuses `odometry` with fixes from `gps`.
+
+        `scale` is the number of odometry units in a metre. `odometry_error` is the standard
+        deviation of the odometry's position error as a fraction of the distance travelled, and
+        `turn_error` is the standard deviation of its heading error as a fraction of the angle
+        turned. `heading_error` is the standard deviation of the GPS heading in radians; make it
+        large to rely on the odometry's heading instead. `gate` is how many standard deviations a
+        fix may be from the estimate before it is rejected.
+
+        # Raises
+
+        - `TypeError`: If `odometry` isn't an `Odometry`, `gps` isn't a `GpsSensor`, or a number
+        isn't numeric.
+        - `ValueError`: If `scale`, `heading_error` or `gate` isn't positive and finite, or
+        `odometry_error` or `turn_error` is negative or non-finite.
+        """
+        ...
+
+    def update(self) -> None:
+        """
+        Updates the odometry, then fuses the latest GPS fix into the estimate.
+
+        Use this instead of `PoseEstimator.run` to update the estimate from your own loop.
+
+        # Raises
+
+        - `DeviceError`: If a sensor can't be read.
+        """
+        ...
+
+    def run(
+        self, period: float = 10.0, unit: TimeUnit = TimeUnit.MILLIS, /
+    ) -> PoseEstimatorFuture:
+        """
+        Returns a `PoseEstimatorFuture` that updates the estimate every `period`, measured in
+        `unit`, until it is cancelled.
+
+        Each update also updates the odometry, so there is no need to run it separately.
+
+        # Raises
+
+        - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+        """
+        ...
+
+    def get_pose(self, /) -> Pose2 | None:
+        """Returns the estimated pose, or `None` if there is no estimate yet."""
+        ...
+
+    def get_covariance(self, /) -> tuple[tuple[float, float, float], ...]:
+        """
+        Returns the covariance of the estimate as three rows of three, in the order x, y, heading.
+
+        Positions are in odometry units and headings are in radians, so the square root of the
+        first entry is the standard deviation of x.
+        """
+        ...
+
+    def set_pose(self, pose: Pose2) -> None:
+        """Sets the estimate to `pose`, treating it as exact until the robot moves."""
+        ...
+
+    def reset(self) -> None:
+        """Discards the estimate so that the next accepted GPS fix starts a new one."""
+        ...
+
+class PoseEstimatorFuture:
+    """
+    An awaitable that updates a `PoseEstimator` on a fixed schedule until it is cancelled, returned
+    by `PoseEstimator.run`.
+
+    Scheduling works like `OdometryFuture`.
+
+    # Raises
+
+    - `DeviceError`: If a sensor can't be read.
+    """
+
+class PurePursuit:
+    """
+    A path follower for a differential drive that steers towards a point a set distance ahead on
+    the path.
+
+    `PurePursuit` drives forwards through a list of `Point2` or `Pose2` waypoints, using the pose
+    reported by `source`, which is an `Odometry` or a `PoseEstimator`. The path runs in straight
+    lines from the robot's position when following starts, through each waypoint in turn. Only
+    the positions of `Pose2` waypoints are used; the robot's heading follows the path. A
+    `Trajectory` can be followed instead, in which case only its shape is used: the path runs along
+    the trajectory's curves from its first waypoint, and the controller sets its own speed.
+
+    On each update, the controller finds the point on the path nearest the robot, then steers along
+    the arc that passes through the point `lookahead` further along. A longer lookahead follows the
+    path more smoothly but cuts corners more. With a `lookahead_gain`, the lookahead grows by that
+    much per unit of velocity, up to `max_lookahead`, so that the robot looks further ahead when it
+    is moving quickly.
+
+    The robot slows for tight turns so that neither wheel exceeds `max_velocity`. With a
+    `max_acceleration`, it also speeds up gradually and slows down so that it can stop at the end
+    of the path. The path is finished once the robot is within `tolerance` of its last waypoint or
+    has passed it.
+
+    Distances are measured in the unit of the pose source, velocities in that unit per second and
+    accelerations in that unit per second squared. The source must be kept up to date while the
+    path is followed, such as by awaiting `Odometry.run` in another task.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    odom = Odometry(
+    [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+    imu=InertialSensor(3),
+    )
+    left = MotorGroup([(11, Direction.REVERSE), (12, Direction.REVERSE)], Gearset.BLUE)
+    right = MotorGroup([13, 14], Gearset.BLUE)
+    pursuit = PurePursuit(odom, 12.0, 50.0, 10.0, max_acceleration=80.0)
+
+    async def track():
+    await odom.run(10, MILLIS)
+
+    async def main():
+    vasyncio.spawn(track())
+    path = [Point2(24, 0), Point2(48, 24), Point2(48, 48)]
+    await pursuit.follow(path, left, right, 3.25, gear_ratio=0.6, timeout=5000)
+
+    vasyncio.run(main())
+    ```
+    """
+    def __init__(
+        self,
+        source: Odometry | PoseEstimator,
+        track_width: float,
+        max_velocity: float,
+        lookahead: float,
+        /,
+        *,
+        lookahead_gain: float = 0.0,
+        max_lookahead: float | None = None,
+        max_acceleration: float | None = None,
+        tolerance: float = 1.0,
+    ) -> None:
+        """
+        Creates a pure pursuit controller that reads the robot's pose from `source`.
+
+        `track_width` is the distance between the left and right wheels. `max_lookahead` and
+        `max_acceleration` are unlimited if `None`.
+
+        # Raises
+
+        - `TypeError`: If `source` isn't an `Odometry` or `PoseEstimator`, or a number isn't
+        numeric.
+        - `ValueError`: If `track_width`, `max_velocity`, `lookahead`, `max_lookahead` or
+        `max_acceleration` isn't positive and finite, if `lookahead_gain` or `tolerance` is
+        negative or non-finite, or if `max_lookahead` is less than `lookahead`.
+        """
+        ...
+
+    def set_path(self, path: list[Point2 | Pose2] | Trajectory, /) -> None:
+        """
+        Sets the waypoints or trajectory to follow, starting again at the next update.
+
+        # Raises
+
+        - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+        waypoints.
+        - `ValueError`: If `path` is empty.
+        """
+        ...
+
+    def calculate(self, /) -> tuple[float, float]:
+        """
+        Reads the robot's pose and returns the left and right wheel velocities that steer it along
+        the path, or `(0.0, 0.0)` once the path is finished.
+
+        Use this instead of `PurePursuit.follow` to drive the wheels from your own loop. The
+        acceleration limit assumes that it is called regularly.
+
+        # Raises
+
+        - `RuntimeError`: If no path has been set, or if the pose source is a `PoseEstimator` that
+        has no estimate yet.
+        """
+        ...
+
+    def is_finished(self) -> bool:
+        """Returns whether the robot has reached the end of the path."""
+        ...
+
+    def follow(
+        self,
+        path: list[Point2 | Pose2] | Trajectory,
+        left: MotorGroup,
+        right: MotorGroup,
+        wheel_diameter: float,
+        /,
+        *,
+        gear_ratio: float = 1.0,
+        timeout: float | None = None,
+        time_unit: TimeUnit = TimeUnit.MILLIS,
+    ) -> FollowFuture:
+        """
+        Sets the waypoints or trajectory to follow, then returns a `FollowFuture` that drives the
+        `left` and `right` motor groups along it until the path is finished.
+
+        `wheel_diameter` is measured in the unit of the pose source, and `gear_ratio` is the number
+        of turns of the wheels per turn of the motors. `timeout`, measured in `time_unit`, is
+        unlimited if `None`.
+
+        # Raises
+
+        - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+        waypoints, or if `left` or `right` isn't a `MotorGroup`.
+        - `ValueError`: If `path` is empty, if `wheel_diameter` or `gear_ratio` isn't positive and
+        finite, or if `timeout` is negative, non-finite, or too large to represent.
+        """
+        ...
+
+class Ramsete:
+    """
+    A path follower for a differential drive that tracks a reference moving along the path, using
+    the RAMSETE control law.
+
+    `Ramsete` drives forwards through a list of `Point2` or `Pose2` waypoints, or along a
+    `Trajectory`, using the pose reported by `source`, which is an `Odometry` or a
+    `PoseEstimator`. A list of waypoints makes a path that runs in straight lines from the robot's
+    position when following starts, through each waypoint in turn. Only the positions of `Pose2`
+    waypoints are used; the robot's heading follows the path.
+
+    A reference point moves along the path, speeding up at `max_acceleration`, slowing for curves
+    so that neither wheel has to exceed `max_velocity`, and slowing so that it stops at the end. The
+    controller drives the robot at the reference's velocity, corrected for how far the robot is
+    behind, beside, or turned away from it. Unlike `PurePursuit`, this keeps the robot to a
+    schedule, so it arrives at a predictable time. Corners are rounded off over one track width.
+    The path is finished once the reference reaches the end, so the robot stops close behind it.
+
+    Along a `Trajectory`, the reference instead keeps to the trajectory's own timing, starting when
+    following starts, and `max_acceleration` has no effect. The trajectory is finished once its
+    duration has passed.
+
+    `b` and `zeta` tune the controller: a larger `b` corrects errors more aggressively, and `zeta`,
+    between 0 and 1, damps the correction. The defaults of 2.0 and 0.7 suit most robots when
+    distances are in metres; set `scale` to the number of source units in a metre, such as 39.37
+    for inches, to keep them suitable.
+
+    Distances are measured in the unit of the pose source, velocities in that unit per second and
+    accelerations in that unit per second squared. The source must be kept up to date while the
+    path is followed, such as by awaiting `Odometry.run` in another task.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    odom = Odometry(
+    [TrackingWheel(RotationSensor(1), 2.75, 5.0), TrackingWheel(RotationSensor(2), 2.75, -5.0)],
+    imu=InertialSensor(3),
+    )
+    left = MotorGroup([(11, Direction.REVERSE), (12, Direction.REVERSE)], Gearset.BLUE)
+    right = MotorGroup([13, 14], Gearset.BLUE)
+    ramsete = Ramsete(odom, 12.0, 50.0, 80.0, scale=39.37)
+
+    async def track():
+    await odom.run(10, MILLIS)
+
+    async def main():
+    vasyncio.spawn(track())
+    path = [Point2(24, 0), Point2(48, 24), Point2(48, 48)]
+    await ramsete.follow(path, left, right, 3.25, gear_ratio=0.6)
+
+    vasyncio.run(main())
+    ```
+    """
+    def __init__(
+        self,
+        source: Odometry | PoseEstimator,
+        track_width: float,
+        max_velocity: float,
+        max_acceleration: float,
+        /,
+        *,
+        b: float = 2.0,
+        zeta: float = 0.7,
+        scale: float = 1.0,
+    ) -> None:
+        """
+        Creates a RAMSETE controller that reads the robot's pose from `source`.
+
+        `track_width` is the distance between the left and right wheels.
+
+        # Raises
+
+        - `TypeError`: If `source` isn't an `Odometry` or `PoseEstimator`, or a number isn't
+        numeric.
+        - `ValueError`: If `track_width`, `max_velocity`, `max_acceleration`, `b` or `scale` isn't
+        positive and finite, or if `zeta` isn't between 0 and 1.
+        """
+        ...
+
+    def set_path(self, path: list[Point2 | Pose2] | Trajectory, /) -> None:
+        """
+        Sets the waypoints or trajectory to follow, starting again at the next update.
+
+        # Raises
+
+        - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+        waypoints.
+        - `ValueError`: If `path` is empty.
+        """
+        ...
+
+    def calculate(self, /) -> tuple[float, float]:
+        """
+        Moves the reference along the path, reads the robot's pose, and returns the left and right
+        wheel velocities that steer it towards the reference, or `(0.0, 0.0)` once the path is
+        finished.
+
+        Use this instead of `Ramsete.follow` to drive the wheels from your own loop. The reference
+        moves by the time since the previous call, so call it regularly.
+
+        # Raises
+
+        - `RuntimeError`: If no path has been set, or if the pose source is a `PoseEstimator` that
+        has no estimate yet.
+        """
+        ...
+
+    def is_finished(self) -> bool:
+        """Returns whether the reference has reached the end of the path."""
+        ...
+
+    def follow(
+        self,
+        path: list[Point2 | Pose2] | Trajectory,
+        left: MotorGroup,
+        right: MotorGroup,
+        wheel_diameter: float,
+        /,
+        *,
+        gear_ratio: float = 1.0,
+        timeout: float | None = None,
+        time_unit: TimeUnit = TimeUnit.MILLIS,
+    ) -> FollowFuture:
+        """
+        Sets the waypoints or trajectory to follow, then returns a `FollowFuture` that drives the
+        `left` and `right` motor groups along it until the path is finished.
+
+        See `PurePursuit.follow`.
+
+        # Raises
+
+        - `TypeError`: If `path` isn't a `Trajectory` or a list or tuple of `Point2` or `Pose2`
+        waypoints, or if `left` or `right` isn't a `MotorGroup`.
+        - `ValueError`: If `path` is empty, if `wheel_diameter` or `gear_ratio` isn't positive and
+        finite, or if `timeout` is negative, non-finite, or too large to represent.
+        """
+        ...
+
+class FollowFuture:
+    """
+    An awaitable that drives two motor groups along a path until the end is reached, returned by
+    `PurePursuit.follow` and `Ramsete.follow`.
+
+    Between updates to the motors' velocities, the awaiting task is parked on the event loop, so
+    other tasks keep running. When the future completes, times out or fails, both motor groups are
+    told to hold a velocity of zero, including when the awaiting task is cancelled.
+
+    # Raises
+
+    - `DeviceError`: If a motor or sensor can't be used.
+    - `RuntimeError`: If the pose source is a `PoseEstimator` that has no estimate yet.
+    - `vasyncio.TimeoutError`: If the end of the path hasn't been reached before the timeout.
+    """
+    def throw(self, exc: BaseException, /) -> Any:
+        """
+        Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
+
+        Both motor groups are stopped before the exception propagates.
+        """
+        ...
+
+    def close(self) -> None:
+        """Stops both motor groups when the awaiting coroutine is closed."""
+        ...
+
+class Trajectory:
+    """
+    A smooth path through a list of poses, timed so that a differential drive can follow it.
+
+    Each pair of waypoints in a row is joined by a curve that leaves the first along its heading
+    and arrives at the second along its heading, so the headings shape the path. The robot starts
+    and ends at rest, and moves along the curves as fast as it can while keeping both wheels under
+    `max_velocity`, which makes it slow down for tight curves, and never speeding up or slowing
+    down faster than `max_acceleration`. `track_width` is the distance between the left and right
+    wheels.
+
+    Distances are measured in the waypoints' unit, velocities in that unit per second, and
+    accelerations in that unit per second squared. Headings should point along the direction of
+    travel, since a trajectory only drives forwards.
+
+    Pass a trajectory to `Ramsete.follow` to drive along it on schedule, or to `PurePursuit.follow`
+    to follow its shape.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    trajectory = Trajectory(
+    [Pose2(0, 0, 0, DEGREES), Pose2(48, 24, 90, DEGREES), Pose2(24, 60, 180, DEGREES)],
+    12.0,
+    50.0,
+    80.0,
+    )
+    print(trajectory.duration(SECOND))
+    state = trajectory.sample(1.0)
+    print(state.pose, state.velocity)
+    ```
+    """
+    def __init__(
+        self,
+        waypoints: list[Pose2],
+        track_width: float,
+        max_velocity: float,
+        max_acceleration: float,
+        /,
+    ) -> None:
+        """
+        Plans a trajectory through `waypoints`.
+
+        # Raises
+
+        - `TypeError`: If `waypoints` isn't a list or tuple of `Pose2`, or a limit isn't numeric.
+        - `ValueError`: If there are fewer than two waypoints, if two waypoints in a row share a
+        position, or if a limit isn't positive and finite.
+        """
+        ...
+
+    def sample(self, t: float, unit: TimeUnit = TimeUnit.SECOND, /) -> TrajectoryState:
+        """
+        Returns the state of the trajectory `t` after it starts, measured in `unit`.
+
+        Times before the start or after the end of the trajectory return the state at the first or
+        last waypoint, where the robot is at rest.
+
+        # Raises
+
+        - `ValueError`: If `t` isn't finite, or is too large to represent.
+        """
+        ...
+
+    def duration(self, unit: TimeUnit = TimeUnit.SECOND, /) -> float:
+        """
+        Returns the time taken to follow the trajectory, measured in `unit`.
+
+        # Raises
+
+        - `ValueError`: If the duration is too large to represent.
+        """
+        ...
+
+    def length(self) -> float:
+        """Returns the length of the trajectory."""
+        ...
+
+class TrajectoryState:
+    """
+    The state of a `Trajectory` at one point in time, returned by `Trajectory.sample`.
+
+    `time` is in seconds since the start. `pose` is the `Pose2` the robot should be at, and
+    `distance` is how far along the trajectory it is. `velocity` and `acceleration` are measured
+    along the path, `curvature` is in radians per unit travelled and `angular_velocity` in radians
+    per second, both positive when turning counterclockwise. All are read-only attributes.
+    """
+
+    time: float
+    distance: float
+    pose: Pose2
+    velocity: float
+    acceleration: float
+    curvature: float
+    angular_velocity: float
+
+class BrakeMode:
+    """
+    Determines the behavior a motor should use when braking with `Motor.brake`.
+
+    `BrakeMode` is root-importable and isn't constructed directly; use one of its singleton
+    constants. Each constant has a readable representation such as `BrakeMode.BRAKE`.
+    """
+
+    BRAKE: ClassVar[BrakeMode]
+    """Motor uses regenerative braking to slow down faster."""
+    COAST: ClassVar[BrakeMode]
+    """Motor never brakes."""
+    HOLD: ClassVar[BrakeMode]
+    """Motor exerts force holding itself in the same position."""
+
+class Direction:
+    """
+    A rotational direction.
+
+    `Direction` is root-importable and isn't constructed directly; use `Direction.FORWARD` or
+    `Direction.REVERSE`. Applying Python's `~` operator returns the opposite singleton, so
+    `~Direction.FORWARD` is `Direction.REVERSE`. Each value has a readable representation such as
+    `Direction.FORWARD`.
+    """
+
+    FORWARD: ClassVar[Direction]
+    """Rotates in the forward direction."""
+    REVERSE: ClassVar[Direction]
+    """Rotates in the reverse direction."""
+
+class Gearset:
+    """
+    Internal gearset used by VEX Smart motors.
+
+    The selected value must match an 11W motor's physical cartridge so position and velocity are
+    scaled correctly. EXP motors have a fixed 200 RPM gearset reported as `Gearset.GREEN`.
+    `Gearset` is root-importable and isn't constructed directly; use one of its singleton constants.
+    Values have readable representations such as `Gearset.GREEN`.
+    """
+
+    RED: ClassVar[Gearset]
+    """36:1 gear ratio with a rated maximum speed of 100 RPM."""
+    GREEN: ClassVar[Gearset]
+    """18:1 gear ratio with a rated maximum speed of 200 RPM."""
+    BLUE: ClassVar[Gearset]
+    """6:1 gear ratio with a rated maximum speed of 600 RPM."""
+
+class Motor:
+    """
+    A motor plugged into a Smart Port.
+
+    This class provides abstractions for interacting with VEX Smart Motors, supporting both the 11W
+    and 5.5W variants.
+
+    # Overview
+
+    The V5 Smart Motors come in two variants: [an 11W model](https://www.vexrobotics.com/276-4840.html),
+    with interchangeable gear cartridges and [a 5.5W model](https://www.vexrobotics.com/276-4842.html),
+    with a fixed gearing. The 11W motor supports three cartridge options, which will gear the motor
+    down from its base RPM of 3600: a red cartridge providing 100 RPM output, a green cartridge for
+    200 RPM, and a blue cartridge for 600 RPM. The 5.5W motor comes with a non-interchangeable 200
+    RPM gear cartridge.
+
+    Smart Motors feature several integrated sensors, including an encoder for measuring the velocity
+    and position of the motor, a temperature sensor for detecting overheats, and sensors for
+    measuring output voltage, current, and efficiency.
+
+    Communication between a Smart motor and the V5 Brain occur at two different intervals. While
+    the motor communicates with the Brain every 5 milliseconds (and commands can be written to the
+    motor every 5mS), the Brain only reads data from the motor every 10mS. This effectively places
+    the date *write* interval at 5mS and the data *read* interval at 10mS.
+
+    More in-depth specs for the 11W motor can be found [here](https://kb.vex.com/hc/en-us/articles/360060929971-Understanding-V5-Smart-Motors).
+
+    # Current Limitations
+
+    There are some cases where VEXos or the motor itself may decide to limit output current:
+
+    - **Stall Prevention**: The stall current on 11W motors is limited to 2.5A. This limitation
+    eliminates the need for automatic resetting fuses (PTC devices) in the motor, which can
+    disrupt operation. By restricting the stall current to 2.5A, the motor effectively avoids
+    undesirable performance dips and ensures that users do not inadvertently cause stall
+    situations.
+
+    - **Motor Count**: Robots that use 8 or fewer 11W motors will have the aforementioned current limit
+    of 2.5A set for each motor. Robots using more than 8 motors, will have a lower default current limit
+    per-motor than 2.5A. This limit is determined in VEXos by a calculation accounting for the number of
+    motors plugged in, and the user's manually set current limits using `Motor.set_current_limit`. For
+    more information regarding the current limiting behavior of VEXos, see [this forum post](https://www.vexforum.com/t/how-does-the-decreased-current-affect-the-robot-when-using-more-than-8-motors/72650/4).
+
+    - **Temperature Management**: Motors have an onboard sensor for measuring internal temperature.
+    If the motor determines that it is overheating, it will throttle its output current and warn
+    the user.
+
+    # Motor Control
+
+    Each motor contains a sophisticated control system built around a Cortex M0+ microcontroller.
+    The microcontroller continuously monitors position, speed, direction, voltage, current, and
+    temperature through integrated sensors.
+
+    The onboard motor firmware implements a set of pre-tuned PID (Proportional-Integral-Derivative)
+    controllers operating on a 10-millisecond cycle for position and velocity control. Motors also
+    have braking functionality for holding a specific position under load.
+    """
+
+    is_exp: bool
+    is_v5: bool
+    max_voltage: float
+    motor_type: MotorType
+    V5_MAX_VOLTAGE: ClassVar[float]
+    """The maximum voltage value that can be sent to a V5 `Motor`."""
+    EXP_MAX_VOLTAGE: ClassVar[float]
+    """The maximum voltage value that can be sent to an EXP `Motor`."""
+    WRITE_INTERVAL_MS: ClassVar[int]
+    """The interval at which the Brain will send new packets to a `Motor`, in milliseconds."""
+
+    @staticmethod
+    def new_v5(
+        port: int,
+        direction: Direction = Direction.FORWARD,
+        gearset: Gearset = Gearset.GREEN,
+        /,
+    ) -> Motor: ...
+    @staticmethod
+    def new_exp(port: int, direction: Direction = Direction.FORWARD, /) -> Motor:
+        """
+        Creates a new 5.5W (EXP) Smart Motor.
+
+        See `Motor()` or `Motor.new_v5` to create a 11W (V5) Smart Motor.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+        assert motor.is_exp
+        assert motor.max_voltage == Motor.EXP_MAX_VOLTAGE
+        ```
+        """
+        ...
+
+    def __init__(
+        self,
+        port: int,
+        direction: Direction = Direction.FORWARD,
+        gearset: Gearset = Gearset.GREEN,
+        /,
+    ) -> None:
+        """
+        Creates a new 11W (V5) Smart Motor.
+
+        See `Motor.new_exp` to create a 5.5W (EXP) Smart Motor.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+        assert motor.is_v5
+        assert motor.max_voltage == Motor.V5_MAX_VOLTAGE
+        ```
+        """
+        ...
+
+    def set_voltage(self, volts: float) -> None:
+        """
+        Sets the motor's output voltage.
+
+        This voltage value spans from -12 (fully spinning reverse) to +12 (fully spinning forwards)
+        volts, and controls the raw output of the motor.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Give the motor full power:
+
+        ```python
+        from venice import *
+
+        v5_motor = Motor(1)
+        exp_motor = Motor(2)
+
+        v5_motor.set_voltage(v5_motor.max_voltage)
+        exp_motor.set_voltage(exp_motor.max_voltage)
+        ```
+
+        Drive the motor based on a controller joystick:
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+        controller = Controller()
+
+        async def main():
+        while True:
+        controller_state = controller.get_state()
+        voltage = controller_state.left_stick.x * motor.max_voltage
+
+        motor.set_voltage(voltage)
+
+        await vasyncio.Sleep(Motor.WRITE_INTERVAL_MS, MILLIS)
+
+        vasyncio.run(main)
+        ```
+        """
+        ...
+
+    def set_velocity(self, rpm: int) -> None:
+        """
+        Spins the motor at a target velocity.
+
+        This velocity corresponds to different actual speeds in RPM depending on the gearset used
+        for the motor. Velocity is held with an internal PID controller to ensure consistent
+        speed, as opposed to setting the motor's voltage.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Spin a motor at 100 RPM:
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+        motor.set_velocity(100)
+        ```
+        """
+        ...
+
+    def brake(self, mode: BrakeMode) -> None:
+        """
+        Stops this motor with the given `BrakeMode`.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+        motor.brake(BrakeMode.HOLD)
+        ```
+        """
+        ...
+
+    def set_gearset(self, gearset: Gearset) -> None:
+        """
+        Sets the gearset of an 11W motor.
+
+        # Raises
+
+        `DeviceError`: If the motor is a 5.5W EXP Smart Motor, which has no swappable gearset, or if
+        no motor is connected to the port.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        # This must be a V5 motor
+        motor = Motor(1, Direction.FORWARD, Gearset.GREEN)
+
+        # Set the motor to use the red gearset
+        motor.set_gearset(Gearset.RED)
+        ```
+        """
+        ...
+
+    def get_gearset(self, /) -> Gearset:
+        """
+        Returns the gearset of the motor
+
+        For 5.5W motors, this will always be returned as `Gearset::Green`.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Print the gearset of a motor:
+
+        ```python
+        from venice import *
+
+        def print_gearset(motor: Motor):
+        try:
+        gearset = motor.gearset()
+        except:
+        print("Failed to get gearset. Is this an EXP motor?")
+        return
+
+        if gearset == Gearset.RED:
+        print("Motor is using the red gearset")
+        elif gearset == Gearset.GREEN:
+        print("Motor is using the green gearset")
+        elif gearset == Gearset.BLUE:
+        print("Motor is using the blue gearset")
+        ```
+        """
+        ...
+
+    def set_position_target(
+        self, position: float, unit: RotationUnit, velocity: int, /
+    ) -> None:
+        """
+        Sets an absolute position target for the motor to attempt to reach.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+
+        # Turn the motor to a position of 90 degrees at 200rpm.
+        motor.set_position_target(90, DEGREES, 200)
+        ```
+        """
+        ...
+
+    def move_to(
+        self,
+        position: float,
+        velocity: int,
+        unit: RotationUnit,
+        /,
+        *,
+        tolerance: float | None = None,
+        settle_time: float = 50,
+        timeout: float | None = None,
+        time_unit: TimeUnit = TimeUnit.MILLIS,
+    ) -> MoveFuture:
+        """
+        Moves the motor to an absolute `position`, measured in `unit`, at up to `velocity` RPM, and
+        returns a `MoveFuture` that completes once the motor has settled at the position.
+
+        The position target is sent to the motor when the future is first awaited. The future then
+        completes once the motor's position has stayed within `tolerance` of `position` for
+        `settle_time`. `tolerance` is measured in `unit` and defaults to one degree; `settle_time`
+        and `timeout` are measured in `time_unit`. A `timeout` of `None` waits without a time limit.
+
+        Awaiting the future again sends the position target again and restarts the timeout. If the
+        awaiting task is cancelled before the motor settles, the motor brakes.
+
+        # Raises
+
+        - `ValueError`: If `tolerance` is negative, or if `settle_time` or `timeout` is negative,
+        non-finite, or too large to represent.
+
+        Awaiting the returned future raises:
+
+        - `DeviceError`: If no device is connected to the port, if the wrong type of device is
+        connected, or if the motor reports any fault flags (see `Motor.get_faults`) while moving.
+        - `vasyncio.TimeoutError`: If the motor hasn't settled at the position before `timeout`
+        elapses. The motor keeps its position target.
+
+        # Examples
+
+        Raise an arm, then lower it again once it has arrived:
+
+        ```python
+        from venice import *
+
+        arm = Motor(1)
+
+        async def main():
+        await arm.move_to(90, 100, DEGREES, tolerance=2, timeout=1500)
+        await arm.move_to(0, 100, DEGREES)
+
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
+    def follow_profile(
+        self, profile: TrapezoidProfile | SCurveProfile, unit: RotationUnit, /
+    ) -> ProfileFuture:
+        """
+        Drives the motor along a motion `profile`, whose distances are measured in `unit`, and returns
+        a `ProfileFuture` that completes when the profile ends.
+
+        The profile starts from the motor's position when the future is first awaited. The motor is
+        given a position target at the end of the profile, and its profiled velocity is then updated
+        every 10 milliseconds to follow the profile's velocity, so the motor's own position
+        controller does the tracking. Velocities in the profile are in `unit` per second.
+
+        When the future completes, the motor keeps its position target, so it finishes any
+        remaining distance and then holds the end position. Use `Motor.move_to` afterwards to wait
+        until it has settled there.
+
+        # Raises
+
+        - `TypeError`: If `profile` isn't a `TrapezoidProfile` or `SCurveProfile`.
+
+        Awaiting the returned future raises:
+
+        - `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Raise a lift by half a turn of its motor, smoothly:
+
+        ```python
+        from venice import *
+
+        lift = Motor(1)
+        profile = SCurveProfile(0.5, 1, 2, 10)
+
+        async def main():
+        await lift.follow_profile(profile, TURNS)
+
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
+    def get_velocity(self) -> float: ...
+    def get_power(self) -> float: ...
+    def get_torque(self) -> float:
+        """
+        Returns the torque output of the motor in Nm.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Print the torque output of a motor:
 
         ```python
         from venice import *
 
         motor = Motor(1)
-        controller = Controller()
 
         async def main():
         while True:
-        controller_state = controller.get_state()
-        voltage = controller_state.left_stick.x * motor.max_voltage
+        torque = motor.get_torque()
+        print(f"Torque: {torque:.2}Nm")
+        await vasyncio.Sleep(10, MILLIS)
 
-        motor.set_voltage(voltage)
+        vasyncio.run(main)
+        ```
+        """
+        ...
+
+    def get_voltage(self) -> float:
+        """
+        Returns the voltage the motor is drawing in volts.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Print the voltage drawn by a motor:
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+
+        async def main():
+        while True:
+        voltage = motor.get_voltage()
+        print(f"Voltage: {voltage:.2}V")
+        await vasyncio.Sleep(10, MILLIS)
+
+        vasyncio.run(main)
+        ```
+        """
+        ...
+
+    def get_raw_position(self) -> int:
+        """
+        Returns the most recently recorded raw encoder tick data from the motor's IME.
+
+        The motor's integrated encoder has a TPR of 4096. Gearset is not taken into consideration
+        when dealing with the raw value, meaning this measurement will be taken relative to the
+        motor's internal position *before* being geared down from 3600RPM.
+
+        Methods such as `Motor.reset_position` and `Motor.set_position` do not change the value of
+        this raw measurement.
+
+        # Raises
+
+        - `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        async def main():
+        motor = Motor(1)
+        while True:
+        raw_pos = motor.get_raw_position()
+        print(f"Raw Position: {raw_pos}")
 
         await vasyncio.Sleep(Motor.WRITE_INTERVAL_MS, MILLIS)
 
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
+    def get_current(self) -> float:
+        """
+        Returns the electrical current draw of the motor in amps.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Print the current draw of a motor:
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+
+        async def main():
+        motor.set_voltage(motor.max_voltage)
+        while True:
+        current = motor.get_current()
+        print(f"Current: {current:.2}A")
+
+        await vasyncio.Sleep(10, MILLIS)
+
         vasyncio.run(main)
         ```
         """
         ...
 
-    def set_velocity(self, rpm: int) -> None:
+    def get_efficiency(self) -> float:
+        """
+        Returns the efficiency of the motor from a range of [0.0, 1.0].
+
+        An efficiency of 1.0 means that the motor is moving electrically while drawing no electrical
+        power, and an efficiency of 0.0 means that the motor is drawing power but not moving.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Print the efficiency of a motor:
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+
+        async def main():
+        motor.set_voltage(motor.max_voltage)
+        while True:
+        efficiency = motor.get_efficiency()
+        print(f"Current: {efficiency:.2}")
+
+        await vasyncio.Sleep(10, MILLIS)
+        ```
+        """
+        ...
+
+    def get_current_limit(self) -> float:
+        """
+        Returns the current limit for the motor in amps.
+
+        This limit can be configured with the `Motor.set_current_limit` method.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Print the current limit of a motor:
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+        current_limit = motor.get_current_limit()
+        print(f"Current Limit: {current_limit:.2}A")
+        ```
+        """
+        ...
+
+    def get_voltage_limit(self) -> float:
+        """
+        Returns the voltage limit for the motor if one has been explicitly set.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Print the voltage limit of a motor:
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+        voltage_limit = motor.get_voltage_limit()
+        print(f"Voltage Limit: {voltage_limit:.2}V")
+        ```
+        """
+        ...
+
+    def get_temperature(self) -> float:
+        """
+        Returns the internal temperature recorded by the motor in increments of 5 °C.
+
+        # Raises
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Turn off the motor if it gets too hot:
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+
+        async def main():
+        motor.set_voltage(12)
+        while True:
+        if motor.get_temperature() > 30:
+        motor.brake(BrakeMode.COAST)
+        else:
+        motor.set_voltage(12)
+        await vasyncio.Sleep(10, MILLIS)
+        ```
+        """
+        ...
+
+    def set_profiled_velocity(self, velocity: int) -> None:
         """
-        Spins the motor at a target velocity.
+        Changes the output velocity for a profiled movement (motor_move_absolute or
+        motor_move_relative).
 
-        This velocity corresponds to different actual speeds in RPM depending on the gearset used
-        for the motor. Velocity is held with an internal PID controller to ensure consistent
-        speed, as opposed to setting the motor's voltage.
+        This will have no effect if the motor is not following a profiled movement.
 
         # Raises
 
@@ -5359,20 +7074,23 @@ class Motor:
 
         # Examples
 
-        Spin a motor at 100 RPM:
-
         ```python
         from venice import *
 
         motor = Motor(1)
-        motor.set_velocity(100)
+
+        # Set the motor's target to a Position so that changing the velocity isn't a noop.
+        motor.set_position_target(90, DEGREES, 200)
+        motor.set_profiled_velocity(100)
         ```
         """
         ...
 
-    def brake(self, mode: BrakeMode) -> None:
+    def reset_position(self) -> None:
         """
-        Stops this motor with the given `BrakeMode`.
+        Sets the current encoder position to zero without moving the motor.
+
+        Analogous to taring or resetting the encoder to the current position.
 
         # Raises
 
@@ -5381,43 +7099,47 @@ class Motor:
 
         # Examples
 
+        Move the motor in increments of 10 degrees:
+
         ```python
         from venice import *
 
         motor = Motor(1)
-        motor.brake(BrakeMode.HOLD)
+
+        async def main():
+        while True:
+        motor.set_position_target(10, DEGREES, 200)
+        await vasyncio.Sleep(1, SECOND)
+        motor.reset_position()
         ```
         """
         ...
 
-    def set_gearset(self, gearset: Gearset) -> None:
+    def set_current_limit(self, limit: float) -> None:
         """
-        Sets the gearset of an 11W motor.
+        Sets the current limit for the motor in amps.
 
         # Raises
 
-        `DeviceError`: If the motor is a 5.5W EXP Smart Motor, which has no swappable gearset, or if
-        no motor is connected to the port.
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
 
         # Examples
 
+        Limit the current draw of a motor to 2.5A:
+
         ```python
         from venice import *
 
-        # This must be a V5 motor
-        motor = Motor(1, Direction.FORWARD, Gearset.GREEN)
-
-        # Set the motor to use the red gearset
-        motor.set_gearset(Gearset.RED)
+        motor = Motor(1)
+        motor.set_current_limit(2.5)
         ```
         """
         ...
 
-    def get_gearset(self, /) -> Gearset:
+    def set_voltage_limit(self, limit: float) -> None:
         """
-        Returns the gearset of the motor
-
-        For 5.5W motors, this will always be returned as `Gearset::Green`.
+        Sets the voltage limit for the motor in volts.
 
         # Raises
 
@@ -5426,33 +7148,22 @@ class Motor:
 
         # Examples
 
-        Print the gearset of a motor:
+        Limit the voltage of a motor to 4V:
 
         ```python
         from venice import *
 
-        def print_gearset(motor: Motor):
-        try:
-        gearset = motor.gearset()
-        except:
-        print("Failed to get gearset. Is this an EXP motor?")
-        return
-
-        if gearset == Gearset.RED:
-        print("Motor is using the red gearset")
-        elif gearset == Gearset.GREEN:
-        print("Motor is using the green gearset")
-        elif gearset == Gearset.BLUE:
-        print("Motor is using the blue gearset")
+        motor = Motor(1)
+        motor.set_voltage_limit(4)
+        # Will appear as if the voltage was set to only 4V
+        motor.set_voltage(12)
         ```
         """
         ...
 
-    def set_position_target(
-        self, position: float, unit: RotationUnit, velocity: int, /
-    ) -> None:
+    def is_over_temperature(self) -> bool:
         """
-        Sets an absolute position target for the motor to attempt to reach.
+        Returns `True` if the motor's over temperature flag is set.
 
         # Raises
 
@@ -5461,22 +7172,30 @@ class Motor:
 
         # Examples
 
+        Turn off the motor if it gets too hot:
+
         ```python
         from venice import *
 
         motor = Motor(1)
 
-        # Turn the motor to a position of 90 degrees at 200rpm.
-        motor.set_position_target(90, DEGREES, 200)
+        async def main():
+        motor.set_voltage(12)
+        while True:
+        if motor.is_over_temperature():
+        motor.brake(BrakeMode.COAST)
+        else:
+        motor.set_voltage(12)
+        await vasyncio.Sleep(10, MILLIS)
+
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def get_velocity(self) -> float: ...
-    def get_power(self) -> float: ...
-    def get_torque(self) -> float:
+    def is_over_current(self) -> bool:
         """
-        Returns the torque output of the motor in Nm.
+        Returns `True` if the motor's over-current flag is set.
 
         # Raises
 
@@ -5485,7 +7204,7 @@ class Motor:
 
         # Examples
 
-        Print the torque output of a motor:
+        Print a warning if the motor draws too much current:
 
         ```python
         from venice import *
@@ -5493,9 +7212,13 @@ class Motor:
         motor = Motor(1)
 
         async def main():
+        motor.set_voltage(12)
         while True:
-        torque = motor.get_torque()
-        print(f"Torque: {torque:.2}Nm")
+        if motor.is_over_current():
+        print("Warning: Motor is drawing too much current")
+        current = motor.get_current()
+        print(f"Current: {current:.2}A")
+
         await vasyncio.Sleep(10, MILLIS)
 
         vasyncio.run(main)
@@ -5503,9 +7226,9 @@ class Motor:
         """
         ...
 
-    def get_voltage(self) -> float:
+    def is_driver_fault(self) -> bool:
         """
-        Returns the voltage the motor is drawing in volts.
+        Returns `True` if a H-bridge (motor driver) fault has occurred.
 
         # Raises
 
@@ -5514,7 +7237,7 @@ class Motor:
 
         # Examples
 
-        Print the voltage drawn by a motor:
+        Print a warning if the motor's H-bridge has a fault:
 
         ```python
         from venice import *
@@ -5522,9 +7245,13 @@ class Motor:
         motor = Motor(1)
 
         async def main():
+        motor.set_voltage(12)
         while True:
-        voltage = motor.get_voltage()
-        print(f"Voltage: {voltage:.2}V")
+        if motor.is_driver_fault():
+        print("Warning: Motor has a H-bridge fault")
+        current = motor.get_current()
+        print(f"Current: {current:.2}A")
+
         await vasyncio.Sleep(10, MILLIS)
 
         vasyncio.run(main)
@@ -5532,43 +7259,48 @@ class Motor:
         """
         ...
 
-    def get_raw_position(self) -> int:
+    def is_driver_over_current(self) -> bool:
         """
-        Returns the most recently recorded raw encoder tick data from the motor's IME.
-
-        The motor's integrated encoder has a TPR of 4096. Gearset is not taken into consideration
-        when dealing with the raw value, meaning this measurement will be taken relative to the
-        motor's internal position *before* being geared down from 3600RPM.
-
-        Methods such as `Motor.reset_position` and `Motor.set_position` do not change the value of
-        this raw measurement.
+        Returns `True` if the motor's H-bridge has an over-current fault.
 
         # Raises
 
-        - `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
         connected.
 
         # Examples
 
-        ```python
+        Print a warning if it draws too much current:
+
+        ```no_run
         from venice import *
 
-        async def main():
         motor = Motor(1)
+
+        async def main():
+        motor.set_voltage(12)
         while True:
-        raw_pos = motor.get_raw_position()
-        print(f"Raw Position: {raw_pos}")
+        if motor.is_driver_over_current():
+        print("Warning: Motor is drawing too much current")
+        current = motor.get_current()
+        print(f"Current: {current:.2}A")
 
-        await vasyncio.Sleep(Motor.WRITE_INTERVAL_MS, MILLIS)
+        await vasyncio.Sleep(10, MILLIS)
 
-        vasyncio.run(main())
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def get_current(self) -> float:
+    def get_position(self, unit: RotationUnit) -> float:
         """
-        Returns the electrical current draw of the motor in amps.
+        Returns the angular position of the motor as measured by the IME (integrated motor encoder).
+
+        # Gearing affects position!
+
+        Position measurements are dependent on the Motor's `Gearset`, and may be reported
+        incorrectly if the motor is configured with the incorrect gearset variant. Make sure
+        that the motor is configured with the same gearset as its physical cartridge color.
 
         # Raises
 
@@ -5577,7 +7309,7 @@ class Motor:
 
         # Examples
 
-        Print the current draw of a motor:
+        Print the current position of a motor:
 
         ```python
         from venice import *
@@ -5585,11 +7317,9 @@ class Motor:
         motor = Motor(1)
 
         async def main():
-        motor.set_voltage(motor.max_voltage)
         while True:
-        current = motor.get_current()
-        print(f"Current: {current:.2}A")
-
+        position = motor.get_position(DEGREES)
+        print(f"Position: {position}")
         await vasyncio.Sleep(10, MILLIS)
 
         vasyncio.run(main)
@@ -5597,12 +7327,12 @@ class Motor:
         """
         ...
 
-    def get_efficiency(self) -> float:
+    def set_position(self, position: float, unit: RotationUnit) -> None:
         """
-        Returns the efficiency of the motor from a range of [0.0, 1.0].
+        Sets the current encoder position to the given position without moving the motor.
 
-        An efficiency of 1.0 means that the motor is moving electrically while drawing no electrical
-        power, and an efficiency of 0.0 means that the motor is drawing power but not moving.
+        Analogous to taring or resetting the encoder so that the new position is equal to the given
+        position.
 
         # Raises
 
@@ -5611,29 +7341,39 @@ class Motor:
 
         # Examples
 
-        Print the efficiency of a motor:
+        Set the current position of the motor to 90 degrees:
 
         ```python
         from venice import *
 
         motor = Motor(1)
+        motor.set_position(90, DEGREES)
+        ```
 
-        async def main():
-        motor.set_voltage(motor.max_voltage)
-        while True:
-        efficiency = motor.get_efficiency()
-        print(f"Current: {efficiency:.2}")
+        Reset the position of the motor to 0 degrees (analogous to `Motor.reset_position`):
 
-        await vasyncio.Sleep(10, MILLIS)
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+        motor.set_position(0, DEGREES)
         ```
         """
         ...
 
-    def get_current_limit(self) -> float:
+    def set_direction(self, direction: Direction) -> None:
         """
-        Returns the current limit for the motor in amps.
+        Sets the motor to operate in a given `Direction`.
 
-        This limit can be configured with the `Motor.set_current_limit` method.
+        This determines which way the motor considers to be “forwards”. You can use the marking on
+        the back of the motor as a reference:
+
+        - When `Direction.FORWARD` is specified, positive velocity/voltage values will cause the
+        motor to rotate **with the arrow on the back**. Position will increase as the motor
+        rotates **with the arrow**.
+        - When `Direction::REVERSE` is specified, positive velocity/voltage values will cause the
+        motor to rotate **against the arrow on the back**. Position will increase as the motor
+        rotates **against the arrow**.
 
         # Raises
 
@@ -5642,21 +7382,18 @@ class Motor:
 
         # Examples
 
-        Print the current limit of a motor:
-
         ```python
         from venice import *
 
-        motor = Motor(1)
-        current_limit = motor.get_current_limit()
-        print(f"Current Limit: {current_limit:.2}A")
+        motor = Motor(1, Direction.FORWARD)
+        motor.set_direction(Direction.REVERSE)
         ```
         """
         ...
 
-    def get_voltage_limit(self) -> float:
+    def get_direction(self, /) -> Direction:
         """
-        Returns the voltage limit for the motor if one has been explicitly set.
+        Returns the `Direction` of this motor.
 
         # Raises
 
@@ -5665,21 +7402,46 @@ class Motor:
 
         # Examples
 
-        Print the voltage limit of a motor:
+        ```python
+        from venice import *
+
+        def print_motor_direction(motor: Motor):
+        if motor.get_direction() == Direction.FORWARD:
+        print("Motor is set to forwards")
+        else:
+        print("Motor is set to reverse")
+        ```
+        """
+        ...
+
+    def get_status(self) -> int:
+        """
+        Returns the status flags of a motor.
+
+        # Errors
+
+        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
+        connected.
+
+        # Examples
+
+        Check if a motor is "busy" (busy only occurs if communicating with the motor fails)
 
         ```python
         from venice import *
 
-        motor = Motor(1)
-        voltage_limit = motor.get_voltage_limit()
-        print(f"Voltage Limit: {voltage_limit:.2}V")
+        BUSY_FLAG = 0x01
+
+        def is_motor_busy(motor: Motor) -> bool:
+        # Test if the motor status bits contain `BUSY_FLAG`
+        motor.get_status() & BUSY_FLAG == BUSY_FLAG
         ```
         """
         ...
 
-    def get_temperature(self) -> float:
+    def get_faults(self) -> int:
         """
-        Returns the internal temperature recorded by the motor in increments of 5 °C.
+        Returns the fault flags of the motor.
 
         # Raises
 
@@ -5688,478 +7450,713 @@ class Motor:
 
         # Examples
 
-        Turn off the motor if it gets too hot:
+        Check if a motor is over temperature:
 
         ```python
         from venice import *
 
+        OVER_TEMPERATURE_FLAG = 0x01
+
         motor = Motor(1)
 
         async def main():
-        motor.set_voltage(12)
         while True:
-        if motor.get_temperature() > 30:
-        motor.brake(BrakeMode.COAST)
-        else:
-        motor.set_voltage(12)
+        faults = motor.get_faults()
+        print(f"Status: 0x{status:08x}")
+
+        # Test if `faults` contains `OVER_TEMPERATURE_FLAG`
+        if faults & OVER_TEMPERATURE_FLAG == OVER_TEMPERATURE_FLAG:
+        print("Warning: Motor is over temperature!")
         await vasyncio.Sleep(10, MILLIS)
+
+        vasyncio.run(main)
         ```
         """
         ...
 
-    def set_profiled_velocity(self, velocity: int) -> None:
+    def free(self) -> None:
         """
-        Changes the output velocity for a profiled movement (motor_move_absolute or
-        motor_move_relative).
+        Release this device and free its Smart Port lock. This binding will become unusable after
+        this call, but you can reuse the underlying Smart Port number in a new device.
 
-        This will have no effect if the motor is not following a profiled movement.
+        Any attempts to use this device after freeing will result in a `ValueError` being raised.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `ValueError`: If the device has already been freed.
 
         # Examples
 
+        Construct a `Motor`, free it, then construct a `RotationSensor` with the same Smart Port:
+
         ```python
         from venice import *
 
         motor = Motor(1)
-
-        # Set the motor's target to a Position so that changing the velocity isn't a noop.
-        motor.set_position_target(90, DEGREES, 200)
-        motor.set_profiled_velocity(100)
+        motor.free()
+        # `motor` is now unusable, but port 1 can be used in another device, such as a `RotationSensor`
+        rotation_sensor = RotationSensor(1)
         ```
         """
         ...
 
-    def reset_position(self) -> None:
+class MotorGroup:
+    """
+    Several motors that are driven together as one.
+
+    A `MotorGroup` is useful for mechanisms that are powered by more than one motor, such as one
+    side of a drivetrain or a double-motor lift. Every command sent to the group is sent to each of
+    its motors, and readings from the group combine the readings of each motor:
+
+    - Position, velocity, voltage and efficiency are averaged.
+    - Current, power and torque are summed.
+    - Temperature is the hottest motor's temperature.
+    - Fault and status flags are combined with a bitwise OR, and the `is_*` flag checks are `True`
+    if any motor has the flag set.
+
+    Each motor's `Direction` is chosen when the group is created, so motors that are mounted facing
+    the opposite way can be reversed once and then commanded like the rest of the group. Unlike
+    `Motor`, a group has no `set_direction` method.
+
+    The group reserves each of its Smart Ports until it is freed with `MotorGroup.free`.
+
+    # Errors from individual motors
+
+    Commands are always sent to every motor in the group, even if sending it to an earlier motor
+    failed, so one disconnected motor won't leave the others running with a stale command. If any
+    motor failed, the first error is raised once every motor has been commanded. Readings raise as
+    soon as any motor fails to report.
+    """
+    def __init__(
+        self,
+        motors: list[int | tuple[int, Direction]],
+        gearset: Gearset = Gearset.GREEN,
+        /,
+    ) -> None:
         """
-        Sets the current encoder position to zero without moving the motor.
+        Creates a group of 11W (V5) Smart Motors.
 
-        Analogous to taring or resetting the encoder to the current position.
+        Each entry of `motors` is either a port number, for a motor spinning `Direction.FORWARD`, or
+        a `(port, Direction)` pair. Every motor in the group uses `gearset`.
+
+        See `MotorGroup.new_exp` to create a group of 5.5W (EXP) Smart Motors.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        - `TypeError`: If `motors` isn't a list or tuple of port numbers and `(port, Direction)`
+        pairs.
+        - `ValueError`: If `motors` is empty, names an invalid port or the same port twice, or if any
+        of the ports is already in use. In that case, none of the ports are reserved.
 
         # Examples
 
-        Move the motor in increments of 10 degrees:
+        Drive the left side of a drivetrain, where the back motor is mounted backwards:
 
         ```python
         from venice import *
 
-        motor = Motor(1)
-
-        async def main():
-        while True:
-        motor.set_position_target(10, DEGREES, 200)
-        await vasyncio.Sleep(1, SECOND)
-        motor.reset_position()
+        left = MotorGroup([1, 2, (3, Direction.REVERSE)], Gearset.BLUE)
+        left.set_voltage(6)
         ```
         """
         ...
 
-    def set_current_limit(self, limit: float) -> None:
+    @staticmethod
+    def new_exp(motors: list[int | tuple[int, Direction]], /) -> MotorGroup:
         """
-        Sets the current limit for the motor in amps.
+        Creates a group of 5.5W (EXP) Smart Motors.
+
+        `motors` is given in the same form as for `MotorGroup()`.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        - `TypeError`: If `motors` isn't a list or tuple of port numbers and `(port, Direction)`
+        pairs.
+        - `ValueError`: If `motors` is empty, names an invalid port or the same port twice, or if any
+        of the ports is already in use. In that case, none of the ports are reserved.
 
         # Examples
 
-        Limit the current draw of a motor to 2.5A:
-
         ```python
         from venice import *
 
-        motor = Motor(1)
-        motor.set_current_limit(2.5)
+        intake = MotorGroup.new_exp([4, (5, Direction.REVERSE)])
+        intake.set_voltage(Motor.EXP_MAX_VOLTAGE)
         ```
         """
         ...
 
-    def set_voltage_limit(self, limit: float) -> None:
+    def set_voltage(self, volts: float) -> None:
         """
-        Sets the voltage limit for the motor in volts.
+        Sets the output voltage of every motor in the group.
+
+        See `Motor.set_voltage`.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        # Examples
+    def set_velocity(self, rpm: int) -> None:
+        """
+        Spins every motor in the group at a target velocity in RPM.
 
-        Limit the voltage of a motor to 4V:
+        See `Motor.set_velocity`.
 
-        ```python
-        from venice import *
+        # Raises
 
-        motor = Motor(1)
-        motor.set_voltage_limit(4)
-        # Will appear as if the voltage was set to only 4V
-        motor.set_voltage(12)
-        ```
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def is_over_temperature(self) -> bool:
+    def brake(self, mode: BrakeMode) -> None:
         """
-        Returns `True` if the motor's over temperature flag is set.
+        Stops every motor in the group with the given `BrakeMode`.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        # Examples
+    def set_gearset(self, gearset: Gearset) -> None:
+        """
+        Sets the gearset of every motor in the group.
 
-        Turn off the motor if it gets too hot:
+        # Raises
 
-        ```python
-        from venice import *
+        `DeviceError`: If any motor is a 5.5W EXP Smart Motor, which has no swappable gearset, or if
+        any motor is disconnected.
+        """
+        ...
 
-        motor = Motor(1)
+    def set_position_target(
+        self, position: float, unit: RotationUnit, velocity: int, /
+    ) -> None:
+        """
+        Sets an absolute position target for every motor in the group to attempt to reach.
 
-        async def main():
-        motor.set_voltage(12)
-        while True:
-        if motor.is_over_temperature():
-        motor.brake(BrakeMode.COAST)
-        else:
-        motor.set_voltage(12)
-        await vasyncio.Sleep(10, MILLIS)
+        Each motor moves to the target as measured by its own encoder, so motors whose positions
+        have drifted apart should be reset with `MotorGroup.reset_position` first.
 
-        vasyncio.run(main)
-        ```
+        # Raises
+
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def is_over_current(self) -> bool:
+    def set_profiled_velocity(self, velocity: int) -> None:
         """
-        Returns `True` if the motor's over-current flag is set.
+        Changes the output velocity of every motor's profiled movement.
+
+        See `Motor.set_profiled_velocity`.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        # Examples
+    def reset_position(self) -> None:
+        """
+        Sets the encoder position of every motor in the group to zero without moving them.
 
-        Print a warning if the motor draws too much current:
+        # Raises
 
-        ```python
-        from venice import *
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        motor = Motor(1)
+    def set_position(self, position: float, unit: RotationUnit) -> None:
+        """
+        Sets the encoder position of every motor in the group to the given position without moving
+        them.
 
-        async def main():
-        motor.set_voltage(12)
-        while True:
-        if motor.is_over_current():
-        print("Warning: Motor is drawing too much current")
-        current = motor.get_current()
-        print(f"Current: {current:.2}A")
+        # Raises
 
-        await vasyncio.Sleep(10, MILLIS)
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        vasyncio.run(main)
-        ```
+    def set_current_limit(self, limit: float) -> None:
+        """
+        Sets the current limit of every motor in the group in amps.
+
+        The limit applies to each motor separately, so the group as a whole may draw up to `limit`
+        times the number of motors.
+
+        # Raises
+
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def is_driver_fault(self) -> bool:
+    def set_voltage_limit(self, limit: float) -> None:
         """
-        Returns `True` if a H-bridge (motor driver) fault has occurred.
+        Sets the voltage limit of every motor in the group in volts.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        # Examples
+    def get_position(self, unit: RotationUnit) -> float:
+        """
+        Returns the average angular position of the motors in the group.
 
-        Print a warning if the motor's H-bridge has a fault:
+        # Raises
+
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+
+        # Examples
 
         ```python
         from venice import *
 
-        motor = Motor(1)
+        lift = MotorGroup([1, (2, Direction.REVERSE)])
+        print(f"Lift position: {lift.get_position(DEGREES)}")
+        ```
+        """
+        ...
 
-        async def main():
-        motor.set_voltage(12)
-        while True:
-        if motor.is_driver_fault():
-        print("Warning: Motor has a H-bridge fault")
-        current = motor.get_current()
-        print(f"Current: {current:.2}A")
+    def get_velocity(self) -> float:
+        """
+        Returns the average estimated angular velocity of the motors in the group in RPM.
 
-        await vasyncio.Sleep(10, MILLIS)
+        # Raises
 
-        vasyncio.run(main)
-        ```
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def is_driver_over_current(self) -> bool:
+    def get_voltage(self) -> float:
         """
-        Returns `True` if the motor's H-bridge has an over-current fault.
+        Returns the average voltage of the motors in the group in volts.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        # Examples
+    def get_efficiency(self) -> float:
+        """
+        Returns the average efficiency of the motors in the group from a range of [0.0, 1.0].
 
-        Print a warning if it draws too much current:
+        # Raises
 
-        ```no_run
-        from venice import *
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        motor = Motor(1)
+    def get_current(self) -> float:
+        """
+        Returns the total electrical current drawn by the motors in the group in amps.
 
-        async def main():
-        motor.set_voltage(12)
-        while True:
-        if motor.is_driver_over_current():
-        print("Warning: Motor is drawing too much current")
-        current = motor.get_current()
-        print(f"Current: {current:.2}A")
+        # Raises
 
-        await vasyncio.Sleep(10, MILLIS)
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        vasyncio.run(main)
-        ```
+    def get_power(self) -> float:
+        """
+        Returns the total power drawn by the motors in the group in Watts.
+
+        # Raises
+
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def get_position(self, unit: RotationUnit) -> float:
+    def get_torque(self) -> float:
         """
-        Returns the angular position of the motor as measured by the IME (integrated motor encoder).
+        Returns the total torque output of the motors in the group in Nm.
 
-        # Gearing affects position!
+        # Raises
 
-        Position measurements are dependent on the Motor's `Gearset`, and may be reported
-        incorrectly if the motor is configured with the incorrect gearset variant. Make sure
-        that the motor is configured with the same gearset as its physical cartridge color.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
+
+    def get_temperature(self) -> float:
+        """
+        Returns the internal temperature of the hottest motor in the group in increments of 5 °C.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
 
         # Examples
 
-        Print the current position of a motor:
+        Stop the group if any of its motors gets too hot:
 
         ```python
         from venice import *
 
-        motor = Motor(1)
+        drive = MotorGroup([1, 2, 3])
 
         async def main():
-        while True:
-        position = motor.get_position(DEGREES)
-        print(f"Position: {position}")
+        drive.set_voltage(12)
+        while drive.get_temperature() <= 50:
         await vasyncio.Sleep(10, MILLIS)
+        drive.brake(BrakeMode.COAST)
 
-        vasyncio.run(main)
+        vasyncio.run(main())
         ```
         """
         ...
 
-    def set_position(self, position: float, unit: RotationUnit) -> None:
+    def get_status(self) -> int:
         """
-        Sets the current encoder position to the given position without moving the motor.
+        Returns the status flags of every motor in the group, combined with a bitwise OR.
 
-        Analogous to taring or resetting the encoder so that the new position is equal to the given
-        position.
+        See `Motor.get_status`.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        # Examples
+    def get_faults(self) -> int:
+        """
+        Returns the fault flags of every motor in the group, combined with a bitwise OR.
 
-        Set the current position of the motor to 90 degrees:
+        See `Motor.get_faults`.
 
-        ```python
-        from venice import *
+        # Raises
 
-        motor = Motor(1)
-        motor.set_position(90, DEGREES)
-        ```
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        Reset the position of the motor to 0 degrees (analogous to `Motor.reset_position`):
+    def is_over_temperature(self) -> bool:
+        """
+        Returns `True` if any motor in the group has its over temperature flag set.
 
-        ```python
-        from venice import *
+        # Raises
 
-        motor = Motor(1)
-        motor.set_position(0, DEGREES)
-        ```
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def set_direction(self, direction: Direction) -> None:
+    def is_over_current(self) -> bool:
         """
-        Sets the motor to operate in a given `Direction`.
+        Returns `True` if any motor in the group has its over-current flag set.
 
-        This determines which way the motor considers to be “forwards”. You can use the marking on
-        the back of the motor as a reference:
+        # Raises
 
-        - When `Direction.FORWARD` is specified, positive velocity/voltage values will cause the
-        motor to rotate **with the arrow on the back**. Position will increase as the motor
-        rotates **with the arrow**.
-        - When `Direction::REVERSE` is specified, positive velocity/voltage values will cause the
-        motor to rotate **against the arrow on the back**. Position will increase as the motor
-        rotates **against the arrow**.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
+
+    def is_driver_fault(self) -> bool:
+        """
+        Returns `True` if a H-bridge (motor driver) fault has occurred in any motor in the group.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
+        """
+        ...
 
-        # Examples
+    def is_driver_over_current(self) -> bool:
+        """
+        Returns `True` if the H-bridge of any motor in the group has an over-current fault.
 
-        ```python
-        from venice import *
+        # Raises
 
-        motor = Motor(1, Direction.FORWARD)
-        motor.set_direction(Direction.REVERSE)
-        ```
+        `DeviceError`: If any motor is disconnected, or if the wrong type of device is connected to
+        any of the group's ports.
         """
         ...
 
-    def get_direction(self, /) -> Direction:
+    def free(self) -> None:
         """
-        Returns the `Direction` of this motor.
+        Release every motor in this group and free their Smart Port locks. This binding will become
+        unusable after this call, but you can reuse the underlying Smart Port numbers in new
+        devices.
+
+        Any attempts to use this group after freeing will result in a `ValueError` being raised.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        `ValueError`: If the group has already been freed.
+        """
+        ...
 
-        # Examples
+class MoveFuture:
+    """
+    An awaitable that completes once a motor has settled at a position target, created with
+    `Motor.move_to`.
 
-        ```python
-        from venice import *
+    The motor's position and fault flags are checked every 10 milliseconds, the rate at which the
+    Brain reads motor data. Between checks, the awaiting task is parked on the event loop, so other
+    tasks keep running.
+    """
+    def throw(self, exc: BaseException, /) -> Any:
+        """
+        Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
 
-        def print_motor_direction(motor: Motor):
-        if motor.get_direction() == Direction.FORWARD:
-        print("Motor is set to forwards")
-        else:
-        print("Motor is set to reverse")
-        ```
+        The motor brakes and the move is abandoned before the exception propagates.
         """
         ...
 
-    def get_status(self) -> int:
+    def close(self) -> None:
+        """Brakes the motor and abandons the move when the awaiting coroutine is closed."""
+        ...
+
+class ProfileFuture:
+    """
+    An awaitable that drives a motor along a motion profile, created with `Motor.follow_profile`.
+
+    Between updates to the motor's velocity, the awaiting task is parked on the event loop, so
+    other tasks keep running.
+    """
+    def throw(self, exc: BaseException, /) -> Any:
         """
-        Returns the status flags of a motor.
+        Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
 
-        # Errors
+        The motor brakes and the profile is abandoned before the exception propagates.
+        """
+        ...
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+    def close(self) -> None:
+        """Brakes the motor and abandons the profile when the awaiting coroutine is closed."""
+        ...
 
-        # Examples
+class MotorType:
+    """
+    Represents the type of a Smart Motor: either an 11W (V5) or 5.5W (EXP) motor.
 
-        Check if a motor is "busy" (busy only occurs if communicating with the motor fails)
+    Values are returned by the read-only
+    `Motor.motor_type` attribute. `MotorType` is root-importable and isn't constructed directly;
+    use its singleton constants. Values have readable representations such as `MotorType.V5`.
+    """
 
-        ```python
-        from venice import *
+    V5: ClassVar[MotorType]
+    """An 11W Smart Motor."""
+    EXP: ClassVar[MotorType]
+    """A 5.5W Smart Motor."""
 
-        BUSY_FLAG = 0x01
+class DifferentialDrive:
+    """
+    A drivetrain with a left and a right side, such as a tank drive, driven from joystick inputs.
 
-        def is_motor_busy(motor: Motor) -> bool:
-        # Test if the motor status bits contain `BUSY_FLAG`
-        motor.get_status() & BUSY_FLAG == BUSY_FLAG
-        ```
+    Each side is a `Motor` or a `MotorGroup`, commanded by voltage as a fraction of its motors'
+    maximum voltage. Inputs are between -1 and 1, as read from a `Controller`'s sticks, with
+    positive values driving forwards or turning clockwise, to the right.
+
+    Before they are used, inputs are clamped to between -1 and 1 and then shaped. Inputs within
+    `deadband` of zero are ignored, so a stick that doesn't quite centre doesn't creep, and the
+    remaining range is stretched back out to 1 so that control starts smoothly at the dead band's
+    edge. `curve` then shapes the input: a number raises its magnitude to that power, so that 2 or 3
+    gives finer control at low speeds, and a function is called with the input and returns the
+    shaped one. When the inputs would ask for more than full power on either side, both sides are
+    scaled down together, so the robot keeps its heading rather than turning unexpectedly.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    left = MotorGroup([(1, Direction.REVERSE), (2, Direction.REVERSE)])
+    right = MotorGroup([3, 4])
+    drive = DifferentialDrive(left, right, deadband=0.05, curve=2.0)
+    controller = Controller()
+
+    async def main():
+    while True:
+    state = controller.read_state()
+    drive.arcade(state.left_stick.y, state.right_stick.x)
+    await vasyncio.Sleep(Controller.UPDATE_INTERVAL_MS, MILLIS)
+
+    vasyncio.run(main())
+    ```
+    """
+    def __init__(
+        self,
+        left: Motor | MotorGroup,
+        right: Motor | MotorGroup,
+        /,
+        *,
+        deadband: float = 0.0,
+        curve: float | Callable[[float], float] = 1.0,
+    ) -> None:
+        """
+        Creates a drivetrain from its `left` and `right` sides.
+
+        `deadband` is between 0 and 1, and `curve` is a positive exponent or a function from a
+        float to a float.
+
+        # Raises
+
+        - `TypeError`: If `left` or `right` isn't a `Motor` or `MotorGroup`, or `curve` isn't a
+        number or callable.
+        - `ValueError`: If `deadband` isn't at least 0 and less than 1, or `curve` is a number that
+        isn't positive and finite.
         """
         ...
 
-    def get_faults(self) -> int:
+    def tank(self, left: float, right: float) -> None:
         """
-        Returns the fault flags of the motor.
+        Drives each side directly from its own input, such as the left and right sticks' `y`.
 
         # Raises
 
-        `DeviceError`: If no device is connected to the port, or if the wrong type of device is
-        connected.
+        - `DeviceError`: If a motor can't be used.
+        - Any exception raised by the input curve.
+        """
+        ...
 
-        # Examples
+    def arcade(self, forward: float, turn: float) -> None:
+        """
+        Drives forwards at `forward` while turning clockwise at `turn`.
 
-        Check if a motor is over temperature:
+        Turning is added to one side and taken from the other, so the robot turns in place when
+        `forward` is zero.
 
-        ```python
-        from venice import *
+        # Raises
 
-        OVER_TEMPERATURE_FLAG = 0x01
+        - `DeviceError`: If a motor can't be used.
+        - Any exception raised by the input curve.
+        """
+        ...
 
-        motor = Motor(1)
+    def curvature(self, throttle: float, curvature: float) -> None:
+        """
+        Drives forwards at `throttle` along a curve set by `curvature`, which is clockwise when
+        positive.
 
-        async def main():
-        while True:
-        faults = motor.get_faults()
-        print(f"Status: 0x{status:08x}")
+        Unlike `DifferentialDrive.arcade`, the turning rate grows with speed, so the same
+        `curvature` traces the same arc whether the robot is moving quickly or slowly. This makes
+        the robot easier to steer at speed. When `throttle` is zero after the dead band, the robot
+        turns in place at `curvature` instead.
 
-        # Test if `faults` contains `OVER_TEMPERATURE_FLAG`
-        if faults & OVER_TEMPERATURE_FLAG == OVER_TEMPERATURE_FLAG:
-        print("Warning: Motor is over temperature!")
-        await vasyncio.Sleep(10, MILLIS)
+        # Raises
 
-        vasyncio.run(main)
-        ```
+        - `DeviceError`: If a motor can't be used.
+        - Any exception raised by the input curve.
         """
         ...
 
-    def free(self) -> None:
+class HolonomicDrive:
+    """
+    A drivetrain with four omni or mecanum wheels that can drive in any direction, such as an
+    X-drive or a mecanum drive, driven from joystick inputs.
+
+    Each wheel is a `Motor` or a `MotorGroup`, commanded by voltage as a fraction of its motors'
+    maximum voltage. Inputs are between -1 and 1, as read from a `Controller`'s sticks, with
+    positive values driving forwards, moving right, or turning clockwise. The wheels are expected to
+    be set up so that a positive voltage on each drives the robot forwards.
+
+    Inputs are clamped, given a dead band and shaped by a curve as in `DifferentialDrive`. When the
+    inputs would ask for more than full power on any wheel, every wheel is scaled down together, so
+    the robot keeps moving in the requested direction.
+
+    With an `imu`, `HolonomicDrive.field_oriented` drives relative to the field instead of the
+    robot: forwards is the direction the robot faced when the sensor's rotation was last reset,
+    however the robot has turned since.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    imu = InertialSensor(5)
+    drive = HolonomicDrive(
+    Motor(1), Motor(2, Direction.REVERSE), Motor(3), Motor(4, Direction.REVERSE), imu=imu,
+    )
+    controller = Controller()
+
+    async def main():
+    await imu.calibrate()
+    while True:
+    state = controller.read_state()
+    drive.field_oriented(state.left_stick.y, state.left_stick.x, state.right_stick.x)
+    await vasyncio.Sleep(Controller.UPDATE_INTERVAL_MS, MILLIS)
+
+    vasyncio.run(main())
+    ```
+    """
+    def __init__(
+        self,
+        front_left: Motor | MotorGroup,
+        front_right: Motor | MotorGroup,
+        back_left: Motor | MotorGroup,
+        back_right: Motor | MotorGroup,
+        /,
+        *,
+        imu: InertialSensor | None = None,
+        deadband: float = 0.0,
+        curve: float | Callable[[float], float] = 1.0,
+    ) -> None:
         """
-        Release this device and free its Smart Port lock. This binding will become unusable after
-        this call, but you can reuse the underlying Smart Port number in a new device.
+        Creates a drivetrain from its four wheels.
 
-        Any attempts to use this device after freeing will result in a `ValueError` being raised.
+        `deadband` and `curve` are as in `DifferentialDrive`. `imu` is needed for
+        `HolonomicDrive.field_oriented`.
 
         # Raises
 
-        `ValueError`: If the device has already been freed.
-
-        # Examples
+        - `TypeError`: If a wheel isn't a `Motor` or `MotorGroup`, `imu` isn't an
+        `InertialSensor`, or `curve` isn't a number or callable.
+        - `ValueError`: If `deadband` isn't at least 0 and less than 1, or `curve` is a number that
+        isn't positive and finite.
+        """
+        ...
 
-        Construct a `Motor`, free it, then construct a `RotationSensor` with the same Smart Port:
+    def arcade(self, forward: float, strafe: float, turn: float, /) -> None:
+        """
+        Drives relative to the robot: forwards at `forward`, right at `strafe`, and clockwise at
+        `turn`.
 
-        ```python
-        from venice import *
+        # Raises
 
-        motor = Motor(1)
-        motor.free()
-        # `motor` is now unusable, but port 1 can be used in another device, such as a `RotationSensor`
-        rotation_sensor = RotationSensor(1)
-        ```
+        - `DeviceError`: If a motor can't be used.
+        - Any exception raised by the input curve.
         """
         ...
 
-class MotorType:
-    """
-    Represents the type of a Smart Motor: either an 11W (V5) or 5.5W (EXP) motor.
+    def field_oriented(self, forward: float, strafe: float, turn: float, /) -> None:
+        """
+        Drives relative to the field: towards the field's forwards at `forward` and its right at
+        `strafe`, whichever way the robot faces, while turning clockwise at `turn`.
 
-    Values are returned by the read-only
-    `Motor.motor_type` attribute. `MotorType` is root-importable and isn't constructed directly;
-    use its singleton constants. Values have readable representations such as `MotorType.V5`.
-    """
+        The robot's heading is read from `imu`.
 
-    V5: ClassVar[MotorType]
-    """An 11W Smart Motor."""
-    EXP: ClassVar[MotorType]
-    """A 5.5W Smart Motor."""
+        # Raises
+
+        - `RuntimeError`: If the drivetrain has no `imu`.
+        - `DeviceError`: If a motor or the inertial sensor can't be used.
+        - Any exception raised by the input curve.
+        """
+        ...
 
 class Gesture:
     """Gesture data from an `OpticalSensor`."""
@@ -6360,6 +8357,36 @@ class OpticalSensor:
         """
         ...
 
+    def wait_until_proximity(self, proximity: float) -> vasyncio.UntilFuture:
+        """
+        Waits until the sensor's proximity reading is at least `proximity`.
+
+        `proximity` uses the same scale as `OpticalSensor.get_proximity`, where higher values mean
+        closer objects. Awaiting the returned `UntilFuture` returns `None`.
+
+        # Raises
+
+        `DeviceError`: If, while awaiting, no device is connected to the port, or if the wrong type
+        of device is connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        sensor = OpticalSensor(1)
+        intake = Motor(2)
+
+        async def main():
+        intake.set_voltage(12)
+        await sensor.wait_until_proximity(0.8)
+        intake.brake(BrakeMode.HOLD)
+
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
     def get_color(self) -> OpticalRgb:
         """
         Returns the processed RGB color data from the sensor.
@@ -6661,6 +8688,38 @@ class RotationSensor:
         """
         ...
 
+    def wait_until_position(self, position: float, unit: RotationUnit) -> vasyncio.UntilFuture:
+        """
+        Waits until the sensor's position reaches `position`, measured in `unit`.
+
+        The returned `UntilFuture` compares the position when it is awaited against `position`, then
+        completes once the sensor has moved as far as `position` in that direction. Because the
+        position is polled, the sensor may be slightly past `position` by the time the await
+        completes. Awaiting it returns `None`.
+
+        # Raises
+
+        `DeviceError`: If, while awaiting, no device is connected to the port, or if the wrong type
+        of device is connected.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        lift = Motor(1)
+        sensor = RotationSensor(2)
+
+        async def main():
+        lift.set_voltage(8)
+        await sensor.wait_until_position(90, DEGREES)
+        lift.brake(BrakeMode.HOLD)
+
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
     def set_position(self, position: float, unit: RotationUnit) -> None:
         """
         Sets the sensor's position reading.
@@ -4,7 +4,7 @@ Display and touchscreen input.
 Contains user calls to the V5 Brain display for touching and displaying graphics.
 """
 
-from typing import Any, ClassVar
+from typing import IO, Any, ClassVar
 
 from . import Color
 
@@ -114,6 +114,567 @@ class TouchEvent:
     is_released: bool
     is_held: bool
 
+class Image:
+    """
+    A decoded picture that can be drawn on the display, available as `display.Image`.
+
+    Images are decoded from PNG, BMP or QOI data, recognized from their first bytes. Every PNG
+    color type, bit depth and interlacing method is supported, as are uncompressed BMPs with 1, 4,
+    8, 16, 24 or 32 bits per pixel. Images can have at most 460800 pixels, four times as many as
+    the 480 by 240 display, though a full-screen image is usually as large as is useful.
+
+    Decoded images are held in memory at four bytes per pixel, whatever their format. QOI decodes
+    the fastest and PNG files are the smallest to bundle.
+
+    The read-only `width` and `height` attributes are the image's size in pixels. Images print as
+    `Image(width=..., height=...)`.
+
+    # Examples
+
+    Draw a logo bundled with the program in the middle of the display:
+
+    ```python
+    from venice import *
+
+    logo = display.Image(open("logo.png", "rb"))
+    logo.draw(240 - logo.width // 2, 120 - logo.height // 2)
+    ```
+
+    Draw the second of a row of 32 by 32 icons at double size, leaving magenta pixels undrawn:
+
+    ```python
+    from venice import *
+
+    icons = display.Image(open("icons.bmp", "rb"))
+    icons.draw(10, 10, region=(32, 0, 32, 32), scale=2.0, key=Color(255, 0, 255))
+    ```
+    """
+
+    width: int
+    height: int
+    def __init__(self, source: bytes | bytearray | memoryview | IO[bytes], /) -> None:
+        """
+        Decodes an image from `source`, which is a bytes-like object holding the image's data or a
+        file opened with `open`.
+
+        A file is read from its current position to its end.
+
+        # Raises
+
+        - `TypeError`: If `source` isn't a bytes-like object or a file.
+        - `ValueError`: If the data isn't a PNG, BMP or QOI image, is corrupt or truncated, uses a
+        feature that isn't supported, or has too many pixels.
+        - `MemoryError`: If there isn't enough memory to read or decode the image.
+        - `OSError`: If the file can't be read.
+        """
+        ...
+
+    def draw(
+        self,
+        x: int,
+        y: int,
+        /,
+        *,
+        region: tuple[int, int, int, int] | None = None,
+        scale: float = 1.0,
+        key: Color | None = None,
+    ) -> None:
+        """
+        Draws the image with its top-left corner at (`x`, `y`) on the display.
+
+        `region`, as `(x, y, width, height)` in the image's pixels, draws only that part of the
+        image, such as one icon from a sheet of them. `scale` stretches or shrinks the image,
+        picking the nearest pixel rather than blending them. Pixels that are less than half
+        opaque, or that match `key`, are left undrawn so that whatever is behind them shows
+        through. Anything that falls outside the display is clipped.
+
+        # Raises
+
+        - `TypeError`: If `region` isn't a tuple of four integers or `key` isn't a `Color`.
+        - `ValueError`: If `region` is empty or extends outside the image, or `scale` isn't
+        positive and finite.
+        """
+        ...
+
+class Plot:
+    """
+    A scrolling strip chart of one or more channels of samples, available as `display.Plot`.
+
+    Every call to `push` records one sample per channel, and `draw` shows the samples pushed since
+    it was last called. Time runs down the plot: the newest sample is on the bottom row, one row
+    per sample, and values are plotted across its width. Since the display can only scroll
+    vertically, this lets `draw` move the older samples up with `display.scroll_region` and draw
+    just the new rows, so feeding a plot from a task at 50 Hz takes little time.
+
+    The plot keeps as many samples as it has rows, and older samples are discarded. Each channel is
+    drawn in its own color and named in a legend at the top, above the ends of the value axis.
+
+    The value axis is fixed if `min` and `max` are given. Otherwise it scales automatically,
+    growing whenever a sample falls outside of it, which redraws the whole plot. `NaN` samples leave
+    a gap in their channel's line.
+
+    The read-only `min` and `max` attributes are the ends of the value axis, or `None` if it scales
+    automatically and nothing has been pushed yet.
+
+    # Examples
+
+    Plot a flywheel's velocity against its target:
+
+    ```python
+    from venice import *
+
+    flywheel = Motor(1)
+    target = 3000.0
+    plot = display.Plot(0, 0, 480, 240, channels=[("target", Color.GRAY), ("rpm", Color.LIME)])
+
+    async def main():
+    ticker = vasyncio.Ticker(20, MILLIS)
+    while True:
+    await ticker
+    plot.push(target, flywheel.get_velocity())
+    plot.draw()
+
+    vasyncio.run(main())
+    ```
+    """
+
+    min: float | None
+    max: float | None
+    def __init__(
+        self,
+        x: int,
+        y: int,
+        width: int,
+        height: int,
+        /,
+        *,
+        channels: list[tuple[str, Color]] | tuple[tuple[str, Color], ...],
+        min: float | None = None,
+        max: float | None = None,
+        grid: int = 4,
+        background: Color = Color.BLACK,
+    ) -> None:
+        """
+        Creates a plot filling the region whose top-left corner is at `x` and `y`, which is
+        `width` by `height` pixels.
+
+        `channels` lists each channel's name and color, in the order their samples are pushed.
+        The value axis runs from `min` to `max`, or scales automatically if they are `None`, and
+        is divided by `grid` gridlines into equal parts. Nothing is drawn until `draw` is called.
+
+        # Raises
+
+        - `TypeError`: If `channels` isn't a list or tuple of `(str, Color)` tuples, or an axis
+        limit isn't a number or `None`.
+        - `ValueError`: If there are no channels or more than eight, a channel name contains a
+        NUL character, only one of `min` and `max` is given, `min` isn't less than `max`, `grid`
+        is more than 16, the region is too small to plot in, or the region exceeds the
+        supported coordinate range.
+        """
+        ...
+
+    def push(self, *values: float) -> None:
+        """
+        Records one sample for each channel, in the order the channels were given.
+
+        The samples are shown the next time `draw` is called.
+
+        # Raises
+
+        - `TypeError`: If a sample isn't a number.
+        - `ValueError`: If there isn't one sample per channel.
+        """
+        ...
+
+    def draw(self) -> None:
+        """
+        Shows the samples pushed since the plot was last drawn.
+
+        The first draw, and any after the value axis changes or `redraw` is called, draws the
+        whole plot. Otherwise only the new rows are drawn, after scrolling the rest up. With
+        `RenderMode.DOUBLE_BUFFERED`, `display.render` must still be called for them to appear.
+        """
+        ...
+
+    def redraw(self) -> None:
+        """
+        Draws the whole plot again at the next call to `draw`.
+
+        Call this after drawing over the plot with anything else.
+        """
+        ...
+
+    def clear(self) -> None:
+        """
+        Discards every sample.
+
+        An automatic value axis starts over from the next sample pushed.
+        """
+        ...
+
+    def set_range(self, min: float | None, max: float | None, /) -> None:
+        """
+        Fixes the value axis to run from `min` to `max`, or makes it scale automatically if both
+        are `None`.
+
+        An automatic axis is fitted to the samples already in the plot.
+
+        # Raises
+
+        - `TypeError`: If an axis limit isn't a number or `None`.
+        - `ValueError`: If only one of `min` and `max` is given, or `min` isn't less than `max`.
+        """
+        ...
+
+class LinearGradient:
+    """
+    A color that changes gradually along a line, available as `display.LinearGradient`.
+
+    A gradient can be passed instead of a `Color` to any of the shape drawing functions, such as
+    `display.fill_polygon` or `display.fill_round_rect`. Pixels are colored by how far along the
+    line from (`start_x`, `start_y`) to (`end_x`, `end_y`) they are, and the colors at the ends
+    continue past them.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    # Fade the top of the display from blue to black.
+    sky = display.LinearGradient(0, 0, 0, 119, Color(0, 80, 200), Color.BLACK)
+    display.fill_round_rect(0, 0, 480, 120, 0, sky)
+    ```
+    """
+    def __init__(
+        self,
+        start_x: int,
+        start_y: int,
+        end_x: int,
+        end_y: int,
+        start_color: Color,
+        end_color: Color,
+        /,
+    ) -> None:
+        """
+        Creates a gradient from `start_color` at (`start_x`, `start_y`) to `end_color` at
+        (`end_x`, `end_y`).
+        """
+        ...
+
+class Canvas:
+    """
+    An image in memory that can be drawn on and then drawn on the display, available as
+    `display.Canvas`.
+
+    Canvases have the same drawing functions as the `display` module, as methods, except for
+    `draw_text`, since text is rendered by the display itself. Unlike on the display, shapes drawn
+    with `antialias=True` are blended with whatever is already on the canvas, so they don't take a
+    `bg_color`.
+
+    Every pixel has an alpha channel, and a new canvas is fully transparent. `blit` draws one
+    canvas or `Image` on another, blending the pixels that are partly transparent, which lets a
+    sprite be drawn over a background drawn once ahead of time. `draw` puts the canvas on the
+    display, leaving its fully transparent pixels undrawn. With
+    `display.RenderMode.DOUBLE_BUFFERED`, composing each frame on a canvas and drawing it in one go
+    keeps moving sprites from flickering.
+
+    Canvases are held in memory at four bytes per pixel, so a full-screen 480 by 240 canvas takes
+    460800 bytes. The read-only `width` and `height` attributes are the canvas's size in pixels.
+    Canvases print as `Canvas(width=..., height=...)`.
+
+    # Examples
+
+    Draw a robot moving over a field map:
+
+    ```python
+    from venice import *
+
+    field = display.Canvas(240, 240, color=Color(40, 40, 40))
+    for i in range(1, 6):
+    field.draw_line(i * 40, 0, i * 40, 239, Color.GRAY)
+    field.draw_line(0, i * 40, 239, i * 40, Color.GRAY)
+
+    robot = display.Canvas(21, 21)
+    robot.fill_circle(10, 10, 10, Color.BLUE)
+    robot.draw_line(10, 10, 20, 10, Color.WHITE, thickness=3)
+
+    frame = display.Canvas(240, 240)
+    display.set_render_mode(display.RenderMode.DOUBLE_BUFFERED)
+
+    async def main():
+    ticker = vasyncio.Ticker(20, MILLIS)
+    x = 0
+    while True:
+    await ticker
+    frame.blit(field, 0, 0)
+    frame.blit(robot, x - 10, 110)
+    frame.draw(120, 0)
+    display.render()
+    x = (x + 1) % 240
+
+    vasyncio.run(main())
+    ```
+    """
+
+    width: int
+    height: int
+    def __init__(
+        self, width: int, height: int, /, *, color: Color | None = None
+    ) -> None:
+        """
+        Creates a canvas that's `width` by `height` pixels, filled with `color`, or fully
+        transparent if `color` is `None`.
+
+        # Raises
+
+        - `ValueError`: If `width` or `height` is zero.
+        """
+        ...
+
+    def erase(self, color: Color) -> None:
+        """Fills the whole canvas with `color`."""
+        ...
+
+    def clear(self) -> None:
+        """Makes the whole canvas fully transparent."""
+        ...
+
+    def draw_pixel(self, x: int, y: int, color: Color, /) -> None:
+        """Like `display.draw_pixel`, but draws on the canvas."""
+        ...
+
+    def draw_line(
+        self,
+        start_x: int,
+        start_y: int,
+        end_x: int,
+        end_y: int,
+        color: Color | LinearGradient,
+        /,
+        *,
+        thickness: int = 1,
+        antialias: bool = False,
+    ) -> None:
+        """
+        Like `display.draw_line`, but draws on the canvas.
+
+        Lines on a canvas are always rasterized, even when they're one pixel wide and not
+        anti-aliased.
+
+        # Raises
+
+        - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+        - `ValueError`: If `thickness` is zero.
+        """
+        ...
+
+    def draw_circle(self, x: int, y: int, radius: int, color: Color, /) -> None:
+        """Like `display.draw_circle`, but draws on the canvas."""
+        ...
+
+    def fill_circle(self, x: int, y: int, radius: int, color: Color, /) -> None:
+        """Like `display.fill_circle`, but draws on the canvas."""
+        ...
+
+    def draw_rect(
+        self, x: int, y: int, width: int, height: int, color: Color, /
+    ) -> None:
+        """Like `display.draw_rect`, but draws on the canvas."""
+        ...
+
+    def fill_rect(
+        self, x: int, y: int, width: int, height: int, color: Color, /
+    ) -> None:
+        """Like `display.fill_rect`, but draws on the canvas."""
+        ...
+
+    def draw_buffer(
+        self, x: int, y: int, width: int, height: int, buffer: Any, /
+    ) -> None:
+        """
+        Like `display.draw_buffer`, but draws on the canvas.
+
+        # Raises
+
+        - `TypeError`: If `buffer` doesn't support the readable buffer protocol.
+        - `ValueError`: If the buffer is unaligned, its readable byte length isn't a multiple of
+        four, or its pixel count doesn't equal `width * height`.
+        """
+        ...
+
+    def draw_polygon(
+        self,
+        points: list[tuple[int, int]] | tuple[tuple[int, int], ...],
+        color: Color | LinearGradient,
+        /,
+        *,
+        thickness: int = 1,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.draw_polygon`, but draws on the canvas."""
+        ...
+
+    def fill_polygon(
+        self,
+        points: list[tuple[int, int]] | tuple[tuple[int, int], ...],
+        color: Color | LinearGradient,
+        /,
+        *,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.fill_polygon`, but draws on the canvas."""
+        ...
+
+    def draw_triangle(
+        self,
+        x0: int,
+        y0: int,
+        x1: int,
+        y1: int,
+        x2: int,
+        y2: int,
+        color: Color | LinearGradient,
+        /,
+        *,
+        thickness: int = 1,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.draw_triangle`, but draws on the canvas."""
+        ...
+
+    def fill_triangle(
+        self,
+        x0: int,
+        y0: int,
+        x1: int,
+        y1: int,
+        x2: int,
+        y2: int,
+        color: Color | LinearGradient,
+        /,
+        *,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.fill_triangle`, but draws on the canvas."""
+        ...
+
+    def draw_arc(
+        self,
+        x: int,
+        y: int,
+        radius: int,
+        start: float,
+        end: float,
+        color: Color | LinearGradient,
+        /,
+        *,
+        thickness: int = 1,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.draw_arc`, but draws on the canvas."""
+        ...
+
+    def fill_pie(
+        self,
+        x: int,
+        y: int,
+        radius: int,
+        start: float,
+        end: float,
+        color: Color | LinearGradient,
+        /,
+        *,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.fill_pie`, but draws on the canvas."""
+        ...
+
+    def draw_round_rect(
+        self,
+        x: int,
+        y: int,
+        width: int,
+        height: int,
+        radius: int,
+        color: Color | LinearGradient,
+        /,
+        *,
+        thickness: int = 1,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.draw_round_rect`, but draws on the canvas."""
+        ...
+
+    def fill_round_rect(
+        self,
+        x: int,
+        y: int,
+        width: int,
+        height: int,
+        radius: int,
+        color: Color | LinearGradient,
+        /,
+        *,
+        antialias: bool = False,
+    ) -> None:
+        """Like `display.fill_round_rect`, but draws on the canvas."""
+        ...
+
+    def blit(
+        self,
+        source: Canvas | Image,
+        x: int,
+        y: int,
+        /,
+        *,
+        region: tuple[int, int, int, int] | None = None,
+        alpha: int = 255,
+        blend: bool = True,
+    ) -> None:
+        """
+        Draws `source`, a `Canvas` or `Image`, on this canvas with its top-left corner at (`x`,
+        `y`).
+
+        `region`, as `(x, y, width, height)` in the source's pixels, draws only that part of it,
+        which copies part of a canvas to another place on itself if `source` is this canvas.
+        Pixels are blended over the canvas by their alpha, scaled by `alpha` out of 255 so that the
+        whole source can be faded. With `blend=False`, pixels are instead copied as they are,
+        transparency included, and `alpha` is ignored. Anything that falls outside the canvas is
+        clipped.
+
+        # Raises
+
+        - `TypeError`: If `source` isn't a `Canvas` or `Image`, or `region` isn't a tuple of four
+        integers.
+        - `ValueError`: If `region` is empty or extends outside the source, or `alpha` is outside
+        0 to 255.
+        """
+        ...
+
+    def draw(
+        self,
+        x: int,
+        y: int,
+        /,
+        *,
+        region: tuple[int, int, int, int] | None = None,
+        bg_color: Color = Color.BLACK,
+    ) -> None:
+        """
+        Draws the canvas with its top-left corner at (`x`, `y`) on the display.
+
+        `region`, as `(x, y, width, height)` in the canvas's pixels, draws only that part of the
+        canvas. Fully transparent pixels are left undrawn. Since the display can't be read back,
+        partly transparent pixels are blended with `bg_color`, which should be the color behind
+        them. Anything that falls outside the display is clipped.
+
+        # Raises
+
+        - `TypeError`: If `region` isn't a tuple of four integers.
+        - `ValueError`: If `region` is empty or extends outside the canvas.
+        """
+        ...
+
 def draw_pixel(x: int, y: int, color: Color) -> None:
     """
     Draws a filled pixel to the display with the specified `color`.
@@ -124,7 +685,16 @@ def draw_pixel(x: int, y: int, color: Color) -> None:
     ...
 
 def draw_line(
-    start_x: int, start_y: int, end_x: int, end_y: int, color: Color, /
+    start_x: int,
+    start_y: int,
+    end_x: int,
+    end_y: int,
+    color: Color | LinearGradient,
+    /,
+    *,
+    thickness: int = 1,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
 ) -> None:
     """
     Draws a line to the display with the specified `color`.
@@ -132,6 +702,15 @@ def draw_line(
     `start_x` and `start_y` are the start point of the line; `end_x` and `end_y` are the end point. The
     line width is one pixel. Coordinates are measured from the display's top-left, and both supplied
     endpoints are passed directly to the display SDK.
+
+    With `thickness` above one or `antialias=True`, the line is instead rasterized like the other
+    shapes, such as `display.draw_arc`: it's `thickness` pixels wide with rounded ends, and its
+    anti-aliased edges are blended with `bg_color`. `color` can then also be a `LinearGradient`.
+
+    # Raises
+
+    - `TypeError`: If `color` isn't a `Color`, or a `LinearGradient` when the line is rasterized.
+    - `ValueError`: If `thickness` is zero.
     """
     ...
 
@@ -261,6 +840,209 @@ def draw_text(
     """
     ...
 
+def draw_polygon(
+    points: list[tuple[int, int]] | tuple[tuple[int, int], ...],
+    color: Color | LinearGradient,
+    /,
+    *,
+    thickness: int = 1,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
+    """
+    Draws the outline of a closed polygon through `points`, a list of `(x, y)` tuples.
+
+    The outline is `thickness` pixels wide, centered on the edges, with rounded corners. With
+    `antialias=True`, its edges are smoothed by blending them with `bg_color`, which should be the
+    color behind the outline, since the display can't be read back.
+
+    # Raises
+
+    - `TypeError`: If `points` isn't a list or tuple of `(x, y)` tuples, or `color` isn't a `Color`
+    or `LinearGradient`.
+    - `ValueError`: If `points` is empty, a coordinate is outside the 16-bit range, or `thickness`
+    is zero.
+    """
+    ...
+
+def fill_polygon(
+    points: list[tuple[int, int]] | tuple[tuple[int, int], ...],
+    color: Color | LinearGradient,
+    /,
+    *,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
+    """
+    Fills the polygon through `points`, a list of `(x, y)` tuples.
+
+    Where edges cross, areas are filled by the even-odd rule, so the middle of a five-pointed star
+    is left empty. With `antialias=True`, the edges are smoothed by blending them with `bg_color`.
+
+    # Raises
+
+    - `TypeError`: If `points` isn't a list or tuple of `(x, y)` tuples, or `color` isn't a `Color`
+    or `LinearGradient`.
+    - `ValueError`: If `points` is empty or a coordinate is outside the 16-bit range.
+    """
+    ...
+
+def draw_triangle(
+    x0: int,
+    y0: int,
+    x1: int,
+    y1: int,
+    x2: int,
+    y2: int,
+    color: Color | LinearGradient,
+    /,
+    *,
+    thickness: int = 1,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
+    """
+    Draws the outline of the triangle with corners at (`x0`, `y0`), (`x1`, `y1`) and (`x2`, `y2`).
+
+    `thickness`, `antialias` and `bg_color` work like they do for `display.draw_polygon`.
+
+    # Raises
+
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    - `ValueError`: If `thickness` is zero.
+    """
+    ...
+
+def fill_triangle(
+    x0: int,
+    y0: int,
+    x1: int,
+    y1: int,
+    x2: int,
+    y2: int,
+    color: Color | LinearGradient,
+    /,
+    *,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
+    """
+    Fills the triangle with corners at (`x0`, `y0`), (`x1`, `y1`) and (`x2`, `y2`).
+
+    `antialias` and `bg_color` work like they do for `display.fill_polygon`.
+
+    # Raises
+
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    """
+    ...
+
+def draw_arc(
+    x: int,
+    y: int,
+    radius: int,
+    start: float,
+    end: float,
+    color: Color | LinearGradient,
+    /,
+    *,
+    thickness: int = 1,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
+    """
+    Draws part of the outline of the circle centered at `x` and `y`, from the angle `start` to
+    `end`.
+
+    Angles are in degrees, measured clockwise from the right, so 90 is straight down. The arc runs
+    clockwise from `start` to `end`, and is a whole circle if `end` is at least 360 degrees past
+    `start`. The ends of the arc are rounded. `thickness`, `antialias` and `bg_color` work like
+    they do for `display.draw_polygon`.
+
+    # Raises
+
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    - `ValueError`: If an angle isn't finite or `thickness` is zero.
+    """
+    ...
+
+def fill_pie(
+    x: int,
+    y: int,
+    radius: int,
+    start: float,
+    end: float,
+    color: Color | LinearGradient,
+    /,
+    *,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
+    """
+    Fills a slice of the circle centered at `x` and `y`, from the angle `start` to `end`.
+
+    Angles work like they do for `display.draw_arc`. `antialias` and `bg_color` work like they do
+    for `display.fill_polygon`.
+
+    # Raises
+
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    - `ValueError`: If an angle isn't finite.
+    """
+    ...
+
+def draw_round_rect(
+    x: int,
+    y: int,
+    width: int,
+    height: int,
+    radius: int,
+    color: Color | LinearGradient,
+    /,
+    *,
+    thickness: int = 1,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
+    """
+    Draws the outline of a rectangle with rounded corners.
+
+    `x` and `y` are the top-left corner, and `width` and `height` are measured in pixels. Corners
+    are rounded with `radius`, which is reduced if it doesn't fit. The outline is drawn inside the
+    rectangle, `thickness` pixels wide. `antialias` and `bg_color` work like they do for
+    `display.draw_polygon`.
+
+    # Raises
+
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    - `ValueError`: If `thickness` is zero.
+    """
+    ...
+
+def fill_round_rect(
+    x: int,
+    y: int,
+    width: int,
+    height: int,
+    radius: int,
+    color: Color | LinearGradient,
+    /,
+    *,
+    antialias: bool = False,
+    bg_color: Color = Color.BLACK,
+) -> None:
+    """
+    Fills a rectangle with rounded corners.
+
+    The rectangle is placed like it is for `display.draw_round_rect`. `antialias` and `bg_color`
+    work like they do for `display.fill_polygon`.
+
+    # Raises
+
+    - `TypeError`: If `color` isn't a `Color` or `LinearGradient`.
+    """
+    ...
+
 def print(*values: object, sep: str = " ", end: str = "\n") -> None:
     """
     Writes `values` to the Brain display's scrolling text area.
@@ -0,0 +1,587 @@
+"""
+Retained-mode widgets for the V5 Brain display.
+
+Widgets are arranged in a tree with `Row`, `Column` and `TabView`, and shown by a `Screen`,
+which redraws only what has changed and turns touches into widget events.
+"""
+
+from typing import Any, Callable
+
+from . import Color, TimeUnit
+
+Widget = Label | Button | Toggle | Slider | List | Row | Column | TabView | Dialog
+"""Any widget that can be shown on a `Screen`."""
+
+class Screen:
+    """
+    A tree of widgets shown on the display, available as `ui.Screen`.
+
+    The screen reads the touchscreen and redraws whatever has changed each time it is updated,
+    either by calling `update` in a loop or by awaiting the future returned by `run` in a task of
+    its own. Drawing is double buffered, so a screen should be the only thing drawing to the
+    display while it is in use.
+
+    Widgets raise events when they are used. Each event calls the widget's callback, if it has
+    one, and completes any `WidgetFuture` waiting on it.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    count = 0
+    label = ui.Label("Pressed 0 times", centered=True)
+
+    def pressed(_):
+    global count
+    count += 1
+    label.set_text("Pressed " + str(count) + " times")
+
+    screen = ui.Screen(ui.Column([label, ui.Button("Press me", on_press=pressed)], padding=8))
+    vasyncio.run(screen.run())
+    ```
+    """
+    def __init__(self, root: Widget, /) -> None:
+        """
+        Creates a screen showing `root`, which fills the whole display.
+
+        Nothing is drawn until the screen is first updated.
+
+        # Raises
+
+        - `TypeError`: If `root` isn't a widget, or is a `Dialog`.
+        """
+        ...
+
+    def update(self) -> None:
+        """
+        Reads the touchscreen and redraws whatever has changed since the last update.
+
+        If a touch raised an event, its callback is called once drawing is done. Callbacks that
+        are `async` functions are spawned as tasks, so they need a running event loop.
+
+        # Raises
+
+        - `RuntimeError`: If an `async` callback is called outside of an event loop.
+
+        Any exception raised by a callback propagates out of the update.
+        """
+        ...
+
+    def run(
+        self, period: float = 20.0, unit: TimeUnit = TimeUnit.MILLIS, /
+    ) -> ScreenFuture:
+        """
+        Returns a `ScreenFuture` that updates the screen every `period`, measured in `unit`, until
+        it is cancelled.
+
+        Await it from a task of its own, spawned with `vasyncio.spawn`.
+
+        # Raises
+
+        - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+        """
+        ...
+
+    def open(self, dialog: Dialog, /) -> None:
+        """
+        Shows `dialog` in the middle of the screen, on top of everything else.
+
+        Until it is closed, touches only reach the dialog. A dialog that is already open is
+        closed first.
+
+        # Raises
+
+        - `TypeError`: If `dialog` isn't a `Dialog`.
+        """
+        ...
+
+    def close(self) -> None:
+        """Closes the open dialog without raising an event, if there is one."""
+        ...
+
+    def redraw(self) -> None:
+        """
+        Lays out and draws every widget again at the next update.
+
+        Call this after drawing to the display with anything other than the screen.
+        """
+        ...
+
+class ScreenFuture:
+    """
+    An awaitable that updates a `Screen` on a fixed schedule until it is cancelled, returned by
+    `Screen.run`.
+
+    The first update happens as soon as the future is awaited and the rest follow once per period.
+    Like `vasyncio.Ticker`, updates are scheduled from the first one, and periods that are missed
+    because other tasks ran too long are skipped.
+    """
+
+class WidgetFuture:
+    """
+    An awaitable that completes with the value of a widget's next event, returned by the `wait`
+    method of each widget.
+
+    Events are raised by `Screen.update`, so the widget's screen must be updated, for example by
+    another task awaiting `Screen.run`, for the future to complete.
+    """
+
+class Label:
+    """
+    A line of text, available as `ui.Label`.
+
+    The read-only `text` attribute is the text being shown. Text longer than the label's width
+    isn't wrapped or cut off.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    status = ui.Label("Waiting for sensors", centered=True)
+    screen = ui.Screen(status)
+    screen.update()
+    ```
+    """
+
+    text: str
+    def __init__(
+        self, text: str, /, *, color: Color = Color.WHITE, centered: bool = False
+    ) -> None:
+        """
+        Creates a label showing `text` in `color`.
+
+        Text is left-aligned unless `centered` is `True`.
+
+        # Raises
+
+        - `TypeError`: If `text` isn't a string or `color` isn't a `Color`.
+        - `ValueError`: If `text` contains a NUL character.
+        """
+        ...
+
+    def set_text(self, text: str, /) -> None:
+        """
+        Changes the label's text.
+
+        # Raises
+
+        - `TypeError`: If `text` isn't a string.
+        - `ValueError`: If `text` contains a NUL character.
+        """
+        ...
+
+class Button:
+    """
+    A button that raises an event when tapped, available as `ui.Button`.
+
+    A tap counts once the finger is lifted, and only if it is lifted over the button, so sliding
+    off a button cancels it. The read-only `text` attribute is the button's text and
+    `is_pressed` is whether it is being held down.
+    """
+
+    text: str
+    is_pressed: bool
+    def __init__(
+        self, text: str, /, *, on_press: Callable[[None], Any] | None = None
+    ) -> None:
+        """
+        Creates a button showing `text`.
+
+        `on_press` is called with `None` each time the button is tapped. If it is an `async`
+        function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If `text` isn't a string or `on_press` isn't callable.
+        - `ValueError`: If `text` contains a NUL character.
+        """
+        ...
+
+    def set_text(self, text: str, /) -> None:
+        """
+        Changes the button's text.
+
+        # Raises
+
+        - `TypeError`: If `text` isn't a string.
+        - `ValueError`: If `text` contains a NUL character.
+        """
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """Returns a `WidgetFuture` that completes with `None` the next time the button is tapped."""
+        ...
+
+class Toggle:
+    """
+    An on/off switch with a label, available as `ui.Toggle`.
+
+    Tapping the toggle flips its value and raises an event with the new value. The read-only
+    `text` attribute is the toggle's label and `value` is whether it is on.
+    """
+
+    text: str
+    value: bool
+    def __init__(
+        self,
+        text: str,
+        /,
+        *,
+        value: bool = False,
+        on_change: Callable[[bool], Any] | None = None,
+    ) -> None:
+        """
+        Creates a toggle labeled `text`, which starts on if `value` is `True`.
+
+        `on_change` is called with the new value each time the toggle is tapped. If it is an
+        `async` function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If `text` isn't a string or `on_change` isn't callable.
+        - `ValueError`: If `text` contains a NUL character.
+        """
+        ...
+
+    def set_value(self, value: bool) -> None:
+        """Turns the toggle on or off without raising an event."""
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """
+        Returns a `WidgetFuture` that completes with the toggle's new value the next time it is
+        tapped.
+        """
+        ...
+
+class Slider:
+    """
+    A horizontal slider for picking a number in a range, available as `ui.Slider`.
+
+    The knob follows the finger while it is dragged, and an event with the new value is raised
+    when the finger is lifted. The read-only `value`, `min` and `max` attributes are the current
+    value and the ends of the range.
+    """
+
+    value: float
+    min: float
+    max: float
+    def __init__(
+        self,
+        min: float = 0.0,
+        max: float = 1.0,
+        /,
+        *,
+        value: float | None = None,
+        step: float | None = None,
+        on_change: Callable[[float], Any] | None = None,
+    ) -> None:
+        """
+        Creates a slider from `min` to `max`, starting at `value`, or at `min` if `value` is
+        `None`.
+
+        If `step` is given, the value is rounded to the nearest whole number of steps from `min`.
+        `on_change` is called with the new value each time the knob is let go. If it is an `async`
+        function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If a number isn't numeric or `on_change` isn't callable.
+        - `ValueError`: If `min` or `max` isn't finite, `min` isn't less than `max`, `step` isn't
+        positive and finite, or `value` is outside the range.
+        """
+        ...
+
+    def set_value(self, value: float) -> None:
+        """
+        Moves the knob to `value`, clamped to the range and rounded to a whole number of steps,
+        without raising an event.
+
+        # Raises
+
+        - `ValueError`: If `value` is NaN.
+        """
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """
+        Returns a `WidgetFuture` that completes with the slider's new value the next time the knob
+        is let go.
+        """
+        ...
+
+class List:
+    """
+    A scrolling list of text items, one of which can be selected, available as `ui.List`.
+
+    Tapping an item selects it and raises an event with its index. Dragging up or down scrolls
+    through items that don't fit. The read-only `selected` attribute is the index of the selected
+    item, or `None`.
+
+    # Examples
+
+    Pick an autonomous routine before the match:
+
+    ```python
+    from venice import *
+
+    routines = ["Left side", "Right side", "Skills"]
+    picker = ui.List(routines, selected=0)
+    screen = ui.Screen(picker)
+
+    async def main():
+    vasyncio.spawn(screen.run())
+    while True:
+    index = await picker.wait()
+    print("selected", routines[index])
+
+    vasyncio.run(main())
+    ```
+    """
+
+    selected: int | None
+    def __init__(
+        self,
+        items: list[str] | tuple[str, ...],
+        /,
+        *,
+        selected: int | None = None,
+        on_change: Callable[[int], Any] | None = None,
+    ) -> None:
+        """
+        Creates a list showing `items`, with the item at index `selected` selected.
+
+        `on_change` is called with an item's index each time it is tapped. If it is an `async`
+        function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If `items` isn't a list or tuple of strings, `selected` isn't an int or
+        `None`, or `on_change` isn't callable.
+        - `ValueError`: If an item contains a NUL character or `selected` is out of range.
+        """
+        ...
+
+    def set_items(self, items: list[str] | tuple[str, ...], /) -> None:
+        """
+        Replaces the list's items, clearing the selection and scrolling back to the top.
+
+        # Raises
+
+        - `TypeError`: If `items` isn't a list or tuple of strings.
+        - `ValueError`: If an item contains a NUL character.
+        """
+        ...
+
+    def select(self, index: int | None, /) -> None:
+        """
+        Selects the item at `index`, scrolling to it, or clears the selection if `index` is
+        `None`, without raising an event.
+
+        # Raises
+
+        - `TypeError`: If `index` isn't an int or `None`.
+        - `ValueError`: If `index` is out of range.
+        """
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """Returns a `WidgetFuture` that completes with an item's index the next time one is tapped."""
+        ...
+
+class Row:
+    """
+    Widgets placed side by side from left to right, available as `ui.Row`.
+
+    Each child gets a share of the row's width in proportion to its weight, after `padding` is
+    taken from every edge and `spacing` is left between neighbours.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    status = ui.Label("Ready")
+    start = ui.Button("Start")
+    screen = ui.Screen(ui.Row([status, start], weights=[2, 1], padding=8))
+    ```
+    """
+    def __init__(
+        self,
+        children: list[Widget] | tuple[Widget, ...],
+        /,
+        *,
+        weights: list[float] | tuple[float, ...] | None = None,
+        spacing: int = 6,
+        padding: int = 0,
+    ) -> None:
+        """
+        Creates a row of `children`, sized according to `weights`, or equally if it is `None`.
+
+        # Raises
+
+        - `TypeError`: If `children` isn't a list or tuple of widgets, or a `Dialog` is in it.
+        - `ValueError`: If there isn't one weight per child, a weight is negative or non-finite,
+        every weight is zero, or `spacing` or `padding` is negative.
+        """
+        ...
+
+class Column:
+    """
+    Widgets stacked from top to bottom, available as `ui.Column`.
+
+    Each child gets a share of the column's height in proportion to its weight, after `padding`
+    is taken from every edge and `spacing` is left between neighbours.
+    """
+    def __init__(
+        self,
+        children: list[Widget] | tuple[Widget, ...],
+        /,
+        *,
+        weights: list[float] | tuple[float, ...] | None = None,
+        spacing: int = 6,
+        padding: int = 0,
+    ) -> None:
+        """
+        Creates a column of `children`, sized according to `weights`, or equally if it is `None`.
+
+        # Raises
+
+        - `TypeError`: If `children` isn't a list or tuple of widgets, or a `Dialog` is in it.
+        - `ValueError`: If there isn't one weight per child, a weight is negative or non-finite,
+        every weight is zero, or `spacing` or `padding` is negative.
+        """
+        ...
+
+class TabView:
+    """
+    Pages of widgets with a bar of tabs to switch between them, available as `ui.TabView`.
+
+    Only the selected page is shown and receives touches. Tapping a tab selects its page and
+    raises an event with its index. The read-only `index` attribute is the index of the selected
+    page.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    tabs = ui.TabView([
+    ("Drive", ui.Label("Battery OK")),
+    ("Auton", ui.List(["Left", "Right", "Skills"])),
+    ])
+    screen = ui.Screen(tabs)
+    ```
+    """
+
+    index: int
+    def __init__(
+        self,
+        tabs: list[tuple[str, Widget]] | tuple[tuple[str, Widget], ...],
+        /,
+        *,
+        index: int = 0,
+        on_change: Callable[[int], Any] | None = None,
+    ) -> None:
+        """
+        Creates a tab view from `(title, widget)` pairs, with the page at `index` selected.
+
+        `on_change` is called with a page's index each time its tab is tapped. If it is an
+        `async` function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If `tabs` isn't a list or tuple of `(str, Widget)` tuples, a `Dialog` is in
+        it, or `on_change` isn't callable.
+        - `ValueError`: If `tabs` is empty, a title contains a NUL character, or `index` is out of
+        range.
+        """
+        ...
+
+    def select(self, index: int) -> None:
+        """
+        Shows the page at `index` without raising an event.
+
+        # Raises
+
+        - `ValueError`: If `index` is out of range.
+        """
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """
+        Returns a `WidgetFuture` that completes with a page's index the next time its tab is
+        tapped.
+        """
+        ...
+
+class Dialog:
+    """
+    A modal message with a row of buttons, available as `ui.Dialog`.
+
+    A dialog is shown over everything else with `Screen.open`, and while it is open, nothing
+    else on the screen can be touched. Tapping one of its buttons closes it and raises an event
+    with the button's index. The read-only `is_open` attribute is whether it is being shown.
+
+    The message is drawn on a single line, so it should be short.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    root = ui.Label("Driver control")
+    screen = ui.Screen(root)
+    confirm = ui.Dialog("Reset odometry?", "Put the robot in its corner.", buttons=["No", "Yes"])
+
+    async def main():
+    vasyncio.spawn(screen.run())
+    screen.open(confirm)
+    if await confirm.wait() == 1:
+    print("resetting")
+
+    vasyncio.run(main())
+    ```
+    """
+
+    is_open: bool
+    def __init__(
+        self,
+        title: str,
+        message: str,
+        /,
+        *,
+        buttons: list[str] | tuple[str, ...] = ["OK"],
+        on_choose: Callable[[int], Any] | None = None,
+    ) -> None:
+        """
+        Creates a dialog showing `title` and `message` above a row of `buttons`.
+
+        The dialog isn't shown until it is passed to `Screen.open`. `on_choose` is called with a
+        button's index each time the dialog is closed by tapping one. If it is an `async`
+        function, the coroutine it returns is spawned as a task.
+
+        # Raises
+
+        - `TypeError`: If `title`, `message` or a button's text isn't a string, or `on_choose`
+        isn't callable.
+        - `ValueError`: If a string contains a NUL character, or `buttons` is empty.
+        """
+        ...
+
+    def close(self) -> None:
+        """
+        Closes the dialog without raising an event.
+
+        The screen behind it is redrawn at the next update.
+        """
+        ...
+
+    def wait(self, /) -> WidgetFuture:
+        """
+        Returns a `WidgetFuture` that completes with a button's index the next time the dialog is
+        closed by tapping one.
+        """
+        ...
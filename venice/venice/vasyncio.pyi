@@ -2,10 +2,33 @@
 Venice implements its own async runtime on top of Micropython. `vasyncio` provides primitives for working with async Python in Venice.
 """
 
-from typing import Any
+from typing import Any, Callable, ClassVar
 
 from . import TimeUnit
 
+class CancelledError(BaseException):
+    """
+    Raised inside a task's coroutine when the task is cancelled.
+
+    Like CPython's `asyncio.CancelledError`, this derives from `BaseException` so that a broad
+    `except Exception:` clause does not accidentally swallow a cancellation.
+    """
+
+class TimeoutError(Exception):
+    """Raised by `vasyncio.wait_for` when the awaited task does not finish before its timeout."""
+
+class WatchdogError(RuntimeError):
+    """
+    Passed to the exception handler when a single resume of a task overruns the budget set with
+    `EventLoop.set_watchdog`.
+    """
+
+class QueueEmpty(Exception):
+    """Raised by `Queue.get_nowait` when the queue is empty."""
+
+class QueueFull(Exception):
+    """Raised by `Queue.put_nowait` when the queue is full."""
+
 class EventLoop:
     """
     A cooperative scheduler for Venice coroutine tasks and timed sleeps.
@@ -56,8 +79,10 @@ class EventLoop:
         Runs scheduled tasks until no ready tasks or pending sleeps remain.
 
         While this method is running, `vasyncio.get_running_loop` returns this loop and
-        `vasyncio.spawn` adds tasks to it. An exception raised by a task stops the loop and is
-        propagated to the caller.
+        `vasyncio.spawn` adds tasks to it. An exception raised by a task that nothing observes stops
+        the loop and is propagated to the caller, unless an exception handler is set with
+        `EventLoop.set_exception_handler`. Exceptions delivered to awaiting tasks and the
+        `CancelledError` of a cancelled task do not stop the loop.
 
         # Raises
 
@@ -66,6 +91,94 @@ class EventLoop:
         """
         ...
 
+    def set_watchdog(
+        self, budget: float | None, unit: TimeUnit = TimeUnit.SECOND, /
+    ) -> None:
+        """
+        Reports whenever a single resume of a task runs for longer than `budget`, measured in
+        `unit`.
+
+        A task only gives other tasks a chance to run when it reaches an `await`, so a task that
+        computes for a long time, or loops without awaiting, stalls the whole loop. With a watchdog
+        budget set, the loop creates a `vasyncio.WatchdogError` naming the offending task and how
+        long it ran, and passes it with the task to the exception handler set with
+        `EventLoop.set_exception_handler`. If there is no handler, the loop prints the message as a
+        warning instead. Either way, the task and the loop keep running. Pass `None` to turn the
+        watchdog off again.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        async def main():
+        vasyncio.get_running_loop().set_watchdog(5, MILLIS)
+        ...
+
+        vasyncio.run(main())
+        ```
+
+        # Raises
+
+        - `ValueError`: If `budget` is negative, non-finite, or too large to represent.
+        """
+        ...
+
+    def all_tasks(self, /) -> list[Task]:
+        """Returns a list of the tasks on this loop that have not finished yet."""
+        ...
+
+    def set_exception_handler(
+        self, handler: Callable[[Task, BaseException], Any] | None, /
+    ) -> None:
+        """
+        Sets the function called when a task fails with an exception that nothing observes.
+
+        A task's failure is observed if another task is awaiting it when it fails. Otherwise
+        `handler` is called with the failed task and its exception, and the loop keeps running the
+        remaining tasks, so one crashing background task doesn't stop the rest of the program.
+        `handler` is also called each time a supervised task fails before it is restarted, and with
+        a `vasyncio.WatchdogError` for each resume that overruns the budget set with
+        `EventLoop.set_watchdog`.
+
+        Pass `None` to restore the default behavior, where an unobserved exception propagates out
+        of the running loop, and supervised task failures and watchdog overruns are printed.
+        Exceptions raised by `handler` itself propagate out of the loop.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        def log_failure(task, exc):
+        print("task", task.get_name(), "failed:", exc)
+
+        async def telemetry():
+        raise RuntimeError("radio disconnected")
+
+        async def main():
+        vasyncio.get_running_loop().set_exception_handler(log_failure)
+        vasyncio.spawn(telemetry())
+        # ... keep driving ...
+
+        vasyncio.run(main())
+        ```
+
+        # Raises
+
+        - `TypeError`: If `handler` is neither callable nor `None`.
+        """
+        ...
+
+class ExitFuture:
+    """
+    An awaitable returned when leaving an `async with` block on a `Lock`, `Semaphore`, or
+    `Condition`.
+
+    The primitive is released as soon as the block is left, so awaiting this completes immediately.
+    It returns `False`, so an exception raised inside the block keeps propagating.
+    """
+
 class Sleep:
     """
     An awaitable that will complete after a given duration.
@@ -112,8 +225,16 @@ class Task:
     `vasyncio` submodule and is not constructed by users. Awaiting a task cooperatively waits for
     its coroutine and returns that coroutine's return value, including when the task completed
     before the await began. Direct or transitive cycles between awaited tasks raise `RuntimeError`.
-    A coroutine exception propagates out of the running event loop. `Task` objects cannot be
-    cancelled.
+
+    If the coroutine raises an exception, the task stores it and every task awaiting it receives the
+    same exception. A `CancelledError` marks the task as cancelled. Any other exception that nothing
+    observes, because no task is awaiting the failed task, is passed to the loop's exception
+    handler; see `EventLoop.set_exception_handler`. Without a handler, it propagates out of the
+    running event loop. Tasks created with `vasyncio.supervise` are restarted instead of failing.
+
+    Every task has a name, which defaults to `Task-<n>` and can be chosen with the `name` argument
+    of `vasyncio.spawn`. The loop also keeps run time statistics for each task; see `Task.stats`.
+    Tasks can be given a scheduling priority; see `Task.set_priority`.
 
     # Examples
 
@@ -134,69 +255,1137 @@ class Task:
     vasyncio.run(main())
     ```
     """
+    def cancel(self, /) -> bool:
+        """
+        Requests cancellation of this task.
 
-def run(coro: Any, /) -> None:
+        A `CancelledError` is thrown into the task's coroutine at the `await` point where it is
+        currently suspended, the next time the event loop runs. Pending sleeps are abandoned, so
+        `try`/`finally` blocks in the coroutine run promptly and can perform cleanup such as
+        stopping motors. If the coroutine lets the error propagate, the task becomes cancelled and
+        every task awaiting it receives the same `CancelledError`. A coroutine may catch the error
+        to refuse cancellation, in which case it keeps running normally.
+
+        A task that cancels itself receives the `CancelledError` at its next `await` point.
+
+        Returns `True` if cancellation was requested, or `False` if the task had already finished.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        motor = Motor(1)
+
+        async def spin():
+        try:
+        motor.set_voltage(6)
+        await vasyncio.Sleep(10, SECOND)
+        finally:
+        motor.brake(BrakeMode.COAST)
+
+        async def main():
+        task = vasyncio.spawn(spin())
+        await vasyncio.Sleep(1, SECOND)
+        task.cancel()
+
+        try:
+        await task
+        except vasyncio.CancelledError:
+        print("spin was cancelled")
+
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
+    def cancelled(self) -> bool:
+        """Returns `True` if the task was cancelled."""
+        ...
+
+    def done(self) -> bool:
+        """
+        Returns `True` if the task has finished, either by returning, by raising an exception, or by
+        being cancelled.
+        """
+        ...
+
+    def result(self, /) -> Any:
+        """
+        Returns the task's return value.
+
+        # Raises
+
+        - The exception the task raised, if it failed or was cancelled.
+        - `RuntimeError`: If the task hasn't finished.
+        """
+        ...
+
+    def exception(self, /) -> BaseException | None:
+        """
+        Returns the exception the task raised, or `None` if it returned normally.
+
+        Retrieving the exception marks the failure as handled.
+
+        # Raises
+
+        - `CancelledError`: If the task was cancelled.
+        - `RuntimeError`: If the task hasn't finished.
+        """
+        ...
+
+    def get_name(self, /) -> str:
+        """Returns the task's name."""
+        ...
+
+    def set_name(self, name: str) -> None:
+        """Renames the task."""
+        ...
+
+    def get_priority(self) -> int:
+        """Returns the task's scheduling priority."""
+        ...
+
+    def set_priority(self, priority: int) -> None:
+        """
+        Changes the task's scheduling priority.
+
+        When several tasks are ready to run, the event loop resumes the one with the highest
+        priority first, and tasks with equal priority run in the order they became ready. To keep
+        busy high-priority tasks from starving the rest, a ready task's priority is temporarily
+        raised by one for every few times another task is resumed ahead of it, and drops back once
+        it runs.
+
+        Priorities only decide which ready task runs next. A running task is never interrupted, so
+        a high-priority task still has to wait for the current task to yield.
+        """
+        ...
+
+    def stats(self) -> TaskStats:
+        """
+        Returns a snapshot of the task's run time statistics.
+
+        The event loop measures how long each resume of the task's coroutine runs before it yields
+        again. A task whose maximum run time is large is blocking other tasks from running, usually
+        because of a long loop without an `await`.
+
+        # Examples
+
+        ```python
+        from venice import *
+
+        async def main():
+        await vasyncio.Sleep(5, SECOND)
+        for task in vasyncio.all_tasks():
+        stats = task.stats()
+        print(task.get_name(), stats.resumes, stats.max_time(MILLIS))
+
+        vasyncio.run(main())
+        ```
+        """
+        ...
+
+class TaskStats:
     """
-    Runs coroutine object `coro` on a new event loop until no work remains.
+    Run time statistics of a `Task`, returned by `Task.stats`.
 
-    The loop also waits for tasks spawned into it and pending `Sleep` objects before returning
-    `None`. The root coroutine's return value is discarded, and an exception from any task stops
-    the loop and is propagated to the caller.
+    `resumes` is the number of times the event loop has resumed the task's coroutine, and
+    `restarts` is the number of times a supervised task has been restarted after failing. The run
+    time of a resume is measured from when the loop resumes the coroutine until it yields, returns,
+    or raises.
+    """
+
+    resumes: int
+    restarts: int
+    def total_time(self, unit: TimeUnit) -> float:
+        """Returns the combined run time of every resume, measured in `unit`."""
+        ...
+
+    def max_time(self, unit: TimeUnit) -> float:
+        """Returns the run time of the longest single resume, measured in `unit`."""
+        ...
+
+class Ticker:
+    """
+    A periodic timer whose ticks fire on a fixed schedule.
+
+    Awaiting a `Ticker` waits until its next tick. Unlike sleeping for the period at the end of a
+    loop, tick deadlines are computed from the time of the first tick rather than from when the
+    previous iteration finished, so the time spent doing work between ticks does not make the loop
+    drift. This keeps control loops, such as PID or odometry updates, running at a consistent rate.
+
+    The first tick fires one period after the ticker is first awaited. If an iteration runs long
+    and the deadline has already passed when the ticker is awaited, it returns immediately and counts
+    an *overrun*. If an entire period or more has been lost, the ticks that were skipped are counted
+    as *missed* and the schedule resumes at the next deadline that is still in the future, so the
+    ticker never fires a burst of ticks to catch up.
+
+    Awaiting a `Ticker` returns the number of ticks that were missed since the previous tick.
+
+    The `ticks`, `missed`, and `overruns` attributes report how many ticks have fired, how many
+    were skipped, and how many fired late.
 
     # Examples
 
     ```python
     from venice import *
 
+    motor = Motor(1)
+
     async def main():
-    print("start")
-    await vasyncio.Sleep(100, MILLIS)
-    print("done")
+    ticker = vasyncio.Ticker(10, MILLIS)
+    while True:
+    missed = await ticker
+    if missed:
+    print("control loop missed", missed, "ticks")
+    motor.set_voltage(6.0)
 
     vasyncio.run(main())
     ```
+    """
+
+    ticks: int
+    missed: int
+    overruns: int
+    def __init__(self, period: float, unit: TimeUnit, /) -> None:
+        """
+        Creates a ticker that fires every `period`, measured in `unit`.
+
+        Use `MILLIS` for milliseconds or `SECOND` for seconds. The schedule starts the first time the
+        ticker is awaited.
+
+        # Raises
+
+        - `TypeError`: If any keyword argument is supplied.
+        - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+        """
+        ...
+
+    def reset(self) -> None:
+        """
+        Restarts the schedule so that the next tick fires one period after the ticker is next
+        awaited.
+
+        The `ticks`, `missed`, and `overruns` counters are left unchanged.
+        """
+        ...
+
+class UntilFuture:
+    """
+    An awaitable that completes once a condition becomes true.
+
+    The condition is checked each time the future is awaited, and then again every poll interval
+    until it holds. Between checks, the awaiting task is parked on the event loop, so other tasks
+    keep running. Awaiting an `UntilFuture` again checks its condition afresh.
+
+    Users receive this from `vasyncio.until` or from device helpers such as
+    `RotationSensor.wait_until_position` rather than constructing it directly.
+
+    To give up on a condition that might never become true, await the future from a coroutine
+    passed to `vasyncio.wait_for`.
 
     # Raises
 
-    - `TypeError`: If `coro` is not a coroutine object.
-    - `RuntimeError`: If tasks form a direct or transitive await cycle.
-    - `ValueError`: If a `Sleep` deadline is too large to represent.
+    Any exception raised by the predicate or by the device reading propagates out of the await.
     """
-    ...
 
-def spawn(coro: Any, /) -> Task:
+class ReturnWhen:
     """
-    Spawns a new asynchronous task that can be controlled with the returned `Task` handle.
+    Selects when `vasyncio.wait` returns.
 
-    Call this from code already executing under `vasyncio.run` or `EventLoop.run`. Awaiting the task
-    yields the coroutine's return value whether the task is still running or has already completed.
+    `ReturnWhen` is exported from `vasyncio` and isn't constructed directly; use one of its
+    singleton constants, which are also exported from `vasyncio` as `FIRST_COMPLETED`,
+    `FIRST_EXCEPTION`, and `ALL_COMPLETED`.
+    """
+
+    FIRST_COMPLETED: ClassVar[ReturnWhen]
+    """Return as soon as any task finishes."""
+    FIRST_EXCEPTION: ClassVar[ReturnWhen]
+    """
+    Return as soon as any task fails with an exception other than `CancelledError`, or once
+    every task has finished if none fails.
+    """
+    ALL_COMPLETED: ClassVar[ReturnWhen]
+    """Return once every task has finished."""
+
+class SampleStream:
+    """
+    An asynchronous iterator that produces a sample on a fixed schedule.
+
+    Iterating with `async for` waits for each tick of the schedule and then takes a sample, so the
+    loop body runs once per period. Like `Ticker`, ticks are scheduled from the first sample rather
+    than from when the loop body finished, and ticks that are missed because the loop body ran too
+    long are skipped instead of being delivered in a burst. The stream never ends on its own; leave
+    the loop with `break` or `return`.
+
+    Users receive this from `vasyncio.sample` or from device helpers such as `Controller.states`
+    rather than constructing it directly.
+
+    # Raises
+
+    Any exception raised while taking a sample propagates out of the `async for` loop.
+    """
+    def __aiter__(self, /) -> SampleStream:
+        """Returns the stream itself."""
+        ...
+
+    def __anext__(self, /) -> SampleStream:
+        """Waits for the next tick of the schedule, then returns a new sample."""
+        ...
+
+class GatherFuture:
+    """
+    An awaitable returned by `vasyncio.gather`.
+
+    Awaiting it waits for every task to finish and returns a list of their return values, in the
+    order the awaitables were passed to `gather`.
+    """
+
+class WaitFuture:
+    """
+    An awaitable returned by `vasyncio.wait`.
+
+    Awaiting it returns a `(done, pending)` tuple of task lists once its `ReturnWhen` condition is
+    met or its timeout elapses.
+    """
+
+class WaitForFuture:
+    """
+    An awaitable returned by `vasyncio.wait_for`.
+
+    Awaiting it returns the task's result if the task finishes in time. Otherwise, the task is
+    cancelled and `TimeoutError` is raised once the cancellation has been delivered.
+    """
+
+class SyncFuture:
+    """
+    An awaitable returned by the waiting methods of `vasyncio` synchronization primitives.
+
+    Users receive it from `Event.wait`, `Lock.acquire`, `Semaphore.acquire`, `Condition.acquire`,
+    `Condition.wait`, `Queue.get`, or `Queue.put`, or by entering an `async with` block on a
+    `Lock`, `Semaphore`, or `Condition`, rather than constructing it directly. Awaiting it
+    parks the current task on the primitive until it can continue. It then returns the item for
+    `Queue.get`, `None` for `Queue.put`, and `True` otherwise. Waiting tasks are served in the order
+    they started waiting.
+
+    If the awaiting task is cancelled, the future removes itself from the primitive's queue. Any
+    lock or semaphore slot that was handed to it in the meantime is passed on to the next waiter, and
+    a queue item handed to a cancelled getter is put back at the front of the queue, so cancelled
+    tasks never leak ownership or drop items.
+
+    # Raises
+
+    - `RuntimeError`: If the future is awaited again after completing.
+    """
+    def throw(self, exc: BaseException, /) -> Any:
+        """
+        Delivers an exception thrown into the awaiting coroutine, such as a `CancelledError`.
+
+        The future leaves its wait queue before the exception propagates. A `Condition` waiter first
+        reacquires its lock so the lock is held when the exception reaches the caller, as it would be
+        after a normal return.
+        """
+        ...
+
+    def close(self) -> None:
+        """Leaves the wait queue when the awaiting coroutine is closed."""
+        ...
+
+class Event:
+    """
+    An event that tasks can wait for.
+
+    An `Event` holds a flag that starts out clear. `Event.set` sets the flag and wakes every task
+    waiting in `Event.wait`; while the flag is set, `Event.wait` returns immediately. `Event.clear`
+    resets the flag. Waiting tasks are parked on the event loop instead of polling.
 
     # Examples
 
     ```python
     from venice import *
 
-    async def answer():
+    optical = OpticalSensor(1)
+    ring_detected = vasyncio.Event()
+
+    async def watch_intake():
+    while optical.get_proximity() < 0.5:
     await vasyncio.Sleep(10, MILLIS)
-    return 42
+    ring_detected.set()
 
     async def main():
-    task = vasyncio.spawn(answer())
-    print(await task)
+    vasyncio.spawn(watch_intake())
+    await ring_detected.wait()
+    print("ring detected")
 
     vasyncio.run(main())
     ```
+    """
+    def __init__(self, /) -> None:
+        """
+        Creates an event whose flag is clear.
 
-    # Raises
+        # Raises
 
-    - `RuntimeError`: If no event loop is running.
-    - `TypeError`: If `coro` is not a coroutine object.
+        - `TypeError`: If any arguments are supplied.
+        """
+        ...
+
+    def is_set(self) -> bool:
+        """Returns `True` if the event's flag is set."""
+        ...
+
+    def set(self) -> None:
+        """Sets the event's flag and wakes every task waiting for it."""
+        ...
+
+    def clear(self) -> None:
+        """Clears the event's flag so that subsequent calls to `Event.wait` block again."""
+        ...
+
+    def wait(self, /) -> SyncFuture:
+        """
+        Waits until the event's flag is set.
+
+        Returns `True` immediately if the flag is already set. A task woken by `Event.set` returns
+        `True` even if the flag is cleared again before the task resumes.
+        """
+        ...
+
+class Lock:
     """
-    ...
+    A mutual exclusion lock for tasks.
 
-def get_running_loop() -> EventLoop | None:
+    A `Lock` guarantees exclusive access to a shared resource, such as a drivetrain or an intake,
+    across tasks. `Lock.acquire` waits until the lock is free and then takes it; `Lock.release`
+    frees it and hands it to the longest-waiting task. Locks are not owned by a particular task, so
+    any task may release a lock.
+
+    A lock can also be held for the duration of an `async with` block, which acquires it on entry
+    and releases it on exit, even if the block raises.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    drivetrain_lock = vasyncio.Lock()
+
+    async def auto_align():
+    async with drivetrain_lock:
+    ...
+    ```
     """
-    Returns the event loop currently executing tasks, or `None` outside `vasyncio.run` or
-    `EventLoop.run`.
+    def __init__(self, /) -> None:
+        """
+        Creates an unlocked lock.
+
+        # Raises
+
+        - `TypeError`: If any arguments are supplied.
+        """
+        ...
+
+    def locked(self) -> bool:
+        """Returns `True` if the lock is held."""
+        ...
+
+    def acquire(self, /) -> SyncFuture:
+        """
+        Waits until the lock is free, then takes it.
+
+        Returns `True` once the lock is held. Tasks acquire the lock in the order they started
+        waiting.
+        """
+        ...
+
+    def release(self) -> None:
+        """
+        Releases the lock, waking the next task waiting to acquire it.
+
+        # Raises
+
+        - `RuntimeError`: If the lock isn't held.
+        """
+        ...
+
+    def __aenter__(self, /) -> SyncFuture:
+        """Acquires the lock on entry to an `async with` block."""
+        ...
+
+    def __aexit__(self, exc_type: Any, exc: Any, tb: Any, /) -> ExitFuture:
+        """
+        Releases the lock on exit from an `async with` block.
+
+        # Raises
+
+        - `RuntimeError`: If the lock was released inside the block.
+        """
+        ...
+
+class Semaphore:
+    """
+    A counter-based limit on how many tasks may use a resource at once.
+
+    A `Semaphore` holds a number of free slots. `Semaphore.acquire` waits until a slot is free and
+    takes it; `Semaphore.release` returns a slot, handing it to the longest-waiting task if there is
+    one. An `async with` block holds a slot for its duration.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    # Allow at most two tasks to use the radio at a time.
+    radio_slots = vasyncio.Semaphore(2)
+
+    async def send_telemetry():
+    async with radio_slots:
+    ...
+    ```
+    """
+    def __init__(self, value: int = 1, /) -> None:
+        """
+        Creates a semaphore with `value` free slots.
+
+        # Raises
+
+        - `TypeError`: If keyword arguments or more than one positional argument are supplied.
+        - `ValueError`: If `value` is negative.
+        """
+        ...
+
+    def locked(self) -> bool:
+        """Returns `True` if no slot is free."""
+        ...
+
+    def acquire(self, /) -> SyncFuture:
+        """
+        Waits until a slot is free, then takes it.
+
+        Returns `True` once a slot is held. Tasks acquire slots in the order they started waiting.
+        """
+        ...
+
+    def release(self) -> None:
+        """Returns a slot, waking the next task waiting to acquire one."""
+        ...
+
+    def __aenter__(self, /) -> SyncFuture:
+        """Acquires a slot on entry to an `async with` block."""
+        ...
+
+    def __aexit__(self, exc_type: Any, exc: Any, tb: Any, /) -> ExitFuture:
+        """Returns the slot on exit from an `async with` block."""
+        ...
+
+class Condition:
+    """
+    A condition variable that lets tasks wait for a notification while sharing a `Lock`.
+
+    A task must hold the condition's lock to call `Condition.wait`, which releases the lock, waits
+    for `Condition.notify` or `Condition.notify_all`, then reacquires the lock before returning.
+    Notifications are not remembered, so a waiting task should check its condition in a loop.
+    An `async with` block holds the condition's lock for its duration.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    rings = []
+    ring_added = vasyncio.Condition()
+
+    async def consumer():
+    async with ring_added:
+    while not rings:
+    await ring_added.wait()
+    print("scoring", rings.pop(0))
+
+    async def producer():
+    async with ring_added:
+    rings.append("red")
+    ring_added.notify()
+    ```
+    """
+    def __init__(self, lock: Lock | None = None, /) -> None:
+        """
+        Creates a condition variable using `lock`, or a new `Lock` if `lock` is `None`.
+
+        # Raises
+
+        - `TypeError`: If `lock` is neither a `Lock` nor `None`, or if keyword arguments or more
+        than one positional argument are supplied.
+        """
+        ...
+
+    def locked(self) -> bool:
+        """Returns `True` if the underlying lock is held."""
+        ...
+
+    def acquire(self, /) -> SyncFuture:
+        """Waits until the underlying lock is free, then takes it."""
+        ...
+
+    def release(self) -> None:
+        """
+        Releases the underlying lock.
+
+        # Raises
+
+        - `RuntimeError`: If the lock isn't held.
+        """
+        ...
+
+    def __aenter__(self, /) -> SyncFuture:
+        """Acquires the underlying lock on entry to an `async with` block."""
+        ...
+
+    def __aexit__(self, exc_type: Any, exc: Any, tb: Any, /) -> ExitFuture:
+        """
+        Releases the underlying lock on exit from an `async with` block.
+
+        # Raises
+
+        - `RuntimeError`: If the lock was released inside the block.
+        """
+        ...
+
+    def wait(self, /) -> SyncFuture:
+        """
+        Releases the underlying lock, waits until notified, then reacquires the lock.
+
+        Returns `True` once the lock is held again. If the waiting task is cancelled, the lock is
+        still reacquired before `CancelledError` propagates.
+
+        # Raises
+
+        - `RuntimeError`: If the underlying lock isn't held when the wait starts.
+        """
+        ...
+
+    def notify(self, n: int = 1, /) -> None:
+        """
+        Wakes up to `n` tasks waiting on this condition.
+
+        Woken tasks resume once they have reacquired the underlying lock.
+
+        # Raises
+
+        - `TypeError`: If keyword arguments or more than one positional argument are supplied.
+        - `ValueError`: If `n` is negative.
+        """
+        ...
+
+    def notify_all(self) -> None:
+        """Wakes every task waiting on this condition."""
+        ...
+
+class Queue:
+    """
+    A first-in, first-out queue for passing items between tasks.
+
+    A `Queue` connects producer tasks, such as a sensor-polling loop, to consumer tasks, such as a
+    control loop. `Queue.get` waits until an item is available and `Queue.put` waits until there is
+    room for one, so a bounded queue slows a producer down when its consumer falls behind. Waiting
+    tasks are parked on the event loop and served in the order they started waiting.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    optical = OpticalSensor(1)
+    readings = vasyncio.Queue(4)
+
+    async def poll_proximity():
+    while True:
+    await readings.put(optical.get_proximity())
+    await vasyncio.Sleep(10, MILLIS)
+
+    async def control():
+    while True:
+    reading = await readings.get()
+    print("proximity:", reading)
+
+    async def main():
+    vasyncio.spawn(poll_proximity())
+    await control()
+
+    vasyncio.run(main())
+    ```
+    """
+
+    maxsize: int
+    def __init__(self, maxsize: int = 0, /) -> None:
+        """
+        Creates a queue that holds at most `maxsize` items.
+
+        If `maxsize` is less than or equal to zero, the queue is unbounded and `Queue.put` never
+        waits.
+
+        # Raises
+
+        - `TypeError`: If keyword arguments or more than one positional argument are supplied, or
+        if `maxsize` isn't an `int`.
+        """
+        ...
+
+    def qsize(self) -> int:
+        """Returns the number of items in the queue."""
+        ...
+
+    def empty(self) -> bool:
+        """Returns `True` if the queue has no items."""
+        ...
+
+    def full(self) -> bool:
+        """
+        Returns `True` if the queue holds `maxsize` items.
+
+        An unbounded queue is never full.
+        """
+        ...
+
+    def get(self, /) -> SyncFuture:
+        """Waits until an item is available, then removes and returns it."""
+        ...
+
+    def put(self, item: Any, /) -> SyncFuture:
+        """Waits until there is room in the queue, then adds `item` to it."""
+        ...
+
+    def get_nowait(self) -> Any:
+        """
+        Removes and returns an item without waiting.
+
+        # Raises
+
+        - `QueueEmpty`: If no item is available.
+        """
+        ...
+
+    def put_nowait(self, item: Any) -> None:
+        """
+        Adds `item` to the queue without waiting.
+
+        # Raises
+
+        - `QueueFull`: If the queue is full.
+        """
+        ...
+
+def run(coro: Any, /) -> None:
+    """
+    Runs coroutine object `coro` on a new event loop until no work remains.
+
+    The loop also waits for tasks spawned into it and pending `Sleep` objects before returning
+    `None`. The root coroutine's return value is discarded. An exception from a task that nothing
+    observes, including the root coroutine, stops the loop and is propagated to the caller unless
+    an exception handler is set with `EventLoop.set_exception_handler`. A `CancelledError` that ends
+    a cancelled task does not stop the loop.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    async def main():
+    print("start")
+    await vasyncio.Sleep(100, MILLIS)
+    print("done")
+
+    vasyncio.run(main())
+    ```
+
+    # Raises
+
+    - `TypeError`: If `coro` is not a coroutine object.
+    - `RuntimeError`: If tasks form a direct or transitive await cycle.
+    - `ValueError`: If a `Sleep` deadline is too large to represent.
+    """
+    ...
+
+def sample(source: Callable[[], Any], period: float, unit: TimeUnit, /) -> SampleStream:
+    """
+    Returns an asynchronous iterator that calls `source()` every `period`, measured in `unit`.
+
+    Each iteration of an `async for` loop over the stream waits for the next period to elapse and
+    then produces the value `source` returns. The awaiting task is parked between samples, so other
+    tasks keep running. See `SampleStream` for how late samples are handled.
+
+    # Examples
+
+    Print a rotation sensor's position 20 times per second:
+
+    ```python
+    from venice import *
+
+    sensor = RotationSensor(1)
+
+    async def main():
+    async for position in vasyncio.sample(lambda: sensor.get_position(DEGREES), 50, MILLIS):
+    print(position)
+
+    vasyncio.run(main())
+    ```
+
+    # Raises
+
+    - `TypeError`: If `source` isn't callable.
+    - `ValueError`: If `period` is not positive, is non-finite, or is too large to represent.
+    """
+    ...
+
+def spawn(coro: Any, /, *, name: str | None = None, priority: int = 0) -> Task:
+    """
+    Spawns a new asynchronous task that can be controlled with the returned `Task` handle.
+
+    Call this from code already executing under `vasyncio.run` or `EventLoop.run`. Awaiting the task
+    yields the coroutine's return value whether the task is still running or has already completed.
+
+    The task is named `name` if it is given, or `Task-<n>` otherwise. The name identifies the task
+    when it is printed and in watchdog errors.
+
+    When several tasks are ready to run, the loop resumes the one with the highest `priority`
+    first; see `Task.set_priority`. Tasks default to priority `0`.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    async def answer():
+    await vasyncio.Sleep(10, MILLIS)
+    return 42
+
+    async def main():
+    task = vasyncio.spawn(answer())
+    print(await task)
+
+    vasyncio.run(main())
+    ```
+
+    Run a drive control loop ahead of a logging task:
+
+    ```python
+    from venice import *
+
+    async def drive():
+    ...
+
+    async def log():
+    ...
+
+    async def main():
+    vasyncio.spawn(drive(), name="drive", priority=10)
+    vasyncio.spawn(log(), name="log", priority=-10)
+
+    vasyncio.run(main())
+    ```
+
+    # Raises
+
+    - `RuntimeError`: If no event loop is running.
+    - `TypeError`: If `coro` is not a coroutine object, `name` is not a `str`, or `priority` is not
+    an `int`.
+    """
+    ...
+
+def supervise(
+    factory: Callable[[], Any],
+    /,
+    *,
+    name: str | None = None,
+    priority: int = 0,
+    max_restarts: int | None = None,
+) -> Task:
+    """
+    Spawns a supervised task that is restarted whenever it fails.
+
+    `factory` is called with no arguments to create the task's coroutine, so it is usually an
+    `async def` function. When the coroutine raises an exception other than `CancelledError`, the
+    exception is reported to the loop's exception handler, or printed if there is none, and
+    `factory` is called again to create a fresh coroutine that continues as the same task. After
+    `max_restarts` restarts, the next failure ends the task like any other exception; `None` allows
+    unlimited restarts. Cancelling a supervised task stops it for good.
+
+    Tasks awaiting a supervised task keep waiting across restarts, and receive its return value
+    once a coroutine returns. `name` and `priority` work as they do for `vasyncio.spawn`.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    async def telemetry():
+    while True:
+    ...  # may raise if the radio disconnects
+    await vasyncio.Sleep(100, MILLIS)
+
+    async def main():
+    vasyncio.supervise(telemetry, name="telemetry", max_restarts=5)
+    # ... drive ...
+
+    vasyncio.run(main())
+    ```
+
+    # Raises
+
+    - `RuntimeError`: If no event loop is running.
+    - `TypeError`: If `factory` isn't callable, doesn't return a coroutine object, `name` is not a
+    `str`, or `priority` is not an `int`.
+    - `ValueError`: If `max_restarts` is negative.
+    """
+    ...
+
+def until(
+    predicate: Callable[[], Any],
+    /,
+    *,
+    poll: float = 10,
+    unit: TimeUnit = TimeUnit.MILLIS,
+) -> UntilFuture:
+    """
+    Waits until `predicate()` returns a truthy value.
+
+    `predicate` is called with no arguments when the returned future is awaited, and then every
+    `poll` interval, measured in `unit`, until it returns a truthy value. Awaiting the future
+    returns that value. The awaiting task is parked between calls, so other tasks keep running.
+
+    `predicate` should return quickly, since the event loop cannot run other tasks while it runs.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    optical = OpticalSensor(1)
+    intake = Motor(2)
+
+    async def main():
+    intake.set_voltage(12)
+    await vasyncio.until(lambda: optical.get_proximity() > 0.8, poll=20)
+    intake.brake(BrakeMode.HOLD)
+
+    vasyncio.run(main())
+    ```
+
+    # Raises
+
+    - `TypeError`: If `predicate` isn't callable.
+    - `ValueError`: If `poll` is negative, non-finite, or too large to represent.
+    """
+    ...
+
+def get_running_loop() -> EventLoop | None:
+    """
+    Returns the event loop currently executing tasks, or `None` outside `vasyncio.run` or
+    `EventLoop.run`.
+    """
+    ...
+
+def current_task() -> Task | None:
+    """
+    Returns the task that is currently running, or `None` outside a task.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    async def main():
+    print("running in", vasyncio.current_task().get_name())
+
+    vasyncio.run(main())
+    ```
+    """
+    ...
+
+def all_tasks() -> list[Task]:
+    """
+    Returns a list of the tasks on the running event loop that have not finished yet.
+
+    Together with `Task.stats`, this helps find the task that is stalling the loop.
+
+    # Raises
+
+    - `RuntimeError`: If no event loop is running.
+    """
+    ...
+
+def reserve(device: Any, /) -> Lock:
+    """
+    Returns the lock that reserves `device` for the task holding it.
+
+    Every call with the same device object on the running loop returns the same `Lock`, so tasks
+    that each take the reservation with `async with` never command the device at the same time. A
+    task that enters the block while another task holds it waits until the other task leaves its
+    block, and the reservation is released when the block exits, even if it raises or the task is
+    cancelled.
+
+    Reservations are only respected by code that takes them; they do not stop a task from using the
+    device directly. Reservations are keyed on the device object, so every task should share one
+    object for each device rather than creating its own.
+
+    # Raises
+
+    - `RuntimeError`: If no event loop is running.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    intake = Motor(1)
+
+    async def score():
+        async with vasyncio.reserve(intake):
+            intake.set_voltage(12.0)
+            await vasyncio.Sleep(500, MILLIS)
+            intake.set_voltage(0.0)
+
+    async def clear_jam():
+        async with vasyncio.reserve(intake):
+            intake.set_voltage(-12.0)
+            await vasyncio.Sleep(200, MILLIS)
+            intake.set_voltage(0.0)
+    ```
+    """
+    ...
+
+def gather(*aws: Any) -> GatherFuture:
+    """
+    Runs awaitables concurrently and returns an awaitable for all of their results.
+
+    Each awaitable in `aws` may be a coroutine object, which is spawned onto the running event loop
+    as a `Task`, or an existing `Task`. Awaiting the returned object waits for every task to finish
+    and returns a list of their return values in the same order as `aws`.
+
+    If any task fails, its exception, such as the `CancelledError` of a cancelled task, is raised in
+    the awaiting task as soon as it happens. The remaining tasks are not cancelled and keep running.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    async def double(x):
+    await vasyncio.Sleep(x, MILLIS)
+    return x * 2
+
+    async def main():
+    results = await vasyncio.gather(double(10), double(20), double(30))
+    assert results == [20, 40, 60]
+
+    vasyncio.run(main())
+    ```
+
+    # Raises
+
+    - `RuntimeError`: If a coroutine is passed and no event loop is running.
+    - `TypeError`: If an awaitable is neither a coroutine object nor a `Task`.
+    """
+    ...
+
+def wait(
+    tasks: list[Any] | tuple[Any, ...],
+    /,
+    *,
+    timeout: float | None = None,
+    unit: TimeUnit = TimeUnit.SECOND,
+    return_when: ReturnWhen = ReturnWhen.ALL_COMPLETED,
+) -> WaitFuture:
+    """
+    Waits for the tasks in `tasks` until the `return_when` condition is met or `timeout` elapses.
+
+    `tasks` is a list or tuple of coroutine objects or `Task`s; coroutines are spawned onto the
+    running event loop. `timeout` is measured in `unit` and starts when the returned object is first
+    awaited. A `timeout` of `None` waits without a time limit.
+
+    Awaiting the returned object produces a `(done, pending)` tuple of lists of tasks. Unlike
+    `wait_for`, tasks that are still pending when the timeout elapses are neither cancelled nor
+    reported as an error.
+
+    # Examples
+
+    Drive until a limit switch is pressed or two seconds pass, whichever happens first:
+
+    ```python
+    from venice import *
+
+    switch = AdiDigitalIn("A")
+
+    async def wait_for_switch():
+    while not switch.is_high():
+    await vasyncio.Sleep(10, MILLIS)
+
+    async def main():
+    done, pending = await vasyncio.wait(
+    [wait_for_switch()],
+    timeout=2,
+    return_when=vasyncio.FIRST_COMPLETED,
+    )
+
+    if pending:
+    print("switch was not pressed in time")
+    for task in pending:
+    task.cancel()
+
+    vasyncio.run(main())
+    ```
+
+    # Raises
+
+    - `RuntimeError`: If a coroutine is passed and no event loop is running.
+    - `TypeError`: If `tasks` isn't a list or tuple, or if an item is neither a coroutine object nor
+    a `Task`.
+    - `ValueError`: If `timeout` is negative, non-finite, or too large to represent.
+    """
+    ...
+
+def wait_for(
+    aw: Any, timeout: float | None, unit: TimeUnit = TimeUnit.SECOND, /
+) -> WaitForFuture:
+    """
+    Waits for `aw` to finish, cancelling it if `timeout` elapses first.
+
+    `aw` may be a coroutine object, which is spawned onto the running event loop, or a `Task`.
+    `timeout` is measured in `unit` and starts when the returned object is first awaited. A
+    `timeout` of `None` waits without a time limit.
+
+    Awaiting the returned object produces the task's return value. If the timeout elapses first,
+    the task is cancelled, the awaiting task waits until the cancellation has been delivered so that
+    the task's `finally` blocks have run, and then `TimeoutError` is raised.
+
+    # Examples
+
+    ```python
+    from venice import *
+
+    motor = Motor(1)
+    switch = AdiDigitalIn("A")
+
+    async def drive_until_switch():
+    try:
+    motor.set_voltage(6)
+    while not switch.is_high():
+    await vasyncio.Sleep(10, MILLIS)
+    finally:
+    motor.brake(BrakeMode.BRAKE)
+
+    async def main():
+    try:
+    await vasyncio.wait_for(drive_until_switch(), 2, SECOND)
+    except vasyncio.TimeoutError:
+    print("gave up after two seconds")
+
+    vasyncio.run(main())
+    ```
+
+    # Raises
+
+    - `RuntimeError`: If a coroutine is passed and no event loop is running.
+    - `TypeError`: If `aw` is neither a coroutine object nor a `Task`.
+    - `ValueError`: If `timeout` is negative, non-finite, or too large to represent.
     """
     ...